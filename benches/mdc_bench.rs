@@ -1,7 +1,10 @@
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 use glam::Vec3;
-use isomesh::manifold_dual_contouring::mdc::{MeshBuffers, mdc_mesh_generation};
-use isomesh::manifold_dual_contouring::sampler::{CuboidSampler, SphereSampler};
+use isomesh::manifold_dual_contouring::mdc::{
+    GridBounds, MeshBuffers, mdc_chunk_generation, mdc_mesh_generation,
+};
+use isomesh::manifold_dual_contouring::sampler::{CuboidSampler, Sampler, SphereSampler};
+use isomesh::validate::metrics::mesh_metrics;
 
 fn bench_single_sphere_small(c: &mut Criterion) {
     let resolution = 16;
@@ -13,8 +16,7 @@ fn bench_single_sphere_small(c: &mut Criterion) {
                 0.5,
                 &mut mesh_buffers,
                 false,
-                resolution,
-                40.0,
+                GridBounds::centered(40.0, resolution),
                 true,
                 &sphere,
             );
@@ -32,8 +34,7 @@ fn bench_single_sphere_large(c: &mut Criterion) {
                 0.5,
                 &mut mesh_buffers,
                 false,
-                resolution,
-                40.0,
+                GridBounds::centered(40.0, resolution),
                 true,
                 &sphere,
             );
@@ -52,8 +53,7 @@ fn bench_bulk_spheres_small(c: &mut Criterion) {
                     0.5,
                     &mut mesh_buffers,
                     false,
-                    resolution,
-                    40.0,
+                    GridBounds::centered(40.0, resolution),
                     true,
                     &sphere,
                 );
@@ -73,8 +73,7 @@ fn bench_bulk_spheres_large(c: &mut Criterion) {
                     0.5,
                     &mut mesh_buffers,
                     false,
-                    resolution,
-                    40.0,
+                    GridBounds::centered(40.0, resolution),
                     true,
                     &sphere,
                 );
@@ -91,7 +90,14 @@ fn bench_single_cube_small(c: &mut Criterion) {
         b.iter(|| {
             let cube = CuboidSampler::new(Vec3::ZERO, size);
             let mut mesh_buffers = MeshBuffers::new();
-            mdc_mesh_generation(0.5, &mut mesh_buffers, false, resolution, 40.0, true, &cube);
+            mdc_mesh_generation(
+                0.5,
+                &mut mesh_buffers,
+                false,
+                GridBounds::centered(40.0, resolution),
+                true,
+                &cube,
+            );
             black_box(mesh_buffers);
         });
     });
@@ -104,7 +110,14 @@ fn bench_single_cube_large(c: &mut Criterion) {
         b.iter(|| {
             let cube = CuboidSampler::new(Vec3::ZERO, size);
             let mut mesh_buffers = MeshBuffers::new();
-            mdc_mesh_generation(0.5, &mut mesh_buffers, false, resolution, 40.0, true, &cube);
+            mdc_mesh_generation(
+                0.5,
+                &mut mesh_buffers,
+                false,
+                GridBounds::centered(40.0, resolution),
+                true,
+                &cube,
+            );
             black_box(mesh_buffers);
         });
     });
@@ -118,7 +131,14 @@ fn bench_bulk_cubes_small(c: &mut Criterion) {
             for _ in 0..100 {
                 let cube = CuboidSampler::new(Vec3::ZERO, size);
                 let mut mesh_buffers = MeshBuffers::new();
-                mdc_mesh_generation(0.5, &mut mesh_buffers, false, resolution, 40.0, true, &cube);
+                mdc_mesh_generation(
+                    0.5,
+                    &mut mesh_buffers,
+                    false,
+                    GridBounds::centered(40.0, resolution),
+                    true,
+                    &cube,
+                );
                 black_box(mesh_buffers);
             }
         });
@@ -133,13 +153,101 @@ fn bench_bulk_cubes_large(c: &mut Criterion) {
             for _ in 0..100 {
                 let cube = CuboidSampler::new(Vec3::ZERO, size);
                 let mut mesh_buffers = MeshBuffers::new();
-                mdc_mesh_generation(0.5, &mut mesh_buffers, false, resolution, 40.0, true, &cube);
+                mdc_mesh_generation(
+                    0.5,
+                    &mut mesh_buffers,
+                    false,
+                    GridBounds::centered(40.0, resolution),
+                    true,
+                    &cube,
+                );
                 black_box(mesh_buffers);
             }
         });
     });
 }
 
+/// A sphere with an optional smooth bump that only changes samples within `radius` of `center`.
+struct BumpedSphere {
+    bump: f32,
+    center: Vec3,
+    radius: f32,
+}
+
+impl Sampler for BumpedSphere {
+    fn sample(&self, point: Vec3) -> f32 {
+        let falloff =
+            (1.0 - point.distance_squared(self.center) / (self.radius * self.radius)).max(0.0);
+        point.length() - 20.0 - self.bump * falloff * falloff
+    }
+}
+
+// The same edit applied to a 64^3 chunk by remeshing the edited region and by meshing the whole
+// chunk again. The region bench toggles the bump on and off, so every iteration is an edit.
+fn bench_remesh_region(c: &mut Criterion) {
+    let bounds = GridBounds::centered(48.0, 64);
+    let sampler = |bump| BumpedSphere {
+        bump,
+        center: Vec3::new(0.0, 20.0, 0.0),
+        radius: 3.0,
+    };
+    let reach = Vec3::splat(3.1);
+    let (min, max) = (
+        Vec3::new(0.0, 20.0, 0.0) - reach,
+        Vec3::new(0.0, 20.0, 0.0) + reach,
+    );
+    let mut group = c.benchmark_group("remesh_region");
+    group.bench_function("region", |b| {
+        let mut chunk = mdc_chunk_generation(0.5, bounds, true, &sampler(0.0));
+        let mut bumped = false;
+        b.iter(|| {
+            bumped = !bumped;
+            chunk.remesh_region(min, max, &sampler(if bumped { 1.5 } else { 0.0 }));
+            black_box(chunk.mesh_buffers.indices.len());
+        });
+    });
+    group.bench_function("full_rebuild", |b| {
+        b.iter(|| black_box(mdc_chunk_generation(0.5, bounds, true, &sampler(1.5))));
+    });
+    group.finish();
+}
+
+// Not timed: prints the approximation error and triangle quality of the benchmarked meshes, so
+// speed changes can be weighed against quality.
+fn report_mesh_quality(_c: &mut Criterion) {
+    fn report<S: Sampler + Send + Sync + 'static>(name: &str, resolution: i32, sampler: &S) {
+        let mut mesh_buffers = MeshBuffers::new();
+        mdc_mesh_generation(
+            0.5,
+            &mut mesh_buffers,
+            false,
+            GridBounds::centered(40.0, resolution),
+            true,
+            sampler,
+        );
+        let metrics = mesh_metrics(
+            &mesh_buffers.positions,
+            &mesh_buffers.normals,
+            &mesh_buffers.indices,
+            sampler,
+        );
+        println!("{name} quality\n{metrics}");
+    }
+    let sphere = SphereSampler::new(Vec3::ZERO, 20.0);
+    report("single_sphere_small", 16, &sphere);
+    report("single_sphere_large", 64, &sphere);
+    report(
+        "single_cube_small",
+        16,
+        &CuboidSampler::new(Vec3::ZERO, Vec3::new(2.0, 4.0, 8.0)),
+    );
+    report(
+        "single_cube_large",
+        64,
+        &CuboidSampler::new(Vec3::ZERO, Vec3::new(10.0, 15.0, 20.0)),
+    );
+}
+
 criterion_group!(
     benches,
     bench_single_sphere_small,
@@ -150,6 +258,8 @@ criterion_group!(
     bench_single_cube_large,
     bench_bulk_cubes_small,
     bench_bulk_cubes_large,
+    bench_remesh_region,
+    report_mesh_quality,
 );
 
 criterion_main!(benches);
@@ -0,0 +1,66 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use glam::{EulerRot, Quat, Vec3};
+use isomesh::qef::solver::{QefSolveOptions, QefSolverKind};
+
+const PROBLEMS: usize = 1000;
+
+/// Upper-triangle `AᵀA`, `Aᵀb` and mass point of randomly rotated sharp corners.
+fn corner_corpus() -> Vec<([f32; 6], Vec3, Vec3)> {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 40) as f32 / (1u64 << 24) as f32
+    };
+    (0..PROBLEMS)
+        .map(|_| {
+            let rotation = Quat::from_euler(
+                EulerRot::XYZ,
+                random() * 6.3,
+                random() * 6.3,
+                random() * 6.3,
+            );
+            let corner = Vec3::new(random(), random(), random()) * 8.0;
+            let axes = [Vec3::X, Vec3::Y, Vec3::Z].map(|axis| rotation * axis);
+            let mut ata = [0.0; 6];
+            let mut atb = Vec3::ZERO;
+            let mut mass_point = Vec3::ZERO;
+            for i in 0..3 {
+                let n = axes[i];
+                let p = corner + axes[(i + 1) % 3] * random() + axes[(i + 2) % 3] * random();
+                ata[0] += n.x * n.x;
+                ata[1] += n.x * n.y;
+                ata[2] += n.x * n.z;
+                ata[3] += n.y * n.y;
+                ata[4] += n.y * n.z;
+                ata[5] += n.z * n.z;
+                atb += n * n.dot(p);
+                mass_point += p;
+            }
+            (ata, atb, mass_point / 3.0)
+        })
+        .collect()
+}
+
+fn bench_qef_solvers(c: &mut Criterion) {
+    let corpus = corner_corpus();
+    for kind in [
+        QefSolverKind::Jacobi,
+        QefSolverKind::Leven,
+        QefSolverKind::Double,
+    ] {
+        let options = QefSolveOptions::new(kind);
+        c.bench_function(&format!("qef_corners_{kind:?}"), |b| {
+            b.iter(|| {
+                for (ata, atb, mass_point) in &corpus {
+                    black_box(options.solve(ata, *atb, *mass_point));
+                }
+            });
+        });
+    }
+}
+
+criterion_group!(benches, bench_qef_solvers);
+
+criterion_main!(benches);
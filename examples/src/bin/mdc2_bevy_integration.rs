@@ -10,8 +10,11 @@ use bevy::{
     },
 };
 use isomesh::{
-    manifold_dual_contouring::sampler::{CuboidSampler, SphereSampler},
-    manifold_dual_contouring_2::{mdc::mdc_mesh_generation, octree::MeshVertex},
+    manifold_dual_contouring::{
+        mdc::{GridBounds, MeshBuffers},
+        sampler::{CuboidSampler, SphereSampler},
+    },
+    manifold_dual_contouring_2::mdc::mdc_mesh_generation,
 };
 
 fn main() {
@@ -39,8 +42,16 @@ fn setup_mdc(
     let resolution = 64;
     // Generate sphere
     let sphere_sampler = SphereSampler::new(Vec3::new(0.0, 0.0, 0.0), 20.0);
-    let (vertices, indexes) = mdc_mesh_generation(sphere_sampler, resolution);
-    let sphere_mesh = generate_bevy_mesh(vertices, indexes);
+    let mut mesh_buffers = MeshBuffers::new();
+    mdc_mesh_generation(
+        0.5,
+        &mut mesh_buffers,
+        false,
+        GridBounds::centered(resolution as f32, resolution),
+        true,
+        &sphere_sampler,
+    );
+    let sphere_mesh = generate_bevy_mesh(mesh_buffers);
     commands.spawn((
         Mesh3d(meshes.add(sphere_mesh)),
         MeshMaterial3d(materials.add(StandardMaterial {
@@ -53,8 +64,16 @@ fn setup_mdc(
     // Generate cube
     let size = Vec3::new(10.0, 15.0, 20.0);
     let cuboid_sampler = CuboidSampler::new(Vec3::new(0.0, 0.0, 0.0), size);
-    let (vertices, indexes) = mdc_mesh_generation(cuboid_sampler, resolution);
-    let cube_mesh = generate_bevy_mesh(vertices, indexes);
+    let mut mesh_buffers = MeshBuffers::new();
+    mdc_mesh_generation(
+        0.5,
+        &mut mesh_buffers,
+        false,
+        GridBounds::centered(resolution as f32, resolution),
+        true,
+        &cuboid_sampler,
+    );
+    let cube_mesh = generate_bevy_mesh(mesh_buffers);
     commands.spawn((
         Mesh3d(meshes.add(cube_mesh)),
         MeshMaterial3d(materials.add(StandardMaterial {
@@ -85,31 +104,21 @@ fn setup_mdc(
     ));
 }
 
-pub fn generate_bevy_mesh(vertices: Vec<MeshVertex>, indices: Vec<i32>) -> Mesh {
+pub fn generate_bevy_mesh(mesh_buffers: MeshBuffers) -> Mesh {
     let mut mesh = Mesh::new(
         PrimitiveTopology::TriangleList,
         RenderAssetUsages::default(),
     );
-    let positions: Vec<[f32; 3]> = vertices.iter().map(|v| v.pos.to_array()).collect();
-    let normals: Vec<[f32; 3]> = vertices.iter().map(|v| v.normal.to_array()).collect();
-    let colors: Vec<[f32; 4]> = vertices
-        .iter()
-        .map(|v| [v.color.x, v.color.y, v.color.z, 1.0])
-        .collect();
-
-    // Decode indices - remove the flip bit marker (0x10000000)
-    let indices_u32: Vec<u32> = indices
-        .iter()
-        .map(|i| {
-            let idx = (i & 0x0FFFFFFF) as u32;
-            idx
-        })
-        .collect();
-
+    let MeshBuffers {
+        positions,
+        normals,
+        colors,
+        materials: _,
+        indices,
+    } = mesh_buffers;
     mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
     mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
     mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
-    mesh.insert_indices(Indices::U32(indices_u32));
-
+    mesh.insert_indices(Indices::U32(indices));
     mesh
 }
@@ -10,8 +10,11 @@ use bevy::{
     },
 };
 use isomesh::{
-    manifold_dual_contouring::sampler::FunSurfaceSampler,
-    manifold_dual_contouring_2::{mdc::mdc_mesh_generation, octree::MeshVertex},
+    manifold_dual_contouring::{
+        mdc::{GridBounds, MeshBuffers},
+        sampler::FunSurfaceSampler,
+    },
+    manifold_dual_contouring_2::mdc::mdc_mesh_generation,
 };
 
 fn main() {
@@ -38,8 +41,16 @@ fn setup_mdc(
 ) {
     let resolution = 512;
     let fun_blob = FunSurfaceSampler::new(Vec3::new(0.0, 0.0, 0.0), 40.0);
-    let (vertices, indexes) = mdc_mesh_generation(fun_blob, resolution);
-    let mesh = generate_bevy_mesh(vertices, indexes);
+    let mut mesh_buffers = MeshBuffers::new();
+    mdc_mesh_generation(
+        0.5,
+        &mut mesh_buffers,
+        false,
+        GridBounds::centered(resolution as f32, resolution),
+        true,
+        &fun_blob,
+    );
+    let mesh = generate_bevy_mesh(mesh_buffers);
     commands.spawn((
         Mesh3d(meshes.add(mesh)),
         MeshMaterial3d(materials.add(StandardMaterial {
@@ -70,27 +81,21 @@ fn setup_mdc(
     ));
 }
 
-pub fn generate_bevy_mesh(vertices: Vec<MeshVertex>, indices: Vec<i32>) -> Mesh {
+pub fn generate_bevy_mesh(mesh_buffers: MeshBuffers) -> Mesh {
     let mut mesh = Mesh::new(
         PrimitiveTopology::TriangleList,
         RenderAssetUsages::default(),
     );
-    let positions: Vec<[f32; 3]> = vertices.iter().map(|v| v.pos.to_array()).collect();
-    let normals: Vec<[f32; 3]> = vertices.iter().map(|v| v.normal.to_array()).collect();
-    let colors: Vec<[f32; 4]> = vertices
-        .iter()
-        .map(|v| [v.color.x, v.color.y, v.color.z, 1.0])
-        .collect();
-    let indices_u32: Vec<u32> = indices
-        .iter()
-        .map(|i| {
-            let idx = (i & 0x0FFFFFFF) as u32;
-            idx
-        })
-        .collect();
+    let MeshBuffers {
+        positions,
+        normals,
+        colors,
+        materials: _,
+        indices,
+    } = mesh_buffers;
     mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
     mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
     mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
-    mesh.insert_indices(Indices::U32(indices_u32));
+    mesh.insert_indices(Indices::U32(indices));
     mesh
 }
@@ -11,7 +11,7 @@ use bevy::{
 };
 use isomesh::manifold_dual_contouring::sampler::SphereSampler;
 use isomesh::manifold_dual_contouring::{
-    mdc::{mdc_mesh_generation, MeshBuffers},
+    mdc::{mdc_mesh_generation, GridBounds, MeshBuffers},
     sampler::CuboidSampler,
 };
 
@@ -45,8 +45,7 @@ fn setup_mdc(
         0.5,
         &mut mesh_buffers,
         false,
-        resolution,
-        40.0,
+        GridBounds::centered(40.0, resolution),
         true,
         &sphere_sampler,
     );
@@ -68,8 +67,7 @@ fn setup_mdc(
         0.5,
         &mut mesh_buffers,
         false,
-        resolution,
-        40.0,
+        GridBounds::centered(40.0, resolution),
         true,
         &cuboid_sampler,
     );
@@ -112,6 +110,7 @@ pub fn generate_bevy_mesh(mesh_buffers: MeshBuffers) -> Mesh {
         positions,
         normals,
         colors,
+        materials: _,
         indices,
     } = mesh_buffers;
     mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
@@ -11,7 +11,7 @@ use bevy::{
 };
 //use isomesh::mdc::sampler::FunBlobSampler;
 use isomesh::manifold_dual_contouring::{
-    mdc::{mdc_mesh_generation, MeshBuffers},
+    mdc::{mdc_mesh_generation, GridBounds, MeshBuffers},
     sampler::FunSurfaceSampler,
 };
 
@@ -44,8 +44,7 @@ fn setup_mdc(
         0.5,
         &mut mesh_buffers,
         false,
-        resolution,
-        400.0,
+        GridBounds::centered(400.0, resolution),
         true,
         &fun_blob,
     );
@@ -88,6 +87,7 @@ pub fn generate_bevy_mesh(mesh_buffers: MeshBuffers) -> Mesh {
         positions,
         normals,
         colors,
+        materials: _,
         indices,
     } = mesh_buffers;
     mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
@@ -0,0 +1,353 @@
+// Octree level-of-detail selection: chunks double in size (and voxel size) with each lod.
+
+use std::collections::HashSet;
+
+use glam::{IVec3, Vec3};
+
+use crate::manifold_dual_contouring::mdc::GridBounds;
+
+pub const SEAM_NEG_X: u8 = 1 << 0;
+pub const SEAM_POS_X: u8 = 1 << 1;
+pub const SEAM_NEG_Y: u8 = 1 << 2;
+pub const SEAM_POS_Y: u8 = 1 << 3;
+pub const SEAM_NEG_Z: u8 = 1 << 4;
+pub const SEAM_POS_Z: u8 = 1 << 5;
+
+const FACE_NORMALS: [(IVec3, u8); 6] = [
+    (IVec3::NEG_X, SEAM_NEG_X),
+    (IVec3::X, SEAM_POS_X),
+    (IVec3::NEG_Y, SEAM_NEG_Y),
+    (IVec3::Y, SEAM_POS_Y),
+    (IVec3::NEG_Z, SEAM_NEG_Z),
+    (IVec3::Z, SEAM_POS_Z),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LodSettings {
+    /// Edge length of a lod 0 chunk.
+    pub chunk_size: f32,
+    pub cells_per_chunk: i32,
+    /// `lod_distances[i]` is the distance below which a lod `i + 1` chunk splits into lod `i` chunks.
+    /// Its length is the coarsest lod.
+    pub lod_distances: Vec<f32>,
+    /// Fraction of each distance a chunk must move past before it splits or merges again.
+    pub hysteresis: f32,
+    /// Coarsest-lod chunks further than this from the camera are not selected.
+    pub view_distance: f32,
+}
+
+impl Default for LodSettings {
+    fn default() -> Self {
+        Self {
+            chunk_size: 32.0,
+            cells_per_chunk: 32,
+            lod_distances: vec![64.0, 128.0, 256.0, 512.0],
+            hysteresis: 0.1,
+            view_distance: 1024.0,
+        }
+    }
+}
+
+impl LodSettings {
+    #[inline]
+    pub fn max_lod(&self) -> u32 {
+        self.lod_distances.len() as u32
+    }
+
+    #[inline]
+    pub fn chunk_size_at(&self, lod: u32) -> f32 {
+        self.chunk_size * (1u32 << lod) as f32
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LodChunk {
+    /// Chunk coordinate in units of this lod's chunk size.
+    pub coord: IVec3,
+    pub lod: u32,
+    pub origin: Vec3,
+    pub size: f32,
+    /// `SEAM_*` bits for faces that touch a chunk of a different lod.
+    pub seam_faces: u8,
+}
+
+impl LodChunk {
+    /// Grid to mesh this chunk with: the same cell count at every lod, so voxels grow with distance.
+    pub fn bounds(&self, cells_per_chunk: i32) -> GridBounds {
+        GridBounds::new(
+            self.origin,
+            self.size / cells_per_chunk as f32,
+            IVec3::splat(cells_per_chunk),
+        )
+    }
+
+    #[inline]
+    pub fn has_seam(&self, face: u8) -> bool {
+        self.seam_faces & face != 0
+    }
+}
+
+pub struct LodSelector {
+    settings: LodSettings,
+    split: HashSet<(u32, IVec3)>,
+}
+
+impl LodSelector {
+    pub fn new(settings: LodSettings) -> Self {
+        Self {
+            settings,
+            split: HashSet::new(),
+        }
+    }
+
+    #[inline]
+    pub fn settings(&self) -> &LodSettings {
+        &self.settings
+    }
+
+    /// Selects the chunks to draw for `camera`. Splits from the previous call are remembered,
+    /// so a chunk only merges again once the camera is `hysteresis` past its split distance.
+    /// Chunks sharing a face are at most one lod apart.
+    pub fn select(&mut self, camera: Vec3) -> Vec<LodChunk> {
+        let max_lod = self.settings.max_lod();
+        let root_size = self.settings.chunk_size_at(max_lod);
+        let radius = (self.settings.view_distance / root_size).ceil() as i32;
+        let camera_root = (camera / root_size).floor().as_ivec3();
+        let mut roots = Vec::new();
+        for x in -radius..=radius {
+            for y in -radius..=radius {
+                for z in -radius..=radius {
+                    let coord = camera_root + IVec3::new(x, y, z);
+                    let min = coord.as_vec3() * root_size;
+                    if distance_to_box(camera, min, min + Vec3::splat(root_size))
+                        <= self.settings.view_distance
+                    {
+                        roots.push(coord);
+                    }
+                }
+            }
+        }
+        let mut split = HashSet::new();
+        for &root in &roots {
+            self.split_node(camera, max_lod, root, &mut split);
+        }
+        balance(&mut split, max_lod);
+        let mut chunks = Vec::new();
+        for &root in &roots {
+            self.collect_chunks(max_lod, root, &split, &mut chunks);
+        }
+        flag_seams(&mut chunks, &split);
+        self.split = split;
+        chunks
+    }
+
+    fn split_node(&self, camera: Vec3, lod: u32, coord: IVec3, split: &mut HashSet<(u32, IVec3)>) {
+        if lod == 0 {
+            return;
+        }
+        let size = self.settings.chunk_size_at(lod);
+        let origin = coord.as_vec3() * size;
+        let distance = distance_to_box(camera, origin, origin + Vec3::splat(size));
+        let threshold = self.settings.lod_distances[lod as usize - 1];
+        let factor = if self.split.contains(&(lod, coord)) {
+            1.0 + self.settings.hysteresis
+        } else {
+            1.0 - self.settings.hysteresis
+        };
+        if distance < threshold * factor {
+            split.insert((lod, coord));
+            for child in children(coord) {
+                self.split_node(camera, lod - 1, child, split);
+            }
+        }
+    }
+
+    fn collect_chunks(
+        &self,
+        lod: u32,
+        coord: IVec3,
+        split: &HashSet<(u32, IVec3)>,
+        chunks: &mut Vec<LodChunk>,
+    ) {
+        if split.contains(&(lod, coord)) {
+            for child in children(coord) {
+                self.collect_chunks(lod - 1, child, split, chunks);
+            }
+            return;
+        }
+        let size = self.settings.chunk_size_at(lod);
+        chunks.push(LodChunk {
+            coord,
+            lod,
+            origin: coord.as_vec3() * size,
+            size,
+            seam_faces: 0,
+        });
+    }
+}
+
+#[inline]
+fn children(coord: IVec3) -> impl Iterator<Item = IVec3> {
+    (0..8).map(move |i| coord * 2 + IVec3::new((i >> 2) & 1, (i >> 1) & 1, i & 1))
+}
+
+/// Splits coarser chunks until chunks sharing a face are at most one lod apart: the children of
+/// a split node may only border chunks of the node's own size, so the nodes one lod up across
+/// each of its faces must be split as well.
+fn balance(split: &mut HashSet<(u32, IVec3)>, max_lod: u32) {
+    let mut pending: Vec<(u32, IVec3)> = split.iter().copied().collect();
+    while let Some((lod, coord)) = pending.pop() {
+        if lod == max_lod {
+            continue;
+        }
+        for (normal, _) in FACE_NORMALS {
+            let node = (lod + 1, (coord + normal).div_euclid(IVec3::splat(2)));
+            if split.insert(node) {
+                pending.push(node);
+            }
+        }
+    }
+}
+
+fn flag_seams(chunks: &mut [LodChunk], split: &HashSet<(u32, IVec3)>) {
+    let selected: HashSet<(u32, IVec3)> = chunks
+        .iter()
+        .map(|chunk| (chunk.lod, chunk.coord))
+        .collect();
+    for chunk in chunks.iter_mut() {
+        for (normal, bit) in FACE_NORMALS {
+            // Across the face lies one node of this chunk's size. After balancing it is either
+            // selected itself, split into chunks one lod finer, or part of a chunk one lod
+            // coarser; it is none of those only outside the view distance.
+            let neighbour = chunk.coord + normal;
+            let parent = (chunk.lod + 1, neighbour.div_euclid(IVec3::splat(2)));
+            if split.contains(&(chunk.lod, neighbour)) || selected.contains(&parent) {
+                chunk.seam_faces |= bit;
+            }
+        }
+    }
+}
+
+#[inline]
+fn distance_to_box(point: Vec3, min: Vec3, max: Vec3) -> f32 {
+    (point.clamp(min, max) - point).length()
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::{IVec3, Vec3, Vec3Swizzles};
+
+    use crate::chunk_world::lod::{
+        FACE_NORMALS, LodChunk, LodSelector, LodSettings, SEAM_NEG_X, SEAM_POS_X,
+    };
+
+    fn settings(lod_distances: Vec<f32>, hysteresis: f32, view_distance: f32) -> LodSettings {
+        LodSettings {
+            chunk_size: 1.0,
+            cells_per_chunk: 8,
+            lod_distances,
+            hysteresis,
+            view_distance,
+        }
+    }
+
+    fn chunk_at(chunks: &[LodChunk], point: Vec3) -> Option<&LodChunk> {
+        chunks.iter().find(|chunk| {
+            point.cmpge(chunk.origin).all() && point.cmplt(chunk.origin + chunk.size).all()
+        })
+    }
+
+    #[test]
+    fn test_selection_is_balanced() {
+        // The distances are close enough that, unbalanced, chunks two lods apart share faces.
+        let mut selector = LodSelector::new(settings(vec![3.0, 4.0, 5.0, 20.0], 0.0, 20.0));
+        for camera in [
+            Vec3::ZERO,
+            Vec3::new(0.5, 7.9, -3.2),
+            Vec3::new(15.9, 16.1, 0.0),
+        ] {
+            let chunks = selector.select(camera);
+            assert!(chunks.iter().any(|chunk| chunk.lod == 0));
+            for chunk in &chunks {
+                let center = chunk.origin + chunk.size * 0.5;
+                for (normal, bit) in FACE_NORMALS {
+                    // One probe just across each quarter of the face, which after balancing
+                    // meets every chunk there.
+                    let normal = normal.as_vec3();
+                    let (u, v) = (normal.yzx().abs(), normal.zxy().abs());
+                    let mut seam = false;
+                    for (su, sv) in [(-1.0, -1.0), (-1.0, 1.0), (1.0, -1.0), (1.0, 1.0)] {
+                        let offset = normal * 0.75 + (u * su + v * sv) * 0.25;
+                        let Some(neighbour) = chunk_at(&chunks, center + offset * chunk.size)
+                        else {
+                            continue;
+                        };
+                        assert!(
+                            neighbour.lod.abs_diff(chunk.lod) <= 1,
+                            "{camera}: {chunk:?} next to {neighbour:?}"
+                        );
+                        seam |= neighbour.lod != chunk.lod;
+                    }
+                    assert_eq!(chunk.has_seam(bit), seam, "{camera}: {chunk:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_does_not_flicker_at_its_distance() {
+        // One lod 1 root, split within 4 +- 10%, with the camera moving along +x.
+        let mut selector = LodSelector::new(settings(vec![4.0], 0.1, 16.0));
+        let mut is_split = |distance: f32| {
+            let chunks = selector.select(Vec3::new(2.0 + distance, 1.0, 1.0));
+            !chunks
+                .iter()
+                .any(|chunk| chunk.lod == 1 && chunk.coord == IVec3::ZERO)
+        };
+        assert!(!is_split(5.0));
+        assert!(!is_split(4.0));
+        assert!(!is_split(3.7));
+        assert!(is_split(3.5));
+        assert!(is_split(4.0));
+        assert!(is_split(4.0));
+        assert!(is_split(4.3));
+        assert!(!is_split(4.5));
+        assert!(!is_split(4.0));
+    }
+
+    #[test]
+    fn test_seams_flag_faces_between_lods() {
+        // The root around the camera splits; its 26 neighbours are in view but don't.
+        let mut selector = LodSelector::new(settings(vec![1.0], 0.0, 2.0));
+        let chunks = selector.select(Vec3::ONE);
+        assert_eq!(chunks.len(), 8 + 26);
+        for chunk in &chunks {
+            let expected = match chunk.lod {
+                // Only the faces of a split root's children on the root's surface are seams.
+                0 => FACE_NORMALS
+                    .iter()
+                    .filter(|(normal, _)| {
+                        let across = chunk.coord + *normal;
+                        across.cmplt(IVec3::ZERO).any() || across.cmpgt(IVec3::ONE).any()
+                    })
+                    .fold(0, |bits, (_, bit)| bits | bit),
+                // Face neighbours of the split root have one seam, towards it.
+                _ => FACE_NORMALS
+                    .iter()
+                    .filter(|(normal, _)| chunk.coord + *normal == IVec3::ZERO)
+                    .fold(0, |bits, (_, bit)| bits | bit),
+            };
+            assert_eq!(chunk.seam_faces, expected, "{chunk:?}");
+        }
+        let left = chunks
+            .iter()
+            .find(|chunk| chunk.lod == 1 && chunk.coord == IVec3::NEG_X)
+            .unwrap();
+        assert_eq!(left.seam_faces, SEAM_POS_X);
+        let corner = chunks
+            .iter()
+            .find(|chunk| chunk.lod == 0 && chunk.coord == IVec3::ZERO)
+            .unwrap();
+        assert!(corner.has_seam(SEAM_NEG_X) && !corner.has_seam(SEAM_POS_X));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod lod;
+pub mod world;
@@ -0,0 +1,676 @@
+// Streams fixed-size chunks around one or more viewers and meshes them on the rayon pool.
+
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+use glam::{IVec3, Vec3};
+use parking_lot::Mutex;
+
+use crate::manifold_dual_contouring::{
+    mdc::{self, GridBounds, mdc_mesh_generation_cancellable},
+    sampler::{Sampler, bake_sampler_quantized},
+};
+use crate::marching_cubes::mc::{self, mc_mesh_generation};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChunkMesher {
+    ManifoldDualContouring {
+        threshold: f32,
+        flat_shading: bool,
+        enforce_manifold: bool,
+    },
+    MarchingCubes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChunkWorldSettings {
+    /// World-space edge length of a chunk.
+    pub chunk_size: f32,
+    pub cells_per_chunk: i32,
+    /// Chunks whose centre is within this many chunk lengths of a viewer are kept loaded.
+    pub load_radius: i32,
+    /// Out-of-range chunks stay cached until this many chunks are loaded, then the least recently used are evicted.
+    pub max_loaded_chunks: usize,
+    pub mesher: ChunkMesher,
+}
+
+impl Default for ChunkWorldSettings {
+    fn default() -> Self {
+        Self {
+            chunk_size: 32.0,
+            cells_per_chunk: 32,
+            load_radius: 4,
+            max_loaded_chunks: 1024,
+            mesher: ChunkMesher::ManifoldDualContouring {
+                threshold: 0.5,
+                flat_shading: false,
+                enforce_manifold: true,
+            },
+        }
+    }
+}
+
+pub enum ChunkMesh {
+    ManifoldDualContouring(mdc::MeshBuffers),
+    MarchingCubes(mc::MeshBuffers),
+}
+
+pub enum ChunkEvent {
+    Loaded { coord: IVec3, mesh: ChunkMesh },
+    Unloaded(IVec3),
+}
+
+enum ChunkStatus {
+    Queued { distance: f32 },
+    Meshing { cancelled: Arc<AtomicBool> },
+    Loaded,
+}
+
+struct ChunkEntry {
+    status: ChunkStatus,
+    last_used: u64,
+}
+
+#[derive(Clone, Copy)]
+struct QueuedChunk {
+    distance: f32,
+    coord: IVec3,
+}
+
+// Reversed so the closest chunk sits on top of the max-heap.
+impl Ord for QueuedChunk {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then_with(|| other.coord.to_array().cmp(&self.coord.to_array()))
+    }
+}
+
+impl PartialOrd for QueuedChunk {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for QueuedChunk {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == CmpOrdering::Equal
+    }
+}
+
+impl Eq for QueuedChunk {}
+
+struct WorldState {
+    chunks: HashMap<IVec3, ChunkEntry>,
+    queue: BinaryHeap<QueuedChunk>,
+    frame: u64,
+}
+
+impl WorldState {
+    fn evict(&mut self, max_loaded_chunks: usize, sender: &Sender<ChunkEvent>) {
+        let mut loaded: Vec<(u64, IVec3)> = self
+            .chunks
+            .iter()
+            .filter(|(_, entry)| matches!(entry.status, ChunkStatus::Loaded))
+            .map(|(coord, entry)| (entry.last_used, *coord))
+            .collect();
+        if loaded.len() <= max_loaded_chunks {
+            return;
+        }
+        loaded.sort_unstable_by_key(|(last_used, coord)| (*last_used, coord.to_array()));
+        let excess = loaded.len() - max_loaded_chunks;
+        for (last_used, coord) in loaded.into_iter().take(excess) {
+            // Chunks still in range are never evicted, even over budget.
+            if last_used == self.frame {
+                break;
+            }
+            self.chunks.remove(&coord);
+            let _ = sender.send(ChunkEvent::Unloaded(coord));
+        }
+    }
+}
+
+pub struct ChunkWorld<S: Sampler + Send + Sync + 'static> {
+    sampler: Arc<S>,
+    settings: ChunkWorldSettings,
+    state: Arc<Mutex<WorldState>>,
+    sender: Sender<ChunkEvent>,
+}
+
+impl<S: Sampler + Send + Sync + 'static> ChunkWorld<S> {
+    /// Finished meshes and evictions are delivered on the returned receiver.
+    pub fn new(sampler: S, settings: ChunkWorldSettings) -> (Self, Receiver<ChunkEvent>) {
+        let (sender, receiver) = channel();
+        let world = Self {
+            sampler: Arc::new(sampler),
+            settings,
+            state: Arc::new(Mutex::new(WorldState {
+                chunks: HashMap::new(),
+                queue: BinaryHeap::new(),
+                frame: 0,
+            })),
+            sender,
+        };
+        (world, receiver)
+    }
+
+    #[inline]
+    pub fn settings(&self) -> &ChunkWorldSettings {
+        &self.settings
+    }
+
+    #[inline]
+    pub fn chunk_coord(&self, position: Vec3) -> IVec3 {
+        (position / self.settings.chunk_size).floor().as_ivec3()
+    }
+
+    pub fn chunk_bounds(&self, coord: IVec3) -> GridBounds {
+        chunk_bounds(&self.settings, coord)
+    }
+
+    pub fn is_loaded(&self, coord: IVec3) -> bool {
+        self.state
+            .lock()
+            .chunks
+            .get(&coord)
+            .is_some_and(|entry| matches!(entry.status, ChunkStatus::Loaded))
+    }
+
+    pub fn loaded_chunks(&self) -> Vec<IVec3> {
+        self.state
+            .lock()
+            .chunks
+            .iter()
+            .filter(|(_, entry)| matches!(entry.status, ChunkStatus::Loaded))
+            .map(|(coord, _)| *coord)
+            .collect()
+    }
+
+    /// Chunks queued or currently being meshed.
+    pub fn pending_count(&self) -> usize {
+        self.state
+            .lock()
+            .chunks
+            .values()
+            .filter(|entry| !matches!(entry.status, ChunkStatus::Loaded))
+            .count()
+    }
+
+    /// Recomputes the wanted chunk set around `viewers`, queues new chunks closest first,
+    /// cancels work that fell out of range and evicts cached chunks over the budget.
+    pub fn update(&mut self, viewers: &[Vec3]) {
+        let new_jobs = self.schedule(viewers);
+        // Each task meshes whichever chunk is closest when it starts, not the one it was spawned for.
+        for _ in 0..new_jobs {
+            let state = Arc::clone(&self.state);
+            let sampler = Arc::clone(&self.sampler);
+            let sender = self.sender.clone();
+            let settings = self.settings;
+            rayon::spawn(move || run_next_job(&state, &*sampler, &settings, &sender));
+        }
+    }
+
+    // `update` without spawning the meshing tasks; returns how many chunks were newly queued.
+    fn schedule(&mut self, viewers: &[Vec3]) -> usize {
+        let wanted = self.wanted_chunks(viewers);
+        let mut new_jobs = 0;
+        {
+            let mut state = self.state.lock();
+            state.frame += 1;
+            let frame = state.frame;
+            let WorldState { chunks, queue, .. } = &mut *state;
+            chunks.retain(|coord, entry| {
+                if wanted.contains_key(coord) {
+                    return true;
+                }
+                match &entry.status {
+                    ChunkStatus::Queued { .. } => false,
+                    ChunkStatus::Meshing { cancelled } => {
+                        cancelled.store(true, Ordering::Relaxed);
+                        false
+                    }
+                    ChunkStatus::Loaded => true,
+                }
+            });
+            for (coord, distance) in &wanted {
+                match chunks.get_mut(coord) {
+                    Some(entry) => {
+                        entry.last_used = frame;
+                        if let ChunkStatus::Queued { distance: queued } = &mut entry.status {
+                            *queued = *distance;
+                        }
+                    }
+                    None => {
+                        chunks.insert(
+                            *coord,
+                            ChunkEntry {
+                                status: ChunkStatus::Queued {
+                                    distance: *distance,
+                                },
+                                last_used: frame,
+                            },
+                        );
+                        new_jobs += 1;
+                    }
+                }
+            }
+            // Priorities change as viewers move, so the queue is rebuilt rather than patched.
+            queue.clear();
+            queue.extend(
+                chunks
+                    .iter()
+                    .filter_map(|(coord, entry)| match entry.status {
+                        ChunkStatus::Queued { distance } => Some(QueuedChunk {
+                            distance,
+                            coord: *coord,
+                        }),
+                        _ => None,
+                    }),
+            );
+            state.evict(self.settings.max_loaded_chunks, &self.sender);
+        }
+        new_jobs
+    }
+
+    fn wanted_chunks(&self, viewers: &[Vec3]) -> HashMap<IVec3, f32> {
+        let mut wanted = HashMap::new();
+        let radius = self.settings.load_radius;
+        let max_distance = radius as f32 * self.settings.chunk_size;
+        for viewer in viewers {
+            let center = self.chunk_coord(*viewer);
+            for x in -radius..=radius {
+                for y in -radius..=radius {
+                    for z in -radius..=radius {
+                        let coord = center + IVec3::new(x, y, z);
+                        let chunk_center =
+                            (coord.as_vec3() + Vec3::splat(0.5)) * self.settings.chunk_size;
+                        let distance = chunk_center.distance(*viewer);
+                        if distance > max_distance {
+                            continue;
+                        }
+                        wanted
+                            .entry(coord)
+                            .and_modify(|d: &mut f32| *d = d.min(distance))
+                            .or_insert(distance);
+                    }
+                }
+            }
+        }
+        wanted
+    }
+
+    /// Cancels all queued and in-flight work. Loaded chunks are kept.
+    pub fn cancel_pending(&mut self) {
+        let mut state = self.state.lock();
+        state.queue.clear();
+        state.chunks.retain(|_, entry| match &entry.status {
+            ChunkStatus::Queued { .. } => false,
+            ChunkStatus::Meshing { cancelled } => {
+                cancelled.store(true, Ordering::Relaxed);
+                false
+            }
+            ChunkStatus::Loaded => true,
+        });
+    }
+}
+
+impl<S: Sampler + Send + Sync + 'static> Drop for ChunkWorld<S> {
+    fn drop(&mut self) {
+        self.cancel_pending();
+    }
+}
+
+fn run_next_job<S: Sampler + Send + Sync + 'static>(
+    state: &Mutex<WorldState>,
+    sampler: &S,
+    settings: &ChunkWorldSettings,
+    sender: &Sender<ChunkEvent>,
+) {
+    let Some((coord, cancelled)) = start_next_job(state) else {
+        return;
+    };
+    let Some(mesh) = mesh_chunk(sampler, settings, coord, &cancelled) else {
+        return;
+    };
+    finish_job(state, settings, sender, coord, &cancelled, mesh);
+}
+
+// Pops the closest queued chunk and marks it as being meshed.
+fn start_next_job(state: &Mutex<WorldState>) -> Option<(IVec3, Arc<AtomicBool>)> {
+    let mut state = state.lock();
+    let WorldState { chunks, queue, .. } = &mut *state;
+    let job = queue.pop()?;
+    let entry = chunks.get_mut(&job.coord)?;
+    let cancelled = Arc::new(AtomicBool::new(false));
+    entry.status = ChunkStatus::Meshing {
+        cancelled: Arc::clone(&cancelled),
+    };
+    Some((job.coord, cancelled))
+}
+
+fn finish_job(
+    state: &Mutex<WorldState>,
+    settings: &ChunkWorldSettings,
+    sender: &Sender<ChunkEvent>,
+    coord: IVec3,
+    cancelled: &AtomicBool,
+    mesh: ChunkMesh,
+) {
+    let mut state = state.lock();
+    if cancelled.load(Ordering::Relaxed) {
+        return;
+    }
+    if let Some(entry) = state.chunks.get_mut(&coord) {
+        entry.status = ChunkStatus::Loaded;
+        // Sent under the lock so a later `Unloaded` for this chunk can't overtake it.
+        let _ = sender.send(ChunkEvent::Loaded { coord, mesh });
+        state.evict(settings.max_loaded_chunks, sender);
+    }
+}
+
+fn chunk_bounds(settings: &ChunkWorldSettings, coord: IVec3) -> GridBounds {
+    GridBounds::new(
+        coord.as_vec3() * settings.chunk_size,
+        settings.chunk_size / settings.cells_per_chunk as f32,
+        IVec3::splat(settings.cells_per_chunk),
+    )
+}
+
+// `None` once `cancelled` is seen, which is checked between meshing phases.
+fn mesh_chunk<S: Sampler + Send + Sync + 'static>(
+    sampler: &S,
+    settings: &ChunkWorldSettings,
+    coord: IVec3,
+    cancelled: &AtomicBool,
+) -> Option<ChunkMesh> {
+    if cancelled.load(Ordering::Relaxed) {
+        return None;
+    }
+    let bounds = chunk_bounds(settings, coord);
+    match settings.mesher {
+        ChunkMesher::ManifoldDualContouring {
+            threshold,
+            flat_shading,
+            enforce_manifold,
+        } => {
+            let mut mesh_buffers = mdc::MeshBuffers::new();
+            let finished = mdc_mesh_generation_cancellable(
+                threshold,
+                &mut mesh_buffers,
+                flat_shading,
+                bounds,
+                enforce_manifold,
+                cancelled,
+                sampler,
+            );
+            finished.then_some(ChunkMesh::ManifoldDualContouring(mesh_buffers))
+        }
+        ChunkMesher::MarchingCubes => {
+            let samples = settings.cells_per_chunk as usize + 1;
+            let half_extent = settings.chunk_size * 0.5;
+            let densities = bake_sampler_quantized(
+                sampler,
+                bounds.min,
+                bounds.max(),
+                (samples, samples, samples),
+            );
+            if cancelled.load(Ordering::Relaxed) {
+                return None;
+            }
+            let materials = vec![1; samples * samples * samples];
+            let mut mesh_buffers = mc::MeshBuffers::new();
+            mc_mesh_generation(
+                &mut mesh_buffers,
+                &densities,
+                &materials,
+                samples,
+                half_extent,
+            );
+            // MC meshes around the origin; move the chunk into place.
+            let center = bounds.min + Vec3::splat(half_extent);
+            for position in &mut mesh_buffers.positions {
+                *position = (Vec3::from_array(*position) + center).to_array();
+            }
+            Some(ChunkMesh::MarchingCubes(mesh_buffers))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::mpsc::Receiver;
+
+    use glam::{IVec3, Vec3};
+
+    use crate::{
+        chunk_world::world::{
+            ChunkEvent, ChunkMesher, ChunkWorld, ChunkWorldSettings, finish_job, mesh_chunk,
+            run_next_job, start_next_job,
+        },
+        manifold_dual_contouring::sampler::{Sampler, SphereSampler},
+    };
+
+    const CHUNK_SIZE: f32 = 8.0;
+
+    // Loads the chunk a viewer is in and its six face neighbours.
+    fn test_world(max_loaded_chunks: usize) -> (ChunkWorld<SphereSampler>, Receiver<ChunkEvent>) {
+        let settings = ChunkWorldSettings {
+            chunk_size: CHUNK_SIZE,
+            cells_per_chunk: 4,
+            load_radius: 1,
+            max_loaded_chunks,
+            mesher: ChunkMesher::MarchingCubes,
+        };
+        ChunkWorld::new(SphereSampler::new(Vec3::ZERO, 6.0), settings)
+    }
+
+    fn chunk_center(coord: IVec3) -> Vec3 {
+        (coord.as_vec3() + 0.5) * CHUNK_SIZE
+    }
+
+    // `coord` and its face neighbours, in the queue's tie-break order.
+    fn neighbourhood(coord: IVec3) -> Vec<IVec3> {
+        let mut coords = vec![coord];
+        for axis in [IVec3::X, IVec3::Y, IVec3::Z] {
+            coords.extend([coord - axis, coord + axis]);
+        }
+        coords.sort_by_key(|coord| coord.to_array());
+        coords
+    }
+
+    // Runs the queued jobs one at a time on this thread, as `update`'s tasks would.
+    fn run_jobs(world: &ChunkWorld<SphereSampler>) {
+        while !world.state.lock().queue.is_empty() {
+            run_next_job(
+                &world.state,
+                &*world.sampler,
+                &world.settings,
+                &world.sender,
+            );
+        }
+    }
+
+    // Received events as `(loaded, coord)`.
+    fn drain(events: &Receiver<ChunkEvent>) -> Vec<(bool, IVec3)> {
+        events
+            .try_iter()
+            .map(|event| match event {
+                ChunkEvent::Loaded { coord, .. } => (true, coord),
+                ChunkEvent::Unloaded(coord) => (false, coord),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_chunks_mesh_closest_first() {
+        let (mut world, events) = test_world(64);
+        assert_eq!(world.schedule(&[chunk_center(IVec3::ZERO)]), 7);
+        run_next_job(
+            &world.state,
+            &*world.sampler,
+            &world.settings,
+            &world.sender,
+        );
+        assert_eq!(drain(&events), [(true, IVec3::ZERO)]);
+
+        // Moving the viewer reorders the queue and drops chunks that fell out of range.
+        assert_eq!(world.schedule(&[chunk_center(IVec3::Z)]), 5);
+        run_jobs(&world);
+        let mut expected = vec![(true, IVec3::Z)];
+        expected.extend(
+            neighbourhood(IVec3::Z)
+                .into_iter()
+                .filter(|coord| *coord != IVec3::Z && *coord != IVec3::ZERO)
+                .map(|coord| (true, coord)),
+        );
+        assert_eq!(drain(&events), expected);
+        assert_eq!(world.pending_count(), 0);
+        assert!(!world.is_loaded(IVec3::X));
+    }
+
+    #[test]
+    fn test_cancelled_chunks_are_never_loaded() {
+        let (mut world, events) = test_world(64);
+        world.schedule(&[chunk_center(IVec3::ZERO)]);
+        let (coord, cancelled) = start_next_job(&world.state).unwrap();
+        assert_eq!(coord, IVec3::ZERO);
+
+        world.schedule(&[chunk_center(IVec3::splat(10))]);
+        assert!(cancelled.load(Ordering::Relaxed));
+        // A mesh finished just before the cancellation is dropped as well.
+        let mesh = mesh_chunk(
+            &*world.sampler,
+            &world.settings,
+            coord,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        finish_job(
+            &world.state,
+            &world.settings,
+            &world.sender,
+            coord,
+            &cancelled,
+            mesh,
+        );
+        assert!(!world.is_loaded(coord));
+
+        world.cancel_pending();
+        assert_eq!(world.pending_count(), 0);
+        run_jobs(&world);
+        run_next_job(
+            &world.state,
+            &*world.sampler,
+            &world.settings,
+            &world.sender,
+        );
+        assert!(drain(&events).is_empty());
+        assert!(world.loaded_chunks().is_empty());
+    }
+
+    #[test]
+    fn test_eviction_unloads_least_recently_used_after_loading() {
+        let (a, b, c) = (IVec3::ZERO, IVec3::new(10, 0, 0), IVec3::new(20, 0, 0));
+        let (mut world, events) = test_world(10);
+        world.schedule(&[chunk_center(a)]);
+        run_jobs(&world);
+        assert_eq!(drain(&events).len(), 7);
+
+        // Each chunk over the budget evicts one out of range, right after its own `Loaded`.
+        world.schedule(&[chunk_center(b)]);
+        run_jobs(&world);
+        let received = drain(&events);
+        let kinds: Vec<bool> = received.iter().map(|(loaded, _)| *loaded).collect();
+        assert_eq!(
+            kinds,
+            [
+                true, true, true, true, false, true, false, true, false, true, false
+            ]
+        );
+        let unloaded: Vec<IVec3> = received
+            .iter()
+            .filter(|(loaded, _)| !loaded)
+            .map(|(_, coord)| *coord)
+            .collect();
+        assert_eq!(unloaded, neighbourhood(a)[..4]);
+
+        // The chunks left over from `a` were used least recently, so they go before `b`'s.
+        world.schedule(&[chunk_center(c)]);
+        run_jobs(&world);
+        let unloaded: Vec<IVec3> = drain(&events)
+            .into_iter()
+            .filter(|(loaded, _)| !loaded)
+            .map(|(_, coord)| coord)
+            .collect();
+        let mut expected = neighbourhood(a)[4..].to_vec();
+        expected.extend_from_slice(&neighbourhood(b)[..4]);
+        assert_eq!(unloaded, expected);
+        let mut loaded = world.loaded_chunks();
+        loaded.sort_by_key(|coord| coord.to_array());
+        let mut expected = neighbourhood(b)[4..].to_vec();
+        expected.extend(neighbourhood(c));
+        expected.sort_by_key(|coord| coord.to_array());
+        assert_eq!(loaded, expected);
+    }
+
+    #[test]
+    fn test_chunks_in_range_are_kept_over_budget() {
+        let (mut world, events) = test_world(2);
+        world.schedule(&[chunk_center(IVec3::ZERO)]);
+        run_jobs(&world);
+        assert!(drain(&events).iter().all(|(loaded, _)| *loaded));
+        assert_eq!(world.loaded_chunks().len(), 7);
+    }
+
+    // A sphere that sets `cancel` on its first sample, as if the chunk fell out of range while
+    // it was being sampled.
+    struct CancellingSphere {
+        cancel: Arc<AtomicBool>,
+        samples: AtomicUsize,
+    }
+
+    impl Sampler for CancellingSphere {
+        fn sample(&self, point: Vec3) -> f32 {
+            self.cancel.store(true, Ordering::Relaxed);
+            self.samples.fetch_add(1, Ordering::Relaxed);
+            point.length() - 5.0
+        }
+    }
+
+    #[test]
+    fn test_mesh_chunk_stops_between_phases_once_cancelled() {
+        for mesher in [
+            ChunkWorldSettings::default().mesher,
+            ChunkMesher::MarchingCubes,
+        ] {
+            let settings = ChunkWorldSettings {
+                chunk_size: 16.0,
+                cells_per_chunk: 8,
+                mesher,
+                ..Default::default()
+            };
+            let cancel = Arc::new(AtomicBool::new(false));
+            let sampler = CancellingSphere {
+                cancel: Arc::clone(&cancel),
+                samples: AtomicUsize::new(0),
+            };
+            assert!(mesh_chunk(&sampler, &settings, IVec3::splat(-1), &cancel).is_none());
+            let samples = sampler.samples.load(Ordering::Relaxed);
+            assert!(samples > 0);
+
+            // Cancelled before it starts, the chunk is not even sampled.
+            assert!(mesh_chunk(&sampler, &settings, IVec3::splat(-1), &cancel).is_none());
+            assert_eq!(
+                sampler.samples.load(Ordering::Relaxed),
+                samples,
+                "{mesher:?}"
+            );
+        }
+    }
+}
@@ -1,3 +1,9 @@
+pub mod chunk_world;
 pub mod manifold_dual_contouring;
 pub mod manifold_dual_contouring_2;
 pub mod marching_cubes;
+pub mod post_process;
+pub mod qef;
+pub mod serialization;
+pub mod validate;
+pub mod voxel_volume;
@@ -1,19 +1,28 @@
 // Manifold Dual Contouring
 // https://github.com/Lin20/isosurface/tree/master/Isosurface/Isosurface/ManifoldDC
 
-use std::sync::{Arc, atomic::Ordering};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
 
-use glam::Vec3;
+use glam::{IVec3, Vec3};
 
-use crate::manifold_dual_contouring::{
-    octree::{ENFORCE_MANIFOLD, OctreeNode},
-    sampler::Sampler,
+use crate::{
+    manifold_dual_contouring::{
+        octree::OctreeNode,
+        sampler::{CornerSampler, MaterialSampler, MultiMaterial, Sampler, SingleMaterial},
+    },
+    marching_cubes::color_provider::{ColorProvider, MaterialColorProvider},
+    qef::solver::QefSolveOptions,
 };
 
 pub struct MeshBuffers {
     pub positions: Vec<[f32; 3]>,
     pub normals: Vec<[f32; 3]>,
     pub colors: Vec<[f32; 4]>,
+    /// Material of every vertex; empty unless meshed with `mdc_material_mesh_generation`.
+    pub materials: Vec<u8>,
     pub indices: Vec<u32>,
 }
 
@@ -30,109 +39,397 @@ impl Default for MeshBuffers {
             positions: Vec::new(),
             normals: Vec::new(),
             colors: Vec::new(),
+            materials: Vec::new(),
             indices: Vec::new(),
         }
     }
 }
 
+/// Axis-aligned sampling grid in world space: `resolution` cells of `cell_size` starting at `min`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridBounds {
+    pub min: Vec3,
+    pub cell_size: f32,
+    pub resolution: IVec3,
+}
+
+impl GridBounds {
+    pub fn new(min: Vec3, cell_size: f32, resolution: IVec3) -> Self {
+        Self {
+            min,
+            cell_size,
+            resolution,
+        }
+    }
+
+    /// Cubic grid of `bounding_width` centred on the origin.
+    pub fn centered(bounding_width: f32, resolution: i32) -> Self {
+        Self::new(
+            Vec3::splat(-bounding_width * 0.5),
+            bounding_width / resolution as f32,
+            IVec3::splat(resolution),
+        )
+    }
+
+    #[inline]
+    pub fn max(&self) -> Vec3 {
+        self.min + self.resolution.as_vec3() * self.cell_size
+    }
+
+    /// Side length in cells of the smallest power-of-two octree covering the grid.
+    #[inline]
+    pub(crate) fn octree_size(&self) -> i32 {
+        (self.resolution.max_element().max(1) as u32).next_power_of_two() as i32
+    }
+}
+
 pub fn mdc_mesh_generation<S: Sampler + Send + Sync + 'static>(
     threshold: f32,
     mesh_buffers: &mut MeshBuffers,
     flat_shading: bool,
-    resolution: i32,
-    bounding_width: f32,
+    bounds: GridBounds,
     enforce_manifold: bool,
     sampler: &S,
 ) {
-    ENFORCE_MANIFOLD.store(enforce_manifold, Ordering::Relaxed);
+    mdc_mesh_generation_with_options(
+        threshold,
+        mesh_buffers,
+        flat_shading,
+        bounds,
+        enforce_manifold,
+        QefSolveOptions::default(),
+        sampler,
+    );
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MdcStats {
+    /// Vertices whose QEF minimiser left their cell and were moved back into it.
+    pub clamped_vertices: usize,
+}
+
+/// `mdc_mesh_generation` with an explicit QEF solver backend and its SVD settings.
+pub fn mdc_mesh_generation_with_options<S: Sampler + Send + Sync + 'static>(
+    threshold: f32,
+    mesh_buffers: &mut MeshBuffers,
+    flat_shading: bool,
+    bounds: GridBounds,
+    enforce_manifold: bool,
+    qef: QefSolveOptions,
+    sampler: &S,
+) -> MdcStats {
+    let (tree, clamped_vertices) =
+        build_octree(bounds, mesh_buffers, &qef, &SingleMaterial(sampler));
+    calculate_indexes(
+        &tree,
+        threshold,
+        enforce_manifold,
+        mesh_buffers,
+        flat_shading,
+    );
+    MdcStats { clamped_vertices }
+}
+
+/// `mdc_mesh_generation` for background jobs: stops between meshing phases once `cancelled` is
+/// set and returns `false`, leaving `mesh_buffers` partly filled.
+pub(crate) fn mdc_mesh_generation_cancellable<S: Sampler + Send + Sync + 'static>(
+    threshold: f32,
+    mesh_buffers: &mut MeshBuffers,
+    flat_shading: bool,
+    bounds: GridBounds,
+    enforce_manifold: bool,
+    cancelled: &AtomicBool,
+    sampler: &S,
+) -> bool {
+    let is_cancelled = || cancelled.load(Ordering::Relaxed);
+    let qef = QefSolveOptions::default();
     let mut tree = Box::new(OctreeNode::new());
-    tree.construct_base(resolution, bounding_width, mesh_buffers, Arc::new(sampler));
-    tree.cluster_cell_base(0.0);
-    tree.generate_vertex_buffer(mesh_buffers);
-    calculate_indexes(&tree, threshold, mesh_buffers, flat_shading);
+    tree.construct_base(
+        bounds,
+        mesh_buffers,
+        &qef,
+        Arc::new(&SingleMaterial(sampler)),
+    );
+    if is_cancelled() {
+        return false;
+    }
+    tree.cluster_cell_base(&qef);
+    if is_cancelled() {
+        return false;
+    }
+    tree.generate_vertex_buffer(mesh_buffers, &qef);
+    if is_cancelled() {
+        return false;
+    }
+    calculate_indexes(
+        &tree,
+        threshold,
+        enforce_manifold,
+        mesh_buffers,
+        flat_shading,
+    );
+    true
+}
+
+/// Meshes a solid made of several materials in a single pass. Cell corners are labelled by their
+/// material, so a border between two materials gets one surface, facing out of the material with
+/// the higher id, which meets the outer surface in cells where three labels meet. Vertices keep
+/// the material they face out of in `materials` and are colored by `MaterialColorProvider`.
+pub fn mdc_material_mesh_generation<S: MaterialSampler + Send + Sync>(
+    threshold: f32,
+    mesh_buffers: &mut MeshBuffers,
+    flat_shading: bool,
+    bounds: GridBounds,
+    enforce_manifold: bool,
+    sampler: &S,
+) {
+    *mesh_buffers = MeshBuffers::new();
+    let qef = QefSolveOptions::default();
+    let (tree, _) = build_octree(bounds, mesh_buffers, &qef, &MultiMaterial(sampler));
+    mesh_buffers.materials = vec![0; mesh_buffers.positions.len()];
+    tree.vertex_materials(&mut mesh_buffers.materials);
+    mesh_buffers.colors = mesh_buffers
+        .materials
+        .iter()
+        .map(|material| MaterialColorProvider.get_color(*material, Vec3::ZERO))
+        .collect();
+    calculate_indexes(
+        &tree,
+        threshold,
+        enforce_manifold,
+        mesh_buffers,
+        flat_shading,
+    );
+}
+
+/// A meshed chunk that keeps its octree, so seams to neighbouring chunks can be generated
+/// with `mdc_seam_generation`. `mesh_buffers` is indexed and must not be reordered.
+pub struct MdcChunk {
+    pub bounds: GridBounds,
+    pub mesh_buffers: MeshBuffers,
+    pub(crate) tree: Box<OctreeNode>,
+    pub(crate) threshold: f32,
+    pub(crate) enforce_manifold: bool,
+    pub(crate) qef: QefSolveOptions,
+}
+
+impl MdcChunk {
+    /// Updates the chunk after the sampler changed inside `min..=max`. Only the cells overlapping
+    /// the region are re-sampled, their ancestors reclustered and their vertices solved; only the
+    /// triangles that can use one of those vertices are regenerated. Other vertices keep their
+    /// slot in `mesh_buffers`, and slots freed by the edit stay unreferenced until a later edit
+    /// reuses them.
+    pub fn remesh_region<S: Sampler + Send + Sync + 'static>(
+        &mut self,
+        min: Vec3,
+        max: Vec3,
+        sampler: &S,
+    ) {
+        let mut freed = Vec::new();
+        let mut dirty = Vec::new();
+        self.tree.rebuild_region_base(
+            self.bounds,
+            (min, max),
+            &self.qef,
+            &SingleMaterial(sampler),
+            &mut freed,
+            &mut dirty,
+        );
+        let mut stale = vec![false; self.mesh_buffers.positions.len()];
+        for &slot in freed.iter().chain(&dirty) {
+            stale[slot as usize] = true;
+        }
+        let mut indices = std::mem::take(&mut self.mesh_buffers.indices);
+        retain_triangles(&mut indices, |triangle| {
+            !triangle.iter().any(|i| stale[*i as usize])
+        });
+
+        self.tree.update_vertex_buffer(
+            self.bounds,
+            (min, max),
+            &mut self.mesh_buffers,
+            &self.qef,
+            &mut freed,
+            &mut dirty,
+        );
+        let mut is_dirty = vec![false; self.mesh_buffers.positions.len()];
+        for slot in dirty {
+            is_dirty[slot as usize] = true;
+        }
+        let (threshold, enforce_manifold) = (self.threshold, self.enforce_manifold);
+        let mut new_indices = Vec::new();
+        self.tree.process_cell(
+            &mut new_indices,
+            &mut Vec::new(),
+            threshold,
+            enforce_manifold,
+            &|_, index| index,
+            &|node| node.reaches_dirty(&is_dirty, threshold, enforce_manifold),
+        );
+        // Quads next to a dirty vertex can still hold a triangle without one, which was kept.
+        retain_triangles(&mut new_indices, |triangle| {
+            triangle.iter().any(|i| is_dirty[*i as usize])
+        });
+        indices.append(&mut new_indices);
+        self.mesh_buffers.indices = indices;
+    }
+}
+
+fn retain_triangles(indices: &mut Vec<u32>, keep: impl Fn(&[u32]) -> bool) {
+    let mut kept = 0;
+    for t in 0..indices.len() / 3 {
+        if keep(&indices[t * 3..t * 3 + 3]) {
+            indices.copy_within(t * 3..t * 3 + 3, kept * 3);
+            kept += 1;
+        }
+    }
+    indices.truncate(kept * 3);
+}
+
+pub fn mdc_chunk_generation<S: Sampler + Send + Sync + 'static>(
+    threshold: f32,
+    bounds: GridBounds,
+    enforce_manifold: bool,
+    sampler: &S,
+) -> MdcChunk {
+    mdc_chunk_generation_with_options(
+        threshold,
+        bounds,
+        enforce_manifold,
+        QefSolveOptions::default(),
+        sampler,
+    )
+}
+
+/// `mdc_chunk_generation` with an explicit QEF solver backend, kept for later edits.
+pub fn mdc_chunk_generation_with_options<S: Sampler + Send + Sync + 'static>(
+    threshold: f32,
+    bounds: GridBounds,
+    enforce_manifold: bool,
+    qef: QefSolveOptions,
+    sampler: &S,
+) -> MdcChunk {
+    let mut mesh_buffers = MeshBuffers::new();
+    let (tree, _) = build_octree(bounds, &mut mesh_buffers, &qef, &SingleMaterial(sampler));
+    calculate_indexes(&tree, threshold, enforce_manifold, &mut mesh_buffers, false);
+    MdcChunk {
+        bounds,
+        mesh_buffers,
+        tree,
+        threshold,
+        enforce_manifold,
+        qef,
+    }
+}
+
+fn build_octree<S: CornerSampler + Send + Sync>(
+    bounds: GridBounds,
+    mesh_buffers: &mut MeshBuffers,
+    qef: &QefSolveOptions,
+    sampler: &S,
+) -> (Box<OctreeNode>, usize) {
+    let mut tree = Box::new(OctreeNode::new());
+    tree.construct_base(bounds, mesh_buffers, qef, Arc::new(sampler));
+    tree.cluster_cell_base(qef);
+    let clamped = tree.generate_vertex_buffer(mesh_buffers, qef);
+    (tree, clamped)
 }
 
 pub(crate) fn calculate_indexes(
     tree: &Box<OctreeNode>,
     threshold: f32,
+    enforce_manifold: bool,
     mesh_buffers: &mut MeshBuffers,
     flat_shading: bool,
 ) {
     mesh_buffers.indices.clear();
-    let enforce_manifold = ENFORCE_MANIFOLD.load(Ordering::Relaxed);
     let mut tri_count = Vec::new();
     tree.process_cell(
         &mut mesh_buffers.indices,
         &mut tri_count,
         threshold,
         enforce_manifold,
+        &|_, index| index,
+        &|_| true,
     );
-    if !flat_shading {
-        if mesh_buffers.indices.is_empty() {
-            return;
-        }
-    } else {
-        let mut new_positions = Vec::with_capacity(mesh_buffers.indices.len());
-        let mut new_normals = Vec::with_capacity(mesh_buffers.indices.len());
-        let mut new_colors = Vec::with_capacity(mesh_buffers.indices.len());
-        let mut t_index = 0;
-        let mut i = 0;
-        while i < mesh_buffers.indices.len() {
-            let count = tri_count[t_index];
-            t_index += 1;
-            let idx0 = (mesh_buffers.indices[i + 0] & 0x0FFFFFFF) as usize;
-            let idx1 = (mesh_buffers.indices[i + 1] & 0x0FFFFFFF) as usize;
-            let idx2 = (mesh_buffers.indices[i + 2] & 0x0FFFFFFF) as usize;
-            new_positions.push(mesh_buffers.positions[idx0]);
-            new_positions.push(mesh_buffers.positions[idx1]);
-            new_positions.push(mesh_buffers.positions[idx2]);
-            if count == 1 {
-                let n = get_normal_q(&[idx2, idx0, idx1], &mesh_buffers.positions);
-                let nc = n * 0.5 + Vec3::ONE * 0.5;
-                let c = [nc.x, nc.y, nc.z, 1.0];
-                let normal = [n.x, n.y, n.z];
-                new_normals.push(normal);
-                new_colors.push(c);
-                new_normals.push(normal);
-                new_colors.push(c);
-                new_normals.push(normal);
-                new_colors.push(c);
-                i += 3;
-            } else {
-                let idx3 = (mesh_buffers.indices[i + 3] & 0x0FFFFFFF) as usize;
-                let idx4 = (mesh_buffers.indices[i + 4] & 0x0FFFFFFF) as usize;
-                let idx5 = (mesh_buffers.indices[i + 5] & 0x0FFFFFFF) as usize;
-                let n = get_normal_q(
-                    &[idx2, idx0, idx1, idx5, idx3, idx4],
-                    &mesh_buffers.positions,
-                );
-                let nc = n * 0.5 + Vec3::ONE * 0.5;
-                let c = [nc.x, nc.y, nc.z, 1.0];
-                let normal = [n.x, n.y, n.z];
-                new_normals.push(normal);
-                new_colors.push(c);
-                new_normals.push(normal);
-                new_colors.push(c);
-                new_normals.push(normal);
-                new_colors.push(c);
-                new_positions.push(mesh_buffers.positions[idx3]);
-                new_normals.push(normal);
-                new_colors.push(c);
-                new_positions.push(mesh_buffers.positions[idx4]);
-                new_normals.push(normal);
-                new_colors.push(c);
-                new_positions.push(mesh_buffers.positions[idx5]);
-                new_normals.push(normal);
-                new_colors.push(c);
-                i += 6;
-            }
+    if flat_shading {
+        flat_shade(mesh_buffers, &tri_count);
+    }
+}
+
+/// Splits indexed triangles into unshared vertices with one face normal per quad; `tri_count`
+/// holds the number of triangles (1 or 2) each quad emitted.
+pub(crate) fn flat_shade(mesh_buffers: &mut MeshBuffers, tri_count: &[i32]) {
+    let mut new_positions = Vec::with_capacity(mesh_buffers.indices.len());
+    let mut new_normals = Vec::with_capacity(mesh_buffers.indices.len());
+    let mut new_colors = Vec::with_capacity(mesh_buffers.indices.len());
+    let mut new_materials = Vec::new();
+    let mut t_index = 0;
+    let mut i = 0;
+    while i < mesh_buffers.indices.len() {
+        let count = tri_count[t_index];
+        t_index += 1;
+        let idx0 = (mesh_buffers.indices[i + 0] & 0x0FFFFFFF) as usize;
+        let idx1 = (mesh_buffers.indices[i + 1] & 0x0FFFFFFF) as usize;
+        let idx2 = (mesh_buffers.indices[i + 2] & 0x0FFFFFFF) as usize;
+        let corners = if count == 1 { 3 } else { 6 };
+        if !mesh_buffers.materials.is_empty() {
+            new_materials.extend(
+                mesh_buffers.indices[i..i + corners]
+                    .iter()
+                    .map(|index| mesh_buffers.materials[(index & 0x0FFFFFFF) as usize]),
+            );
+        }
+        new_positions.push(mesh_buffers.positions[idx0]);
+        new_positions.push(mesh_buffers.positions[idx1]);
+        new_positions.push(mesh_buffers.positions[idx2]);
+        if count == 1 {
+            let n = get_normal_q(&[idx2, idx0, idx1], &mesh_buffers.positions);
+            let nc = n * 0.5 + Vec3::ONE * 0.5;
+            let c = [nc.x, nc.y, nc.z, 1.0];
+            let normal = [n.x, n.y, n.z];
+            new_normals.push(normal);
+            new_colors.push(c);
+            new_normals.push(normal);
+            new_colors.push(c);
+            new_normals.push(normal);
+            new_colors.push(c);
+            i += 3;
+        } else {
+            let idx3 = (mesh_buffers.indices[i + 3] & 0x0FFFFFFF) as usize;
+            let idx4 = (mesh_buffers.indices[i + 4] & 0x0FFFFFFF) as usize;
+            let idx5 = (mesh_buffers.indices[i + 5] & 0x0FFFFFFF) as usize;
+            let n = get_normal_q(
+                &[idx2, idx0, idx1, idx5, idx3, idx4],
+                &mesh_buffers.positions,
+            );
+            let nc = n * 0.5 + Vec3::ONE * 0.5;
+            let c = [nc.x, nc.y, nc.z, 1.0];
+            let normal = [n.x, n.y, n.z];
+            new_normals.push(normal);
+            new_colors.push(c);
+            new_normals.push(normal);
+            new_colors.push(c);
+            new_normals.push(normal);
+            new_colors.push(c);
+            new_positions.push(mesh_buffers.positions[idx3]);
+            new_normals.push(normal);
+            new_colors.push(c);
+            new_positions.push(mesh_buffers.positions[idx4]);
+            new_normals.push(normal);
+            new_colors.push(c);
+            new_positions.push(mesh_buffers.positions[idx5]);
+            new_normals.push(normal);
+            new_colors.push(c);
+            i += 6;
         }
-        let vertex_count = new_positions.len();
-        mesh_buffers.positions = new_positions;
-        mesh_buffers.normals = new_normals;
-        mesh_buffers.colors = new_colors;
-        mesh_buffers.indices = (0..vertex_count as u32).collect();
     }
+    let vertex_count = new_positions.len();
+    mesh_buffers.positions = new_positions;
+    mesh_buffers.normals = new_normals;
+    mesh_buffers.colors = new_colors;
+    mesh_buffers.materials = new_materials;
+    mesh_buffers.indices = (0..vertex_count as u32).collect();
 }
 
 fn get_normal_q(indexes: &[usize], vertices_buffer: &[[f32; 3]]) -> Vec3 {
@@ -162,12 +459,28 @@ fn get_normal_q(indexes: &[usize], vertices_buffer: &[[f32; 3]]) -> Vec3 {
 
 #[cfg(test)]
 mod tests {
-    use glam::Vec3;
+    use std::collections::HashSet;
+
+    use glam::{DVec3, IVec3, Vec3};
 
-    use crate::manifold_dual_contouring::{
-        mdc::{MeshBuffers, mdc_mesh_generation},
-        sampler::SphereSampler,
-        test_data::{EXPECTED_COLORS, EXPECTED_INDICES, EXPECTED_NORMALS, EXPECTED_POSITIONS},
+    use crate::{
+        manifold_dual_contouring::{
+            mdc::{
+                GridBounds, MeshBuffers, build_octree, mdc_chunk_generation,
+                mdc_material_mesh_generation, mdc_mesh_generation,
+                mdc_mesh_generation_with_options,
+            },
+            octree::{NodeType, OctreeNode},
+            sampler::{
+                FunBlobSampler, MaterialSampler, OriginSampler, Sampler, Sampler64, SingleMaterial,
+                SphereSampler,
+            },
+        },
+        qef::solver::{QefSolveOptions, QefSolverKind},
+        validate::{
+            golden::{GoldenTolerance, MeshSnapshot, assert_golden},
+            mesh::{assert_mdc_is_manifold, validate_mesh},
+        },
     };
 
     #[test]
@@ -180,30 +493,279 @@ mod tests {
             0.5,
             &mut mesh_buffers,
             false,
-            resolution,
-            bounding_width,
+            GridBounds::centered(bounding_width, resolution),
             true,
             &sphere,
         );
-        // Test positions
-        assert_eq!(mesh_buffers.positions.len(), EXPECTED_POSITIONS.len());
-        for (i, expected_pos) in EXPECTED_POSITIONS.iter().enumerate() {
-            assert_eq!(mesh_buffers.positions[i], *expected_pos);
-        }
-        // Test normals
-        assert_eq!(mesh_buffers.normals.len(), EXPECTED_NORMALS.len());
-        for (i, expected_normal) in EXPECTED_NORMALS.iter().enumerate() {
-            assert_eq!(mesh_buffers.normals[i], *expected_normal);
-        }
-        // Test colors
-        assert_eq!(mesh_buffers.colors.len(), EXPECTED_COLORS.len());
-        for (i, expected_color) in EXPECTED_COLORS.iter().enumerate() {
-            assert_eq!(mesh_buffers.colors[i], *expected_color);
-        }
-        // Test indices
-        assert_eq!(mesh_buffers.indices.len(), EXPECTED_INDICES.len());
-        for (i, expected_idx) in EXPECTED_INDICES.iter().enumerate() {
-            assert_eq!(mesh_buffers.indices[i], *expected_idx);
+        let snapshot = MeshSnapshot::new(&mesh_buffers.positions, &mesh_buffers.indices)
+            .with_attribute("normals", &mesh_buffers.normals)
+            .with_attribute("colors", &mesh_buffers.colors);
+        assert_golden(
+            "mdc_sphere",
+            &snapshot,
+            &GoldenTolerance::for_voxel_size(bounding_width / resolution as f32),
+        );
+    }
+
+    /// Counts the vertices of `node`'s subtree lying outside the cell of the node owning them.
+    fn vertices_outside_cell(node: &OctreeNode, positions: &[[f32; 3]]) -> usize {
+        let min = node.position - Vec3::splat(1e-4);
+        let max = node.position + Vec3::splat(node.size as f32 * node.voxel_size + 1e-4);
+        let own = node
+            .vertices
+            .iter()
+            .map(|v| Vec3::from_array(positions[v.lock().index as usize]))
+            .filter(|p| !(p.cmpge(min).all() && p.cmple(max).all()))
+            .count();
+        if node.node_type == NodeType::Leaf {
+            return own;
+        }
+        own + node
+            .children
+            .iter()
+            .flatten()
+            .map(|child| vertices_outside_cell(child, positions))
+            .sum::<usize>()
+    }
+
+    #[test]
+    fn test_mdc_bound_to_cell() {
+        let bounds = GridBounds::centered(64.0, 32);
+        let blob = FunBlobSampler::new(Vec3::ZERO, 24.0);
+        let mut qef = QefSolveOptions::new(QefSolverKind::Double);
+        let mut mesh_buffers = MeshBuffers::new();
+        let (tree, clamped) = build_octree(bounds, &mut mesh_buffers, &qef, &SingleMaterial(&blob));
+        assert_eq!(clamped, 0);
+        let outside = vertices_outside_cell(&tree, &mesh_buffers.positions);
+        assert!(
+            outside > 0,
+            "the blob should push some minimisers out of their cell"
+        );
+
+        qef.bound_to_cell = true;
+        let mut mesh_buffers = MeshBuffers::new();
+        let (tree, clamped) = build_octree(bounds, &mut mesh_buffers, &qef, &SingleMaterial(&blob));
+        assert!(clamped > 0);
+        assert_eq!(vertices_outside_cell(&tree, &mesh_buffers.positions), 0);
+    }
+
+    struct Sphere64 {
+        center: DVec3,
+        radius: f64,
+    }
+
+    impl Sampler64 for Sphere64 {
+        fn sample(&self, point: DVec3) -> f64 {
+            point.distance(self.center) - self.radius
+        }
+    }
+
+    #[test]
+    fn test_mdc_far_from_origin() {
+        let mesh_sphere_at = |center: DVec3| {
+            let origin = center - DVec3::splat(16.0);
+            let sampler = OriginSampler::new(
+                origin,
+                Sphere64 {
+                    center,
+                    radius: 10.0,
+                },
+            );
+            let mut mesh_buffers = MeshBuffers::new();
+            mdc_mesh_generation_with_options(
+                0.0,
+                &mut mesh_buffers,
+                false,
+                GridBounds::new(Vec3::ZERO, 1.0, IVec3::splat(32)),
+                true,
+                QefSolveOptions::new(QefSolverKind::Double),
+                &sampler,
+            );
+            mesh_buffers
+        };
+        let near = mesh_sphere_at(DVec3::ZERO);
+        // 40 km out, f32 world coordinates are only good to about 4 mm.
+        let far = mesh_sphere_at(DVec3::new(40_000.0, -5_000.0, 30_000.0));
+        assert_eq!(near.positions.len(), far.positions.len());
+        assert_eq!(near.indices, far.indices);
+        for (a, b) in near.positions.iter().zip(&far.positions) {
+            assert!(Vec3::from_array(*a).distance(Vec3::from_array(*b)) < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_mdc_is_manifold() {
+        assert_mdc_is_manifold(|threshold, mesh_buffers, bounds, sampler| {
+            mdc_mesh_generation(threshold, mesh_buffers, false, bounds, true, sampler)
+        });
+    }
+
+    /// A sphere with smooth bumps of compact support, so an edit only changes samples within
+    /// `BUMP_RADIUS` of its center.
+    struct BumpySphere {
+        bumps: Vec<(Vec3, f32)>,
+    }
+
+    const BUMP_RADIUS: f32 = 5.0;
+
+    impl Sampler for BumpySphere {
+        fn sample(&self, point: Vec3) -> f32 {
+            self.bumps
+                .iter()
+                .fold(point.length() - 14.0, |value, (center, height)| {
+                    let falloff = (1.0
+                        - point.distance_squared(*center) / (BUMP_RADIUS * BUMP_RADIUS))
+                        .max(0.0);
+                    value - height * falloff * falloff
+                })
+        }
+    }
+
+    /// The triangles of the mesh by the bits of their vertices' positions and normals, rotated
+    /// and sorted so meshes with differently ordered buffers compare equal.
+    fn canonical_triangles(mesh_buffers: &MeshBuffers) -> Vec<[[u32; 6]; 3]> {
+        let key = |i: u32| {
+            let (p, n) = (
+                mesh_buffers.positions[i as usize],
+                mesh_buffers.normals[i as usize],
+            );
+            [p[0], p[1], p[2], n[0], n[1], n[2]].map(f32::to_bits)
+        };
+        let mut triangles: Vec<_> = mesh_buffers
+            .indices
+            .chunks_exact(3)
+            .map(|t| {
+                let mut triangle = [key(t[0]), key(t[1]), key(t[2])];
+                let first = (0..3).min_by_key(|i| triangle[*i]).unwrap();
+                triangle.rotate_left(first);
+                triangle
+            })
+            .collect();
+        triangles.sort_unstable();
+        triangles
+    }
+
+    #[test]
+    fn test_remesh_region_matches_fresh_chunk() {
+        let bounds = GridBounds::centered(40.0, 32);
+        for threshold in [0.0, 0.5, 5.0] {
+            let mut sampler = BumpySphere { bumps: Vec::new() };
+            let mut chunk = mdc_chunk_generation(threshold, bounds, true, &sampler);
+            let vertices = chunk.mesh_buffers.positions.len();
+            // A bump, a dent and a dent flattening the bump again.
+            for bump in [
+                (Vec3::new(0.0, 14.0, 0.0), 3.0),
+                (Vec3::new(9.0, -3.0, 10.0), -2.5),
+                (Vec3::new(1.0, 14.5, 0.5), -3.0),
+            ] {
+                sampler.bumps.push(bump);
+                // Wider than the bump by the normals' finite difference step.
+                let reach = Vec3::splat(BUMP_RADIUS + 0.1);
+                chunk.remesh_region(bump.0 - reach, bump.0 + reach, &sampler);
+                let fresh = mdc_chunk_generation(threshold, bounds, true, &sampler);
+                assert_eq!(
+                    canonical_triangles(&chunk.mesh_buffers),
+                    canonical_triangles(&fresh.mesh_buffers),
+                    "threshold {threshold}, {} bumps",
+                    sampler.bumps.len()
+                );
+            }
+            // Freed slots are reused rather than appended.
+            assert!(chunk.mesh_buffers.positions.len() < vertices + vertices / 10);
+        }
+    }
+
+    /// A sphere of radius 12, stone below y = 0.3 and grass above.
+    struct LayeredSphere;
+
+    const STONE: u8 = 1;
+    const GRASS: u8 = 2;
+
+    impl Sampler for LayeredSphere {
+        fn sample(&self, point: Vec3) -> f32 {
+            point.length() - 12.0
+        }
+    }
+
+    impl MaterialSampler for LayeredSphere {
+        fn material(&self, point: Vec3) -> u8 {
+            if point.y < 0.3 { STONE } else { GRASS }
+        }
+
+        fn sample_material(&self, point: Vec3, material: u8) -> f32 {
+            let layer = if material == STONE {
+                point.y - 0.3
+            } else {
+                0.3 - point.y
+            };
+            self.sample(point).max(layer)
+        }
+    }
+
+    #[test]
+    fn test_mdc_material_interface() {
+        for flat_shading in [false, true] {
+            let mut mesh_buffers = MeshBuffers::new();
+            mdc_material_mesh_generation(
+                0.0,
+                &mut mesh_buffers,
+                flat_shading,
+                GridBounds::centered(32.0, 32),
+                true,
+                &LayeredSphere,
+            );
+            let vertices = mesh_buffers.positions.len();
+            assert_eq!(mesh_buffers.materials.len(), vertices);
+            assert_eq!(mesh_buffers.colors.len(), vertices);
+            assert_eq!(mesh_buffers.normals.len(), vertices);
+            let position = |i: u32| Vec3::from_array(mesh_buffers.positions[i as usize]);
+
+            // The border is the flat, downward-facing part of the mesh at y = 0.3.
+            let (border, outer): (Vec<&[u32]>, Vec<&[u32]>) =
+                mesh_buffers.indices.chunks_exact(3).partition(|t| {
+                    let [a, b, c] = [position(t[0]), position(t[1]), position(t[2])];
+                    let normal = (b - a).cross(c - a).normalize();
+                    ((a + b + c).y / 3.0 - 0.3).abs() < 0.3 && normal.y < -0.7
+                });
+            assert!(!border.is_empty());
+            for &i in border.iter().copied().flatten() {
+                let p = position(i);
+                if p.length() < 10.0 {
+                    assert!((p.y - 0.3).abs() < 0.05, "{p}");
+                    // Faces out of grass, the higher material, with a single vertex.
+                    assert_eq!(mesh_buffers.materials[i as usize], GRASS);
+                    assert!(mesh_buffers.normals[i as usize][1] < -0.95, "{p}");
+                }
+            }
+            // Away from the border the outer surface has the material it encloses. It points away
+            // from the centre right up to the vertices shared with the border.
+            let on_border: HashSet<u32> = border.iter().copied().flatten().copied().collect();
+            for &i in outer.iter().copied().flatten() {
+                let p = position(i);
+                let normal = Vec3::from_array(mesh_buffers.normals[i as usize]);
+                if !flat_shading && !on_border.contains(&i) {
+                    assert!(normal.dot(p.normalize()) > 0.9, "{p}: {normal}");
+                }
+                if (p.y - 0.3).abs() > 1.0 {
+                    let material = if p.y < 0.3 { STONE } else { GRASS };
+                    assert_eq!(mesh_buffers.materials[i as usize], material, "{p}");
+                }
+            }
+            if flat_shading {
+                continue;
+            }
+            // A single sheet between the materials: a disk whose rim joins the outer surface,
+            // which is closed on its own.
+            let report = validate_mesh(&mesh_buffers.positions, &border.concat(), false);
+            assert!(report.is_manifold(), "{report:?}");
+            assert_eq!(report.euler_characteristic, 1);
+            let rim = report.boundary_edges;
+            let report = validate_mesh(&mesh_buffers.positions, &outer.concat(), false);
+            assert!(report.is_watertight(), "{report:?}");
+            assert_eq!(report.euler_characteristic, 2);
+            let report = validate_mesh(&mesh_buffers.positions, &mesh_buffers.indices, false);
+            assert_eq!(report.boundary_edges, 0);
+            assert_eq!(report.non_manifold_edges, rim);
         }
     }
 }
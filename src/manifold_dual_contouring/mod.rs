@@ -4,7 +4,7 @@ pub mod mdc;
 pub(crate) mod octree;
 pub(crate) mod qef_solver;
 pub mod sampler;
+pub mod seam;
 pub(crate) mod smat3;
 pub(crate) mod svd;
 pub(crate) mod tables;
-pub(crate) mod test_data;
@@ -7,10 +7,12 @@ use rayon::iter::ParallelIterator;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
-use std::sync::atomic::AtomicBool;
 
+use crate::manifold_dual_contouring::mdc::GridBounds;
 use crate::manifold_dual_contouring::mdc::MeshBuffers;
 use crate::manifold_dual_contouring::qef_solver::QEFSolver;
+use crate::manifold_dual_contouring::sampler::CornerSampler;
+use crate::manifold_dual_contouring::sampler::MaterialField;
 use crate::manifold_dual_contouring::sampler::Sampler;
 use crate::manifold_dual_contouring::sampler::get_intersection;
 use crate::manifold_dual_contouring::sampler::get_normal;
@@ -26,6 +28,7 @@ use crate::manifold_dual_contouring::tables::T_INTERNAL_EDGES;
 use crate::manifold_dual_contouring::tables::T_PROCESS_EDGE_MASK;
 use crate::manifold_dual_contouring::tables::TRANSFORMED_EDGES_TABLE;
 use crate::manifold_dual_contouring::tables::TRANSFORMED_VERTICES_NUMBER_TABLE;
+use crate::qef::solver::QefSolveOptions;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NodeType {
@@ -34,6 +37,28 @@ pub enum NodeType {
     Leaf,
 }
 
+/// Corner labels on either side of a vertex's surface: 0 outside the solid, `1 + material`
+/// inside it. Only vertices of the same interface are clustered together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Interface {
+    /// The surface between two labels, facing out of `inside`, the higher one.
+    Between { outside: u16, inside: u16 },
+    /// A leaf vertex where three or more labels meet; `inside` is the highest.
+    Junction { inside: u16 },
+}
+
+impl Interface {
+    /// Material of the mesh vertex: that of the side the surface faces out of.
+    #[inline]
+    pub(crate) fn material(&self) -> u8 {
+        match *self {
+            Interface::Between { inside, .. } | Interface::Junction { inside } => {
+                (inside - 1) as u8
+            }
+        }
+    }
+}
+
 pub(crate) struct Vertex {
     pub(crate) parent: Option<Arc<Mutex<Vertex>>>,
     pub(crate) index: i32,
@@ -45,6 +70,7 @@ pub(crate) struct Vertex {
     pub(crate) eis: Option<[i32; 12]>,
     pub(crate) in_cell: i32,
     pub(crate) face_prop2: bool,
+    pub(crate) interface: Interface,
 }
 
 impl Clone for Vertex {
@@ -60,6 +86,7 @@ impl Clone for Vertex {
             eis: self.eis,
             in_cell: self.in_cell,
             face_prop2: self.face_prop2,
+            interface: self.interface,
         }
     }
 }
@@ -77,6 +104,10 @@ impl Vertex {
             eis: None,
             in_cell: 0,
             face_prop2: false,
+            interface: Interface::Between {
+                outside: 0,
+                inside: 1,
+            },
         }
     }
 }
@@ -101,11 +132,11 @@ pub(crate) struct OctreeNode {
     pub(crate) node_type: NodeType,
     pub(crate) vertices: Vec<Arc<Mutex<Vertex>>>,
     pub(crate) corners: u8,
+    /// Material of a leaf's solid corners.
+    pub(crate) materials: [u8; 8],
     pub(crate) child_index: i32,
 }
 
-pub static ENFORCE_MANIFOLD: AtomicBool = AtomicBool::new(false);
-
 impl OctreeNode {
     pub(crate) fn new() -> Self {
         OctreeNode {
@@ -117,6 +148,7 @@ impl OctreeNode {
             node_type: NodeType::None,
             vertices: Vec::new(),
             corners: 0,
+            materials: [0; 8],
             child_index: 0,
         }
     }
@@ -136,71 +168,278 @@ impl OctreeNode {
             node_type,
             vertices: Vec::new(),
             corners: 0,
+            materials: [0; 8],
             child_index: 0,
         }
     }
 
-    pub(crate) fn construct_base<S: Sampler + Send + Sync + 'static>(
+    /// Corner labels of a leaf: 0 outside the solid, `1 + material` inside it.
+    pub(crate) fn labels(&self) -> [u16; 8] {
+        std::array::from_fn(|i| {
+            if (self.corners >> i) & 1 == 0 {
+                0
+            } else {
+                1 + self.materials[i] as u16
+            }
+        })
+    }
+
+    /// Writes the material of every vertex with a buffer slot to `materials`.
+    pub(crate) fn vertex_materials(&self, materials: &mut [u8]) {
+        for child in self.children.iter().flatten() {
+            child.vertex_materials(materials);
+        }
+        for v_arc in &self.vertices {
+            let v = v_arc.lock();
+            if v.index >= 0 {
+                materials[v.index as usize] = v.interface.material();
+            }
+        }
+    }
+
+    pub(crate) fn construct_base<S: CornerSampler + Send + Sync>(
         &mut self,
-        resolution: i32,
-        bounding_width: f32,
+        bounds: GridBounds,
         mesh_buffers: &mut MeshBuffers,
+        qef: &QefSolveOptions,
         sampler: Arc<&S>,
     ) {
         self.index = 0;
-        self.position = Vec3::splat(-bounding_width * 0.5);
-        let cell_size = bounding_width / resolution as f32;
-        self.size = resolution;
-        self.voxel_size = cell_size;
+        self.position = bounds.min;
+        self.size = bounds.octree_size();
+        self.voxel_size = bounds.cell_size;
         self.node_type = NodeType::Internal;
         self.children = [None, None, None, None, None, None, None, None];
         self.vertices = Vec::new();
         self.child_index = 0;
         let mut n_index = 1;
-        self.construct_nodes(mesh_buffers, &mut n_index, 4, &sampler);
+        // The octree is padded up to a power of two; cells past `limit` are never built.
+        let limit = bounds.max() - Vec3::splat(bounds.cell_size * 0.5);
+        self.construct_nodes(mesh_buffers, &mut n_index, 4, limit, qef, &sampler);
+    }
+
+    /// Re-samples the leaves overlapping `min..=max` and reclusters their ancestors, leaving the
+    /// rest of the tree as it was. The buffer slots of the replaced vertices are pushed to
+    /// `freed`, those of untouched vertices that got a new parent to `reparented`; the new
+    /// vertices have no slot until `update_vertex_buffer`.
+    pub(crate) fn rebuild_region_base<S: CornerSampler>(
+        &mut self,
+        bounds: GridBounds,
+        (min, max): (Vec3, Vec3),
+        qef: &QefSolveOptions,
+        sampler: &S,
+        freed: &mut Vec<u32>,
+        reparented: &mut Vec<u32>,
+    ) {
+        let limit = bounds.max() - Vec3::splat(bounds.cell_size * 0.5);
+        self.rebuild_children((min, max, limit), qef, sampler, freed, reparented);
+    }
+
+    fn rebuild_region<S: CornerSampler>(
+        &mut self,
+        region: (Vec3, Vec3, Vec3),
+        qef: &QefSolveOptions,
+        sampler: &S,
+        freed: &mut Vec<u32>,
+        reparented: &mut Vec<u32>,
+    ) -> bool {
+        freed.extend(
+            self.vertices
+                .drain(..)
+                .map(|v| v.lock().index)
+                .filter(|index| *index >= 0)
+                .map(|index| index as u32),
+        );
+        if self.size == 1 {
+            let mut index = self.index;
+            return self.construct_leaf(&mut index, qef, sampler);
+        }
+        self.rebuild_children(region, qef, sampler, freed, reparented);
+        // Untouched children keep their own clusters but join this node's afresh.
+        for child in self.children.iter().flatten() {
+            for v_arc in &child.vertices {
+                let mut v = v_arc.lock();
+                v.parent = None;
+                if v.index >= 0 {
+                    reparented.push(v.index as u32);
+                }
+            }
+        }
+        self.cluster_children(qef);
+        self.children.iter().any(Option::is_some)
+    }
+
+    /// Whether the child cell at `position` is built and overlaps `min..=max`.
+    fn in_region(&self, position: Vec3, (min, max, limit): (Vec3, Vec3, Vec3)) -> bool {
+        let child_extent = (self.size / 2) as f32 * self.voxel_size;
+        position.cmplt(limit).all()
+            && position.cmple(max).all()
+            && (position + Vec3::splat(child_extent)).cmpge(min).all()
+    }
+
+    fn rebuild_children<S: CornerSampler>(
+        &mut self,
+        region: (Vec3, Vec3, Vec3),
+        qef: &QefSolveOptions,
+        sampler: &S,
+        freed: &mut Vec<u32>,
+        reparented: &mut Vec<u32>,
+    ) {
+        let child_size = self.size / 2;
+        let child_extent = child_size as f32 * self.voxel_size;
+        for (i, delta) in T_CORNER_DELTAS.iter().enumerate() {
+            let position = self.position + *delta * child_extent;
+            if !self.in_region(position, region) {
+                continue;
+            }
+            // A missing child was empty, so only the part inside the region can gain a surface.
+            let mut child = self.children[i].take().unwrap_or_else(|| {
+                let mut child = Box::new(OctreeNode::with_params(
+                    position,
+                    child_size,
+                    self.voxel_size,
+                    NodeType::Internal,
+                ));
+                child.child_index = i as i32;
+                child
+            });
+            if child.rebuild_region(region, qef, sampler, freed, reparented) {
+                self.children[i] = Some(child);
+            }
+        }
     }
 
-    pub(crate) fn generate_vertex_buffer(&self, mesh_buffers: &mut MeshBuffers) {
+    /// Appends the vertices of the subtree in post-order and returns how many of them had to be
+    /// moved back into their cell.
+    pub(crate) fn generate_vertex_buffer(
+        &self,
+        mesh_buffers: &mut MeshBuffers,
+        qef: &QefSolveOptions,
+    ) -> usize {
+        let mut clamped = 0;
         if self.node_type != NodeType::Leaf {
             for child_opt in &self.children {
                 if let Some(child) = child_opt {
-                    child.generate_vertex_buffer(mesh_buffers);
+                    clamped += child.generate_vertex_buffer(mesh_buffers, qef);
                 }
             }
         }
-        if self.vertices.is_empty() {
-            return;
+        for v_arc in &self.vertices {
+            let mut vertex = v_arc.lock();
+            vertex.index = mesh_buffers.positions.len() as i32;
+            mesh_buffers.positions.push([0.0; 3]);
+            mesh_buffers.normals.push([0.0; 3]);
+            mesh_buffers.colors.push([0.0; 4]);
+            if self.write_vertex(&mut vertex, mesh_buffers, qef) {
+                clamped += 1;
+            }
         }
-        for i in 0..self.vertices.len() {
-            let mut vertex_lock = self.vertices[i].lock();
-            vertex_lock.index = mesh_buffers.positions.len() as i32;
-            let position = vertex_lock.qef.solve(1e-6, 4, 1e-6);
-            let nc = vertex_lock.normal * 0.5 + Vec3::ONE * 0.5;
-            let normal = [
-                vertex_lock.normal.x,
-                vertex_lock.normal.y,
-                vertex_lock.normal.z,
-            ];
-            drop(vertex_lock);
-            let nc_normalized = nc.normalize();
-            let color = [nc_normalized.x, nc_normalized.y, nc_normalized.z, 1.0];
-            mesh_buffers
-                .positions
-                .push([position.x, position.y, position.z]);
-            mesh_buffers.normals.push(normal);
-            mesh_buffers.colors.push(color);
+        clamped
+    }
+
+    /// Gives the vertices `rebuild_region_base` created a slot in `mesh_buffers`, reusing the
+    /// `freed` ones first, and pushes their slots to `dirty`.
+    pub(crate) fn update_vertex_buffer(
+        &self,
+        bounds: GridBounds,
+        (min, max): (Vec3, Vec3),
+        mesh_buffers: &mut MeshBuffers,
+        qef: &QefSolveOptions,
+        freed: &mut Vec<u32>,
+        dirty: &mut Vec<u32>,
+    ) {
+        let limit = bounds.max() - Vec3::splat(bounds.cell_size * 0.5);
+        self.update_region_vertices((min, max, limit), mesh_buffers, qef, freed, dirty);
+    }
+
+    fn update_region_vertices(
+        &self,
+        region: (Vec3, Vec3, Vec3),
+        mesh_buffers: &mut MeshBuffers,
+        qef: &QefSolveOptions,
+        freed: &mut Vec<u32>,
+        dirty: &mut Vec<u32>,
+    ) {
+        if self.node_type != NodeType::Leaf {
+            for child in self.children.iter().flatten() {
+                if self.in_region(child.position, region) {
+                    child.update_region_vertices(region, mesh_buffers, qef, freed, dirty);
+                }
+            }
+        }
+        for v_arc in &self.vertices {
+            let mut vertex = v_arc.lock();
+            if vertex.index >= 0 {
+                continue;
+            }
+            let slot = freed.pop().unwrap_or_else(|| {
+                mesh_buffers.positions.push([0.0; 3]);
+                mesh_buffers.normals.push([0.0; 3]);
+                mesh_buffers.colors.push([0.0; 4]);
+                mesh_buffers.positions.len() as u32 - 1
+            });
+            vertex.index = slot as i32;
+            self.write_vertex(&mut vertex, mesh_buffers, qef);
+            dirty.push(slot);
         }
     }
 
-    fn construct_nodes<S: Sampler + Send + Sync + 'static>(
+    /// Solves `vertex` inside this cell and writes it to its slot; returns whether it had to be
+    /// moved back into the cell.
+    fn write_vertex(
+        &self,
+        vertex: &mut Vertex,
+        mesh_buffers: &mut MeshBuffers,
+        qef: &QefSolveOptions,
+    ) -> bool {
+        let extent = self.size as f32 * self.voxel_size;
+        let clamped = vertex.qef.solve(qef, self.position, extent);
+        let slot = vertex.index as usize;
+        let nc = (vertex.normal * 0.5 + Vec3::ONE * 0.5).normalize();
+        mesh_buffers.positions[slot] = vertex.qef.x.to_array();
+        mesh_buffers.normals[slot] = vertex.normal.to_array();
+        mesh_buffers.colors[slot] = [nc.x, nc.y, nc.z, 1.0];
+        clamped
+    }
+
+    /// Whether a leaf of this subtree may emit one of the `dirty` vertex slots: one of this
+    /// node's vertices is dirty or has an accepted chain of ancestors leading to a dirty one.
+    pub(crate) fn reaches_dirty(
+        &self,
+        dirty: &[bool],
+        threshold: f32,
+        enforce_manifold: bool,
+    ) -> bool {
+        self.vertices.iter().any(|v_arc| {
+            let mut vertex_arc = Arc::clone(v_arc);
+            loop {
+                let v = vertex_arc.lock();
+                if dirty[v.index as usize] {
+                    return true;
+                }
+                let Some(parent) = v.parent.clone() else {
+                    return false;
+                };
+                drop(v);
+                if !accepts_parent(&parent.lock(), threshold, enforce_manifold) {
+                    return false;
+                }
+                vertex_arc = parent;
+            }
+        })
+    }
+
+    fn construct_nodes<S: CornerSampler + Send + Sync>(
         &mut self,
         mesh_buffers: &mut MeshBuffers,
         n_index: &mut i32,
         threaded: i32,
+        limit: Vec3,
+        qef: &QefSolveOptions,
         sampler: &Arc<&S>,
     ) -> bool {
         if self.size == 1 {
-            return self.construct_leaf(n_index, sampler);
+            return self.construct_leaf(n_index, qef, **sampler);
         }
         self.node_type = NodeType::Internal;
         let child_size = self.size / 2;
@@ -209,6 +448,12 @@ impl OctreeNode {
         if threaded > 0 && self.size > 2 {
             let results: Vec<_> = (0..8)
                 .into_par_iter()
+                .filter(|&i| {
+                    let child_pos = T_CORNER_DELTAS[i];
+                    (self.position + child_pos * (child_size as f32 * child_cell_size))
+                        .cmplt(limit)
+                        .all()
+                })
                 .map_init(
                     || Arc::clone(sampler),
                     |sampler_clone, i| {
@@ -226,6 +471,8 @@ impl OctreeNode {
                             &mut temp_buffers,
                             &mut temp_index,
                             threaded - 1,
+                            limit,
+                            qef,
                             &sampler_clone,
                         );
                         (i, result, child)
@@ -243,14 +490,18 @@ impl OctreeNode {
                 self.index = *n_index;
                 *n_index += 1;
                 let child_pos = T_CORNER_DELTAS[i];
+                let position = self.position + child_pos * (child_size as f32 * child_cell_size);
+                if !position.cmplt(limit).all() {
+                    continue;
+                }
                 let mut child = Box::new(OctreeNode::with_params(
-                    self.position + child_pos * (child_size as f32 * child_cell_size),
+                    position,
                     child_size,
                     child_cell_size,
                     NodeType::Internal,
                 ));
                 child.child_index = i as i32;
-                if child.construct_nodes(mesh_buffers, n_index, 0, sampler) {
+                if child.construct_nodes(mesh_buffers, n_index, 0, limit, qef, sampler) {
                     self.children[i] = Some(child);
                     has_children = true;
                 }
@@ -259,7 +510,12 @@ impl OctreeNode {
         has_children
     }
 
-    fn construct_leaf<S: Sampler>(&mut self, index: &mut i32, sampler: &Arc<&S>) -> bool {
+    fn construct_leaf<S: CornerSampler>(
+        &mut self,
+        index: &mut i32,
+        qef: &QefSolveOptions,
+        sampler: &S,
+    ) -> bool {
         if self.size != 1 {
             return false;
         }
@@ -273,31 +529,54 @@ impl OctreeNode {
             samples[i] = sampler.sample(world_pos);
             if samples[i] < 0.0 {
                 corners |= 1 << i;
+                self.materials[i] = sampler.corner_material(world_pos);
             }
         }
         self.corners = corners;
-        if corners == 0 || corners == 255 {
+        let labels = self.labels();
+        if labels.iter().all(|label| *label == labels[0]) {
             return false;
         }
-        let v_count = TRANSFORMED_VERTICES_NUMBER_TABLE[corners as usize];
-        let mut v_edges = vec![[-1; 13]; v_count as usize];
-        self.vertices = Vec::with_capacity(v_count as usize);
+        let (lowest, highest) = (*labels.iter().min().unwrap(), *labels.iter().max().unwrap());
+        let mut v_edges;
         let mut v_index = 0;
-        let mut e_index = 0;
-        for e in 0..16 {
-            let code = TRANSFORMED_EDGES_TABLE[corners as usize][e];
-            if code == -2 {
-                v_index += 1;
-                break;
-            }
-            if code == -1 {
-                v_index += 1;
-                e_index = 0;
-                continue;
+        let interface = match edge_config(&labels) {
+            Some(config) => {
+                let v_count = TRANSFORMED_VERTICES_NUMBER_TABLE[config as usize];
+                v_edges = vec![[-1; 13]; v_count as usize];
+                let mut e_index = 0;
+                for e in 0..16 {
+                    let code = TRANSFORMED_EDGES_TABLE[config as usize][e];
+                    if code == -2 {
+                        v_index += 1;
+                        break;
+                    }
+                    if code == -1 {
+                        v_index += 1;
+                        e_index = 0;
+                        continue;
+                    }
+                    v_edges[v_index][e_index] = code;
+                    e_index += 1;
+                }
+                Interface::Between {
+                    outside: lowest,
+                    inside: highest,
+                }
             }
-            v_edges[v_index][e_index] = code;
-            e_index += 1;
-        }
+            None => {
+                v_edges = vec![[-1; 13]; 1];
+                let crossed = (0..12).filter(|&edge| {
+                    labels[T_EDGE_PAIRS[edge][0] as usize] != labels[T_EDGE_PAIRS[edge][1] as usize]
+                });
+                for (e_index, edge) in crossed.enumerate() {
+                    v_edges[0][e_index] = edge as i32;
+                }
+                v_index = 1;
+                Interface::Junction { inside: highest }
+            }
+        };
+        self.vertices = Vec::with_capacity(v_index);
         for i in 0..v_index {
             let mut k = 0;
             let mut vertex = Vertex::new();
@@ -310,13 +589,27 @@ impl OctreeNode {
                     + T_CORNER_DELTAS[T_EDGE_PAIRS[edge][0] as usize] * self.voxel_size;
                 let b = self.position
                     + T_CORNER_DELTAS[T_EDGE_PAIRS[edge][1] as usize] * self.voxel_size;
-                let intersection = get_intersection(
-                    a,
-                    b,
-                    samples[T_EDGE_PAIRS[edge][0] as usize],
-                    samples[T_EDGE_PAIRS[edge][1] as usize],
+                let (c1, c2) = (
+                    T_EDGE_PAIRS[edge][0] as usize,
+                    T_EDGE_PAIRS[edge][1] as usize,
                 );
-                let n = get_normal(intersection, sampler);
+                let (intersection, n) = if labels[c1].min(labels[c2]) == 0 {
+                    let intersection = get_intersection(a, b, samples[c1], samples[c2]);
+                    (intersection, get_normal(intersection, sampler))
+                } else {
+                    // Between two materials, on the border of the higher one.
+                    let field = MaterialField {
+                        sampler,
+                        material: (labels[c1].max(labels[c2]) - 1) as u8,
+                    };
+                    let (da, db) = (field.sample(a), field.sample(b));
+                    let intersection = if (da < 0.0) != (db < 0.0) {
+                        get_intersection(a, b, da, db)
+                    } else {
+                        (a + b) * 0.5
+                    };
+                    (intersection, get_normal(intersection, &field))
+                };
                 normal += n;
                 vertex.qef.add(intersection, n);
                 k += 1;
@@ -330,24 +623,38 @@ impl OctreeNode {
             vertex.eis = Some(ei);
             vertex.in_cell = self.child_index;
             vertex.face_prop2 = true;
-            vertex.qef.solve(1e-6, 4, 1e-6);
+            vertex.interface = interface;
+            vertex.qef.solve(qef, self.position, self.voxel_size);
             vertex.error = vertex.qef.get_error();
             self.vertices.push(Arc::new(Mutex::new(vertex)));
         }
         true
     }
 
-    pub(crate) fn process_cell(
+    /// `map_index` turns a node's vertex index into the index written to `indexes`. Only the
+    /// children, faces and edges with at least one node passing `visit` are processed.
+    pub(crate) fn process_cell<F: Fn(&OctreeNode, i32) -> i32, V: Fn(&OctreeNode) -> bool>(
         &self,
         indexes: &mut Vec<u32>,
         tri_count: &mut Vec<i32>,
         threshold: f32,
         enforce_manifold: bool,
+        map_index: &F,
+        visit: &V,
     ) {
         if self.node_type == NodeType::Internal {
             for i in 0..8 {
-                if let Some(ref child) = self.children[i] {
-                    child.process_cell(indexes, tri_count, threshold, enforce_manifold);
+                if let Some(ref child) = self.children[i]
+                    && visit(child)
+                {
+                    child.process_cell(
+                        indexes,
+                        tri_count,
+                        threshold,
+                        enforce_manifold,
+                        map_index,
+                        visit,
+                    );
                 }
             }
             for i in 0..12 {
@@ -363,6 +670,8 @@ impl OctreeNode {
                     tri_count,
                     threshold,
                     enforce_manifold,
+                    map_index,
+                    visit,
                 );
             }
             for i in 0..6 {
@@ -387,35 +696,46 @@ impl OctreeNode {
                     tri_count,
                     threshold,
                     enforce_manifold,
+                    map_index,
+                    visit,
                 );
             }
         }
     }
 
-    pub(crate) fn cluster_cell_base(&mut self, error: f32) {
+    pub(crate) fn cluster_cell_base(&mut self, qef: &QefSolveOptions) {
         if self.node_type != NodeType::Internal {
             return;
         }
         for i in 0..8 {
             if let Some(ref mut child) = self.children[i] {
-                child.cluster_cell(error);
+                child.cluster_cell(qef);
             }
         }
     }
 
-    fn cluster_cell(&mut self, error: f32) {
+    fn cluster_cell(&mut self, qef: &QefSolveOptions) {
         if self.node_type != NodeType::Internal {
             return;
         }
+        for i in 0..8 {
+            if let Some(ref mut child) = self.children[i] {
+                child.cluster_cell(qef);
+            }
+        }
+        self.cluster_children(qef);
+    }
+
+    /// Clusters the top-level vertices of already clustered children into this node's vertices.
+    fn cluster_children(&mut self, options: &QefSolveOptions) {
         let mut signs = [-1i32; 8];
         let mut mid_sign = -1i32;
         for i in 0..8 {
-            if let Some(ref mut child) = self.children[i] {
-                child.cluster_cell(error);
-                if child.node_type != NodeType::Internal {
-                    mid_sign = ((child.corners >> (7 - i)) & 1) as i32;
-                    signs[i] = ((child.corners >> i) & 1) as i32;
-                }
+            if let Some(ref child) = self.children[i]
+                && child.node_type != NodeType::Internal
+            {
+                mid_sign = ((child.corners >> (7 - i)) & 1) as i32;
+                signs[i] = ((child.corners >> i) & 1) as i32;
             }
         }
         self.corners = 0;
@@ -491,9 +811,11 @@ impl OctreeNode {
             let mut edges = [0i32; 12];
             let mut euler = 0;
             let mut e = 0;
+            let mut interface = None;
             for v_arc in &collected_vertices {
                 let v = v_arc.lock();
                 if v.surface_index == i {
+                    interface = Some(v.interface);
                     if let Some(ref eis) = v.eis {
                         for k in 0..3 {
                             let edge = T_EXTERNAL_EDGES[v.in_cell as usize][k];
@@ -513,7 +835,8 @@ impl OctreeNode {
             if count == 0 {
                 continue;
             }
-            let mut face_prop2 = true;
+            // A coarse edge crossed twice hides a feature the clustered vertex cannot keep.
+            let mut face_prop2 = edges.iter().all(|e| *e <= 1);
             for f in 0..6 {
                 let mut intersections = 0;
                 for ei in 0..4 {
@@ -533,7 +856,10 @@ impl OctreeNode {
             new_vertex.euler = euler - e / 4;
             new_vertex.in_cell = self.child_index;
             new_vertex.face_prop2 = face_prop2;
-            new_vertex.qef.solve(1e-6, 4, 1e-6);
+            new_vertex.interface = interface.unwrap();
+            new_vertex
+                .qef
+                .solve(options, self.position, self.size as f32 * self.voxel_size);
             new_vertex.error = new_vertex.qef.get_error();
             let new_vertex_arc = Arc::new(Mutex::new(new_vertex));
             for v_arc in &collected_vertices {
@@ -671,30 +997,19 @@ fn cluster_indexes(
     let mut v_count = 0;
     for i in 0..4 {
         if let Some(node) = nodes[i] {
-            let edge = T_PROCESS_EDGE_MASK[direction as usize][i];
-            let c1 = T_EDGE_PAIRS[edge as usize][0];
-            let c2 = T_EDGE_PAIRS[edge as usize][1];
-            let m1 = (node.corners >> c1) & 1;
-            let m2 = (node.corners >> c2) & 1;
-            let mut index = 0;
-            let mut skip = false;
-            for k in 0..16 {
-                let e = TRANSFORMED_EDGES_TABLE[node.corners as usize][k];
-                if e == -1 {
-                    index += 1;
-                    continue;
-                }
-                if e == -2 {
-                    if !((m1 == 0 && m2 != 0) || (m1 != 0 && m2 == 0)) {
-                        skip = true;
-                    }
-                    break;
-                }
-                if e == edge as i32 {
-                    break;
-                }
-            }
-            if !skip && index < node.vertices.len() {
+            let edge = T_PROCESS_EDGE_MASK[direction as usize][i] as usize;
+            let labels = node.labels();
+            let (l1, l2) = (
+                labels[T_EDGE_PAIRS[edge][0] as usize],
+                labels[T_EDGE_PAIRS[edge][1] as usize],
+            );
+            let interface = Interface::Between {
+                outside: l1.min(l2),
+                inside: l1.max(l2),
+            };
+            if let Some(index) = edge_group(&labels, edge)
+                && index < node.vertices.len()
+            {
                 let mut vertex_arc = Arc::clone(&node.vertices[index]);
                 loop {
                     let v = vertex_arc.lock();
@@ -707,6 +1022,10 @@ fn cluster_indexes(
                         None => break,
                     }
                 }
+                // Junction vertices and other interfaces meeting this edge stay apart.
+                if vertex_arc.lock().interface != interface {
+                    continue;
+                }
                 vertices[i] = Some(vertex_arc);
                 v_count += 1;
             }
@@ -755,49 +1074,79 @@ fn assign_surface(vertices: &mut Vec<Arc<Mutex<Vertex>>>, from: i32, to: i32) {
     }
 }
 
-fn process_indexes(
+/// Table configuration grouping a leaf's edges into vertices: the corners with the higher of
+/// its two labels. Leaves with three or more labels have none; all their edges share a vertex.
+fn edge_config(labels: &[u16; 8]) -> Option<u8> {
+    let lowest = *labels.iter().min().unwrap();
+    let highest = *labels.iter().max().unwrap();
+    let mut config = 0;
+    for (i, label) in labels.iter().enumerate() {
+        if *label == highest {
+            config |= 1 << i;
+        } else if *label != lowest {
+            return None;
+        }
+    }
+    Some(config)
+}
+
+/// Which of a leaf's vertices lies on `edge`, or `None` if the edge is not crossed.
+fn edge_group(labels: &[u16; 8], edge: usize) -> Option<usize> {
+    let Some(config) = edge_config(labels) else {
+        let crossed =
+            labels[T_EDGE_PAIRS[edge][0] as usize] != labels[T_EDGE_PAIRS[edge][1] as usize];
+        return crossed.then_some(0);
+    };
+    let mut index = 0;
+    for &e in &TRANSFORMED_EDGES_TABLE[config as usize] {
+        if e == -1 {
+            index += 1;
+            continue;
+        }
+        if e == -2 {
+            return None;
+        }
+        if e == edge as i32 {
+            break;
+        }
+    }
+    Some(index)
+}
+
+/// Whether a leaf's vertex may be replaced by its clustered `parent` at this `threshold`.
+fn accepts_parent(parent: &Vertex, threshold: f32, enforce_manifold: bool) -> bool {
+    parent.error <= threshold && (!enforce_manifold || (parent.euler == 1 && parent.face_prop2))
+}
+
+fn process_indexes<F: Fn(&OctreeNode, i32) -> i32>(
     nodes: &[Option<&OctreeNode>; 4],
     direction: i32,
     indexes: &mut Vec<u32>,
     tri_count: &mut Vec<i32>,
     threshold: f32,
     enforce_manifold: bool,
+    map_index: &F,
 ) {
-    let mut min_size = 10000000;
+    // Compared in world units so nodes from octrees of different depths order correctly.
+    let mut min_size = f32::MAX;
     let mut indices = [-1i32; 4];
     let mut flip = false;
     let mut sign_changed = false;
     for i in 0..4 {
         if let Some(node) = nodes[i] {
-            let edge = T_PROCESS_EDGE_MASK[direction as usize][i];
-            let c1 = T_EDGE_PAIRS[edge as usize][0];
-            let c2 = T_EDGE_PAIRS[edge as usize][1];
-            let m1 = (node.corners >> c1) & 1;
-            let m2 = (node.corners >> c2) & 1;
-            if node.size < min_size {
-                min_size = node.size;
-                flip = m1 == 1;
-                sign_changed = (m1 == 0 && m2 != 0) || (m1 != 0 && m2 == 0);
-            }
-            let mut index = 0;
-            let mut skip = false;
-            for k in 0..16 {
-                let e = TRANSFORMED_EDGES_TABLE[node.corners as usize][k];
-                if e == -1 {
-                    index += 1;
-                    continue;
-                }
-                if e == -2 {
-                    skip = true;
-                    break;
-                }
-                if e == edge as i32 {
-                    break;
-                }
+            let edge = T_PROCESS_EDGE_MASK[direction as usize][i] as usize;
+            let labels = node.labels();
+            let l1 = labels[T_EDGE_PAIRS[edge][0] as usize];
+            let l2 = labels[T_EDGE_PAIRS[edge][1] as usize];
+            let size = node.size as f32 * node.voxel_size;
+            if size < min_size {
+                min_size = size;
+                flip = l1 > l2;
+                sign_changed = l1 != l2;
             }
-            if skip {
+            let Some(index) = edge_group(&labels, edge) else {
                 continue;
-            }
+            };
             if index >= node.vertices.len() {
                 return;
             }
@@ -807,9 +1156,7 @@ fn process_indexes(
             loop {
                 if let Some(parent_arc) = highest_parent.take() {
                     let parent = parent_arc.lock();
-                    if parent.error <= threshold
-                        && (!enforce_manifold || (parent.euler == 1 && parent.face_prop2))
-                    {
+                    if accepts_parent(&parent, threshold, enforce_manifold) {
                         highest_index = parent.index;
                         highest_parent = parent.parent.clone();
                     } else {
@@ -819,7 +1166,7 @@ fn process_indexes(
                     break;
                 }
             }
-            indices[i] = highest_index;
+            indices[i] = map_index(node, highest_index);
         }
     }
     if sign_changed {
@@ -827,9 +1174,10 @@ fn process_indexes(
         if !flip {
             if indices[0] != -1
                 && indices[1] != -1
-                && indices[2] != -1
+                && indices[3] != -1
                 && indices[0] != indices[1]
                 && indices[1] != indices[3]
+                && indices[0] != indices[3]
             {
                 indexes.push((indices[0] & 0x0FFFFFFF) as u32);
                 indexes.push((indices[1] & 0x0FFFFFFF) as u32);
@@ -841,6 +1189,7 @@ fn process_indexes(
                 && indices[3] != -1
                 && indices[0] != indices[2]
                 && indices[2] != indices[3]
+                && indices[0] != indices[3]
             {
                 indexes.push((indices[0] & 0x0FFFFFFF) as u32);
                 indexes.push((indices[3] & 0x0FFFFFFF) as u32);
@@ -853,6 +1202,7 @@ fn process_indexes(
                 && indices[1] != -1
                 && indices[0] != indices[1]
                 && indices[1] != indices[3]
+                && indices[0] != indices[3]
             {
                 indexes.push((indices[0] & 0x0FFFFFFF) as u32);
                 indexes.push((indices[3] & 0x0FFFFFFF) as u32);
@@ -864,6 +1214,7 @@ fn process_indexes(
                 && indices[3] != -1
                 && indices[0] != indices[2]
                 && indices[2] != indices[3]
+                && indices[0] != indices[3]
             {
                 indexes.push((indices[0] & 0x0FFFFFFF) as u32);
                 indexes.push((indices[2] & 0x0FFFFFFF) as u32);
@@ -877,17 +1228,23 @@ fn process_indexes(
     }
 }
 
-fn process_edge(
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn process_edge<F: Fn(&OctreeNode, i32) -> i32, V: Fn(&OctreeNode) -> bool>(
     nodes: &[Option<&OctreeNode>; 4],
     direction: i32,
     indexes: &mut Vec<u32>,
     tri_count: &mut Vec<i32>,
     threshold: f32,
     enforce_manifold: bool,
+    map_index: &F,
+    visit: &V,
 ) {
     if nodes[0].is_none() || nodes[1].is_none() || nodes[2].is_none() || nodes[3].is_none() {
         return;
     }
+    if !nodes.iter().flatten().any(|node| visit(node)) {
+        return;
+    }
     if nodes[0].unwrap().node_type == NodeType::Leaf
         && nodes[1].unwrap().node_type == NodeType::Leaf
         && nodes[2].unwrap().node_type == NodeType::Leaf
@@ -900,6 +1257,7 @@ fn process_edge(
             tri_count,
             threshold,
             enforce_manifold,
+            map_index,
         );
     } else {
         for i in 0..2 {
@@ -921,22 +1279,30 @@ fn process_edge(
                 tri_count,
                 threshold,
                 enforce_manifold,
+                map_index,
+                visit,
             );
         }
     }
 }
 
-fn process_face(
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn process_face<F: Fn(&OctreeNode, i32) -> i32, V: Fn(&OctreeNode) -> bool>(
     nodes: &[Option<&OctreeNode>; 2],
     direction: i32,
     indexes: &mut Vec<u32>,
     tri_count: &mut Vec<i32>,
     threshold: f32,
     enforce_manifold: bool,
+    map_index: &F,
+    visit: &V,
 ) {
     if nodes[0].is_none() || nodes[1].is_none() {
         return;
     }
+    if !nodes.iter().flatten().any(|node| visit(node)) {
+        return;
+    }
     let node0 = nodes[0].unwrap();
     let node1 = nodes[1].unwrap();
     if node0.node_type != NodeType::Leaf || node1.node_type != NodeType::Leaf {
@@ -959,6 +1325,8 @@ fn process_face(
                 tri_count,
                 threshold,
                 enforce_manifold,
+                map_index,
+                visit,
             );
         }
         let orders = [[0, 0, 1, 1], [0, 1, 0, 1]];
@@ -982,6 +1350,8 @@ fn process_face(
                 tri_count,
                 threshold,
                 enforce_manifold,
+                map_index,
+                visit,
             );
         }
     }
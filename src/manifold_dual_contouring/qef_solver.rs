@@ -2,16 +2,19 @@
 
 use glam::Vec3;
 
-use crate::manifold_dual_contouring::{smat3::SMat3, svd::solve_symmetric};
+use crate::{manifold_dual_contouring::smat3::SMat3, qef::solver::QefSolveOptions};
+
+/// Weight per intersection point of the mass point spring used to retry out-of-cell solves.
+const MASS_POINT_BIAS: f32 = 0.1;
 
 #[derive(Clone)]
 pub(crate) struct QEFSolver {
-    ata: SMat3,
-    atb: Vec3,
-    btb: f32,
-    num_points: i32,
-    x: Vec3,
-    mass_point: Vec3,
+    pub(crate) ata: SMat3,
+    pub(crate) atb: Vec3,
+    pub(crate) btb: f32,
+    pub(crate) num_points: i32,
+    pub(crate) x: Vec3,
+    pub(crate) mass_point: Vec3,
 }
 
 impl QEFSolver {
@@ -59,23 +62,32 @@ impl QEFSolver {
         last_error
     }
 
-    pub(crate) fn solve(&mut self, svd_tol: f32, svd_sweeps: i32, pinv_tol: f32) -> Vec3 {
-        let mass_point_2 = self.mass_point / self.num_points as f32;
-        let tmpv = self.ata.vmul(mass_point_2);
-        let atb_2 = self.atb - tmpv;
-        let result = solve_symmetric(
-            &self.ata,
-            &atb_2,
-            &mut self.x,
-            svd_tol,
-            svd_sweeps,
-            pinv_tol,
-        );
-        self.x = if result.is_nan() {
-            mass_point_2
+    /// Solves the QEF. With `options.bound_to_cell`, a minimiser outside the cell
+    /// `min..=min + extent` is solved again biased towards the mass point, and clamped to the
+    /// cell if it still leaves it. Returns whether the vertex had to be moved.
+    pub(crate) fn solve(&mut self, options: &QefSolveOptions, min: Vec3, extent: f32) -> bool {
+        let mass_point = self.mass_point / self.num_points as f32;
+        let mut ata = self.ata.to_upper();
+        self.x = options.solve(&ata, self.atb, mass_point);
+        let max = min + Vec3::splat(extent);
+        let inside = |x: Vec3| x.is_finite() && x.cmpge(min).all() && x.cmple(max).all();
+        if !options.bound_to_cell || inside(self.x) {
+            return false;
+        }
+        // Near-degenerate normals leave tiny singular values that fling the vertex away;
+        // a spring towards the mass point damps exactly those directions.
+        let bias = MASS_POINT_BIAS * self.num_points as f32;
+        for i in [0, 3, 5] {
+            ata[i] += bias;
+        }
+        let biased = options.solve(&ata, self.atb + bias * mass_point, mass_point);
+        self.x = if inside(biased) {
+            biased
+        } else if biased.is_finite() {
+            biased.clamp(min, max)
         } else {
-            self.x + mass_point_2
+            mass_point
         };
-        self.x
+        true
     }
 }
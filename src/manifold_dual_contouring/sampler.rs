@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use glam::Vec3;
+use glam::{DVec3, Vec3};
 
 #[allow(dead_code)]
 pub(crate) fn read_data_from_file() {
@@ -92,6 +92,124 @@ pub trait Sampler {
     fn sample(&self, point: Vec3) -> f32;
 }
 
+/// A sampler whose solid is made of several materials, for `mdc_material_mesh_generation`.
+pub trait MaterialSampler: Sampler {
+    /// Material of the solid at `point`; only asked where `sample` is negative.
+    fn material(&self, point: Vec3) -> u8;
+
+    /// Signed distance to the part of the solid made of `material`. Borders between two
+    /// materials are placed on its zero set and their normals taken from its gradient, so it
+    /// must be continuous across them.
+    fn sample_material(&self, point: Vec3, material: u8) -> f32;
+}
+
+/// What the MDC octree samples at cell corners: the solid's distance, the material of solid
+/// corners and, on edges between two materials, the distance to one of them.
+pub(crate) trait CornerSampler: Sampler {
+    fn corner_material(&self, point: Vec3) -> u8;
+
+    fn material_distance(&self, point: Vec3, material: u8) -> f32;
+}
+
+/// A plain sampler's solid, all of material 0.
+pub(crate) struct SingleMaterial<'a, S>(pub(crate) &'a S);
+
+impl<S: Sampler> Sampler for SingleMaterial<'_, S> {
+    #[inline]
+    fn sample(&self, point: Vec3) -> f32 {
+        self.0.sample(point)
+    }
+}
+
+impl<S: Sampler> CornerSampler for SingleMaterial<'_, S> {
+    #[inline]
+    fn corner_material(&self, _point: Vec3) -> u8 {
+        0
+    }
+
+    #[inline]
+    fn material_distance(&self, point: Vec3, _material: u8) -> f32 {
+        self.0.sample(point)
+    }
+}
+
+/// A `MaterialSampler`'s solid with its materials.
+pub(crate) struct MultiMaterial<'a, S>(pub(crate) &'a S);
+
+impl<S: MaterialSampler> Sampler for MultiMaterial<'_, S> {
+    #[inline]
+    fn sample(&self, point: Vec3) -> f32 {
+        self.0.sample(point)
+    }
+}
+
+impl<S: MaterialSampler> CornerSampler for MultiMaterial<'_, S> {
+    #[inline]
+    fn corner_material(&self, point: Vec3) -> u8 {
+        self.0.material(point)
+    }
+
+    #[inline]
+    fn material_distance(&self, point: Vec3, material: u8) -> f32 {
+        self.0.sample_material(point, material)
+    }
+}
+
+/// The part of a `CornerSampler`'s solid made of one material, as a sampler of its own.
+pub(crate) struct MaterialField<'a, S> {
+    pub(crate) sampler: &'a S,
+    pub(crate) material: u8,
+}
+
+impl<S: CornerSampler> Sampler for MaterialField<'_, S> {
+    #[inline]
+    fn sample(&self, point: Vec3) -> f32 {
+        self.sampler.material_distance(point, self.material)
+    }
+}
+
+/// A sampler evaluated in double precision, for worlds too large for f32 coordinates. Mesh it
+/// through an `OriginSampler`.
+pub trait Sampler64 {
+    fn sample(&self, point: DVec3) -> f64;
+}
+
+/// Presents a `Sampler64` as a `Sampler` whose points are offsets from `origin`. Meshers then
+/// only see small chunk-local coordinates, and their output is in f32 relative to `origin`, so
+/// chunks far from the world origin mesh as cleanly as those near it.
+#[derive(Clone)]
+pub struct OriginSampler<S> {
+    pub origin: DVec3,
+    pub sampler: S,
+}
+
+impl<S: Sampler64> OriginSampler<S> {
+    pub fn new(origin: DVec3, sampler: S) -> Self {
+        Self { origin, sampler }
+    }
+}
+
+impl<S: Sampler64> Sampler for OriginSampler<S> {
+    #[inline]
+    fn sample(&self, point: Vec3) -> f32 {
+        self.sampler.sample(self.origin + point.as_dvec3()) as f32
+    }
+}
+
+impl<S: Sampler64 + ?Sized> Sampler64 for Arc<S> {
+    #[inline]
+    fn sample(&self, point: DVec3) -> f64 {
+        (**self).sample(point)
+    }
+}
+
+impl<S: Sampler64 + ?Sized> Sampler64 for &S {
+    #[inline]
+    fn sample(&self, point: DVec3) -> f64 {
+        (*self).sample(point)
+    }
+}
+
 #[derive(Clone)]
 pub struct SphereSampler {
     center: Vec3,
@@ -225,19 +343,51 @@ impl Sampler for CuboidSampler {
     }
 }
 
-impl<S: Sampler> Sampler for Arc<S> {
+impl<S: Sampler + ?Sized> Sampler for Arc<S> {
     #[inline]
     fn sample(&self, point: Vec3) -> f32 {
         (**self).sample(point)
     }
 }
 
+/// Samples `sampler` on a regular grid spanning `min..=max`, x fastest, quantized like `bake_quantized`.
+pub fn bake_sampler_quantized<S: Sampler>(
+    sampler: &S,
+    min: Vec3,
+    max: Vec3,
+    resolution: (usize, usize, usize),
+) -> Vec<i16> {
+    let (res_x, res_y, res_z) = resolution;
+    let mut baked = Vec::with_capacity(res_x * res_y * res_z);
+    let step_x = (max.x - min.x) / (res_x - 1).max(1) as f32;
+    let step_y = (max.y - min.y) / (res_y - 1).max(1) as f32;
+    let step_z = (max.z - min.z) / (res_z - 1).max(1) as f32;
+    for z in 0..res_z {
+        for y in 0..res_y {
+            for x in 0..res_x {
+                let point = Vec3::new(
+                    min.x + x as f32 * step_x,
+                    min.y + y as f32 * step_y,
+                    min.z + z as f32 * step_z,
+                );
+                baked.push(quantize_f32_to_i16(sampler.sample(point)));
+            }
+        }
+    }
+    baked
+}
+
 #[inline]
 pub fn quantize_f32_to_i16(value: f32) -> i16 {
     let scale = 32767.0 / 10.0; // Map [-10, 10] to [-32767, 32767]
     (value * scale).round() as i16
 }
 
+#[inline]
+pub fn dequantize_i16_to_f32(value: i16) -> f32 {
+    value as f32 * (10.0 / 32767.0)
+}
+
 impl<S: Sampler + ?Sized> Sampler for &S {
     fn sample(&self, point: Vec3) -> f32 {
         (*self).sample(point)
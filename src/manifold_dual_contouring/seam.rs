@@ -0,0 +1,322 @@
+// Seams between independently meshed chunks: the quads around edges shared by two or more
+// chunk octrees, which neither chunk can emit on its own.
+
+use std::{collections::HashMap, fmt};
+
+use glam::{IVec3, Vec3};
+
+use crate::manifold_dual_contouring::{
+    mdc::{MdcChunk, MeshBuffers},
+    octree::{OctreeNode, process_edge, process_face},
+    tables::{T_CELL_PROC_EDGE_MASK, T_CORNER_DELTAS, T_EDGE_PAIRS},
+};
+
+// Rows of `T_EDGE_PAIRS` and `T_CELL_PROC_EDGE_MASK` that touch child 0.
+const SEAM_FACES: [usize; 3] = [0, 4, 8];
+const SEAM_EDGES: [usize; 3] = [0, 2, 4];
+
+/// Why `mdc_seam_generation` rejected a block of chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeamError {
+    /// A chunk's resolution is not the same power of two along every axis.
+    UnsupportedResolution(IVec3),
+    /// The chunks do not all span the same cube size.
+    MismatchedChunkSize,
+    /// The chunk at this index does not sit in its slot next to the others.
+    NotAdjacent(usize),
+}
+
+impl fmt::Display for SeamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeamError::UnsupportedResolution(resolution) => write!(
+                f,
+                "seam chunks need a cubic power-of-two resolution, got {resolution}"
+            ),
+            SeamError::MismatchedChunkSize => write!(f, "seam chunks need the same size"),
+            SeamError::NotAdjacent(slot) => {
+                write!(f, "seam chunk {slot} is not next to the others in its slot")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SeamError {}
+
+/// Generates the seam quads of a 2x2x2 block of chunks into `mesh_buffers`, with its own copy of
+/// the vertices it uses. Chunks are indexed like octree children (`x` in bit 2, `y` in bit 1,
+/// `z` in bit 0); missing chunks are `None`.
+///
+/// Only the faces and edges touching `chunks[0]` are processed, so calling this once per chunk
+/// with that chunk at index 0 covers every seam exactly once. Chunks must span the same
+/// power-of-two-sized cube, sit next to each other as their indices say, and may be meshed at
+/// different resolutions; otherwise nothing is generated and an error is returned.
+pub fn mdc_seam_generation(
+    chunks: [Option<&MdcChunk>; 8],
+    threshold: f32,
+    enforce_manifold: bool,
+    mesh_buffers: &mut MeshBuffers,
+) -> Result<(), SeamError> {
+    let Some((corner, chunk)) = chunks
+        .iter()
+        .enumerate()
+        .find_map(|(i, chunk)| chunk.map(|chunk| (i, chunk)))
+    else {
+        return Ok(());
+    };
+    let chunk_size = chunk.bounds.octree_size() as f32 * chunk.bounds.cell_size;
+    let mid = chunk.bounds.min + (Vec3::ONE - T_CORNER_DELTAS[corner]) * chunk_size;
+    for (slot, chunk) in chunks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, chunk)| chunk.map(|chunk| (i, chunk)))
+    {
+        let resolution = chunk.bounds.resolution;
+        if resolution != IVec3::splat(resolution.x)
+            || resolution.x <= 0
+            || !(resolution.x as u32).is_power_of_two()
+        {
+            return Err(SeamError::UnsupportedResolution(resolution));
+        }
+        if (chunk.bounds.octree_size() as f32 * chunk.bounds.cell_size - chunk_size).abs()
+            > chunk_size * 1e-4
+        {
+            return Err(SeamError::MismatchedChunkSize);
+        }
+        let expected_min = mid - (Vec3::ONE - T_CORNER_DELTAS[slot]) * chunk_size;
+        if chunk.bounds.min.distance(expected_min) > chunk_size * 1e-4 {
+            return Err(SeamError::NotAdjacent(slot));
+        }
+    }
+
+    // Vertex indices of all eight chunks are laid out one after another.
+    let mut offsets = [0i32; 9];
+    for i in 0..8 {
+        let count = chunks[i].map_or(0, |chunk| chunk.mesh_buffers.positions.len());
+        offsets[i + 1] = offsets[i] + count as i32;
+    }
+    let map_index = |node: &OctreeNode, index: i32| {
+        let half = Vec3::splat(node.voxel_size * 0.5);
+        let side = node.position.cmpge(mid - half);
+        let slot = ((side.x as usize) << 2) | ((side.y as usize) << 1) | side.z as usize;
+        offsets[slot] + index
+    };
+
+    let roots = chunks.map(|chunk| chunk.map(|chunk| chunk.tree.as_ref()));
+    let mut indexes = Vec::new();
+    let mut tri_count = Vec::new();
+    for i in SEAM_FACES {
+        let face_nodes = [
+            roots[T_EDGE_PAIRS[i][0] as usize],
+            roots[T_EDGE_PAIRS[i][1] as usize],
+        ];
+        process_face(
+            &face_nodes,
+            T_EDGE_PAIRS[i][2],
+            &mut indexes,
+            &mut tri_count,
+            threshold,
+            enforce_manifold,
+            &map_index,
+            &|_| true,
+        );
+    }
+    for i in SEAM_EDGES {
+        let edge_nodes = [
+            roots[T_CELL_PROC_EDGE_MASK[i][0] as usize],
+            roots[T_CELL_PROC_EDGE_MASK[i][1] as usize],
+            roots[T_CELL_PROC_EDGE_MASK[i][2] as usize],
+            roots[T_CELL_PROC_EDGE_MASK[i][3] as usize],
+        ];
+        process_edge(
+            &edge_nodes,
+            T_CELL_PROC_EDGE_MASK[i][4],
+            &mut indexes,
+            &mut tri_count,
+            threshold,
+            enforce_manifold,
+            &map_index,
+            &|_| true,
+        );
+    }
+
+    let mut remap = HashMap::new();
+    for index in indexes {
+        let local = *remap.entry(index).or_insert_with(|| {
+            let slot = (0..8).find(|&i| (index as i32) < offsets[i + 1]).unwrap();
+            let source = &chunks[slot].unwrap().mesh_buffers;
+            let vertex = (index as i32 - offsets[slot]) as usize;
+            mesh_buffers.positions.push(source.positions[vertex]);
+            mesh_buffers.normals.push(source.normals[vertex]);
+            mesh_buffers.colors.push(source.colors[vertex]);
+            mesh_buffers
+                .materials
+                .extend(source.materials.get(vertex).copied());
+            mesh_buffers.positions.len() as u32 - 1
+        });
+        mesh_buffers.indices.push(local);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use glam::{IVec3, Vec3};
+
+    use crate::{
+        manifold_dual_contouring::{
+            mdc::{GridBounds, MdcChunk, MeshBuffers, mdc_chunk_generation},
+            sampler::SphereSampler,
+            seam::{SeamError, mdc_seam_generation},
+        },
+        validate::mesh::validate_mesh,
+    };
+
+    const CHUNK_SIZE: f32 = 16.0;
+
+    // A 2x2x2 block of chunks around a sphere through all of them, each meshed at
+    // `resolutions[i]` cells per side.
+    fn chunk_block(resolutions: [i32; 8]) -> Vec<MdcChunk> {
+        let sphere = SphereSampler::new(Vec3::new(0.3, -0.2, 0.1), 10.0);
+        (0..8)
+            .map(|i| {
+                let corner = Vec3::new((i >> 2) as f32, ((i >> 1) & 1) as f32, (i & 1) as f32);
+                let bounds = GridBounds::new(
+                    (corner - 1.0) * CHUNK_SIZE,
+                    CHUNK_SIZE / resolutions[i] as f32,
+                    IVec3::splat(resolutions[i]),
+                );
+                mdc_chunk_generation(0.5, bounds, true, &sphere)
+            })
+            .collect()
+    }
+
+    // The seams of every chunk whose `present` bit is set, each generated with its +x/+y/+z
+    // neighbours as the chunk at index 0 would see them.
+    fn block_seams(chunks: &[MdcChunk], present: u8) -> MeshBuffers {
+        let mut seams = MeshBuffers::new();
+        for i in 0..8 {
+            let mut block = [None; 8];
+            for (j, slot) in block.iter_mut().enumerate() {
+                let k = i | j;
+                if i & j == 0 && present & (1 << k) != 0 {
+                    *slot = Some(&chunks[k]);
+                }
+            }
+            if block[0].is_none() {
+                continue;
+            }
+            mdc_seam_generation(block, 0.5, true, &mut seams).unwrap();
+        }
+        seams
+    }
+
+    // Merges the meshes into one, welding vertices at bit-identical positions.
+    fn weld<'a>(meshes: impl IntoIterator<Item = &'a MeshBuffers>) -> (Vec<[f32; 3]>, Vec<u32>) {
+        let mut positions = Vec::new();
+        let mut indices = Vec::new();
+        let mut welded = HashMap::new();
+        for mesh in meshes {
+            for &index in &mesh.indices {
+                let position = mesh.positions[index as usize];
+                let key = position.map(f32::to_bits);
+                indices.push(*welded.entry(key).or_insert_with(|| {
+                    positions.push(position);
+                    positions.len() as u32 - 1
+                }));
+            }
+        }
+        (positions, indices)
+    }
+
+    fn assert_block_watertight(resolutions: [i32; 8]) {
+        let chunks = chunk_block(resolutions);
+        let seams = block_seams(&chunks, 0xFF);
+        assert!(!seams.indices.is_empty());
+        let (positions, indices) = weld(
+            chunks
+                .iter()
+                .map(|chunk| &chunk.mesh_buffers)
+                .chain([&seams]),
+        );
+        let report = validate_mesh(&positions, &indices, false);
+        assert!(report.is_watertight(), "{resolutions:?}: {report:?}");
+        assert_eq!(report.connected_components, 1, "{resolutions:?}");
+    }
+
+    #[test]
+    fn test_seams_close_equal_resolution_chunks() {
+        assert_block_watertight([16; 8]);
+    }
+
+    // Across a resolution change the smallest node's edge can be missing from a coarser node,
+    // leaving a hole in the quad; it must not be emitted as a triangle.
+    #[test]
+    fn test_seams_close_mixed_resolution_chunks() {
+        assert_block_watertight([16, 8, 8, 16, 8, 16, 16, 8]);
+    }
+
+    #[test]
+    fn test_seams_with_missing_chunks_index_own_vertices() {
+        let chunks = chunk_block([8; 8]);
+        for present in 1..=u8::MAX {
+            let seams = block_seams(&chunks, present);
+            assert!(
+                seams
+                    .indices
+                    .iter()
+                    .all(|&i| (i as usize) < seams.positions.len()),
+                "chunks {present:08b}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_seam_rejects_unsupported_chunks() {
+        let sphere = SphereSampler::new(Vec3::ZERO, 10.0);
+        let chunk = |min: Vec3, cell_size: f32, resolution: IVec3| {
+            mdc_chunk_generation(
+                0.5,
+                GridBounds::new(min, cell_size, resolution),
+                true,
+                &sphere,
+            )
+        };
+        let mut seams = MeshBuffers::new();
+
+        let flat = chunk(Vec3::splat(-16.0), 1.0, IVec3::new(16, 16, 8));
+        let mut block = [None; 8];
+        block[0] = Some(&flat);
+        assert_eq!(
+            mdc_seam_generation(block, 0.5, true, &mut seams),
+            Err(SeamError::UnsupportedResolution(IVec3::new(16, 16, 8)))
+        );
+
+        let odd = chunk(Vec3::splat(-12.0), 1.0, IVec3::splat(12));
+        block[0] = Some(&odd);
+        assert_eq!(
+            mdc_seam_generation(block, 0.5, true, &mut seams),
+            Err(SeamError::UnsupportedResolution(IVec3::splat(12)))
+        );
+
+        let small = chunk(Vec3::splat(-16.0), 1.0, IVec3::splat(16));
+        let large = chunk(Vec3::new(0.0, -16.0, -16.0), 2.0, IVec3::splat(16));
+        block[0] = Some(&small);
+        block[4] = Some(&large);
+        assert_eq!(
+            mdc_seam_generation(block, 0.5, true, &mut seams),
+            Err(SeamError::MismatchedChunkSize)
+        );
+
+        // The +y neighbour put in the +x slot would hand its vertices to the wrong chunk.
+        let above = chunk(Vec3::new(-16.0, 0.0, -16.0), 1.0, IVec3::splat(16));
+        block[4] = Some(&above);
+        assert_eq!(
+            mdc_seam_generation(block, 0.5, true, &mut seams),
+            Err(SeamError::NotAdjacent(4))
+        );
+        assert!(seams.indices.is_empty() && seams.positions.is_empty());
+    }
+}
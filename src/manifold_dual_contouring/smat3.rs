@@ -20,6 +20,23 @@ impl SMat3 {
         m22: 0.0,
     };
 
+    #[inline]
+    pub(crate) fn from_upper(m: &[f32; 6]) -> Self {
+        Self {
+            m00: m[0],
+            m01: m[1],
+            m02: m[2],
+            m11: m[3],
+            m12: m[4],
+            m22: m[5],
+        }
+    }
+
+    #[inline]
+    pub(crate) fn to_upper(self) -> [f32; 6] {
+        [self.m00, self.m01, self.m02, self.m11, self.m12, self.m22]
+    }
+
     #[inline(always)]
     #[must_use]
     pub(crate) fn fnorm(&self) -> f32 {
@@ -230,7 +230,7 @@ pub(crate) const TRANSFORMED_EDGES_TABLE: [[i32; 16]; 256] = [
     [2, 11, 3, 9, 4, 7, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [0, 1, 11, 3, 4, 7, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1],
     [1, 8, 11, 3, 7, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1],
-    [0, 8, 11, 3, 4, 7, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [0, 8, 4, -1, 7, 11, 3, -2, -1, -1, -1, -1, -1, -1, -1, -1],
     [11, 3, 7, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [11, 3, 7, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [0, 8, 4, -1, 7, 11, 3, -2, -1, -1, -1, -1, -1, -1, -1, -1],
@@ -293,7 +293,7 @@ pub(crate) const TRANSFORMED_EDGES_TABLE: [[i32; 16]; 256] = [
     [0, 8, 11, 9, 6, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [11, 9, 4, 6, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [0, 1, 2, 11, 4, 6, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1],
-    [1, 8, 2, 11, 6, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [6, 11, 2, -1, 1, 8, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1],
     [0, 8, 2, 11, 4, 6, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [2, 11, 6, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [2, 3, 6, 7, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
@@ -323,7 +323,7 @@ pub(crate) const TRANSFORMED_EDGES_TABLE: [[i32; 16]; 256] = [
     [0, 10, 2, 9, 7, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [10, 8, 2, 9, 4, 7, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1],
     [0, 10, 1, 8, 2, 9, 7, -2, -1, -1, -1, -1, -1, -1, -1, -1],
-    [10, 1, 2, 9, 4, 7, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [10, 1, 7, -1, 4, 2, 9, -2, -1, -1, -1, -1, -1, -1, -1, -1],
     [0, 10, 4, 7, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [10, 8, 7, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [0, 10, 1, 8, 4, 7, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1],
@@ -335,7 +335,7 @@ pub(crate) const TRANSFORMED_EDGES_TABLE: [[i32; 16]; 256] = [
     [10, 1, 3, 9, 4, 6, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [0, 10, 1, 8, 3, 9, 6, -2, -1, -1, -1, -1, -1, -1, -1, -1],
     [10, 8, 3, 9, 4, 6, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1],
-    [0, 10, 3, 9, 6, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1],
+    [5, 9, 3, -1, 0, 6, 10, -2, -1, -1, -1, -1, -1, -1, -1, -1],
     [10, 1, 2, 9, 6, 5, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1],
     [10, 1, 2, 9, 6, 5, -1, 0, 8, 4, -2, -1, -1, -1, -1, -1],
     [10, 8, 2, 9, 6, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1],
@@ -366,11 +366,76 @@ pub(crate) const TRANSFORMED_EDGES_TABLE: [[i32; 16]; 256] = [
 
 pub(crate) const TRANSFORMED_VERTICES_NUMBER_TABLE: [i32; 256] = [
     0, 1, 1, 1, 1, 1, 2, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 2, 1, 3, 1, 2, 2, 2, 1, 2, 1, 2, 1,
-    1, 2, 1, 1, 2, 2, 2, 1, 2, 3, 1, 1, 2, 2, 1, 1, 1, 1, 1, 1, 2, 1, 2, 1, 2, 2, 1, 1, 2, 1, 2, 1,
+    1, 2, 1, 1, 2, 2, 2, 1, 2, 3, 1, 1, 2, 2, 1, 1, 1, 1, 1, 1, 2, 1, 2, 1, 2, 2, 1, 1, 2, 1, 1, 1,
     1, 2, 2, 2, 1, 1, 2, 1, 2, 3, 2, 2, 1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 2, 1, 2, 2, 2, 1, 1, 1, 1, 1,
-    2, 3, 2, 2, 2, 2, 2, 1, 3, 4, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 1, 1, 1, 1, 1, 1,
+    2, 3, 2, 2, 2, 2, 2, 1, 3, 4, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 1, 1, 1, 1, 2, 1,
     1, 2, 2, 2, 2, 2, 3, 2, 1, 2, 1, 1, 1, 1, 1, 1, 2, 2, 3, 2, 3, 2, 4, 2, 2, 2, 2, 1, 2, 1, 2, 1,
-    1, 2, 1, 1, 2, 2, 2, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, 2, 2, 2, 1, 1, 2, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1,
-    1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0,
+    1, 2, 1, 1, 2, 2, 2, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 2, 1, 1, 1, 1, 1, 1, 2, 1, 1,
+    1, 2, 2, 2, 1, 1, 2, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 2, 1, 1, 1, 1, 2, 1, 1, 1, 1,
+    1, 2, 1, 1, 1, 1, 1, 2, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0,
 ];
+
+#[cfg(test)]
+mod tests {
+    use crate::manifold_dual_contouring::tables::{
+        TRANSFORMED_EDGES_TABLE, TRANSFORMED_VERTICES_NUMBER_TABLE,
+    };
+
+    /// The edge groups of one vertex each, sorted so configurations can be compared.
+    fn vertex_groups(config: usize) -> Vec<Vec<i32>> {
+        let mut groups: Vec<Vec<i32>> = TRANSFORMED_EDGES_TABLE[config]
+            .split(|e| *e == -2)
+            .next()
+            .unwrap()
+            .split(|e| *e == -1)
+            .filter(|g| !g.is_empty())
+            .map(|g| {
+                let mut g = g.to_vec();
+                g.sort_unstable();
+                g
+            })
+            .collect();
+        groups.sort();
+        groups
+    }
+
+    /// Whether a face of the cell has its two inside corners on a diagonal.
+    fn has_ambiguous_face(config: usize) -> bool {
+        [4, 2, 1].iter().any(|&axis| {
+            let [a, b] = match axis {
+                4 => [2, 1],
+                2 => [4, 1],
+                _ => [4, 2],
+            };
+            [0, axis].iter().any(|&side| {
+                let sign = |corner: usize| (config >> corner) & 1;
+                let ring = [side, side | a, side | a | b, side | b].map(sign);
+                ring[0] == ring[2] && ring[1] == ring[3] && ring[0] != ring[1]
+            })
+        })
+    }
+
+    #[test]
+    fn test_vertex_counts_match_edges() {
+        for (config, count) in TRANSFORMED_VERTICES_NUMBER_TABLE.iter().enumerate() {
+            assert_eq!(
+                vertex_groups(config).len() as i32,
+                *count,
+                "config {config}"
+            );
+        }
+    }
+
+    // Without an ambiguous face the surface in a cell is unique, so flipping every corner has to
+    // give the same vertices. Neighbouring cells rely on this to agree on the shared face.
+    #[test]
+    fn test_unambiguous_configs_match_complement() {
+        for config in (0..256).filter(|c| !has_ambiguous_face(*c)) {
+            assert_eq!(
+                vertex_groups(config),
+                vertex_groups(255 - config),
+                "config {config}"
+            );
+        }
+    }
+}
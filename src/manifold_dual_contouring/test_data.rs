@@ -1,19338 +0,0 @@
-#[allow(dead_code)]
-pub const EXPECTED_POSITIONS: &[[f32; 3]] = &[
-    [-13.878777, -10.17324, -10.17324],
-    [-12.720387, -11.493118, -10.271195],
-    [-12.720387, -10.271195, -11.493118],
-    [-13.137054, -10.638885, -10.638885],
-    [-13.1187315, -10.6430645, -10.6430645],
-    [-11.493118, -12.720387, -10.271195],
-    [-10.17324, -13.878777, -10.17324],
-    [-10.271195, -12.720387, -11.493118],
-    [-10.638885, -13.137054, -10.638885],
-    [-10.6430645, -13.1187315, -10.6430645],
-    [-11.493118, -10.271195, -12.720387],
-    [-10.271195, -11.493118, -12.720387],
-    [-10.17324, -10.17324, -13.878777],
-    [-10.638885, -10.638885, -13.137054],
-    [-10.6430645, -10.6430645, -13.1187315],
-    [-11.536771, -11.536771, -11.536771],
-    [-11.931611, -11.931611, -10.687862],
-    [-11.931611, -10.687862, -11.931611],
-    [-12.370105, -11.104529, -11.104529],
-    [-10.687862, -11.931611, -11.931611],
-    [-11.104529, -12.370105, -11.104529],
-    [-11.104529, -11.104529, -12.370105],
-    [-11.521811, -11.521811, -11.521811],
-    [-11.489697, -11.489697, -11.489697],
-    [-11.489697, -11.489697, -11.489697],
-    [-15.202538, -10.295564, -7.884995],
-    [-15.202538, -10.295564, -7.884995],
-    [-16.36011, -10.17324, -5.324711],
-    [-15.101833, -11.383239, -6.478384],
-    [-15.265717, -11.597614, -5.625],
-    [-15.413081, -10.677339, -6.88703],
-    [-15.943033, -10.603945, -5.6948266],
-    [-15.611464, -10.873316, -6.0409284],
-    [-15.5581255, -10.797956, -6.281459],
-    [-15.051056, -12.560555, -3.9292688],
-    [-15.139938, -12.665969, -3.125],
-    [-15.101846, -12.620791, -3.4696867],
-    [-15.241021, -12.785843, -1.875],
-    [-15.291279, -12.845437, -0.625],
-    [-15.2661495, -12.8156395, -1.25],
-    [-16.2816, -11.294578, -2.6671536],
-    [-16.49668, -10.388045, -4.375],
-    [-16.577555, -10.706492, -3.1002922],
-    [-15.402763, -11.948149, -4.3575616],
-    [-15.832608, -11.776747, -3.1002922],
-    [-16.090162, -11.013046, -4.375],
-    [-16.201096, -11.177487, -3.500487],
-    [-16.098623, -11.213018, -3.6652985],
-    [-16.332794, -11.366785, -1.875],
-    [-16.379944, -11.43328, -0.625],
-    [-16.810333, -10.625, -1.875],
-    [-16.903103, -10.625, -0.625],
-    [-15.866021, -11.991785, -1.875],
-    [-15.916279, -12.05828, -0.625],
-    [-16.36808, -11.350021, -1.25],
-    [-15.993178, -11.594834, -2.508551],
-    [-10.295564, -15.202538, -7.884995],
-    [-10.295564, -15.202538, -7.884995],
-    [-11.383239, -15.101833, -6.478384],
-    [-11.597614, -15.265717, -5.625],
-    [-10.17324, -16.36011, -5.324711],
-    [-10.677339, -15.413083, -6.88703],
-    [-10.603945, -15.943031, -5.6948266],
-    [-10.873316, -15.611463, -6.040929],
-    [-10.797956, -15.558124, -6.281459],
-    [-12.560555, -15.051056, -3.9292688],
-    [-12.665969, -15.139938, -3.125],
-    [-12.620791, -15.101846, -3.4696867],
-    [-12.785843, -15.241021, -1.875],
-    [-12.845437, -15.291278, -0.625],
-    [-12.8156395, -15.2661495, -1.25],
-    [-11.294578, -16.2816, -2.6671536],
-    [-11.948149, -15.402763, -4.3575616],
-    [-11.776747, -15.832608, -3.1002922],
-    [-10.388045, -16.49668, -4.375],
-    [-10.706492, -16.577555, -3.1002922],
-    [-11.013045, -16.090162, -4.375],
-    [-11.177487, -16.201096, -3.500487],
-    [-11.2130165, -16.098623, -3.6652985],
-    [-11.366785, -16.332794, -1.875],
-    [-11.433281, -16.379942, -0.625],
-    [-11.991785, -15.866021, -1.875],
-    [-12.058281, -15.916278, -0.625],
-    [-10.625, -16.810335, -1.875],
-    [-10.625, -16.903103, -0.625],
-    [-11.350021, -16.368078, -1.25],
-    [-11.594833, -15.993178, -2.508551],
-    [-12.640775, -12.640775, -8.945962],
-    [-12.903498, -12.903497, -8.125],
-    [-12.790902, -12.790902, -8.476841],
-    [-13.933454, -12.700542, -6.6291614],
-    [-14.146767, -12.933707, -5.625],
-    [-12.700542, -13.933454, -6.6291614],
-    [-12.933707, -14.146767, -5.625],
-    [-13.2155485, -13.2155485, -7.045828],
-    [-13.558707, -13.558707, -5.625],
-    [-13.406334, -13.406334, -6.231247],
-    [-13.842153, -11.361135, -8.890239],
-    [-14.06489, -11.629698, -8.125],
-    [-14.121927, -10.603945, -9.334143],
-    [-14.7412815, -10.677339, -8.230997],
-    [-13.097249, -11.843793, -9.334767],
-    [-13.528498, -12.254698, -8.125],
-    [-13.553721, -11.006574, -9.723573],
-    [-13.84869, -11.336995, -8.822524],
-    [-14.422244, -11.930851, -6.9704394],
-    [-14.771767, -12.222614, -5.625],
-    [-14.911034, -11.128898, -7.3117175],
-    [-13.730554, -12.478462, -7.4624944],
-    [-14.4715805, -11.956102, -6.7903295],
-    [-11.361135, -13.842153, -8.890239],
-    [-11.629698, -14.06489, -8.125],
-    [-11.843793, -13.097248, -9.334767],
-    [-12.254698, -13.528497, -8.125],
-    [-10.603945, -14.121927, -9.334143],
-    [-10.677339, -14.7412815, -8.230997],
-    [-11.006574, -13.553721, -9.723573],
-    [-11.336995, -13.84869, -8.822524],
-    [-11.93085, -14.422244, -6.9704394],
-    [-12.222614, -14.771767, -5.625],
-    [-12.478462, -13.730554, -7.4624944],
-    [-11.128898, -14.911034, -7.3117175],
-    [-11.956101, -14.471581, -6.7903295],
-    [-12.326451, -12.326451, -9.779296],
-    [-12.326451, -12.326451, -9.779296],
-    [-12.910168, -12.910168, -7.81641],
-    [-13.842153, -13.842153, -4.048203],
-    [-13.956705, -13.956705, -3.125],
-    [-14.385528, -13.135811, -4.4054027],
-    [-14.581705, -13.290969, -3.125],
-    [-13.135811, -14.385528, -4.4054027],
-    [-13.290969, -14.581705, -3.125],
-    [-13.711067, -13.711067, -4.881536],
-    [-13.836841, -13.83684, -3.905135],
-    [-14.065951, -14.065951, -1.875],
-    [-14.120259, -14.120259, -0.625],
-    [-14.690951, -13.410843, -1.875],
-    [-14.745259, -13.470437, -0.625],
-    [-13.410843, -14.690951, -1.875],
-    [-13.470437, -14.745259, -0.625],
-    [-14.08395, -14.08395, -1.25],
-    [-14.928902, -12.413581, -4.7626023],
-    [-14.928902, -12.413581, -4.7626023],
-    [-12.413581, -14.928902, -4.7626023],
-    [-12.413581, -14.928902, -4.7626023],
-    [-13.919125, -13.919125, -2.9288278],
-    [-13.434792, -13.434792, -4.866052],
-    [-15.202538, -7.884995, -10.295564],
-    [-15.202538, -7.884995, -10.295564],
-    [-16.36011, -5.324711, -10.17324],
-    [-15.101833, -6.478384, -11.383239],
-    [-15.413081, -6.88703, -10.677339],
-    [-15.265717, -5.625, -11.597614],
-    [-15.943033, -5.6948266, -10.603945],
-    [-15.611464, -6.0409284, -10.873316],
-    [-15.5581255, -6.281459, -10.797956],
-    [-15.051056, -3.9292688, -12.560555],
-    [-15.139938, -3.125, -12.665969],
-    [-15.101846, -3.4696867, -12.620791],
-    [-16.49668, -4.375, -10.388045],
-    [-16.2816, -2.6671536, -11.294578],
-    [-16.577555, -3.1002922, -10.706492],
-    [-15.402763, -4.3575616, -11.948149],
-    [-16.090162, -4.375, -11.013046],
-    [-15.832608, -3.1002922, -11.776747],
-    [-16.201096, -3.500487, -11.177487],
-    [-16.098623, -3.6652985, -11.213018],
-    [-15.241021, -1.875, -12.785843],
-    [-15.291278, -0.625, -12.845437],
-    [-15.2661495, -1.25, -12.8156395],
-    [-16.332794, -1.875, -11.366785],
-    [-16.810333, -1.875, -10.625],
-    [-16.379942, -0.625, -11.433281],
-    [-16.903103, -0.625, -10.625],
-    [-15.866021, -1.875, -11.991785],
-    [-15.916278, -0.625, -12.05828],
-    [-16.368078, -1.25, -11.350021],
-    [-15.993178, -2.508551, -11.594834],
-    [-10.295564, -7.884995, -15.202538],
-    [-10.295564, -7.884995, -15.202538],
-    [-11.383239, -6.478384, -15.101833],
-    [-11.597614, -5.625, -15.265717],
-    [-10.677339, -6.88703, -15.413083],
-    [-10.17324, -5.324711, -16.36011],
-    [-10.603945, -5.6948266, -15.943031],
-    [-10.873316, -6.040929, -15.611463],
-    [-10.797956, -6.281459, -15.558124],
-    [-12.640775, -8.945962, -12.640775],
-    [-12.903497, -8.125, -12.903498],
-    [-12.790902, -8.476841, -12.790902],
-    [-13.842153, -8.890239, -11.361135],
-    [-14.121927, -9.334143, -10.603945],
-    [-14.06489, -8.125, -11.629698],
-    [-14.7412815, -8.230997, -10.677339],
-    [-13.097248, -9.334767, -11.843793],
-    [-13.553721, -9.723573, -11.006574],
-    [-13.528498, -8.125, -12.254698],
-    [-13.84869, -8.822524, -11.336996],
-    [-13.933454, -6.6291614, -12.700542],
-    [-14.146767, -5.625, -12.933707],
-    [-12.700542, -6.6291614, -13.933454],
-    [-13.2155485, -7.0458274, -13.2155485],
-    [-12.933707, -5.625, -14.146767],
-    [-13.558707, -5.625, -13.558707],
-    [-13.406334, -6.231247, -13.406334],
-    [-14.422244, -6.9704385, -11.93085],
-    [-14.911034, -7.3117175, -11.128898],
-    [-14.771767, -5.625, -12.222614],
-    [-13.730554, -7.4624944, -12.478462],
-    [-14.4715805, -6.790329, -11.956102],
-    [-11.361135, -8.890239, -13.842153],
-    [-11.843793, -9.334767, -13.097248],
-    [-11.629698, -8.125, -14.06489],
-    [-12.254698, -8.125, -13.528497],
-    [-10.603945, -9.334143, -14.121927],
-    [-11.006574, -9.723573, -13.553721],
-    [-10.677339, -8.230997, -14.7412815],
-    [-11.336996, -8.822524, -13.848689],
-    [-12.326451, -9.779296, -12.326451],
-    [-12.326451, -9.779296, -12.326451],
-    [-11.93085, -6.9704385, -14.422244],
-    [-12.478462, -7.4624944, -13.730554],
-    [-12.222614, -5.625, -14.771767],
-    [-11.128898, -7.3117175, -14.911034],
-    [-11.956102, -6.790329, -14.471581],
-    [-12.910169, -7.81641, -12.910168],
-    [-12.560555, -3.9292688, -15.051056],
-    [-12.665969, -3.125, -15.139938],
-    [-12.620791, -3.4696867, -15.101846],
-    [-12.785843, -1.875, -15.241021],
-    [-12.845437, -0.625, -15.291278],
-    [-12.8156395, -1.25, -15.2661495],
-    [-11.948149, -4.3575616, -15.402763],
-    [-11.294578, -2.6671536, -16.2816],
-    [-11.776747, -3.1002922, -15.832608],
-    [-10.388045, -4.375, -16.49668],
-    [-11.013045, -4.375, -16.090162],
-    [-10.706492, -3.1002922, -16.577555],
-    [-11.177487, -3.500487, -16.201096],
-    [-11.2130165, -3.6652985, -16.098623],
-    [-11.366785, -1.875, -16.332794],
-    [-11.991785, -1.875, -15.866021],
-    [-11.43328, -0.625, -16.379942],
-    [-12.058281, -0.625, -15.916278],
-    [-10.625, -1.875, -16.810335],
-    [-10.625, -0.625, -16.903103],
-    [-11.350021, -1.25, -16.368078],
-    [-11.594833, -2.508551, -15.993178],
-    [-13.842153, -4.048203, -13.842153],
-    [-14.385528, -4.4054027, -13.135811],
-    [-13.956705, -3.125, -13.956705],
-    [-14.581705, -3.125, -13.290969],
-    [-13.135811, -4.4054027, -14.385528],
-    [-13.711067, -4.881536, -13.711067],
-    [-13.290969, -3.125, -14.581705],
-    [-13.83684, -3.905135, -13.83684],
-    [-14.928902, -4.7626023, -12.413581],
-    [-14.928902, -4.7626023, -12.413581],
-    [-14.065951, -1.875, -14.065951],
-    [-14.690951, -1.875, -13.410843],
-    [-14.120259, -0.625, -14.120259],
-    [-14.745259, -0.625, -13.470437],
-    [-13.410843, -1.875, -14.690951],
-    [-13.470437, -0.625, -14.745259],
-    [-14.08395, -1.25, -14.08395],
-    [-12.413581, -4.7626023, -14.928902],
-    [-12.413581, -4.7626023, -14.928902],
-    [-13.919124, -2.9288278, -13.919125],
-    [-13.434792, -4.866052, -13.434792],
-    [-17.617271, -7.7576656, -5.3707347],
-    [-17.617271, -7.7576656, -5.3707347],
-    [-17.617271, -5.3707347, -7.7576656],
-    [-17.617271, -5.3707347, -7.7576656],
-    [-17.646322, -6.6291614, -6.6291614],
-    [-17.823565, -6.977496, -5.7224407],
-    [-17.823565, -5.7224407, -6.977496],
-    [-18.325289, -5.625, -5.625],
-    [-17.91034, -6.228657, -6.228657],
-    [-16.2816, -8.8062525, -7.564785],
-    [-16.2816, -7.564785, -8.8062525],
-    [-16.428442, -8.038871, -8.038871],
-    [-15.235847, -9.139274, -9.139274],
-    [-15.6358595, -9.38943, -8.141557],
-    [-15.6358595, -8.141557, -9.38943],
-    [-16.069181, -8.398118, -8.398118],
-    [-15.909559, -8.520881, -8.520881],
-    [-16.42622, -9.063559, -6.875],
-    [-16.642797, -9.467094, -5.6948266],
-    [-16.9031, -8.125, -6.875],
-    [-17.317713, -8.154599, -5.7224407],
-    [-16.064978, -9.688559, -6.875],
-    [-16.217432, -9.945158, -6.1580443],
-    [-16.641281, -9.018096, -6.3224187],
-    [-16.42622, -6.875, -9.063559],
-    [-16.903101, -6.875, -8.125],
-    [-16.642797, -5.6948266, -9.467094],
-    [-17.317713, -5.7224407, -8.154599],
-    [-16.064978, -6.875, -9.688559],
-    [-16.217432, -6.1580443, -9.945158],
-    [-16.641281, -6.3224187, -9.018096],
-    [-17.372486, -6.977496, -6.977496],
-    [-17.48497, -7.4624944, -6.2040677],
-    [-17.48497, -6.2040677, -7.4624944],
-    [-17.43384, -6.8988338, -6.8988338],
-    [-16.773388, -7.5378575, -7.5378575],
-    [-17.529474, -8.8062525, -3.8696263],
-    [-17.599136, -8.939161, -3.125],
-    [-17.719128, -8.154599, -4.321776],
-    [-17.980421, -8.125, -3.125],
-    [-17.718893, -8.465525, -3.63861],
-    [-17.686771, -9.106327, -1.875],
-    [-17.730347, -9.189424, -0.625],
-    [-18.153528, -8.125, -1.875],
-    [-18.239578, -8.125, -0.625],
-    [-17.952557, -8.636437, -1.25],
-    [-18.777624, -6.3264184, -2.6671536],
-    [-18.83258, -5.2785945, -4.107494],
-    [-18.906551, -5.6671567, -3.1002922],
-    [-18.239578, -6.875, -4.375],
-    [-18.529837, -6.795851, -3.1002922],
-    [-18.63184, -5.6671567, -4.464496],
-    [-18.708017, -6.1119275, -3.500487],
-    [-18.651932, -6.117916, -3.6303859],
-    [-18.822376, -6.450191, -1.875],
-    [-18.863594, -6.564165, -0.625],
-    [-19.078878, -5.625, -1.875],
-    [-19.160631, -5.625, -0.625],
-    [-18.591757, -7.075191, -1.875],
-    [-18.634232, -7.189165, -0.625],
-    [-18.858578, -6.4214516, -1.25],
-    [-17.15206, -9.2837515, -4.321776],
-    [-17.260742, -9.564161, -3.125],
-    [-17.424526, -8.590999, -4.7029595],
-    [-17.256403, -9.204034, -4.0181465],
-    [-17.35254, -9.731327, -1.875],
-    [-17.398159, -9.814424, -0.625],
-    [-17.375349, -9.772875, -1.25],
-    [-18.189257, -7.573838, -2.5777528],
-    [-17.529474, -3.8696263, -8.8062525],
-    [-17.719128, -4.321776, -8.154599],
-    [-17.599136, -3.125, -8.939161],
-    [-17.980421, -3.125, -8.125],
-    [-17.718893, -3.63861, -8.465525],
-    [-18.83258, -4.107494, -5.2785945],
-    [-18.777624, -2.6671536, -6.3264184],
-    [-18.906551, -3.1002922, -5.6671567],
-    [-18.239578, -4.375, -6.875],
-    [-18.63184, -4.464496, -5.6671567],
-    [-18.529839, -3.1002922, -6.795851],
-    [-18.708017, -3.500487, -6.1119275],
-    [-18.651934, -3.6303859, -6.117916],
-    [-17.686771, -1.875, -9.106327],
-    [-18.153526, -1.875, -8.125],
-    [-17.730347, -0.625, -9.189424],
-    [-18.239578, -0.625, -8.125],
-    [-17.952557, -1.25, -8.636437],
-    [-18.822376, -1.875, -6.450191],
-    [-19.078878, -1.875, -5.625],
-    [-18.863594, -0.625, -6.564165],
-    [-19.160633, -0.625, -5.625],
-    [-18.591757, -1.875, -7.075191],
-    [-18.634232, -0.625, -7.189165],
-    [-18.85858, -1.25, -6.4214516],
-    [-17.152058, -4.321776, -9.2837515],
-    [-17.424526, -4.7029595, -8.590999],
-    [-17.260742, -3.125, -9.564161],
-    [-17.256403, -4.0181465, -9.204033],
-    [-17.35254, -1.875, -9.731327],
-    [-17.398159, -0.625, -9.814424],
-    [-17.375349, -1.25, -9.772875],
-    [-18.189259, -2.5777528, -7.573838],
-    [-18.95577, -4.464496, -4.464496],
-    [-19.24208, -4.375, -3.125],
-    [-19.24208, -3.125, -4.375],
-    [-19.484573, -3.125, -3.125],
-    [-18.736036, -4.940827, -4.940827],
-    [-19.143095, -3.9822478, -3.9822478],
-    [-19.404055, -4.375, -1.875],
-    [-19.484573, -4.375, -0.625],
-    [-19.644651, -3.125, -1.875],
-    [-19.724205, -3.125, -0.625],
-    [-19.564371, -3.75, -1.25],
-    [-19.404055, -1.875, -4.375],
-    [-19.644653, -1.875, -3.125],
-    [-19.484573, -0.625, -4.375],
-    [-19.724205, -0.625, -3.125],
-    [-19.564371, -1.25, -3.75],
-    [-19.803429, -1.875, -1.875],
-    [-19.88232, -1.875, -0.625],
-    [-19.882318, -0.625, -1.875],
-    [-19.960873, -0.625, -0.625],
-    [-19.882235, -1.25, -1.25],
-    [-19.515257, -2.6418376, -2.6418376],
-    [-14.806005, -9.483564, -9.483564],
-    [-14.982282, -9.972607, -8.718328],
-    [-14.982282, -8.718328, -9.972607],
-    [-14.902155, -9.408239, -9.408238],
-    [-14.902155, -9.408239, -9.408238],
-    [-17.9243, -5.4766974, -5.4766974],
-    [-7.884995, -15.202538, -10.295564],
-    [-7.884995, -15.202538, -10.295564],
-    [-6.478384, -15.101833, -11.383239],
-    [-6.88703, -15.413081, -10.677339],
-    [-5.324711, -16.36011, -10.17324],
-    [-5.625, -15.265716, -11.597614],
-    [-5.6948266, -15.943033, -10.603945],
-    [-6.040929, -15.611463, -10.873316],
-    [-6.281459, -15.558124, -10.797956],
-    [-7.884995, -10.295564, -15.202538],
-    [-7.884995, -10.295564, -15.202538],
-    [-6.478384, -11.383239, -15.101833],
-    [-6.88703, -10.677339, -15.413081],
-    [-5.625, -11.597614, -15.265717],
-    [-5.324711, -10.17324, -16.36011],
-    [-5.6948266, -10.603945, -15.943033],
-    [-6.040929, -10.873316, -15.611463],
-    [-6.281459, -10.797956, -15.558124],
-    [-8.945962, -12.640775, -12.640775],
-    [-8.125, -12.903498, -12.903498],
-    [-8.476841, -12.790902, -12.790902],
-    [-8.890239, -13.842153, -11.361135],
-    [-9.334143, -14.121927, -10.603945],
-    [-9.334767, -13.097248, -11.843793],
-    [-9.723573, -13.553721, -11.006574],
-    [-8.125, -14.06489, -11.629698],
-    [-8.230997, -14.7412815, -10.677339],
-    [-8.125, -13.528497, -12.254698],
-    [-8.822525, -13.84869, -11.336995],
-    [-8.890239, -11.361135, -13.842153],
-    [-9.334767, -11.843793, -13.097248],
-    [-9.334143, -10.603945, -14.121927],
-    [-9.723573, -11.006574, -13.553721],
-    [-8.125, -11.629698, -14.06489],
-    [-8.125, -12.254698, -13.528498],
-    [-8.230997, -10.677339, -14.7412815],
-    [-8.822525, -11.336995, -13.848691],
-    [-9.779296, -12.326451, -12.326451],
-    [-9.779296, -12.326451, -12.326451],
-    [-6.6291614, -13.933454, -12.700542],
-    [-6.6291614, -12.700542, -13.933454],
-    [-7.045828, -13.2155485, -13.2155485],
-    [-5.625, -14.146767, -12.933707],
-    [-5.625, -12.933707, -14.146767],
-    [-5.625, -13.558707, -13.558707],
-    [-6.231247, -13.406334, -13.406333],
-    [-6.9704394, -14.422244, -11.93085],
-    [-7.3117175, -14.911034, -11.128898],
-    [-7.4624944, -13.730554, -12.478462],
-    [-5.625, -14.771767, -12.222614],
-    [-6.790329, -14.4715805, -11.956102],
-    [-6.9704394, -11.93085, -14.422244],
-    [-7.4624944, -12.478462, -13.730554],
-    [-7.3117175, -11.128898, -14.911034],
-    [-5.625, -12.222614, -14.771767],
-    [-6.790329, -11.956102, -14.471581],
-    [-7.816411, -12.910168, -12.910168],
-    [-3.9292688, -15.051056, -12.560555],
-    [-3.125, -15.139938, -12.665969],
-    [-3.4696867, -15.101846, -12.620791],
-    [-4.375, -16.49668, -10.388045],
-    [-4.3575616, -15.402763, -11.948149],
-    [-4.375, -16.090162, -11.013045],
-    [-2.6671536, -16.2816, -11.294578],
-    [-3.1002922, -16.577555, -10.706492],
-    [-3.1002922, -15.832608, -11.776747],
-    [-3.500487, -16.201096, -11.177487],
-    [-3.6652985, -16.098621, -11.2130165],
-    [-1.875, -15.241021, -12.785843],
-    [-0.625, -15.291278, -12.845437],
-    [-1.25, -15.2661495, -12.8156395],
-    [-1.875, -16.332794, -11.366785],
-    [-1.875, -16.810335, -10.625],
-    [-1.875, -15.866021, -11.991785],
-    [-0.625, -16.379942, -11.433281],
-    [-0.625, -16.903103, -10.625],
-    [-0.625, -15.916278, -12.058281],
-    [-1.25, -16.368078, -11.350021],
-    [-2.508551, -15.993176, -11.594833],
-    [-3.9292688, -12.560555, -15.051056],
-    [-3.125, -12.665969, -15.139938],
-    [-3.4696867, -12.620791, -15.101846],
-    [-4.3575616, -11.948149, -15.402763],
-    [-4.375, -10.388045, -16.49668],
-    [-4.375, -11.013046, -16.090162],
-    [-2.6671536, -11.294578, -16.2816],
-    [-3.1002922, -11.776747, -15.832608],
-    [-3.1002922, -10.706492, -16.577555],
-    [-3.500487, -11.177487, -16.201096],
-    [-3.6652985, -11.2130165, -16.098621],
-    [-1.875, -12.785843, -15.241021],
-    [-0.625, -12.845437, -15.291279],
-    [-1.25, -12.8156395, -15.2661495],
-    [-1.875, -11.366784, -16.332794],
-    [-1.875, -11.991785, -15.866021],
-    [-1.875, -10.625, -16.810335],
-    [-0.625, -11.433281, -16.379944],
-    [-0.625, -12.05828, -15.916279],
-    [-0.625, -10.625, -16.903103],
-    [-1.25, -11.350021, -16.36808],
-    [-2.508551, -11.594833, -15.993178],
-    [-4.048203, -13.842153, -13.842153],
-    [-4.4054027, -14.385528, -13.135811],
-    [-4.4054027, -13.135811, -14.385528],
-    [-4.881536, -13.711067, -13.711067],
-    [-3.125, -13.956705, -13.956705],
-    [-3.125, -14.581705, -13.290969],
-    [-3.125, -13.290969, -14.581705],
-    [-3.905135, -13.83684, -13.836841],
-    [-4.7626023, -14.928902, -12.413581],
-    [-4.7626023, -14.928902, -12.413581],
-    [-4.7626023, -12.413581, -14.928902],
-    [-4.7626023, -12.413581, -14.928902],
-    [-1.875, -14.065951, -14.065951],
-    [-1.875, -14.690951, -13.410843],
-    [-1.875, -13.410843, -14.690951],
-    [-0.625, -14.120259, -14.120259],
-    [-0.625, -14.745259, -13.470437],
-    [-0.625, -13.470437, -14.745259],
-    [-1.25, -14.08395, -14.08395],
-    [-2.928828, -13.919124, -13.919124],
-    [-4.866053, -13.434791, -13.434791],
-    [-7.7576656, -17.617271, -5.3707347],
-    [-7.7576656, -17.617271, -5.3707347],
-    [-8.8062525, -16.2816, -7.564785],
-    [-9.139274, -15.235847, -9.139274],
-    [-9.38943, -15.6358595, -8.141557],
-    [-7.564785, -16.2816, -8.8062525],
-    [-8.038871, -16.428442, -8.038871],
-    [-8.141557, -15.6358595, -9.38943],
-    [-8.398118, -16.069181, -8.398118],
-    [-8.520881, -15.909558, -8.520882],
-    [-9.063559, -16.42622, -6.875],
-    [-9.467094, -16.642796, -5.6948266],
-    [-9.688559, -16.064978, -6.875],
-    [-9.945158, -16.217432, -6.1580443],
-    [-8.125, -16.903101, -6.875],
-    [-8.154599, -17.317713, -5.7224407],
-    [-9.018097, -16.641281, -6.3224187],
-    [-5.3707347, -17.617271, -7.7576656],
-    [-5.3707347, -17.617271, -7.7576656],
-    [-6.6291614, -17.646322, -6.6291614],
-    [-6.977496, -17.823565, -5.7224407],
-    [-5.7224407, -17.823565, -6.977496],
-    [-5.625, -18.325289, -5.625],
-    [-6.228657, -17.91034, -6.228657],
-    [-6.875, -16.42622, -9.063559],
-    [-6.875, -16.9031, -8.125],
-    [-6.875, -16.064978, -9.688559],
-    [-5.6948266, -16.642797, -9.467094],
-    [-5.7224407, -17.317713, -8.154599],
-    [-6.1580443, -16.217432, -9.945158],
-    [-6.3224187, -16.641281, -9.018097],
-    [-6.977496, -17.372486, -6.977496],
-    [-7.4624944, -17.48497, -6.2040677],
-    [-6.2040677, -17.48497, -7.4624944],
-    [-6.8988338, -17.43384, -6.8988338],
-    [-7.5378575, -16.773388, -7.5378585],
-    [-8.8062525, -17.529474, -3.8696263],
-    [-8.939161, -17.599136, -3.125],
-    [-8.154599, -17.719128, -4.321776],
-    [-8.125, -17.98042, -3.125],
-    [-8.465525, -17.718893, -3.63861],
-    [-9.106327, -17.686771, -1.875],
-    [-9.1894245, -17.730347, -0.625],
-    [-8.125, -18.153526, -1.875],
-    [-8.125, -18.239578, -0.625],
-    [-8.636438, -17.952557, -1.25],
-    [-9.2837515, -17.15206, -4.321776],
-    [-9.564161, -17.260742, -3.125],
-    [-8.590999, -17.424526, -4.7029595],
-    [-9.204034, -17.256403, -4.0181465],
-    [-9.731327, -17.35254, -1.875],
-    [-9.8144245, -17.398159, -0.625],
-    [-9.772876, -17.375349, -1.25],
-    [-6.3264184, -18.777624, -2.6671536],
-    [-6.875, -18.239578, -4.375],
-    [-6.795851, -18.529837, -3.1002922],
-    [-5.2785945, -18.83258, -4.107494],
-    [-5.6671567, -18.906551, -3.1002922],
-    [-5.6671567, -18.63184, -4.464496],
-    [-6.1119275, -18.708017, -3.500487],
-    [-6.117916, -18.651934, -3.6303859],
-    [-6.450191, -18.822376, -1.875],
-    [-6.564165, -18.863594, -0.625],
-    [-7.075191, -18.591757, -1.875],
-    [-7.189165, -18.634232, -0.625],
-    [-5.625, -19.078878, -1.875],
-    [-5.625, -19.160633, -0.625],
-    [-6.421452, -18.858578, -1.25],
-    [-7.5738397, -18.189257, -2.5777528],
-    [-9.483564, -14.806005, -9.483564],
-    [-9.972607, -14.982282, -8.718328],
-    [-8.718328, -14.982282, -9.972607],
-    [-9.408239, -14.902155, -9.408238],
-    [-9.408239, -14.902155, -9.408238],
-    [-3.8696263, -17.529474, -8.8062525],
-    [-4.321776, -17.719128, -8.154599],
-    [-3.125, -17.599136, -8.939161],
-    [-3.125, -17.980421, -8.125],
-    [-3.63861, -17.718893, -8.465525],
-    [-4.107494, -18.83258, -5.2785945],
-    [-4.375, -18.239576, -6.875],
-    [-4.464496, -18.63184, -5.6671567],
-    [-2.6671536, -18.777624, -6.3264184],
-    [-3.1002922, -18.906551, -5.6671567],
-    [-3.1002922, -18.529837, -6.795851],
-    [-3.500487, -18.708017, -6.1119275],
-    [-3.6303864, -18.651934, -6.1179156],
-    [-4.321776, -17.15206, -9.2837515],
-    [-4.7029595, -17.424526, -8.590999],
-    [-3.125, -17.260742, -9.564161],
-    [-4.0181465, -17.256403, -9.204033],
-    [-1.875, -17.686771, -9.106327],
-    [-1.875, -18.153528, -8.125],
-    [-0.625, -17.730347, -9.1894245],
-    [-0.625, -18.239578, -8.125],
-    [-1.25, -17.952557, -8.636438],
-    [-1.875, -18.822376, -6.450191],
-    [-1.875, -19.078878, -5.625],
-    [-1.875, -18.591757, -7.075191],
-    [-0.625, -18.863594, -6.564165],
-    [-0.625, -19.160631, -5.625],
-    [-0.625, -18.634232, -7.189165],
-    [-1.25, -18.858578, -6.421452],
-    [-1.875, -17.35254, -9.731327],
-    [-0.625, -17.398159, -9.8144245],
-    [-1.25, -17.375349, -9.772876],
-    [-2.5777533, -18.189257, -7.5738397],
-    [-4.464496, -18.95577, -4.464496],
-    [-4.375, -19.24208, -3.125],
-    [-4.940827, -18.736036, -4.940827],
-    [-3.125, -19.24208, -4.375],
-    [-3.125, -19.484573, -3.125],
-    [-3.9822478, -19.143095, -3.9822478],
-    [-4.375, -19.404055, -1.875],
-    [-4.375, -19.484573, -0.625],
-    [-3.125, -19.644653, -1.875],
-    [-3.125, -19.724205, -0.625],
-    [-3.75, -19.564371, -1.25],
-    [-1.875, -19.404057, -4.375],
-    [-1.875, -19.644651, -3.125],
-    [-0.625, -19.484573, -4.375],
-    [-0.625, -19.724205, -3.125],
-    [-1.25, -19.564371, -3.75],
-    [-1.875, -19.803429, -1.875],
-    [-1.875, -19.882318, -0.625],
-    [-0.625, -19.88232, -1.875],
-    [-0.625, -19.960873, -0.625],
-    [-1.25, -19.882236, -1.25],
-    [-2.6418376, -19.515259, -2.6418376],
-    [-5.476698, -17.9243, -5.476698],
-    [-9.139274, -9.139274, -15.235847],
-    [-8.8062525, -7.564785, -16.2816],
-    [-9.38943, -8.141557, -15.6358595],
-    [-7.564785, -8.8062525, -16.2816],
-    [-8.141557, -9.38943, -15.6358595],
-    [-8.038871, -8.038871, -16.428444],
-    [-8.398118, -8.398118, -16.069181],
-    [-8.520881, -8.520881, -15.90956],
-    [-7.7576656, -5.3707347, -17.617271],
-    [-7.7576656, -5.3707347, -17.617271],
-    [-9.063559, -6.875, -16.42622],
-    [-9.688559, -6.875, -16.064978],
-    [-9.467094, -5.6948266, -16.642796],
-    [-9.945158, -6.1580443, -16.217432],
-    [-8.125, -6.875, -16.903101],
-    [-8.154599, -5.7224407, -17.317713],
-    [-9.018097, -6.3224187, -16.641281],
-    [-5.3707347, -7.7576656, -17.617271],
-    [-5.3707347, -7.7576656, -17.617271],
-    [-6.875, -9.063559, -16.42622],
-    [-6.875, -9.688559, -16.064978],
-    [-6.875, -8.125, -16.9031],
-    [-5.6948266, -9.467094, -16.642797],
-    [-6.1580443, -9.945158, -16.217432],
-    [-5.7224407, -8.154599, -17.317713],
-    [-6.3224187, -9.018096, -16.641281],
-    [-6.6291614, -6.6291614, -17.646322],
-    [-6.977496, -5.7224407, -17.823565],
-    [-5.7224407, -6.977496, -17.823565],
-    [-5.625, -5.625, -18.325289],
-    [-6.228657, -6.228657, -17.91034],
-    [-6.977496, -6.977496, -17.372486],
-    [-7.4624944, -6.2040677, -17.48497],
-    [-6.2040677, -7.4624944, -17.48497],
-    [-6.8988338, -6.8988338, -17.43384],
-    [-7.5378575, -7.537858, -16.773388],
-    [-9.483564, -9.483564, -14.806005],
-    [-9.972607, -8.718328, -14.982282],
-    [-8.718328, -9.972607, -14.982282],
-    [-9.408239, -9.408238, -14.902155],
-    [-9.408239, -9.408238, -14.902155],
-    [-8.8062525, -3.8696263, -17.529474],
-    [-8.939161, -3.125, -17.599136],
-    [-8.154599, -4.321776, -17.719128],
-    [-8.125, -3.125, -17.98042],
-    [-8.465525, -3.63861, -17.718893],
-    [-9.2837515, -4.321776, -17.152058],
-    [-9.564161, -3.125, -17.260742],
-    [-8.590999, -4.7029595, -17.424526],
-    [-9.204034, -4.0181465, -17.256403],
-    [-9.106327, -1.875, -17.686771],
-    [-9.189424, -0.625, -17.730347],
-    [-8.125, -1.875, -18.153526],
-    [-8.125, -0.625, -18.239578],
-    [-8.636437, -1.25, -17.952557],
-    [-9.731327, -1.875, -17.35254],
-    [-9.814424, -0.625, -17.398159],
-    [-9.772875, -1.25, -17.375349],
-    [-6.875, -4.375, -18.239578],
-    [-6.3264184, -2.6671536, -18.777624],
-    [-6.795851, -3.1002922, -18.529839],
-    [-5.2785945, -4.107494, -18.83258],
-    [-5.6671567, -4.464496, -18.63184],
-    [-5.6671567, -3.1002922, -18.906551],
-    [-6.1119275, -3.500487, -18.708017],
-    [-6.117916, -3.6303859, -18.651934],
-    [-6.450191, -1.875, -18.822376],
-    [-7.075191, -1.875, -18.591757],
-    [-6.564165, -0.625, -18.863594],
-    [-7.189165, -0.625, -18.634232],
-    [-5.625, -1.875, -19.078878],
-    [-5.625, -0.625, -19.160633],
-    [-6.421452, -1.25, -18.858578],
-    [-7.5738397, -2.5777528, -18.189257],
-    [-3.8696263, -8.8062525, -17.529474],
-    [-4.321776, -8.154599, -17.719128],
-    [-3.125, -8.939161, -17.599136],
-    [-3.125, -8.125, -17.980421],
-    [-3.63861, -8.465525, -17.718893],
-    [-4.321776, -9.2837515, -17.15206],
-    [-4.7029595, -8.590999, -17.424526],
-    [-3.125, -9.564161, -17.260742],
-    [-4.0181465, -9.204033, -17.256403],
-    [-4.375, -6.875, -18.239576],
-    [-4.107494, -5.2785945, -18.83258],
-    [-4.464496, -5.6671567, -18.63184],
-    [-2.6671536, -6.3264184, -18.777624],
-    [-3.1002922, -6.795851, -18.529837],
-    [-3.1002922, -5.6671567, -18.906551],
-    [-3.500487, -6.1119275, -18.708017],
-    [-3.6303864, -6.1179156, -18.651932],
-    [-1.875, -9.106327, -17.686771],
-    [-1.875, -8.125, -18.153528],
-    [-0.625, -9.189424, -17.730347],
-    [-0.625, -8.125, -18.239578],
-    [-1.25, -8.636437, -17.952557],
-    [-1.875, -9.731327, -17.35254],
-    [-0.625, -9.814424, -17.398159],
-    [-1.25, -9.772875, -17.375349],
-    [-1.875, -6.450191, -18.822376],
-    [-1.875, -7.075191, -18.591757],
-    [-1.875, -5.625, -19.078878],
-    [-0.625, -6.564165, -18.863594],
-    [-0.625, -7.189165, -18.634232],
-    [-0.625, -5.625, -19.160631],
-    [-1.25, -6.421452, -18.858578],
-    [-2.5777533, -7.5738397, -18.189257],
-    [-4.464496, -4.464496, -18.95577],
-    [-4.940827, -4.940827, -18.736036],
-    [-4.375, -3.125, -19.24208],
-    [-3.125, -4.375, -19.24208],
-    [-3.125, -3.125, -19.484573],
-    [-3.9822478, -3.9822478, -19.143095],
-    [-4.375, -1.875, -19.404055],
-    [-4.375, -0.625, -19.484573],
-    [-3.125, -1.875, -19.644653],
-    [-3.125, -0.625, -19.724205],
-    [-3.75, -1.25, -19.564371],
-    [-1.875, -4.375, -19.404057],
-    [-1.875, -3.125, -19.644651],
-    [-0.625, -4.375, -19.484573],
-    [-0.625, -3.125, -19.724205],
-    [-1.25, -3.75, -19.564371],
-    [-1.875, -1.875, -19.803429],
-    [-1.875, -0.625, -19.882318],
-    [-0.625, -1.875, -19.88232],
-    [-0.625, -0.625, -19.960873],
-    [-1.25, -1.25, -19.882236],
-    [-2.6418376, -2.6418376, -19.515259],
-    [-5.476698, -5.476698, -17.9243],
-    [-10.126742, -10.126742, -10.126742],
-    [-15.291279, -12.845437, 0.625],
-    [-15.241021, -12.785843, 1.875],
-    [-15.2661495, -12.8156395, 1.25],
-    [-15.139938, -12.665969, 3.125],
-    [-15.051056, -12.560555, 3.9292688],
-    [-15.101846, -12.620791, 3.4696867],
-    [-16.379944, -11.433281, 0.625],
-    [-16.332794, -11.366784, 1.875],
-    [-16.903103, -10.625, 0.625],
-    [-16.810335, -10.625, 1.875],
-    [-15.916279, -12.058281, 0.625],
-    [-15.866021, -11.991784, 1.875],
-    [-16.36808, -11.350021, 1.25],
-    [-16.2816, -11.294578, 2.6671536],
-    [-16.577555, -10.706492, 3.1002922],
-    [-16.49668, -10.388045, 4.375],
-    [-15.832608, -11.776747, 3.1002922],
-    [-15.402763, -11.948149, 4.3575616],
-    [-16.201096, -11.177487, 3.500487],
-    [-16.090162, -11.013046, 4.375],
-    [-16.098625, -11.213018, 3.6652985],
-    [-15.993178, -11.594834, 2.508551],
-    [-16.36011, -10.17324, 5.324711],
-    [-15.265717, -11.597614, 5.625],
-    [-15.101833, -11.383239, 6.478384],
-    [-15.943033, -10.603945, 5.6948266],
-    [-15.413081, -10.677339, 6.88703],
-    [-15.611464, -10.873316, 6.0409284],
-    [-15.202538, -10.295564, 7.884995],
-    [-15.202538, -10.295564, 7.884995],
-    [-15.5581255, -10.797956, 6.281459],
-    [-12.845437, -15.291278, 0.625],
-    [-12.785843, -15.241021, 1.875],
-    [-12.8156395, -15.2661495, 1.25],
-    [-12.665969, -15.139938, 3.125],
-    [-12.560555, -15.051056, 3.9292688],
-    [-12.620791, -15.101846, 3.4696867],
-    [-11.433281, -16.379942, 0.625],
-    [-11.366785, -16.332794, 1.875],
-    [-12.058281, -15.916278, 0.625],
-    [-11.991785, -15.866021, 1.875],
-    [-10.625, -16.903103, 0.625],
-    [-10.625, -16.810335, 1.875],
-    [-11.350021, -16.368078, 1.25],
-    [-11.294578, -16.2816, 2.6671536],
-    [-11.776747, -15.832608, 3.1002922],
-    [-11.948149, -15.402763, 4.3575616],
-    [-10.706492, -16.577555, 3.1002922],
-    [-10.388045, -16.49668, 4.375],
-    [-11.177487, -16.201096, 3.500487],
-    [-11.013045, -16.090162, 4.375],
-    [-11.2130165, -16.098625, 3.6652985],
-    [-11.594833, -15.993178, 2.508551],
-    [-11.597614, -15.265716, 5.625],
-    [-11.383239, -15.101833, 6.478384],
-    [-10.17324, -16.36011, 5.324711],
-    [-10.603945, -15.943033, 5.6948266],
-    [-10.677339, -15.413081, 6.88703],
-    [-10.873315, -15.611463, 6.040929],
-    [-10.295564, -15.202538, 7.884995],
-    [-10.295564, -15.202538, 7.884995],
-    [-10.7979555, -15.558124, 6.281459],
-    [-14.120259, -14.120259, 0.625],
-    [-14.065951, -14.065951, 1.875],
-    [-14.745259, -13.470437, 0.625],
-    [-14.690951, -13.410843, 1.875],
-    [-13.470437, -14.745259, 0.625],
-    [-13.410843, -14.690951, 1.875],
-    [-14.08395, -14.08395, 1.25],
-    [-13.956705, -13.956705, 3.125],
-    [-13.842153, -13.842153, 4.048203],
-    [-14.581705, -13.290969, 3.125],
-    [-14.385528, -13.135811, 4.4054027],
-    [-13.290969, -14.581705, 3.125],
-    [-13.135811, -14.385528, 4.4054027],
-    [-13.711067, -13.711067, 4.881536],
-    [-13.83684, -13.83684, 3.905135],
-    [-14.928902, -12.413581, 4.7626023],
-    [-14.928902, -12.413581, 4.7626023],
-    [-12.413581, -14.928902, 4.7626023],
-    [-12.413581, -14.928902, 4.7626023],
-    [-13.919125, -13.919125, 2.9288278],
-    [-14.146767, -12.933707, 5.625],
-    [-13.933454, -12.700542, 6.6291614],
-    [-12.933707, -14.146767, 5.625],
-    [-12.700542, -13.933454, 6.6291614],
-    [-13.558707, -13.558707, 5.625],
-    [-13.2155485, -13.2155485, 7.045828],
-    [-13.406333, -13.406334, 6.231247],
-    [-12.903498, -12.903498, 8.125],
-    [-12.640775, -12.640775, 8.945962],
-    [-12.790902, -12.790902, 8.476841],
-    [-14.771767, -12.222614, 5.625],
-    [-14.422244, -11.93085, 6.9704394],
-    [-14.911034, -11.128898, 7.3117175],
-    [-13.730554, -12.478462, 7.4624944],
-    [-14.4715805, -11.956102, 6.7903295],
-    [-14.06489, -11.629698, 8.125],
-    [-13.842153, -11.361135, 8.890239],
-    [-14.7412815, -10.677339, 8.230997],
-    [-14.121927, -10.603945, 9.334143],
-    [-13.528498, -12.254698, 8.125],
-    [-13.097249, -11.843793, 9.334767],
-    [-13.553721, -11.006574, 9.723573],
-    [-13.84869, -11.336995, 8.822524],
-    [-12.222614, -14.771767, 5.625],
-    [-11.93085, -14.422244, 6.9704394],
-    [-12.478462, -13.730554, 7.4624944],
-    [-11.128898, -14.911034, 7.3117175],
-    [-11.956101, -14.471581, 6.7903295],
-    [-11.629698, -14.06489, 8.125],
-    [-11.361135, -13.842153, 8.890239],
-    [-12.254698, -13.528498, 8.125],
-    [-11.843793, -13.097248, 9.334767],
-    [-10.677339, -14.7412815, 8.230997],
-    [-10.603945, -14.121927, 9.334143],
-    [-11.006574, -13.553721, 9.723573],
-    [-11.336995, -13.84869, 8.822524],
-    [-12.326451, -12.326451, 9.779296],
-    [-12.326451, -12.326451, 9.779296],
-    [-12.910168, -12.910168, 7.81641],
-    [-13.434792, -13.434792, 4.866052],
-    [-13.878777, -10.17324, 10.17324],
-    [-12.720387, -11.493118, 10.271195],
-    [-13.137054, -10.638885, 10.638885],
-    [-12.720387, -10.271195, 11.493118],
-    [-13.1187315, -10.6430645, 10.6430645],
-    [-11.493118, -12.720387, 10.271195],
-    [-10.17324, -13.878777, 10.17324],
-    [-10.638885, -13.137054, 10.638885],
-    [-10.271195, -12.720387, 11.493118],
-    [-10.6430645, -13.1187315, 10.6430645],
-    [-11.931611, -11.931611, 10.687862],
-    [-11.536771, -11.536771, 11.536771],
-    [-12.370105, -11.104529, 11.104529],
-    [-11.931611, -10.687862, 11.931611],
-    [-11.104529, -12.370105, 11.104529],
-    [-10.687862, -11.931611, 11.931611],
-    [-11.104529, -11.104529, 12.370105],
-    [-11.521811, -11.521811, 11.521811],
-    [-11.493118, -10.271195, 12.720387],
-    [-10.271195, -11.493118, 12.720387],
-    [-10.638885, -10.638885, 13.137054],
-    [-10.17324, -10.17324, 13.878777],
-    [-10.6430645, -10.6430645, 13.1187315],
-    [-11.489697, -11.489697, 11.489697],
-    [-11.489697, -11.489697, 11.489697],
-    [-17.730347, -9.189424, 0.625],
-    [-17.686771, -9.106327, 1.875],
-    [-18.239578, -8.125, 0.625],
-    [-18.153528, -8.125, 1.875],
-    [-17.952557, -8.636437, 1.25],
-    [-17.599136, -8.939161, 3.125],
-    [-17.529474, -8.8062525, 3.8696263],
-    [-17.980421, -8.125, 3.125],
-    [-17.719128, -8.154599, 4.321776],
-    [-17.718895, -8.465525, 3.63861],
-    [-18.863594, -6.564165, 0.625],
-    [-18.822376, -6.450191, 1.875],
-    [-19.160631, -5.625, 0.625],
-    [-19.078878, -5.625, 1.875],
-    [-18.634232, -7.189165, 0.625],
-    [-18.591757, -7.075191, 1.875],
-    [-18.858578, -6.421452, 1.25],
-    [-18.777624, -6.3264184, 2.6671536],
-    [-18.906551, -5.6671567, 3.1002922],
-    [-18.83258, -5.2785945, 4.107494],
-    [-18.529837, -6.795851, 3.1002922],
-    [-18.239576, -6.875, 4.375],
-    [-18.708017, -6.1119275, 3.500487],
-    [-18.63184, -5.6671567, 4.464496],
-    [-18.651932, -6.117916, 3.6303859],
-    [-17.398159, -9.814424, 0.625],
-    [-17.35254, -9.731327, 1.875],
-    [-17.375349, -9.772875, 1.25],
-    [-17.260742, -9.564161, 3.125],
-    [-17.15206, -9.2837515, 4.321776],
-    [-17.424526, -8.590999, 4.7029595],
-    [-17.256403, -9.204034, 4.0181465],
-    [-18.189259, -7.573839, 2.5777528],
-    [-17.617271, -7.7576656, 5.3707347],
-    [-17.617271, -7.7576656, 5.3707347],
-    [-17.823565, -6.977496, 5.7224407],
-    [-17.646322, -6.6291614, 6.6291614],
-    [-18.325289, -5.625, 5.625],
-    [-17.823565, -5.7224407, 6.977496],
-    [-17.91034, -6.228657, 6.228657],
-    [-17.617271, -5.3707347, 7.7576656],
-    [-17.617271, -5.3707347, 7.7576656],
-    [-16.642797, -9.467094, 5.6948266],
-    [-16.42622, -9.063559, 6.875],
-    [-17.317713, -8.154599, 5.7224407],
-    [-16.9031, -8.125, 6.875],
-    [-16.217432, -9.945158, 6.1580443],
-    [-16.064978, -9.688559, 6.875],
-    [-16.641281, -9.018096, 6.3224187],
-    [-16.2816, -8.8062525, 7.564785],
-    [-16.428444, -8.038871, 8.038871],
-    [-16.2816, -7.564785, 8.8062525],
-    [-15.6358595, -9.38943, 8.141557],
-    [-15.235847, -9.139274, 9.139274],
-    [-16.069181, -8.398118, 8.398118],
-    [-15.6358595, -8.141557, 9.38943],
-    [-15.90956, -8.520882, 8.520882],
-    [-17.48497, -7.4624944, 6.2040677],
-    [-17.372486, -6.977496, 6.977496],
-    [-17.48497, -6.2040677, 7.4624944],
-    [-17.43384, -6.8988338, 6.8988338],
-    [-16.903101, -6.875, 8.125],
-    [-16.42622, -6.875, 9.063559],
-    [-17.317713, -5.7224407, 8.154599],
-    [-16.642796, -5.6948266, 9.467094],
-    [-16.064978, -6.875, 9.688559],
-    [-16.217432, -6.1580443, 9.945158],
-    [-16.641281, -6.3224187, 9.018096],
-    [-16.773388, -7.537858, 7.537858],
-    [-19.484573, -4.375, 0.625],
-    [-19.404057, -4.375, 1.875],
-    [-19.724205, -3.125, 0.625],
-    [-19.644651, -3.125, 1.875],
-    [-19.564371, -3.75, 1.25],
-    [-19.24208, -4.375, 3.125],
-    [-18.95577, -4.464496, 4.464496],
-    [-19.484573, -3.125, 3.125],
-    [-19.24208, -3.125, 4.375],
-    [-18.736036, -4.940827, 4.940827],
-    [-19.143095, -3.9822478, 3.9822478],
-    [-19.88232, -1.875, 0.625],
-    [-19.803429, -1.875, 1.875],
-    [-19.960873, -0.625, 0.625],
-    [-19.882318, -0.625, 1.875],
-    [-19.882235, -1.25, 1.25],
-    [-19.644653, -1.875, 3.125],
-    [-19.404055, -1.875, 4.375],
-    [-19.724205, -0.625, 3.125],
-    [-19.484573, -0.625, 4.375],
-    [-19.564371, -1.25, 3.75],
-    [-19.515257, -2.6418376, 2.6418376],
-    [-18.83258, -4.107494, 5.2785945],
-    [-18.906551, -3.1002922, 5.6671567],
-    [-18.777624, -2.6671536, 6.3264184],
-    [-18.63184, -4.464496, 5.6671567],
-    [-18.239578, -4.375, 6.875],
-    [-18.708017, -3.500487, 6.1119275],
-    [-18.529839, -3.1002922, 6.795851],
-    [-18.651934, -3.6303859, 6.1179156],
-    [-17.719128, -4.321776, 8.154599],
-    [-17.529474, -3.8696263, 8.8062525],
-    [-17.98042, -3.125, 8.125],
-    [-17.599136, -3.125, 8.939161],
-    [-17.718893, -3.63861, 8.465525],
-    [-19.078878, -1.875, 5.625],
-    [-18.822376, -1.875, 6.450191],
-    [-19.160633, -0.625, 5.625],
-    [-18.863594, -0.625, 6.564165],
-    [-18.591757, -1.875, 7.075191],
-    [-18.634232, -0.625, 7.189165],
-    [-18.85858, -1.25, 6.4214516],
-    [-18.153526, -1.875, 8.125],
-    [-17.686771, -1.875, 9.106327],
-    [-18.239578, -0.625, 8.125],
-    [-17.730347, -0.625, 9.189424],
-    [-17.952557, -1.25, 8.636437],
-    [-17.424526, -4.7029595, 8.590999],
-    [-17.152058, -4.321776, 9.2837515],
-    [-17.260742, -3.125, 9.564161],
-    [-17.256403, -4.0181465, 9.204033],
-    [-17.35254, -1.875, 9.731327],
-    [-17.398159, -0.625, 9.814424],
-    [-17.375349, -1.25, 9.772875],
-    [-18.189259, -2.5777528, 7.573838],
-    [-14.982282, -9.972607, 8.718328],
-    [-14.806005, -9.483564, 9.483564],
-    [-14.982282, -8.718328, 9.972607],
-    [-14.902155, -9.408239, 9.408238],
-    [-14.902155, -9.408239, 9.408238],
-    [-17.9243, -5.476698, 5.4766974],
-    [-15.202538, -7.884995, 10.295564],
-    [-15.202538, -7.884995, 10.295564],
-    [-16.36011, -5.324711, 10.17324],
-    [-15.413083, -6.88703, 10.677339],
-    [-15.101833, -6.478384, 11.383239],
-    [-15.943033, -5.6948266, 10.603945],
-    [-15.265717, -5.625, 11.597614],
-    [-15.611464, -6.0409284, 10.873316],
-    [-15.5581255, -6.281459, 10.797956],
-    [-16.49668, -4.375, 10.388045],
-    [-16.577555, -3.1002922, 10.706492],
-    [-16.2816, -2.6671536, 11.294578],
-    [-16.090162, -4.375, 11.013046],
-    [-15.402763, -4.3575616, 11.948149],
-    [-16.201096, -3.500487, 11.177487],
-    [-15.832608, -3.1002922, 11.776747],
-    [-16.098623, -3.6652985, 11.213018],
-    [-15.051056, -3.9292688, 12.560555],
-    [-15.139938, -3.125, 12.665969],
-    [-15.101846, -3.4696867, 12.620791],
-    [-16.810335, -1.875, 10.625],
-    [-16.332794, -1.875, 11.366784],
-    [-16.903103, -0.625, 10.625],
-    [-16.379942, -0.625, 11.433281],
-    [-15.866021, -1.875, 11.991785],
-    [-15.916278, -0.625, 12.05828],
-    [-16.368078, -1.25, 11.350021],
-    [-15.241021, -1.875, 12.785843],
-    [-15.291278, -0.625, 12.845437],
-    [-15.2661495, -1.25, 12.8156395],
-    [-15.993176, -2.508551, 11.594832],
-    [-14.121927, -9.334143, 10.603945],
-    [-13.842153, -8.890239, 11.361135],
-    [-14.7412815, -8.230997, 10.677339],
-    [-14.06489, -8.125, 11.629698],
-    [-13.553721, -9.723573, 11.006574],
-    [-13.097248, -9.334767, 11.843793],
-    [-13.528498, -8.125, 12.254698],
-    [-13.84869, -8.822524, 11.336996],
-    [-12.640775, -8.945962, 12.640775],
-    [-12.903498, -8.125, 12.903498],
-    [-12.790902, -8.476841, 12.790902],
-    [-14.911034, -7.3117175, 11.128898],
-    [-14.422244, -6.9704385, 11.93085],
-    [-14.771767, -5.625, 12.222614],
-    [-13.730554, -7.4624944, 12.478462],
-    [-14.4715805, -6.790329, 11.956102],
-    [-13.933454, -6.6291614, 12.700542],
-    [-14.146767, -5.625, 12.933707],
-    [-13.2155485, -7.0458274, 13.2155485],
-    [-12.700542, -6.6291614, 13.933454],
-    [-13.558707, -5.625, 13.558707],
-    [-12.933707, -5.625, 14.146767],
-    [-13.406334, -6.231247, 13.406334],
-    [-12.326451, -9.779296, 12.326451],
-    [-12.326451, -9.779296, 12.326451],
-    [-11.843793, -9.334767, 13.097248],
-    [-11.361135, -8.890239, 13.842153],
-    [-12.254698, -8.125, 13.528497],
-    [-11.629698, -8.125, 14.06489],
-    [-11.006574, -9.723573, 13.553721],
-    [-10.603945, -9.334143, 14.121927],
-    [-10.677339, -8.230997, 14.7412815],
-    [-11.336996, -8.822524, 13.848691],
-    [-12.478462, -7.4624944, 13.730554],
-    [-11.93085, -6.9704385, 14.422244],
-    [-12.222614, -5.625, 14.771767],
-    [-11.128898, -7.3117175, 14.911034],
-    [-11.956102, -6.790329, 14.471581],
-    [-12.910169, -7.81641, 12.910169],
-    [-10.295564, -7.884995, 15.202538],
-    [-10.295564, -7.884995, 15.202538],
-    [-11.383239, -6.478384, 15.101833],
-    [-11.597614, -5.625, 15.265716],
-    [-10.677339, -6.88703, 15.413083],
-    [-10.603945, -5.6948266, 15.943031],
-    [-10.17324, -5.324711, 16.36011],
-    [-10.873316, -6.040929, 15.611463],
-    [-10.797956, -6.281459, 15.558124],
-    [-14.928902, -4.7626023, 12.413581],
-    [-14.928902, -4.7626023, 12.413581],
-    [-14.385528, -4.4054027, 13.135811],
-    [-13.842153, -4.048203, 13.842153],
-    [-14.581705, -3.125, 13.290969],
-    [-13.956705, -3.125, 13.956705],
-    [-13.711067, -4.881536, 13.711067],
-    [-13.135811, -4.4054027, 14.385528],
-    [-13.290969, -3.125, 14.581705],
-    [-13.83684, -3.905135, 13.83684],
-    [-14.690951, -1.875, 13.410843],
-    [-14.065951, -1.875, 14.065951],
-    [-14.745259, -0.625, 13.470437],
-    [-14.120259, -0.625, 14.120259],
-    [-13.410843, -1.875, 14.690951],
-    [-13.470437, -0.625, 14.745259],
-    [-14.08395, -1.25, 14.08395],
-    [-12.413581, -4.7626023, 14.928902],
-    [-12.413581, -4.7626023, 14.928902],
-    [-13.919124, -2.9288278, 13.919125],
-    [-12.560555, -3.9292688, 15.051056],
-    [-12.665969, -3.125, 15.139938],
-    [-12.620791, -3.4696867, 15.101846],
-    [-12.785843, -1.875, 15.241021],
-    [-12.845437, -0.625, 15.291278],
-    [-12.8156395, -1.25, 15.2661495],
-    [-11.948149, -4.3575616, 15.402763],
-    [-11.776747, -3.1002922, 15.832608],
-    [-11.294578, -2.6671536, 16.2816],
-    [-11.013045, -4.375, 16.090162],
-    [-10.388045, -4.375, 16.49668],
-    [-11.177487, -3.500487, 16.201096],
-    [-10.706492, -3.1002922, 16.577555],
-    [-11.2130165, -3.6652985, 16.098623],
-    [-11.991785, -1.875, 15.866021],
-    [-11.366785, -1.875, 16.332794],
-    [-12.058281, -0.625, 15.916278],
-    [-11.433281, -0.625, 16.379942],
-    [-10.625, -1.875, 16.810333],
-    [-10.625, -0.625, 16.903101],
-    [-11.350021, -1.25, 16.368078],
-    [-11.594833, -2.508551, 15.993178],
-    [-13.434792, -4.866052, 13.434792],
-    [-9.1894245, -17.730347, 0.625],
-    [-9.106327, -17.686771, 1.875],
-    [-8.125, -18.239578, 0.625],
-    [-8.125, -18.153526, 1.875],
-    [-8.636438, -17.952557, 1.25],
-    [-8.939161, -17.599136, 3.125],
-    [-8.8062525, -17.529474, 3.8696263],
-    [-8.125, -17.98042, 3.125],
-    [-8.154599, -17.719128, 4.321776],
-    [-8.465525, -17.718893, 3.63861],
-    [-9.8144245, -17.398159, 0.625],
-    [-9.731327, -17.35254, 1.875],
-    [-9.772876, -17.375349, 1.25],
-    [-9.564161, -17.260742, 3.125],
-    [-9.2837515, -17.152058, 4.321776],
-    [-8.590999, -17.424526, 4.7029595],
-    [-9.204034, -17.256403, 4.0181465],
-    [-6.564165, -18.863594, 0.625],
-    [-6.450191, -18.822376, 1.875],
-    [-7.189165, -18.634232, 0.625],
-    [-7.075191, -18.591757, 1.875],
-    [-5.625, -19.160631, 0.625],
-    [-5.625, -19.078878, 1.875],
-    [-6.421452, -18.858578, 1.25],
-    [-6.3264184, -18.777624, 2.6671536],
-    [-6.795851, -18.529839, 3.1002922],
-    [-6.875, -18.239578, 4.375],
-    [-5.6671567, -18.906551, 3.1002922],
-    [-5.2785945, -18.83258, 4.107494],
-    [-6.1119275, -18.708017, 3.500487],
-    [-5.6671567, -18.63184, 4.464496],
-    [-6.117916, -18.651934, 3.6303859],
-    [-7.5738397, -18.189259, 2.5777528],
-    [-7.7576656, -17.617271, 5.3707347],
-    [-7.7576656, -17.617271, 5.3707347],
-    [-9.467094, -16.642797, 5.6948266],
-    [-9.063559, -16.42622, 6.875],
-    [-9.945158, -16.217432, 6.1580443],
-    [-9.688559, -16.064978, 6.875],
-    [-8.154599, -17.317713, 5.7224407],
-    [-8.125, -16.903101, 6.875],
-    [-9.018097, -16.641283, 6.3224187],
-    [-8.8062525, -16.2816, 7.564785],
-    [-9.38943, -15.6358595, 8.141557],
-    [-9.139274, -15.235847, 9.139274],
-    [-8.038871, -16.428442, 8.038871],
-    [-7.564785, -16.2816, 8.8062525],
-    [-8.398118, -16.069181, 8.398118],
-    [-8.141557, -15.6358595, 9.38943],
-    [-8.520881, -15.909558, 8.520882],
-    [-6.977496, -17.823565, 5.7224407],
-    [-6.6291614, -17.646322, 6.6291614],
-    [-5.625, -18.325289, 5.625],
-    [-5.7224407, -17.823565, 6.977496],
-    [-6.228657, -17.91034, 6.228657],
-    [-5.3707347, -17.617271, 7.7576656],
-    [-5.3707347, -17.617271, 7.7576656],
-    [-7.4624944, -17.48497, 6.2040677],
-    [-6.977496, -17.372486, 6.977496],
-    [-6.2040677, -17.48497, 7.4624944],
-    [-6.8988338, -17.43384, 6.8988338],
-    [-6.875, -16.903101, 8.125],
-    [-6.875, -16.42622, 9.063559],
-    [-6.875, -16.064978, 9.688559],
-    [-5.7224407, -17.317713, 8.154599],
-    [-5.6948266, -16.642796, 9.467094],
-    [-6.1580443, -16.217432, 9.945158],
-    [-6.3224187, -16.641281, 9.018097],
-    [-7.5378585, -16.773388, 7.5378585],
-    [-9.972607, -14.982282, 8.718328],
-    [-9.483564, -14.806005, 9.483564],
-    [-8.718328, -14.982282, 9.972607],
-    [-9.408239, -14.902155, 9.408238],
-    [-9.408239, -14.902155, 9.408238],
-    [-4.375, -19.484573, 0.625],
-    [-4.375, -19.404057, 1.875],
-    [-3.125, -19.724207, 0.625],
-    [-3.125, -19.644653, 1.875],
-    [-3.75, -19.564373, 1.25],
-    [-4.375, -19.24208, 3.125],
-    [-4.464496, -18.95577, 4.464496],
-    [-4.940827, -18.736036, 4.940827],
-    [-3.125, -19.484573, 3.125],
-    [-3.125, -19.24208, 4.375],
-    [-3.9822478, -19.143095, 3.9822478],
-    [-1.875, -19.88232, 0.625],
-    [-1.875, -19.803429, 1.875],
-    [-0.625, -19.960873, 0.625],
-    [-0.625, -19.88232, 1.875],
-    [-1.25, -19.882236, 1.25],
-    [-1.875, -19.644653, 3.125],
-    [-1.875, -19.404055, 4.375],
-    [-0.625, -19.724205, 3.125],
-    [-0.625, -19.484573, 4.375],
-    [-1.25, -19.564371, 3.75],
-    [-2.6418376, -19.515259, 2.6418376],
-    [-4.107494, -18.83258, 5.2785945],
-    [-4.464496, -18.63184, 5.6671567],
-    [-4.375, -18.239576, 6.875],
-    [-3.1002922, -18.906551, 5.6671567],
-    [-2.6671536, -18.777624, 6.3264184],
-    [-3.500487, -18.708017, 6.1119275],
-    [-3.1002922, -18.529837, 6.795851],
-    [-3.6303864, -18.651932, 6.1179156],
-    [-4.321776, -17.719128, 8.154599],
-    [-3.8696263, -17.529474, 8.8062525],
-    [-3.125, -17.980421, 8.125],
-    [-3.125, -17.599136, 8.939161],
-    [-3.63861, -17.718895, 8.465525],
-    [-4.7029595, -17.424526, 8.590999],
-    [-4.321776, -17.15206, 9.2837515],
-    [-3.125, -17.260742, 9.564161],
-    [-4.0181465, -17.256403, 9.204033],
-    [-1.875, -19.078878, 5.625],
-    [-1.875, -18.822376, 6.450191],
-    [-1.875, -18.591757, 7.075191],
-    [-0.625, -19.160633, 5.625],
-    [-0.625, -18.863594, 6.564165],
-    [-0.625, -18.634232, 7.189165],
-    [-1.25, -18.858578, 6.421452],
-    [-1.875, -18.153526, 8.125],
-    [-1.875, -17.686771, 9.106327],
-    [-0.625, -18.239578, 8.125],
-    [-0.625, -17.730347, 9.1894245],
-    [-1.25, -17.952557, 8.636438],
-    [-1.875, -17.35254, 9.731327],
-    [-0.625, -17.398159, 9.8144245],
-    [-1.25, -17.375349, 9.772876],
-    [-2.5777533, -18.189259, 7.5738397],
-    [-5.4766984, -17.924301, 5.476698],
-    [-7.884995, -15.202538, 10.295564],
-    [-7.884995, -15.202538, 10.295564],
-    [-6.88703, -15.413083, 10.677339],
-    [-6.478384, -15.101833, 11.383239],
-    [-5.324711, -16.36011, 10.17324],
-    [-5.6948266, -15.943033, 10.603945],
-    [-5.625, -15.265717, 11.597614],
-    [-6.040929, -15.611463, 10.873316],
-    [-6.281459, -15.558124, 10.797956],
-    [-9.334143, -14.121927, 10.603945],
-    [-8.890239, -13.842153, 11.361135],
-    [-9.723573, -13.553721, 11.006574],
-    [-9.334767, -13.097248, 11.843793],
-    [-8.230997, -14.7412815, 10.677339],
-    [-8.125, -14.06489, 11.629698],
-    [-8.125, -13.528498, 12.254698],
-    [-8.822525, -13.84869, 11.336995],
-    [-8.945962, -12.640775, 12.640775],
-    [-8.125, -12.903497, 12.903498],
-    [-8.476841, -12.790902, 12.790902],
-    [-9.779296, -12.326451, 12.326451],
-    [-9.779296, -12.326451, 12.326451],
-    [-9.334767, -11.843793, 13.097248],
-    [-8.890239, -11.361135, 13.842153],
-    [-9.723573, -11.006574, 13.553721],
-    [-9.334143, -10.603945, 14.121927],
-    [-8.125, -12.254698, 13.528498],
-    [-8.125, -11.629698, 14.06489],
-    [-8.230997, -10.677339, 14.7412815],
-    [-8.822525, -11.336995, 13.848691],
-    [-7.3117175, -14.911034, 11.128898],
-    [-6.9704394, -14.422244, 11.93085],
-    [-7.4624944, -13.730554, 12.478462],
-    [-5.625, -14.771767, 12.222614],
-    [-6.790329, -14.4715805, 11.956102],
-    [-6.6291614, -13.933454, 12.700542],
-    [-7.045828, -13.2155485, 13.2155485],
-    [-6.6291614, -12.700542, 13.933454],
-    [-5.625, -14.146767, 12.933707],
-    [-5.625, -13.558707, 13.558707],
-    [-5.625, -12.933707, 14.146767],
-    [-6.231247, -13.406334, 13.406334],
-    [-7.4624944, -12.478462, 13.730554],
-    [-6.9704394, -11.930851, 14.422244],
-    [-7.3117175, -11.128898, 14.911034],
-    [-5.625, -12.222614, 14.771767],
-    [-6.790329, -11.956102, 14.471581],
-    [-7.816411, -12.910168, 12.910168],
-    [-7.884995, -10.295564, 15.202538],
-    [-7.884995, -10.295564, 15.202538],
-    [-6.478384, -11.383239, 15.101833],
-    [-6.88703, -10.677339, 15.413081],
-    [-5.625, -11.597614, 15.265717],
-    [-5.6948266, -10.603945, 15.943033],
-    [-5.324711, -10.17324, 16.36011],
-    [-6.040929, -10.873316, 15.611463],
-    [-6.281459, -10.797956, 15.558124],
-    [-4.375, -16.49668, 10.388045],
-    [-4.375, -16.090162, 11.013045],
-    [-4.3575616, -15.402763, 11.948149],
-    [-3.1002922, -16.577555, 10.706492],
-    [-2.6671536, -16.2816, 11.294578],
-    [-3.500487, -16.201096, 11.177487],
-    [-3.1002922, -15.832608, 11.776747],
-    [-3.6652985, -16.098625, 11.2130165],
-    [-3.9292688, -15.051056, 12.560555],
-    [-3.125, -15.139938, 12.665969],
-    [-3.4696867, -15.101846, 12.620791],
-    [-1.875, -16.810333, 10.625],
-    [-1.875, -16.332794, 11.366785],
-    [-1.875, -15.866021, 11.991785],
-    [-0.625, -16.903101, 10.625],
-    [-0.625, -16.379942, 11.433281],
-    [-0.625, -15.916278, 12.058281],
-    [-1.25, -16.368078, 11.350021],
-    [-1.875, -15.241021, 12.785843],
-    [-0.625, -15.291278, 12.845437],
-    [-1.25, -15.2661495, 12.8156395],
-    [-2.508551, -15.993176, 11.594832],
-    [-4.7626023, -14.928902, 12.413581],
-    [-4.7626023, -14.928902, 12.413581],
-    [-4.4054027, -14.385528, 13.135811],
-    [-4.048203, -13.842153, 13.842153],
-    [-4.881536, -13.711067, 13.711067],
-    [-4.4054027, -13.135811, 14.385528],
-    [-3.125, -14.581705, 13.290969],
-    [-3.125, -13.956705, 13.956705],
-    [-3.125, -13.290969, 14.581705],
-    [-3.905135, -13.83684, 13.836841],
-    [-4.7626023, -12.413581, 14.928902],
-    [-4.7626023, -12.413581, 14.928902],
-    [-1.875, -14.690951, 13.410843],
-    [-1.875, -14.065951, 14.065951],
-    [-1.875, -13.410843, 14.690951],
-    [-0.625, -14.745259, 13.470437],
-    [-0.625, -14.120259, 14.120259],
-    [-0.625, -13.470437, 14.745259],
-    [-1.25, -14.08395, 14.08395],
-    [-2.928828, -13.919124, 13.919124],
-    [-3.9292688, -12.560555, 15.051056],
-    [-3.125, -12.665969, 15.139938],
-    [-3.4696867, -12.620791, 15.101846],
-    [-4.3575616, -11.948149, 15.402763],
-    [-4.375, -11.013046, 16.090162],
-    [-4.375, -10.388045, 16.49668],
-    [-3.1002922, -11.776747, 15.832608],
-    [-2.6671536, -11.294578, 16.2816],
-    [-3.500487, -11.177487, 16.201096],
-    [-3.1002922, -10.706492, 16.577555],
-    [-3.6652985, -11.213018, 16.098625],
-    [-1.875, -12.785843, 15.241021],
-    [-0.625, -12.845437, 15.291279],
-    [-1.25, -12.8156395, 15.2661495],
-    [-1.875, -11.991784, 15.866021],
-    [-1.875, -11.366785, 16.332794],
-    [-1.875, -10.625, 16.810335],
-    [-0.625, -12.058281, 15.916279],
-    [-0.625, -11.43328, 16.379944],
-    [-0.625, -10.625, 16.903103],
-    [-1.25, -11.350021, 16.36808],
-    [-2.508551, -11.594834, 15.993178],
-    [-4.866053, -13.434791, 13.434792],
-    [-9.483564, -9.483564, 14.806005],
-    [-9.972607, -8.718328, 14.982282],
-    [-8.718328, -9.972607, 14.982282],
-    [-9.408239, -9.408238, 14.902155],
-    [-9.408239, -9.408238, 14.902155],
-    [-9.139274, -9.139274, 15.235847],
-    [-9.38943, -8.141557, 15.6358595],
-    [-8.8062525, -7.564785, 16.2816],
-    [-8.141557, -9.38943, 15.6358595],
-    [-7.564785, -8.8062525, 16.2816],
-    [-8.398118, -8.398118, 16.069181],
-    [-8.038871, -8.038871, 16.428444],
-    [-8.520881, -8.520881, 15.909558],
-    [-9.688559, -6.875, 16.064978],
-    [-9.063559, -6.875, 16.42622],
-    [-9.945158, -6.1580443, 16.217432],
-    [-9.467094, -5.6948266, 16.642796],
-    [-8.125, -6.875, 16.903101],
-    [-8.154599, -5.7224407, 17.317713],
-    [-9.018097, -6.3224187, 16.641281],
-    [-7.7576656, -5.3707347, 17.617271],
-    [-7.7576656, -5.3707347, 17.617271],
-    [-6.875, -9.688559, 16.064978],
-    [-6.875, -9.063559, 16.42622],
-    [-6.875, -8.125, 16.9031],
-    [-6.1580443, -9.945158, 16.217432],
-    [-5.6948266, -9.467094, 16.642797],
-    [-5.7224407, -8.154599, 17.317713],
-    [-6.3224187, -9.018096, 16.641281],
-    [-5.3707347, -7.7576656, 17.617271],
-    [-5.3707347, -7.7576656, 17.617271],
-    [-6.977496, -6.977496, 17.372486],
-    [-7.4624944, -6.2040677, 17.48497],
-    [-6.2040677, -7.4624944, 17.48497],
-    [-6.8988338, -6.8988338, 17.43384],
-    [-6.6291614, -6.6291614, 17.646322],
-    [-6.977496, -5.7224407, 17.823565],
-    [-5.7224407, -6.977496, 17.823565],
-    [-5.625, -5.625, 18.325289],
-    [-6.228657, -6.228657, 17.91034],
-    [-7.5378575, -7.5378575, 16.773388],
-    [-9.2837515, -4.321776, 17.152058],
-    [-9.564161, -3.125, 17.260742],
-    [-8.590999, -4.7029595, 17.424526],
-    [-9.204034, -4.0181465, 17.256403],
-    [-8.8062525, -3.8696263, 17.529474],
-    [-8.939161, -3.125, 17.599136],
-    [-8.154599, -4.321776, 17.719128],
-    [-8.125, -3.125, 17.98042],
-    [-8.465525, -3.63861, 17.718893],
-    [-9.731327, -1.875, 17.35254],
-    [-9.814424, -0.625, 17.398159],
-    [-9.772875, -1.25, 17.375349],
-    [-9.106327, -1.875, 17.686771],
-    [-9.189424, -0.625, 17.730347],
-    [-8.125, -1.875, 18.153528],
-    [-8.125, -0.625, 18.239578],
-    [-8.636437, -1.25, 17.952557],
-    [-6.875, -4.375, 18.239576],
-    [-6.795851, -3.1002922, 18.529839],
-    [-6.3264184, -2.6671536, 18.777624],
-    [-5.6671567, -4.464496, 18.63184],
-    [-5.2785945, -4.107494, 18.83258],
-    [-6.1119275, -3.500487, 18.708017],
-    [-5.6671567, -3.1002922, 18.906551],
-    [-6.117916, -3.6303859, 18.651934],
-    [-7.075191, -1.875, 18.591757],
-    [-6.450191, -1.875, 18.822376],
-    [-7.189165, -0.625, 18.634232],
-    [-6.564165, -0.625, 18.863594],
-    [-5.625, -1.875, 19.078878],
-    [-5.625, -0.625, 19.160633],
-    [-6.421452, -1.25, 18.858578],
-    [-7.5738397, -2.5777528, 18.189257],
-    [-4.321776, -9.2837515, 17.15206],
-    [-4.7029595, -8.590999, 17.424526],
-    [-3.125, -9.564161, 17.260742],
-    [-4.0181465, -9.204033, 17.256403],
-    [-3.8696263, -8.8062525, 17.529474],
-    [-4.321776, -8.154599, 17.719128],
-    [-3.125, -8.939161, 17.599136],
-    [-3.125, -8.125, 17.98042],
-    [-3.63861, -8.465525, 17.718893],
-    [-4.375, -6.875, 18.239578],
-    [-4.464496, -5.6671567, 18.63184],
-    [-4.107494, -5.2785945, 18.83258],
-    [-3.1002922, -6.795851, 18.529837],
-    [-2.6671536, -6.3264184, 18.777624],
-    [-3.500487, -6.1119275, 18.708017],
-    [-3.1002922, -5.6671567, 18.906551],
-    [-3.6303864, -6.1179156, 18.651932],
-    [-1.875, -9.731327, 17.35254],
-    [-0.625, -9.814424, 17.398159],
-    [-1.25, -9.772875, 17.375349],
-    [-1.875, -9.106327, 17.686771],
-    [-1.875, -8.125, 18.153526],
-    [-0.625, -9.189424, 17.730347],
-    [-0.625, -8.125, 18.239578],
-    [-1.25, -8.636437, 17.952557],
-    [-1.875, -7.075191, 18.591757],
-    [-1.875, -6.450191, 18.822376],
-    [-1.875, -5.625, 19.078878],
-    [-0.625, -7.189165, 18.634232],
-    [-0.625, -6.564165, 18.863594],
-    [-0.625, -5.625, 19.160631],
-    [-1.25, -6.421452, 18.858578],
-    [-2.5777533, -7.573839, 18.189257],
-    [-4.940827, -4.940827, 18.736036],
-    [-4.464496, -4.464496, 18.95577],
-    [-4.375, -3.125, 19.24208],
-    [-3.125, -4.375, 19.24208],
-    [-3.125, -3.125, 19.484573],
-    [-3.9822478, -3.9822478, 19.143095],
-    [-4.375, -1.875, 19.404055],
-    [-4.375, -0.625, 19.484573],
-    [-3.125, -1.875, 19.644651],
-    [-3.125, -0.625, 19.724205],
-    [-3.75, -1.25, 19.564371],
-    [-1.875, -4.375, 19.404057],
-    [-1.875, -3.125, 19.644651],
-    [-0.625, -4.375, 19.484573],
-    [-0.625, -3.125, 19.724207],
-    [-1.25, -3.75, 19.564371],
-    [-1.875, -1.875, 19.803429],
-    [-1.875, -0.625, 19.88232],
-    [-0.625, -1.875, 19.88232],
-    [-0.625, -0.625, 19.960873],
-    [-1.25, -1.25, 19.882236],
-    [-2.6418376, -2.6418376, 19.515259],
-    [-5.476698, -5.476698, 17.9243],
-    [-10.126742, -10.126742, 10.126742],
-    [-15.291278, 0.625, -12.845437],
-    [-15.241021, 1.875, -12.785843],
-    [-15.2661495, 1.25, -12.8156395],
-    [-16.379942, 0.625, -11.433281],
-    [-16.903103, 0.625, -10.625],
-    [-16.332794, 1.875, -11.366785],
-    [-16.810335, 1.875, -10.625],
-    [-15.916278, 0.625, -12.058281],
-    [-15.866021, 1.875, -11.991784],
-    [-16.368078, 1.25, -11.350021],
-    [-15.139938, 3.125, -12.665969],
-    [-15.051056, 3.9292688, -12.560555],
-    [-15.101846, 3.4696867, -12.620791],
-    [-16.2816, 2.6671536, -11.294578],
-    [-16.577555, 3.1002922, -10.706492],
-    [-16.49668, 4.375, -10.388045],
-    [-15.832608, 3.1002922, -11.776747],
-    [-16.201096, 3.500487, -11.177487],
-    [-15.402763, 4.3575616, -11.948149],
-    [-16.090162, 4.375, -11.013046],
-    [-16.098625, 3.6652985, -11.213018],
-    [-15.993178, 2.508551, -11.594834],
-    [-16.36011, 5.324711, -10.17324],
-    [-15.265716, 5.625, -11.597614],
-    [-15.943031, 5.6948266, -10.603945],
-    [-15.101833, 6.478384, -11.383239],
-    [-15.413081, 6.88703, -10.677339],
-    [-15.611463, 6.0409284, -10.873316],
-    [-15.202538, 7.884995, -10.295564],
-    [-15.202538, 7.884995, -10.295564],
-    [-15.558124, 6.281459, -10.797956],
-    [-12.845437, 0.625, -15.291278],
-    [-12.785843, 1.875, -15.241021],
-    [-12.8156395, 1.25, -15.2661495],
-    [-12.665969, 3.125, -15.139938],
-    [-12.560555, 3.9292688, -15.051056],
-    [-12.620791, 3.4696867, -15.101846],
-    [-11.433281, 0.625, -16.379942],
-    [-12.058281, 0.625, -15.916278],
-    [-11.366784, 1.875, -16.332794],
-    [-11.991785, 1.875, -15.866021],
-    [-10.625, 0.625, -16.903103],
-    [-10.625, 1.875, -16.810335],
-    [-11.350021, 1.25, -16.368078],
-    [-11.294578, 2.6671536, -16.2816],
-    [-11.776747, 3.1002922, -15.832608],
-    [-11.948149, 4.3575616, -15.402763],
-    [-10.706492, 3.1002922, -16.577555],
-    [-11.177487, 3.500487, -16.201096],
-    [-10.388045, 4.375, -16.49668],
-    [-11.013045, 4.375, -16.090162],
-    [-11.2130165, 3.6652985, -16.098625],
-    [-11.594833, 2.508551, -15.993178],
-    [-14.120259, 0.625, -14.120259],
-    [-14.745259, 0.625, -13.470437],
-    [-14.065951, 1.875, -14.065951],
-    [-14.690951, 1.875, -13.410843],
-    [-13.470437, 0.625, -14.745259],
-    [-13.410843, 1.875, -14.690951],
-    [-14.08395, 1.25, -14.08395],
-    [-13.956705, 3.125, -13.956705],
-    [-14.581705, 3.125, -13.290969],
-    [-13.842153, 4.048203, -13.842153],
-    [-14.385528, 4.4054027, -13.135811],
-    [-13.290969, 3.125, -14.581705],
-    [-13.135811, 4.4054027, -14.385528],
-    [-13.711067, 4.881536, -13.711067],
-    [-13.83684, 3.905135, -13.83684],
-    [-14.928902, 4.7626023, -12.413581],
-    [-14.928902, 4.7626023, -12.413581],
-    [-12.413581, 4.7626023, -14.928902],
-    [-12.413581, 4.7626023, -14.928902],
-    [-13.919124, 2.9288278, -13.919125],
-    [-11.597614, 5.625, -15.265717],
-    [-11.383239, 6.478384, -15.101833],
-    [-10.17324, 5.324711, -16.36011],
-    [-10.603945, 5.6948266, -15.943033],
-    [-10.677339, 6.88703, -15.413083],
-    [-10.873315, 6.040929, -15.611463],
-    [-10.295564, 7.884995, -15.202538],
-    [-10.295564, 7.884995, -15.202538],
-    [-10.7979555, 6.281459, -15.558124],
-    [-14.146767, 5.625, -12.933707],
-    [-13.933454, 6.6291614, -12.700542],
-    [-12.933707, 5.625, -14.146767],
-    [-13.558707, 5.625, -13.558707],
-    [-12.700542, 6.6291614, -13.933454],
-    [-13.2155485, 7.0458274, -13.2155485],
-    [-13.406333, 6.231247, -13.406334],
-    [-14.771767, 5.625, -12.222614],
-    [-14.422244, 6.9704385, -11.93085],
-    [-14.911034, 7.3117175, -11.128898],
-    [-13.730554, 7.4624944, -12.478462],
-    [-14.4715805, 6.790329, -11.956102],
-    [-12.903498, 8.125, -12.903498],
-    [-12.640775, 8.945962, -12.640775],
-    [-12.790902, 8.476841, -12.790902],
-    [-14.06489, 8.125, -11.629698],
-    [-14.7412815, 8.230997, -10.677339],
-    [-13.842153, 8.890239, -11.361135],
-    [-14.121927, 9.334143, -10.603945],
-    [-13.528498, 8.125, -12.254698],
-    [-13.097248, 9.334767, -11.843793],
-    [-13.553721, 9.723573, -11.006574],
-    [-13.84869, 8.822524, -11.336995],
-    [-12.222614, 5.625, -14.771767],
-    [-11.93085, 6.9704385, -14.422244],
-    [-12.478462, 7.4624944, -13.730554],
-    [-11.128898, 7.3117175, -14.911034],
-    [-11.956102, 6.790329, -14.471581],
-    [-11.629698, 8.125, -14.06489],
-    [-12.254698, 8.125, -13.528498],
-    [-11.361135, 8.890239, -13.842153],
-    [-11.843793, 9.334767, -13.097249],
-    [-10.677339, 8.230997, -14.7412815],
-    [-10.603945, 9.334143, -14.121927],
-    [-11.006574, 9.723573, -13.553721],
-    [-11.336995, 8.822524, -13.84869],
-    [-12.326451, 9.779296, -12.326451],
-    [-12.326451, 9.779296, -12.326451],
-    [-12.910168, 7.81641, -12.910168],
-    [-13.434792, 4.866052, -13.434792],
-    [-17.730347, 0.625, -9.189424],
-    [-18.239578, 0.625, -8.125],
-    [-17.686771, 1.875, -9.106327],
-    [-18.153526, 1.875, -8.125],
-    [-17.952557, 1.25, -8.636437],
-    [-18.863594, 0.625, -6.564165],
-    [-19.160631, 0.625, -5.625],
-    [-18.822376, 1.875, -6.450191],
-    [-19.078878, 1.875, -5.625],
-    [-18.634232, 0.625, -7.189165],
-    [-18.591757, 1.875, -7.075191],
-    [-18.858578, 1.25, -6.421452],
-    [-17.599136, 3.125, -8.939161],
-    [-17.98042, 3.125, -8.125],
-    [-17.529474, 3.8696263, -8.8062525],
-    [-17.719128, 4.321776, -8.154599],
-    [-17.718893, 3.63861, -8.465525],
-    [-18.777624, 2.6671536, -6.3264184],
-    [-18.906551, 3.1002922, -5.6671567],
-    [-18.83258, 4.107494, -5.2785945],
-    [-18.529837, 3.1002922, -6.795851],
-    [-18.708017, 3.500487, -6.1119275],
-    [-18.239578, 4.375, -6.875],
-    [-18.63184, 4.464496, -5.6671567],
-    [-18.651932, 3.6303859, -6.117916],
-    [-17.398159, 0.625, -9.814424],
-    [-17.35254, 1.875, -9.731327],
-    [-17.375349, 1.25, -9.772875],
-    [-17.260742, 3.125, -9.564161],
-    [-17.15206, 4.321776, -9.2837515],
-    [-17.424526, 4.7029595, -8.590999],
-    [-17.256403, 4.0181465, -9.204033],
-    [-18.189259, 2.5777528, -7.573839],
-    [-19.484573, 0.625, -4.375],
-    [-19.724207, 0.625, -3.125],
-    [-19.404057, 1.875, -4.375],
-    [-19.644651, 1.875, -3.125],
-    [-19.564373, 1.25, -3.75],
-    [-19.88232, 0.625, -1.875],
-    [-19.960873, 0.625, -0.625],
-    [-19.803429, 1.875, -1.875],
-    [-19.88232, 1.875, -0.625],
-    [-19.882236, 1.25, -1.25],
-    [-19.24208, 3.125, -4.375],
-    [-19.484573, 3.125, -3.125],
-    [-18.95577, 4.464496, -4.464496],
-    [-19.24208, 4.375, -3.125],
-    [-18.736036, 4.940827, -4.940827],
-    [-19.143095, 3.9822478, -3.9822478],
-    [-19.644651, 3.125, -1.875],
-    [-19.724205, 3.125, -0.625],
-    [-19.404055, 4.375, -1.875],
-    [-19.484573, 4.375, -0.625],
-    [-19.564371, 3.75, -1.25],
-    [-19.515259, 2.6418376, -2.6418376],
-    [-17.617271, 5.3707347, -7.7576656],
-    [-17.617271, 5.3707347, -7.7576656],
-    [-17.823565, 5.7224407, -6.977496],
-    [-18.325289, 5.625, -5.625],
-    [-17.646322, 6.6291614, -6.6291614],
-    [-17.823565, 6.977496, -5.7224407],
-    [-17.91034, 6.228657, -6.228657],
-    [-17.617271, 7.7576656, -5.3707347],
-    [-17.617271, 7.7576656, -5.3707347],
-    [-16.642796, 5.6948266, -9.467094],
-    [-17.317713, 5.7224407, -8.154599],
-    [-16.42622, 6.875, -9.063559],
-    [-16.9031, 6.875, -8.125],
-    [-16.217432, 6.1580443, -9.945158],
-    [-16.064978, 6.875, -9.688559],
-    [-16.641281, 6.3224187, -9.018096],
-    [-17.48497, 6.2040677, -7.4624944],
-    [-17.372486, 6.977496, -6.977496],
-    [-17.48497, 7.4624944, -6.2040677],
-    [-17.43384, 6.8988338, -6.8988338],
-    [-16.2816, 7.564785, -8.8062525],
-    [-16.428444, 8.038871, -8.038871],
-    [-16.2816, 8.8062525, -7.564785],
-    [-15.6358595, 8.141557, -9.38943],
-    [-16.069181, 8.398118, -8.398118],
-    [-15.235847, 9.139274, -9.139274],
-    [-15.6358595, 9.38943, -8.141557],
-    [-15.90956, 8.520882, -8.520882],
-    [-16.903101, 8.125, -6.875],
-    [-17.317713, 8.154599, -5.7224407],
-    [-16.42622, 9.063559, -6.875],
-    [-16.642796, 9.467094, -5.6948266],
-    [-16.064978, 9.688559, -6.875],
-    [-16.217432, 9.945158, -6.1580443],
-    [-16.641281, 9.018096, -6.3224187],
-    [-16.773388, 7.537858, -7.537858],
-    [-18.83258, 5.2785945, -4.107494],
-    [-18.906551, 5.6671567, -3.1002922],
-    [-18.777624, 6.3264184, -2.6671536],
-    [-18.63184, 5.6671567, -4.464496],
-    [-18.708017, 6.1119275, -3.500487],
-    [-18.239576, 6.875, -4.375],
-    [-18.529839, 6.795851, -3.1002922],
-    [-18.651932, 6.1179156, -3.6303859],
-    [-19.078878, 5.625, -1.875],
-    [-19.160633, 5.625, -0.625],
-    [-18.822376, 6.450191, -1.875],
-    [-18.863594, 6.564165, -0.625],
-    [-18.591757, 7.075191, -1.875],
-    [-18.634232, 7.189165, -0.625],
-    [-18.85858, 6.4214516, -1.25],
-    [-17.719128, 8.154599, -4.321776],
-    [-17.98042, 8.125, -3.125],
-    [-17.529474, 8.8062525, -3.8696263],
-    [-17.599136, 8.939161, -3.125],
-    [-17.718893, 8.465525, -3.63861],
-    [-18.153528, 8.125, -1.875],
-    [-18.239578, 8.125, -0.625],
-    [-17.686771, 9.106327, -1.875],
-    [-17.730347, 9.189424, -0.625],
-    [-17.952557, 8.636437, -1.25],
-    [-17.424526, 8.590999, -4.7029595],
-    [-17.152058, 9.2837515, -4.321776],
-    [-17.260742, 9.564161, -3.125],
-    [-17.256403, 9.204034, -4.0181465],
-    [-17.35254, 9.731327, -1.875],
-    [-17.398159, 9.814424, -0.625],
-    [-17.375349, 9.772875, -1.25],
-    [-18.189259, 7.573838, -2.5777528],
-    [-14.982282, 8.718328, -9.972607],
-    [-14.806005, 9.483564, -9.483564],
-    [-14.982282, 9.972607, -8.718328],
-    [-14.902155, 9.408239, -9.408238],
-    [-14.902155, 9.408239, -9.408238],
-    [-17.9243, 5.4766974, -5.476698],
-    [-13.878777, 10.17324, -10.17324],
-    [-12.720387, 10.271195, -11.493118],
-    [-13.137054, 10.638885, -10.638885],
-    [-12.720387, 11.493118, -10.271195],
-    [-13.1187315, 10.6430645, -10.6430645],
-    [-11.493118, 10.271195, -12.720387],
-    [-10.17324, 10.17324, -13.878777],
-    [-10.638885, 10.638885, -13.137054],
-    [-10.271195, 11.493118, -12.720387],
-    [-10.6430645, 10.6430645, -13.1187315],
-    [-11.931611, 10.687862, -11.931611],
-    [-12.370105, 11.104529, -11.104529],
-    [-11.536771, 11.536771, -11.536771],
-    [-11.931611, 11.931611, -10.687862],
-    [-11.104529, 11.104529, -12.370105],
-    [-10.687862, 11.931611, -11.931611],
-    [-11.104529, 12.370105, -11.104529],
-    [-11.521811, 11.521811, -11.521811],
-    [-11.493118, 12.720387, -10.271195],
-    [-10.271195, 12.720387, -11.493118],
-    [-10.638885, 13.137054, -10.638885],
-    [-10.17324, 13.878777, -10.17324],
-    [-10.6430645, 13.1187315, -10.6430645],
-    [-11.489697, 11.489697, -11.489697],
-    [-11.489697, 11.489697, -11.489697],
-    [-15.202538, 10.295564, -7.884995],
-    [-15.202538, 10.295564, -7.884995],
-    [-16.36011, 10.17324, -5.324711],
-    [-15.413083, 10.677339, -6.88703],
-    [-15.943033, 10.603945, -5.6948266],
-    [-15.101833, 11.383239, -6.478384],
-    [-15.265717, 11.597614, -5.625],
-    [-15.611464, 10.873316, -6.0409284],
-    [-15.5581255, 10.797956, -6.281459],
-    [-16.49668, 10.388045, -4.375],
-    [-16.577555, 10.706492, -3.1002922],
-    [-16.2816, 11.294578, -2.6671536],
-    [-16.090162, 11.013046, -4.375],
-    [-16.201096, 11.177487, -3.500487],
-    [-15.402763, 11.948149, -4.3575616],
-    [-15.832608, 11.776747, -3.1002922],
-    [-16.098623, 11.213018, -3.6652985],
-    [-16.810333, 10.625, -1.875],
-    [-16.903101, 10.625, -0.625],
-    [-16.332794, 11.366785, -1.875],
-    [-16.379942, 11.43328, -0.625],
-    [-15.866021, 11.991785, -1.875],
-    [-15.916279, 12.05828, -0.625],
-    [-16.368078, 11.350021, -1.25],
-    [-15.051056, 12.560555, -3.9292688],
-    [-15.139938, 12.665969, -3.125],
-    [-15.101846, 12.620791, -3.4696867],
-    [-15.241021, 12.785843, -1.875],
-    [-15.291278, 12.845437, -0.625],
-    [-15.2661495, 12.8156395, -1.25],
-    [-15.993176, 11.594834, -2.508551],
-    [-14.121927, 10.603945, -9.334143],
-    [-14.7412815, 10.677339, -8.230997],
-    [-13.842153, 11.361135, -8.890239],
-    [-14.06489, 11.629698, -8.125],
-    [-13.553721, 11.006574, -9.723573],
-    [-13.097248, 11.843793, -9.334767],
-    [-13.528498, 12.254698, -8.125],
-    [-13.84869, 11.336996, -8.822524],
-    [-14.911034, 11.128898, -7.3117175],
-    [-14.422244, 11.930851, -6.9704394],
-    [-14.771767, 12.222614, -5.625],
-    [-13.730554, 12.478462, -7.4624944],
-    [-14.4715805, 11.956102, -6.7903295],
-    [-12.640775, 12.640775, -8.945962],
-    [-12.903498, 12.903497, -8.125],
-    [-12.790902, 12.790902, -8.476841],
-    [-13.933454, 12.700542, -6.6291614],
-    [-14.146767, 12.933707, -5.625],
-    [-13.2155485, 13.2155485, -7.045828],
-    [-13.558707, 13.558707, -5.625],
-    [-12.700542, 13.933454, -6.6291614],
-    [-12.933707, 14.146767, -5.625],
-    [-13.406334, 13.406334, -6.231247],
-    [-12.326451, 12.326451, -9.779296],
-    [-12.326451, 12.326451, -9.779296],
-    [-11.843793, 13.097248, -9.334767],
-    [-12.254698, 13.528497, -8.125],
-    [-11.361135, 13.842153, -8.890239],
-    [-11.629698, 14.06489, -8.125],
-    [-11.006574, 13.553721, -9.723573],
-    [-10.603945, 14.121927, -9.334143],
-    [-10.677339, 14.7412815, -8.230997],
-    [-11.336996, 13.848691, -8.822524],
-    [-12.478462, 13.730554, -7.4624944],
-    [-11.93085, 14.422244, -6.9704394],
-    [-12.222614, 14.771767, -5.625],
-    [-11.128898, 14.911034, -7.3117175],
-    [-11.956101, 14.471581, -6.7903295],
-    [-12.910169, 12.910169, -7.81641],
-    [-14.928902, 12.413581, -4.7626023],
-    [-14.928902, 12.413581, -4.7626023],
-    [-14.385528, 13.135811, -4.4054027],
-    [-14.581705, 13.290969, -3.125],
-    [-13.842153, 13.842153, -4.048203],
-    [-13.956705, 13.956705, -3.125],
-    [-13.711067, 13.711067, -4.881536],
-    [-13.135811, 14.385528, -4.4054027],
-    [-13.290969, 14.581705, -3.125],
-    [-13.83684, 13.83684, -3.905135],
-    [-14.690951, 13.410843, -1.875],
-    [-14.745259, 13.470437, -0.625],
-    [-14.065951, 14.065951, -1.875],
-    [-14.120259, 14.120259, -0.625],
-    [-13.410843, 14.690951, -1.875],
-    [-13.470437, 14.745259, -0.625],
-    [-14.08395, 14.08395, -1.25],
-    [-12.413581, 14.928902, -4.7626023],
-    [-12.413581, 14.928902, -4.7626023],
-    [-13.919125, 13.919125, -2.9288278],
-    [-10.295564, 15.202538, -7.884995],
-    [-10.295564, 15.202538, -7.884995],
-    [-11.383239, 15.101833, -6.478384],
-    [-11.597614, 15.265717, -5.625],
-    [-10.677339, 15.413083, -6.88703],
-    [-10.603945, 15.943031, -5.6948266],
-    [-10.17324, 16.36011, -5.324711],
-    [-10.873316, 15.611463, -6.040929],
-    [-10.797956, 15.558124, -6.281459],
-    [-12.560555, 15.051056, -3.9292688],
-    [-12.665969, 15.139938, -3.125],
-    [-12.620791, 15.101846, -3.4696867],
-    [-12.785843, 15.241021, -1.875],
-    [-12.845437, 15.291278, -0.625],
-    [-12.8156395, 15.2661495, -1.25],
-    [-11.948149, 15.402763, -4.3575616],
-    [-11.776747, 15.832608, -3.1002922],
-    [-11.294578, 16.2816, -2.6671536],
-    [-11.013045, 16.090162, -4.375],
-    [-11.177487, 16.201096, -3.500487],
-    [-10.388045, 16.49668, -4.375],
-    [-10.706492, 16.577555, -3.1002922],
-    [-11.2130165, 16.098623, -3.6652985],
-    [-11.991785, 15.866021, -1.875],
-    [-12.058281, 15.916278, -0.625],
-    [-11.366785, 16.332794, -1.875],
-    [-11.433281, 16.379942, -0.625],
-    [-10.625, 16.810335, -1.875],
-    [-10.625, 16.903103, -0.625],
-    [-11.350021, 16.368078, -1.25],
-    [-11.594833, 15.993178, -2.508551],
-    [-13.434792, 13.434792, -4.866052],
-    [-9.189424, 0.625, -17.730347],
-    [-9.106327, 1.875, -17.686771],
-    [-8.125, 0.625, -18.239578],
-    [-8.125, 1.875, -18.153526],
-    [-8.636437, 1.25, -17.952557],
-    [-9.814424, 0.625, -17.398159],
-    [-9.731327, 1.875, -17.35254],
-    [-9.772875, 1.25, -17.375349],
-    [-8.939161, 3.125, -17.599136],
-    [-8.8062525, 3.8696263, -17.529474],
-    [-8.125, 3.125, -17.98042],
-    [-8.154599, 4.321776, -17.719128],
-    [-8.465525, 3.63861, -17.718893],
-    [-9.564161, 3.125, -17.260742],
-    [-9.2837515, 4.321776, -17.152058],
-    [-8.590999, 4.7029595, -17.424526],
-    [-9.204034, 4.0181465, -17.256403],
-    [-6.564165, 0.625, -18.863594],
-    [-7.189165, 0.625, -18.634232],
-    [-6.450191, 1.875, -18.822376],
-    [-7.075191, 1.875, -18.591757],
-    [-5.625, 0.625, -19.160631],
-    [-5.625, 1.875, -19.078878],
-    [-6.421452, 1.25, -18.858578],
-    [-6.3264184, 2.6671536, -18.777624],
-    [-6.795851, 3.1002922, -18.529839],
-    [-6.875, 4.375, -18.239578],
-    [-5.6671567, 3.1002922, -18.906551],
-    [-6.1119275, 3.500487, -18.708017],
-    [-5.2785945, 4.107494, -18.83258],
-    [-5.6671567, 4.464496, -18.63184],
-    [-6.117916, 3.6303859, -18.651934],
-    [-7.5738397, 2.5777528, -18.189259],
-    [-7.7576656, 5.3707347, -17.617271],
-    [-7.7576656, 5.3707347, -17.617271],
-    [-9.467094, 5.6948266, -16.642796],
-    [-9.945158, 6.1580443, -16.217432],
-    [-9.063559, 6.875, -16.42622],
-    [-9.688559, 6.875, -16.064978],
-    [-8.154599, 5.7224407, -17.317713],
-    [-8.125, 6.875, -16.903101],
-    [-9.018097, 6.3224187, -16.641283],
-    [-8.8062525, 7.564785, -16.2816],
-    [-9.38943, 8.141557, -15.6358595],
-    [-9.139274, 9.139274, -15.235847],
-    [-8.038871, 8.038871, -16.428444],
-    [-8.398118, 8.398118, -16.069181],
-    [-7.564785, 8.8062525, -16.2816],
-    [-8.141557, 9.38943, -15.6358595],
-    [-8.520881, 8.520882, -15.90956],
-    [-6.977496, 5.7224407, -17.823565],
-    [-6.6291614, 6.6291614, -17.646322],
-    [-5.625, 5.625, -18.325289],
-    [-5.7224407, 6.977496, -17.823565],
-    [-6.228657, 6.228657, -17.91034],
-    [-7.4624944, 6.2040677, -17.48497],
-    [-6.977496, 6.977496, -17.372486],
-    [-6.2040677, 7.4624944, -17.48497],
-    [-6.8988338, 6.8988338, -17.43384],
-    [-5.3707347, 7.7576656, -17.617271],
-    [-5.3707347, 7.7576656, -17.617271],
-    [-6.875, 8.125, -16.903101],
-    [-6.875, 9.063559, -16.42622],
-    [-6.875, 9.688559, -16.064978],
-    [-5.7224407, 8.154599, -17.317713],
-    [-5.6948266, 9.467094, -16.642796],
-    [-6.1580443, 9.945158, -16.217432],
-    [-6.3224187, 9.018097, -16.641281],
-    [-7.5378585, 7.5378585, -16.773388],
-    [-9.972607, 8.718328, -14.982282],
-    [-9.483564, 9.483564, -14.806005],
-    [-8.718328, 9.972607, -14.982282],
-    [-9.408239, 9.408238, -14.902155],
-    [-9.408239, 9.408238, -14.902155],
-    [-4.375, 0.625, -19.484573],
-    [-4.375, 1.875, -19.404057],
-    [-3.125, 0.625, -19.724207],
-    [-3.125, 1.875, -19.644653],
-    [-3.75, 1.25, -19.564373],
-    [-4.375, 3.125, -19.24208],
-    [-4.464496, 4.464496, -18.95577],
-    [-4.940827, 4.940827, -18.736036],
-    [-3.125, 3.125, -19.484573],
-    [-3.125, 4.375, -19.24208],
-    [-3.9822478, 3.9822478, -19.143095],
-    [-1.875, 0.625, -19.88232],
-    [-1.875, 1.875, -19.803429],
-    [-0.625, 0.625, -19.960873],
-    [-0.625, 1.875, -19.88232],
-    [-1.25, 1.25, -19.882236],
-    [-1.875, 3.125, -19.644653],
-    [-1.875, 4.375, -19.404055],
-    [-0.625, 3.125, -19.724205],
-    [-0.625, 4.375, -19.484573],
-    [-1.25, 3.75, -19.564371],
-    [-2.6418376, 2.6418376, -19.515259],
-    [-4.107494, 5.2785945, -18.83258],
-    [-4.464496, 5.6671567, -18.63184],
-    [-4.375, 6.875, -18.239576],
-    [-3.1002922, 5.6671567, -18.906551],
-    [-3.500487, 6.1119275, -18.708017],
-    [-2.6671536, 6.3264184, -18.777624],
-    [-3.1002922, 6.795851, -18.529837],
-    [-3.6303864, 6.1179156, -18.651932],
-    [-4.321776, 8.154599, -17.719128],
-    [-3.8696263, 8.8062525, -17.529474],
-    [-3.125, 8.125, -17.980421],
-    [-3.125, 8.939161, -17.599136],
-    [-3.63861, 8.465525, -17.718895],
-    [-4.7029595, 8.590999, -17.424526],
-    [-4.321776, 9.2837515, -17.15206],
-    [-3.125, 9.564161, -17.260742],
-    [-4.0181465, 9.204033, -17.256403],
-    [-1.875, 5.625, -19.078878],
-    [-1.875, 6.450191, -18.822376],
-    [-1.875, 7.075191, -18.591757],
-    [-0.625, 5.625, -19.160633],
-    [-0.625, 6.564165, -18.863594],
-    [-0.625, 7.189165, -18.634232],
-    [-1.25, 6.421452, -18.858578],
-    [-1.875, 8.125, -18.153526],
-    [-1.875, 9.106327, -17.686771],
-    [-0.625, 8.125, -18.239578],
-    [-0.625, 9.189424, -17.730347],
-    [-1.25, 8.636437, -17.952557],
-    [-1.875, 9.731327, -17.35254],
-    [-0.625, 9.814424, -17.398159],
-    [-1.25, 9.772875, -17.375349],
-    [-2.5777533, 7.5738397, -18.189259],
-    [-5.4766984, 5.476698, -17.924301],
-    [-7.884995, 10.295564, -15.202538],
-    [-7.884995, 10.295564, -15.202538],
-    [-6.88703, 10.677339, -15.413083],
-    [-6.478384, 11.383239, -15.101833],
-    [-5.324711, 10.17324, -16.36011],
-    [-5.6948266, 10.603945, -15.943033],
-    [-5.625, 11.597614, -15.265717],
-    [-6.040929, 10.873316, -15.611463],
-    [-6.281459, 10.797956, -15.558124],
-    [-9.334143, 10.603945, -14.121927],
-    [-9.723573, 11.006574, -13.553721],
-    [-8.890239, 11.361135, -13.842153],
-    [-9.334767, 11.843793, -13.097248],
-    [-8.230997, 10.677339, -14.7412815],
-    [-8.125, 11.629698, -14.06489],
-    [-8.125, 12.254698, -13.528498],
-    [-8.822525, 11.336995, -13.84869],
-    [-9.779296, 12.326451, -12.326451],
-    [-9.779296, 12.326451, -12.326451],
-    [-8.945962, 12.640775, -12.640775],
-    [-8.125, 12.903498, -12.903498],
-    [-8.476841, 12.790902, -12.790902],
-    [-9.334767, 13.097248, -11.843793],
-    [-9.723573, 13.553721, -11.006574],
-    [-8.890239, 13.842153, -11.361135],
-    [-9.334143, 14.121927, -10.603945],
-    [-8.125, 13.528497, -12.254698],
-    [-8.125, 14.06489, -11.629698],
-    [-8.230997, 14.7412815, -10.677339],
-    [-8.822525, 13.848691, -11.336995],
-    [-7.3117175, 11.128898, -14.911034],
-    [-6.9704394, 11.93085, -14.422244],
-    [-7.4624944, 12.478462, -13.730554],
-    [-5.625, 12.222614, -14.771767],
-    [-6.790329, 11.956102, -14.471581],
-    [-6.6291614, 12.700542, -13.933454],
-    [-7.045828, 13.2155485, -13.2155485],
-    [-6.6291614, 13.933454, -12.700542],
-    [-5.625, 12.933707, -14.146767],
-    [-5.625, 13.558707, -13.558707],
-    [-5.625, 14.146767, -12.933707],
-    [-6.231247, 13.406334, -13.406334],
-    [-7.4624944, 13.730554, -12.478462],
-    [-6.9704394, 14.422244, -11.93085],
-    [-7.3117175, 14.911034, -11.128898],
-    [-5.625, 14.771767, -12.222614],
-    [-6.790329, 14.4715805, -11.956102],
-    [-7.816411, 12.910168, -12.910168],
-    [-7.884995, 15.202538, -10.295564],
-    [-7.884995, 15.202538, -10.295564],
-    [-6.478384, 15.101833, -11.383239],
-    [-6.88703, 15.413081, -10.677339],
-    [-5.625, 15.265716, -11.597614],
-    [-5.6948266, 15.943033, -10.603945],
-    [-5.324711, 16.36011, -10.17324],
-    [-6.040929, 15.611463, -10.873316],
-    [-6.281459, 15.558124, -10.797956],
-    [-4.375, 10.388045, -16.49668],
-    [-4.375, 11.013046, -16.090162],
-    [-4.3575616, 11.948149, -15.402763],
-    [-3.1002922, 10.706492, -16.577555],
-    [-3.500487, 11.177487, -16.201096],
-    [-2.6671536, 11.294578, -16.2816],
-    [-3.1002922, 11.776747, -15.832608],
-    [-3.6652985, 11.2130165, -16.098625],
-    [-3.9292688, 12.560555, -15.051056],
-    [-3.125, 12.665969, -15.139938],
-    [-3.4696867, 12.620791, -15.101846],
-    [-1.875, 10.625, -16.810333],
-    [-1.875, 11.366784, -16.332794],
-    [-1.875, 11.991785, -15.866021],
-    [-0.625, 10.625, -16.903101],
-    [-0.625, 11.433281, -16.379944],
-    [-0.625, 12.05828, -15.916279],
-    [-1.25, 11.350021, -16.368078],
-    [-1.875, 12.785843, -15.241021],
-    [-0.625, 12.845437, -15.291279],
-    [-1.25, 12.8156395, -15.2661495],
-    [-2.508551, 11.594832, -15.993176],
-    [-4.7626023, 12.413581, -14.928902],
-    [-4.7626023, 12.413581, -14.928902],
-    [-4.4054027, 13.135811, -14.385528],
-    [-4.881536, 13.711067, -13.711067],
-    [-4.048203, 13.842153, -13.842153],
-    [-4.4054027, 14.385528, -13.135811],
-    [-3.125, 13.290969, -14.581705],
-    [-3.125, 13.956705, -13.956705],
-    [-3.125, 14.581705, -13.290969],
-    [-3.905135, 13.836841, -13.83684],
-    [-4.7626023, 14.928902, -12.413581],
-    [-4.7626023, 14.928902, -12.413581],
-    [-1.875, 13.410843, -14.690951],
-    [-1.875, 14.065951, -14.065951],
-    [-1.875, 14.690951, -13.410843],
-    [-0.625, 13.470437, -14.745259],
-    [-0.625, 14.120259, -14.120259],
-    [-0.625, 14.745259, -13.470437],
-    [-1.25, 14.08395, -14.08395],
-    [-2.928828, 13.919124, -13.919124],
-    [-3.9292688, 15.051056, -12.560555],
-    [-3.125, 15.139938, -12.665969],
-    [-3.4696867, 15.101846, -12.620791],
-    [-4.3575616, 15.402763, -11.948149],
-    [-4.375, 16.090162, -11.013045],
-    [-4.375, 16.49668, -10.388045],
-    [-3.1002922, 15.832608, -11.776747],
-    [-3.500487, 16.201096, -11.177487],
-    [-2.6671536, 16.2816, -11.294578],
-    [-3.1002922, 16.577555, -10.706492],
-    [-3.6652985, 16.098625, -11.2130165],
-    [-1.875, 15.241021, -12.785843],
-    [-0.625, 15.291278, -12.845437],
-    [-1.25, 15.2661495, -12.8156395],
-    [-1.875, 15.866021, -11.991785],
-    [-1.875, 16.332794, -11.366785],
-    [-1.875, 16.810335, -10.625],
-    [-0.625, 15.916278, -12.058281],
-    [-0.625, 16.379942, -11.433281],
-    [-0.625, 16.903103, -10.625],
-    [-1.25, 16.368078, -11.350021],
-    [-2.508551, 15.993178, -11.594833],
-    [-4.866053, 13.434792, -13.434791],
-    [-9.483564, 14.806005, -9.483564],
-    [-9.972607, 14.982282, -8.718328],
-    [-8.718328, 14.982282, -9.972607],
-    [-9.408239, 14.902155, -9.408238],
-    [-9.408239, 14.902155, -9.408238],
-    [-9.139274, 15.235847, -9.139274],
-    [-9.38943, 15.6358595, -8.141557],
-    [-8.8062525, 16.2816, -7.564785],
-    [-8.141557, 15.6358595, -9.38943],
-    [-8.398118, 16.069181, -8.398118],
-    [-7.564785, 16.2816, -8.8062525],
-    [-8.038871, 16.428442, -8.038871],
-    [-8.520881, 15.909558, -8.520881],
-    [-9.688559, 16.064978, -6.875],
-    [-9.945158, 16.217432, -6.1580443],
-    [-9.063559, 16.42622, -6.875],
-    [-9.467094, 16.642796, -5.6948266],
-    [-8.125, 16.903101, -6.875],
-    [-8.154599, 17.317713, -5.7224407],
-    [-9.018097, 16.641281, -6.3224187],
-    [-7.7576656, 17.617271, -5.3707347],
-    [-7.7576656, 17.617271, -5.3707347],
-    [-6.875, 16.064978, -9.688559],
-    [-6.875, 16.42622, -9.063559],
-    [-6.875, 16.9031, -8.125],
-    [-6.1580443, 16.217432, -9.945158],
-    [-5.6948266, 16.642797, -9.467094],
-    [-5.7224407, 17.317713, -8.154599],
-    [-6.3224187, 16.641281, -9.018096],
-    [-6.977496, 17.372486, -6.977496],
-    [-7.4624944, 17.48497, -6.2040677],
-    [-6.2040677, 17.48497, -7.4624944],
-    [-6.8988338, 17.43384, -6.8988338],
-    [-5.3707347, 17.617271, -7.7576656],
-    [-5.3707347, 17.617271, -7.7576656],
-    [-6.6291614, 17.646322, -6.6291614],
-    [-6.977496, 17.823565, -5.7224407],
-    [-5.7224407, 17.823565, -6.977496],
-    [-5.625, 18.325289, -5.625],
-    [-6.228657, 17.91034, -6.228657],
-    [-7.5378575, 16.773388, -7.5378575],
-    [-9.2837515, 17.15206, -4.321776],
-    [-9.564161, 17.260742, -3.125],
-    [-8.590999, 17.424526, -4.7029595],
-    [-9.204034, 17.256403, -4.0181465],
-    [-9.731327, 17.35254, -1.875],
-    [-9.8144245, 17.398159, -0.625],
-    [-9.772876, 17.375349, -1.25],
-    [-8.8062525, 17.529474, -3.8696263],
-    [-8.939161, 17.599136, -3.125],
-    [-8.154599, 17.719128, -4.321776],
-    [-8.125, 17.98042, -3.125],
-    [-8.465525, 17.718893, -3.63861],
-    [-9.106327, 17.686771, -1.875],
-    [-9.189424, 17.730347, -0.625],
-    [-8.125, 18.153526, -1.875],
-    [-8.125, 18.239578, -0.625],
-    [-8.636437, 17.952557, -1.25],
-    [-6.875, 18.239578, -4.375],
-    [-6.795851, 18.529837, -3.1002922],
-    [-6.3264184, 18.777624, -2.6671536],
-    [-5.6671567, 18.63184, -4.464496],
-    [-6.1119275, 18.708017, -3.500487],
-    [-5.2785945, 18.83258, -4.107494],
-    [-5.6671567, 18.906551, -3.1002922],
-    [-6.117916, 18.651934, -3.6303859],
-    [-7.075191, 18.591757, -1.875],
-    [-7.189165, 18.634232, -0.625],
-    [-6.450191, 18.822376, -1.875],
-    [-6.564165, 18.863594, -0.625],
-    [-5.625, 19.078878, -1.875],
-    [-5.625, 19.160633, -0.625],
-    [-6.421452, 18.858578, -1.25],
-    [-7.5738397, 18.189257, -2.5777528],
-    [-4.321776, 17.15206, -9.2837515],
-    [-4.7029595, 17.424526, -8.590999],
-    [-3.125, 17.260742, -9.564161],
-    [-4.0181465, 17.256403, -9.204033],
-    [-3.8696263, 17.529474, -8.8062525],
-    [-4.321776, 17.719128, -8.154599],
-    [-3.125, 17.599136, -8.939161],
-    [-3.125, 17.980421, -8.125],
-    [-3.63861, 17.718893, -8.465525],
-    [-4.375, 18.239576, -6.875],
-    [-4.464496, 18.63184, -5.6671567],
-    [-4.107494, 18.83258, -5.2785945],
-    [-3.1002922, 18.529837, -6.795851],
-    [-3.500487, 18.708017, -6.1119275],
-    [-2.6671536, 18.777624, -6.3264184],
-    [-3.1002922, 18.906551, -5.6671567],
-    [-3.6303864, 18.651932, -6.1179156],
-    [-1.875, 17.35254, -9.731327],
-    [-0.625, 17.398159, -9.8144245],
-    [-1.25, 17.375349, -9.772876],
-    [-1.875, 17.686771, -9.106327],
-    [-1.875, 18.153528, -8.125],
-    [-0.625, 17.730347, -9.1894245],
-    [-0.625, 18.239578, -8.125],
-    [-1.25, 17.952557, -8.636438],
-    [-1.875, 18.591757, -7.075191],
-    [-1.875, 18.822376, -6.450191],
-    [-1.875, 19.078878, -5.625],
-    [-0.625, 18.634232, -7.189165],
-    [-0.625, 18.863594, -6.564165],
-    [-0.625, 19.160631, -5.625],
-    [-1.25, 18.858578, -6.421452],
-    [-2.5777533, 18.189257, -7.5738397],
-    [-4.940827, 18.736036, -4.940827],
-    [-4.464496, 18.95577, -4.464496],
-    [-4.375, 19.24208, -3.125],
-    [-3.125, 19.24208, -4.375],
-    [-3.125, 19.484573, -3.125],
-    [-3.9822478, 19.143095, -3.9822478],
-    [-4.375, 19.404055, -1.875],
-    [-4.375, 19.484573, -0.625],
-    [-3.125, 19.644653, -1.875],
-    [-3.125, 19.724205, -0.625],
-    [-3.75, 19.564371, -1.25],
-    [-1.875, 19.404057, -4.375],
-    [-1.875, 19.644651, -3.125],
-    [-0.625, 19.484573, -4.375],
-    [-0.625, 19.724205, -3.125],
-    [-1.25, 19.564371, -3.75],
-    [-1.875, 19.803429, -1.875],
-    [-1.875, 19.882318, -0.625],
-    [-0.625, 19.88232, -1.875],
-    [-0.625, 19.960873, -0.625],
-    [-1.25, 19.882236, -1.25],
-    [-2.6418376, 19.515259, -2.6418376],
-    [-5.476698, 17.9243, -5.476698],
-    [-10.126742, 10.126742, -10.126742],
-    [-19.960873, 0.625, 0.625],
-    [-19.88232, 0.625, 1.875],
-    [-19.88232, 1.875, 0.625],
-    [-19.803429, 1.875, 1.875],
-    [-19.882236, 1.25, 1.25],
-    [-19.724207, 0.625, 3.125],
-    [-19.484573, 0.625, 4.375],
-    [-19.644653, 1.875, 3.125],
-    [-19.404057, 1.875, 4.375],
-    [-19.564373, 1.25, 3.75],
-    [-19.724205, 3.125, 0.625],
-    [-19.644653, 3.125, 1.875],
-    [-19.484573, 4.375, 0.625],
-    [-19.404055, 4.375, 1.875],
-    [-19.564371, 3.75, 1.25],
-    [-19.484573, 3.125, 3.125],
-    [-19.24208, 3.125, 4.375],
-    [-19.24208, 4.375, 3.125],
-    [-18.95577, 4.464496, 4.464496],
-    [-18.736036, 4.940827, 4.940827],
-    [-19.143095, 3.9822478, 3.9822478],
-    [-19.515259, 2.6418376, 2.6418376],
-    [-19.160631, 0.625, 5.625],
-    [-18.863594, 0.625, 6.564165],
-    [-19.078878, 1.875, 5.625],
-    [-18.822376, 1.875, 6.450191],
-    [-18.634232, 0.625, 7.189165],
-    [-18.591757, 1.875, 7.075191],
-    [-18.858578, 1.25, 6.421452],
-    [-18.239578, 0.625, 8.125],
-    [-17.730347, 0.625, 9.189424],
-    [-18.153526, 1.875, 8.125],
-    [-17.686771, 1.875, 9.106327],
-    [-17.952557, 1.25, 8.636437],
-    [-18.906551, 3.1002922, 5.6671567],
-    [-18.777624, 2.6671536, 6.3264184],
-    [-18.83258, 4.107494, 5.2785945],
-    [-18.708017, 3.500487, 6.1119275],
-    [-18.529839, 3.1002922, 6.795851],
-    [-18.63184, 4.464496, 5.6671567],
-    [-18.239578, 4.375, 6.875],
-    [-18.651934, 3.6303859, 6.1179156],
-    [-17.98042, 3.125, 8.125],
-    [-17.599136, 3.125, 8.939161],
-    [-17.719128, 4.321776, 8.154599],
-    [-17.529474, 3.8696263, 8.8062525],
-    [-17.718893, 3.63861, 8.465525],
-    [-17.398159, 0.625, 9.814424],
-    [-17.35254, 1.875, 9.731327],
-    [-17.375349, 1.25, 9.772875],
-    [-17.260742, 3.125, 9.564161],
-    [-17.424526, 4.7029595, 8.590999],
-    [-17.152058, 4.321776, 9.2837515],
-    [-17.256403, 4.0181465, 9.204033],
-    [-18.189259, 2.5777528, 7.573839],
-    [-19.160633, 5.625, 0.625],
-    [-19.078878, 5.625, 1.875],
-    [-18.863594, 6.564165, 0.625],
-    [-18.822376, 6.450191, 1.875],
-    [-18.634232, 7.189165, 0.625],
-    [-18.591757, 7.075191, 1.875],
-    [-18.858578, 6.421452, 1.25],
-    [-18.906551, 5.6671567, 3.1002922],
-    [-18.83258, 5.2785945, 4.107494],
-    [-18.777624, 6.3264184, 2.6671536],
-    [-18.708017, 6.1119275, 3.500487],
-    [-18.63184, 5.6671567, 4.464496],
-    [-18.529837, 6.795851, 3.1002922],
-    [-18.239576, 6.875, 4.375],
-    [-18.651932, 6.1179156, 3.6303859],
-    [-18.239578, 8.125, 0.625],
-    [-18.153526, 8.125, 1.875],
-    [-17.730347, 9.189424, 0.625],
-    [-17.686771, 9.106327, 1.875],
-    [-17.952557, 8.636437, 1.25],
-    [-17.980421, 8.125, 3.125],
-    [-17.719128, 8.154599, 4.321776],
-    [-17.599136, 8.939161, 3.125],
-    [-17.529474, 8.8062525, 3.8696263],
-    [-17.718895, 8.465525, 3.63861],
-    [-17.398159, 9.814424, 0.625],
-    [-17.35254, 9.731327, 1.875],
-    [-17.375349, 9.772875, 1.25],
-    [-17.424526, 8.590999, 4.7029595],
-    [-17.260742, 9.564161, 3.125],
-    [-17.15206, 9.2837515, 4.321776],
-    [-17.256403, 9.204034, 4.0181465],
-    [-18.189259, 7.573839, 2.5777528],
-    [-18.325289, 5.625, 5.625],
-    [-17.823565, 5.7224407, 6.977496],
-    [-17.823565, 6.977496, 5.7224407],
-    [-17.646322, 6.6291614, 6.6291614],
-    [-17.91034, 6.228657, 6.228657],
-    [-17.617271, 5.3707347, 7.7576656],
-    [-17.617271, 5.3707347, 7.7576656],
-    [-17.617271, 7.7576656, 5.3707347],
-    [-17.617271, 7.7576656, 5.3707347],
-    [-17.48497, 6.2040677, 7.4624944],
-    [-17.48497, 7.4624944, 6.2040677],
-    [-17.372486, 6.977496, 6.977496],
-    [-17.43384, 6.8988338, 6.8988338],
-    [-17.317713, 5.7224407, 8.154599],
-    [-16.642796, 5.6948266, 9.467094],
-    [-16.903101, 6.875, 8.125],
-    [-16.42622, 6.875, 9.063559],
-    [-16.217432, 6.1580443, 9.945158],
-    [-16.064978, 6.875, 9.688559],
-    [-16.641281, 6.3224187, 9.018096],
-    [-17.317713, 8.154599, 5.7224407],
-    [-16.903101, 8.125, 6.875],
-    [-16.642796, 9.467094, 5.6948266],
-    [-16.42622, 9.063559, 6.875],
-    [-16.217432, 9.945158, 6.1580443],
-    [-16.064978, 9.688559, 6.875],
-    [-16.641281, 9.018096, 6.3224187],
-    [-16.428444, 8.038871, 8.038871],
-    [-16.2816, 7.564785, 8.8062525],
-    [-16.2816, 8.8062525, 7.564785],
-    [-16.069181, 8.398118, 8.398118],
-    [-15.6358595, 8.141557, 9.38943],
-    [-15.6358595, 9.38943, 8.141557],
-    [-15.235847, 9.139274, 9.139274],
-    [-15.909558, 8.520882, 8.520882],
-    [-16.773388, 7.537858, 7.537858],
-    [-14.982282, 8.718328, 9.972607],
-    [-14.982282, 9.972607, 8.718328],
-    [-14.806005, 9.483564, 9.483564],
-    [-14.902155, 9.408239, 9.408238],
-    [-14.902155, 9.408239, 9.408238],
-    [-17.9243, 5.476698, 5.476698],
-    [-16.903103, 0.625, 10.625],
-    [-16.379942, 0.625, 11.43328],
-    [-16.810335, 1.875, 10.625],
-    [-16.332794, 1.875, 11.366785],
-    [-15.916278, 0.625, 12.058281],
-    [-15.866021, 1.875, 11.991784],
-    [-16.368078, 1.25, 11.350021],
-    [-15.291278, 0.625, 12.845437],
-    [-15.241021, 1.875, 12.785843],
-    [-15.2661495, 1.25, 12.8156395],
-    [-16.577555, 3.1002922, 10.706492],
-    [-16.2816, 2.6671536, 11.294578],
-    [-16.49668, 4.375, 10.388045],
-    [-16.201096, 3.500487, 11.177487],
-    [-15.832608, 3.1002922, 11.776747],
-    [-16.090162, 4.375, 11.013046],
-    [-15.402763, 4.3575616, 11.948149],
-    [-16.098625, 3.6652985, 11.213018],
-    [-15.139938, 3.125, 12.665969],
-    [-15.051056, 3.9292688, 12.560555],
-    [-15.101846, 3.4696867, 12.620791],
-    [-15.993178, 2.508551, 11.594833],
-    [-16.36011, 5.324711, 10.17324],
-    [-15.943033, 5.6948266, 10.603945],
-    [-15.265717, 5.625, 11.597614],
-    [-15.413083, 6.88703, 10.677339],
-    [-15.101833, 6.478384, 11.383239],
-    [-15.611464, 6.0409284, 10.873316],
-    [-15.202538, 7.884995, 10.295564],
-    [-15.202538, 7.884995, 10.295564],
-    [-15.5581255, 6.281459, 10.797956],
-    [-14.745259, 0.625, 13.470437],
-    [-14.120259, 0.625, 14.120259],
-    [-14.690951, 1.875, 13.410843],
-    [-14.065951, 1.875, 14.065951],
-    [-13.470437, 0.625, 14.745259],
-    [-13.410843, 1.875, 14.690951],
-    [-14.08395, 1.25, 14.08395],
-    [-14.928902, 4.7626023, 12.413581],
-    [-14.928902, 4.7626023, 12.413581],
-    [-14.581705, 3.125, 13.290969],
-    [-13.956705, 3.125, 13.956705],
-    [-14.385528, 4.4054027, 13.135811],
-    [-13.842153, 4.048203, 13.842153],
-    [-13.290969, 3.125, 14.581705],
-    [-13.711067, 4.881536, 13.711067],
-    [-13.135811, 4.4054027, 14.385528],
-    [-13.83684, 3.9051352, 13.836841],
-    [-12.413581, 4.7626023, 14.928902],
-    [-12.413581, 4.7626023, 14.928902],
-    [-13.919124, 2.928828, 13.919125],
-    [-12.845437, 0.625, 15.291278],
-    [-12.785843, 1.875, 15.241021],
-    [-12.8156395, 1.25, 15.2661495],
-    [-12.665969, 3.125, 15.139938],
-    [-12.560555, 3.9292688, 15.051056],
-    [-12.620791, 3.4696867, 15.101846],
-    [-12.058281, 0.625, 15.916278],
-    [-11.433281, 0.625, 16.379942],
-    [-11.991785, 1.875, 15.866021],
-    [-11.366785, 1.875, 16.332794],
-    [-10.625, 0.625, 16.903101],
-    [-10.625, 1.875, 16.810333],
-    [-11.350021, 1.25, 16.368078],
-    [-11.776747, 3.1002922, 15.832608],
-    [-11.294578, 2.6671536, 16.2816],
-    [-11.948149, 4.3575616, 15.402763],
-    [-11.177487, 3.500487, 16.201096],
-    [-10.706492, 3.1002922, 16.577555],
-    [-11.013045, 4.375, 16.090162],
-    [-10.388045, 4.375, 16.49668],
-    [-11.2130165, 3.6652985, 16.098625],
-    [-11.594833, 2.508551, 15.993178],
-    [-14.771767, 5.625, 12.222614],
-    [-14.911034, 7.3117175, 11.128898],
-    [-14.422244, 6.9704385, 11.93085],
-    [-13.730554, 7.4624944, 12.478462],
-    [-14.4715805, 6.790329, 11.956102],
-    [-14.146767, 5.625, 12.933707],
-    [-13.933454, 6.6291614, 12.700542],
-    [-13.558707, 5.625, 13.558707],
-    [-12.933707, 5.625, 14.146767],
-    [-13.2155485, 7.0458274, 13.2155485],
-    [-12.700542, 6.6291614, 13.933454],
-    [-13.406333, 6.231247, 13.406334],
-    [-14.7412815, 8.230997, 10.677339],
-    [-14.06489, 8.125, 11.629698],
-    [-14.121927, 9.334143, 10.603945],
-    [-13.842153, 8.890239, 11.361135],
-    [-13.528498, 8.125, 12.254698],
-    [-13.553721, 9.723573, 11.006574],
-    [-13.097249, 9.334767, 11.843793],
-    [-13.84869, 8.822524, 11.336996],
-    [-12.903498, 8.125, 12.903497],
-    [-12.640775, 8.945962, 12.640775],
-    [-12.790902, 8.476841, 12.790902],
-    [-12.222614, 5.625, 14.771767],
-    [-12.478462, 7.4624944, 13.730554],
-    [-11.93085, 6.9704385, 14.422244],
-    [-11.128898, 7.3117175, 14.911034],
-    [-11.956102, 6.790329, 14.471581],
-    [-12.326451, 9.779296, 12.326451],
-    [-12.326451, 9.779296, 12.326451],
-    [-12.254698, 8.125, 13.528498],
-    [-11.629698, 8.125, 14.06489],
-    [-11.843793, 9.334767, 13.097248],
-    [-11.361135, 8.890239, 13.842153],
-    [-10.677339, 8.230997, 14.7412815],
-    [-11.006574, 9.723573, 13.553721],
-    [-10.603945, 9.334143, 14.121927],
-    [-11.336996, 8.822524, 13.848691],
-    [-12.910168, 7.81641, 12.910169],
-    [-11.597614, 5.625, 15.265717],
-    [-11.383239, 6.478384, 15.101833],
-    [-10.603945, 5.6948266, 15.943033],
-    [-10.17324, 5.324711, 16.36011],
-    [-10.677339, 6.88703, 15.413081],
-    [-10.873315, 6.040929, 15.611463],
-    [-10.295564, 7.884995, 15.202538],
-    [-10.295564, 7.884995, 15.202538],
-    [-10.7979555, 6.281459, 15.558124],
-    [-13.434792, 4.866052, 13.434792],
-    [-16.903101, 10.625, 0.625],
-    [-16.810333, 10.625, 1.875],
-    [-16.379942, 11.433281, 0.625],
-    [-16.332794, 11.366784, 1.875],
-    [-15.916279, 12.058281, 0.625],
-    [-15.866021, 11.991784, 1.875],
-    [-16.368078, 11.350021, 1.25],
-    [-16.577555, 10.706492, 3.1002922],
-    [-16.49668, 10.388045, 4.375],
-    [-16.2816, 11.294578, 2.6671536],
-    [-16.201096, 11.177487, 3.500487],
-    [-16.090162, 11.013046, 4.375],
-    [-15.832608, 11.776747, 3.1002922],
-    [-15.402763, 11.948149, 4.3575616],
-    [-16.098623, 11.213018, 3.6652985],
-    [-15.291278, 12.845437, 0.625],
-    [-15.241021, 12.785843, 1.875],
-    [-15.2661495, 12.8156395, 1.25],
-    [-15.139938, 12.665969, 3.125],
-    [-15.051056, 12.560555, 3.9292688],
-    [-15.101846, 12.620791, 3.4696867],
-    [-15.993176, 11.594833, 2.508551],
-    [-16.36011, 10.17324, 5.324711],
-    [-15.943033, 10.603945, 5.6948266],
-    [-15.413083, 10.677339, 6.88703],
-    [-15.265717, 11.597614, 5.625],
-    [-15.101833, 11.383239, 6.478384],
-    [-15.611464, 10.873316, 6.0409284],
-    [-15.202538, 10.295564, 7.884995],
-    [-15.202538, 10.295564, 7.884995],
-    [-15.5581255, 10.797956, 6.281459],
-    [-14.928902, 12.413581, 4.7626023],
-    [-14.928902, 12.413581, 4.7626023],
-    [-14.745259, 13.470437, 0.625],
-    [-14.690951, 13.410843, 1.875],
-    [-14.120259, 14.120259, 0.625],
-    [-14.065951, 14.065951, 1.875],
-    [-13.470437, 14.745259, 0.625],
-    [-13.410843, 14.690951, 1.875],
-    [-14.08395, 14.08395, 1.25],
-    [-14.581705, 13.290969, 3.125],
-    [-14.385528, 13.135811, 4.4054027],
-    [-13.956705, 13.956705, 3.125],
-    [-13.842153, 13.842153, 4.048203],
-    [-13.711067, 13.711067, 4.881536],
-    [-13.290969, 14.581705, 3.125],
-    [-13.135811, 14.385528, 4.4054027],
-    [-13.83684, 13.836841, 3.9051352],
-    [-12.413581, 14.928902, 4.7626023],
-    [-12.413581, 14.928902, 4.7626023],
-    [-13.919125, 13.919125, 2.928828],
-    [-14.911034, 11.128898, 7.3117175],
-    [-14.771767, 12.222614, 5.625],
-    [-14.422244, 11.93085, 6.9704394],
-    [-13.730554, 12.478462, 7.4624944],
-    [-14.4715805, 11.956102, 6.7903295],
-    [-14.7412815, 10.677339, 8.230997],
-    [-14.121927, 10.603945, 9.334143],
-    [-14.06489, 11.629698, 8.125],
-    [-13.842153, 11.361135, 8.890239],
-    [-13.553721, 11.006574, 9.723573],
-    [-13.528498, 12.254698, 8.125],
-    [-13.097248, 11.843793, 9.334767],
-    [-13.84869, 11.336996, 8.822524],
-    [-14.146767, 12.933707, 5.625],
-    [-13.933454, 12.700542, 6.6291614],
-    [-13.558707, 13.558707, 5.625],
-    [-13.2155485, 13.2155485, 7.045828],
-    [-12.933707, 14.146767, 5.625],
-    [-12.700542, 13.933454, 6.6291614],
-    [-13.406333, 13.406334, 6.231247],
-    [-12.903498, 12.903498, 8.125],
-    [-12.640775, 12.640775, 8.945962],
-    [-12.790902, 12.790902, 8.476841],
-    [-12.326451, 12.326451, 9.779296],
-    [-12.326451, 12.326451, 9.779296],
-    [-12.478462, 13.730554, 7.4624944],
-    [-12.222614, 14.771767, 5.625],
-    [-11.93085, 14.422244, 6.9704394],
-    [-11.128898, 14.911034, 7.3117175],
-    [-11.956101, 14.471581, 6.7903295],
-    [-12.254698, 13.528498, 8.125],
-    [-11.843793, 13.097248, 9.334767],
-    [-11.629698, 14.06489, 8.125],
-    [-11.361135, 13.842153, 8.890239],
-    [-11.006574, 13.553721, 9.723573],
-    [-10.677339, 14.7412815, 8.230997],
-    [-10.603945, 14.121927, 9.334143],
-    [-11.336996, 13.848689, 8.822524],
-    [-12.910167, 12.910168, 7.81641],
-    [-12.845437, 15.291278, 0.625],
-    [-12.785843, 15.241021, 1.875],
-    [-12.8156395, 15.2661495, 1.25],
-    [-12.665969, 15.139938, 3.125],
-    [-12.560555, 15.051056, 3.9292688],
-    [-12.620791, 15.101846, 3.4696867],
-    [-12.058281, 15.916278, 0.625],
-    [-11.991785, 15.866021, 1.875],
-    [-11.433281, 16.379942, 0.625],
-    [-11.366785, 16.332794, 1.875],
-    [-10.625, 16.903103, 0.625],
-    [-10.625, 16.810335, 1.875],
-    [-11.350021, 16.368078, 1.25],
-    [-11.776747, 15.832608, 3.1002922],
-    [-11.948149, 15.402763, 4.3575616],
-    [-11.294578, 16.2816, 2.6671536],
-    [-11.177487, 16.201096, 3.500487],
-    [-11.013045, 16.090162, 4.375],
-    [-10.706492, 16.577555, 3.1002922],
-    [-10.388045, 16.49668, 4.375],
-    [-11.2130165, 16.098623, 3.6652985],
-    [-11.594833, 15.993178, 2.508551],
-    [-11.597614, 15.265716, 5.625],
-    [-11.383239, 15.101833, 6.478384],
-    [-10.603945, 15.943033, 5.6948266],
-    [-10.677339, 15.413081, 6.88703],
-    [-10.17324, 16.36011, 5.324711],
-    [-10.873315, 15.611463, 6.040929],
-    [-10.295564, 15.202538, 7.884995],
-    [-10.295564, 15.202538, 7.884995],
-    [-10.7979555, 15.558124, 6.281459],
-    [-13.434791, 13.434792, 4.866052],
-    [-13.878777, 10.17324, 10.17324],
-    [-13.137054, 10.638885, 10.638885],
-    [-12.720387, 10.271195, 11.493118],
-    [-12.720387, 11.493118, 10.271195],
-    [-13.1187315, 10.6430645, 10.6430645],
-    [-12.370105, 11.104529, 11.104529],
-    [-11.931611, 10.687862, 11.931611],
-    [-11.931611, 11.931611, 10.687862],
-    [-11.536771, 11.536771, 11.536771],
-    [-11.104529, 11.104529, 12.370105],
-    [-11.104529, 12.370105, 11.104529],
-    [-10.687862, 11.931611, 11.931611],
-    [-11.52181, 11.521811, 11.52181],
-    [-11.493118, 10.271195, 12.720387],
-    [-10.638885, 10.638885, 13.137054],
-    [-10.17324, 10.17324, 13.878777],
-    [-10.271195, 11.493118, 12.720387],
-    [-10.6430645, 10.6430645, 13.1187315],
-    [-11.493118, 12.720387, 10.271195],
-    [-10.638885, 13.137054, 10.638885],
-    [-10.271195, 12.720387, 11.493118],
-    [-10.17324, 13.878777, 10.17324],
-    [-10.6430645, 13.1187315, 10.6430645],
-    [-11.489697, 11.489697, 11.489696],
-    [-11.489697, 11.489697, 11.489696],
-    [-9.814424, 0.625, 17.398159],
-    [-9.731327, 1.875, 17.35254],
-    [-9.772875, 1.25, 17.375349],
-    [-9.189424, 0.625, 17.730347],
-    [-9.106327, 1.875, 17.686771],
-    [-8.125, 0.625, 18.239578],
-    [-8.125, 1.875, 18.153526],
-    [-8.636437, 1.25, 17.952557],
-    [-9.564161, 3.125, 17.260742],
-    [-9.2837515, 4.321776, 17.152058],
-    [-8.590999, 4.7029595, 17.424526],
-    [-9.204034, 4.0181465, 17.256403],
-    [-8.939161, 3.125, 17.599136],
-    [-8.8062525, 3.8696263, 17.529474],
-    [-8.125, 3.125, 17.980421],
-    [-8.154599, 4.321776, 17.719128],
-    [-8.465525, 3.63861, 17.718895],
-    [-7.189165, 0.625, 18.634232],
-    [-6.564165, 0.625, 18.863594],
-    [-7.075191, 1.875, 18.591757],
-    [-6.450191, 1.875, 18.822376],
-    [-5.625, 0.625, 19.160633],
-    [-5.625, 1.875, 19.078878],
-    [-6.421452, 1.25, 18.85858],
-    [-6.795851, 3.1002922, 18.529839],
-    [-6.3264184, 2.6671536, 18.777624],
-    [-6.875, 4.375, 18.239576],
-    [-6.1119275, 3.500487, 18.708017],
-    [-5.6671567, 3.1002922, 18.906551],
-    [-5.6671567, 4.464496, 18.63184],
-    [-5.2785945, 4.107494, 18.83258],
-    [-6.117916, 3.6303859, 18.651934],
-    [-7.5738397, 2.5777528, 18.189259],
-    [-9.972607, 8.718328, 14.982282],
-    [-9.483564, 9.483564, 14.806005],
-    [-8.718328, 9.972607, 14.982282],
-    [-9.408239, 9.408238, 14.902155],
-    [-9.408239, 9.408238, 14.902155],
-    [-9.945158, 6.1580443, 16.217432],
-    [-9.467094, 5.6948266, 16.642796],
-    [-9.688559, 6.875, 16.064978],
-    [-9.063559, 6.875, 16.42622],
-    [-8.154599, 5.7224407, 17.317713],
-    [-8.125, 6.875, 16.903101],
-    [-9.018097, 6.3224187, 16.641283],
-    [-7.7576656, 5.3707347, 17.617271],
-    [-7.7576656, 5.3707347, 17.617271],
-    [-9.38943, 8.141557, 15.6358595],
-    [-8.8062525, 7.564785, 16.2816],
-    [-9.139274, 9.139274, 15.235847],
-    [-8.398118, 8.398118, 16.069181],
-    [-8.038871, 8.038871, 16.428444],
-    [-8.141557, 9.38943, 15.6358595],
-    [-7.564785, 8.8062525, 16.2816],
-    [-8.520881, 8.520882, 15.90956],
-    [-7.4624944, 6.2040677, 17.48497],
-    [-6.977496, 6.977496, 17.372486],
-    [-6.2040677, 7.4624944, 17.48497],
-    [-6.8988338, 6.8988338, 17.43384],
-    [-6.977496, 5.7224407, 17.823565],
-    [-6.6291614, 6.6291614, 17.646322],
-    [-5.625, 5.625, 18.325289],
-    [-5.7224407, 6.977496, 17.823565],
-    [-6.228657, 6.228657, 17.91034],
-    [-6.875, 8.125, 16.903101],
-    [-6.875, 9.688559, 16.064978],
-    [-6.875, 9.063559, 16.42622],
-    [-5.7224407, 8.154599, 17.317713],
-    [-6.1580443, 9.945158, 16.217432],
-    [-5.6948266, 9.467094, 16.642796],
-    [-6.3224187, 9.018097, 16.641281],
-    [-5.3707347, 7.7576656, 17.617271],
-    [-5.3707347, 7.7576656, 17.617271],
-    [-7.5378585, 7.5378585, 16.77339],
-    [-4.375, 0.625, 19.484573],
-    [-4.375, 1.875, 19.404055],
-    [-3.125, 0.625, 19.724205],
-    [-3.125, 1.875, 19.644653],
-    [-3.75, 1.25, 19.564371],
-    [-4.375, 3.125, 19.24208],
-    [-4.940827, 4.940827, 18.736036],
-    [-4.464496, 4.464496, 18.95577],
-    [-3.125, 3.125, 19.484573],
-    [-3.125, 4.375, 19.24208],
-    [-3.9822478, 3.9822478, 19.143095],
-    [-1.875, 0.625, 19.88232],
-    [-1.875, 1.875, 19.803429],
-    [-0.625, 0.625, 19.960873],
-    [-0.625, 1.875, 19.88232],
-    [-1.25, 1.25, 19.882236],
-    [-1.875, 3.125, 19.644653],
-    [-1.875, 4.375, 19.404057],
-    [-0.625, 3.125, 19.724207],
-    [-0.625, 4.375, 19.484573],
-    [-1.25, 3.75, 19.564373],
-    [-2.6418376, 2.6418376, 19.515259],
-    [-4.464496, 5.6671567, 18.63184],
-    [-4.107494, 5.2785945, 18.83258],
-    [-4.375, 6.875, 18.239578],
-    [-3.500487, 6.1119275, 18.708017],
-    [-3.1002922, 5.6671567, 18.906551],
-    [-3.1002922, 6.795851, 18.529837],
-    [-2.6671536, 6.3264184, 18.777624],
-    [-3.6303864, 6.1179156, 18.651934],
-    [-4.7029595, 8.590999, 17.424526],
-    [-4.321776, 9.2837515, 17.15206],
-    [-3.125, 9.564161, 17.260742],
-    [-4.0181465, 9.204033, 17.256403],
-    [-4.321776, 8.154599, 17.719128],
-    [-3.8696263, 8.8062525, 17.529474],
-    [-3.125, 8.125, 17.98042],
-    [-3.125, 8.939161, 17.599136],
-    [-3.63861, 8.465525, 17.718893],
-    [-1.875, 5.625, 19.078878],
-    [-1.875, 7.075191, 18.591757],
-    [-1.875, 6.450191, 18.822376],
-    [-0.625, 5.625, 19.160631],
-    [-0.625, 7.189165, 18.634232],
-    [-0.625, 6.564165, 18.863594],
-    [-1.25, 6.421452, 18.858578],
-    [-1.875, 9.731327, 17.35254],
-    [-0.625, 9.814424, 17.398159],
-    [-1.25, 9.772875, 17.375349],
-    [-1.875, 8.125, 18.153526],
-    [-1.875, 9.106327, 17.686771],
-    [-0.625, 8.125, 18.239578],
-    [-0.625, 9.189424, 17.730347],
-    [-1.25, 8.636437, 17.952557],
-    [-2.5777533, 7.5738397, 18.189259],
-    [-5.4766984, 5.476698, 17.9243],
-    [-9.972607, 14.982282, 8.718328],
-    [-9.483564, 14.806005, 9.483564],
-    [-8.718328, 14.982282, 9.972607],
-    [-9.408239, 14.902155, 9.408238],
-    [-9.408239, 14.902155, 9.408238],
-    [-9.8144245, 17.398159, 0.625],
-    [-9.731327, 17.35254, 1.875],
-    [-9.772876, 17.375349, 1.25],
-    [-9.564161, 17.260742, 3.125],
-    [-9.2837515, 17.152058, 4.321776],
-    [-8.590999, 17.424526, 4.7029595],
-    [-9.204034, 17.256403, 4.0181465],
-    [-9.189424, 17.730347, 0.625],
-    [-9.106327, 17.686771, 1.875],
-    [-8.125, 18.239578, 0.625],
-    [-8.125, 18.153526, 1.875],
-    [-8.636437, 17.952557, 1.25],
-    [-8.939161, 17.599136, 3.125],
-    [-8.8062525, 17.529474, 3.8696263],
-    [-8.125, 17.98042, 3.125],
-    [-8.154599, 17.719128, 4.321776],
-    [-8.465525, 17.718893, 3.63861],
-    [-7.189165, 18.634232, 0.625],
-    [-7.075191, 18.591757, 1.875],
-    [-6.564165, 18.863594, 0.625],
-    [-6.450191, 18.822376, 1.875],
-    [-5.625, 19.160631, 0.625],
-    [-5.625, 19.078878, 1.875],
-    [-6.421452, 18.858578, 1.25],
-    [-6.795851, 18.529839, 3.1002922],
-    [-6.875, 18.239578, 4.375],
-    [-6.3264184, 18.777624, 2.6671536],
-    [-6.1119275, 18.708017, 3.500487],
-    [-5.6671567, 18.63184, 4.464496],
-    [-5.6671567, 18.906551, 3.1002922],
-    [-5.2785945, 18.83258, 4.107494],
-    [-6.117916, 18.651934, 3.6303859],
-    [-7.5738397, 18.189259, 2.5777528],
-    [-9.945158, 16.217432, 6.1580443],
-    [-9.688559, 16.064978, 6.875],
-    [-9.467094, 16.642797, 5.6948266],
-    [-9.063559, 16.42622, 6.875],
-    [-8.154599, 17.317713, 5.7224407],
-    [-8.125, 16.903101, 6.875],
-    [-9.018097, 16.641283, 6.3224187],
-    [-9.38943, 15.6358595, 8.141557],
-    [-9.139274, 15.235847, 9.139274],
-    [-8.8062525, 16.2816, 7.564785],
-    [-8.398118, 16.069181, 8.398118],
-    [-8.141557, 15.6358595, 9.38943],
-    [-8.038871, 16.428442, 8.038871],
-    [-7.564785, 16.2816, 8.8062525],
-    [-8.520881, 15.909558, 8.520882],
-    [-7.7576656, 17.617271, 5.3707347],
-    [-7.7576656, 17.617271, 5.3707347],
-    [-7.4624944, 17.48497, 6.2040677],
-    [-6.977496, 17.372486, 6.977496],
-    [-6.2040677, 17.48497, 7.4624944],
-    [-6.8988338, 17.43384, 6.8988338],
-    [-6.875, 16.064978, 9.688559],
-    [-6.875, 16.903101, 8.125],
-    [-6.875, 16.42622, 9.063559],
-    [-6.1580443, 16.217432, 9.945158],
-    [-5.7224407, 17.317713, 8.154599],
-    [-5.6948266, 16.642797, 9.467094],
-    [-6.3224187, 16.641281, 9.018097],
-    [-6.977496, 17.823565, 5.7224407],
-    [-6.6291614, 17.646322, 6.6291614],
-    [-5.625, 18.325289, 5.625],
-    [-5.7224407, 17.823565, 6.977496],
-    [-6.228657, 17.91034, 6.228657],
-    [-5.3707347, 17.617271, 7.7576656],
-    [-5.3707347, 17.617271, 7.7576656],
-    [-7.537858, 16.77339, 7.537858],
-    [-4.375, 19.484573, 0.625],
-    [-4.375, 19.404057, 1.875],
-    [-3.125, 19.724207, 0.625],
-    [-3.125, 19.644653, 1.875],
-    [-3.75, 19.564373, 1.25],
-    [-4.940827, 18.736036, 4.940827],
-    [-4.375, 19.24208, 3.125],
-    [-4.464496, 18.95577, 4.464496],
-    [-3.125, 19.484573, 3.125],
-    [-3.125, 19.24208, 4.375],
-    [-3.9822478, 19.143095, 3.9822478],
-    [-1.875, 19.88232, 0.625],
-    [-1.875, 19.803429, 1.875],
-    [-0.625, 19.960873, 0.625],
-    [-0.625, 19.88232, 1.875],
-    [-1.25, 19.882236, 1.25],
-    [-1.875, 19.644653, 3.125],
-    [-1.875, 19.404055, 4.375],
-    [-0.625, 19.724205, 3.125],
-    [-0.625, 19.484573, 4.375],
-    [-1.25, 19.564371, 3.75],
-    [-2.6418376, 19.515259, 2.6418376],
-    [-4.7029595, 17.424526, 8.590999],
-    [-4.321776, 17.15206, 9.2837515],
-    [-3.125, 17.260742, 9.564161],
-    [-4.0181465, 17.256403, 9.204033],
-    [-4.464496, 18.63184, 5.6671567],
-    [-4.375, 18.239576, 6.875],
-    [-4.107494, 18.83258, 5.2785945],
-    [-3.500487, 18.708017, 6.1119275],
-    [-3.1002922, 18.529837, 6.795851],
-    [-3.1002922, 18.906551, 5.6671567],
-    [-2.6671536, 18.777624, 6.3264184],
-    [-3.6303864, 18.651932, 6.1179156],
-    [-4.321776, 17.719128, 8.154599],
-    [-3.8696263, 17.529474, 8.8062525],
-    [-3.125, 17.980421, 8.125],
-    [-3.125, 17.599136, 8.939161],
-    [-3.63861, 17.718895, 8.465525],
-    [-1.875, 17.35254, 9.731327],
-    [-0.625, 17.398159, 9.8144245],
-    [-1.25, 17.375349, 9.772876],
-    [-1.875, 18.591757, 7.075191],
-    [-1.875, 19.078878, 5.625],
-    [-1.875, 18.822376, 6.450191],
-    [-0.625, 18.634232, 7.189165],
-    [-0.625, 19.160633, 5.625],
-    [-0.625, 18.863594, 6.564165],
-    [-1.25, 18.858578, 6.421452],
-    [-1.875, 18.153526, 8.125],
-    [-1.875, 17.686771, 9.106327],
-    [-0.625, 18.239578, 8.125],
-    [-0.625, 17.730347, 9.1894245],
-    [-1.25, 17.952557, 8.636438],
-    [-2.5777533, 18.189259, 7.5738397],
-    [-5.4766984, 17.9243, 5.476698],
-    [-9.779296, 12.326451, 12.326451],
-    [-9.779296, 12.326451, 12.326451],
-    [-9.723573, 11.006574, 13.553721],
-    [-9.334143, 10.603945, 14.121927],
-    [-9.334767, 11.843793, 13.097248],
-    [-8.890239, 11.361135, 13.842153],
-    [-8.230997, 10.677339, 14.7412815],
-    [-8.125, 12.254698, 13.528498],
-    [-8.125, 11.629698, 14.06489],
-    [-8.822525, 11.336996, 13.84869],
-    [-9.723573, 13.553721, 11.006574],
-    [-9.334767, 13.097248, 11.843793],
-    [-9.334143, 14.121927, 10.603945],
-    [-8.890239, 13.842153, 11.361135],
-    [-8.125, 13.528498, 12.254698],
-    [-8.230997, 14.7412815, 10.677339],
-    [-8.125, 14.06489, 11.629698],
-    [-8.822525, 13.84869, 11.336995],
-    [-8.945962, 12.640775, 12.640775],
-    [-8.125, 12.903497, 12.903498],
-    [-8.476841, 12.790902, 12.790902],
-    [-7.3117175, 11.128898, 14.911034],
-    [-7.4624944, 12.478462, 13.730554],
-    [-6.9704394, 11.930851, 14.422244],
-    [-5.625, 12.222614, 14.771767],
-    [-6.790329, 11.956102, 14.471581],
-    [-7.4624944, 13.730554, 12.478462],
-    [-7.3117175, 14.911034, 11.128898],
-    [-6.9704394, 14.422244, 11.93085],
-    [-5.625, 14.771767, 12.222614],
-    [-6.790329, 14.4715805, 11.956102],
-    [-7.045828, 13.2155485, 13.2155485],
-    [-6.6291614, 12.700542, 13.933454],
-    [-6.6291614, 13.933454, 12.700542],
-    [-5.625, 13.558707, 13.558707],
-    [-5.625, 12.933707, 14.146767],
-    [-5.625, 14.146767, 12.933707],
-    [-6.231247, 13.406334, 13.406334],
-    [-7.8164105, 12.910169, 12.910167],
-    [-7.884995, 10.295564, 15.202538],
-    [-7.884995, 10.295564, 15.202538],
-    [-6.88703, 10.677339, 15.413083],
-    [-6.478384, 11.383239, 15.101833],
-    [-5.6948266, 10.603945, 15.943033],
-    [-5.324711, 10.17324, 16.36011],
-    [-5.625, 11.597614, 15.265717],
-    [-6.040929, 10.873316, 15.611463],
-    [-6.281459, 10.797956, 15.558124],
-    [-7.884995, 15.202538, 10.295564],
-    [-7.884995, 15.202538, 10.295564],
-    [-6.88703, 15.413081, 10.677339],
-    [-6.478384, 15.101833, 11.383239],
-    [-5.6948266, 15.943033, 10.603945],
-    [-5.625, 15.265717, 11.597614],
-    [-5.324711, 16.36011, 10.17324],
-    [-6.040929, 15.611463, 10.873316],
-    [-6.281459, 15.558124, 10.797956],
-    [-4.7626023, 12.413581, 14.928902],
-    [-4.7626023, 12.413581, 14.928902],
-    [-4.7626023, 14.928902, 12.413581],
-    [-4.7626023, 14.928902, 12.413581],
-    [-4.881536, 13.711067, 13.711067],
-    [-4.4054027, 13.135811, 14.385528],
-    [-4.4054027, 14.385528, 13.135811],
-    [-4.048203, 13.842153, 13.842153],
-    [-3.125, 13.290969, 14.581705],
-    [-3.125, 14.581705, 13.290969],
-    [-3.125, 13.956705, 13.956705],
-    [-3.905135, 13.83684, 13.83684],
-    [-1.875, 13.410843, 14.690951],
-    [-1.875, 14.690951, 13.410843],
-    [-1.875, 14.065951, 14.065951],
-    [-0.625, 13.470437, 14.745259],
-    [-0.625, 14.745259, 13.470437],
-    [-0.625, 14.120259, 14.120259],
-    [-1.25, 14.08395, 14.08395],
-    [-2.928828, 13.919124, 13.919124],
-    [-4.375, 11.013046, 16.090162],
-    [-4.375, 10.388045, 16.49668],
-    [-4.3575616, 11.948149, 15.402763],
-    [-3.500487, 11.177487, 16.201096],
-    [-3.1002922, 10.706492, 16.577555],
-    [-3.1002922, 11.776747, 15.832608],
-    [-2.6671536, 11.294578, 16.2816],
-    [-3.6652985, 11.2130165, 16.098625],
-    [-3.9292688, 12.560555, 15.051056],
-    [-3.125, 12.665969, 15.139938],
-    [-3.4696867, 12.620791, 15.101846],
-    [-1.875, 10.625, 16.810335],
-    [-1.875, 11.991784, 15.866021],
-    [-1.875, 11.366785, 16.332794],
-    [-0.625, 10.625, 16.903103],
-    [-0.625, 12.058281, 15.916279],
-    [-0.625, 11.43328, 16.379944],
-    [-1.25, 11.350021, 16.36808],
-    [-1.875, 12.785843, 15.241021],
-    [-0.625, 12.845437, 15.291279],
-    [-1.25, 12.8156395, 15.2661495],
-    [-2.508551, 11.594832, 15.993178],
-    [-4.375, 16.090162, 11.013045],
-    [-4.3575616, 15.402763, 11.948149],
-    [-4.375, 16.49668, 10.388045],
-    [-3.500487, 16.201096, 11.177487],
-    [-3.1002922, 15.832608, 11.776747],
-    [-3.1002922, 16.577555, 10.706492],
-    [-2.6671536, 16.2816, 11.294578],
-    [-3.6652985, 16.098625, 11.2130165],
-    [-3.9292688, 15.051056, 12.560555],
-    [-3.125, 15.139938, 12.665969],
-    [-3.4696867, 15.101846, 12.620791],
-    [-1.875, 15.866021, 11.991785],
-    [-1.875, 16.810333, 10.625],
-    [-1.875, 16.332794, 11.366785],
-    [-0.625, 15.916278, 12.058281],
-    [-0.625, 16.903101, 10.625],
-    [-0.625, 16.379942, 11.433281],
-    [-1.25, 16.368078, 11.350021],
-    [-1.875, 15.241021, 12.785843],
-    [-0.625, 15.291278, 12.845437],
-    [-1.25, 15.2661495, 12.8156395],
-    [-2.508551, 15.993176, 11.594832],
-    [-4.866052, 13.434792, 13.434791],
-    [-10.126742, 10.126742, 10.126742],
-    [0.625, -15.291278, -12.845437],
-    [1.875, -15.241021, -12.785843],
-    [1.25, -15.2661495, -12.8156395],
-    [0.625, -16.379942, -11.433281],
-    [0.625, -16.903103, -10.625],
-    [0.625, -15.916278, -12.058281],
-    [1.875, -16.332794, -11.366785],
-    [1.875, -16.810333, -10.625],
-    [1.875, -15.866021, -11.991785],
-    [1.25, -16.368078, -11.350021],
-    [3.125, -15.139938, -12.665969],
-    [3.9292688, -15.051056, -12.560555],
-    [3.4696867, -15.101846, -12.620791],
-    [2.6671536, -16.2816, -11.294578],
-    [3.1002922, -16.577555, -10.706492],
-    [3.1002922, -15.832608, -11.776747],
-    [3.500487, -16.201096, -11.177487],
-    [4.375, -16.49668, -10.388045],
-    [4.3575616, -15.402763, -11.948149],
-    [4.375, -16.090162, -11.013045],
-    [3.6652985, -16.098621, -11.2130165],
-    [2.508551, -15.993178, -11.594833],
-    [0.625, -12.845437, -15.291279],
-    [1.875, -12.785843, -15.241021],
-    [1.25, -12.8156395, -15.2661495],
-    [0.625, -11.43328, -16.379944],
-    [0.625, -12.058281, -15.916279],
-    [0.625, -10.625, -16.903103],
-    [1.875, -11.366785, -16.332794],
-    [1.875, -11.991784, -15.866021],
-    [1.875, -10.625, -16.810333],
-    [1.25, -11.350021, -16.36808],
-    [3.125, -12.665969, -15.139938],
-    [3.9292688, -12.560555, -15.051056],
-    [3.4696867, -12.620791, -15.101846],
-    [2.6671536, -11.294578, -16.2816],
-    [3.1002922, -11.776747, -15.832608],
-    [3.1002922, -10.706492, -16.577555],
-    [3.500487, -11.177487, -16.201096],
-    [4.3575616, -11.948149, -15.402763],
-    [4.375, -10.388045, -16.49668],
-    [4.375, -11.013046, -16.090162],
-    [3.6652985, -11.2130165, -16.098621],
-    [2.508551, -11.594833, -15.993178],
-    [0.625, -14.120259, -14.120259],
-    [0.625, -14.745259, -13.470437],
-    [0.625, -13.470437, -14.745259],
-    [1.875, -14.065951, -14.065951],
-    [1.875, -14.690951, -13.410843],
-    [1.875, -13.410843, -14.690951],
-    [1.25, -14.08395, -14.08395],
-    [3.125, -13.956705, -13.956705],
-    [3.125, -14.581705, -13.290969],
-    [3.125, -13.290969, -14.581705],
-    [4.048203, -13.842153, -13.842153],
-    [4.4054027, -14.385528, -13.135811],
-    [4.4054027, -13.135811, -14.385528],
-    [4.881536, -13.711067, -13.711067],
-    [3.9051352, -13.83684, -13.836841],
-    [4.7626023, -14.928902, -12.413581],
-    [4.7626023, -14.928902, -12.413581],
-    [4.7626023, -12.413581, -14.928902],
-    [4.7626023, -12.413581, -14.928902],
-    [2.928828, -13.919124, -13.919124],
-    [5.324711, -16.36011, -10.17324],
-    [5.625, -15.265717, -11.597614],
-    [5.6948266, -15.943031, -10.603945],
-    [6.478384, -15.101833, -11.383239],
-    [6.88703, -15.413081, -10.677339],
-    [6.040929, -15.611463, -10.873316],
-    [7.884995, -15.202538, -10.295564],
-    [7.884995, -15.202538, -10.295564],
-    [6.281459, -15.558124, -10.797956],
-    [5.625, -11.597614, -15.265717],
-    [5.324711, -10.17324, -16.36011],
-    [5.6948266, -10.603945, -15.943033],
-    [6.478384, -11.383239, -15.101833],
-    [6.88703, -10.677339, -15.413081],
-    [6.040929, -10.873316, -15.611463],
-    [7.884995, -10.295564, -15.202538],
-    [7.884995, -10.295564, -15.202538],
-    [6.281459, -10.797956, -15.558124],
-    [5.625, -14.146767, -12.933707],
-    [5.625, -12.933707, -14.146767],
-    [5.625, -13.558707, -13.558707],
-    [6.6291614, -13.933454, -12.700542],
-    [6.6291614, -12.700542, -13.933454],
-    [7.045828, -13.2155485, -13.2155485],
-    [6.231247, -13.406334, -13.406334],
-    [5.625, -14.771767, -12.222614],
-    [6.9704394, -14.422244, -11.93085],
-    [7.3117175, -14.911034, -11.128898],
-    [7.4624944, -13.730554, -12.478462],
-    [6.790329, -14.4715805, -11.956102],
-    [5.625, -12.222614, -14.771767],
-    [6.9704394, -11.93085, -14.422244],
-    [7.4624944, -12.478462, -13.730554],
-    [7.3117175, -11.128898, -14.911034],
-    [6.790329, -11.956102, -14.471581],
-    [8.125, -12.903497, -12.903498],
-    [8.945962, -12.640775, -12.640775],
-    [8.476841, -12.790902, -12.790902],
-    [8.125, -14.06489, -11.629698],
-    [8.230997, -14.7412815, -10.677339],
-    [8.125, -13.528498, -12.254698],
-    [8.890239, -13.842153, -11.361135],
-    [9.334143, -14.121927, -10.603945],
-    [9.334767, -13.097248, -11.843793],
-    [9.723573, -13.553721, -11.006574],
-    [8.822525, -13.848691, -11.336995],
-    [8.125, -11.629698, -14.06489],
-    [8.125, -12.254698, -13.528498],
-    [8.230997, -10.677339, -14.7412815],
-    [8.890239, -11.361135, -13.842153],
-    [9.334767, -11.843793, -13.097248],
-    [9.334143, -10.603945, -14.121927],
-    [9.723573, -11.006574, -13.553721],
-    [8.822525, -11.336995, -13.848689],
-    [9.779296, -12.326451, -12.326451],
-    [9.779296, -12.326451, -12.326451],
-    [7.816411, -12.910168, -12.910168],
-    [4.866053, -13.434792, -13.434792],
-    [0.625, -17.730347, -9.1894245],
-    [0.625, -18.239578, -8.125],
-    [1.875, -17.686771, -9.106327],
-    [1.875, -18.153528, -8.125],
-    [1.25, -17.952557, -8.636438],
-    [0.625, -18.863594, -6.564165],
-    [0.625, -19.160631, -5.625],
-    [0.625, -18.634232, -7.189165],
-    [1.875, -18.822376, -6.450191],
-    [1.875, -19.078878, -5.625],
-    [1.875, -18.591757, -7.075191],
-    [1.25, -18.85858, -6.421452],
-    [0.625, -17.398159, -9.8144245],
-    [1.875, -17.35254, -9.731327],
-    [1.25, -17.375349, -9.772876],
-    [3.125, -17.599136, -8.939161],
-    [3.125, -17.980421, -8.125],
-    [3.8696263, -17.529474, -8.8062525],
-    [4.321776, -17.719128, -8.154599],
-    [3.63861, -17.718893, -8.465525],
-    [2.6671536, -18.777624, -6.3264184],
-    [3.1002922, -18.906551, -5.6671567],
-    [3.1002922, -18.529837, -6.795851],
-    [3.500487, -18.708017, -6.1119275],
-    [4.107494, -18.83258, -5.2785945],
-    [4.375, -18.239578, -6.875],
-    [4.464496, -18.63184, -5.6671567],
-    [3.6303859, -18.651934, -6.1179156],
-    [3.125, -17.260742, -9.564161],
-    [4.321776, -17.15206, -9.2837515],
-    [4.7029595, -17.424526, -8.590999],
-    [4.0181465, -17.256403, -9.204033],
-    [2.5777528, -18.189259, -7.5738397],
-    [0.625, -19.484573, -4.375],
-    [0.625, -19.724205, -3.125],
-    [1.875, -19.404055, -4.375],
-    [1.875, -19.644651, -3.125],
-    [1.25, -19.564371, -3.75],
-    [0.625, -19.88232, -1.875],
-    [0.625, -19.960873, -0.625],
-    [1.875, -19.803429, -1.875],
-    [1.875, -19.882318, -0.625],
-    [1.25, -19.882235, -1.25],
-    [3.125, -19.24208, -4.375],
-    [3.125, -19.484573, -3.125],
-    [4.464496, -18.95577, -4.464496],
-    [4.375, -19.24208, -3.125],
-    [4.940827, -18.736036, -4.940827],
-    [3.9822478, -19.143095, -3.9822478],
-    [3.125, -19.644653, -1.875],
-    [3.125, -19.724205, -0.625],
-    [4.375, -19.404055, -1.875],
-    [4.375, -19.484573, -0.625],
-    [3.75, -19.564371, -1.25],
-    [2.6418376, -19.515257, -2.6418376],
-    [5.3707347, -17.617271, -7.7576656],
-    [5.3707347, -17.617271, -7.7576656],
-    [5.7224407, -17.823565, -6.977496],
-    [5.625, -18.325289, -5.625],
-    [6.6291614, -17.646322, -6.6291614],
-    [6.977496, -17.823565, -5.7224407],
-    [6.228657, -17.91034, -6.228657],
-    [5.6948266, -16.642797, -9.467094],
-    [5.7224407, -17.317713, -8.154599],
-    [6.1580443, -16.217432, -9.945158],
-    [6.875, -16.42622, -9.063559],
-    [6.875, -16.9031, -8.125],
-    [6.875, -16.064978, -9.688559],
-    [6.3224187, -16.641281, -9.018097],
-    [6.2040677, -17.48497, -7.4624944],
-    [6.977496, -17.372486, -6.977496],
-    [7.4624944, -17.48497, -6.2040677],
-    [6.8988338, -17.43384, -6.8988338],
-    [7.7576656, -17.617271, -5.3707347],
-    [7.7576656, -17.617271, -5.3707347],
-    [7.564785, -16.2816, -8.8062525],
-    [8.038871, -16.428442, -8.038871],
-    [8.141557, -15.6358595, -9.38943],
-    [8.398118, -16.069181, -8.398118],
-    [8.8062525, -16.2816, -7.564785],
-    [9.139274, -15.235847, -9.139274],
-    [9.38943, -15.6358595, -8.141557],
-    [8.520881, -15.909558, -8.520882],
-    [8.125, -16.903101, -6.875],
-    [8.154599, -17.317713, -5.7224407],
-    [9.063559, -16.42622, -6.875],
-    [9.467094, -16.642797, -5.6948266],
-    [9.688559, -16.064978, -6.875],
-    [9.945158, -16.217432, -6.1580443],
-    [9.018097, -16.641281, -6.3224187],
-    [7.537858, -16.773388, -7.5378585],
-    [5.2785945, -18.83258, -4.107494],
-    [5.6671567, -18.906551, -3.1002922],
-    [5.6671567, -18.63184, -4.464496],
-    [6.1119275, -18.708017, -3.500487],
-    [6.3264184, -18.777624, -2.6671536],
-    [6.875, -18.239578, -4.375],
-    [6.795851, -18.529839, -3.1002922],
-    [6.117916, -18.651934, -3.6303859],
-    [5.625, -19.078878, -1.875],
-    [5.625, -19.160633, -0.625],
-    [6.450191, -18.822376, -1.875],
-    [6.564165, -18.863594, -0.625],
-    [7.075191, -18.591757, -1.875],
-    [7.189165, -18.634232, -0.625],
-    [6.421452, -18.858578, -1.25],
-    [8.154599, -17.719128, -4.321776],
-    [8.125, -17.980421, -3.125],
-    [8.8062525, -17.529474, -3.8696263],
-    [8.939161, -17.599136, -3.125],
-    [8.465525, -17.718893, -3.63861],
-    [8.125, -18.153526, -1.875],
-    [8.125, -18.239578, -0.625],
-    [9.106327, -17.686771, -1.875],
-    [9.1894245, -17.730347, -0.625],
-    [8.636438, -17.952557, -1.25],
-    [8.590999, -17.424526, -4.7029595],
-    [9.2837515, -17.152058, -4.321776],
-    [9.564161, -17.260742, -3.125],
-    [9.204034, -17.256403, -4.0181465],
-    [9.731327, -17.35254, -1.875],
-    [9.8144245, -17.398159, -0.625],
-    [9.772876, -17.375349, -1.25],
-    [7.57384, -18.189259, -2.5777528],
-    [8.718328, -14.982282, -9.972607],
-    [9.483564, -14.806005, -9.483564],
-    [9.972607, -14.982282, -8.718328],
-    [9.408239, -14.902155, -9.408238],
-    [9.408239, -14.902155, -9.408238],
-    [5.4766984, -17.924301, -5.476698],
-    [0.625, -9.189424, -17.730347],
-    [0.625, -8.125, -18.239578],
-    [1.875, -9.106327, -17.686771],
-    [1.875, -8.125, -18.153528],
-    [1.25, -8.636437, -17.952557],
-    [0.625, -9.814424, -17.398159],
-    [1.875, -9.731327, -17.35254],
-    [1.25, -9.772875, -17.375349],
-    [0.625, -6.564165, -18.863594],
-    [0.625, -7.189165, -18.634232],
-    [0.625, -5.625, -19.160631],
-    [1.875, -6.450191, -18.822376],
-    [1.875, -7.075191, -18.591757],
-    [1.875, -5.625, -19.078878],
-    [1.25, -6.421452, -18.858578],
-    [3.125, -8.939161, -17.599136],
-    [3.125, -8.125, -17.980421],
-    [3.8696263, -8.8062525, -17.529474],
-    [4.321776, -8.154599, -17.719128],
-    [3.63861, -8.465525, -17.718893],
-    [3.125, -9.564161, -17.260742],
-    [4.321776, -9.2837515, -17.15206],
-    [4.7029595, -8.590999, -17.424526],
-    [4.0181465, -9.204033, -17.256403],
-    [2.6671536, -6.3264184, -18.777624],
-    [3.1002922, -6.795851, -18.529837],
-    [3.1002922, -5.6671567, -18.906551],
-    [3.500487, -6.1119275, -18.708017],
-    [4.375, -6.875, -18.239578],
-    [4.107494, -5.2785945, -18.83258],
-    [4.464496, -5.6671567, -18.63184],
-    [3.6303859, -6.1179156, -18.651934],
-    [2.5777528, -7.573839, -18.189259],
-    [0.625, -4.375, -19.484573],
-    [0.625, -3.125, -19.724205],
-    [1.875, -4.375, -19.404055],
-    [1.875, -3.125, -19.644651],
-    [1.25, -3.75, -19.564371],
-    [0.625, -1.875, -19.88232],
-    [0.625, -0.625, -19.960873],
-    [1.875, -1.875, -19.803429],
-    [1.875, -0.625, -19.882318],
-    [1.25, -1.25, -19.882235],
-    [3.125, -4.375, -19.24208],
-    [3.125, -3.125, -19.484573],
-    [4.464496, -4.464496, -18.95577],
-    [4.940827, -4.940827, -18.736036],
-    [4.375, -3.125, -19.24208],
-    [3.9822478, -3.9822478, -19.143095],
-    [3.125, -1.875, -19.644653],
-    [3.125, -0.625, -19.724205],
-    [4.375, -1.875, -19.404055],
-    [4.375, -0.625, -19.484573],
-    [3.75, -1.25, -19.564371],
-    [2.6418376, -2.6418376, -19.515257],
-    [5.3707347, -7.7576656, -17.617271],
-    [5.3707347, -7.7576656, -17.617271],
-    [5.6948266, -9.467094, -16.642797],
-    [6.1580443, -9.945158, -16.217432],
-    [5.7224407, -8.154599, -17.317713],
-    [6.875, -9.063559, -16.42622],
-    [6.875, -9.688559, -16.064978],
-    [6.875, -8.125, -16.9031],
-    [6.3224187, -9.018096, -16.641281],
-    [5.7224407, -6.977496, -17.823565],
-    [5.625, -5.625, -18.325289],
-    [6.6291614, -6.6291614, -17.646322],
-    [6.977496, -5.7224407, -17.823565],
-    [6.228657, -6.228657, -17.91034],
-    [6.2040677, -7.4624944, -17.48497],
-    [6.977496, -6.977496, -17.372486],
-    [7.4624944, -6.2040677, -17.48497],
-    [6.8988338, -6.8988338, -17.43384],
-    [7.564785, -8.8062525, -16.2816],
-    [8.141557, -9.38943, -15.6358595],
-    [8.038871, -8.038871, -16.428442],
-    [8.398118, -8.398118, -16.069181],
-    [9.139274, -9.139274, -15.235847],
-    [8.8062525, -7.564785, -16.2816],
-    [9.38943, -8.141557, -15.6358595],
-    [8.520882, -8.520882, -15.909558],
-    [7.7576656, -5.3707347, -17.617271],
-    [7.7576656, -5.3707347, -17.617271],
-    [8.125, -6.875, -16.903101],
-    [8.154599, -5.7224407, -17.317713],
-    [9.063559, -6.875, -16.42622],
-    [9.688559, -6.875, -16.064978],
-    [9.467094, -5.6948266, -16.642797],
-    [9.945158, -6.1580443, -16.217432],
-    [9.018097, -6.3224187, -16.641281],
-    [7.537858, -7.537858, -16.773388],
-    [8.718328, -9.972607, -14.982282],
-    [9.483564, -9.483564, -14.806005],
-    [9.972607, -8.718328, -14.982282],
-    [9.408239, -9.408238, -14.902155],
-    [9.408239, -9.408238, -14.902155],
-    [5.2785945, -4.107494, -18.83258],
-    [5.6671567, -4.464496, -18.63184],
-    [5.6671567, -3.1002922, -18.906551],
-    [6.1119275, -3.500487, -18.708017],
-    [6.875, -4.375, -18.239578],
-    [6.3264184, -2.6671536, -18.777624],
-    [6.795851, -3.1002922, -18.529839],
-    [6.117916, -3.6303859, -18.651934],
-    [5.625, -1.875, -19.078878],
-    [5.625, -0.625, -19.160633],
-    [6.450191, -1.875, -18.822376],
-    [7.075191, -1.875, -18.591757],
-    [6.564165, -0.625, -18.863594],
-    [7.189165, -0.625, -18.634232],
-    [6.421452, -1.25, -18.858578],
-    [8.154599, -4.321776, -17.719128],
-    [8.125, -3.125, -17.980421],
-    [8.8062525, -3.8696263, -17.529474],
-    [8.939161, -3.125, -17.599136],
-    [8.465525, -3.63861, -17.718893],
-    [8.590999, -4.7029595, -17.424526],
-    [9.2837515, -4.321776, -17.152058],
-    [9.564161, -3.125, -17.260742],
-    [9.204034, -4.0181465, -17.256403],
-    [8.125, -1.875, -18.153526],
-    [8.125, -0.625, -18.239578],
-    [9.106327, -1.875, -17.686771],
-    [9.189424, -0.625, -17.730347],
-    [8.636437, -1.25, -17.952557],
-    [9.731327, -1.875, -17.35254],
-    [9.814424, -0.625, -17.398159],
-    [9.772875, -1.25, -17.375349],
-    [7.5738397, -2.5777528, -18.189259],
-    [5.476698, -5.476698, -17.924301],
-    [10.17324, -13.878777, -10.17324],
-    [10.271195, -12.720387, -11.493118],
-    [10.638885, -13.137054, -10.638885],
-    [11.493118, -12.720387, -10.271195],
-    [10.6430645, -13.1187315, -10.6430645],
-    [10.271195, -11.493118, -12.720387],
-    [10.17324, -10.17324, -13.878777],
-    [10.638885, -10.638885, -13.137054],
-    [11.493118, -10.271195, -12.720387],
-    [10.6430645, -10.6430645, -13.1187315],
-    [10.687862, -11.931611, -11.931611],
-    [11.104529, -12.370105, -11.104529],
-    [11.104529, -11.104529, -12.370105],
-    [11.536771, -11.536771, -11.536771],
-    [11.931611, -11.931611, -10.687862],
-    [11.931611, -10.687862, -11.931611],
-    [12.370105, -11.104529, -11.104529],
-    [11.521811, -11.521811, -11.521811],
-    [12.720387, -11.493118, -10.271195],
-    [12.720387, -10.271195, -11.493118],
-    [13.137054, -10.638885, -10.638885],
-    [13.878777, -10.17324, -10.17324],
-    [13.1187315, -10.6430645, -10.6430645],
-    [11.489697, -11.489697, -11.489697],
-    [11.489697, -11.489697, -11.489697],
-    [10.295564, -15.202538, -7.884995],
-    [10.295564, -15.202538, -7.884995],
-    [10.17324, -16.36011, -5.324711],
-    [10.677339, -15.413081, -6.88703],
-    [10.603945, -15.943033, -5.6948266],
-    [11.383239, -15.101833, -6.478384],
-    [11.597614, -15.265717, -5.625],
-    [10.873315, -15.611463, -6.040929],
-    [10.7979555, -15.558124, -6.281459],
-    [10.388045, -16.49668, -4.375],
-    [10.706492, -16.577555, -3.1002922],
-    [11.013045, -16.090162, -4.375],
-    [11.177487, -16.201096, -3.500487],
-    [11.294578, -16.2816, -2.6671536],
-    [11.948149, -15.402763, -4.3575616],
-    [11.776747, -15.832608, -3.1002922],
-    [11.2130165, -16.098623, -3.6652985],
-    [10.625, -16.810333, -1.875],
-    [10.625, -16.903103, -0.625],
-    [11.366785, -16.332794, -1.875],
-    [11.433281, -16.379942, -0.625],
-    [11.991785, -15.866021, -1.875],
-    [12.058281, -15.916278, -0.625],
-    [11.350021, -16.368078, -1.25],
-    [12.560555, -15.051056, -3.9292688],
-    [12.665969, -15.139938, -3.125],
-    [12.620791, -15.101846, -3.4696867],
-    [12.785843, -15.241021, -1.875],
-    [12.845437, -15.291278, -0.625],
-    [12.8156395, -15.2661495, -1.25],
-    [11.594834, -15.993176, -2.508551],
-    [10.603945, -14.121927, -9.334143],
-    [10.677339, -14.7412815, -8.230997],
-    [11.006574, -13.553721, -9.723573],
-    [11.361135, -13.842153, -8.890239],
-    [11.629698, -14.06489, -8.125],
-    [11.843793, -13.097248, -9.334767],
-    [12.254698, -13.528497, -8.125],
-    [11.336995, -13.84869, -8.822524],
-    [11.128898, -14.911034, -7.3117175],
-    [11.93085, -14.422244, -6.9704394],
-    [12.222614, -14.771767, -5.625],
-    [12.478462, -13.730554, -7.4624944],
-    [11.956101, -14.471581, -6.7903295],
-    [12.326451, -12.326451, -9.779296],
-    [12.326451, -12.326451, -9.779296],
-    [12.640775, -12.640775, -8.945962],
-    [12.903498, -12.903497, -8.125],
-    [12.790902, -12.790902, -8.476841],
-    [12.700542, -13.933454, -6.6291614],
-    [12.933707, -14.146767, -5.625],
-    [13.2155485, -13.2155485, -7.045828],
-    [13.558707, -13.558707, -5.625],
-    [13.933454, -12.700542, -6.6291614],
-    [14.146767, -12.933707, -5.625],
-    [13.406334, -13.406334, -6.231247],
-    [13.097249, -11.843793, -9.334767],
-    [13.528498, -12.254698, -8.125],
-    [13.553721, -11.006574, -9.723573],
-    [13.842153, -11.361135, -8.890239],
-    [14.06489, -11.629698, -8.125],
-    [14.121927, -10.603945, -9.334143],
-    [14.7412815, -10.677339, -8.230997],
-    [13.84869, -11.336995, -8.822524],
-    [13.730554, -12.478462, -7.4624944],
-    [14.422244, -11.930851, -6.9704394],
-    [14.771767, -12.222614, -5.625],
-    [14.911034, -11.128898, -7.3117175],
-    [14.4715805, -11.956102, -6.7903295],
-    [12.910168, -12.910168, -7.81641],
-    [12.413581, -14.928902, -4.7626023],
-    [12.413581, -14.928902, -4.7626023],
-    [13.135811, -14.385528, -4.4054027],
-    [13.290969, -14.581705, -3.125],
-    [13.711067, -13.711067, -4.881536],
-    [13.842153, -13.842153, -4.048203],
-    [13.956705, -13.956705, -3.125],
-    [14.385528, -13.135811, -4.4054027],
-    [14.581705, -13.290969, -3.125],
-    [13.836841, -13.836841, -3.9051352],
-    [13.410843, -14.690951, -1.875],
-    [13.470437, -14.745259, -0.625],
-    [14.065951, -14.065951, -1.875],
-    [14.120259, -14.120259, -0.625],
-    [14.690951, -13.410843, -1.875],
-    [14.745259, -13.470437, -0.625],
-    [14.08395, -14.08395, -1.25],
-    [14.928902, -12.413581, -4.7626023],
-    [14.928902, -12.413581, -4.7626023],
-    [13.919125, -13.919125, -2.928828],
-    [15.202538, -10.295564, -7.884995],
-    [15.202538, -10.295564, -7.884995],
-    [15.101833, -11.383239, -6.478384],
-    [15.265717, -11.597614, -5.625],
-    [15.413081, -10.677339, -6.88703],
-    [15.943033, -10.603945, -5.6948266],
-    [16.36011, -10.17324, -5.324711],
-    [15.611464, -10.873316, -6.0409284],
-    [15.5581255, -10.797956, -6.281459],
-    [15.051056, -12.560555, -3.9292688],
-    [15.139938, -12.665969, -3.125],
-    [15.101846, -12.620791, -3.4696867],
-    [15.241021, -12.785843, -1.875],
-    [15.291279, -12.845437, -0.625],
-    [15.2661495, -12.8156395, -1.25],
-    [15.402763, -11.948149, -4.3575616],
-    [15.832608, -11.776747, -3.1002922],
-    [16.090162, -11.013046, -4.375],
-    [16.201096, -11.177487, -3.500487],
-    [16.2816, -11.294578, -2.6671536],
-    [16.49668, -10.388045, -4.375],
-    [16.577555, -10.706492, -3.1002922],
-    [16.098623, -11.213018, -3.6652985],
-    [15.866021, -11.991785, -1.875],
-    [15.916279, -12.05828, -0.625],
-    [16.332794, -11.366785, -1.875],
-    [16.379944, -11.43328, -0.625],
-    [16.810333, -10.625, -1.875],
-    [16.903103, -10.625, -0.625],
-    [16.36808, -11.350021, -1.25],
-    [15.993178, -11.594834, -2.508551],
-    [13.434792, -13.434792, -4.866052],
-    [10.295564, -7.884995, -15.202538],
-    [10.295564, -7.884995, -15.202538],
-    [10.677339, -6.88703, -15.413081],
-    [10.17324, -5.324711, -16.36011],
-    [10.603945, -5.6948266, -15.943031],
-    [11.383239, -6.478384, -15.101833],
-    [11.597614, -5.625, -15.265717],
-    [10.873315, -6.040929, -15.611463],
-    [10.7979555, -6.281459, -15.558124],
-    [10.603945, -9.334143, -14.121927],
-    [11.006574, -9.723573, -13.553721],
-    [10.677339, -8.230997, -14.7412815],
-    [11.361135, -8.890239, -13.842153],
-    [11.843793, -9.334767, -13.097248],
-    [11.629698, -8.125, -14.06489],
-    [12.254698, -8.125, -13.528497],
-    [11.336996, -8.822524, -13.84869],
-    [12.326451, -9.779296, -12.326451],
-    [12.326451, -9.779296, -12.326451],
-    [11.128898, -7.3117175, -14.911034],
-    [11.93085, -6.9704385, -14.422244],
-    [12.478462, -7.4624944, -13.730554],
-    [12.222614, -5.625, -14.771767],
-    [11.956102, -6.790329, -14.471581],
-    [12.640775, -8.945962, -12.640775],
-    [12.903498, -8.125, -12.903497],
-    [12.790902, -8.476841, -12.790902],
-    [13.097248, -9.334767, -11.843793],
-    [13.553721, -9.723573, -11.006574],
-    [13.528498, -8.125, -12.254698],
-    [13.842153, -8.890239, -11.361135],
-    [14.121927, -9.334143, -10.603945],
-    [14.06489, -8.125, -11.629698],
-    [14.7412815, -8.230997, -10.677339],
-    [13.84869, -8.822524, -11.336996],
-    [12.700542, -6.6291614, -13.933454],
-    [13.2155485, -7.0458274, -13.2155485],
-    [12.933707, -5.625, -14.146767],
-    [13.558707, -5.625, -13.558707],
-    [13.933454, -6.6291614, -12.700542],
-    [14.146767, -5.625, -12.933707],
-    [13.406334, -6.231247, -13.406334],
-    [13.730554, -7.4624944, -12.478462],
-    [14.422244, -6.9704385, -11.93085],
-    [14.911034, -7.3117175, -11.128898],
-    [14.771767, -5.625, -12.222614],
-    [14.4715805, -6.790329, -11.956102],
-    [12.910169, -7.81641, -12.910168],
-    [10.388045, -4.375, -16.49668],
-    [11.013045, -4.375, -16.090162],
-    [10.706492, -3.1002922, -16.577555],
-    [11.177487, -3.500487, -16.201096],
-    [11.948149, -4.3575616, -15.402763],
-    [11.294578, -2.6671536, -16.2816],
-    [11.776747, -3.1002922, -15.832608],
-    [11.2130165, -3.6652985, -16.098623],
-    [10.625, -1.875, -16.810333],
-    [10.625, -0.625, -16.903103],
-    [11.366785, -1.875, -16.332794],
-    [11.991785, -1.875, -15.866021],
-    [11.433281, -0.625, -16.379942],
-    [12.058281, -0.625, -15.916278],
-    [11.350021, -1.25, -16.368078],
-    [12.560555, -3.9292688, -15.051056],
-    [12.665969, -3.125, -15.139938],
-    [12.620791, -3.4696867, -15.101846],
-    [12.785843, -1.875, -15.241021],
-    [12.845437, -0.625, -15.291278],
-    [12.8156395, -1.25, -15.2661495],
-    [11.594834, -2.508551, -15.993176],
-    [12.413581, -4.7626023, -14.928902],
-    [12.413581, -4.7626023, -14.928902],
-    [13.135811, -4.4054027, -14.385528],
-    [13.711067, -4.881536, -13.711067],
-    [13.290969, -3.125, -14.581705],
-    [13.842153, -4.048203, -13.842153],
-    [14.385528, -4.4054027, -13.135811],
-    [13.956705, -3.125, -13.956705],
-    [14.581705, -3.125, -13.290969],
-    [13.83684, -3.905135, -13.83684],
-    [14.928902, -4.7626023, -12.413581],
-    [14.928902, -4.7626023, -12.413581],
-    [13.410843, -1.875, -14.690951],
-    [13.470437, -0.625, -14.745259],
-    [14.065951, -1.875, -14.065951],
-    [14.690951, -1.875, -13.410843],
-    [14.120259, -0.625, -14.120259],
-    [14.745259, -0.625, -13.470437],
-    [14.08395, -1.25, -14.08395],
-    [13.919124, -2.9288278, -13.919125],
-    [15.202538, -7.884995, -10.295564],
-    [15.202538, -7.884995, -10.295564],
-    [15.101833, -6.478384, -11.383239],
-    [15.413081, -6.88703, -10.677339],
-    [15.265717, -5.625, -11.597614],
-    [15.943033, -5.6948266, -10.603945],
-    [16.36011, -5.324711, -10.17324],
-    [15.611464, -6.0409284, -10.873316],
-    [15.5581255, -6.281459, -10.797956],
-    [15.051056, -3.9292688, -12.560555],
-    [15.139938, -3.125, -12.665969],
-    [15.101846, -3.4696867, -12.620791],
-    [15.402763, -4.3575616, -11.948149],
-    [16.090162, -4.375, -11.013046],
-    [15.832608, -3.1002922, -11.776747],
-    [16.201096, -3.500487, -11.177487],
-    [16.49668, -4.375, -10.388045],
-    [16.2816, -2.6671536, -11.294578],
-    [16.577555, -3.1002922, -10.706492],
-    [16.098623, -3.6652985, -11.213018],
-    [15.241021, -1.875, -12.785843],
-    [15.291278, -0.625, -12.845437],
-    [15.2661495, -1.25, -12.8156395],
-    [15.866021, -1.875, -11.991785],
-    [15.916278, -0.625, -12.05828],
-    [16.332794, -1.875, -11.366785],
-    [16.810333, -1.875, -10.625],
-    [16.379942, -0.625, -11.43328],
-    [16.903103, -0.625, -10.625],
-    [16.368078, -1.25, -11.350021],
-    [15.993178, -2.508551, -11.594834],
-    [13.434792, -4.866052, -13.434792],
-    [14.806005, -9.483564, -9.483564],
-    [14.982282, -9.972607, -8.718328],
-    [14.982282, -8.718328, -9.972607],
-    [14.902155, -9.408239, -9.408238],
-    [14.902155, -9.408239, -9.408238],
-    [15.235847, -9.139274, -9.139274],
-    [15.6358595, -9.38943, -8.141557],
-    [15.6358595, -8.141557, -9.38943],
-    [16.069181, -8.398118, -8.398118],
-    [16.2816, -8.8062525, -7.564785],
-    [16.2816, -7.564785, -8.8062525],
-    [16.428442, -8.038871, -8.038871],
-    [15.909559, -8.520881, -8.520881],
-    [16.064978, -9.688559, -6.875],
-    [16.217432, -9.945158, -6.1580443],
-    [16.42622, -9.063559, -6.875],
-    [16.642797, -9.467094, -5.6948266],
-    [16.9031, -8.125, -6.875],
-    [17.317713, -8.154599, -5.7224407],
-    [16.641281, -9.018096, -6.3224187],
-    [16.064978, -6.875, -9.688559],
-    [16.217432, -6.1580443, -9.945158],
-    [16.42622, -6.875, -9.063559],
-    [16.903101, -6.875, -8.125],
-    [16.642797, -5.6948266, -9.467094],
-    [17.317713, -5.7224407, -8.154599],
-    [16.641281, -6.3224187, -9.018096],
-    [17.372486, -6.977496, -6.977496],
-    [17.48497, -7.4624944, -6.2040677],
-    [17.48497, -6.2040677, -7.4624944],
-    [17.43384, -6.8988338, -6.8988338],
-    [17.617271, -7.7576656, -5.3707347],
-    [17.617271, -7.7576656, -5.3707347],
-    [17.617271, -5.3707347, -7.7576656],
-    [17.617271, -5.3707347, -7.7576656],
-    [17.646322, -6.6291614, -6.6291614],
-    [17.823565, -6.977496, -5.7224407],
-    [17.823565, -5.7224407, -6.977496],
-    [18.325289, -5.625, -5.625],
-    [17.91034, -6.228657, -6.228657],
-    [16.773388, -7.537858, -7.537858],
-    [17.15206, -9.2837515, -4.321776],
-    [17.260742, -9.564161, -3.125],
-    [17.424526, -8.590999, -4.7029595],
-    [17.256403, -9.204034, -4.0181465],
-    [17.35254, -9.731327, -1.875],
-    [17.398159, -9.814424, -0.625],
-    [17.375349, -9.772875, -1.25],
-    [17.529474, -8.8062525, -3.8696263],
-    [17.599136, -8.939161, -3.125],
-    [17.719128, -8.154599, -4.321776],
-    [17.980421, -8.125, -3.125],
-    [17.718893, -8.465525, -3.63861],
-    [17.686771, -9.106327, -1.875],
-    [17.730347, -9.189424, -0.625],
-    [18.153528, -8.125, -1.875],
-    [18.239578, -8.125, -0.625],
-    [17.952557, -8.636437, -1.25],
-    [18.239578, -6.875, -4.375],
-    [18.529837, -6.795851, -3.1002922],
-    [18.63184, -5.6671567, -4.464496],
-    [18.708017, -6.1119275, -3.500487],
-    [18.777624, -6.3264184, -2.6671536],
-    [18.83258, -5.2785945, -4.107494],
-    [18.906551, -5.6671567, -3.1002922],
-    [18.651932, -6.117916, -3.6303859],
-    [18.591757, -7.075191, -1.875],
-    [18.634232, -7.189165, -0.625],
-    [18.822376, -6.450191, -1.875],
-    [18.863594, -6.564165, -0.625],
-    [19.078878, -5.625, -1.875],
-    [19.160631, -5.625, -0.625],
-    [18.858578, -6.4214516, -1.25],
-    [18.189257, -7.573838, -2.5777528],
-    [17.152058, -4.321776, -9.2837515],
-    [17.424526, -4.7029595, -8.590999],
-    [17.260742, -3.125, -9.564161],
-    [17.256403, -4.0181465, -9.204033],
-    [17.35254, -1.875, -9.731327],
-    [17.398159, -0.625, -9.814424],
-    [17.375349, -1.25, -9.772875],
-    [17.529474, -3.8696263, -8.8062525],
-    [17.719128, -4.321776, -8.154599],
-    [17.599136, -3.125, -8.939161],
-    [17.980421, -3.125, -8.125],
-    [17.718893, -3.63861, -8.465525],
-    [18.239578, -4.375, -6.875],
-    [18.63184, -4.464496, -5.6671567],
-    [18.529839, -3.1002922, -6.795851],
-    [18.708017, -3.500487, -6.1119275],
-    [18.83258, -4.107494, -5.2785945],
-    [18.777624, -2.6671536, -6.3264184],
-    [18.906551, -3.1002922, -5.6671567],
-    [18.651932, -3.6303859, -6.1179156],
-    [17.686771, -1.875, -9.106327],
-    [18.153526, -1.875, -8.125],
-    [17.730347, -0.625, -9.189424],
-    [18.239578, -0.625, -8.125],
-    [17.952557, -1.25, -8.636437],
-    [18.591757, -1.875, -7.075191],
-    [18.634232, -0.625, -7.189165],
-    [18.822376, -1.875, -6.450191],
-    [19.078878, -1.875, -5.625],
-    [18.863594, -0.625, -6.564165],
-    [19.160633, -0.625, -5.625],
-    [18.85858, -1.25, -6.4214516],
-    [18.189259, -2.5777528, -7.573838],
-    [18.736036, -4.940827, -4.940827],
-    [18.95577, -4.464496, -4.464496],
-    [19.24208, -4.375, -3.125],
-    [19.24208, -3.125, -4.375],
-    [19.484573, -3.125, -3.125],
-    [19.143095, -3.9822478, -3.9822478],
-    [19.404055, -4.375, -1.875],
-    [19.484573, -4.375, -0.625],
-    [19.644651, -3.125, -1.875],
-    [19.724205, -3.125, -0.625],
-    [19.564371, -3.75, -1.25],
-    [19.404055, -1.875, -4.375],
-    [19.644653, -1.875, -3.125],
-    [19.484573, -0.625, -4.375],
-    [19.724205, -0.625, -3.125],
-    [19.564371, -1.25, -3.75],
-    [19.803429, -1.875, -1.875],
-    [19.88232, -1.875, -0.625],
-    [19.882318, -0.625, -1.875],
-    [19.960873, -0.625, -0.625],
-    [19.882235, -1.25, -1.25],
-    [19.515257, -2.6418376, -2.6418376],
-    [17.9243, -5.4766974, -5.4766974],
-    [10.126742, -10.126743, -10.126743],
-    [0.625, -19.960873, 0.625],
-    [0.625, -19.88232, 1.875],
-    [1.875, -19.88232, 0.625],
-    [1.875, -19.803429, 1.875],
-    [1.25, -19.882236, 1.25],
-    [0.625, -19.724205, 3.125],
-    [0.625, -19.484573, 4.375],
-    [1.875, -19.644651, 3.125],
-    [1.875, -19.404055, 4.375],
-    [1.25, -19.564371, 3.75],
-    [3.125, -19.724207, 0.625],
-    [3.125, -19.644651, 1.875],
-    [4.375, -19.484573, 0.625],
-    [4.375, -19.404057, 1.875],
-    [3.75, -19.564373, 1.25],
-    [3.125, -19.484573, 3.125],
-    [3.125, -19.24208, 4.375],
-    [4.375, -19.24208, 3.125],
-    [4.464496, -18.95577, 4.464496],
-    [4.940827, -18.736036, 4.940827],
-    [3.9822478, -19.143095, 3.9822478],
-    [2.6418376, -19.515259, 2.6418376],
-    [0.625, -19.160633, 5.625],
-    [0.625, -18.863594, 6.564165],
-    [0.625, -18.634232, 7.189165],
-    [1.875, -19.078878, 5.625],
-    [1.875, -18.822376, 6.450191],
-    [1.875, -18.591757, 7.075191],
-    [1.25, -18.858578, 6.421452],
-    [0.625, -18.239578, 8.125],
-    [0.625, -17.730347, 9.1894245],
-    [1.875, -18.153528, 8.125],
-    [1.875, -17.686771, 9.106327],
-    [1.25, -17.952557, 8.636438],
-    [0.625, -17.398159, 9.8144245],
-    [1.875, -17.35254, 9.731327],
-    [1.25, -17.375349, 9.772876],
-    [3.1002922, -18.906551, 5.6671567],
-    [2.6671536, -18.777624, 6.3264184],
-    [3.500487, -18.708017, 6.1119275],
-    [3.1002922, -18.529839, 6.795851],
-    [4.107494, -18.83258, 5.2785945],
-    [4.464496, -18.63184, 5.6671567],
-    [4.375, -18.239576, 6.875],
-    [3.6303859, -18.651932, 6.1179156],
-    [3.125, -17.98042, 8.125],
-    [3.125, -17.599136, 8.939161],
-    [4.321776, -17.719128, 8.154599],
-    [3.8696263, -17.529474, 8.8062525],
-    [3.63861, -17.718893, 8.465525],
-    [3.125, -17.260742, 9.564161],
-    [4.7029595, -17.424526, 8.590999],
-    [4.321776, -17.152058, 9.2837515],
-    [4.0181465, -17.256403, 9.204033],
-    [2.5777528, -18.189257, 7.5738397],
-    [5.625, -19.160631, 0.625],
-    [5.625, -19.078878, 1.875],
-    [6.564165, -18.863594, 0.625],
-    [6.450191, -18.822376, 1.875],
-    [7.189165, -18.634232, 0.625],
-    [7.075191, -18.591757, 1.875],
-    [6.421452, -18.858578, 1.25],
-    [5.6671567, -18.906551, 3.1002922],
-    [5.2785945, -18.83258, 4.107494],
-    [6.1119275, -18.708017, 3.500487],
-    [5.6671567, -18.63184, 4.464496],
-    [6.3264184, -18.777624, 2.6671536],
-    [6.795851, -18.529837, 3.1002922],
-    [6.875, -18.239578, 4.375],
-    [6.117916, -18.651932, 3.6303859],
-    [8.125, -18.239578, 0.625],
-    [8.125, -18.153526, 1.875],
-    [9.1894245, -17.730347, 0.625],
-    [9.106327, -17.686771, 1.875],
-    [8.636438, -17.952557, 1.25],
-    [8.125, -17.98042, 3.125],
-    [8.154599, -17.719128, 4.321776],
-    [8.939161, -17.599136, 3.125],
-    [8.8062525, -17.529474, 3.8696263],
-    [8.465525, -17.718893, 3.63861],
-    [9.8144245, -17.398159, 0.625],
-    [9.731327, -17.35254, 1.875],
-    [9.772876, -17.375349, 1.25],
-    [8.590999, -17.424526, 4.7029595],
-    [9.564161, -17.260742, 3.125],
-    [9.2837515, -17.15206, 4.321776],
-    [9.204034, -17.256403, 4.0181465],
-    [7.573839, -18.189259, 2.5777528],
-    [5.625, -18.325289, 5.625],
-    [5.7224407, -17.823565, 6.977496],
-    [6.977496, -17.823565, 5.7224407],
-    [6.6291614, -17.646322, 6.6291614],
-    [6.228657, -17.91034, 6.228657],
-    [5.3707347, -17.617271, 7.7576656],
-    [5.3707347, -17.617271, 7.7576656],
-    [6.2040677, -17.48497, 7.4624944],
-    [7.4624944, -17.48497, 6.2040677],
-    [6.977496, -17.372486, 6.977496],
-    [6.8988338, -17.43384, 6.8988338],
-    [5.7224407, -17.317713, 8.154599],
-    [5.6948266, -16.642796, 9.467094],
-    [6.1580443, -16.217432, 9.945158],
-    [6.875, -16.903101, 8.125],
-    [6.875, -16.42622, 9.063559],
-    [6.875, -16.064978, 9.688559],
-    [6.3224187, -16.641281, 9.018097],
-    [7.7576656, -17.617271, 5.3707347],
-    [7.7576656, -17.617271, 5.3707347],
-    [8.154599, -17.317713, 5.7224407],
-    [8.125, -16.9031, 6.875],
-    [9.467094, -16.642796, 5.6948266],
-    [9.063559, -16.42622, 6.875],
-    [9.945158, -16.217432, 6.1580443],
-    [9.688559, -16.064978, 6.875],
-    [9.018097, -16.641281, 6.3224187],
-    [8.038871, -16.428444, 8.038871],
-    [7.564785, -16.2816, 8.8062525],
-    [8.398118, -16.069181, 8.398118],
-    [8.141557, -15.6358595, 9.38943],
-    [8.8062525, -16.2816, 7.564785],
-    [9.38943, -15.6358595, 8.141557],
-    [9.139274, -15.235847, 9.139274],
-    [8.520881, -15.90956, 8.520882],
-    [7.537858, -16.773388, 7.5378585],
-    [8.718328, -14.982282, 9.972607],
-    [9.972607, -14.982282, 8.718328],
-    [9.483564, -14.806005, 9.483564],
-    [9.408239, -14.902155, 9.408238],
-    [9.408239, -14.902155, 9.408238],
-    [5.476698, -17.9243, 5.4766984],
-    [0.625, -16.903101, 10.625],
-    [0.625, -16.379942, 11.433281],
-    [0.625, -15.916278, 12.058281],
-    [1.875, -16.810333, 10.625],
-    [1.875, -16.332794, 11.366785],
-    [1.875, -15.866021, 11.991785],
-    [1.25, -16.368078, 11.350021],
-    [0.625, -15.291278, 12.845437],
-    [1.875, -15.241021, 12.785843],
-    [1.25, -15.2661495, 12.8156395],
-    [3.1002922, -16.577555, 10.706492],
-    [2.6671536, -16.2816, 11.294578],
-    [3.500487, -16.201096, 11.177487],
-    [3.1002922, -15.832608, 11.776747],
-    [4.375, -16.49668, 10.388045],
-    [4.375, -16.090162, 11.013045],
-    [4.3575616, -15.402763, 11.948149],
-    [3.6652985, -16.098625, 11.2130165],
-    [3.125, -15.139938, 12.665969],
-    [3.9292688, -15.051056, 12.560555],
-    [3.4696867, -15.101846, 12.620791],
-    [2.508551, -15.993178, 11.594833],
-    [0.625, -14.745259, 13.470437],
-    [0.625, -14.120259, 14.120259],
-    [0.625, -13.470437, 14.745259],
-    [1.875, -14.690951, 13.410843],
-    [1.875, -14.065951, 14.065951],
-    [1.875, -13.410843, 14.690951],
-    [1.25, -14.08395, 14.08395],
-    [4.7626023, -14.928902, 12.413581],
-    [4.7626023, -14.928902, 12.413581],
-    [3.125, -14.581705, 13.290969],
-    [3.125, -13.956705, 13.956705],
-    [3.125, -13.290969, 14.581705],
-    [4.4054027, -14.385528, 13.135811],
-    [4.048203, -13.842153, 13.842153],
-    [4.881536, -13.711067, 13.711067],
-    [4.4054027, -13.135811, 14.385528],
-    [3.9051352, -13.83684, 13.836841],
-    [4.7626023, -12.413581, 14.928902],
-    [4.7626023, -12.413581, 14.928902],
-    [2.928828, -13.919124, 13.919124],
-    [0.625, -12.845437, 15.291279],
-    [1.875, -12.785843, 15.241021],
-    [1.25, -12.8156395, 15.2661495],
-    [0.625, -12.05828, 15.916279],
-    [0.625, -11.433281, 16.379944],
-    [0.625, -10.625, 16.903103],
-    [1.875, -11.991785, 15.866021],
-    [1.875, -11.366784, 16.332794],
-    [1.875, -10.625, 16.810333],
-    [1.25, -11.350021, 16.36808],
-    [3.125, -12.665969, 15.139938],
-    [3.9292688, -12.560555, 15.051056],
-    [3.4696867, -12.620791, 15.101846],
-    [3.1002922, -11.776747, 15.832608],
-    [2.6671536, -11.294578, 16.2816],
-    [3.500487, -11.177487, 16.201096],
-    [3.1002922, -10.706492, 16.577555],
-    [4.3575616, -11.948149, 15.402763],
-    [4.375, -11.013046, 16.090162],
-    [4.375, -10.388045, 16.49668],
-    [3.6652985, -11.2130165, 16.098625],
-    [2.508551, -11.594833, 15.993178],
-    [5.324711, -16.36011, 10.17324],
-    [5.6948266, -15.943033, 10.603945],
-    [5.625, -15.265716, 11.597614],
-    [6.88703, -15.413083, 10.677339],
-    [6.478384, -15.101833, 11.383239],
-    [6.040929, -15.611464, 10.873316],
-    [7.884995, -15.202538, 10.295564],
-    [7.884995, -15.202538, 10.295564],
-    [6.281459, -15.5581255, 10.797956],
-    [5.625, -14.771767, 12.222614],
-    [7.3117175, -14.911034, 11.128898],
-    [6.9704394, -14.422244, 11.93085],
-    [7.4624944, -13.730554, 12.478462],
-    [6.790329, -14.4715805, 11.956102],
-    [5.625, -14.146767, 12.933707],
-    [5.625, -13.558707, 13.558707],
-    [5.625, -12.933707, 14.146767],
-    [6.6291614, -13.933454, 12.700542],
-    [7.045828, -13.2155485, 13.2155485],
-    [6.6291614, -12.700542, 13.933454],
-    [6.231247, -13.406333, 13.406334],
-    [5.625, -12.222614, 14.771767],
-    [7.4624944, -12.478462, 13.730554],
-    [6.9704394, -11.930851, 14.422244],
-    [7.3117175, -11.128898, 14.911034],
-    [6.790329, -11.956102, 14.471581],
-    [8.230997, -14.7412815, 10.677339],
-    [8.125, -14.06489, 11.629698],
-    [8.125, -13.528497, 12.254698],
-    [9.334143, -14.121927, 10.603945],
-    [8.890239, -13.842153, 11.361135],
-    [9.723573, -13.553721, 11.006574],
-    [9.334767, -13.097248, 11.843793],
-    [8.822525, -13.84869, 11.336995],
-    [8.125, -12.903498, 12.903498],
-    [8.945962, -12.640775, 12.640775],
-    [8.476841, -12.790902, 12.790902],
-    [9.779296, -12.326451, 12.326451],
-    [9.779296, -12.326451, 12.326451],
-    [8.125, -12.254698, 13.528498],
-    [8.125, -11.629698, 14.06489],
-    [8.230997, -10.677339, 14.7412815],
-    [9.334767, -11.843793, 13.097249],
-    [8.890239, -11.361135, 13.842153],
-    [9.723573, -11.006574, 13.553721],
-    [9.334143, -10.603945, 14.121927],
-    [8.822525, -11.336995, 13.848689],
-    [7.816411, -12.910167, 12.910168],
-    [5.625, -11.597614, 15.265717],
-    [5.6948266, -10.603945, 15.943033],
-    [5.324711, -10.17324, 16.36011],
-    [6.478384, -11.383239, 15.101833],
-    [6.88703, -10.677339, 15.413081],
-    [6.040929, -10.873316, 15.611463],
-    [7.884995, -10.295564, 15.202538],
-    [7.884995, -10.295564, 15.202538],
-    [6.281459, -10.797956, 15.558124],
-    [4.8660526, -13.434792, 13.434791],
-    [0.625, -9.814424, 17.398159],
-    [1.875, -9.731327, 17.35254],
-    [1.25, -9.772875, 17.375349],
-    [0.625, -9.189424, 17.730347],
-    [0.625, -8.125, 18.239578],
-    [1.875, -9.106327, 17.686771],
-    [1.875, -8.125, 18.153526],
-    [1.25, -8.636437, 17.952557],
-    [0.625, -7.189165, 18.634232],
-    [0.625, -6.564165, 18.863594],
-    [0.625, -5.625, 19.160633],
-    [1.875, -7.075191, 18.591757],
-    [1.875, -6.450191, 18.822376],
-    [1.875, -5.625, 19.078878],
-    [1.25, -6.421452, 18.858578],
-    [3.125, -9.564161, 17.260742],
-    [4.321776, -9.2837515, 17.15206],
-    [4.7029595, -8.590999, 17.424526],
-    [4.0181465, -9.204033, 17.256403],
-    [3.125, -8.939161, 17.599136],
-    [3.125, -8.125, 17.980421],
-    [3.8696263, -8.8062525, 17.529474],
-    [4.321776, -8.154599, 17.719128],
-    [3.63861, -8.465525, 17.718893],
-    [3.1002922, -6.795851, 18.529837],
-    [2.6671536, -6.3264184, 18.777624],
-    [3.500487, -6.1119275, 18.708017],
-    [3.1002922, -5.6671567, 18.906551],
-    [4.375, -6.875, 18.239578],
-    [4.464496, -5.6671567, 18.63184],
-    [4.107494, -5.2785945, 18.83258],
-    [3.6303859, -6.1179156, 18.651932],
-    [2.5777528, -7.573839, 18.189259],
-    [0.625, -4.375, 19.484573],
-    [0.625, -3.125, 19.724205],
-    [1.875, -4.375, 19.404055],
-    [1.875, -3.125, 19.644653],
-    [1.25, -3.75, 19.564371],
-    [0.625, -1.875, 19.882318],
-    [0.625, -0.625, 19.960873],
-    [1.875, -1.875, 19.803429],
-    [1.875, -0.625, 19.88232],
-    [1.25, -1.25, 19.882235],
-    [3.125, -4.375, 19.24208],
-    [3.125, -3.125, 19.484573],
-    [4.940827, -4.940827, 18.736036],
-    [4.464496, -4.464496, 18.95577],
-    [4.375, -3.125, 19.24208],
-    [3.9822478, -3.9822478, 19.143095],
-    [3.125, -1.875, 19.644651],
-    [3.125, -0.625, 19.724205],
-    [4.375, -1.875, 19.404055],
-    [4.375, -0.625, 19.484573],
-    [3.75, -1.25, 19.564371],
-    [2.6418376, -2.6418376, 19.515257],
-    [8.718328, -9.972607, 14.982282],
-    [9.483564, -9.483564, 14.806005],
-    [9.972607, -8.718328, 14.982282],
-    [9.408239, -9.408238, 14.902155],
-    [9.408239, -9.408238, 14.902155],
-    [6.1580443, -9.945158, 16.217432],
-    [5.6948266, -9.467094, 16.642797],
-    [5.7224407, -8.154599, 17.317713],
-    [6.875, -9.688559, 16.064978],
-    [6.875, -9.063559, 16.42622],
-    [6.875, -8.125, 16.903101],
-    [6.3224187, -9.018096, 16.641281],
-    [5.3707347, -7.7576656, 17.617271],
-    [5.3707347, -7.7576656, 17.617271],
-    [6.2040677, -7.4624944, 17.48497],
-    [6.977496, -6.977496, 17.372486],
-    [7.4624944, -6.2040677, 17.48497],
-    [6.8988338, -6.8988338, 17.43384],
-    [5.7224407, -6.977496, 17.823565],
-    [5.625, -5.625, 18.325289],
-    [6.6291614, -6.6291614, 17.646322],
-    [6.977496, -5.7224407, 17.823565],
-    [6.228657, -6.228657, 17.91034],
-    [8.141557, -9.38943, 15.6358595],
-    [7.564785, -8.8062525, 16.2816],
-    [8.398118, -8.398118, 16.069181],
-    [8.038871, -8.038871, 16.428442],
-    [9.139274, -9.139274, 15.235847],
-    [9.38943, -8.141557, 15.6358595],
-    [8.8062525, -7.564785, 16.2816],
-    [8.520882, -8.520881, 15.909558],
-    [8.125, -6.875, 16.9031],
-    [8.154599, -5.7224407, 17.317713],
-    [9.688559, -6.875, 16.064978],
-    [9.063559, -6.875, 16.42622],
-    [9.945158, -6.1580443, 16.217432],
-    [9.467094, -5.6948266, 16.642797],
-    [9.018097, -6.3224187, 16.641281],
-    [7.7576656, -5.3707347, 17.617271],
-    [7.7576656, -5.3707347, 17.617271],
-    [7.537858, -7.537858, 16.773388],
-    [5.6671567, -4.464496, 18.63184],
-    [5.2785945, -4.107494, 18.83258],
-    [6.1119275, -3.500487, 18.708017],
-    [5.6671567, -3.1002922, 18.906551],
-    [6.875, -4.375, 18.239578],
-    [6.795851, -3.1002922, 18.529839],
-    [6.3264184, -2.6671536, 18.777624],
-    [6.1179156, -3.6303859, 18.651932],
-    [5.625, -1.875, 19.078878],
-    [5.625, -0.625, 19.160631],
-    [7.075191, -1.875, 18.591757],
-    [6.450191, -1.875, 18.822376],
-    [7.189165, -0.625, 18.634232],
-    [6.564165, -0.625, 18.863594],
-    [6.421452, -1.25, 18.858578],
-    [8.590999, -4.7029595, 17.424526],
-    [9.2837515, -4.321776, 17.152058],
-    [9.564161, -3.125, 17.260742],
-    [9.204034, -4.0181465, 17.256403],
-    [8.154599, -4.321776, 17.719128],
-    [8.125, -3.125, 17.980421],
-    [8.8062525, -3.8696263, 17.529474],
-    [8.939161, -3.125, 17.599136],
-    [8.465525, -3.63861, 17.718893],
-    [9.731327, -1.875, 17.35254],
-    [9.814424, -0.625, 17.398159],
-    [9.772875, -1.25, 17.375349],
-    [8.125, -1.875, 18.153528],
-    [8.125, -0.625, 18.239578],
-    [9.106327, -1.875, 17.686771],
-    [9.189424, -0.625, 17.730347],
-    [8.636437, -1.25, 17.952557],
-    [7.573839, -2.5777528, 18.189257],
-    [5.476698, -5.476698, 17.9243],
-    [10.625, -16.903103, 0.625],
-    [10.625, -16.810335, 1.875],
-    [11.433281, -16.379942, 0.625],
-    [11.366785, -16.332794, 1.875],
-    [12.058281, -15.916278, 0.625],
-    [11.991785, -15.866021, 1.875],
-    [11.350021, -16.368078, 1.25],
-    [10.706492, -16.577555, 3.1002922],
-    [10.388045, -16.49668, 4.375],
-    [11.177487, -16.201096, 3.500487],
-    [11.013045, -16.090162, 4.375],
-    [11.294578, -16.2816, 2.6671536],
-    [11.776747, -15.832608, 3.1002922],
-    [11.948149, -15.402763, 4.3575616],
-    [11.213018, -16.098625, 3.6652985],
-    [12.845437, -15.291278, 0.625],
-    [12.785843, -15.241021, 1.875],
-    [12.8156395, -15.2661495, 1.25],
-    [12.665969, -15.139938, 3.125],
-    [12.560555, -15.051056, 3.9292688],
-    [12.620791, -15.101846, 3.4696867],
-    [11.594833, -15.993178, 2.508551],
-    [10.17324, -16.36011, 5.324711],
-    [10.603945, -15.943031, 5.6948266],
-    [10.677339, -15.413083, 6.88703],
-    [11.597614, -15.265716, 5.625],
-    [11.383239, -15.101833, 6.478384],
-    [10.873316, -15.611463, 6.040929],
-    [10.295564, -15.202538, 7.884995],
-    [10.295564, -15.202538, 7.884995],
-    [10.797956, -15.558124, 6.281459],
-    [12.413581, -14.928902, 4.7626023],
-    [12.413581, -14.928902, 4.7626023],
-    [13.470437, -14.745259, 0.625],
-    [13.410843, -14.690951, 1.875],
-    [14.120259, -14.120259, 0.625],
-    [14.065951, -14.065951, 1.875],
-    [14.745259, -13.470437, 0.625],
-    [14.690951, -13.410843, 1.875],
-    [14.08395, -14.08395, 1.25],
-    [13.290969, -14.581705, 3.125],
-    [13.135811, -14.385528, 4.4054027],
-    [13.711067, -13.711067, 4.881536],
-    [13.956705, -13.956705, 3.125],
-    [13.842153, -13.842153, 4.048203],
-    [14.581705, -13.290969, 3.125],
-    [14.385528, -13.135811, 4.4054027],
-    [13.83684, -13.836841, 3.9051352],
-    [14.928902, -12.413581, 4.7626023],
-    [14.928902, -12.413581, 4.7626023],
-    [13.919125, -13.919125, 2.928828],
-    [11.128898, -14.911034, 7.3117175],
-    [12.222614, -14.771767, 5.625],
-    [11.93085, -14.422244, 6.9704394],
-    [12.478462, -13.730554, 7.4624944],
-    [11.956101, -14.471581, 6.7903295],
-    [10.677339, -14.7412815, 8.230997],
-    [10.603945, -14.121927, 9.334143],
-    [11.006574, -13.553721, 9.723573],
-    [11.629698, -14.06489, 8.125],
-    [11.361135, -13.842153, 8.890239],
-    [12.254698, -13.528498, 8.125],
-    [11.843793, -13.097248, 9.334767],
-    [11.336995, -13.848691, 8.822524],
-    [12.326451, -12.326451, 9.779296],
-    [12.326451, -12.326451, 9.779296],
-    [12.933707, -14.146767, 5.625],
-    [12.700542, -13.933454, 6.6291614],
-    [13.558707, -13.558707, 5.625],
-    [13.2155485, -13.2155485, 7.045828],
-    [14.146767, -12.933707, 5.625],
-    [13.933454, -12.700542, 6.6291614],
-    [13.406334, -13.406333, 6.231247],
-    [12.903498, -12.903498, 8.125],
-    [12.640775, -12.640775, 8.945962],
-    [12.790902, -12.790902, 8.476841],
-    [13.730554, -12.478462, 7.4624944],
-    [14.771767, -12.222614, 5.625],
-    [14.422244, -11.93085, 6.9704394],
-    [14.911034, -11.128898, 7.3117175],
-    [14.4715805, -11.956102, 6.7903295],
-    [13.528498, -12.254698, 8.125],
-    [13.097248, -11.843793, 9.334767],
-    [13.553721, -11.006574, 9.723573],
-    [14.06489, -11.629698, 8.125],
-    [13.842153, -11.361135, 8.890239],
-    [14.7412815, -10.677339, 8.230997],
-    [14.121927, -10.603945, 9.334143],
-    [13.84869, -11.336995, 8.822524],
-    [12.910168, -12.910169, 7.81641],
-    [15.291279, -12.845437, 0.625],
-    [15.241021, -12.785843, 1.875],
-    [15.2661495, -12.8156395, 1.25],
-    [15.139938, -12.665969, 3.125],
-    [15.051056, -12.560555, 3.9292688],
-    [15.101846, -12.620791, 3.4696867],
-    [15.916279, -12.058281, 0.625],
-    [15.866021, -11.991784, 1.875],
-    [16.379944, -11.433281, 0.625],
-    [16.332794, -11.366784, 1.875],
-    [16.903103, -10.625, 0.625],
-    [16.810335, -10.625, 1.875],
-    [16.36808, -11.350021, 1.25],
-    [15.832608, -11.776747, 3.1002922],
-    [15.402763, -11.948149, 4.3575616],
-    [16.201096, -11.177487, 3.500487],
-    [16.090162, -11.013046, 4.375],
-    [16.2816, -11.294578, 2.6671536],
-    [16.577555, -10.706492, 3.1002922],
-    [16.49668, -10.388045, 4.375],
-    [16.098625, -11.213018, 3.6652985],
-    [15.993178, -11.594834, 2.508551],
-    [15.265717, -11.597614, 5.625],
-    [15.101833, -11.383239, 6.478384],
-    [15.943033, -10.603945, 5.6948266],
-    [15.413081, -10.677339, 6.88703],
-    [16.36011, -10.17324, 5.324711],
-    [15.611464, -10.873316, 6.0409284],
-    [15.202538, -10.295564, 7.884995],
-    [15.202538, -10.295564, 7.884995],
-    [15.5581255, -10.797956, 6.281459],
-    [13.434792, -13.434792, 4.866052],
-    [10.17324, -13.878777, 10.17324],
-    [10.638885, -13.137054, 10.638885],
-    [10.271195, -12.720387, 11.493118],
-    [11.493118, -12.720387, 10.271195],
-    [10.6430645, -13.1187315, 10.6430645],
-    [11.104529, -12.370105, 11.104529],
-    [10.687862, -11.931611, 11.931611],
-    [11.104529, -11.104529, 12.370105],
-    [11.931611, -11.931611, 10.687862],
-    [11.536771, -11.536771, 11.536771],
-    [12.370105, -11.104529, 11.104529],
-    [11.931611, -10.687862, 11.931611],
-    [11.521811, -11.521811, 11.521811],
-    [10.271195, -11.493118, 12.720387],
-    [10.638885, -10.638885, 13.137054],
-    [10.17324, -10.17324, 13.878777],
-    [11.493118, -10.271195, 12.720387],
-    [10.6430645, -10.6430645, 13.1187315],
-    [12.720387, -11.493118, 10.271195],
-    [13.137054, -10.638885, 10.638885],
-    [12.720387, -10.271195, 11.493118],
-    [13.878777, -10.17324, 10.17324],
-    [13.1187315, -10.6430645, 10.6430645],
-    [11.489697, -11.489697, 11.489697],
-    [11.489697, -11.489697, 11.489697],
-    [14.982282, -9.972607, 8.718328],
-    [14.806005, -9.483564, 9.483564],
-    [14.982282, -8.718328, 9.972607],
-    [14.902155, -9.408239, 9.408238],
-    [14.902155, -9.408239, 9.408238],
-    [17.398159, -9.814424, 0.625],
-    [17.35254, -9.731327, 1.875],
-    [17.375349, -9.772875, 1.25],
-    [17.260742, -9.564161, 3.125],
-    [17.15206, -9.2837515, 4.321776],
-    [17.424526, -8.590999, 4.7029595],
-    [17.256403, -9.204034, 4.0181465],
-    [17.730347, -9.189424, 0.625],
-    [17.686771, -9.106327, 1.875],
-    [18.239578, -8.125, 0.625],
-    [18.153528, -8.125, 1.875],
-    [17.952557, -8.636437, 1.25],
-    [17.599136, -8.939161, 3.125],
-    [17.529474, -8.8062525, 3.8696263],
-    [17.980421, -8.125, 3.125],
-    [17.719128, -8.154599, 4.321776],
-    [17.718895, -8.465525, 3.63861],
-    [18.634232, -7.189165, 0.625],
-    [18.591757, -7.075191, 1.875],
-    [18.863594, -6.564165, 0.625],
-    [18.822376, -6.450191, 1.875],
-    [19.160631, -5.625, 0.625],
-    [19.078878, -5.625, 1.875],
-    [18.858578, -6.421452, 1.25],
-    [18.529837, -6.795851, 3.1002922],
-    [18.239576, -6.875, 4.375],
-    [18.708017, -6.1119275, 3.500487],
-    [18.63184, -5.6671567, 4.464496],
-    [18.777624, -6.3264184, 2.6671536],
-    [18.906551, -5.6671567, 3.1002922],
-    [18.83258, -5.2785945, 4.107494],
-    [18.651932, -6.117916, 3.6303859],
-    [18.189259, -7.573839, 2.5777528],
-    [16.217432, -9.945158, 6.1580443],
-    [16.064978, -9.688559, 6.875],
-    [16.642797, -9.467094, 5.6948266],
-    [16.42622, -9.063559, 6.875],
-    [17.317713, -8.154599, 5.7224407],
-    [16.9031, -8.125, 6.875],
-    [16.641281, -9.018096, 6.3224187],
-    [15.6358595, -9.38943, 8.141557],
-    [15.235847, -9.139274, 9.139274],
-    [16.069181, -8.398118, 8.398118],
-    [15.6358595, -8.141557, 9.38943],
-    [16.2816, -8.8062525, 7.564785],
-    [16.428444, -8.038871, 8.038871],
-    [16.2816, -7.564785, 8.8062525],
-    [15.909559, -8.520881, 8.520882],
-    [17.48497, -7.4624944, 6.2040677],
-    [17.372486, -6.977496, 6.977496],
-    [17.48497, -6.2040677, 7.4624944],
-    [17.43384, -6.8988338, 6.8988338],
-    [16.064978, -6.875, 9.688559],
-    [16.217432, -6.1580443, 9.945158],
-    [16.903101, -6.875, 8.125],
-    [16.42622, -6.875, 9.063559],
-    [17.317713, -5.7224407, 8.154599],
-    [16.642796, -5.6948266, 9.467094],
-    [16.641281, -6.3224187, 9.018096],
-    [17.617271, -7.7576656, 5.3707347],
-    [17.617271, -7.7576656, 5.3707347],
-    [17.823565, -6.977496, 5.7224407],
-    [17.646322, -6.6291614, 6.6291614],
-    [18.325289, -5.625, 5.625],
-    [17.823565, -5.7224407, 6.977496],
-    [17.91034, -6.228657, 6.228657],
-    [17.617271, -5.3707347, 7.7576656],
-    [17.617271, -5.3707347, 7.7576656],
-    [16.773388, -7.537858, 7.537858],
-    [19.484573, -4.375, 0.625],
-    [19.404057, -4.375, 1.875],
-    [19.724205, -3.125, 0.625],
-    [19.644651, -3.125, 1.875],
-    [19.564371, -3.75, 1.25],
-    [18.736036, -4.940827, 4.940827],
-    [19.24208, -4.375, 3.125],
-    [18.95577, -4.464496, 4.464496],
-    [19.484573, -3.125, 3.125],
-    [19.24208, -3.125, 4.375],
-    [19.143095, -3.9822478, 3.9822478],
-    [19.88232, -1.875, 0.625],
-    [19.803429, -1.875, 1.875],
-    [19.960873, -0.625, 0.625],
-    [19.882318, -0.625, 1.875],
-    [19.882235, -1.25, 1.25],
-    [19.644653, -1.875, 3.125],
-    [19.404055, -1.875, 4.375],
-    [19.724205, -0.625, 3.125],
-    [19.484573, -0.625, 4.375],
-    [19.564371, -1.25, 3.75],
-    [19.515257, -2.6418376, 2.6418376],
-    [17.424526, -4.7029595, 8.590999],
-    [17.152058, -4.321776, 9.2837515],
-    [17.260742, -3.125, 9.564161],
-    [17.256403, -4.0181465, 9.204033],
-    [17.35254, -1.875, 9.731327],
-    [17.398159, -0.625, 9.814424],
-    [17.375349, -1.25, 9.772875],
-    [18.63184, -4.464496, 5.6671567],
-    [18.239578, -4.375, 6.875],
-    [18.708017, -3.500487, 6.1119275],
-    [18.529839, -3.1002922, 6.795851],
-    [18.83258, -4.107494, 5.2785945],
-    [18.906551, -3.1002922, 5.6671567],
-    [18.777624, -2.6671536, 6.3264184],
-    [18.651934, -3.6303859, 6.1179156],
-    [17.719128, -4.321776, 8.154599],
-    [17.529474, -3.8696263, 8.8062525],
-    [17.98042, -3.125, 8.125],
-    [17.599136, -3.125, 8.939161],
-    [17.718893, -3.63861, 8.465525],
-    [18.591757, -1.875, 7.075191],
-    [18.634232, -0.625, 7.189165],
-    [19.078878, -1.875, 5.625],
-    [18.822376, -1.875, 6.450191],
-    [19.160633, -0.625, 5.625],
-    [18.863594, -0.625, 6.564165],
-    [18.85858, -1.25, 6.4214516],
-    [18.153526, -1.875, 8.125],
-    [17.686771, -1.875, 9.106327],
-    [18.239578, -0.625, 8.125],
-    [17.730347, -0.625, 9.189424],
-    [17.952557, -1.25, 8.636437],
-    [18.189259, -2.5777528, 7.573838],
-    [17.9243, -5.476698, 5.4766974],
-    [12.326451, -9.779296, 12.326451],
-    [12.326451, -9.779296, 12.326451],
-    [11.006574, -9.723573, 13.553721],
-    [10.603945, -9.334143, 14.121927],
-    [10.677339, -8.230997, 14.7412815],
-    [11.843793, -9.334767, 13.097248],
-    [11.361135, -8.890239, 13.842153],
-    [12.254698, -8.125, 13.528498],
-    [11.629698, -8.125, 14.06489],
-    [11.336996, -8.822524, 13.84869],
-    [11.128898, -7.3117175, 14.911034],
-    [12.478462, -7.4624944, 13.730554],
-    [11.93085, -6.9704385, 14.422244],
-    [12.222614, -5.625, 14.771767],
-    [11.956102, -6.790329, 14.471581],
-    [13.553721, -9.723573, 11.006574],
-    [13.097248, -9.334767, 11.843793],
-    [13.528497, -8.125, 12.254698],
-    [14.121927, -9.334143, 10.603945],
-    [13.842153, -8.890239, 11.361135],
-    [14.7412815, -8.230997, 10.677339],
-    [14.06489, -8.125, 11.629698],
-    [13.84869, -8.822524, 11.336996],
-    [12.640775, -8.945962, 12.640775],
-    [12.903498, -8.125, 12.903497],
-    [12.790902, -8.476841, 12.790902],
-    [13.730554, -7.4624944, 12.478462],
-    [14.911034, -7.3117175, 11.128898],
-    [14.422244, -6.9704385, 11.93085],
-    [14.771767, -5.625, 12.222614],
-    [14.4715805, -6.790329, 11.956102],
-    [13.2155485, -7.0458274, 13.2155485],
-    [12.700542, -6.6291614, 13.933454],
-    [13.558707, -5.625, 13.558707],
-    [12.933707, -5.625, 14.146767],
-    [13.933454, -6.6291614, 12.700542],
-    [14.146767, -5.625, 12.933707],
-    [13.406334, -6.231247, 13.406334],
-    [12.910169, -7.81641, 12.910168],
-    [10.295564, -7.884995, 15.202538],
-    [10.295564, -7.884995, 15.202538],
-    [10.677339, -6.88703, 15.413081],
-    [10.603945, -5.6948266, 15.943033],
-    [10.17324, -5.324711, 16.36011],
-    [11.383239, -6.478384, 15.101833],
-    [11.597614, -5.625, 15.265717],
-    [10.873315, -6.040929, 15.611463],
-    [10.7979555, -6.281459, 15.558124],
-    [12.413581, -4.7626023, 14.928902],
-    [12.413581, -4.7626023, 14.928902],
-    [14.928902, -4.7626023, 12.413581],
-    [14.928902, -4.7626023, 12.413581],
-    [13.711067, -4.881536, 13.711067],
-    [13.135811, -4.4054027, 14.385528],
-    [13.290969, -3.125, 14.581705],
-    [14.385528, -4.4054027, 13.135811],
-    [13.842153, -4.048203, 13.842153],
-    [14.581705, -3.125, 13.290969],
-    [13.956705, -3.125, 13.956705],
-    [13.83684, -3.905135, 13.83684],
-    [13.410843, -1.875, 14.690951],
-    [13.470437, -0.625, 14.745259],
-    [14.690951, -1.875, 13.410843],
-    [14.065951, -1.875, 14.065951],
-    [14.745259, -0.625, 13.470437],
-    [14.120259, -0.625, 14.120259],
-    [14.08395, -1.25, 14.08395],
-    [13.919124, -2.9288278, 13.919125],
-    [11.013046, -4.375, 16.090162],
-    [10.388045, -4.375, 16.49668],
-    [11.177487, -3.500487, 16.201096],
-    [10.706492, -3.1002922, 16.577555],
-    [11.948149, -4.3575616, 15.402763],
-    [11.776747, -3.1002922, 15.832608],
-    [11.294578, -2.6671536, 16.2816],
-    [11.2130165, -3.6652985, 16.098623],
-    [10.625, -1.875, 16.810333],
-    [10.625, -0.625, 16.903103],
-    [11.991785, -1.875, 15.866021],
-    [11.366785, -1.875, 16.332794],
-    [12.05828, -0.625, 15.916278],
-    [11.433281, -0.625, 16.379942],
-    [11.350021, -1.25, 16.368078],
-    [12.560555, -3.9292688, 15.051056],
-    [12.665969, -3.125, 15.139938],
-    [12.620791, -3.4696867, 15.101846],
-    [12.785843, -1.875, 15.241021],
-    [12.845437, -0.625, 15.291278],
-    [12.8156395, -1.25, 15.2661495],
-    [11.594834, -2.508551, 15.993176],
-    [15.202538, -7.884995, 10.295564],
-    [15.202538, -7.884995, 10.295564],
-    [15.413083, -6.88703, 10.677339],
-    [15.101833, -6.478384, 11.383239],
-    [15.943031, -5.6948266, 10.603945],
-    [15.265717, -5.625, 11.597614],
-    [16.36011, -5.324711, 10.17324],
-    [15.611463, -6.0409284, 10.873316],
-    [15.558124, -6.281459, 10.797956],
-    [16.090162, -4.375, 11.013045],
-    [15.402763, -4.3575616, 11.948149],
-    [16.201096, -3.500487, 11.177487],
-    [15.832608, -3.1002922, 11.776747],
-    [16.49668, -4.375, 10.388045],
-    [16.577555, -3.1002922, 10.706492],
-    [16.2816, -2.6671536, 11.294578],
-    [16.098623, -3.6652985, 11.2130165],
-    [15.051056, -3.9292688, 12.560555],
-    [15.139938, -3.125, 12.665969],
-    [15.101846, -3.4696867, 12.620791],
-    [15.866021, -1.875, 11.991785],
-    [15.916278, -0.625, 12.058281],
-    [16.810335, -1.875, 10.625],
-    [16.332794, -1.875, 11.366785],
-    [16.903103, -0.625, 10.625],
-    [16.379942, -0.625, 11.43328],
-    [16.368078, -1.25, 11.350021],
-    [15.241021, -1.875, 12.785843],
-    [15.291278, -0.625, 12.845437],
-    [15.2661495, -1.25, 12.8156395],
-    [15.993176, -2.508551, 11.594832],
-    [13.434792, -4.866052, 13.434792],
-    [10.126742, -10.126742, 10.126742],
-    [0.625, 0.625, -19.960873],
-    [0.625, 1.875, -19.88232],
-    [1.875, 0.625, -19.88232],
-    [1.875, 1.875, -19.803429],
-    [1.25, 1.25, -19.882236],
-    [0.625, 3.125, -19.724205],
-    [0.625, 4.375, -19.484573],
-    [1.875, 3.125, -19.644651],
-    [1.875, 4.375, -19.404055],
-    [1.25, 3.75, -19.564371],
-    [3.125, 0.625, -19.724207],
-    [3.125, 1.875, -19.644651],
-    [4.375, 0.625, -19.484573],
-    [4.375, 1.875, -19.404057],
-    [3.75, 1.25, -19.564373],
-    [3.125, 3.125, -19.484573],
-    [3.125, 4.375, -19.24208],
-    [4.375, 3.125, -19.24208],
-    [4.464496, 4.464496, -18.95577],
-    [4.940827, 4.940827, -18.736036],
-    [3.9822478, 3.9822478, -19.143095],
-    [2.6418376, 2.6418376, -19.515259],
-    [0.625, 5.625, -19.160633],
-    [0.625, 6.564165, -18.863594],
-    [0.625, 7.189165, -18.634232],
-    [1.875, 5.625, -19.078878],
-    [1.875, 6.450191, -18.822376],
-    [1.875, 7.075191, -18.591757],
-    [1.25, 6.421452, -18.858578],
-    [0.625, 8.125, -18.239578],
-    [0.625, 9.189424, -17.730347],
-    [1.875, 8.125, -18.153528],
-    [1.875, 9.106327, -17.686771],
-    [1.25, 8.636437, -17.952557],
-    [0.625, 9.814424, -17.398159],
-    [1.875, 9.731327, -17.35254],
-    [1.25, 9.772875, -17.375349],
-    [3.1002922, 5.6671567, -18.906551],
-    [3.500487, 6.1119275, -18.708017],
-    [2.6671536, 6.3264184, -18.777624],
-    [3.1002922, 6.795851, -18.529839],
-    [4.107494, 5.2785945, -18.83258],
-    [4.464496, 5.6671567, -18.63184],
-    [4.375, 6.875, -18.239576],
-    [3.6303859, 6.1179156, -18.651932],
-    [3.125, 8.125, -17.98042],
-    [3.125, 8.939161, -17.599136],
-    [4.321776, 8.154599, -17.719128],
-    [3.8696263, 8.8062525, -17.529474],
-    [3.63861, 8.465525, -17.718893],
-    [3.125, 9.564161, -17.260742],
-    [4.7029595, 8.590999, -17.424526],
-    [4.321776, 9.2837515, -17.152058],
-    [4.0181465, 9.204033, -17.256403],
-    [2.5777528, 7.573838, -18.189257],
-    [5.625, 0.625, -19.160631],
-    [5.625, 1.875, -19.078878],
-    [6.564165, 0.625, -18.863594],
-    [7.189165, 0.625, -18.634232],
-    [6.450191, 1.875, -18.822376],
-    [7.075191, 1.875, -18.591757],
-    [6.421452, 1.25, -18.858578],
-    [5.6671567, 3.1002922, -18.906551],
-    [6.1119275, 3.500487, -18.708017],
-    [5.2785945, 4.107494, -18.83258],
-    [5.6671567, 4.464496, -18.63184],
-    [6.3264184, 2.6671536, -18.777624],
-    [6.795851, 3.1002922, -18.529837],
-    [6.875, 4.375, -18.239578],
-    [6.117916, 3.6303859, -18.651932],
-    [8.125, 0.625, -18.239578],
-    [8.125, 1.875, -18.153526],
-    [9.189424, 0.625, -17.730347],
-    [9.106327, 1.875, -17.686771],
-    [8.636437, 1.25, -17.952557],
-    [9.814424, 0.625, -17.398159],
-    [9.731327, 1.875, -17.35254],
-    [9.772875, 1.25, -17.375349],
-    [8.125, 3.125, -17.98042],
-    [8.154599, 4.321776, -17.719128],
-    [8.939161, 3.125, -17.599136],
-    [8.8062525, 3.8696263, -17.529474],
-    [8.465525, 3.63861, -17.718893],
-    [8.590999, 4.7029595, -17.424526],
-    [9.564161, 3.125, -17.260742],
-    [9.2837515, 4.321776, -17.15206],
-    [9.204034, 4.0181465, -17.256403],
-    [7.573839, 2.5777528, -18.189259],
-    [5.625, 5.625, -18.325289],
-    [5.7224407, 6.977496, -17.823565],
-    [6.977496, 5.7224407, -17.823565],
-    [6.6291614, 6.6291614, -17.646322],
-    [6.228657, 6.228657, -17.91034],
-    [6.2040677, 7.4624944, -17.48497],
-    [7.4624944, 6.2040677, -17.48497],
-    [6.977496, 6.977496, -17.372486],
-    [6.8988338, 6.8988338, -17.43384],
-    [5.3707347, 7.7576656, -17.617271],
-    [5.3707347, 7.7576656, -17.617271],
-    [5.7224407, 8.154599, -17.317713],
-    [5.6948266, 9.467094, -16.642796],
-    [6.1580443, 9.945158, -16.217432],
-    [6.875, 8.125, -16.903101],
-    [6.875, 9.063559, -16.42622],
-    [6.875, 9.688559, -16.064978],
-    [6.3224187, 9.018097, -16.641281],
-    [7.7576656, 5.3707347, -17.617271],
-    [7.7576656, 5.3707347, -17.617271],
-    [8.154599, 5.7224407, -17.317713],
-    [8.125, 6.875, -16.9031],
-    [9.467094, 5.6948266, -16.642796],
-    [9.945158, 6.1580443, -16.217432],
-    [9.063559, 6.875, -16.42622],
-    [9.688559, 6.875, -16.064978],
-    [9.018097, 6.3224187, -16.641281],
-    [8.038871, 8.038871, -16.428444],
-    [8.398118, 8.398118, -16.069181],
-    [7.564785, 8.8062525, -16.2816],
-    [8.141557, 9.38943, -15.6358595],
-    [8.8062525, 7.564785, -16.2816],
-    [9.38943, 8.141557, -15.6358595],
-    [9.139274, 9.139274, -15.235847],
-    [8.520881, 8.520882, -15.90956],
-    [7.537858, 7.5378585, -16.773388],
-    [8.718328, 9.972607, -14.982282],
-    [9.972607, 8.718328, -14.982282],
-    [9.483564, 9.483564, -14.806005],
-    [9.408239, 9.408238, -14.902155],
-    [9.408239, 9.408238, -14.902155],
-    [5.476698, 5.476698, -17.9243],
-    [0.625, 10.625, -16.903101],
-    [0.625, 11.43328, -16.379944],
-    [0.625, 12.058281, -15.916279],
-    [1.875, 10.625, -16.810333],
-    [1.875, 11.366785, -16.332794],
-    [1.875, 11.991784, -15.866021],
-    [1.25, 11.350021, -16.368078],
-    [0.625, 12.845437, -15.291279],
-    [1.875, 12.785843, -15.241021],
-    [1.25, 12.8156395, -15.2661495],
-    [3.1002922, 10.706492, -16.577555],
-    [3.500487, 11.177487, -16.201096],
-    [2.6671536, 11.294578, -16.2816],
-    [3.1002922, 11.776747, -15.832608],
-    [4.375, 10.388045, -16.49668],
-    [4.375, 11.013046, -16.090162],
-    [4.3575616, 11.948149, -15.402763],
-    [3.6652985, 11.2130165, -16.098625],
-    [3.125, 12.665969, -15.139938],
-    [3.9292688, 12.560555, -15.051056],
-    [3.4696867, 12.620791, -15.101846],
-    [2.508551, 11.594833, -15.993178],
-    [0.625, 13.470437, -14.745259],
-    [0.625, 14.120259, -14.120259],
-    [0.625, 14.745259, -13.470437],
-    [1.875, 13.410843, -14.690951],
-    [1.875, 14.065951, -14.065951],
-    [1.875, 14.690951, -13.410843],
-    [1.25, 14.08395, -14.08395],
-    [4.7626023, 12.413581, -14.928902],
-    [4.7626023, 12.413581, -14.928902],
-    [3.125, 13.290969, -14.581705],
-    [3.125, 13.956705, -13.956705],
-    [3.125, 14.581705, -13.290969],
-    [4.4054027, 13.135811, -14.385528],
-    [4.881536, 13.711067, -13.711067],
-    [4.048203, 13.842153, -13.842153],
-    [4.4054027, 14.385528, -13.135811],
-    [3.9051352, 13.836841, -13.83684],
-    [4.7626023, 14.928902, -12.413581],
-    [4.7626023, 14.928902, -12.413581],
-    [2.928828, 13.919124, -13.919124],
-    [0.625, 15.291278, -12.845437],
-    [1.875, 15.241021, -12.785843],
-    [1.25, 15.2661495, -12.8156395],
-    [0.625, 15.916278, -12.058281],
-    [0.625, 16.379942, -11.433281],
-    [0.625, 16.903103, -10.625],
-    [1.875, 15.866021, -11.991785],
-    [1.875, 16.332794, -11.366785],
-    [1.875, 16.810333, -10.625],
-    [1.25, 16.368078, -11.350021],
-    [3.125, 15.139938, -12.665969],
-    [3.9292688, 15.051056, -12.560555],
-    [3.4696867, 15.101846, -12.620791],
-    [3.1002922, 15.832608, -11.776747],
-    [3.500487, 16.201096, -11.177487],
-    [2.6671536, 16.2816, -11.294578],
-    [3.1002922, 16.577555, -10.706492],
-    [4.3575616, 15.402763, -11.948149],
-    [4.375, 16.090162, -11.013045],
-    [4.375, 16.49668, -10.388045],
-    [3.6652985, 16.098625, -11.2130165],
-    [2.508551, 15.993178, -11.594833],
-    [5.324711, 10.17324, -16.36011],
-    [5.6948266, 10.603945, -15.943033],
-    [5.625, 11.597614, -15.265717],
-    [6.88703, 10.677339, -15.413083],
-    [6.478384, 11.383239, -15.101833],
-    [6.040929, 10.873316, -15.611464],
-    [7.884995, 10.295564, -15.202538],
-    [7.884995, 10.295564, -15.202538],
-    [6.281459, 10.797956, -15.5581255],
-    [5.625, 12.222614, -14.771767],
-    [7.3117175, 11.128898, -14.911034],
-    [6.9704394, 11.93085, -14.422244],
-    [7.4624944, 12.478462, -13.730554],
-    [6.790329, 11.956102, -14.471581],
-    [5.625, 12.933707, -14.146767],
-    [5.625, 13.558707, -13.558707],
-    [5.625, 14.146767, -12.933707],
-    [6.6291614, 12.700542, -13.933454],
-    [7.045828, 13.2155485, -13.2155485],
-    [6.6291614, 13.933454, -12.700542],
-    [6.231247, 13.406334, -13.406333],
-    [5.625, 14.771767, -12.222614],
-    [7.4624944, 13.730554, -12.478462],
-    [6.9704394, 14.422244, -11.93085],
-    [7.3117175, 14.911034, -11.128898],
-    [6.790329, 14.4715805, -11.956102],
-    [8.230997, 10.677339, -14.7412815],
-    [8.125, 11.629698, -14.06489],
-    [8.125, 12.254698, -13.528498],
-    [9.334143, 10.603945, -14.121927],
-    [9.723573, 11.006574, -13.553721],
-    [8.890239, 11.361135, -13.842153],
-    [9.334767, 11.843793, -13.097248],
-    [8.822525, 11.336995, -13.84869],
-    [9.779296, 12.326451, -12.326451],
-    [9.779296, 12.326451, -12.326451],
-    [8.125, 12.903497, -12.903498],
-    [8.945962, 12.640775, -12.640775],
-    [8.476841, 12.790902, -12.790902],
-    [8.125, 13.528498, -12.254698],
-    [8.125, 14.06489, -11.629698],
-    [8.230997, 14.7412815, -10.677339],
-    [9.334767, 13.097248, -11.843793],
-    [9.723573, 13.553721, -11.006574],
-    [8.890239, 13.842153, -11.361135],
-    [9.334143, 14.121927, -10.603945],
-    [8.822525, 13.848689, -11.336995],
-    [7.816411, 12.910168, -12.910167],
-    [5.625, 15.265717, -11.597614],
-    [5.6948266, 15.943031, -10.603945],
-    [5.324711, 16.36011, -10.17324],
-    [6.478384, 15.101833, -11.383239],
-    [6.88703, 15.413081, -10.677339],
-    [6.040929, 15.611463, -10.873316],
-    [7.884995, 15.202538, -10.295564],
-    [7.884995, 15.202538, -10.295564],
-    [6.281459, 15.558124, -10.797956],
-    [4.8660526, 13.434791, -13.434792],
-    [0.625, 17.398159, -9.8144245],
-    [1.875, 17.35254, -9.731327],
-    [1.25, 17.375349, -9.772876],
-    [0.625, 17.730347, -9.1894245],
-    [0.625, 18.239578, -8.125],
-    [1.875, 17.686771, -9.106327],
-    [1.875, 18.153528, -8.125],
-    [1.25, 17.952557, -8.636438],
-    [0.625, 18.634232, -7.189165],
-    [0.625, 18.863594, -6.564165],
-    [0.625, 19.160631, -5.625],
-    [1.875, 18.591757, -7.075191],
-    [1.875, 18.822376, -6.450191],
-    [1.875, 19.078878, -5.625],
-    [1.25, 18.858578, -6.421452],
-    [3.125, 17.260742, -9.564161],
-    [4.321776, 17.15206, -9.2837515],
-    [4.7029595, 17.424526, -8.590999],
-    [4.0181465, 17.256403, -9.204033],
-    [3.125, 17.599136, -8.939161],
-    [3.125, 17.980421, -8.125],
-    [3.8696263, 17.529474, -8.8062525],
-    [4.321776, 17.719128, -8.154599],
-    [3.63861, 17.718893, -8.465525],
-    [3.1002922, 18.529837, -6.795851],
-    [3.500487, 18.708017, -6.1119275],
-    [2.6671536, 18.777624, -6.3264184],
-    [3.1002922, 18.906551, -5.6671567],
-    [4.375, 18.239578, -6.875],
-    [4.464496, 18.63184, -5.6671567],
-    [4.107494, 18.83258, -5.2785945],
-    [3.6303859, 18.651932, -6.1179156],
-    [2.5777528, 18.189259, -7.5738397],
-    [0.625, 19.484573, -4.375],
-    [0.625, 19.724205, -3.125],
-    [1.875, 19.404055, -4.375],
-    [1.875, 19.644651, -3.125],
-    [1.25, 19.564371, -3.75],
-    [0.625, 19.88232, -1.875],
-    [0.625, 19.960873, -0.625],
-    [1.875, 19.803429, -1.875],
-    [1.875, 19.882318, -0.625],
-    [1.25, 19.882235, -1.25],
-    [3.125, 19.24208, -4.375],
-    [3.125, 19.484573, -3.125],
-    [4.940827, 18.736036, -4.940827],
-    [4.464496, 18.95577, -4.464496],
-    [4.375, 19.24208, -3.125],
-    [3.9822478, 19.143095, -3.9822478],
-    [3.125, 19.644653, -1.875],
-    [3.125, 19.724205, -0.625],
-    [4.375, 19.404055, -1.875],
-    [4.375, 19.484573, -0.625],
-    [3.75, 19.564371, -1.25],
-    [2.6418376, 19.515257, -2.6418376],
-    [8.718328, 14.982282, -9.972607],
-    [9.483564, 14.806005, -9.483564],
-    [9.972607, 14.982282, -8.718328],
-    [9.408239, 14.902155, -9.408238],
-    [9.408239, 14.902155, -9.408238],
-    [6.1580443, 16.217432, -9.945158],
-    [5.6948266, 16.642797, -9.467094],
-    [5.7224407, 17.317713, -8.154599],
-    [6.875, 16.064978, -9.688559],
-    [6.875, 16.42622, -9.063559],
-    [6.875, 16.9031, -8.125],
-    [6.3224187, 16.641281, -9.018096],
-    [6.2040677, 17.48497, -7.4624944],
-    [6.977496, 17.372486, -6.977496],
-    [7.4624944, 17.48497, -6.2040677],
-    [6.8988338, 17.43384, -6.8988338],
-    [5.3707347, 17.617271, -7.7576656],
-    [5.3707347, 17.617271, -7.7576656],
-    [5.7224407, 17.823565, -6.977496],
-    [5.625, 18.325289, -5.625],
-    [6.6291614, 17.646322, -6.6291614],
-    [6.977496, 17.823565, -5.7224407],
-    [6.228657, 17.91034, -6.228657],
-    [8.141557, 15.6358595, -9.38943],
-    [8.398118, 16.069181, -8.398118],
-    [7.564785, 16.2816, -8.8062525],
-    [8.038871, 16.428442, -8.038871],
-    [9.139274, 15.235847, -9.139274],
-    [9.38943, 15.6358595, -8.141557],
-    [8.8062525, 16.2816, -7.564785],
-    [8.520882, 15.909558, -8.520881],
-    [8.125, 16.903101, -6.875],
-    [8.154599, 17.317713, -5.7224407],
-    [9.688559, 16.064978, -6.875],
-    [9.945158, 16.217432, -6.1580443],
-    [9.063559, 16.42622, -6.875],
-    [9.467094, 16.642797, -5.6948266],
-    [9.018097, 16.641281, -6.3224187],
-    [7.7576656, 17.617271, -5.3707347],
-    [7.7576656, 17.617271, -5.3707347],
-    [7.537858, 16.773388, -7.537858],
-    [5.6671567, 18.63184, -4.464496],
-    [6.1119275, 18.708017, -3.500487],
-    [5.2785945, 18.83258, -4.107494],
-    [5.6671567, 18.906551, -3.1002922],
-    [6.875, 18.239578, -4.375],
-    [6.795851, 18.529839, -3.1002922],
-    [6.3264184, 18.777624, -2.6671536],
-    [6.1179156, 18.651932, -3.6303859],
-    [5.625, 19.078878, -1.875],
-    [5.625, 19.160633, -0.625],
-    [7.075191, 18.591757, -1.875],
-    [7.189165, 18.634232, -0.625],
-    [6.450191, 18.822376, -1.875],
-    [6.564165, 18.863594, -0.625],
-    [6.421452, 18.858578, -1.25],
-    [8.590999, 17.424526, -4.7029595],
-    [9.2837515, 17.152058, -4.321776],
-    [9.564161, 17.260742, -3.125],
-    [9.204034, 17.256403, -4.0181465],
-    [9.731327, 17.35254, -1.875],
-    [9.8144245, 17.398159, -0.625],
-    [9.772876, 17.375349, -1.25],
-    [8.154599, 17.719128, -4.321776],
-    [8.125, 17.980421, -3.125],
-    [8.8062525, 17.529474, -3.8696263],
-    [8.939161, 17.599136, -3.125],
-    [8.465525, 17.718893, -3.63861],
-    [8.125, 18.153526, -1.875],
-    [8.125, 18.239578, -0.625],
-    [9.106327, 17.686771, -1.875],
-    [9.189424, 17.730347, -0.625],
-    [8.636437, 17.952557, -1.25],
-    [7.573839, 18.189257, -2.5777528],
-    [5.476698, 17.9243, -5.476698],
-    [10.625, 0.625, -16.903103],
-    [10.625, 1.875, -16.810335],
-    [11.433281, 0.625, -16.379942],
-    [12.058281, 0.625, -15.916278],
-    [11.366785, 1.875, -16.332794],
-    [11.991785, 1.875, -15.866021],
-    [11.350021, 1.25, -16.368078],
-    [10.706492, 3.1002922, -16.577555],
-    [11.177487, 3.500487, -16.201096],
-    [10.388045, 4.375, -16.49668],
-    [11.013045, 4.375, -16.090162],
-    [11.294578, 2.6671536, -16.2816],
-    [11.776747, 3.1002922, -15.832608],
-    [11.948149, 4.3575616, -15.402763],
-    [11.213018, 3.6652985, -16.098625],
-    [12.845437, 0.625, -15.291278],
-    [12.785843, 1.875, -15.241021],
-    [12.8156395, 1.25, -15.2661495],
-    [12.665969, 3.125, -15.139938],
-    [12.560555, 3.9292688, -15.051056],
-    [12.620791, 3.4696867, -15.101846],
-    [11.594833, 2.508551, -15.993178],
-    [12.413581, 4.7626023, -14.928902],
-    [12.413581, 4.7626023, -14.928902],
-    [13.470437, 0.625, -14.745259],
-    [13.410843, 1.875, -14.690951],
-    [14.120259, 0.625, -14.120259],
-    [14.745259, 0.625, -13.470437],
-    [14.065951, 1.875, -14.065951],
-    [14.690951, 1.875, -13.410843],
-    [14.08395, 1.25, -14.08395],
-    [13.290969, 3.125, -14.581705],
-    [13.135811, 4.4054027, -14.385528],
-    [13.711067, 4.881536, -13.711067],
-    [13.956705, 3.125, -13.956705],
-    [14.581705, 3.125, -13.290969],
-    [13.842153, 4.048203, -13.842153],
-    [14.385528, 4.4054027, -13.135811],
-    [13.83684, 3.9051352, -13.836841],
-    [14.928902, 4.7626023, -12.413581],
-    [14.928902, 4.7626023, -12.413581],
-    [13.919124, 2.928828, -13.919125],
-    [10.17324, 5.324711, -16.36011],
-    [10.603945, 5.6948266, -15.943031],
-    [10.677339, 6.88703, -15.413081],
-    [11.597614, 5.625, -15.265716],
-    [11.383239, 6.478384, -15.101833],
-    [10.873316, 6.040929, -15.611463],
-    [10.295564, 7.884995, -15.202538],
-    [10.295564, 7.884995, -15.202538],
-    [10.797956, 6.281459, -15.558124],
-    [11.128898, 7.3117175, -14.911034],
-    [12.222614, 5.625, -14.771767],
-    [11.93085, 6.9704385, -14.422244],
-    [12.478462, 7.4624944, -13.730554],
-    [11.956102, 6.790329, -14.471581],
-    [10.677339, 8.230997, -14.7412815],
-    [10.603945, 9.334143, -14.121927],
-    [11.006574, 9.723573, -13.553721],
-    [11.629698, 8.125, -14.06489],
-    [12.254698, 8.125, -13.528498],
-    [11.361135, 8.890239, -13.842153],
-    [11.843793, 9.334767, -13.097248],
-    [11.336995, 8.822524, -13.848691],
-    [12.326451, 9.779296, -12.326451],
-    [12.326451, 9.779296, -12.326451],
-    [12.933707, 5.625, -14.146767],
-    [13.558707, 5.625, -13.558707],
-    [12.700542, 6.6291614, -13.933454],
-    [13.2155485, 7.0458274, -13.2155485],
-    [14.146767, 5.625, -12.933707],
-    [13.933454, 6.6291614, -12.700542],
-    [13.406334, 6.231247, -13.406333],
-    [13.730554, 7.4624944, -12.478462],
-    [14.771767, 5.625, -12.222614],
-    [14.422244, 6.9704385, -11.93085],
-    [14.911034, 7.3117175, -11.128898],
-    [14.4715805, 6.790329, -11.956102],
-    [12.903498, 8.125, -12.903498],
-    [12.640775, 8.945962, -12.640775],
-    [12.790902, 8.476841, -12.790902],
-    [13.528498, 8.125, -12.254698],
-    [13.097249, 9.334767, -11.843793],
-    [13.553721, 9.723573, -11.006574],
-    [14.06489, 8.125, -11.629698],
-    [14.7412815, 8.230997, -10.677339],
-    [13.842153, 8.890239, -11.361135],
-    [14.121927, 9.334143, -10.603945],
-    [13.84869, 8.822524, -11.336995],
-    [12.910169, 7.81641, -12.910169],
-    [15.291278, 0.625, -12.845437],
-    [15.241021, 1.875, -12.785843],
-    [15.2661495, 1.25, -12.8156395],
-    [15.916278, 0.625, -12.058281],
-    [15.866021, 1.875, -11.991784],
-    [16.379942, 0.625, -11.433281],
-    [16.903103, 0.625, -10.625],
-    [16.332794, 1.875, -11.366784],
-    [16.810335, 1.875, -10.625],
-    [16.368078, 1.25, -11.350021],
-    [15.139938, 3.125, -12.665969],
-    [15.051056, 3.9292688, -12.560555],
-    [15.101846, 3.4696867, -12.620791],
-    [15.832608, 3.1002922, -11.776747],
-    [16.201096, 3.500487, -11.177487],
-    [15.402763, 4.3575616, -11.948149],
-    [16.090162, 4.375, -11.013046],
-    [16.2816, 2.6671536, -11.294578],
-    [16.577555, 3.1002922, -10.706492],
-    [16.49668, 4.375, -10.388045],
-    [16.098625, 3.6652985, -11.213018],
-    [15.993178, 2.508551, -11.594834],
-    [15.265717, 5.625, -11.597614],
-    [15.943031, 5.6948266, -10.603945],
-    [15.101833, 6.478384, -11.383239],
-    [15.413083, 6.88703, -10.677339],
-    [16.36011, 5.324711, -10.17324],
-    [15.611463, 6.0409284, -10.873316],
-    [15.202538, 7.884995, -10.295564],
-    [15.202538, 7.884995, -10.295564],
-    [15.558124, 6.281459, -10.797956],
-    [13.434792, 4.866052, -13.434792],
-    [14.982282, 8.718328, -9.972607],
-    [14.806005, 9.483564, -9.483564],
-    [14.982282, 9.972607, -8.718328],
-    [14.902155, 9.408239, -9.408238],
-    [14.902155, 9.408239, -9.408238],
-    [17.398159, 0.625, -9.814424],
-    [17.35254, 1.875, -9.731327],
-    [17.375349, 1.25, -9.772875],
-    [17.260742, 3.125, -9.564161],
-    [17.15206, 4.321776, -9.2837515],
-    [17.424526, 4.7029595, -8.590999],
-    [17.256403, 4.0181465, -9.204033],
-    [17.730347, 0.625, -9.189424],
-    [18.239578, 0.625, -8.125],
-    [17.686771, 1.875, -9.106327],
-    [18.153526, 1.875, -8.125],
-    [17.952557, 1.25, -8.636437],
-    [18.634232, 0.625, -7.189165],
-    [18.591757, 1.875, -7.075191],
-    [18.863594, 0.625, -6.564165],
-    [19.160631, 0.625, -5.625],
-    [18.822376, 1.875, -6.450191],
-    [19.078878, 1.875, -5.625],
-    [18.858578, 1.25, -6.421452],
-    [17.599136, 3.125, -8.939161],
-    [17.98042, 3.125, -8.125],
-    [17.529474, 3.8696263, -8.8062525],
-    [17.719128, 4.321776, -8.154599],
-    [17.718893, 3.63861, -8.465525],
-    [18.529837, 3.1002922, -6.795851],
-    [18.708017, 3.500487, -6.1119275],
-    [18.239578, 4.375, -6.875],
-    [18.63184, 4.464496, -5.6671567],
-    [18.777624, 2.6671536, -6.3264184],
-    [18.906551, 3.1002922, -5.6671567],
-    [18.83258, 4.107494, -5.2785945],
-    [18.651932, 3.6303859, -6.117916],
-    [18.189259, 2.5777528, -7.573839],
-    [19.484573, 0.625, -4.375],
-    [19.724207, 0.625, -3.125],
-    [19.404057, 1.875, -4.375],
-    [19.644651, 1.875, -3.125],
-    [19.564373, 1.25, -3.75],
-    [19.88232, 0.625, -1.875],
-    [19.960873, 0.625, -0.625],
-    [19.803429, 1.875, -1.875],
-    [19.88232, 1.875, -0.625],
-    [19.882236, 1.25, -1.25],
-    [18.736036, 4.940827, -4.940827],
-    [19.24208, 3.125, -4.375],
-    [19.484573, 3.125, -3.125],
-    [18.95577, 4.464496, -4.464496],
-    [19.24208, 4.375, -3.125],
-    [19.143095, 3.9822478, -3.9822478],
-    [19.644651, 3.125, -1.875],
-    [19.724205, 3.125, -0.625],
-    [19.404055, 4.375, -1.875],
-    [19.484573, 4.375, -0.625],
-    [19.564371, 3.75, -1.25],
-    [19.515259, 2.6418376, -2.6418376],
-    [16.217432, 6.1580443, -9.945158],
-    [16.064978, 6.875, -9.688559],
-    [16.642796, 5.6948266, -9.467094],
-    [17.317713, 5.7224407, -8.154599],
-    [16.42622, 6.875, -9.063559],
-    [16.9031, 6.875, -8.125],
-    [16.641281, 6.3224187, -9.018096],
-    [17.48497, 6.2040677, -7.4624944],
-    [17.372486, 6.977496, -6.977496],
-    [17.48497, 7.4624944, -6.2040677],
-    [17.43384, 6.8988338, -6.8988338],
-    [15.6358595, 8.141557, -9.38943],
-    [16.069181, 8.398118, -8.398118],
-    [15.235847, 9.139274, -9.139274],
-    [15.6358595, 9.38943, -8.141557],
-    [16.2816, 7.564785, -8.8062525],
-    [16.428444, 8.038871, -8.038871],
-    [16.2816, 8.8062525, -7.564785],
-    [15.909559, 8.520882, -8.520881],
-    [16.064978, 9.688559, -6.875],
-    [16.217432, 9.945158, -6.1580443],
-    [16.903101, 8.125, -6.875],
-    [17.317713, 8.154599, -5.7224407],
-    [16.42622, 9.063559, -6.875],
-    [16.642796, 9.467094, -5.6948266],
-    [16.641281, 9.018096, -6.3224187],
-    [17.617271, 5.3707347, -7.7576656],
-    [17.617271, 5.3707347, -7.7576656],
-    [17.823565, 5.7224407, -6.977496],
-    [18.325289, 5.625, -5.625],
-    [17.646322, 6.6291614, -6.6291614],
-    [17.823565, 6.977496, -5.7224407],
-    [17.91034, 6.228657, -6.228657],
-    [17.617271, 7.7576656, -5.3707347],
-    [17.617271, 7.7576656, -5.3707347],
-    [16.773388, 7.537858, -7.537858],
-    [17.424526, 8.590999, -4.7029595],
-    [17.152058, 9.2837515, -4.321776],
-    [17.260742, 9.564161, -3.125],
-    [17.256403, 9.204034, -4.0181465],
-    [17.35254, 9.731327, -1.875],
-    [17.398159, 9.814424, -0.625],
-    [17.375349, 9.772875, -1.25],
-    [18.63184, 5.6671567, -4.464496],
-    [18.708017, 6.1119275, -3.500487],
-    [18.239576, 6.875, -4.375],
-    [18.529839, 6.795851, -3.1002922],
-    [18.83258, 5.2785945, -4.107494],
-    [18.906551, 5.6671567, -3.1002922],
-    [18.777624, 6.3264184, -2.6671536],
-    [18.651934, 6.1179156, -3.6303859],
-    [18.591757, 7.075191, -1.875],
-    [18.634232, 7.189165, -0.625],
-    [19.078878, 5.625, -1.875],
-    [19.160633, 5.625, -0.625],
-    [18.822376, 6.450191, -1.875],
-    [18.863594, 6.564165, -0.625],
-    [18.85858, 6.4214516, -1.25],
-    [17.719128, 8.154599, -4.321776],
-    [17.98042, 8.125, -3.125],
-    [17.529474, 8.8062525, -3.8696263],
-    [17.599136, 8.939161, -3.125],
-    [17.718893, 8.465525, -3.63861],
-    [18.153528, 8.125, -1.875],
-    [18.239578, 8.125, -0.625],
-    [17.686771, 9.106327, -1.875],
-    [17.730347, 9.189424, -0.625],
-    [17.952557, 8.636437, -1.25],
-    [18.189259, 7.573838, -2.5777528],
-    [17.9243, 5.4766974, -5.476698],
-    [10.17324, 10.17324, -13.878777],
-    [10.638885, 10.638885, -13.137054],
-    [10.271195, 11.493118, -12.720387],
-    [11.493118, 10.271195, -12.720387],
-    [10.6430645, 10.6430645, -13.1187315],
-    [11.104529, 11.104529, -12.370105],
-    [10.687862, 11.931611, -11.931611],
-    [11.104529, 12.370105, -11.104529],
-    [11.931611, 10.687862, -11.931611],
-    [12.370105, 11.104529, -11.104529],
-    [11.536771, 11.536771, -11.536771],
-    [11.931611, 11.931611, -10.687862],
-    [11.521811, 11.521811, -11.521811],
-    [10.271195, 12.720387, -11.493118],
-    [10.638885, 13.137054, -10.638885],
-    [10.17324, 13.878777, -10.17324],
-    [11.493118, 12.720387, -10.271195],
-    [10.6430645, 13.1187315, -10.6430645],
-    [12.720387, 10.271195, -11.493118],
-    [13.137054, 10.638885, -10.638885],
-    [12.720387, 11.493118, -10.271195],
-    [13.878777, 10.17324, -10.17324],
-    [13.1187315, 10.6430645, -10.6430645],
-    [11.489697, 11.489697, -11.489697],
-    [11.489697, 11.489697, -11.489697],
-    [12.326451, 12.326451, -9.779296],
-    [12.326451, 12.326451, -9.779296],
-    [11.006574, 13.553721, -9.723573],
-    [10.603945, 14.121927, -9.334143],
-    [10.677339, 14.7412815, -8.230997],
-    [11.843793, 13.097248, -9.334767],
-    [12.254698, 13.528497, -8.125],
-    [11.361135, 13.842153, -8.890239],
-    [11.629698, 14.06489, -8.125],
-    [11.336996, 13.84869, -8.822524],
-    [11.128898, 14.911034, -7.3117175],
-    [12.478462, 13.730554, -7.4624944],
-    [11.93085, 14.422244, -6.9704394],
-    [12.222614, 14.771767, -5.625],
-    [11.956101, 14.471581, -6.7903295],
-    [13.553721, 11.006574, -9.723573],
-    [13.097248, 11.843793, -9.334767],
-    [13.528498, 12.254698, -8.125],
-    [14.121927, 10.603945, -9.334143],
-    [14.7412815, 10.677339, -8.230997],
-    [13.842153, 11.361135, -8.890239],
-    [14.06489, 11.629698, -8.125],
-    [13.84869, 11.336996, -8.822524],
-    [13.730554, 12.478462, -7.4624944],
-    [14.911034, 11.128898, -7.3117175],
-    [14.422244, 11.930851, -6.9704394],
-    [14.771767, 12.222614, -5.625],
-    [14.4715805, 11.956102, -6.7903295],
-    [12.640775, 12.640775, -8.945962],
-    [12.903497, 12.903498, -8.125],
-    [12.790902, 12.790902, -8.476841],
-    [13.2155485, 13.2155485, -7.045828],
-    [13.558707, 13.558707, -5.625],
-    [12.700542, 13.933454, -6.6291614],
-    [12.933707, 14.146767, -5.625],
-    [13.933454, 12.700542, -6.6291614],
-    [14.146767, 12.933707, -5.625],
-    [13.406334, 13.406334, -6.231247],
-    [12.910169, 12.910168, -7.81641],
-    [12.413581, 14.928902, -4.7626023],
-    [12.413581, 14.928902, -4.7626023],
-    [14.928902, 12.413581, -4.7626023],
-    [14.928902, 12.413581, -4.7626023],
-    [13.711067, 13.711067, -4.881536],
-    [13.135811, 14.385528, -4.4054027],
-    [13.290969, 14.581705, -3.125],
-    [14.385528, 13.135811, -4.4054027],
-    [14.581705, 13.290969, -3.125],
-    [13.842153, 13.842153, -4.048203],
-    [13.956705, 13.956705, -3.125],
-    [13.83684, 13.83684, -3.905135],
-    [13.410843, 14.690951, -1.875],
-    [13.470437, 14.745259, -0.625],
-    [14.690951, 13.410843, -1.875],
-    [14.745259, 13.470437, -0.625],
-    [14.065951, 14.065951, -1.875],
-    [14.120259, 14.120259, -0.625],
-    [14.08395, 14.08395, -1.25],
-    [13.919125, 13.919125, -2.9288278],
-    [10.295564, 15.202538, -7.884995],
-    [10.295564, 15.202538, -7.884995],
-    [10.677339, 15.413081, -6.88703],
-    [10.603945, 15.943033, -5.6948266],
-    [10.17324, 16.36011, -5.324711],
-    [11.383239, 15.101833, -6.478384],
-    [11.597614, 15.265717, -5.625],
-    [10.873315, 15.611463, -6.040929],
-    [10.7979555, 15.558124, -6.281459],
-    [11.013045, 16.090162, -4.375],
-    [11.177487, 16.201096, -3.500487],
-    [10.388045, 16.49668, -4.375],
-    [10.706492, 16.577555, -3.1002922],
-    [11.948149, 15.402763, -4.3575616],
-    [11.776747, 15.832608, -3.1002922],
-    [11.294578, 16.2816, -2.6671536],
-    [11.2130165, 16.098623, -3.6652985],
-    [10.625, 16.810333, -1.875],
-    [10.625, 16.903103, -0.625],
-    [11.991785, 15.866021, -1.875],
-    [12.058281, 15.916278, -0.625],
-    [11.366785, 16.332794, -1.875],
-    [11.43328, 16.379942, -0.625],
-    [11.350021, 16.368078, -1.25],
-    [12.560555, 15.051056, -3.9292688],
-    [12.665969, 15.139938, -3.125],
-    [12.620791, 15.101846, -3.4696867],
-    [12.785843, 15.241021, -1.875],
-    [12.845437, 15.291278, -0.625],
-    [12.8156395, 15.2661495, -1.25],
-    [11.594834, 15.993176, -2.508551],
-    [15.202538, 10.295564, -7.884995],
-    [15.202538, 10.295564, -7.884995],
-    [15.413083, 10.677339, -6.88703],
-    [15.943033, 10.603945, -5.6948266],
-    [15.101833, 11.383239, -6.478384],
-    [15.265717, 11.597614, -5.625],
-    [16.36011, 10.17324, -5.324711],
-    [15.611464, 10.873316, -6.0409284],
-    [15.5581255, 10.797956, -6.281459],
-    [16.090162, 11.013046, -4.375],
-    [16.201096, 11.177487, -3.500487],
-    [15.402763, 11.948149, -4.3575616],
-    [15.832608, 11.776747, -3.1002922],
-    [16.49668, 10.388045, -4.375],
-    [16.577555, 10.706492, -3.1002922],
-    [16.2816, 11.294578, -2.6671536],
-    [16.098623, 11.213018, -3.6652985],
-    [15.866021, 11.991785, -1.875],
-    [15.916279, 12.05828, -0.625],
-    [16.810333, 10.625, -1.875],
-    [16.903101, 10.625, -0.625],
-    [16.332794, 11.366785, -1.875],
-    [16.379942, 11.433281, -0.625],
-    [16.368078, 11.350021, -1.25],
-    [15.051056, 12.560555, -3.9292688],
-    [15.139938, 12.665969, -3.125],
-    [15.101846, 12.620791, -3.4696867],
-    [15.241021, 12.785843, -1.875],
-    [15.291278, 12.845437, -0.625],
-    [15.2661495, 12.8156395, -1.25],
-    [15.993176, 11.594834, -2.508551],
-    [13.434792, 13.434792, -4.866052],
-    [10.126742, 10.126742, -10.126742],
-    [0.625, 0.625, 19.960873],
-    [0.625, 1.875, 19.882318],
-    [1.875, 0.625, 19.88232],
-    [1.875, 1.875, 19.803429],
-    [1.25, 1.25, 19.882235],
-    [0.625, 3.125, 19.724205],
-    [0.625, 4.375, 19.484573],
-    [1.875, 3.125, 19.644653],
-    [1.875, 4.375, 19.404055],
-    [1.25, 3.75, 19.564371],
-    [3.125, 0.625, 19.724205],
-    [3.125, 1.875, 19.644651],
-    [4.375, 0.625, 19.484573],
-    [4.375, 1.875, 19.404057],
-    [3.75, 1.25, 19.564373],
-    [3.125, 3.125, 19.484573],
-    [3.125, 4.375, 19.24208],
-    [4.375, 3.125, 19.24208],
-    [4.940827, 4.940827, 18.736036],
-    [4.464496, 4.464496, 18.95577],
-    [3.9822478, 3.9822478, 19.143095],
-    [2.6418376, 2.6418376, 19.515259],
-    [0.625, 5.625, 19.160633],
-    [0.625, 7.189165, 18.634232],
-    [0.625, 6.564165, 18.863594],
-    [1.875, 5.625, 19.078878],
-    [1.875, 7.075191, 18.591757],
-    [1.875, 6.450191, 18.822376],
-    [1.25, 6.421452, 18.85858],
-    [0.625, 9.814424, 17.398159],
-    [1.875, 9.731327, 17.35254],
-    [1.25, 9.772875, 17.375349],
-    [0.625, 8.125, 18.239578],
-    [0.625, 9.189424, 17.730347],
-    [1.875, 8.125, 18.153526],
-    [1.875, 9.106327, 17.686771],
-    [1.25, 8.636437, 17.952557],
-    [3.500487, 6.1119275, 18.708017],
-    [3.1002922, 5.6671567, 18.906551],
-    [3.1002922, 6.795851, 18.529839],
-    [2.6671536, 6.3264184, 18.777624],
-    [4.464496, 5.6671567, 18.63184],
-    [4.107494, 5.2785945, 18.83258],
-    [4.375, 6.875, 18.239578],
-    [3.6303864, 6.1179156, 18.651934],
-    [3.125, 9.564161, 17.260742],
-    [4.7029595, 8.590999, 17.424526],
-    [4.321776, 9.2837515, 17.152058],
-    [4.0181465, 9.204033, 17.256403],
-    [3.125, 8.125, 17.98042],
-    [3.125, 8.939161, 17.599136],
-    [4.321776, 8.154599, 17.719128],
-    [3.8696263, 8.8062525, 17.529474],
-    [3.63861, 8.465525, 17.718893],
-    [2.5777533, 7.573838, 18.189257],
-    [5.625, 0.625, 19.160631],
-    [5.625, 1.875, 19.078878],
-    [7.189165, 0.625, 18.634232],
-    [6.564165, 0.625, 18.863594],
-    [7.075191, 1.875, 18.591757],
-    [6.450191, 1.875, 18.822376],
-    [6.421452, 1.25, 18.858578],
-    [6.1119275, 3.500487, 18.708017],
-    [5.6671567, 3.1002922, 18.906551],
-    [5.6671567, 4.464496, 18.63184],
-    [5.2785945, 4.107494, 18.83258],
-    [6.795851, 3.1002922, 18.529837],
-    [6.3264184, 2.6671536, 18.777624],
-    [6.875, 4.375, 18.239576],
-    [6.117916, 3.6303859, 18.651932],
-    [9.814424, 0.625, 17.398159],
-    [9.731327, 1.875, 17.35254],
-    [9.772875, 1.25, 17.375349],
-    [8.125, 0.625, 18.239578],
-    [8.125, 1.875, 18.153528],
-    [9.189424, 0.625, 17.730347],
-    [9.106327, 1.875, 17.686771],
-    [8.636437, 1.25, 17.952557],
-    [8.590999, 4.7029595, 17.424526],
-    [9.564161, 3.125, 17.260742],
-    [9.2837515, 4.321776, 17.15206],
-    [9.204034, 4.0181465, 17.256403],
-    [8.125, 3.125, 17.980421],
-    [8.154599, 4.321776, 17.719128],
-    [8.939161, 3.125, 17.599136],
-    [8.8062525, 3.8696263, 17.529474],
-    [8.465525, 3.63861, 17.718895],
-    [7.573839, 2.5777528, 18.189259],
-    [8.718328, 9.972607, 14.982282],
-    [9.972607, 8.718328, 14.982282],
-    [9.483564, 9.483564, 14.806005],
-    [9.408239, 9.408238, 14.902155],
-    [9.408239, 9.408238, 14.902155],
-    [6.2040677, 7.4624944, 17.48497],
-    [7.4624944, 6.2040677, 17.48497],
-    [6.977496, 6.977496, 17.372486],
-    [6.8988338, 6.8988338, 17.43384],
-    [5.625, 5.625, 18.325289],
-    [5.7224407, 6.977496, 17.823565],
-    [6.977496, 5.7224407, 17.823565],
-    [6.6291614, 6.6291614, 17.646322],
-    [6.228657, 6.228657, 17.91034],
-    [5.7224407, 8.154599, 17.317713],
-    [6.1580443, 9.945158, 16.217432],
-    [5.6948266, 9.467094, 16.642796],
-    [6.875, 8.125, 16.903101],
-    [6.875, 9.688559, 16.064978],
-    [6.875, 9.063559, 16.42622],
-    [6.3224187, 9.018097, 16.641281],
-    [5.3707347, 7.7576656, 17.617271],
-    [5.3707347, 7.7576656, 17.617271],
-    [8.154599, 5.7224407, 17.317713],
-    [8.125, 6.875, 16.9031],
-    [9.945158, 6.1580443, 16.217432],
-    [9.467094, 5.6948266, 16.642796],
-    [9.688559, 6.875, 16.064978],
-    [9.063559, 6.875, 16.42622],
-    [9.018097, 6.3224187, 16.641281],
-    [7.7576656, 5.3707347, 17.617271],
-    [7.7576656, 5.3707347, 17.617271],
-    [8.398118, 8.398118, 16.069181],
-    [8.038871, 8.038871, 16.428444],
-    [8.141557, 9.38943, 15.6358595],
-    [7.564785, 8.8062525, 16.2816],
-    [9.38943, 8.141557, 15.6358595],
-    [8.8062525, 7.564785, 16.2816],
-    [9.139274, 9.139274, 15.235847],
-    [8.520881, 8.520882, 15.909558],
-    [7.537858, 7.5378585, 16.773388],
-    [5.476698, 5.476698, 17.9243],
-    [0.625, 19.960873, 0.625],
-    [0.625, 19.88232, 1.875],
-    [1.875, 19.88232, 0.625],
-    [1.875, 19.803429, 1.875],
-    [1.25, 19.882236, 1.25],
-    [0.625, 19.724205, 3.125],
-    [0.625, 19.484573, 4.375],
-    [1.875, 19.644651, 3.125],
-    [1.875, 19.404055, 4.375],
-    [1.25, 19.564371, 3.75],
-    [3.125, 19.724207, 0.625],
-    [3.125, 19.644651, 1.875],
-    [4.375, 19.484573, 0.625],
-    [4.375, 19.404057, 1.875],
-    [3.75, 19.564373, 1.25],
-    [3.125, 19.484573, 3.125],
-    [3.125, 19.24208, 4.375],
-    [4.940827, 18.736036, 4.940827],
-    [4.375, 19.24208, 3.125],
-    [4.464496, 18.95577, 4.464496],
-    [3.9822478, 19.143095, 3.9822478],
-    [2.6418376, 19.515259, 2.6418376],
-    [0.625, 17.398159, 9.8144245],
-    [1.875, 17.35254, 9.731327],
-    [1.25, 17.375349, 9.772876],
-    [0.625, 18.634232, 7.189165],
-    [0.625, 19.160633, 5.625],
-    [0.625, 18.863594, 6.564165],
-    [1.875, 18.591757, 7.075191],
-    [1.875, 19.078878, 5.625],
-    [1.875, 18.822376, 6.450191],
-    [1.25, 18.85858, 6.421452],
-    [0.625, 18.239578, 8.125],
-    [0.625, 17.730347, 9.1894245],
-    [1.875, 18.153528, 8.125],
-    [1.875, 17.686771, 9.106327],
-    [1.25, 17.952557, 8.636438],
-    [3.125, 17.260742, 9.564161],
-    [4.7029595, 17.424526, 8.590999],
-    [4.321776, 17.152058, 9.2837515],
-    [4.0181465, 17.256403, 9.204033],
-    [3.500487, 18.708017, 6.1119275],
-    [3.1002922, 18.529839, 6.795851],
-    [3.1002922, 18.906551, 5.6671567],
-    [2.6671536, 18.777624, 6.3264184],
-    [4.464496, 18.63184, 5.6671567],
-    [4.375, 18.239576, 6.875],
-    [4.107494, 18.83258, 5.2785945],
-    [3.6303864, 18.651932, 6.1179156],
-    [3.125, 17.98042, 8.125],
-    [3.125, 17.599136, 8.939161],
-    [4.321776, 17.719128, 8.154599],
-    [3.8696263, 17.529474, 8.8062525],
-    [3.63861, 17.718893, 8.465525],
-    [2.5777533, 18.189259, 7.5738397],
-    [8.718328, 14.982282, 9.972607],
-    [9.972607, 14.982282, 8.718328],
-    [9.483564, 14.806005, 9.483564],
-    [9.408239, 14.902155, 9.408238],
-    [9.408239, 14.902155, 9.408238],
-    [5.625, 19.160631, 0.625],
-    [5.625, 19.078878, 1.875],
-    [7.189165, 18.634232, 0.625],
-    [7.075191, 18.591757, 1.875],
-    [6.564165, 18.863594, 0.625],
-    [6.450191, 18.822376, 1.875],
-    [6.421452, 18.858578, 1.25],
-    [6.1119275, 18.708017, 3.500487],
-    [5.6671567, 18.63184, 4.464496],
-    [5.6671567, 18.906551, 3.1002922],
-    [5.2785945, 18.83258, 4.107494],
-    [6.795851, 18.529837, 3.1002922],
-    [6.875, 18.239578, 4.375],
-    [6.3264184, 18.777624, 2.6671536],
-    [6.1179156, 18.651932, 3.6303859],
-    [9.8144245, 17.398159, 0.625],
-    [9.731327, 17.35254, 1.875],
-    [9.772876, 17.375349, 1.25],
-    [8.590999, 17.424526, 4.7029595],
-    [9.564161, 17.260742, 3.125],
-    [9.2837515, 17.15206, 4.321776],
-    [9.204034, 17.256403, 4.0181465],
-    [8.125, 18.239578, 0.625],
-    [8.125, 18.153526, 1.875],
-    [9.189424, 17.730347, 0.625],
-    [9.106327, 17.686771, 1.875],
-    [8.636437, 17.952557, 1.25],
-    [8.125, 17.98042, 3.125],
-    [8.154599, 17.719128, 4.321776],
-    [8.939161, 17.599136, 3.125],
-    [8.8062525, 17.529474, 3.8696263],
-    [8.465525, 17.718893, 3.63861],
-    [7.573839, 18.189259, 2.5777528],
-    [6.2040677, 17.48497, 7.4624944],
-    [7.4624944, 17.48497, 6.2040677],
-    [6.977496, 17.372486, 6.977496],
-    [6.8988338, 17.43384, 6.8988338],
-    [6.1580443, 16.217432, 9.945158],
-    [5.7224407, 17.317713, 8.154599],
-    [5.6948266, 16.642796, 9.467094],
-    [6.875, 16.064978, 9.688559],
-    [6.875, 16.903101, 8.125],
-    [6.875, 16.42622, 9.063559],
-    [6.3224187, 16.641281, 9.018097],
-    [5.625, 18.325289, 5.625],
-    [5.7224407, 17.823565, 6.977496],
-    [6.977496, 17.823565, 5.7224407],
-    [6.6291614, 17.646322, 6.6291614],
-    [6.228657, 17.91034, 6.228657],
-    [5.3707347, 17.617271, 7.7576656],
-    [5.3707347, 17.617271, 7.7576656],
-    [8.154599, 17.317713, 5.7224407],
-    [8.125, 16.9031, 6.875],
-    [9.945158, 16.217432, 6.1580443],
-    [9.688559, 16.064978, 6.875],
-    [9.467094, 16.642796, 5.6948266],
-    [9.063559, 16.42622, 6.875],
-    [9.018097, 16.641281, 6.3224187],
-    [8.398118, 16.069181, 8.398118],
-    [8.141557, 15.6358595, 9.38943],
-    [8.038871, 16.428444, 8.038871],
-    [7.564785, 16.2816, 8.8062525],
-    [9.38943, 15.6358595, 8.141557],
-    [9.139274, 15.235847, 9.139274],
-    [8.8062525, 16.2816, 7.564785],
-    [8.520881, 15.909558, 8.520882],
-    [7.7576656, 17.617271, 5.3707347],
-    [7.7576656, 17.617271, 5.3707347],
-    [7.537858, 16.773388, 7.537858],
-    [5.476698, 17.9243, 5.476698],
-    [0.625, 13.470437, 14.745259],
-    [0.625, 14.745259, 13.470437],
-    [0.625, 14.120259, 14.120259],
-    [1.875, 13.410843, 14.690951],
-    [1.875, 14.690951, 13.410843],
-    [1.875, 14.065951, 14.065951],
-    [1.25, 14.08395, 14.08395],
-    [4.7626023, 12.413581, 14.928902],
-    [4.7626023, 12.413581, 14.928902],
-    [4.7626023, 14.928902, 12.413581],
-    [4.7626023, 14.928902, 12.413581],
-    [3.125, 13.290969, 14.581705],
-    [3.125, 14.581705, 13.290969],
-    [3.125, 13.956705, 13.956705],
-    [4.881536, 13.711067, 13.711067],
-    [4.4054027, 13.135811, 14.385528],
-    [4.4054027, 14.385528, 13.135811],
-    [4.048203, 13.842153, 13.842153],
-    [3.905135, 13.836841, 13.83684],
-    [2.928828, 13.919124, 13.919124],
-    [0.625, 10.625, 16.903103],
-    [0.625, 12.05828, 15.916279],
-    [0.625, 11.433281, 16.379944],
-    [1.875, 10.625, 16.810335],
-    [1.875, 11.991785, 15.866021],
-    [1.875, 11.366784, 16.332794],
-    [1.25, 11.350021, 16.36808],
-    [0.625, 12.845437, 15.291279],
-    [1.875, 12.785843, 15.241021],
-    [1.25, 12.8156395, 15.2661495],
-    [3.500487, 11.177487, 16.201096],
-    [3.1002922, 10.706492, 16.577555],
-    [3.1002922, 11.776747, 15.832608],
-    [2.6671536, 11.294578, 16.2816],
-    [4.375, 11.013046, 16.090162],
-    [4.375, 10.388045, 16.49668],
-    [4.3575616, 11.948149, 15.402763],
-    [3.6652985, 11.2130165, 16.098625],
-    [3.125, 12.665969, 15.139938],
-    [3.9292688, 12.560555, 15.051056],
-    [3.4696867, 12.620791, 15.101846],
-    [2.508551, 11.594833, 15.993178],
-    [0.625, 15.916278, 12.058281],
-    [0.625, 16.903101, 10.625],
-    [0.625, 16.379942, 11.433281],
-    [1.875, 15.866021, 11.991785],
-    [1.875, 16.810333, 10.625],
-    [1.875, 16.332794, 11.366785],
-    [1.25, 16.368078, 11.350021],
-    [0.625, 15.291278, 12.845437],
-    [1.875, 15.241021, 12.785843],
-    [1.25, 15.2661495, 12.8156395],
-    [3.500487, 16.201096, 11.177487],
-    [3.1002922, 15.832608, 11.776747],
-    [3.1002922, 16.577555, 10.706492],
-    [2.6671536, 16.2816, 11.294578],
-    [4.375, 16.090162, 11.013045],
-    [4.3575616, 15.402763, 11.948149],
-    [4.375, 16.49668, 10.388045],
-    [3.6652985, 16.098625, 11.2130165],
-    [3.125, 15.139938, 12.665969],
-    [3.9292688, 15.051056, 12.560555],
-    [3.4696867, 15.101846, 12.620791],
-    [2.508551, 15.993178, 11.594833],
-    [5.625, 12.222614, 14.771767],
-    [7.3117175, 11.128898, 14.911034],
-    [7.4624944, 12.478462, 13.730554],
-    [6.9704394, 11.930851, 14.422244],
-    [6.790329, 11.956102, 14.471581],
-    [5.625, 14.771767, 12.222614],
-    [7.4624944, 13.730554, 12.478462],
-    [7.3117175, 14.911034, 11.128898],
-    [6.9704394, 14.422244, 11.93085],
-    [6.790329, 14.4715805, 11.956102],
-    [5.625, 13.558707, 13.558707],
-    [5.625, 12.933707, 14.146767],
-    [5.625, 14.146767, 12.933707],
-    [7.045828, 13.2155485, 13.2155485],
-    [6.6291614, 12.700542, 13.933454],
-    [6.6291614, 13.933454, 12.700542],
-    [6.231247, 13.406334, 13.406334],
-    [9.779296, 12.326451, 12.326451],
-    [9.779296, 12.326451, 12.326451],
-    [8.230997, 10.677339, 14.7412815],
-    [8.125, 12.254698, 13.528498],
-    [8.125, 11.629698, 14.06489],
-    [9.723573, 11.006574, 13.553721],
-    [9.334143, 10.603945, 14.121927],
-    [9.334767, 11.843793, 13.097248],
-    [8.890239, 11.361135, 13.842153],
-    [8.822525, 11.336995, 13.84869],
-    [8.125, 13.528497, 12.254698],
-    [8.230997, 14.7412815, 10.677339],
-    [8.125, 14.06489, 11.629698],
-    [9.723573, 13.553721, 11.006574],
-    [9.334767, 13.097248, 11.843793],
-    [9.334143, 14.121927, 10.603945],
-    [8.890239, 13.842153, 11.361135],
-    [8.822525, 13.84869, 11.336996],
-    [8.125, 12.903498, 12.903498],
-    [8.945962, 12.640775, 12.640775],
-    [8.476841, 12.790902, 12.790902],
-    [7.8164105, 12.910169, 12.910169],
-    [5.6948266, 10.603945, 15.943033],
-    [5.324711, 10.17324, 16.36011],
-    [5.625, 11.597614, 15.265717],
-    [6.88703, 10.677339, 15.413083],
-    [6.478384, 11.383239, 15.101833],
-    [6.040929, 10.873316, 15.611464],
-    [7.884995, 10.295564, 15.202538],
-    [7.884995, 10.295564, 15.202538],
-    [6.281459, 10.797956, 15.5581255],
-    [5.6948266, 15.943033, 10.603945],
-    [5.625, 15.265716, 11.597614],
-    [5.324711, 16.36011, 10.17324],
-    [6.88703, 15.413083, 10.677339],
-    [6.478384, 15.101833, 11.383239],
-    [6.040929, 15.611464, 10.873316],
-    [7.884995, 15.202538, 10.295564],
-    [7.884995, 15.202538, 10.295564],
-    [6.281459, 15.5581255, 10.797956],
-    [4.866052, 13.434792, 13.434792],
-    [14.982282, 8.718328, 9.972607],
-    [14.982282, 9.972607, 8.718328],
-    [14.806005, 9.483564, 9.483564],
-    [14.902155, 9.408239, 9.408238],
-    [14.902155, 9.408239, 9.408238],
-    [19.960873, 0.625, 0.625],
-    [19.88232, 0.625, 1.875],
-    [19.88232, 1.875, 0.625],
-    [19.803429, 1.875, 1.875],
-    [19.882236, 1.25, 1.25],
-    [19.724207, 0.625, 3.125],
-    [19.484573, 0.625, 4.375],
-    [19.644653, 1.875, 3.125],
-    [19.404057, 1.875, 4.375],
-    [19.564373, 1.25, 3.75],
-    [19.724205, 3.125, 0.625],
-    [19.644653, 3.125, 1.875],
-    [19.484573, 4.375, 0.625],
-    [19.404055, 4.375, 1.875],
-    [19.564371, 3.75, 1.25],
-    [18.736036, 4.940827, 4.940827],
-    [19.484573, 3.125, 3.125],
-    [19.24208, 3.125, 4.375],
-    [19.24208, 4.375, 3.125],
-    [18.95577, 4.464496, 4.464496],
-    [19.143095, 3.9822478, 3.9822478],
-    [19.515259, 2.6418376, 2.6418376],
-    [17.398159, 0.625, 9.814424],
-    [17.35254, 1.875, 9.731327],
-    [17.375349, 1.25, 9.772875],
-    [17.260742, 3.125, 9.564161],
-    [17.424526, 4.7029595, 8.590999],
-    [17.152058, 4.321776, 9.2837515],
-    [17.256403, 4.0181465, 9.204033],
-    [18.634232, 0.625, 7.189165],
-    [18.591757, 1.875, 7.075191],
-    [19.160631, 0.625, 5.625],
-    [18.863594, 0.625, 6.564165],
-    [19.078878, 1.875, 5.625],
-    [18.822376, 1.875, 6.450191],
-    [18.858578, 1.25, 6.421452],
-    [18.239578, 0.625, 8.125],
-    [17.730347, 0.625, 9.189424],
-    [18.153526, 1.875, 8.125],
-    [17.686771, 1.875, 9.106327],
-    [17.952557, 1.25, 8.636437],
-    [18.708017, 3.500487, 6.1119275],
-    [18.529839, 3.1002922, 6.795851],
-    [18.63184, 4.464496, 5.6671567],
-    [18.239578, 4.375, 6.875],
-    [18.906551, 3.1002922, 5.6671567],
-    [18.777624, 2.6671536, 6.3264184],
-    [18.83258, 4.107494, 5.2785945],
-    [18.651934, 3.6303859, 6.1179156],
-    [17.98042, 3.125, 8.125],
-    [17.599136, 3.125, 8.939161],
-    [17.719128, 4.321776, 8.154599],
-    [17.529474, 3.8696263, 8.8062525],
-    [17.718893, 3.63861, 8.465525],
-    [18.189259, 2.5777528, 7.573839],
-    [17.398159, 9.814424, 0.625],
-    [17.35254, 9.731327, 1.875],
-    [17.375349, 9.772875, 1.25],
-    [17.424526, 8.590999, 4.7029595],
-    [17.260742, 9.564161, 3.125],
-    [17.15206, 9.2837515, 4.321776],
-    [17.256403, 9.204034, 4.0181465],
-    [18.634232, 7.189165, 0.625],
-    [18.591757, 7.075191, 1.875],
-    [19.160633, 5.625, 0.625],
-    [19.078878, 5.625, 1.875],
-    [18.863594, 6.564165, 0.625],
-    [18.822376, 6.450191, 1.875],
-    [18.858578, 6.421452, 1.25],
-    [18.708017, 6.1119275, 3.500487],
-    [18.63184, 5.6671567, 4.464496],
-    [18.529837, 6.795851, 3.1002922],
-    [18.239576, 6.875, 4.375],
-    [18.906551, 5.6671567, 3.1002922],
-    [18.83258, 5.2785945, 4.107494],
-    [18.777624, 6.3264184, 2.6671536],
-    [18.651932, 6.1179156, 3.6303859],
-    [18.239578, 8.125, 0.625],
-    [18.153526, 8.125, 1.875],
-    [17.730347, 9.189424, 0.625],
-    [17.686771, 9.106327, 1.875],
-    [17.952557, 8.636437, 1.25],
-    [17.980421, 8.125, 3.125],
-    [17.719128, 8.154599, 4.321776],
-    [17.599136, 8.939161, 3.125],
-    [17.529474, 8.8062525, 3.8696263],
-    [17.718895, 8.465525, 3.63861],
-    [18.189259, 7.573839, 2.5777528],
-    [17.48497, 6.2040677, 7.4624944],
-    [17.48497, 7.4624944, 6.2040677],
-    [17.372486, 6.977496, 6.977496],
-    [17.43384, 6.8988338, 6.8988338],
-    [16.217432, 6.1580443, 9.945158],
-    [16.064978, 6.875, 9.688559],
-    [17.317713, 5.7224407, 8.154599],
-    [16.642796, 5.6948266, 9.467094],
-    [16.903101, 6.875, 8.125],
-    [16.42622, 6.875, 9.063559],
-    [16.641281, 6.3224187, 9.018096],
-    [16.217432, 9.945158, 6.1580443],
-    [16.064978, 9.688559, 6.875],
-    [17.317713, 8.154599, 5.7224407],
-    [16.903101, 8.125, 6.875],
-    [16.642796, 9.467094, 5.6948266],
-    [16.42622, 9.063559, 6.875],
-    [16.641281, 9.018096, 6.3224187],
-    [16.069181, 8.398118, 8.398118],
-    [15.6358595, 8.141557, 9.38943],
-    [15.6358595, 9.38943, 8.141557],
-    [15.235847, 9.139274, 9.139274],
-    [16.428444, 8.038871, 8.038871],
-    [16.2816, 7.564785, 8.8062525],
-    [16.2816, 8.8062525, 7.564785],
-    [15.909559, 8.520882, 8.520881],
-    [18.325289, 5.625, 5.625],
-    [17.823565, 5.7224407, 6.977496],
-    [17.823565, 6.977496, 5.7224407],
-    [17.646322, 6.6291614, 6.6291614],
-    [17.91034, 6.228657, 6.228657],
-    [17.617271, 5.3707347, 7.7576656],
-    [17.617271, 5.3707347, 7.7576656],
-    [17.617271, 7.7576656, 5.3707347],
-    [17.617271, 7.7576656, 5.3707347],
-    [16.773388, 7.537858, 7.537858],
-    [17.9243, 5.476698, 5.476698],
-    [12.413581, 4.7626023, 14.928902],
-    [12.413581, 4.7626023, 14.928902],
-    [13.470437, 0.625, 14.745259],
-    [13.410843, 1.875, 14.690951],
-    [14.745259, 0.625, 13.470437],
-    [14.120259, 0.625, 14.120259],
-    [14.690951, 1.875, 13.410843],
-    [14.065951, 1.875, 14.065951],
-    [14.08395, 1.25, 14.08395],
-    [14.928902, 4.7626023, 12.413581],
-    [14.928902, 4.7626023, 12.413581],
-    [13.290969, 3.125, 14.581705],
-    [13.711067, 4.881536, 13.711067],
-    [13.135811, 4.4054027, 14.385528],
-    [14.581705, 3.125, 13.290969],
-    [13.956705, 3.125, 13.956705],
-    [14.385528, 4.4054027, 13.135811],
-    [13.842153, 4.048203, 13.842153],
-    [13.836841, 3.9051352, 13.83684],
-    [13.919125, 2.928828, 13.919125],
-    [10.625, 0.625, 16.903103],
-    [10.625, 1.875, 16.810335],
-    [12.058281, 0.625, 15.916278],
-    [11.433281, 0.625, 16.379942],
-    [11.991784, 1.875, 15.866021],
-    [11.366785, 1.875, 16.332794],
-    [11.350021, 1.25, 16.368078],
-    [11.177487, 3.500487, 16.201096],
-    [10.706492, 3.1002922, 16.577555],
-    [11.013046, 4.375, 16.090162],
-    [10.388045, 4.375, 16.49668],
-    [11.776747, 3.1002922, 15.832608],
-    [11.294578, 2.6671536, 16.2816],
-    [11.948149, 4.3575616, 15.402763],
-    [11.213018, 3.6652985, 16.098625],
-    [12.845437, 0.625, 15.291278],
-    [12.785843, 1.875, 15.241021],
-    [12.8156395, 1.25, 15.2661495],
-    [12.665969, 3.125, 15.139938],
-    [12.560555, 3.9292688, 15.051056],
-    [12.620791, 3.4696867, 15.101846],
-    [11.594833, 2.508551, 15.993178],
-    [11.128898, 7.3117175, 14.911034],
-    [12.222614, 5.625, 14.771767],
-    [12.478462, 7.4624944, 13.730554],
-    [11.93085, 6.9704385, 14.422244],
-    [11.956102, 6.790329, 14.471581],
-    [12.326451, 9.779296, 12.326451],
-    [12.326451, 9.779296, 12.326451],
-    [10.677339, 8.230997, 14.7412815],
-    [11.006574, 9.723573, 13.553721],
-    [10.603945, 9.334143, 14.121927],
-    [12.254698, 8.125, 13.528498],
-    [11.629698, 8.125, 14.06489],
-    [11.843793, 9.334767, 13.097248],
-    [11.361135, 8.890239, 13.842153],
-    [11.336996, 8.822524, 13.84869],
-    [13.730554, 7.4624944, 12.478462],
-    [14.771767, 5.625, 12.222614],
-    [14.911034, 7.3117175, 11.128898],
-    [14.422244, 6.9704385, 11.93085],
-    [14.4715805, 6.790329, 11.956102],
-    [13.558707, 5.625, 13.558707],
-    [12.933707, 5.625, 14.146767],
-    [13.2155485, 7.0458274, 13.2155485],
-    [12.700542, 6.6291614, 13.933454],
-    [14.146767, 5.625, 12.933707],
-    [13.933454, 6.6291614, 12.700542],
-    [13.406334, 6.231247, 13.406333],
-    [13.528498, 8.125, 12.254698],
-    [13.553721, 9.723573, 11.006574],
-    [13.097249, 9.334767, 11.843793],
-    [14.7412815, 8.230997, 10.677339],
-    [14.06489, 8.125, 11.629698],
-    [14.121927, 9.334143, 10.603945],
-    [13.842153, 8.890239, 11.361135],
-    [13.84869, 8.822524, 11.336996],
-    [12.903498, 8.125, 12.903498],
-    [12.640775, 8.945962, 12.640775],
-    [12.790902, 8.476841, 12.790902],
-    [12.910169, 7.81641, 12.910168],
-    [10.603945, 5.6948266, 15.943031],
-    [10.17324, 5.324711, 16.36011],
-    [10.677339, 6.88703, 15.413081],
-    [11.597614, 5.625, 15.265716],
-    [11.383239, 6.478384, 15.101833],
-    [10.873316, 6.040929, 15.611463],
-    [10.295564, 7.884995, 15.202538],
-    [10.295564, 7.884995, 15.202538],
-    [10.797956, 6.281459, 15.558124],
-    [15.916278, 0.625, 12.058281],
-    [15.866021, 1.875, 11.991785],
-    [16.903103, 0.625, 10.625],
-    [16.379942, 0.625, 11.433281],
-    [16.810335, 1.875, 10.625],
-    [16.332794, 1.875, 11.366784],
-    [16.368078, 1.25, 11.350021],
-    [15.291278, 0.625, 12.845437],
-    [15.241021, 1.875, 12.785843],
-    [15.2661495, 1.25, 12.8156395],
-    [16.201096, 3.500487, 11.177487],
-    [15.832608, 3.1002922, 11.776747],
-    [16.090162, 4.375, 11.013045],
-    [15.402763, 4.3575616, 11.948149],
-    [16.577555, 3.1002922, 10.706492],
-    [16.2816, 2.6671536, 11.294578],
-    [16.49668, 4.375, 10.388045],
-    [16.098625, 3.6652985, 11.2130165],
-    [15.139938, 3.125, 12.665969],
-    [15.051056, 3.9292688, 12.560555],
-    [15.101846, 3.4696867, 12.620791],
-    [15.993178, 2.508551, 11.594833],
-    [15.943033, 5.6948266, 10.603945],
-    [15.265717, 5.625, 11.597614],
-    [15.413083, 6.88703, 10.677339],
-    [15.101833, 6.478384, 11.383239],
-    [16.36011, 5.324711, 10.17324],
-    [15.611464, 6.0409284, 10.873316],
-    [15.202538, 7.884995, 10.295564],
-    [15.202538, 7.884995, 10.295564],
-    [15.5581255, 6.281459, 10.797956],
-    [13.434792, 4.866052, 13.434792],
-    [12.413581, 14.928902, 4.7626023],
-    [12.413581, 14.928902, 4.7626023],
-    [14.928902, 12.413581, 4.7626023],
-    [14.928902, 12.413581, 4.7626023],
-    [13.470437, 14.745259, 0.625],
-    [13.410843, 14.690951, 1.875],
-    [14.745259, 13.470437, 0.625],
-    [14.690951, 13.410843, 1.875],
-    [14.120259, 14.120259, 0.625],
-    [14.065951, 14.065951, 1.875],
-    [14.08395, 14.08395, 1.25],
-    [13.711067, 13.711067, 4.881536],
-    [13.290969, 14.581705, 3.125],
-    [13.135811, 14.385528, 4.4054027],
-    [14.581705, 13.290969, 3.125],
-    [14.385528, 13.135811, 4.4054027],
-    [13.956705, 13.956705, 3.125],
-    [13.842153, 13.842153, 4.048203],
-    [13.836841, 13.836841, 3.9051352],
-    [13.919125, 13.919125, 2.928828],
-    [12.326451, 12.326451, 9.779296],
-    [12.326451, 12.326451, 9.779296],
-    [11.128898, 14.911034, 7.3117175],
-    [12.478462, 13.730554, 7.4624944],
-    [12.222614, 14.771767, 5.625],
-    [11.93085, 14.422244, 6.9704394],
-    [11.956101, 14.471581, 6.7903295],
-    [11.006574, 13.553721, 9.723573],
-    [10.677339, 14.7412815, 8.230997],
-    [10.603945, 14.121927, 9.334143],
-    [12.254698, 13.528498, 8.125],
-    [11.843793, 13.097248, 9.334767],
-    [11.629698, 14.06489, 8.125],
-    [11.361135, 13.842153, 8.890239],
-    [11.336996, 13.84869, 8.822524],
-    [13.730554, 12.478462, 7.4624944],
-    [14.911034, 11.128898, 7.3117175],
-    [14.771767, 12.222614, 5.625],
-    [14.422244, 11.93085, 6.9704394],
-    [14.4715805, 11.956102, 6.7903295],
-    [13.553721, 11.006574, 9.723573],
-    [13.528498, 12.254698, 8.125],
-    [13.097248, 11.843793, 9.334767],
-    [14.7412815, 10.677339, 8.230997],
-    [14.121927, 10.603945, 9.334143],
-    [14.06489, 11.629698, 8.125],
-    [13.842153, 11.361135, 8.890239],
-    [13.84869, 11.336996, 8.822524],
-    [13.558707, 13.558707, 5.625],
-    [13.2155485, 13.2155485, 7.045828],
-    [12.933707, 14.146767, 5.625],
-    [12.700542, 13.933454, 6.6291614],
-    [14.146767, 12.933707, 5.625],
-    [13.933454, 12.700542, 6.6291614],
-    [13.406334, 13.406333, 6.231247],
-    [12.903498, 12.903498, 8.125],
-    [12.640775, 12.640775, 8.945962],
-    [12.790902, 12.790902, 8.476841],
-    [12.910169, 12.910168, 7.81641],
-    [10.625, 16.903103, 0.625],
-    [10.625, 16.810335, 1.875],
-    [12.058281, 15.916278, 0.625],
-    [11.991785, 15.866021, 1.875],
-    [11.433281, 16.379942, 0.625],
-    [11.366784, 16.332794, 1.875],
-    [11.350021, 16.368078, 1.25],
-    [11.177487, 16.201096, 3.500487],
-    [11.013045, 16.090162, 4.375],
-    [10.706492, 16.577555, 3.1002922],
-    [10.388045, 16.49668, 4.375],
-    [11.776747, 15.832608, 3.1002922],
-    [11.948149, 15.402763, 4.3575616],
-    [11.294578, 16.2816, 2.6671536],
-    [11.2130165, 16.098623, 3.6652985],
-    [12.845437, 15.291278, 0.625],
-    [12.785843, 15.241021, 1.875],
-    [12.8156395, 15.2661495, 1.25],
-    [12.665969, 15.139938, 3.125],
-    [12.560555, 15.051056, 3.9292688],
-    [12.620791, 15.101846, 3.4696867],
-    [11.594833, 15.993176, 2.508551],
-    [10.603945, 15.943031, 5.6948266],
-    [10.677339, 15.413083, 6.88703],
-    [10.17324, 16.36011, 5.324711],
-    [11.597614, 15.265717, 5.625],
-    [11.383239, 15.101833, 6.478384],
-    [10.873316, 15.611463, 6.040929],
-    [10.295564, 15.202538, 7.884995],
-    [10.295564, 15.202538, 7.884995],
-    [10.797956, 15.558124, 6.281459],
-    [15.916279, 12.058281, 0.625],
-    [15.866021, 11.991784, 1.875],
-    [16.903101, 10.625, 0.625],
-    [16.810333, 10.625, 1.875],
-    [16.379942, 11.433281, 0.625],
-    [16.332794, 11.366785, 1.875],
-    [16.368078, 11.350021, 1.25],
-    [16.201096, 11.177487, 3.500487],
-    [16.090162, 11.013046, 4.375],
-    [15.832608, 11.776747, 3.1002922],
-    [15.402763, 11.948149, 4.3575616],
-    [16.577555, 10.706492, 3.1002922],
-    [16.49668, 10.388045, 4.375],
-    [16.2816, 11.294578, 2.6671536],
-    [16.098623, 11.213018, 3.6652985],
-    [15.291278, 12.845437, 0.625],
-    [15.241021, 12.785843, 1.875],
-    [15.2661495, 12.8156395, 1.25],
-    [15.139938, 12.665969, 3.125],
-    [15.051056, 12.560555, 3.9292688],
-    [15.101846, 12.620791, 3.4696867],
-    [15.993176, 11.594833, 2.508551],
-    [15.943033, 10.603945, 5.6948266],
-    [15.413083, 10.677339, 6.88703],
-    [15.265716, 11.597614, 5.625],
-    [15.101833, 11.383239, 6.478384],
-    [16.36011, 10.17324, 5.324711],
-    [15.611464, 10.873316, 6.0409284],
-    [15.202538, 10.295564, 7.884995],
-    [15.202538, 10.295564, 7.884995],
-    [15.5581255, 10.797956, 6.281459],
-    [13.434792, 13.434792, 4.866052],
-    [11.104529, 11.104529, 12.370105],
-    [11.104529, 12.370105, 11.104529],
-    [10.687862, 11.931611, 11.931611],
-    [12.370105, 11.104529, 11.104529],
-    [11.931611, 10.687862, 11.931611],
-    [11.931611, 11.931611, 10.687862],
-    [11.536771, 11.536771, 11.536771],
-    [11.521811, 11.521811, 11.521811],
-    [10.638885, 10.638885, 13.137054],
-    [10.17324, 10.17324, 13.878777],
-    [10.271195, 11.493118, 12.720387],
-    [11.493118, 10.271195, 12.720387],
-    [10.6430645, 10.6430645, 13.1187315],
-    [10.638885, 13.137054, 10.638885],
-    [10.271195, 12.720387, 11.493118],
-    [10.17324, 13.878777, 10.17324],
-    [11.493118, 12.720387, 10.271195],
-    [10.6430645, 13.1187315, 10.6430645],
-    [13.137054, 10.638885, 10.638885],
-    [12.720387, 10.271195, 11.493118],
-    [12.720387, 11.493118, 10.271195],
-    [13.878777, 10.17324, 10.17324],
-    [13.1187315, 10.6430645, 10.6430645],
-    [11.489697, 11.489697, 11.489697],
-    [11.489697, 11.489697, 11.489697],
-    [10.126742, 10.126742, 10.126742],
-];
-
-#[allow(dead_code)]
-pub const EXPECTED_NORMALS: &[[f32; 3]] = &[
-    [-0.6943562, -0.5088563, -0.5088563],
-    [-0.63657665, -0.57526654, -0.51365227],
-    [-0.63638526, -0.5137616, -0.5753806],
-    [-0.6576295, -0.5326919, -0.5326941],
-    [-0.6568716, -0.5331593, -0.53316104],
-    [-0.57526654, -0.63657665, -0.51365227],
-    [-0.5088563, -0.6943562, -0.5088563],
-    [-0.5137616, -0.63638526, -0.5753806],
-    [-0.5326918, -0.6576295, -0.53269404],
-    [-0.5331593, -0.65687174, -0.5331611],
-    [-0.5753806, -0.5137616, -0.63638526],
-    [-0.5137616, -0.5753806, -0.63638526],
-    [-0.5089309, -0.5089309, -0.69424677],
-    [-0.5327861, -0.5327861, -0.65747863],
-    [-0.53322995, -0.53322995, -0.6567585],
-    [-0.57735026, -0.57735026, -0.57735026],
-    [-0.5974675, -0.5974675, -0.53485054],
-    [-0.59742147, -0.5349535, -0.59742147],
-    [-0.6185422, -0.55560803, -0.55561256],
-    [-0.5349534, -0.59742147, -0.59742147],
-    [-0.55560803, -0.61854213, -0.55561256],
-    [-0.5557188, -0.5557188, -0.6183473],
-    [-0.5773715, -0.5773715, -0.5773078],
-    [-0.5773707, -0.5773707, -0.5773095],
-    [-0.5773707, -0.5773707, -0.5773095],
-    [-0.76067156, -0.51527786, -0.39480042],
-    [-0.7606717, -0.5152779, -0.39480048],
-    [-0.81792694, -0.50965697, -0.2669183],
-    [-0.75566536, -0.5691334, -0.32412475],
-    [-0.7642177, -0.58030725, -0.28145146],
-    [-0.7715333, -0.53451157, -0.34501287],
-    [-0.7976628, -0.5312552, -0.28548548],
-    [-0.78220624, -0.54553425, -0.30090824],
-    [-0.77242386, -0.5310833, -0.3482985],
-    [-0.7524859, -0.6285504, -0.19669628],
-    [-0.7575485, -0.6337491, -0.156468],
-    [-0.75517493, -0.6312817, -0.17661904],
-    [-0.7628616, -0.63976383, -0.09351146],
-    [-0.76540303, -0.6428036, -0.03100915],
-    [-0.76450723, -0.6415984, -0.062290855],
-    [-0.8137997, -0.56565136, -0.13329963],
-    [-0.82522035, -0.52049017, -0.21932015],
-    [-0.8293967, -0.53665316, -0.15525566],
-    [-0.77106327, -0.5982785, -0.21800065],
-    [-0.79242224, -0.58991903, -0.15512083],
-    [-0.8048269, -0.5515847, -0.21910736],
-    [-0.80990016, -0.5598676, -0.17495714],
-    [-0.8075206, -0.56094605, -0.18234566],
-    [-0.81684524, -0.56914115, -0.094032906],
-    [-0.81944174, -0.5722968, -0.031489953],
-    [-0.84120214, -0.53247327, -0.09408019],
-    [-0.84589946, -0.5324105, -0.03151556],
-    [-0.794204, -0.6003644, -0.09382261],
-    [-0.79665226, -0.60362905, -0.03125978],
-    [-0.8199343, -0.56900597, -0.06276856],
-    [-0.7889482, -0.6023601, -0.12133883],
-    [-0.515278, -0.76067156, -0.39480042],
-    [-0.515278, -0.76067156, -0.39480042],
-    [-0.5691334, -0.75566536, -0.32412472],
-    [-0.58030725, -0.7642177, -0.28145143],
-    [-0.50965697, -0.81792694, -0.26691833],
-    [-0.5345116, -0.77153325, -0.3450128],
-    [-0.5312552, -0.7976628, -0.28548548],
-    [-0.5455343, -0.7822062, -0.30090824],
-    [-0.53108335, -0.77242386, -0.34829846],
-    [-0.6285504, -0.7524859, -0.19669631],
-    [-0.6337491, -0.7575485, -0.15646799],
-    [-0.6312817, -0.75517493, -0.17661905],
-    [-0.63976383, -0.7628616, -0.09351146],
-    [-0.6428037, -0.7654031, -0.031009154],
-    [-0.6415984, -0.7645073, -0.062290855],
-    [-0.56565136, -0.8137997, -0.13329963],
-    [-0.5982785, -0.77106327, -0.21800068],
-    [-0.5899191, -0.7924223, -0.15512083],
-    [-0.52049017, -0.82522035, -0.21932015],
-    [-0.5366531, -0.8293967, -0.15525566],
-    [-0.5515847, -0.8048269, -0.21910737],
-    [-0.5598676, -0.80990016, -0.17495714],
-    [-0.56094617, -0.8075206, -0.18234569],
-    [-0.56914115, -0.8168452, -0.0940329],
-    [-0.57229674, -0.8194418, -0.031489953],
-    [-0.6003643, -0.79420394, -0.093822606],
-    [-0.603629, -0.7966522, -0.031259775],
-    [-0.53247327, -0.84120214, -0.094080195],
-    [-0.53241056, -0.8458994, -0.03151556],
-    [-0.56900597, -0.81993437, -0.06276856],
-    [-0.6023601, -0.78894806, -0.12133883],
-    [-0.6323825, -0.6323825, -0.44741994],
-    [-0.6458738, -0.6458738, -0.40705565],
-    [-0.6392875, -0.6392875, -0.4273443],
-    [-0.6973894, -0.63540477, -0.33152497],
-    [-0.70819503, -0.64738035, -0.28170618],
-    [-0.63540477, -0.6973893, -0.33152494],
-    [-0.64738035, -0.70819503, -0.28170618],
-    [-0.66166675, -0.66166675, -0.35269552],
-    [-0.6785289, -0.67852885, -0.28141975],
-    [-0.6721709, -0.6721708, -0.31043956],
-    [-0.69213676, -0.56825644, -0.44500726],
-    [-0.70386404, -0.58223426, -0.40691346],
-    [-0.70701736, -0.5307293, -0.4673896],
-    [-0.73787886, -0.5344669, -0.4121649],
-    [-0.6557199, -0.5929463, -0.46738222],
-    [-0.6769644, -0.61328274, -0.406944],
-    [-0.67813694, -0.5507637, -0.48661032],
-    [-0.69392186, -0.56819654, -0.44229534],
-    [-0.72213846, -0.597313, -0.34890273],
-    [-0.73937637, -0.6115759, -0.28159812],
-    [-0.74587893, -0.5565471, -0.36595076],
-    [-0.6866207, -0.6240265, -0.37301892],
-    [-0.7244028, -0.59810805, -0.34279317],
-    [-0.56825644, -0.69213676, -0.44500726],
-    [-0.58223426, -0.70386404, -0.4069135],
-    [-0.5929463, -0.6557199, -0.46738228],
-    [-0.61328274, -0.6769644, -0.406944],
-    [-0.5307293, -0.70701736, -0.4673896],
-    [-0.5344669, -0.73787886, -0.4121649],
-    [-0.5507637, -0.67813694, -0.48661032],
-    [-0.56819654, -0.69392186, -0.44229534],
-    [-0.5973131, -0.72213846, -0.34890273],
-    [-0.6115759, -0.7393764, -0.2815981],
-    [-0.6240265, -0.6866207, -0.37301892],
-    [-0.5565471, -0.74587893, -0.36595076],
-    [-0.59810805, -0.7244028, -0.34279317],
-    [-0.61683726, -0.61683726, -0.48890042],
-    [-0.61683726, -0.61683726, -0.48890042],
-    [-0.64764833, -0.6476484, -0.40137666],
-    [-0.6924799, -0.6924799, -0.20234467],
-    [-0.6983523, -0.6983523, -0.1568703],
-    [-0.72007656, -0.65791845, -0.22052875],
-    [-0.72982764, -0.665433, -0.15668593],
-    [-0.65791845, -0.72007656, -0.22052875],
-    [-0.6654331, -0.7298277, -0.15668595],
-    [-0.68578, -0.68578, -0.24374485],
-    [-0.69365317, -0.69365317, -0.19414084],
-    [-0.7039446, -0.7039447, -0.09446676],
-    [-0.7067561, -0.7067562, -0.031487603],
-    [-0.735096, -0.6714177, -0.09397877],
-    [-0.73788226, -0.67420614, -0.031237818],
-    [-0.6714177, -0.735096, -0.09397877],
-    [-0.67420614, -0.73788226, -0.031237818],
-    [-0.7057108, -0.7057108, -0.062804855],
-    [-0.74675465, -0.6209723, -0.23822431],
-    [-0.7467547, -0.62097234, -0.23822434],
-    [-0.6209723, -0.7467547, -0.23822434],
-    [-0.62097234, -0.74675477, -0.23822437],
-    [-0.6950065, -0.69500655, -0.18420568],
-    [-0.6831648, -0.6831648, -0.25801516],
-    [-0.7608078, -0.3947095, -0.5151464],
-    [-0.76080793, -0.39470956, -0.51514643],
-    [-0.81792694, -0.26691833, -0.50965697],
-    [-0.75552785, -0.32420805, -0.5692685],
-    [-0.77153325, -0.34500876, -0.53451425],
-    [-0.7642176, -0.28145146, -0.5803073],
-    [-0.7976628, -0.28548548, -0.5312552],
-    [-0.78217995, -0.3009246, -0.5455628],
-    [-0.7724773, -0.34826043, -0.53103065],
-    [-0.75287986, -0.19616966, -0.6282432],
-    [-0.7576048, -0.15600541, -0.6337961],
-    [-0.75539964, -0.1761242, -0.6311511],
-    [-0.82522035, -0.21932015, -0.52049017],
-    [-0.81409216, -0.13335358, -0.5652176],
-    [-0.8295634, -0.15528637, -0.53638667],
-    [-0.77126616, -0.21786658, -0.59806585],
-    [-0.8048269, -0.21910739, -0.5515847],
-    [-0.79262763, -0.1549694, -0.5896828],
-    [-0.8101897, -0.17501052, -0.55943173],
-    [-0.8076838, -0.18232408, -0.560718],
-    [-0.7628616, -0.09351147, -0.63976383],
-    [-0.76540315, -0.031009154, -0.6428037],
-    [-0.7645073, -0.06229086, -0.6415984],
-    [-0.8168452, -0.0940329, -0.56914115],
-    [-0.84120214, -0.094080195, -0.53247327],
-    [-0.81944174, -0.031489953, -0.57229674],
-    [-0.84589946, -0.03151556, -0.5324105],
-    [-0.794204, -0.09382261, -0.6003644],
-    [-0.79665226, -0.03125978, -0.60362905],
-    [-0.8199344, -0.06276857, -0.56900597],
-    [-0.7890399, -0.121208526, -0.602266],
-    [-0.5151102, -0.39460042, -0.76088905],
-    [-0.5151102, -0.39460042, -0.76088905],
-    [-0.5693265, -0.3239224, -0.7556068],
-    [-0.58034486, -0.2812341, -0.764269],
-    [-0.53466713, -0.34472784, -0.77155286],
-    [-0.5094646, -0.26666993, -0.8181278],
-    [-0.5311748, -0.28516495, -0.797831],
-    [-0.5455595, -0.30065462, -0.7822861],
-    [-0.531013, -0.34807205, -0.7725743],
-    [-0.63244194, -0.44770122, -0.63212395],
-    [-0.6458738, -0.40705565, -0.6458738],
-    [-0.6393195, -0.42748648, -0.63916045],
-    [-0.6923032, -0.44491085, -0.56812906],
-    [-0.70701736, -0.4673896, -0.5307293],
-    [-0.70405823, -0.4066382, -0.5821919],
-    [-0.7380218, -0.41195115, -0.5344343],
-    [-0.6558311, -0.4674735, -0.59275126],
-    [-0.67813694, -0.48661035, -0.5507637],
-    [-0.6770918, -0.40687314, -0.6131893],
-    [-0.6940294, -0.44221535, -0.56812745],
-    [-0.6975285, -0.33159938, -0.63521314],
-    [-0.70824456, -0.2814848, -0.6474225],
-    [-0.63554347, -0.33160666, -0.69722414],
-    [-0.6618048, -0.3527732, -0.6614872],
-    [-0.6474225, -0.2814848, -0.70824456],
-    [-0.6785289, -0.28141975, -0.67852885],
-    [-0.6722581, -0.31040588, -0.6720992],
-    [-0.722172, -0.34883973, -0.5973092],
-    [-0.7459645, -0.36566997, -0.55661696],
-    [-0.739426, -0.28137675, -0.6116178],
-    [-0.6867571, -0.37309206, -0.62383264],
-    [-0.724479, -0.3426699, -0.5980864],
-    [-0.56812227, -0.44483417, -0.69235796],
-    [-0.5929142, -0.46744084, -0.6557072],
-    [-0.58234537, -0.40651593, -0.70400196],
-    [-0.6133388, -0.4067483, -0.6770313],
-    [-0.5307137, -0.4673574, -0.7070502],
-    [-0.55073935, -0.48655805, -0.6781944],
-    [-0.5344142, -0.41188684, -0.7380723],
-    [-0.5681868, -0.44214493, -0.6940257],
-    [-0.61688745, -0.48917478, -0.6165694],
-    [-0.6168875, -0.48917484, -0.61656946],
-    [-0.5974804, -0.34884688, -0.72202694],
-    [-0.62416285, -0.37309936, -0.6864529],
-    [-0.6116178, -0.28137672, -0.73942596],
-    [-0.55680907, -0.36548474, -0.74591184],
-    [-0.5982611, -0.34262776, -0.7243547],
-    [-0.647725, -0.40136042, -0.64758176],
-    [-0.6282432, -0.19616966, -0.75287986],
-    [-0.6337961, -0.15600541, -0.7576048],
-    [-0.6311511, -0.1761242, -0.75539964],
-    [-0.63976383, -0.09351147, -0.7628616],
-    [-0.6428037, -0.031009154, -0.76540315],
-    [-0.6415984, -0.06229086, -0.7645073],
-    [-0.5980658, -0.21786655, -0.77126616],
-    [-0.5652887, -0.13328779, -0.8140535],
-    [-0.58972514, -0.15492976, -0.79260385],
-    [-0.5205178, -0.21909471, -0.8252628],
-    [-0.5516121, -0.21888195, -0.8048694],
-    [-0.53643084, -0.15524724, -0.82954216],
-    [-0.55950457, -0.17494518, -0.81015354],
-    [-0.5607578, -0.18222928, -0.8076776],
-    [-0.5691412, -0.094032906, -0.81684524],
-    [-0.6003644, -0.09382262, -0.794204],
-    [-0.57229674, -0.031489953, -0.81944174],
-    [-0.603629, -0.031259775, -0.7966522],
-    [-0.53247327, -0.094080195, -0.84120214],
-    [-0.53241056, -0.03151556, -0.8458994],
-    [-0.5690059, -0.06276856, -0.8199343],
-    [-0.6022749, -0.12118454, -0.7890369],
-    [-0.6924799, -0.20234467, -0.6924799],
-    [-0.72028565, -0.22027145, -0.6577759],
-    [-0.6983523, -0.1568703, -0.6983523],
-    [-0.72985584, -0.15645474, -0.66545653],
-    [-0.6577759, -0.22027145, -0.72028553],
-    [-0.68578, -0.24374485, -0.68578],
-    [-0.6654565, -0.1564547, -0.72985584],
-    [-0.6936726, -0.19400178, -0.6936726],
-    [-0.7471068, -0.23801026, -0.62063086],
-    [-0.7471068, -0.23801026, -0.62063086],
-    [-0.7039446, -0.09446676, -0.7039446],
-    [-0.735096, -0.09397877, -0.6714177],
-    [-0.7067562, -0.031487603, -0.7067562],
-    [-0.73788226, -0.031237818, -0.67420614],
-    [-0.6714177, -0.09397877, -0.735096],
-    [-0.67420614, -0.031237818, -0.73788226],
-    [-0.7057109, -0.06280486, -0.7057109],
-    [-0.62063086, -0.23801026, -0.7471068],
-    [-0.62063086, -0.23801026, -0.7471068],
-    [-0.695025, -0.18406612, -0.6950251],
-    [-0.68318987, -0.2579, -0.6831831],
-    [-0.88146305, -0.38822573, -0.2688933],
-    [-0.88146317, -0.3882258, -0.26889333],
-    [-0.8815712, -0.26893088, -0.38795394],
-    [-0.8815713, -0.2689309, -0.387954],
-    [-0.8830307, -0.3320178, -0.33169383],
-    [-0.8921737, -0.3493525, -0.28631973],
-    [-0.8921842, -0.286514, -0.34916633],
-    [-0.91725105, -0.28164735, -0.28164735],
-    [-0.89698607, -0.3126709, -0.3124946],
-    [-0.81380486, -0.44062606, -0.378907],
-    [-0.81380486, -0.378907, -0.44062606],
-    [-0.8218073, -0.40288505, -0.402885],
-    [-0.7625566, -0.45728362, -0.45760158],
-    [-0.7826584, -0.47014335, -0.40793523],
-    [-0.7826506, -0.40777215, -0.4702976],
-    [-0.80390716, -0.42039615, -0.420714],
-    [-0.79818904, -0.42589805, -0.4260343],
-    [-0.82171464, -0.4536969, -0.34488305],
-    [-0.83276856, -0.47434115, -0.28547677],
-    [-0.84578073, -0.40701374, -0.3449563],
-    [-0.86655223, -0.40844816, -0.28680548],
-    [-0.8036992, -0.48511457, -0.3445741],
-    [-0.8105078, -0.4979435, -0.3084306],
-    [-0.8311822, -0.45498013, -0.31957668],
-    [-0.8217146, -0.344883, -0.45369685],
-    [-0.8457808, -0.3449563, -0.4070137],
-    [-0.83276856, -0.28547677, -0.47434115],
-    [-0.8666205, -0.28682917, -0.4082866],
-    [-0.8036992, -0.3445741, -0.48511457],
-    [-0.8105078, -0.3084306, -0.4979435],
-    [-0.8311948, -0.31958112, -0.4549538],
-    [-0.86938053, -0.34950918, -0.34931475],
-    [-0.8743127, -0.37338692, -0.3100962],
-    [-0.87433016, -0.3104197, -0.37307698],
-    [-0.87326556, -0.34467193, -0.34439573],
-    [-0.8600201, -0.36085558, -0.36076134],
-    [-0.8765209, -0.44064656, -0.19375718],
-    [-0.880411, -0.44760588, -0.15660591],
-    [-0.88679457, -0.40824753, -0.21663171],
-    [-0.8998644, -0.40704724, -0.15670547],
-    [-0.8863728, -0.4261152, -0.18102211],
-    [-0.88492364, -0.45618913, -0.09381681],
-    [-0.8872523, -0.4602232, -0.031273916],
-    [-0.90861446, -0.40700433, -0.09363458],
-    [-0.91294277, -0.40690485, -0.03104798],
-    [-0.8992397, -0.43296865, -0.062499367],
-    [-0.93901, -0.31703112, -0.13323525],
-    [-0.9422029, -0.26451087, -0.20563968],
-    [-0.9460654, -0.284349, -0.15526032],
-    [-0.91308534, -0.34384707, -0.21919033],
-    [-0.9273693, -0.34039623, -0.15529534],
-    [-0.9325484, -0.2835968, -0.22344194],
-    [-0.9357656, -0.30598453, -0.17525989],
-    [-0.93463314, -0.30597222, -0.18122296],
-    [-0.9416977, -0.32312566, -0.0937832],
-    [-0.9438091, -0.32900843, -0.031269234],
-    [-0.95480126, -0.28204092, -0.09384787],
-    [-0.95893526, -0.28189346, -0.03129361],
-    [-0.9304074, -0.3543135, -0.09383009],
-    [-0.9324014, -0.360069, -0.031270664],
-    [-0.9446425, -0.32207158, -0.06261321],
-    [-0.8584523, -0.46501538, -0.21637973],
-    [-0.86374426, -0.47899368, -0.15655987],
-    [-0.8714971, -0.43018106, -0.23545098],
-    [-0.8652482, -0.45842555, -0.20295721],
-    [-0.8680021, -0.48757622, -0.094031125],
-    [-0.8702115, -0.49167037, -0.031501405],
-    [-0.86953413, -0.48986408, -0.06279714],
-    [-0.9042902, -0.40786266, -0.1261247],
-    [-0.8765209, -0.19375718, -0.44064656],
-    [-0.88679457, -0.21663171, -0.40824753],
-    [-0.88041097, -0.15660593, -0.44760585],
-    [-0.8998644, -0.15670547, -0.40704724],
-    [-0.8863728, -0.18102211, -0.4261152],
-    [-0.9422029, -0.20563965, -0.26451087],
-    [-0.9391045, -0.13325031, -0.31674463],
-    [-0.9461164, -0.15526843, -0.28417525],
-    [-0.91308534, -0.21919031, -0.34384707],
-    [-0.9325484, -0.22344194, -0.2835968],
-    [-0.9274302, -0.15530512, -0.3402258],
-    [-0.9358569, -0.17527439, -0.30569702],
-    [-0.9346749, -0.18122962, -0.30584073],
-    [-0.88492364, -0.09381682, -0.45618913],
-    [-0.90861446, -0.09363458, -0.40700433],
-    [-0.8872522, -0.031273916, -0.46022323],
-    [-0.91294277, -0.03104798, -0.40690485],
-    [-0.89923966, -0.062499378, -0.43296868],
-    [-0.9416977, -0.0937832, -0.32312563],
-    [-0.95480126, -0.09384787, -0.28204092],
-    [-0.9438091, -0.031269234, -0.32900846],
-    [-0.95893526, -0.03129361, -0.28189346],
-    [-0.9304073, -0.09383008, -0.35431352],
-    [-0.9324014, -0.031270664, -0.360069],
-    [-0.9446425, -0.06261321, -0.32207158],
-    [-0.8584523, -0.21637973, -0.46501538],
-    [-0.8714971, -0.23545098, -0.43018106],
-    [-0.86374426, -0.15655987, -0.47899368],
-    [-0.8652482, -0.20295721, -0.45842555],
-    [-0.8680021, -0.094031125, -0.48757622],
-    [-0.8702115, -0.0315014, -0.49167028],
-    [-0.86953425, -0.06279714, -0.48986408],
-    [-0.90429944, -0.12612613, -0.4078416],
-    [-0.9486019, -0.22377947, -0.22377947],
-    [-0.9629035, -0.21970935, -0.15666673],
-    [-0.96290356, -0.15666674, -0.21970937],
-    [-0.9751453, -0.15667123, -0.15667123],
-    [-0.9370545, -0.24690987, -0.24690987],
-    [-0.9587303, -0.2010427, -0.2010427],
-    [-0.9710682, -0.21948425, -0.09409146],
-    [-0.9751979, -0.21907744, -0.03153171],
-    [-0.983177, -0.15668844, -0.09387083],
-    [-0.98715264, -0.15668546, -0.031294666],
-    [-0.9801288, -0.18817203, -0.062759906],
-    [-0.9710682, -0.09409146, -0.21948425],
-    [-0.983177, -0.09387083, -0.15668844],
-    [-0.9751979, -0.03153171, -0.21907742],
-    [-0.98715264, -0.031294666, -0.15668546],
-    [-0.9801288, -0.062759906, -0.18817203],
-    [-0.9911904, -0.0936523, -0.0936523],
-    [-0.9950792, -0.09409098, -0.031052334],
-    [-0.9950792, -0.031052334, -0.09409098],
-    [-0.9990212, -0.03127881, -0.03127881],
-    [-0.99607605, -0.0625804, -0.06258039],
-    [-0.9831613, -0.12921643, -0.12921643],
-    [-0.7412505, -0.47463012, -0.47463012],
-    [-0.7492584, -0.4985524, -0.43595585],
-    [-0.7492584, -0.43595585, -0.4985524],
-    [-0.7470914, -0.47002885, -0.47002885],
-    [-0.7470915, -0.4700289, -0.4700289],
-    [-0.90140903, -0.30616686, -0.30614296],
-    [-0.3947095, -0.76080793, -0.5151464],
-    [-0.3947095, -0.76080793, -0.5151464],
-    [-0.32420808, -0.755528, -0.5692686],
-    [-0.34500876, -0.77153325, -0.5345142],
-    [-0.26691833, -0.81792694, -0.50965697],
-    [-0.28145143, -0.7642177, -0.58030725],
-    [-0.28548548, -0.7976628, -0.5312552],
-    [-0.3009246, -0.78217995, -0.5455628],
-    [-0.34826043, -0.7724773, -0.53103065],
-    [-0.39460036, -0.5151102, -0.760889],
-    [-0.39460036, -0.5151102, -0.760889],
-    [-0.3239224, -0.5693265, -0.7556068],
-    [-0.34472787, -0.5346671, -0.7715529],
-    [-0.28123412, -0.5803449, -0.764269],
-    [-0.2666699, -0.5094646, -0.8181278],
-    [-0.28516498, -0.5311748, -0.797831],
-    [-0.30065462, -0.5455595, -0.7822861],
-    [-0.34807205, -0.5310131, -0.7725743],
-    [-0.44770122, -0.632442, -0.632124],
-    [-0.40705562, -0.6458738, -0.6458738],
-    [-0.42748648, -0.6393195, -0.63916045],
-    [-0.44491085, -0.6923032, -0.56812906],
-    [-0.4673896, -0.70701736, -0.5307293],
-    [-0.4674735, -0.6558311, -0.59275126],
-    [-0.48661032, -0.67813694, -0.5507637],
-    [-0.40663823, -0.7040583, -0.5821918],
-    [-0.4119511, -0.7380218, -0.5344343],
-    [-0.40687314, -0.6770918, -0.6131893],
-    [-0.44221538, -0.6940294, -0.56812745],
-    [-0.44483423, -0.5681224, -0.69235796],
-    [-0.46744084, -0.5929143, -0.6557072],
-    [-0.46735743, -0.53071374, -0.7070502],
-    [-0.48655805, -0.55073935, -0.6781944],
-    [-0.40651596, -0.58234537, -0.70400196],
-    [-0.40674835, -0.6133388, -0.67703134],
-    [-0.4118869, -0.5344142, -0.7380723],
-    [-0.44214493, -0.5681868, -0.69402575],
-    [-0.48917478, -0.61688745, -0.6165694],
-    [-0.48917484, -0.6168875, -0.61656946],
-    [-0.33159938, -0.6975285, -0.63521326],
-    [-0.33160663, -0.6355435, -0.6972241],
-    [-0.35277322, -0.6618048, -0.66148716],
-    [-0.28148478, -0.70824444, -0.6474225],
-    [-0.2814848, -0.6474225, -0.70824456],
-    [-0.28141975, -0.67852885, -0.6785289],
-    [-0.31040585, -0.672258, -0.6720991],
-    [-0.34883973, -0.7221721, -0.5973092],
-    [-0.36566997, -0.7459645, -0.55661696],
-    [-0.373092, -0.6867571, -0.6238326],
-    [-0.28137672, -0.739426, -0.6116178],
-    [-0.3426699, -0.7244791, -0.59808636],
-    [-0.34884685, -0.5974804, -0.7220269],
-    [-0.37309933, -0.62416285, -0.6864529],
-    [-0.36548474, -0.55680907, -0.74591184],
-    [-0.28137675, -0.6116178, -0.739426],
-    [-0.34262776, -0.5982611, -0.7243547],
-    [-0.40136042, -0.647725, -0.64758176],
-    [-0.19616963, -0.75287974, -0.62824327],
-    [-0.1560054, -0.7576047, -0.63379604],
-    [-0.1761242, -0.7553996, -0.63115114],
-    [-0.21932015, -0.82522035, -0.52049017],
-    [-0.21786655, -0.77126604, -0.59806585],
-    [-0.21910737, -0.8048269, -0.5515847],
-    [-0.13335358, -0.81409216, -0.56521755],
-    [-0.1552864, -0.8295634, -0.5363867],
-    [-0.1549694, -0.79262763, -0.5896828],
-    [-0.17501052, -0.8101897, -0.55943173],
-    [-0.1823241, -0.8076838, -0.56071794],
-    [-0.09351146, -0.76286167, -0.63976383],
-    [-0.031009154, -0.7654031, -0.64280367],
-    [-0.062290855, -0.7645073, -0.6415984],
-    [-0.0940329, -0.8168452, -0.56914115],
-    [-0.09408019, -0.8412022, -0.53247327],
-    [-0.093822606, -0.79420394, -0.6003643],
-    [-0.031489953, -0.81944174, -0.57229674],
-    [-0.03151556, -0.8458994, -0.5324105],
-    [-0.031259775, -0.7966522, -0.603629],
-    [-0.06276856, -0.81993437, -0.56900597],
-    [-0.12120855, -0.78903997, -0.6022661],
-    [-0.19616964, -0.62824327, -0.75287974],
-    [-0.1560054, -0.63379604, -0.75760466],
-    [-0.17612422, -0.63115114, -0.7553996],
-    [-0.21786655, -0.59806585, -0.77126604],
-    [-0.21909471, -0.5205178, -0.8252628],
-    [-0.21888196, -0.5516121, -0.8048694],
-    [-0.13328779, -0.5652887, -0.8140535],
-    [-0.15492976, -0.58972514, -0.79260385],
-    [-0.15524724, -0.53643084, -0.82954216],
-    [-0.17494518, -0.55950457, -0.81015354],
-    [-0.1822293, -0.5607578, -0.80767757],
-    [-0.09351146, -0.63976383, -0.76286167],
-    [-0.031009154, -0.6428037, -0.76540315],
-    [-0.062290855, -0.6415984, -0.7645073],
-    [-0.094032906, -0.56914115, -0.8168453],
-    [-0.09382261, -0.6003644, -0.794204],
-    [-0.09408019, -0.53247327, -0.8412022],
-    [-0.031489953, -0.57229674, -0.81944174],
-    [-0.03125978, -0.60362905, -0.79665226],
-    [-0.03151556, -0.5324105, -0.8458994],
-    [-0.06276856, -0.5690059, -0.8199343],
-    [-0.12118454, -0.6022749, -0.7890369],
-    [-0.20234467, -0.6924799, -0.6924799],
-    [-0.22027145, -0.72028565, -0.6577759],
-    [-0.22027145, -0.6577759, -0.72028553],
-    [-0.24374485, -0.68578, -0.68578],
-    [-0.1568703, -0.6983523, -0.6983523],
-    [-0.15645471, -0.72985584, -0.66545653],
-    [-0.15645471, -0.6654565, -0.72985584],
-    [-0.19400178, -0.6936726, -0.6936726],
-    [-0.23801024, -0.7471068, -0.62063086],
-    [-0.23801024, -0.7471068, -0.62063086],
-    [-0.23801024, -0.62063086, -0.7471068],
-    [-0.23801024, -0.62063086, -0.7471068],
-    [-0.09446677, -0.7039447, -0.7039446],
-    [-0.09397877, -0.735096, -0.6714177],
-    [-0.09397877, -0.6714177, -0.735096],
-    [-0.031487603, -0.7067562, -0.7067562],
-    [-0.031237818, -0.73788226, -0.67420614],
-    [-0.031237818, -0.67420614, -0.73788226],
-    [-0.06280486, -0.7057109, -0.7057109],
-    [-0.1840661, -0.695025, -0.6950251],
-    [-0.25789994, -0.6831899, -0.683183],
-    [-0.38822573, -0.88146305, -0.26889327],
-    [-0.3882258, -0.88146317, -0.2688933],
-    [-0.44062606, -0.81380486, -0.378907],
-    [-0.45728365, -0.7625565, -0.45760158],
-    [-0.47014332, -0.7826584, -0.40793523],
-    [-0.378907, -0.81380486, -0.44062606],
-    [-0.40288505, -0.8218073, -0.402885],
-    [-0.40777215, -0.7826506, -0.4702976],
-    [-0.42039618, -0.8039072, -0.420714],
-    [-0.42589796, -0.798189, -0.42603427],
-    [-0.45369685, -0.8217146, -0.344883],
-    [-0.4743411, -0.83276856, -0.28547674],
-    [-0.4851146, -0.8036992, -0.34457406],
-    [-0.4979435, -0.8105078, -0.3084306],
-    [-0.4070137, -0.8457808, -0.3449563],
-    [-0.40844816, -0.86655223, -0.28680548],
-    [-0.45498008, -0.8311822, -0.3195767],
-    [-0.26893088, -0.8815713, -0.38795397],
-    [-0.26893088, -0.8815713, -0.38795397],
-    [-0.33201787, -0.8830307, -0.33169386],
-    [-0.34935254, -0.8921737, -0.28631973],
-    [-0.28651404, -0.8921842, -0.34916633],
-    [-0.28164735, -0.91725105, -0.28164735],
-    [-0.31267095, -0.89698607, -0.3124946],
-    [-0.344883, -0.8217146, -0.45369685],
-    [-0.3449563, -0.8457808, -0.40701374],
-    [-0.34457406, -0.8036992, -0.4851146],
-    [-0.28547677, -0.83276856, -0.47434115],
-    [-0.28682917, -0.8666205, -0.4082866],
-    [-0.3084306, -0.8105078, -0.4979435],
-    [-0.31958112, -0.8311948, -0.4549538],
-    [-0.34950918, -0.86938053, -0.34931475],
-    [-0.37338692, -0.8743126, -0.3100962],
-    [-0.3104197, -0.87433016, -0.37307698],
-    [-0.34467193, -0.87326556, -0.34439573],
-    [-0.36085558, -0.86002004, -0.3607614],
-    [-0.44064656, -0.8765209, -0.19375719],
-    [-0.44760585, -0.880411, -0.15660593],
-    [-0.40824753, -0.88679457, -0.21663171],
-    [-0.4070473, -0.89986444, -0.15670548],
-    [-0.4261152, -0.8863728, -0.18102211],
-    [-0.45618913, -0.88492364, -0.09381681],
-    [-0.46022323, -0.8872522, -0.031273916],
-    [-0.4070043, -0.90861434, -0.09363457],
-    [-0.40690488, -0.91294277, -0.03104798],
-    [-0.43296865, -0.89923966, -0.06249937],
-    [-0.46501538, -0.8584523, -0.21637973],
-    [-0.47899362, -0.86374426, -0.15655987],
-    [-0.43018106, -0.87149704, -0.23545098],
-    [-0.45842555, -0.8652482, -0.20295721],
-    [-0.4875762, -0.868002, -0.09403111],
-    [-0.49167028, -0.8702115, -0.0315014],
-    [-0.48986408, -0.86953425, -0.06279713],
-    [-0.31703112, -0.93901, -0.13323525],
-    [-0.34384704, -0.91308534, -0.21919031],
-    [-0.3403962, -0.9273693, -0.15529534],
-    [-0.2645109, -0.9422029, -0.20563965],
-    [-0.28434896, -0.9460654, -0.15526031],
-    [-0.2835968, -0.9325484, -0.22344194],
-    [-0.30598453, -0.9357656, -0.17525989],
-    [-0.30597225, -0.9346332, -0.18122295],
-    [-0.32312563, -0.9416977, -0.0937832],
-    [-0.3290084, -0.9438091, -0.031269234],
-    [-0.35431355, -0.93040735, -0.09383008],
-    [-0.36006898, -0.9324014, -0.031270664],
-    [-0.28204095, -0.9548013, -0.093847886],
-    [-0.28189343, -0.95893526, -0.03129361],
-    [-0.32207155, -0.9446425, -0.06261321],
-    [-0.40786266, -0.9042902, -0.1261247],
-    [-0.47463012, -0.7412505, -0.47463012],
-    [-0.4985524, -0.7492584, -0.43595585],
-    [-0.43595585, -0.7492584, -0.4985524],
-    [-0.47002885, -0.7470914, -0.47002885],
-    [-0.4700289, -0.7470915, -0.4700289],
-    [-0.19375719, -0.8765209, -0.44064656],
-    [-0.21663171, -0.88679457, -0.40824753],
-    [-0.15660593, -0.880411, -0.44760585],
-    [-0.15670548, -0.8998644, -0.40704724],
-    [-0.18102212, -0.8863728, -0.4261152],
-    [-0.20563965, -0.9422029, -0.2645109],
-    [-0.21919033, -0.91308534, -0.34384707],
-    [-0.22344194, -0.9325484, -0.2835968],
-    [-0.13325031, -0.9391045, -0.31674463],
-    [-0.15526845, -0.9461164, -0.28417525],
-    [-0.1553051, -0.9274302, -0.3402258],
-    [-0.17527439, -0.9358569, -0.30569702],
-    [-0.18122962, -0.9346749, -0.3058407],
-    [-0.21637973, -0.8584523, -0.46501544],
-    [-0.23545098, -0.87149704, -0.43018106],
-    [-0.15655987, -0.86374426, -0.47899362],
-    [-0.20295721, -0.8652482, -0.45842555],
-    [-0.09381681, -0.88492364, -0.45618913],
-    [-0.09363458, -0.90861446, -0.40700433],
-    [-0.031273916, -0.8872523, -0.4602232],
-    [-0.031047983, -0.91294277, -0.4069049],
-    [-0.062499363, -0.8992397, -0.43296868],
-    [-0.0937832, -0.9416977, -0.32312563],
-    [-0.093847886, -0.9548013, -0.28204095],
-    [-0.09383008, -0.93040735, -0.35431355],
-    [-0.031269234, -0.9438091, -0.32900843],
-    [-0.03129361, -0.9589352, -0.28189343],
-    [-0.031270664, -0.9324014, -0.36006898],
-    [-0.06261321, -0.9446425, -0.32207158],
-    [-0.09403111, -0.868002, -0.4875762],
-    [-0.0315014, -0.8702115, -0.49167028],
-    [-0.06279713, -0.86953425, -0.48986408],
-    [-0.12612613, -0.90429944, -0.4078416],
-    [-0.22377947, -0.9486019, -0.22377947],
-    [-0.21970937, -0.96290356, -0.15666674],
-    [-0.24690984, -0.93705446, -0.24690984],
-    [-0.15666673, -0.9629035, -0.21970935],
-    [-0.15667123, -0.9751453, -0.15667123],
-    [-0.20104268, -0.9587303, -0.20104268],
-    [-0.21948425, -0.97106814, -0.09409147],
-    [-0.21907741, -0.97519785, -0.031531706],
-    [-0.15668844, -0.983177, -0.09387083],
-    [-0.15668547, -0.9871526, -0.031294666],
-    [-0.18817203, -0.98012877, -0.062759906],
-    [-0.09409147, -0.9710682, -0.21948425],
-    [-0.09387083, -0.983177, -0.15668842],
-    [-0.03153171, -0.97519785, -0.21907744],
-    [-0.031294666, -0.9871526, -0.15668546],
-    [-0.06275991, -0.9801288, -0.18817203],
-    [-0.0936523, -0.9911904, -0.0936523],
-    [-0.09409099, -0.9950793, -0.031052338],
-    [-0.031052334, -0.9950792, -0.09409098],
-    [-0.03127881, -0.9990212, -0.03127881],
-    [-0.0625804, -0.99607605, -0.06258039],
-    [-0.12921645, -0.98316133, -0.12921643],
-    [-0.30616686, -0.90140903, -0.30614296],
-    [-0.45753765, -0.45753765, -0.7624424],
-    [-0.44056633, -0.3785344, -0.81401044],
-    [-0.47012252, -0.40775257, -0.78276604],
-    [-0.3785345, -0.4405664, -0.8140105],
-    [-0.4077525, -0.4701225, -0.78276604],
-    [-0.40275577, -0.40275583, -0.82193404],
-    [-0.4204447, -0.42044467, -0.80402267],
-    [-0.42585835, -0.42585835, -0.798304],
-    [-0.38822904, -0.26888818, -0.8814633],
-    [-0.38822898, -0.26888815, -0.88146317],
-    [-0.45373356, -0.34467536, -0.8217815],
-    [-0.48515105, -0.3443664, -0.80376625],
-    [-0.47419557, -0.28550398, -0.8328422],
-    [-0.49770465, -0.30847806, -0.81063646],
-    [-0.4070137, -0.3449563, -0.8457808],
-    [-0.40845034, -0.28680262, -0.86655223],
-    [-0.45492628, -0.31951776, -0.8312342],
-    [-0.26888812, -0.38822898, -0.8814632],
-    [-0.26888812, -0.38822898, -0.8814632],
-    [-0.34467536, -0.45373356, -0.8217815],
-    [-0.34436643, -0.48515105, -0.80376625],
-    [-0.3449563, -0.40701374, -0.8457808],
-    [-0.285504, -0.47419554, -0.8328422],
-    [-0.30847806, -0.49770465, -0.81063646],
-    [-0.28680262, -0.40845034, -0.86655223],
-    [-0.31951776, -0.45492628, -0.8312342],
-    [-0.33197904, -0.33197904, -0.88293827],
-    [-0.3493115, -0.28666767, -0.8920779],
-    [-0.28666767, -0.3493115, -0.8920779],
-    [-0.28164735, -0.28164735, -0.91725105],
-    [-0.31268921, -0.31268921, -0.896912],
-    [-0.34948465, -0.34948468, -0.8693221],
-    [-0.37331337, -0.31067127, -0.8741399],
-    [-0.3106713, -0.37331337, -0.8741399],
-    [-0.34472048, -0.34472048, -0.87311834],
-    [-0.36083505, -0.36083505, -0.8599978],
-    [-0.47463012, -0.47463012, -0.7412505],
-    [-0.4983138, -0.43602434, -0.7493772],
-    [-0.43602434, -0.4983138, -0.7493772],
-    [-0.4699694, -0.4699694, -0.74716645],
-    [-0.4699694, -0.4699694, -0.74716645],
-    [-0.44064656, -0.19375718, -0.8765209],
-    [-0.44760588, -0.15660593, -0.880411],
-    [-0.4082647, -0.21645129, -0.88683075],
-    [-0.4070473, -0.15670548, -0.89986444],
-    [-0.42611876, -0.18097667, -0.8863803],
-    [-0.46501538, -0.21637972, -0.8584523],
-    [-0.47899368, -0.15655988, -0.86374426],
-    [-0.430212, -0.2351516, -0.8715626],
-    [-0.45843437, -0.20285669, -0.8652671],
-    [-0.45618913, -0.09381682, -0.88492364],
-    [-0.46022323, -0.031273916, -0.8872522],
-    [-0.40700436, -0.09363458, -0.90861446],
-    [-0.40690488, -0.03104798, -0.91294277],
-    [-0.43296868, -0.062499367, -0.8992397],
-    [-0.48757622, -0.094031125, -0.8680021],
-    [-0.49167028, -0.0315014, -0.8702115],
-    [-0.48986408, -0.06279714, -0.86953425],
-    [-0.34384704, -0.21919031, -0.91308534],
-    [-0.31674463, -0.13325031, -0.9391045],
-    [-0.3402258, -0.15530512, -0.9274302],
-    [-0.26451087, -0.20563965, -0.9422029],
-    [-0.2835968, -0.22344194, -0.9325484],
-    [-0.28417525, -0.15526843, -0.9461164],
-    [-0.30569702, -0.17527439, -0.9358569],
-    [-0.3058407, -0.18122958, -0.93467486],
-    [-0.32312566, -0.0937832, -0.9416977],
-    [-0.3543135, -0.09383009, -0.93040735],
-    [-0.32900843, -0.031269234, -0.9438091],
-    [-0.360069, -0.031270664, -0.9324014],
-    [-0.28204095, -0.093847886, -0.9548013],
-    [-0.28189343, -0.03129361, -0.95893526],
-    [-0.32207158, -0.06261321, -0.9446425],
-    [-0.40784296, -0.12610145, -0.90430224],
-    [-0.19375718, -0.44064656, -0.8765209],
-    [-0.21645129, -0.4082647, -0.88683075],
-    [-0.15660593, -0.44760588, -0.880411],
-    [-0.15670548, -0.40704724, -0.8998644],
-    [-0.18097667, -0.42611876, -0.8863803],
-    [-0.21637973, -0.46501538, -0.8584523],
-    [-0.23515163, -0.430212, -0.8715626],
-    [-0.15655987, -0.47899368, -0.86374426],
-    [-0.20285669, -0.45843437, -0.8652671],
-    [-0.2191903, -0.34384704, -0.9130852],
-    [-0.20563968, -0.26451087, -0.9422029],
-    [-0.22344194, -0.2835968, -0.9325484],
-    [-0.13325031, -0.31674463, -0.9391045],
-    [-0.1553051, -0.3402258, -0.9274302],
-    [-0.15526842, -0.28417522, -0.9461163],
-    [-0.17527437, -0.30569702, -0.9358569],
-    [-0.1812296, -0.3058407, -0.9346749],
-    [-0.09381683, -0.4561892, -0.8849237],
-    [-0.09363458, -0.40700433, -0.90861446],
-    [-0.031273916, -0.4602232, -0.8872522],
-    [-0.031047983, -0.4069049, -0.91294277],
-    [-0.06249937, -0.43296868, -0.8992397],
-    [-0.094031125, -0.48757622, -0.8680021],
-    [-0.031501405, -0.49167037, -0.8702115],
-    [-0.062797144, -0.48986414, -0.86953425],
-    [-0.0937832, -0.32312563, -0.9416977],
-    [-0.09383009, -0.3543135, -0.9304074],
-    [-0.093847886, -0.28204095, -0.9548013],
-    [-0.031269234, -0.32900846, -0.9438091],
-    [-0.031270664, -0.360069, -0.9324014],
-    [-0.03129361, -0.28189343, -0.9589352],
-    [-0.06261322, -0.32207158, -0.94464254],
-    [-0.12610146, -0.40784302, -0.9043023],
-    [-0.22377947, -0.22377947, -0.9486019],
-    [-0.24690987, -0.24690987, -0.9370545],
-    [-0.21970937, -0.15666674, -0.96290356],
-    [-0.15666673, -0.21970935, -0.9629035],
-    [-0.15667123, -0.15667123, -0.9751453],
-    [-0.2010427, -0.2010427, -0.9587303],
-    [-0.21948425, -0.09409147, -0.97106814],
-    [-0.21907741, -0.031531706, -0.97519785],
-    [-0.15668844, -0.09387083, -0.983177],
-    [-0.15668549, -0.03129467, -0.9871527],
-    [-0.18817203, -0.062759906, -0.98012877],
-    [-0.09409147, -0.21948425, -0.9710682],
-    [-0.09387083, -0.15668842, -0.983177],
-    [-0.03153171, -0.21907744, -0.97519785],
-    [-0.03129467, -0.15668547, -0.9871527],
-    [-0.062759906, -0.188172, -0.98012877],
-    [-0.0936523, -0.0936523, -0.9911904],
-    [-0.09409099, -0.031052338, -0.9950793],
-    [-0.031052334, -0.09409098, -0.9950792],
-    [-0.03127881, -0.03127881, -0.9990212],
-    [-0.0625804, -0.06258039, -0.99607605],
-    [-0.12921645, -0.12921643, -0.98316133],
-    [-0.30614007, -0.30614007, -0.90141916],
-    [-0.57734746, -0.5773474, -0.57735586],
-    [-0.76540303, -0.6428036, 0.03100915],
-    [-0.76286167, -0.63976383, 0.09351146],
-    [-0.7645073, -0.6415984, 0.062290855],
-    [-0.7575485, -0.6337491, 0.15646799],
-    [-0.7524859, -0.6285504, 0.19669631],
-    [-0.75517493, -0.6312817, 0.17661905],
-    [-0.81944174, -0.57229674, 0.031489953],
-    [-0.8168453, -0.56914115, 0.094032906],
-    [-0.8458994, -0.5324105, 0.03151556],
-    [-0.8412022, -0.53247327, 0.09408019],
-    [-0.79665226, -0.60362905, 0.03125978],
-    [-0.79420394, -0.6003643, 0.093822606],
-    [-0.8199343, -0.56900597, 0.062768556],
-    [-0.8137997, -0.56565136, 0.13329963],
-    [-0.8293967, -0.53665316, 0.15525566],
-    [-0.82522035, -0.52049017, 0.21932015],
-    [-0.79242224, -0.58991903, 0.15512083],
-    [-0.77106327, -0.5982785, 0.21800068],
-    [-0.80990016, -0.5598676, 0.17495714],
-    [-0.8048269, -0.5515847, 0.21910737],
-    [-0.80752057, -0.56094617, 0.18234569],
-    [-0.78894806, -0.6023601, 0.12133883],
-    [-0.81792694, -0.50965697, 0.26691833],
-    [-0.7642176, -0.58030725, 0.28145146],
-    [-0.75566536, -0.5691334, 0.32412472],
-    [-0.7976628, -0.5312552, 0.28548548],
-    [-0.77153325, -0.5345115, 0.3450128],
-    [-0.78220624, -0.5455343, 0.30090824],
-    [-0.76067156, -0.515278, 0.39480042],
-    [-0.76067156, -0.515278, 0.39480042],
-    [-0.7724239, -0.53108335, 0.34829846],
-    [-0.64280367, -0.7654031, 0.031009154],
-    [-0.63976383, -0.76286167, 0.09351146],
-    [-0.6415984, -0.7645073, 0.062290855],
-    [-0.6337491, -0.7575485, 0.15646799],
-    [-0.6285504, -0.7524859, 0.19669628],
-    [-0.6312817, -0.75517493, 0.17661902],
-    [-0.57229674, -0.81944174, 0.031489953],
-    [-0.56914115, -0.8168452, 0.0940329],
-    [-0.603629, -0.79665226, 0.03125978],
-    [-0.6003643, -0.79420394, 0.093822606],
-    [-0.5324105, -0.8458994, 0.03151556],
-    [-0.53247327, -0.84120214, 0.094080195],
-    [-0.56900597, -0.81993437, 0.06276856],
-    [-0.56565136, -0.8137997, 0.13329963],
-    [-0.5899191, -0.7924223, 0.15512083],
-    [-0.5982785, -0.77106327, 0.21800068],
-    [-0.5366531, -0.8293967, 0.15525566],
-    [-0.52049017, -0.82522035, 0.21932015],
-    [-0.5598676, -0.80990016, 0.17495714],
-    [-0.5515847, -0.8048269, 0.21910739],
-    [-0.56094617, -0.8075206, 0.18234569],
-    [-0.6023601, -0.78894806, 0.12133882],
-    [-0.58030725, -0.7642177, 0.28145143],
-    [-0.5691334, -0.75566536, 0.32412475],
-    [-0.50965697, -0.81792694, 0.2669183],
-    [-0.5312552, -0.7976628, 0.28548548],
-    [-0.53451157, -0.77153325, 0.3450128],
-    [-0.5455343, -0.78220624, 0.30090824],
-    [-0.51527786, -0.76067156, 0.39480042],
-    [-0.5152779, -0.7606717, 0.39480048],
-    [-0.5310833, -0.77242386, 0.3482985],
-    [-0.7067562, -0.7067562, 0.031487603],
-    [-0.7039446, -0.7039447, 0.09446677],
-    [-0.73788226, -0.67420614, 0.031237818],
-    [-0.735096, -0.67141783, 0.093978785],
-    [-0.67420614, -0.73788226, 0.031237818],
-    [-0.6714177, -0.735096, 0.09397876],
-    [-0.7057108, -0.7057108, 0.062804855],
-    [-0.6983523, -0.6983523, 0.1568703],
-    [-0.6924799, -0.6924799, 0.20234467],
-    [-0.7298277, -0.665433, 0.15668592],
-    [-0.72007656, -0.65791845, 0.22052875],
-    [-0.665433, -0.72982764, 0.15668593],
-    [-0.65791845, -0.72007656, 0.22052875],
-    [-0.68578, -0.68578, 0.24374485],
-    [-0.69365317, -0.69365317, 0.19414084],
-    [-0.74675465, -0.6209723, 0.23822431],
-    [-0.7467547, -0.62097234, 0.23822434],
-    [-0.6209723, -0.7467547, 0.23822434],
-    [-0.62097234, -0.74675477, 0.23822437],
-    [-0.6950065, -0.69500655, 0.18420568],
-    [-0.70819503, -0.64738035, 0.28170618],
-    [-0.6973893, -0.63540477, 0.33152494],
-    [-0.64738035, -0.70819503, 0.28170618],
-    [-0.63540477, -0.6973894, 0.33152497],
-    [-0.6785289, -0.67852885, 0.28141975],
-    [-0.66166675, -0.66166675, 0.35269552],
-    [-0.6721708, -0.6721708, 0.31043956],
-    [-0.6458738, -0.6458738, 0.40705562],
-    [-0.6323825, -0.6323825, 0.44741994],
-    [-0.6392875, -0.6392875, 0.42734426],
-    [-0.7393764, -0.6115759, 0.28159812],
-    [-0.72213846, -0.59731305, 0.34890276],
-    [-0.74587893, -0.5565471, 0.36595076],
-    [-0.6866207, -0.6240265, 0.37301892],
-    [-0.7244028, -0.59810805, 0.34279317],
-    [-0.70386404, -0.58223426, 0.4069135],
-    [-0.69213676, -0.56825644, 0.44500726],
-    [-0.73787886, -0.5344669, 0.4121649],
-    [-0.70701736, -0.5307293, 0.4673896],
-    [-0.6769644, -0.61328274, 0.406944],
-    [-0.6557199, -0.5929463, 0.46738222],
-    [-0.67813694, -0.5507637, 0.48661032],
-    [-0.69392186, -0.56819654, 0.44229534],
-    [-0.6115759, -0.7393764, 0.28159812],
-    [-0.5973131, -0.72213846, 0.34890276],
-    [-0.6240265, -0.6866207, 0.37301892],
-    [-0.5565471, -0.74587893, 0.36595076],
-    [-0.59810805, -0.7244028, 0.34279317],
-    [-0.58223426, -0.70386404, 0.40691346],
-    [-0.56825644, -0.69213676, 0.44500726],
-    [-0.61328274, -0.6769644, 0.40694398],
-    [-0.5929463, -0.6557199, 0.46738228],
-    [-0.5344669, -0.73787886, 0.41216496],
-    [-0.5307293, -0.70701736, 0.4673896],
-    [-0.5507637, -0.67813694, 0.48661032],
-    [-0.56819654, -0.69392186, 0.44229534],
-    [-0.61683726, -0.61683726, 0.48890042],
-    [-0.61683726, -0.61683726, 0.48890042],
-    [-0.64764833, -0.64764833, 0.40137663],
-    [-0.6831648, -0.68316483, 0.25801516],
-    [-0.6943562, -0.5088563, 0.5088563],
-    [-0.63657665, -0.57526654, 0.51365227],
-    [-0.6576295, -0.5326919, 0.5326941],
-    [-0.63638526, -0.5137616, 0.5753806],
-    [-0.6568716, -0.5331593, 0.53316104],
-    [-0.57526654, -0.63657665, 0.51365227],
-    [-0.5088563, -0.6943562, 0.5088563],
-    [-0.53269184, -0.6576295, 0.5326941],
-    [-0.5137616, -0.63638526, 0.5753806],
-    [-0.5331593, -0.65687174, 0.5331611],
-    [-0.5974675, -0.5974675, 0.53485054],
-    [-0.57735026, -0.57735026, 0.57735026],
-    [-0.6185422, -0.55560803, 0.55561256],
-    [-0.59742147, -0.5349535, 0.59742147],
-    [-0.55560803, -0.61854213, 0.55561256],
-    [-0.5349534, -0.59742147, 0.59742147],
-    [-0.5557188, -0.5557188, 0.6183473],
-    [-0.5773715, -0.5773715, 0.5773078],
-    [-0.5753806, -0.5137616, 0.63638526],
-    [-0.5137616, -0.5753806, 0.63638526],
-    [-0.5327861, -0.5327861, 0.65747863],
-    [-0.5089309, -0.5089309, 0.69424677],
-    [-0.53322995, -0.53322995, 0.6567585],
-    [-0.5773707, -0.5773707, 0.5773095],
-    [-0.5773707, -0.5773707, 0.5773095],
-    [-0.8872522, -0.4602232, 0.031273916],
-    [-0.8849237, -0.4561892, 0.09381683],
-    [-0.91294277, -0.4069049, 0.031047983],
-    [-0.90861446, -0.40700433, 0.09363458],
-    [-0.89923966, -0.43296868, 0.062499374],
-    [-0.880411, -0.44760588, 0.15660593],
-    [-0.8765209, -0.44064656, 0.19375719],
-    [-0.8998644, -0.40704724, 0.15670548],
-    [-0.88679457, -0.40824753, 0.21663171],
-    [-0.8863728, -0.4261152, 0.18102211],
-    [-0.9438091, -0.32900846, 0.031269234],
-    [-0.9416977, -0.32312563, 0.0937832],
-    [-0.9589352, -0.28189343, 0.03129361],
-    [-0.9548013, -0.28204095, 0.093847886],
-    [-0.9324014, -0.360069, 0.031270664],
-    [-0.9304073, -0.3543135, 0.09383008],
-    [-0.9446425, -0.32207158, 0.06261322],
-    [-0.93901, -0.31703112, 0.13323525],
-    [-0.9460654, -0.284349, 0.15526032],
-    [-0.9422029, -0.2645109, 0.20563965],
-    [-0.9273693, -0.34039623, 0.15529534],
-    [-0.91308534, -0.34384707, 0.21919033],
-    [-0.9357656, -0.30598453, 0.17525989],
-    [-0.9325484, -0.2835968, 0.22344194],
-    [-0.93463314, -0.30597222, 0.18122296],
-    [-0.8702115, -0.49167037, 0.031501405],
-    [-0.86800206, -0.48757622, 0.09403112],
-    [-0.86953413, -0.48986408, 0.06279713],
-    [-0.86374426, -0.47899368, 0.15655987],
-    [-0.8584523, -0.46501538, 0.21637973],
-    [-0.8714971, -0.43018106, 0.23545098],
-    [-0.8652482, -0.45842555, 0.20295721],
-    [-0.9042902, -0.40786266, 0.1261247],
-    [-0.88146305, -0.38822573, 0.26889327],
-    [-0.88146317, -0.3882258, 0.2688933],
-    [-0.8921737, -0.34935254, 0.28631976],
-    [-0.8830307, -0.33201787, 0.33169386],
-    [-0.91725105, -0.28164735, 0.28164735],
-    [-0.8921842, -0.286514, 0.34916633],
-    [-0.89698607, -0.31267095, 0.3124946],
-    [-0.8815713, -0.26893088, 0.38795397],
-    [-0.8815713, -0.26893088, 0.38795397],
-    [-0.83276856, -0.47434115, 0.28547677],
-    [-0.8217146, -0.45369688, 0.344883],
-    [-0.86655223, -0.40844816, 0.28680548],
-    [-0.8457808, -0.40701374, 0.3449563],
-    [-0.8105078, -0.4979435, 0.3084306],
-    [-0.8036992, -0.48511457, 0.3445741],
-    [-0.8311822, -0.45498008, 0.31957665],
-    [-0.81380486, -0.44062606, 0.378907],
-    [-0.8218073, -0.402885, 0.40288505],
-    [-0.81380486, -0.378907, 0.44062606],
-    [-0.7826584, -0.47014335, 0.40793523],
-    [-0.7625565, -0.45728365, 0.45760158],
-    [-0.80390716, -0.42039615, 0.420714],
-    [-0.78265065, -0.40777215, 0.4702976],
-    [-0.79818904, -0.42589802, 0.42603433],
-    [-0.8743127, -0.37338692, 0.3100962],
-    [-0.86938053, -0.34950918, 0.34931475],
-    [-0.87433016, -0.3104197, 0.37307698],
-    [-0.87326556, -0.34467193, 0.34439573],
-    [-0.8457808, -0.3449563, 0.4070137],
-    [-0.8217146, -0.344883, 0.45369685],
-    [-0.8666205, -0.28682917, 0.4082866],
-    [-0.83276856, -0.28547674, 0.4743411],
-    [-0.8036992, -0.3445741, 0.48511457],
-    [-0.8105078, -0.3084306, 0.4979435],
-    [-0.8311948, -0.31958112, 0.4549538],
-    [-0.86002004, -0.36085558, 0.36076143],
-    [-0.97519785, -0.21907744, 0.03153171],
-    [-0.9710682, -0.21948425, 0.09409147],
-    [-0.9871527, -0.15668547, 0.03129467],
-    [-0.983177, -0.15668842, 0.09387083],
-    [-0.9801288, -0.18817203, 0.06275991],
-    [-0.9629035, -0.21970935, 0.15666673],
-    [-0.9486019, -0.22377947, 0.22377947],
-    [-0.9751453, -0.15667123, 0.15667123],
-    [-0.96290356, -0.15666674, 0.21970937],
-    [-0.9370545, -0.24690987, 0.24690987],
-    [-0.9587303, -0.20104268, 0.20104268],
-    [-0.9950792, -0.09409098, 0.031052334],
-    [-0.9911904, -0.0936523, 0.0936523],
-    [-0.9990212, -0.03127881, 0.03127881],
-    [-0.9950793, -0.031052338, 0.09409099],
-    [-0.99607605, -0.0625804, 0.0625804],
-    [-0.983177, -0.09387083, 0.15668844],
-    [-0.97106814, -0.09409147, 0.21948425],
-    [-0.9871526, -0.031294666, 0.15668547],
-    [-0.97519785, -0.031531706, 0.21907741],
-    [-0.98012877, -0.06275991, 0.18817203],
-    [-0.98316133, -0.12921645, 0.12921645],
-    [-0.9422029, -0.20563965, 0.2645109],
-    [-0.9461164, -0.15526843, 0.28417525],
-    [-0.9391045, -0.13325031, 0.31674463],
-    [-0.9325484, -0.22344194, 0.2835968],
-    [-0.91308534, -0.21919031, 0.34384704],
-    [-0.9358569, -0.17527439, 0.30569702],
-    [-0.9274302, -0.15530512, 0.3402258],
-    [-0.9346749, -0.1812296, 0.3058407],
-    [-0.88679457, -0.21663171, 0.40824753],
-    [-0.8765209, -0.19375719, 0.44064656],
-    [-0.89986444, -0.15670548, 0.4070473],
-    [-0.88041097, -0.15660593, 0.44760588],
-    [-0.8863728, -0.18102211, 0.4261152],
-    [-0.9548013, -0.093847886, 0.28204095],
-    [-0.9416977, -0.0937832, 0.32312563],
-    [-0.95893526, -0.03129361, 0.28189343],
-    [-0.9438091, -0.031269234, 0.32900843],
-    [-0.9304073, -0.09383008, 0.35431352],
-    [-0.9324014, -0.031270664, 0.360069],
-    [-0.94464254, -0.06261322, 0.32207158],
-    [-0.90861434, -0.09363457, 0.4070043],
-    [-0.8849237, -0.09381682, 0.45618913],
-    [-0.91294277, -0.03104798, 0.40690488],
-    [-0.8872523, -0.031273916, 0.4602232],
-    [-0.89923966, -0.062499363, 0.43296865],
-    [-0.8714971, -0.23545098, 0.43018106],
-    [-0.8584523, -0.21637973, 0.46501538],
-    [-0.86374426, -0.15655987, 0.47899368],
-    [-0.8652482, -0.20295721, 0.45842555],
-    [-0.8680021, -0.094031125, 0.48757622],
-    [-0.8702115, -0.0315014, 0.49167028],
-    [-0.86953425, -0.06279714, 0.48986408],
-    [-0.90429944, -0.1261261, 0.4078416],
-    [-0.7492584, -0.4985524, 0.43595585],
-    [-0.7412505, -0.47463012, 0.47463012],
-    [-0.7492584, -0.43595585, 0.4985524],
-    [-0.7470914, -0.47002885, 0.47002885],
-    [-0.7470915, -0.4700289, 0.4700289],
-    [-0.90140903, -0.30616686, 0.30614296],
-    [-0.76080793, -0.3947095, 0.5151464],
-    [-0.76080793, -0.3947095, 0.5151464],
-    [-0.81792694, -0.26691833, 0.50965697],
-    [-0.77153325, -0.34500876, 0.53451425],
-    [-0.755528, -0.32420808, 0.5692686],
-    [-0.7976628, -0.28548548, 0.5312552],
-    [-0.7642176, -0.28145146, 0.58030725],
-    [-0.78218, -0.3009246, 0.5455628],
-    [-0.7724774, -0.34826043, 0.53103065],
-    [-0.82522035, -0.21932015, 0.52049017],
-    [-0.8295634, -0.15528639, 0.5363867],
-    [-0.81409216, -0.13335358, 0.56521755],
-    [-0.8048269, -0.21910739, 0.5515847],
-    [-0.77126616, -0.21786655, 0.5980658],
-    [-0.8101897, -0.17501052, 0.55943173],
-    [-0.79262763, -0.1549694, 0.5896828],
-    [-0.8076839, -0.18232408, 0.56071794],
-    [-0.75287974, -0.19616963, 0.62824327],
-    [-0.75760466, -0.15600538, 0.63379604],
-    [-0.7553996, -0.17612419, 0.63115114],
-    [-0.84120214, -0.094080195, 0.53247327],
-    [-0.8168452, -0.094032906, 0.56914115],
-    [-0.8458994, -0.03151556, 0.53241056],
-    [-0.81944174, -0.031489953, 0.5722968],
-    [-0.794204, -0.09382261, 0.6003644],
-    [-0.79665226, -0.03125978, 0.60362905],
-    [-0.8199343, -0.06276856, 0.56900597],
-    [-0.7628616, -0.09351146, 0.63976383],
-    [-0.76540315, -0.031009154, 0.6428037],
-    [-0.7645073, -0.062290855, 0.6415984],
-    [-0.78903997, -0.12120853, 0.6022661],
-    [-0.70701736, -0.4673896, 0.5307293],
-    [-0.6923032, -0.44491085, 0.56812906],
-    [-0.7380218, -0.41195115, 0.5344343],
-    [-0.70405823, -0.4066382, 0.58219177],
-    [-0.67813694, -0.48661035, 0.5507637],
-    [-0.65583116, -0.4674735, 0.59275126],
-    [-0.6770918, -0.40687314, 0.6131893],
-    [-0.6940294, -0.44221538, 0.5681274],
-    [-0.632442, -0.44770122, 0.632124],
-    [-0.6458738, -0.40705562, 0.6458738],
-    [-0.6393195, -0.42748648, 0.63916045],
-    [-0.7459645, -0.36566997, 0.55661696],
-    [-0.722172, -0.34883973, 0.5973092],
-    [-0.739426, -0.28137675, 0.6116178],
-    [-0.6867571, -0.37309206, 0.62383264],
-    [-0.724479, -0.3426699, 0.5980864],
-    [-0.6975285, -0.33159938, 0.63521326],
-    [-0.70824456, -0.2814848, 0.6474225],
-    [-0.66180485, -0.35277322, 0.6614873],
-    [-0.6355435, -0.33160663, 0.6972241],
-    [-0.6785289, -0.28141975, 0.67852885],
-    [-0.6474225, -0.2814848, 0.70824456],
-    [-0.672258, -0.31040585, 0.6720991],
-    [-0.61688745, -0.48917478, 0.6165694],
-    [-0.6168875, -0.48917484, 0.61656946],
-    [-0.5929142, -0.46744084, 0.6557072],
-    [-0.5681224, -0.44483423, 0.69235796],
-    [-0.6133388, -0.4067483, 0.6770313],
-    [-0.58234537, -0.40651593, 0.7040019],
-    [-0.55073935, -0.48655805, 0.6781944],
-    [-0.5307137, -0.4673574, 0.7070502],
-    [-0.5344142, -0.41188684, 0.7380723],
-    [-0.5681869, -0.44214493, 0.6940257],
-    [-0.62416285, -0.37309936, 0.6864529],
-    [-0.59748036, -0.34884685, 0.722027],
-    [-0.6116178, -0.28137672, 0.73942596],
-    [-0.55680907, -0.36548474, 0.74591184],
-    [-0.5982611, -0.34262776, 0.7243547],
-    [-0.647725, -0.40136042, 0.6475818],
-    [-0.5151102, -0.39460036, 0.760889],
-    [-0.5151102, -0.39460036, 0.760889],
-    [-0.5693265, -0.3239224, 0.7556068],
-    [-0.5803449, -0.2812341, 0.76426905],
-    [-0.53466713, -0.34472784, 0.77155286],
-    [-0.5311748, -0.28516495, 0.797831],
-    [-0.5094646, -0.2666699, 0.8181278],
-    [-0.5455595, -0.3006546, 0.78228617],
-    [-0.531013, -0.348072, 0.7725743],
-    [-0.7471068, -0.23801026, 0.62063086],
-    [-0.7471068, -0.23801026, 0.62063086],
-    [-0.72028553, -0.22027145, 0.6577759],
-    [-0.6924799, -0.20234467, 0.6924799],
-    [-0.72985584, -0.15645474, 0.66545653],
-    [-0.6983523, -0.1568703, 0.6983523],
-    [-0.68578, -0.24374485, 0.68578],
-    [-0.6577759, -0.22027145, 0.72028565],
-    [-0.6654565, -0.1564547, 0.72985584],
-    [-0.6936726, -0.19400178, 0.6936726],
-    [-0.735096, -0.09397877, 0.6714177],
-    [-0.7039447, -0.09446678, 0.7039446],
-    [-0.73788226, -0.031237818, 0.67420614],
-    [-0.7067562, -0.031487603, 0.7067562],
-    [-0.6714177, -0.09397877, 0.735096],
-    [-0.67420614, -0.031237818, 0.73788226],
-    [-0.7057109, -0.06280486, 0.7057109],
-    [-0.62063086, -0.23801026, 0.7471068],
-    [-0.62063086, -0.23801026, 0.7471068],
-    [-0.695025, -0.18406612, 0.6950251],
-    [-0.62824327, -0.19616964, 0.75287974],
-    [-0.63379604, -0.1560054, 0.7576047],
-    [-0.63115114, -0.17612422, 0.7553996],
-    [-0.63976383, -0.09351146, 0.76286167],
-    [-0.6428037, -0.031009154, 0.7654031],
-    [-0.6415984, -0.062290855, 0.7645073],
-    [-0.5980658, -0.21786655, 0.77126616],
-    [-0.58972514, -0.15492976, 0.79260385],
-    [-0.5652887, -0.13328779, 0.8140535],
-    [-0.5516121, -0.21888195, 0.8048694],
-    [-0.5205178, -0.21909474, 0.8252628],
-    [-0.55950457, -0.17494518, 0.81015354],
-    [-0.53643084, -0.15524724, 0.82954216],
-    [-0.5607578, -0.1822293, 0.8076776],
-    [-0.6003644, -0.09382262, 0.794204],
-    [-0.56914115, -0.094032906, 0.8168453],
-    [-0.603629, -0.031259775, 0.7966522],
-    [-0.5722968, -0.031489953, 0.81944174],
-    [-0.53247327, -0.094080195, 0.84120214],
-    [-0.53241056, -0.03151556, 0.8458994],
-    [-0.56900597, -0.06276856, 0.8199343],
-    [-0.6022749, -0.12118454, 0.7890369],
-    [-0.6831899, -0.25789994, 0.683183],
-    [-0.4602232, -0.8872523, 0.031273916],
-    [-0.45618913, -0.88492364, 0.09381681],
-    [-0.4069049, -0.91294277, 0.031047983],
-    [-0.40700433, -0.90861446, 0.09363459],
-    [-0.43296868, -0.89923966, 0.06249937],
-    [-0.44760585, -0.880411, 0.15660593],
-    [-0.44064656, -0.8765209, 0.19375718],
-    [-0.40704724, -0.8998644, 0.15670547],
-    [-0.40824753, -0.88679457, 0.21663171],
-    [-0.4261152, -0.8863728, 0.18102211],
-    [-0.49167034, -0.8702115, 0.031501405],
-    [-0.4875762, -0.86800206, 0.09403111],
-    [-0.48986408, -0.86953425, 0.06279713],
-    [-0.47899368, -0.86374426, 0.15655987],
-    [-0.46501538, -0.8584523, 0.21637973],
-    [-0.43018106, -0.87149704, 0.23545098],
-    [-0.45842555, -0.8652482, 0.20295721],
-    [-0.32900843, -0.9438091, 0.031269234],
-    [-0.32312563, -0.9416977, 0.0937832],
-    [-0.36006904, -0.9324015, 0.031270668],
-    [-0.35431355, -0.93040735, 0.09383009],
-    [-0.28189346, -0.9589352, 0.03129361],
-    [-0.28204095, -0.9548013, 0.09384788],
-    [-0.32207158, -0.9446425, 0.06261321],
-    [-0.31703112, -0.93901, 0.13323525],
-    [-0.3403962, -0.9273693, 0.15529533],
-    [-0.34384707, -0.91308534, 0.21919031],
-    [-0.284349, -0.9460654, 0.15526031],
-    [-0.26451087, -0.9422029, 0.20563968],
-    [-0.30598453, -0.9357656, 0.17525989],
-    [-0.2835968, -0.9325484, 0.22344194],
-    [-0.30597222, -0.93463314, 0.18122295],
-    [-0.4078627, -0.90429014, 0.1261247],
-    [-0.38822573, -0.88146305, 0.2688933],
-    [-0.3882258, -0.88146317, 0.26889333],
-    [-0.47434115, -0.83276856, 0.28547677],
-    [-0.45369685, -0.8217146, 0.344883],
-    [-0.4979435, -0.8105078, 0.3084306],
-    [-0.4851146, -0.8036993, 0.3445741],
-    [-0.40844816, -0.86655223, 0.28680548],
-    [-0.4070137, -0.8457808, 0.34495628],
-    [-0.45498005, -0.83118224, 0.31957665],
-    [-0.44062606, -0.81380486, 0.378907],
-    [-0.47014332, -0.7826584, 0.40793523],
-    [-0.45728362, -0.7625566, 0.45760158],
-    [-0.402885, -0.8218073, 0.40288505],
-    [-0.378907, -0.81380486, 0.44062606],
-    [-0.42039618, -0.8039072, 0.420714],
-    [-0.40777215, -0.7826506, 0.4702976],
-    [-0.42589796, -0.798189, 0.4260343],
-    [-0.3493525, -0.8921737, 0.2863197],
-    [-0.3320178, -0.8830307, 0.33169383],
-    [-0.28164735, -0.91725105, 0.28164735],
-    [-0.28651404, -0.8921842, 0.34916633],
-    [-0.31267092, -0.89698607, 0.3124946],
-    [-0.26893088, -0.8815712, 0.38795394],
-    [-0.2689309, -0.8815713, 0.387954],
-    [-0.37338692, -0.8743126, 0.3100962],
-    [-0.34950918, -0.86938053, 0.34931475],
-    [-0.3104197, -0.87433016, 0.37307698],
-    [-0.34467193, -0.87326556, 0.34439573],
-    [-0.34495628, -0.8457808, 0.4070137],
-    [-0.344883, -0.8217146, 0.45369685],
-    [-0.3445741, -0.8036993, 0.4851146],
-    [-0.28682917, -0.8666205, 0.40828666],
-    [-0.28547674, -0.83276856, 0.4743411],
-    [-0.3084306, -0.8105078, 0.4979435],
-    [-0.31958112, -0.8311948, 0.4549538],
-    [-0.36085555, -0.86002004, 0.3607614],
-    [-0.4985524, -0.7492584, 0.43595585],
-    [-0.47463012, -0.7412505, 0.47463006],
-    [-0.43595585, -0.7492584, 0.4985524],
-    [-0.4700289, -0.74709153, 0.47002885],
-    [-0.4700289, -0.74709153, 0.47002885],
-    [-0.21907744, -0.9751979, 0.03153171],
-    [-0.21948425, -0.9710682, 0.09409147],
-    [-0.15668549, -0.98715264, 0.03129467],
-    [-0.1566884, -0.98317695, 0.093870826],
-    [-0.18817203, -0.9801288, 0.062759906],
-    [-0.21970935, -0.9629035, 0.15666673],
-    [-0.22377947, -0.9486019, 0.22377947],
-    [-0.24690984, -0.93705446, 0.24690984],
-    [-0.15667123, -0.9751452, 0.15667123],
-    [-0.15666674, -0.9629035, 0.21970937],
-    [-0.20104268, -0.9587303, 0.20104268],
-    [-0.09409098, -0.9950792, 0.031052334],
-    [-0.0936523, -0.9911904, 0.0936523],
-    [-0.03127881, -0.9990212, 0.03127881],
-    [-0.031052334, -0.9950792, 0.09409098],
-    [-0.06258039, -0.99607605, 0.0625804],
-    [-0.09387083, -0.983177, 0.15668844],
-    [-0.09409147, -0.97106814, 0.21948425],
-    [-0.03129467, -0.98715264, 0.15668547],
-    [-0.03153171, -0.9751979, 0.21907744],
-    [-0.06275991, -0.98012877, 0.18817203],
-    [-0.12921645, -0.98316133, 0.12921645],
-    [-0.20563965, -0.9422029, 0.26451087],
-    [-0.22344194, -0.9325484, 0.2835968],
-    [-0.21919031, -0.91308534, 0.34384704],
-    [-0.15526843, -0.9461163, 0.28417522],
-    [-0.13325031, -0.9391045, 0.31674463],
-    [-0.17527439, -0.9358569, 0.30569702],
-    [-0.15530512, -0.9274302, 0.3402258],
-    [-0.18122962, -0.9346749, 0.30584073],
-    [-0.21663171, -0.88679457, 0.40824753],
-    [-0.19375718, -0.8765209, 0.44064656],
-    [-0.15670547, -0.8998644, 0.40704724],
-    [-0.15660593, -0.880411, 0.44760585],
-    [-0.18102211, -0.88637286, 0.42611519],
-    [-0.23545098, -0.87149704, 0.43018106],
-    [-0.21637973, -0.8584523, 0.46501538],
-    [-0.15655987, -0.86374426, 0.47899368],
-    [-0.20295721, -0.8652482, 0.45842555],
-    [-0.09384788, -0.9548013, 0.28204095],
-    [-0.0937832, -0.9416977, 0.32312563],
-    [-0.09383009, -0.93040735, 0.35431355],
-    [-0.03129361, -0.9589352, 0.28189346],
-    [-0.031269234, -0.9438091, 0.3290084],
-    [-0.031270668, -0.9324015, 0.36006904],
-    [-0.06261321, -0.9446425, 0.32207155],
-    [-0.09363459, -0.90861446, 0.40700433],
-    [-0.09381681, -0.88492364, 0.45618913],
-    [-0.031047983, -0.91294277, 0.4069049],
-    [-0.031273916, -0.8872522, 0.46022323],
-    [-0.06249937, -0.8992397, 0.43296865],
-    [-0.09403111, -0.86800206, 0.4875762],
-    [-0.031501405, -0.8702115, 0.49167034],
-    [-0.06279713, -0.86953425, 0.48986408],
-    [-0.12612613, -0.90429944, 0.40784156],
-    [-0.30616686, -0.90140903, 0.30614296],
-    [-0.3947095, -0.7608078, 0.5151464],
-    [-0.39470956, -0.76080793, 0.51514643],
-    [-0.34500873, -0.77153325, 0.53451425],
-    [-0.32420805, -0.75552785, 0.5692685],
-    [-0.26691833, -0.81792694, 0.50965697],
-    [-0.28548548, -0.7976628, 0.5312552],
-    [-0.28145143, -0.7642177, 0.58030725],
-    [-0.30092466, -0.78218, 0.54556286],
-    [-0.34826043, -0.7724773, 0.5310306],
-    [-0.4673896, -0.70701736, 0.5307293],
-    [-0.44491085, -0.6923032, 0.56812906],
-    [-0.48661032, -0.67813694, 0.5507637],
-    [-0.4674735, -0.6558311, 0.59275126],
-    [-0.41195104, -0.7380218, 0.5344343],
-    [-0.4066382, -0.7040583, 0.5821919],
-    [-0.40687314, -0.6770918, 0.6131893],
-    [-0.44221538, -0.6940294, 0.56812745],
-    [-0.44770122, -0.63244194, 0.63212395],
-    [-0.40705565, -0.6458738, 0.6458738],
-    [-0.42748648, -0.6393195, 0.63916045],
-    [-0.48917478, -0.61688745, 0.6165694],
-    [-0.48917484, -0.6168875, 0.61656946],
-    [-0.46744084, -0.5929143, 0.6557072],
-    [-0.44483417, -0.56812227, 0.69235796],
-    [-0.48655805, -0.55073935, 0.6781944],
-    [-0.46735743, -0.53071374, 0.7070502],
-    [-0.4067483, -0.6133388, 0.6770312],
-    [-0.40651593, -0.58234537, 0.70400196],
-    [-0.4118869, -0.5344142, 0.7380723],
-    [-0.44214493, -0.5681868, 0.69402575],
-    [-0.36566997, -0.7459645, 0.55661696],
-    [-0.3488397, -0.722172, 0.5973092],
-    [-0.373092, -0.6867571, 0.6238326],
-    [-0.28137675, -0.739426, 0.6116178],
-    [-0.3426699, -0.724479, 0.59808636],
-    [-0.33159938, -0.6975285, 0.63521314],
-    [-0.35277322, -0.6618048, 0.6614872],
-    [-0.33160666, -0.63554347, 0.69722414],
-    [-0.28148478, -0.70824444, 0.6474225],
-    [-0.28141975, -0.67852885, 0.6785289],
-    [-0.2814848, -0.6474225, 0.70824456],
-    [-0.31040585, -0.67225796, 0.6720991],
-    [-0.37309933, -0.62416285, 0.6864529],
-    [-0.34884685, -0.5974804, 0.72202694],
-    [-0.36548474, -0.55680907, 0.74591184],
-    [-0.28137675, -0.61161774, 0.73942596],
-    [-0.3426278, -0.5982611, 0.7243548],
-    [-0.40136045, -0.647725, 0.64758176],
-    [-0.39460042, -0.5151102, 0.76088905],
-    [-0.39460042, -0.5151102, 0.76088905],
-    [-0.3239224, -0.5693265, 0.7556068],
-    [-0.34472787, -0.5346671, 0.7715529],
-    [-0.2812341, -0.58034486, 0.764269],
-    [-0.28516498, -0.5311748, 0.797831],
-    [-0.26666993, -0.5094646, 0.8181278],
-    [-0.30065462, -0.5455595, 0.7822861],
-    [-0.34807205, -0.531013, 0.7725743],
-    [-0.21932015, -0.82522035, 0.52049017],
-    [-0.21910739, -0.8048269, 0.5515847],
-    [-0.21786655, -0.77126604, 0.59806585],
-    [-0.15528637, -0.8295634, 0.53638667],
-    [-0.13335358, -0.81409216, 0.5652176],
-    [-0.17501052, -0.8101897, 0.55943173],
-    [-0.1549694, -0.79262763, 0.5896828],
-    [-0.18232405, -0.8076838, 0.56071794],
-    [-0.19616966, -0.75287986, 0.6282432],
-    [-0.1560054, -0.75760466, 0.63379604],
-    [-0.17612422, -0.7553996, 0.6311511],
-    [-0.094080195, -0.84120214, 0.53247327],
-    [-0.0940329, -0.8168452, 0.56914115],
-    [-0.093822606, -0.79420394, 0.6003643],
-    [-0.03151556, -0.8458994, 0.53241056],
-    [-0.031489953, -0.8194418, 0.57229674],
-    [-0.03125978, -0.79665226, 0.603629],
-    [-0.06276856, -0.81993437, 0.56900597],
-    [-0.09351146, -0.7628616, 0.63976383],
-    [-0.031009154, -0.7654031, 0.6428037],
-    [-0.062290855, -0.7645073, 0.6415984],
-    [-0.12120853, -0.78903997, 0.6022661],
-    [-0.23801024, -0.7471068, 0.62063086],
-    [-0.23801024, -0.7471068, 0.62063086],
-    [-0.22027145, -0.72028565, 0.6577759],
-    [-0.20234467, -0.6924799, 0.6924799],
-    [-0.24374485, -0.68578, 0.68578],
-    [-0.22027145, -0.6577759, 0.72028565],
-    [-0.15645471, -0.72985584, 0.66545653],
-    [-0.1568703, -0.6983523, 0.6983523],
-    [-0.1564547, -0.66545653, 0.72985584],
-    [-0.19400178, -0.6936726, 0.6936726],
-    [-0.23801024, -0.62063086, 0.7471068],
-    [-0.23801024, -0.62063086, 0.7471068],
-    [-0.09397876, -0.735096, 0.6714177],
-    [-0.09446676, -0.7039447, 0.7039446],
-    [-0.093978785, -0.67141783, 0.735096],
-    [-0.031237818, -0.73788226, 0.67420614],
-    [-0.031487603, -0.7067562, 0.7067561],
-    [-0.031237818, -0.67420614, 0.73788226],
-    [-0.06280486, -0.7057109, 0.7057109],
-    [-0.1840661, -0.695025, 0.6950251],
-    [-0.19616966, -0.6282432, 0.75287986],
-    [-0.1560054, -0.63379604, 0.75760466],
-    [-0.17612422, -0.6311511, 0.7553996],
-    [-0.21786655, -0.59806585, 0.77126604],
-    [-0.21888196, -0.5516121, 0.8048694],
-    [-0.21909471, -0.5205178, 0.8252628],
-    [-0.15492976, -0.58972514, 0.79260385],
-    [-0.13328779, -0.5652887, 0.8140535],
-    [-0.17494518, -0.55950457, 0.81015354],
-    [-0.15524724, -0.53643084, 0.82954216],
-    [-0.18222928, -0.5607578, 0.80767757],
-    [-0.09351146, -0.63976383, 0.7628616],
-    [-0.031009154, -0.6428037, 0.76540315],
-    [-0.062290855, -0.6415984, 0.7645073],
-    [-0.093822606, -0.6003643, 0.79420394],
-    [-0.094032906, -0.56914115, 0.81684524],
-    [-0.09408019, -0.53247327, 0.8412022],
-    [-0.03125978, -0.60362905, 0.79665226],
-    [-0.031489953, -0.5722968, 0.81944174],
-    [-0.03151556, -0.5324105, 0.8458994],
-    [-0.062768556, -0.5690059, 0.8199343],
-    [-0.121184535, -0.6022749, 0.7890369],
-    [-0.25789997, -0.6831899, 0.683183],
-    [-0.47463012, -0.47463012, 0.7412505],
-    [-0.4983138, -0.43602434, 0.7493772],
-    [-0.43602434, -0.4983138, 0.7493772],
-    [-0.4699694, -0.4699694, 0.74716645],
-    [-0.4699694, -0.4699694, 0.74716645],
-    [-0.45753765, -0.45753765, 0.7624424],
-    [-0.4701225, -0.40775257, 0.78276604],
-    [-0.4405664, -0.3785345, 0.8140105],
-    [-0.40775257, -0.47012252, 0.78276604],
-    [-0.3785344, -0.44056633, 0.81401044],
-    [-0.4204447, -0.42044467, 0.80402267],
-    [-0.40275577, -0.40275583, 0.82193404],
-    [-0.42585832, -0.42585835, 0.7983041],
-    [-0.48515105, -0.3443664, 0.80376625],
-    [-0.45373356, -0.34467536, 0.8217815],
-    [-0.49770465, -0.30847806, 0.81063646],
-    [-0.47419557, -0.28550398, 0.8328422],
-    [-0.4070137, -0.3449563, 0.8457808],
-    [-0.40845034, -0.28680262, 0.86655223],
-    [-0.45492628, -0.31951776, 0.8312342],
-    [-0.38822898, -0.26888812, 0.8814632],
-    [-0.38822898, -0.26888812, 0.8814632],
-    [-0.34436643, -0.48515105, 0.80376625],
-    [-0.34467536, -0.45373356, 0.8217815],
-    [-0.3449563, -0.40701374, 0.8457808],
-    [-0.30847806, -0.49770465, 0.81063646],
-    [-0.285504, -0.47419554, 0.8328422],
-    [-0.28680262, -0.40845034, 0.86655223],
-    [-0.31951776, -0.45492625, 0.8312342],
-    [-0.26888818, -0.38822904, 0.8814633],
-    [-0.26888815, -0.38822898, 0.88146317],
-    [-0.34948465, -0.34948468, 0.8693221],
-    [-0.37331337, -0.31067127, 0.8741399],
-    [-0.3106713, -0.37331337, 0.8741399],
-    [-0.34472048, -0.34472048, 0.87311834],
-    [-0.33197904, -0.33197904, 0.88293827],
-    [-0.3493115, -0.28666767, 0.8920779],
-    [-0.28666767, -0.3493115, 0.8920779],
-    [-0.28164735, -0.28164735, 0.91725105],
-    [-0.31268921, -0.31268921, 0.896912],
-    [-0.36083508, -0.36083505, 0.85999775],
-    [-0.46501538, -0.21637972, 0.8584523],
-    [-0.47899368, -0.15655988, 0.86374426],
-    [-0.430212, -0.2351516, 0.8715626],
-    [-0.45843437, -0.20285669, 0.8652671],
-    [-0.44064656, -0.19375718, 0.8765209],
-    [-0.44760585, -0.15660593, 0.880411],
-    [-0.4082647, -0.21645129, 0.88683075],
-    [-0.4070473, -0.15670548, 0.89986444],
-    [-0.42611876, -0.18097667, 0.8863803],
-    [-0.48757622, -0.094031125, 0.8680021],
-    [-0.49167028, -0.0315014, 0.8702115],
-    [-0.48986408, -0.06279714, 0.86953425],
-    [-0.45618913, -0.09381681, 0.88492364],
-    [-0.4602232, -0.031273916, 0.8872523],
-    [-0.40700433, -0.09363459, 0.90861446],
-    [-0.40690488, -0.03104798, 0.91294277],
-    [-0.43296868, -0.062499367, 0.8992397],
-    [-0.34384704, -0.21919031, 0.91308534],
-    [-0.3402258, -0.15530512, 0.9274302],
-    [-0.31674463, -0.13325031, 0.9391045],
-    [-0.2835968, -0.22344194, 0.9325484],
-    [-0.26451087, -0.20563968, 0.9422029],
-    [-0.30569702, -0.17527439, 0.9358569],
-    [-0.28417525, -0.15526843, 0.9461164],
-    [-0.3058407, -0.1812296, 0.9346749],
-    [-0.3543135, -0.09383009, 0.93040735],
-    [-0.32312563, -0.0937832, 0.9416977],
-    [-0.360069, -0.031270664, 0.9324014],
-    [-0.32900843, -0.031269234, 0.9438091],
-    [-0.28204095, -0.093847886, 0.9548013],
-    [-0.28189343, -0.03129361, 0.9589352],
-    [-0.32207158, -0.06261321, 0.9446425],
-    [-0.40784296, -0.12610145, 0.90430224],
-    [-0.21637973, -0.46501538, 0.8584523],
-    [-0.23515163, -0.430212, 0.8715626],
-    [-0.15655987, -0.47899368, 0.86374426],
-    [-0.20285669, -0.45843437, 0.8652671],
-    [-0.19375718, -0.44064656, 0.8765209],
-    [-0.21645129, -0.4082647, 0.88683075],
-    [-0.15660591, -0.44760588, 0.880411],
-    [-0.15670548, -0.40704724, 0.8998644],
-    [-0.18097666, -0.42611876, 0.8863803],
-    [-0.2191903, -0.34384704, 0.9130852],
-    [-0.22344194, -0.2835968, 0.9325484],
-    [-0.20563965, -0.26451087, 0.9422029],
-    [-0.1553051, -0.3402258, 0.9274302],
-    [-0.13325031, -0.31674463, 0.9391045],
-    [-0.17527437, -0.30569702, 0.9358569],
-    [-0.15526842, -0.28417522, 0.9461163],
-    [-0.1812296, -0.3058407, 0.9346749],
-    [-0.09403112, -0.48757622, 0.86800206],
-    [-0.031501405, -0.49167037, 0.8702115],
-    [-0.06279714, -0.48986414, 0.86953425],
-    [-0.09381681, -0.45618913, 0.88492364],
-    [-0.09363459, -0.40700433, 0.90861446],
-    [-0.031273916, -0.4602232, 0.8872523],
-    [-0.031047983, -0.4069049, 0.91294277],
-    [-0.062499367, -0.43296868, 0.8992397],
-    [-0.09383008, -0.3543135, 0.9304073],
-    [-0.0937832, -0.32312566, 0.9416977],
-    [-0.093847886, -0.28204095, 0.9548013],
-    [-0.031270664, -0.360069, 0.9324014],
-    [-0.031269234, -0.32900843, 0.9438091],
-    [-0.03129361, -0.28189343, 0.9589352],
-    [-0.06261322, -0.3220716, 0.9446425],
-    [-0.12610145, -0.40784302, 0.9043023],
-    [-0.24690987, -0.24690987, 0.9370545],
-    [-0.22377947, -0.22377947, 0.9486019],
-    [-0.21970937, -0.15666674, 0.9629035],
-    [-0.15666673, -0.21970935, 0.9629035],
-    [-0.15667123, -0.15667123, 0.9751453],
-    [-0.2010427, -0.2010427, 0.9587303],
-    [-0.21948425, -0.09409147, 0.97106814],
-    [-0.21907741, -0.031531706, 0.97519785],
-    [-0.15668844, -0.09387083, 0.983177],
-    [-0.15668547, -0.03129467, 0.9871527],
-    [-0.18817203, -0.062759906, 0.98012877],
-    [-0.09409147, -0.21948425, 0.9710682],
-    [-0.093870826, -0.1566884, 0.98317695],
-    [-0.03153171, -0.21907744, 0.97519785],
-    [-0.03129467, -0.15668547, 0.9871527],
-    [-0.06275991, -0.18817201, 0.98012877],
-    [-0.0936523, -0.09365229, 0.9911904],
-    [-0.09409099, -0.031052338, 0.9950793],
-    [-0.031052334, -0.09409098, 0.9950792],
-    [-0.03127881, -0.03127881, 0.9990212],
-    [-0.0625804, -0.06258039, 0.99607605],
-    [-0.12921645, -0.12921643, 0.98316133],
-    [-0.30614007, -0.30614007, 0.90141916],
-    [-0.57734746, -0.5773474, 0.57735586],
-    [-0.7654031, 0.031009154, -0.64280367],
-    [-0.76286167, 0.09351146, -0.63976383],
-    [-0.7645073, 0.062290855, -0.6415984],
-    [-0.81944174, 0.031489953, -0.57229674],
-    [-0.8458994, 0.03151556, -0.5324105],
-    [-0.81684524, 0.094032906, -0.56914115],
-    [-0.8412022, 0.09408019, -0.53247327],
-    [-0.79665226, 0.03125978, -0.60362905],
-    [-0.79420394, 0.093822606, -0.6003643],
-    [-0.8199343, 0.062768556, -0.56900597],
-    [-0.7576047, 0.1560054, -0.63379604],
-    [-0.75287986, 0.19616966, -0.6282432],
-    [-0.7553996, 0.1761242, -0.631151],
-    [-0.81409216, 0.13335358, -0.5652176],
-    [-0.8295634, 0.15528637, -0.53638667],
-    [-0.82522047, 0.21932018, -0.52049017],
-    [-0.79262763, 0.1549694, -0.5896828],
-    [-0.8101897, 0.17501052, -0.55943173],
-    [-0.77126604, 0.21786655, -0.59806585],
-    [-0.8048269, 0.21910737, -0.5515847],
-    [-0.8076838, 0.18232408, -0.56071794],
-    [-0.7890399, 0.12120853, -0.6022661],
-    [-0.81792694, 0.26691833, -0.50965697],
-    [-0.7642177, 0.28145143, -0.58030725],
-    [-0.7976628, 0.28548548, -0.5312552],
-    [-0.75552785, 0.32420805, -0.5692685],
-    [-0.77153325, 0.3450088, -0.53451425],
-    [-0.78218, 0.3009246, -0.5455628],
-    [-0.7608078, 0.3947095, -0.5151464],
-    [-0.76080793, 0.39470956, -0.51514643],
-    [-0.7724774, 0.34826043, -0.53103065],
-    [-0.6428037, 0.031009154, -0.76540315],
-    [-0.63976383, 0.09351146, -0.76286167],
-    [-0.6415984, 0.062290855, -0.7645073],
-    [-0.63379604, 0.1560054, -0.7576047],
-    [-0.6282432, 0.19616966, -0.75287986],
-    [-0.631151, 0.1761242, -0.7553996],
-    [-0.57229674, 0.031489953, -0.81944174],
-    [-0.603629, 0.03125978, -0.79665226],
-    [-0.56914115, 0.094032906, -0.8168453],
-    [-0.6003643, 0.093822606, -0.79420394],
-    [-0.5324105, 0.03151556, -0.8458994],
-    [-0.53247327, 0.094080195, -0.84120214],
-    [-0.56900597, 0.06276856, -0.81993437],
-    [-0.5652887, 0.13328779, -0.8140535],
-    [-0.58972514, 0.15492976, -0.79260385],
-    [-0.59806585, 0.21786655, -0.77126616],
-    [-0.5364308, 0.15524723, -0.82954216],
-    [-0.55950457, 0.17494518, -0.81015354],
-    [-0.5205178, 0.21909471, -0.8252628],
-    [-0.5516121, 0.21888196, -0.8048694],
-    [-0.5607578, 0.18222928, -0.8076776],
-    [-0.6022749, 0.12118454, -0.789037],
-    [-0.7067562, 0.031487603, -0.7067562],
-    [-0.73788226, 0.031237818, -0.67420614],
-    [-0.7039447, 0.09446677, -0.7039447],
-    [-0.735096, 0.093978785, -0.67141783],
-    [-0.67420614, 0.031237818, -0.73788226],
-    [-0.6714177, 0.09397876, -0.735096],
-    [-0.7057109, 0.06280486, -0.7057109],
-    [-0.6983523, 0.1568703, -0.6983523],
-    [-0.72985584, 0.1564547, -0.66545653],
-    [-0.6924799, 0.20234467, -0.6924799],
-    [-0.72028565, 0.22027145, -0.6577759],
-    [-0.6654565, 0.15645471, -0.72985584],
-    [-0.6577759, 0.22027145, -0.72028553],
-    [-0.68578, 0.24374485, -0.68578],
-    [-0.6936726, 0.19400178, -0.6936726],
-    [-0.7471068, 0.23801026, -0.62063086],
-    [-0.7471068, 0.23801026, -0.62063086],
-    [-0.62063086, 0.23801026, -0.7471068],
-    [-0.62063086, 0.23801026, -0.7471068],
-    [-0.695025, 0.18406612, -0.6950251],
-    [-0.5803449, 0.2812341, -0.764269],
-    [-0.5693265, 0.3239224, -0.7556068],
-    [-0.5094646, 0.26666993, -0.8181278],
-    [-0.5311748, 0.28516495, -0.797831],
-    [-0.5346671, 0.34472787, -0.77155286],
-    [-0.5455595, 0.30065462, -0.78228605],
-    [-0.5151102, 0.39460042, -0.76088905],
-    [-0.5151102, 0.39460042, -0.76088905],
-    [-0.5310131, 0.34807208, -0.7725743],
-    [-0.70824456, 0.2814848, -0.6474225],
-    [-0.6975285, 0.33159938, -0.63521314],
-    [-0.6474225, 0.2814848, -0.70824456],
-    [-0.6785289, 0.28141975, -0.67852885],
-    [-0.63554347, 0.33160666, -0.69722414],
-    [-0.6618048, 0.35277322, -0.6614872],
-    [-0.67225814, 0.31040588, -0.6720992],
-    [-0.739426, 0.28137675, -0.61161774],
-    [-0.722172, 0.34883973, -0.5973092],
-    [-0.7459645, 0.36566997, -0.55661696],
-    [-0.6867571, 0.37309206, -0.62383264],
-    [-0.724479, 0.3426699, -0.5980864],
-    [-0.6458738, 0.40705562, -0.6458738],
-    [-0.63244194, 0.44770122, -0.63212395],
-    [-0.6393195, 0.42748648, -0.63916045],
-    [-0.7040582, 0.40663823, -0.5821918],
-    [-0.7380218, 0.4119511, -0.5344343],
-    [-0.6923032, 0.44491085, -0.56812906],
-    [-0.70701736, 0.46738964, -0.5307293],
-    [-0.6770918, 0.40687314, -0.6131893],
-    [-0.6558311, 0.4674735, -0.59275126],
-    [-0.67813694, 0.48661035, -0.5507637],
-    [-0.6940294, 0.44221538, -0.56812745],
-    [-0.6116178, 0.28137675, -0.739426],
-    [-0.5974805, 0.34884685, -0.72202694],
-    [-0.62416285, 0.37309936, -0.6864529],
-    [-0.55680907, 0.36548474, -0.74591184],
-    [-0.5982611, 0.34262776, -0.7243547],
-    [-0.58234537, 0.40651596, -0.7040019],
-    [-0.6133388, 0.4067483, -0.6770312],
-    [-0.56812227, 0.44483417, -0.69235796],
-    [-0.5929141, 0.46744078, -0.6557072],
-    [-0.5344142, 0.41188684, -0.7380723],
-    [-0.5307137, 0.46735737, -0.7070502],
-    [-0.55073935, 0.48655805, -0.6781944],
-    [-0.5681868, 0.44214493, -0.6940257],
-    [-0.61688745, 0.48917478, -0.6165694],
-    [-0.6168875, 0.48917484, -0.61656946],
-    [-0.647725, 0.40136042, -0.64758176],
-    [-0.6831899, 0.25789997, -0.683183],
-    [-0.8872522, 0.031273916, -0.4602232],
-    [-0.91294277, 0.031047983, -0.4069049],
-    [-0.88492364, 0.09381681, -0.45618913],
-    [-0.90861446, 0.09363459, -0.40700433],
-    [-0.89923966, 0.062499367, -0.43296868],
-    [-0.9438091, 0.031269234, -0.32900843],
-    [-0.9589352, 0.03129361, -0.28189343],
-    [-0.9416977, 0.0937832, -0.32312563],
-    [-0.9548013, 0.093847886, -0.28204095],
-    [-0.9324015, 0.031270668, -0.36006904],
-    [-0.9304073, 0.09383008, -0.3543135],
-    [-0.9446425, 0.06261322, -0.32207158],
-    [-0.880411, 0.15660593, -0.44760585],
-    [-0.8998644, 0.15670548, -0.40704724],
-    [-0.8765209, 0.19375718, -0.44064656],
-    [-0.88679457, 0.21663171, -0.40824753],
-    [-0.8863728, 0.18102211, -0.4261152],
-    [-0.9391045, 0.13325031, -0.31674463],
-    [-0.9461164, 0.15526843, -0.28417525],
-    [-0.9422029, 0.20563965, -0.26451087],
-    [-0.9274302, 0.15530512, -0.3402258],
-    [-0.9358569, 0.17527439, -0.30569702],
-    [-0.91308534, 0.21919033, -0.34384707],
-    [-0.9325484, 0.22344194, -0.2835968],
-    [-0.93467486, 0.18122959, -0.30584067],
-    [-0.8702115, 0.031501405, -0.49167034],
-    [-0.86800206, 0.09403111, -0.4875762],
-    [-0.86953425, 0.06279713, -0.48986408],
-    [-0.8637442, 0.15655988, -0.47899368],
-    [-0.8584523, 0.21637973, -0.46501538],
-    [-0.8714971, 0.23545098, -0.43018106],
-    [-0.8652482, 0.20295721, -0.45842555],
-    [-0.90429944, 0.12612613, -0.40784165],
-    [-0.97519785, 0.03153171, -0.21907744],
-    [-0.9871526, 0.031294666, -0.15668546],
-    [-0.9710682, 0.09409147, -0.21948425],
-    [-0.98317695, 0.093870826, -0.1566884],
-    [-0.9801288, 0.062759906, -0.18817201],
-    [-0.9950792, 0.031052334, -0.09409098],
-    [-0.9990212, 0.03127881, -0.03127881],
-    [-0.9911904, 0.0936523, -0.09365229],
-    [-0.9950793, 0.09409099, -0.031052338],
-    [-0.99607605, 0.0625804, -0.0625804],
-    [-0.9629035, 0.15666673, -0.21970935],
-    [-0.9751453, 0.15667123, -0.15667123],
-    [-0.9486019, 0.22377947, -0.22377947],
-    [-0.9629035, 0.21970937, -0.15666674],
-    [-0.9370545, 0.24690987, -0.24690987],
-    [-0.9587303, 0.20104268, -0.20104268],
-    [-0.983177, 0.15668844, -0.09387083],
-    [-0.9871527, 0.15668547, -0.03129467],
-    [-0.97106814, 0.21948425, -0.09409147],
-    [-0.97519785, 0.21907741, -0.031531706],
-    [-0.98012877, 0.18817201, -0.06275991],
-    [-0.98316133, 0.12921643, -0.12921645],
-    [-0.8815712, 0.26893088, -0.38795394],
-    [-0.8815713, 0.2689309, -0.387954],
-    [-0.8921842, 0.286514, -0.3491663],
-    [-0.91725105, 0.28164735, -0.28164735],
-    [-0.8830307, 0.3320178, -0.33169383],
-    [-0.8921737, 0.3493525, -0.28631973],
-    [-0.89698607, 0.3126709, -0.3124946],
-    [-0.88146305, 0.38822573, -0.2688933],
-    [-0.88146317, 0.3882258, -0.26889333],
-    [-0.83276856, 0.28547677, -0.47434115],
-    [-0.8666205, 0.28682917, -0.4082866],
-    [-0.8217146, 0.344883, -0.45369685],
-    [-0.8457808, 0.3449563, -0.40701374],
-    [-0.8105078, 0.3084306, -0.4979435],
-    [-0.8036993, 0.3445741, -0.4851146],
-    [-0.8311948, 0.3195811, -0.4549538],
-    [-0.87433016, 0.3104197, -0.37307698],
-    [-0.86938053, 0.34950918, -0.34931475],
-    [-0.8743127, 0.37338692, -0.3100962],
-    [-0.87326556, 0.34467193, -0.34439573],
-    [-0.81380486, 0.378907, -0.44062606],
-    [-0.8218073, 0.402885, -0.40288505],
-    [-0.81380486, 0.44062606, -0.378907],
-    [-0.7826506, 0.40777215, -0.4702976],
-    [-0.80390716, 0.42039615, -0.420714],
-    [-0.7625566, 0.45728362, -0.45760158],
-    [-0.7826584, 0.47014335, -0.40793523],
-    [-0.79818904, 0.42589805, -0.42603433],
-    [-0.8457808, 0.4070137, -0.3449563],
-    [-0.86655223, 0.40844816, -0.28680548],
-    [-0.82171464, 0.4536969, -0.34488305],
-    [-0.83276856, 0.47434115, -0.2854768],
-    [-0.8036992, 0.48511457, -0.3445741],
-    [-0.8105078, 0.4979435, -0.3084306],
-    [-0.8311822, 0.45498013, -0.31957668],
-    [-0.86002004, 0.36085558, -0.36076143],
-    [-0.9422029, 0.26451087, -0.20563968],
-    [-0.9460654, 0.28434896, -0.15526032],
-    [-0.93901, 0.31703112, -0.13323525],
-    [-0.9325484, 0.2835968, -0.22344194],
-    [-0.9357656, 0.30598453, -0.17525989],
-    [-0.91308534, 0.34384704, -0.21919031],
-    [-0.9273693, 0.3403962, -0.15529534],
-    [-0.9346332, 0.30597228, -0.18122298],
-    [-0.9548013, 0.28204095, -0.093847886],
-    [-0.9589352, 0.28189343, -0.03129361],
-    [-0.9416977, 0.32312566, -0.0937832],
-    [-0.9438091, 0.32900843, -0.031269234],
-    [-0.9304074, 0.3543135, -0.09383009],
-    [-0.9324014, 0.360069, -0.031270664],
-    [-0.9446425, 0.32207155, -0.06261321],
-    [-0.88679457, 0.40824753, -0.21663171],
-    [-0.89986444, 0.4070473, -0.15670548],
-    [-0.8765209, 0.44064656, -0.19375718],
-    [-0.880411, 0.44760588, -0.15660593],
-    [-0.88637286, 0.4261152, -0.18102211],
-    [-0.90861446, 0.40700433, -0.09363459],
-    [-0.91294277, 0.40690488, -0.03104798],
-    [-0.88492364, 0.45618913, -0.09381682],
-    [-0.8872522, 0.46022323, -0.031273916],
-    [-0.8992397, 0.43296865, -0.06249937],
-    [-0.8714971, 0.43018106, -0.23545098],
-    [-0.8584523, 0.46501538, -0.21637972],
-    [-0.86374426, 0.47899368, -0.15655987],
-    [-0.8652482, 0.45842555, -0.20295721],
-    [-0.8680021, 0.48757622, -0.094031125],
-    [-0.8702115, 0.49167037, -0.031501405],
-    [-0.86953413, 0.48986408, -0.06279714],
-    [-0.9042902, 0.4078627, -0.1261247],
-    [-0.7492584, 0.43595585, -0.4985524],
-    [-0.7412505, 0.47463012, -0.47463012],
-    [-0.7492584, 0.4985524, -0.43595585],
-    [-0.7470914, 0.47002885, -0.47002885],
-    [-0.7470915, 0.4700289, -0.4700289],
-    [-0.90140903, 0.30616686, -0.306143],
-    [-0.6943562, 0.5088563, -0.5088563],
-    [-0.63638526, 0.5137616, -0.5753806],
-    [-0.6576295, 0.5326919, -0.5326941],
-    [-0.63657665, 0.57526654, -0.51365227],
-    [-0.6568716, 0.5331593, -0.53316104],
-    [-0.5753806, 0.5137616, -0.63638526],
-    [-0.5089309, 0.5089309, -0.69424677],
-    [-0.5327861, 0.5327861, -0.6574786],
-    [-0.5137616, 0.5753806, -0.63638526],
-    [-0.53322995, 0.53322995, -0.6567584],
-    [-0.59742147, 0.5349535, -0.59742147],
-    [-0.6185422, 0.55560803, -0.55561256],
-    [-0.57735026, 0.57735026, -0.57735026],
-    [-0.5974675, 0.5974675, -0.53485054],
-    [-0.5557188, 0.5557188, -0.6183473],
-    [-0.5349534, 0.59742147, -0.5974215],
-    [-0.55560803, 0.61854213, -0.55561256],
-    [-0.5773715, 0.5773715, -0.5773078],
-    [-0.57526654, 0.63657665, -0.51365227],
-    [-0.5137616, 0.63638526, -0.5753806],
-    [-0.53269184, 0.6576295, -0.5326941],
-    [-0.5088563, 0.6943562, -0.5088563],
-    [-0.5331593, 0.65687174, -0.5331611],
-    [-0.5773707, 0.5773707, -0.5773094],
-    [-0.5773707, 0.5773707, -0.5773094],
-    [-0.76067156, 0.51527786, -0.39480042],
-    [-0.7606717, 0.5152779, -0.39480048],
-    [-0.81792694, 0.50965697, -0.2669183],
-    [-0.77153325, 0.5345115, -0.3450128],
-    [-0.7976628, 0.5312552, -0.28548548],
-    [-0.75566536, 0.5691334, -0.32412475],
-    [-0.7642177, 0.5803073, -0.28145146],
-    [-0.78220624, 0.54553425, -0.30090824],
-    [-0.77242386, 0.5310833, -0.3482985],
-    [-0.82522035, 0.52049017, -0.21932015],
-    [-0.8293967, 0.53665316, -0.15525566],
-    [-0.8137997, 0.56565136, -0.13329963],
-    [-0.8048269, 0.5515847, -0.21910736],
-    [-0.80990016, 0.5598676, -0.17495714],
-    [-0.77106327, 0.5982786, -0.21800065],
-    [-0.7924223, 0.58991903, -0.15512083],
-    [-0.8075206, 0.5609461, -0.18234566],
-    [-0.84120214, 0.53247327, -0.094080195],
-    [-0.8458994, 0.53241056, -0.03151556],
-    [-0.81684524, 0.5691412, -0.094032906],
-    [-0.81944174, 0.57229674, -0.031489953],
-    [-0.794204, 0.6003644, -0.09382261],
-    [-0.79665226, 0.60362905, -0.03125978],
-    [-0.8199343, 0.56900597, -0.06276856],
-    [-0.7524859, 0.6285504, -0.19669628],
-    [-0.7575485, 0.6337491, -0.156468],
-    [-0.75517493, 0.6312817, -0.17661904],
-    [-0.7628616, 0.63976383, -0.09351147],
-    [-0.76540303, 0.6428036, -0.03100915],
-    [-0.76450723, 0.6415984, -0.06229086],
-    [-0.78894806, 0.6023601, -0.121338814],
-    [-0.70701736, 0.5307293, -0.4673896],
-    [-0.7378788, 0.5344669, -0.41216496],
-    [-0.69213676, 0.56825644, -0.44500726],
-    [-0.70386404, 0.58223426, -0.40691346],
-    [-0.67813694, 0.5507637, -0.48661032],
-    [-0.6557199, 0.5929463, -0.46738222],
-    [-0.6769644, 0.61328274, -0.406944],
-    [-0.69392186, 0.56819654, -0.4422953],
-    [-0.74587893, 0.5565471, -0.36595076],
-    [-0.72213846, 0.5973131, -0.34890273],
-    [-0.73937637, 0.6115759, -0.28159812],
-    [-0.6866207, 0.6240265, -0.37301892],
-    [-0.7244028, 0.59810805, -0.34279317],
-    [-0.6323825, 0.6323825, -0.44741994],
-    [-0.6458738, 0.6458738, -0.40705565],
-    [-0.6392875, 0.6392875, -0.4273443],
-    [-0.6973894, 0.63540477, -0.33152497],
-    [-0.70819515, 0.64738035, -0.28170624],
-    [-0.66166675, 0.66166675, -0.35269552],
-    [-0.6785289, 0.67852885, -0.28141975],
-    [-0.63540477, 0.6973893, -0.33152494],
-    [-0.64738035, 0.70819515, -0.28170624],
-    [-0.6721709, 0.6721708, -0.3104396],
-    [-0.61683726, 0.61683726, -0.48890042],
-    [-0.61683726, 0.61683726, -0.48890042],
-    [-0.5929463, 0.6557199, -0.46738228],
-    [-0.61328274, 0.6769644, -0.406944],
-    [-0.56825644, 0.69213676, -0.44500726],
-    [-0.58223426, 0.70386404, -0.4069135],
-    [-0.5507637, 0.67813694, -0.48661032],
-    [-0.5307293, 0.70701736, -0.4673896],
-    [-0.5344669, 0.73787886, -0.4121649],
-    [-0.5681965, 0.69392186, -0.4422953],
-    [-0.6240265, 0.6866207, -0.37301892],
-    [-0.59731305, 0.72213846, -0.34890273],
-    [-0.6115759, 0.7393764, -0.2815981],
-    [-0.5565471, 0.74587893, -0.36595076],
-    [-0.59810805, 0.7244028, -0.34279317],
-    [-0.64764833, 0.6476484, -0.40137666],
-    [-0.74675465, 0.6209723, -0.23822431],
-    [-0.7467547, 0.62097234, -0.23822434],
-    [-0.72007656, 0.65791845, -0.22052875],
-    [-0.72982764, 0.665433, -0.15668593],
-    [-0.6924799, 0.6924799, -0.20234467],
-    [-0.6983523, 0.6983523, -0.1568703],
-    [-0.68578, 0.68578, -0.24374485],
-    [-0.65791845, 0.72007656, -0.22052874],
-    [-0.6654331, 0.7298277, -0.15668595],
-    [-0.69365317, 0.69365317, -0.19414084],
-    [-0.735096, 0.6714177, -0.09397877],
-    [-0.73788226, 0.67420614, -0.031237818],
-    [-0.7039446, 0.7039446, -0.09446676],
-    [-0.7067562, 0.7067562, -0.031487603],
-    [-0.6714177, 0.735096, -0.09397877],
-    [-0.67420614, 0.73788226, -0.031237818],
-    [-0.7057108, 0.7057108, -0.062804855],
-    [-0.6209723, 0.7467547, -0.23822434],
-    [-0.62097234, 0.74675477, -0.23822437],
-    [-0.6950065, 0.69500655, -0.18420568],
-    [-0.515278, 0.76067156, -0.39480042],
-    [-0.515278, 0.76067156, -0.39480042],
-    [-0.5691334, 0.75566536, -0.32412472],
-    [-0.5803073, 0.7642176, -0.28145146],
-    [-0.5345116, 0.77153325, -0.3450128],
-    [-0.5312552, 0.7976628, -0.28548548],
-    [-0.50965697, 0.81792694, -0.26691833],
-    [-0.5455343, 0.7822062, -0.30090824],
-    [-0.53108335, 0.77242386, -0.34829846],
-    [-0.6285504, 0.7524859, -0.19669631],
-    [-0.6337491, 0.7575485, -0.156468],
-    [-0.6312817, 0.75517493, -0.17661905],
-    [-0.63976383, 0.7628616, -0.09351147],
-    [-0.6428036, 0.76540303, -0.03100915],
-    [-0.6415984, 0.76450723, -0.06229086],
-    [-0.5982785, 0.77106327, -0.21800068],
-    [-0.5899191, 0.7924223, -0.15512083],
-    [-0.56565136, 0.8137997, -0.13329963],
-    [-0.5515847, 0.8048269, -0.21910737],
-    [-0.5598676, 0.80990016, -0.17495714],
-    [-0.52049017, 0.82522035, -0.21932015],
-    [-0.5366531, 0.8293967, -0.15525566],
-    [-0.56094617, 0.8075206, -0.18234569],
-    [-0.6003643, 0.79420394, -0.093822606],
-    [-0.603629, 0.7966522, -0.031259775],
-    [-0.56914115, 0.8168452, -0.0940329],
-    [-0.57229674, 0.81944174, -0.031489953],
-    [-0.53247327, 0.84120214, -0.094080195],
-    [-0.53241056, 0.8458994, -0.03151556],
-    [-0.56900597, 0.81993437, -0.06276856],
-    [-0.6023601, 0.78894806, -0.12133883],
-    [-0.6831648, 0.68316483, -0.2580152],
-    [-0.4602232, 0.031273916, -0.8872522],
-    [-0.4561892, 0.09381683, -0.8849237],
-    [-0.4069049, 0.031047983, -0.91294277],
-    [-0.40700433, 0.09363459, -0.90861446],
-    [-0.43296868, 0.062499374, -0.89923966],
-    [-0.49167034, 0.031501405, -0.8702115],
-    [-0.4875762, 0.09403111, -0.86800206],
-    [-0.48986408, 0.06279713, -0.86953425],
-    [-0.44760588, 0.15660593, -0.880411],
-    [-0.44064656, 0.19375718, -0.8765209],
-    [-0.40704724, 0.15670547, -0.8998644],
-    [-0.4082647, 0.21645129, -0.88683075],
-    [-0.42611876, 0.18097666, -0.8863803],
-    [-0.47899368, 0.15655988, -0.8637442],
-    [-0.46501538, 0.21637973, -0.8584523],
-    [-0.430212, 0.2351516, -0.8715626],
-    [-0.45843437, 0.20285669, -0.8652671],
-    [-0.3290084, 0.031269234, -0.9438091],
-    [-0.36006904, 0.031270668, -0.9324015],
-    [-0.32312563, 0.0937832, -0.9416977],
-    [-0.3543135, 0.09383008, -0.9304073],
-    [-0.28189346, 0.03129361, -0.9589352],
-    [-0.28204095, 0.09384788, -0.9548013],
-    [-0.32207158, 0.06261322, -0.94464254],
-    [-0.31674463, 0.13325031, -0.9391045],
-    [-0.3402258, 0.15530512, -0.9274302],
-    [-0.34384707, 0.21919031, -0.91308534],
-    [-0.28417525, 0.15526843, -0.9461164],
-    [-0.30569702, 0.17527439, -0.9358569],
-    [-0.26451087, 0.20563965, -0.9422029],
-    [-0.2835968, 0.22344194, -0.9325484],
-    [-0.30584067, 0.18122958, -0.93467486],
-    [-0.40784302, 0.12610145, -0.9043023],
-    [-0.38822904, 0.26888818, -0.8814633],
-    [-0.38822898, 0.26888815, -0.88146317],
-    [-0.47419563, 0.285504, -0.8328421],
-    [-0.49770465, 0.30847806, -0.81063646],
-    [-0.45373356, 0.34467536, -0.8217815],
-    [-0.48515105, 0.3443664, -0.80376625],
-    [-0.40845034, 0.28680262, -0.86655223],
-    [-0.4070137, 0.34495628, -0.8457808],
-    [-0.45492628, 0.3195178, -0.8312342],
-    [-0.44056633, 0.3785344, -0.81401044],
-    [-0.47012252, 0.40775257, -0.78276604],
-    [-0.45753765, 0.45753765, -0.7624424],
-    [-0.40275577, 0.40275577, -0.82193404],
-    [-0.4204447, 0.42044467, -0.80402267],
-    [-0.3785345, 0.4405664, -0.8140105],
-    [-0.40775254, 0.47012255, -0.7827661],
-    [-0.42585835, 0.42585835, -0.798304],
-    [-0.3493115, 0.28666767, -0.8920779],
-    [-0.33197904, 0.33197904, -0.88293827],
-    [-0.28164735, 0.28164735, -0.91725105],
-    [-0.28666767, 0.34931153, -0.8920779],
-    [-0.3126892, 0.31268921, -0.89691186],
-    [-0.37331337, 0.31067127, -0.8741399],
-    [-0.34948468, 0.34948468, -0.8693221],
-    [-0.3106713, 0.37331337, -0.8741399],
-    [-0.3447205, 0.34472048, -0.87311834],
-    [-0.26888812, 0.38822898, -0.8814632],
-    [-0.26888812, 0.38822898, -0.8814632],
-    [-0.34495628, 0.4070137, -0.8457808],
-    [-0.34467536, 0.45373356, -0.8217815],
-    [-0.34436643, 0.48515105, -0.80376625],
-    [-0.2868026, 0.40845034, -0.86655223],
-    [-0.28550404, 0.47419563, -0.8328421],
-    [-0.30847806, 0.49770465, -0.81063646],
-    [-0.31951776, 0.45492628, -0.8312342],
-    [-0.36083508, 0.36083508, -0.8599978],
-    [-0.4983138, 0.43602434, -0.7493772],
-    [-0.47463012, 0.47463006, -0.7412505],
-    [-0.43602434, 0.4983138, -0.7493772],
-    [-0.4699694, 0.4699694, -0.74716645],
-    [-0.4699694, 0.4699694, -0.74716645],
-    [-0.21907744, 0.03153171, -0.9751979],
-    [-0.21948425, 0.09409147, -0.9710682],
-    [-0.15668549, 0.03129467, -0.98715264],
-    [-0.1566884, 0.093870826, -0.98317695],
-    [-0.18817203, 0.062759906, -0.9801288],
-    [-0.21970935, 0.15666673, -0.9629035],
-    [-0.22377947, 0.22377947, -0.9486019],
-    [-0.24690987, 0.24690987, -0.9370545],
-    [-0.15667123, 0.15667123, -0.9751452],
-    [-0.15666674, 0.21970937, -0.9629035],
-    [-0.20104268, 0.20104268, -0.9587303],
-    [-0.09409098, 0.031052334, -0.9950792],
-    [-0.0936523, 0.0936523, -0.9911904],
-    [-0.03127881, 0.03127881, -0.9990212],
-    [-0.031052334, 0.09409098, -0.9950792],
-    [-0.06258039, 0.0625804, -0.99607605],
-    [-0.09387083, 0.15668844, -0.983177],
-    [-0.09409147, 0.21948425, -0.97106814],
-    [-0.03129467, 0.15668547, -0.98715264],
-    [-0.03153171, 0.21907744, -0.9751979],
-    [-0.06275991, 0.18817203, -0.98012877],
-    [-0.12921645, 0.12921645, -0.98316133],
-    [-0.20563968, 0.26451087, -0.9422029],
-    [-0.22344194, 0.2835968, -0.9325484],
-    [-0.21919031, 0.34384704, -0.91308534],
-    [-0.15526842, 0.28417522, -0.9461163],
-    [-0.17527437, 0.30569702, -0.9358569],
-    [-0.13325031, 0.31674463, -0.9391045],
-    [-0.1553051, 0.34022576, -0.9274302],
-    [-0.18122962, 0.3058407, -0.9346749],
-    [-0.2164513, 0.4082647, -0.88683075],
-    [-0.19375718, 0.44064656, -0.8765209],
-    [-0.15670547, 0.40704724, -0.8998644],
-    [-0.15660593, 0.44760588, -0.880411],
-    [-0.18097667, 0.42611873, -0.8863804],
-    [-0.23515163, 0.430212, -0.8715626],
-    [-0.21637976, 0.46501538, -0.8584523],
-    [-0.15655987, 0.47899368, -0.86374426],
-    [-0.2028567, 0.45843437, -0.8652671],
-    [-0.09384788, 0.28204095, -0.9548013],
-    [-0.0937832, 0.32312563, -0.9416977],
-    [-0.09383009, 0.3543135, -0.9304074],
-    [-0.03129361, 0.28189346, -0.9589352],
-    [-0.031269234, 0.32900846, -0.9438091],
-    [-0.031270664, 0.360069, -0.9324014],
-    [-0.06261321, 0.32207158, -0.9446425],
-    [-0.09363459, 0.40700433, -0.90861446],
-    [-0.09381683, 0.4561892, -0.8849237],
-    [-0.031047983, 0.4069049, -0.91294277],
-    [-0.031273916, 0.4602232, -0.8872522],
-    [-0.062499374, 0.43296868, -0.8992397],
-    [-0.094031125, 0.48757622, -0.8680021],
-    [-0.031501405, 0.49167037, -0.8702115],
-    [-0.062797144, 0.48986414, -0.86953425],
-    [-0.12610145, 0.40784296, -0.90430224],
-    [-0.30614012, 0.30614012, -0.9014191],
-    [-0.39460036, 0.5151102, -0.760889],
-    [-0.39460036, 0.5151102, -0.760889],
-    [-0.34472787, 0.5346671, -0.7715529],
-    [-0.3239224, 0.5693265, -0.7556068],
-    [-0.2666699, 0.5094646, -0.8181278],
-    [-0.28516498, 0.5311748, -0.797831],
-    [-0.28123412, 0.5803449, -0.764269],
-    [-0.30065462, 0.5455595, -0.7822861],
-    [-0.34807205, 0.5310131, -0.7725743],
-    [-0.46735737, 0.5307137, -0.7070502],
-    [-0.48655805, 0.55073935, -0.6781944],
-    [-0.44483423, 0.5681224, -0.69235796],
-    [-0.4674408, 0.5929142, -0.6557072],
-    [-0.4118869, 0.5344142, -0.7380723],
-    [-0.40651596, 0.58234537, -0.70400196],
-    [-0.40674835, 0.6133388, -0.67703134],
-    [-0.44214487, 0.5681868, -0.69402575],
-    [-0.48917478, 0.61688745, -0.6165694],
-    [-0.48917484, 0.6168875, -0.61656946],
-    [-0.44770122, 0.632442, -0.632124],
-    [-0.40705562, 0.6458738, -0.6458738],
-    [-0.42748648, 0.6393195, -0.63916045],
-    [-0.4674735, 0.6558311, -0.59275126],
-    [-0.48661032, 0.67813694, -0.5507637],
-    [-0.44491085, 0.6923032, -0.56812906],
-    [-0.4673896, 0.70701736, -0.5307293],
-    [-0.40687314, 0.6770918, -0.6131893],
-    [-0.40663823, 0.7040583, -0.5821918],
-    [-0.4119511, 0.7380218, -0.5344343],
-    [-0.4422154, 0.69402945, -0.5681274],
-    [-0.36548474, 0.55680907, -0.74591184],
-    [-0.34884685, 0.5974803, -0.722027],
-    [-0.37309933, 0.62416285, -0.6864529],
-    [-0.28137675, 0.6116178, -0.739426],
-    [-0.3426278, 0.5982611, -0.7243548],
-    [-0.33160663, 0.6355435, -0.6972241],
-    [-0.35277322, 0.66180485, -0.6614873],
-    [-0.33159938, 0.6975285, -0.63521326],
-    [-0.2814848, 0.6474225, -0.70824456],
-    [-0.28141975, 0.67852885, -0.6785289],
-    [-0.28148478, 0.70824444, -0.6474225],
-    [-0.31040585, 0.67225796, -0.6720991],
-    [-0.373092, 0.6867571, -0.6238326],
-    [-0.34883973, 0.7221721, -0.5973092],
-    [-0.36566997, 0.7459645, -0.55661696],
-    [-0.28137672, 0.739426, -0.6116178],
-    [-0.3426699, 0.7244791, -0.59808636],
-    [-0.40136042, 0.64772505, -0.64758176],
-    [-0.3947095, 0.76080793, -0.5151464],
-    [-0.3947095, 0.76080793, -0.5151464],
-    [-0.32420808, 0.755528, -0.5692686],
-    [-0.34500876, 0.77153325, -0.5345142],
-    [-0.28145143, 0.7642177, -0.58030725],
-    [-0.28548548, 0.7976628, -0.5312552],
-    [-0.26691833, 0.81792694, -0.50965697],
-    [-0.3009246, 0.78217995, -0.5455628],
-    [-0.34826043, 0.7724773, -0.53103065],
-    [-0.21909471, 0.5205178, -0.8252628],
-    [-0.21888196, 0.5516121, -0.8048694],
-    [-0.21786655, 0.59806585, -0.77126616],
-    [-0.15524724, 0.53643084, -0.82954216],
-    [-0.17494518, 0.55950457, -0.81015354],
-    [-0.13328779, 0.5652887, -0.8140535],
-    [-0.15492976, 0.58972514, -0.79260385],
-    [-0.1822293, 0.5607579, -0.80767757],
-    [-0.19616964, 0.62824327, -0.75287974],
-    [-0.1560054, 0.63379604, -0.75760466],
-    [-0.17612422, 0.63115114, -0.7553996],
-    [-0.094080195, 0.53247327, -0.84120214],
-    [-0.094032906, 0.56914115, -0.8168453],
-    [-0.09382261, 0.6003644, -0.794204],
-    [-0.03151556, 0.53241056, -0.8458994],
-    [-0.031489953, 0.57229674, -0.81944174],
-    [-0.03125978, 0.60362905, -0.79665226],
-    [-0.062768556, 0.56900597, -0.8199343],
-    [-0.09351146, 0.63976383, -0.76286167],
-    [-0.031009154, 0.6428037, -0.76540315],
-    [-0.062290855, 0.6415984, -0.7645073],
-    [-0.12118454, 0.60227495, -0.78903687],
-    [-0.23801024, 0.62063086, -0.7471068],
-    [-0.23801024, 0.62063086, -0.7471068],
-    [-0.22027147, 0.6577759, -0.72028565],
-    [-0.24374485, 0.68578, -0.68578],
-    [-0.20234467, 0.6924799, -0.6924799],
-    [-0.22027145, 0.72028565, -0.6577759],
-    [-0.15645471, 0.6654565, -0.72985584],
-    [-0.1568703, 0.6983523, -0.6983523],
-    [-0.15645471, 0.72985584, -0.66545653],
-    [-0.19400178, 0.6936726, -0.6936726],
-    [-0.23801024, 0.7471068, -0.62063086],
-    [-0.23801024, 0.7471068, -0.62063086],
-    [-0.09397877, 0.6714177, -0.735096],
-    [-0.09446677, 0.7039447, -0.7039446],
-    [-0.09397877, 0.735096, -0.6714177],
-    [-0.031237818, 0.67420614, -0.73788226],
-    [-0.031487603, 0.7067562, -0.7067562],
-    [-0.031237818, 0.73788226, -0.67420614],
-    [-0.06280486, 0.7057109, -0.7057109],
-    [-0.1840661, 0.695025, -0.6950251],
-    [-0.19616963, 0.75287974, -0.62824327],
-    [-0.1560054, 0.7576047, -0.63379604],
-    [-0.1761242, 0.7553996, -0.63115114],
-    [-0.21786655, 0.77126604, -0.59806585],
-    [-0.21910737, 0.8048269, -0.5515847],
-    [-0.21932015, 0.82522035, -0.52049017],
-    [-0.1549694, 0.79262763, -0.5896828],
-    [-0.17501052, 0.8101897, -0.55943173],
-    [-0.13335358, 0.81409216, -0.56521755],
-    [-0.1552864, 0.8295634, -0.5363867],
-    [-0.18232411, 0.8076839, -0.560718],
-    [-0.09351146, 0.76286167, -0.63976383],
-    [-0.031009154, 0.7654031, -0.64280367],
-    [-0.062290855, 0.7645073, -0.6415984],
-    [-0.093822606, 0.79420394, -0.6003643],
-    [-0.0940329, 0.8168452, -0.56914115],
-    [-0.09408019, 0.8412022, -0.53247327],
-    [-0.031259775, 0.7966522, -0.603629],
-    [-0.031489953, 0.81944174, -0.57229674],
-    [-0.03151556, 0.8458994, -0.5324105],
-    [-0.06276856, 0.81993437, -0.56900597],
-    [-0.12120855, 0.78903997, -0.6022661],
-    [-0.25789994, 0.6831899, -0.683183],
-    [-0.47463012, 0.7412505, -0.47463012],
-    [-0.4985524, 0.7492584, -0.43595585],
-    [-0.43595585, 0.7492584, -0.4985524],
-    [-0.47002885, 0.7470914, -0.47002885],
-    [-0.4700289, 0.7470915, -0.4700289],
-    [-0.45728365, 0.7625565, -0.45760158],
-    [-0.47014335, 0.7826584, -0.40793523],
-    [-0.44062606, 0.81380486, -0.378907],
-    [-0.40777215, 0.7826506, -0.4702976],
-    [-0.42039618, 0.8039072, -0.420714],
-    [-0.378907, 0.81380486, -0.44062606],
-    [-0.40288505, 0.8218073, -0.402885],
-    [-0.42589802, 0.798189, -0.42603433],
-    [-0.4851146, 0.8036992, -0.34457406],
-    [-0.4979435, 0.8105078, -0.3084306],
-    [-0.45369685, 0.8217146, -0.344883],
-    [-0.4743411, 0.83276856, -0.28547674],
-    [-0.4070137, 0.8457808, -0.3449563],
-    [-0.40844816, 0.86655223, -0.28680548],
-    [-0.45498008, 0.8311822, -0.3195767],
-    [-0.38822573, 0.88146305, -0.26889327],
-    [-0.3882258, 0.88146317, -0.2688933],
-    [-0.34457406, 0.8036992, -0.4851146],
-    [-0.344883, 0.8217146, -0.45369685],
-    [-0.3449563, 0.8457808, -0.40701374],
-    [-0.3084306, 0.8105078, -0.4979435],
-    [-0.28547677, 0.83276856, -0.47434115],
-    [-0.28682917, 0.8666205, -0.4082866],
-    [-0.31958112, 0.8311948, -0.4549538],
-    [-0.34950918, 0.86938053, -0.34931475],
-    [-0.37338692, 0.8743126, -0.3100962],
-    [-0.3104197, 0.87433016, -0.37307698],
-    [-0.34467193, 0.87326556, -0.34439573],
-    [-0.26893088, 0.8815713, -0.38795397],
-    [-0.26893088, 0.8815713, -0.38795397],
-    [-0.33201787, 0.8830307, -0.33169386],
-    [-0.34935254, 0.8921737, -0.28631973],
-    [-0.28651404, 0.8921842, -0.34916633],
-    [-0.28164735, 0.91725105, -0.28164735],
-    [-0.31267095, 0.89698607, -0.3124946],
-    [-0.36085558, 0.86002004, -0.3607614],
-    [-0.46501538, 0.8584523, -0.21637973],
-    [-0.47899362, 0.86374426, -0.15655987],
-    [-0.43018106, 0.87149704, -0.23545098],
-    [-0.45842555, 0.8652482, -0.20295721],
-    [-0.4875762, 0.868002, -0.09403111],
-    [-0.49167028, 0.8702115, -0.0315014],
-    [-0.48986408, 0.86953425, -0.06279713],
-    [-0.44064656, 0.8765209, -0.19375719],
-    [-0.44760585, 0.88041097, -0.15660593],
-    [-0.40824753, 0.88679457, -0.21663171],
-    [-0.4070473, 0.89986444, -0.15670548],
-    [-0.4261152, 0.8863728, -0.18102211],
-    [-0.45618913, 0.88492364, -0.09381682],
-    [-0.46022323, 0.8872522, -0.031273916],
-    [-0.4070043, 0.90861434, -0.09363457],
-    [-0.40690488, 0.91294277, -0.03104798],
-    [-0.43296865, 0.89923966, -0.062499374],
-    [-0.34384704, 0.91308534, -0.21919031],
-    [-0.3403962, 0.9273693, -0.15529534],
-    [-0.31703112, 0.93901, -0.13323525],
-    [-0.2835968, 0.9325484, -0.22344194],
-    [-0.30598453, 0.9357656, -0.17525989],
-    [-0.2645109, 0.9422029, -0.20563965],
-    [-0.28434896, 0.9460654, -0.15526031],
-    [-0.30597225, 0.93463314, -0.18122295],
-    [-0.35431355, 0.93040735, -0.09383008],
-    [-0.36006898, 0.9324014, -0.031270664],
-    [-0.32312563, 0.9416977, -0.0937832],
-    [-0.32900846, 0.9438091, -0.031269234],
-    [-0.28204095, 0.9548013, -0.093847886],
-    [-0.28189343, 0.95893526, -0.03129361],
-    [-0.32207158, 0.9446425, -0.06261321],
-    [-0.40786266, 0.9042902, -0.1261247],
-    [-0.21637973, 0.8584523, -0.46501544],
-    [-0.23545098, 0.87149704, -0.43018106],
-    [-0.15655987, 0.86374426, -0.47899362],
-    [-0.20295721, 0.8652482, -0.45842555],
-    [-0.19375719, 0.8765209, -0.44064656],
-    [-0.21663171, 0.88679457, -0.40824753],
-    [-0.15660593, 0.880411, -0.44760585],
-    [-0.15670548, 0.8998644, -0.40704724],
-    [-0.18102212, 0.8863728, -0.4261152],
-    [-0.21919033, 0.91308534, -0.34384707],
-    [-0.22344194, 0.9325484, -0.2835968],
-    [-0.20563965, 0.9422029, -0.2645109],
-    [-0.1553051, 0.9274302, -0.3402258],
-    [-0.17527439, 0.9358569, -0.30569702],
-    [-0.13325031, 0.9391045, -0.31674463],
-    [-0.15526845, 0.9461164, -0.28417525],
-    [-0.18122959, 0.93467486, -0.3058407],
-    [-0.09403111, 0.868002, -0.4875762],
-    [-0.0315014, 0.8702115, -0.49167028],
-    [-0.06279713, 0.86953425, -0.48986408],
-    [-0.09381681, 0.88492364, -0.45618913],
-    [-0.09363458, 0.90861446, -0.40700433],
-    [-0.031273916, 0.8872523, -0.4602232],
-    [-0.031047983, 0.91294277, -0.4069049],
-    [-0.062499363, 0.8992397, -0.43296868],
-    [-0.09383008, 0.93040735, -0.35431355],
-    [-0.0937832, 0.9416977, -0.32312563],
-    [-0.093847886, 0.9548013, -0.28204095],
-    [-0.031270664, 0.9324014, -0.36006898],
-    [-0.031269234, 0.9438091, -0.32900843],
-    [-0.03129361, 0.9589352, -0.28189343],
-    [-0.06261322, 0.9446425, -0.32207158],
-    [-0.1261261, 0.90429944, -0.4078416],
-    [-0.24690984, 0.93705446, -0.24690984],
-    [-0.22377947, 0.9486019, -0.22377947],
-    [-0.21970937, 0.96290356, -0.15666674],
-    [-0.15666673, 0.9629035, -0.21970935],
-    [-0.15667123, 0.9751453, -0.15667123],
-    [-0.20104268, 0.9587303, -0.20104268],
-    [-0.21948425, 0.97106814, -0.09409147],
-    [-0.21907741, 0.97519785, -0.031531706],
-    [-0.15668844, 0.983177, -0.09387083],
-    [-0.15668547, 0.9871526, -0.031294666],
-    [-0.18817203, 0.98012877, -0.062759906],
-    [-0.09409147, 0.9710682, -0.21948425],
-    [-0.09387083, 0.983177, -0.15668842],
-    [-0.03153171, 0.97519785, -0.21907744],
-    [-0.031294666, 0.9871526, -0.15668546],
-    [-0.06275991, 0.9801288, -0.18817203],
-    [-0.0936523, 0.9911904, -0.0936523],
-    [-0.09409099, 0.9950793, -0.031052338],
-    [-0.031052334, 0.9950792, -0.09409098],
-    [-0.03127881, 0.9990212, -0.03127881],
-    [-0.0625804, 0.99607605, -0.06258039],
-    [-0.12921645, 0.98316133, -0.12921643],
-    [-0.30616686, 0.90140903, -0.30614296],
-    [-0.57734746, 0.5773474, -0.57735586],
-    [-0.9990212, 0.03127881, 0.03127881],
-    [-0.9950792, 0.031052334, 0.09409098],
-    [-0.9950792, 0.09409098, 0.031052334],
-    [-0.9911904, 0.0936523, 0.0936523],
-    [-0.99607605, 0.06258039, 0.0625804],
-    [-0.98715264, 0.03129467, 0.15668549],
-    [-0.9751979, 0.03153171, 0.21907744],
-    [-0.98317695, 0.093870826, 0.1566884],
-    [-0.9710682, 0.09409147, 0.21948425],
-    [-0.9801288, 0.062759906, 0.18817203],
-    [-0.98715264, 0.15668547, 0.03129467],
-    [-0.983177, 0.15668844, 0.09387083],
-    [-0.9751979, 0.21907744, 0.03153171],
-    [-0.97106814, 0.21948425, 0.09409147],
-    [-0.9801288, 0.18817203, 0.06275991],
-    [-0.9751452, 0.15667123, 0.15667123],
-    [-0.9629035, 0.15666673, 0.21970935],
-    [-0.9629035, 0.21970937, 0.15666674],
-    [-0.9486019, 0.22377947, 0.22377947],
-    [-0.9370545, 0.24690987, 0.24690987],
-    [-0.9587303, 0.2010427, 0.2010427],
-    [-0.98316133, 0.12921645, 0.12921645],
-    [-0.9589352, 0.03129361, 0.28189346],
-    [-0.9438091, 0.031269234, 0.32900843],
-    [-0.9548013, 0.09384788, 0.28204095],
-    [-0.9416977, 0.0937832, 0.32312566],
-    [-0.9324015, 0.031270668, 0.36006904],
-    [-0.9304073, 0.09383008, 0.3543135],
-    [-0.9446425, 0.06261321, 0.32207158],
-    [-0.91294277, 0.031047983, 0.4069049],
-    [-0.8872523, 0.031273916, 0.4602232],
-    [-0.90861446, 0.09363459, 0.40700433],
-    [-0.88492364, 0.09381682, 0.45618913],
-    [-0.8992397, 0.06249937, 0.43296868],
-    [-0.9461164, 0.15526843, 0.28417525],
-    [-0.9391045, 0.13325031, 0.31674463],
-    [-0.9422029, 0.20563965, 0.26451087],
-    [-0.9358569, 0.17527439, 0.30569702],
-    [-0.9274302, 0.15530512, 0.3402258],
-    [-0.9325484, 0.22344194, 0.2835968],
-    [-0.91308534, 0.21919031, 0.34384707],
-    [-0.93467486, 0.18122958, 0.30584067],
-    [-0.8998644, 0.15670547, 0.40704724],
-    [-0.880411, 0.15660591, 0.44760588],
-    [-0.88679457, 0.21663174, 0.40824756],
-    [-0.8765209, 0.19375718, 0.44064656],
-    [-0.8863728, 0.18102211, 0.4261152],
-    [-0.8702115, 0.031501405, 0.49167034],
-    [-0.86800206, 0.09403111, 0.4875762],
-    [-0.86953425, 0.06279713, 0.48986408],
-    [-0.8637442, 0.15655988, 0.47899368],
-    [-0.8714971, 0.23545098, 0.43018106],
-    [-0.8584523, 0.21637973, 0.46501538],
-    [-0.8652482, 0.20295721, 0.45842555],
-    [-0.90429944, 0.12612613, 0.40784165],
-    [-0.9589352, 0.28189346, 0.03129361],
-    [-0.9548013, 0.28204095, 0.09384788],
-    [-0.9438091, 0.3290084, 0.031269234],
-    [-0.9416977, 0.32312563, 0.0937832],
-    [-0.9324014, 0.360069, 0.031270664],
-    [-0.9304073, 0.3543135, 0.09383008],
-    [-0.9446425, 0.32207155, 0.06261321],
-    [-0.9460654, 0.28434896, 0.15526032],
-    [-0.9422029, 0.26451087, 0.20563968],
-    [-0.93901, 0.31703112, 0.13323525],
-    [-0.9357656, 0.30598453, 0.17525989],
-    [-0.9325484, 0.2835968, 0.22344194],
-    [-0.9273693, 0.3403962, 0.15529533],
-    [-0.91308534, 0.34384704, 0.21919031],
-    [-0.9346332, 0.30597225, 0.18122298],
-    [-0.91294277, 0.4069049, 0.031047983],
-    [-0.90861446, 0.40700433, 0.09363459],
-    [-0.8872522, 0.4602232, 0.031273916],
-    [-0.8849237, 0.4561892, 0.09381683],
-    [-0.89923966, 0.43296868, 0.062499374],
-    [-0.8998644, 0.40704724, 0.15670547],
-    [-0.88679457, 0.40824753, 0.21663174],
-    [-0.880411, 0.44760588, 0.15660593],
-    [-0.8765209, 0.44064656, 0.19375718],
-    [-0.8863728, 0.42611519, 0.18102211],
-    [-0.8702115, 0.49167037, 0.031501405],
-    [-0.86800206, 0.48757622, 0.09403112],
-    [-0.86953413, 0.48986408, 0.06279713],
-    [-0.8714971, 0.43018106, 0.23545098],
-    [-0.86374426, 0.47899368, 0.15655987],
-    [-0.8584523, 0.46501538, 0.21637976],
-    [-0.8652482, 0.45842555, 0.20295723],
-    [-0.9042902, 0.4078627, 0.1261247],
-    [-0.91725105, 0.28164735, 0.28164735],
-    [-0.8921842, 0.286514, 0.3491663],
-    [-0.8921737, 0.34935254, 0.28631976],
-    [-0.8830307, 0.3320178, 0.33169383],
-    [-0.8969861, 0.31267095, 0.31249464],
-    [-0.8815712, 0.26893088, 0.38795394],
-    [-0.8815713, 0.2689309, 0.387954],
-    [-0.88146305, 0.38822573, 0.2688933],
-    [-0.88146317, 0.3882258, 0.26889333],
-    [-0.87433016, 0.3104197, 0.37307698],
-    [-0.8743127, 0.37338692, 0.3100962],
-    [-0.86938053, 0.34950918, 0.34931475],
-    [-0.87326556, 0.34467193, 0.34439573],
-    [-0.8666205, 0.28682917, 0.4082866],
-    [-0.83276856, 0.28547677, 0.47434115],
-    [-0.8457808, 0.34495628, 0.4070137],
-    [-0.82171464, 0.34488308, 0.45369688],
-    [-0.8105078, 0.3084306, 0.4979435],
-    [-0.8036993, 0.3445741, 0.4851146],
-    [-0.8311948, 0.31958112, 0.4549538],
-    [-0.86655223, 0.40844816, 0.28680548],
-    [-0.8457808, 0.4070137, 0.34495628],
-    [-0.83276856, 0.47434115, 0.2854768],
-    [-0.8217146, 0.45369685, 0.344883],
-    [-0.8105078, 0.4979435, 0.3084306],
-    [-0.8036992, 0.48511457, 0.3445741],
-    [-0.8311822, 0.45498008, 0.31957668],
-    [-0.8218073, 0.402885, 0.402885],
-    [-0.81380486, 0.378907, 0.44062606],
-    [-0.81380486, 0.44062606, 0.378907],
-    [-0.80390716, 0.42039615, 0.420714],
-    [-0.78265065, 0.40777215, 0.4702976],
-    [-0.7826584, 0.47014332, 0.40793523],
-    [-0.7625566, 0.45728362, 0.45760158],
-    [-0.798189, 0.425898, 0.42603424],
-    [-0.86002004, 0.36085558, 0.3607614],
-    [-0.7492584, 0.43595585, 0.4985524],
-    [-0.7492584, 0.4985524, 0.43595585],
-    [-0.7412505, 0.47463006, 0.47463012],
-    [-0.74709153, 0.47002885, 0.4700289],
-    [-0.74709153, 0.47002885, 0.4700289],
-    [-0.90140903, 0.30616686, 0.306143],
-    [-0.8458994, 0.03151556, 0.5324105],
-    [-0.81944174, 0.031489953, 0.5722968],
-    [-0.84120214, 0.094080195, 0.53247327],
-    [-0.81684524, 0.094032906, 0.56914115],
-    [-0.79665226, 0.03125978, 0.60362905],
-    [-0.79420394, 0.093822606, 0.6003643],
-    [-0.8199343, 0.062768556, 0.56900597],
-    [-0.7654031, 0.031009154, 0.6428037],
-    [-0.7628616, 0.09351147, 0.63976383],
-    [-0.7645073, 0.06229086, 0.6415984],
-    [-0.8295634, 0.15528637, 0.53638667],
-    [-0.81409216, 0.13335358, 0.5652176],
-    [-0.82522035, 0.21932015, 0.52049017],
-    [-0.8101897, 0.17501052, 0.55943173],
-    [-0.79262763, 0.1549694, 0.5896828],
-    [-0.8048269, 0.21910737, 0.5515847],
-    [-0.7712661, 0.21786653, 0.5980658],
-    [-0.8076838, 0.18232408, 0.56071794],
-    [-0.75760466, 0.1560054, 0.63379604],
-    [-0.75287986, 0.19616966, 0.6282432],
-    [-0.7553996, 0.17612422, 0.6311511],
-    [-0.7890399, 0.12120853, 0.6022661],
-    [-0.81792694, 0.26691833, 0.50965697],
-    [-0.7976628, 0.28548548, 0.5312552],
-    [-0.7642177, 0.28145143, 0.58030725],
-    [-0.77153325, 0.34500876, 0.5345142],
-    [-0.75552785, 0.32420805, 0.5692685],
-    [-0.78217995, 0.3009246, 0.5455628],
-    [-0.7608078, 0.3947095, 0.5151464],
-    [-0.76080793, 0.39470956, 0.51514643],
-    [-0.7724773, 0.34826043, 0.53103065],
-    [-0.73788226, 0.031237818, 0.67420614],
-    [-0.7067562, 0.031487603, 0.7067562],
-    [-0.735096, 0.093978785, 0.67141783],
-    [-0.7039446, 0.09446676, 0.7039446],
-    [-0.67420614, 0.031237818, 0.73788226],
-    [-0.6714177, 0.09397876, 0.735096],
-    [-0.7057109, 0.06280486, 0.7057109],
-    [-0.7471068, 0.23801026, 0.62063086],
-    [-0.7471068, 0.23801026, 0.62063086],
-    [-0.72985584, 0.1564547, 0.66545653],
-    [-0.6983523, 0.1568703, 0.6983523],
-    [-0.72028553, 0.22027145, 0.6577759],
-    [-0.6924799, 0.20234467, 0.6924799],
-    [-0.6654565, 0.15645471, 0.72985584],
-    [-0.68578, 0.24374485, 0.68578],
-    [-0.6577759, 0.22027145, 0.72028565],
-    [-0.6936726, 0.19400176, 0.6936726],
-    [-0.62063086, 0.23801026, 0.7471068],
-    [-0.62063086, 0.23801026, 0.7471068],
-    [-0.695025, 0.18406612, 0.6950251],
-    [-0.6428037, 0.031009154, 0.7654031],
-    [-0.63976383, 0.09351147, 0.7628616],
-    [-0.6415984, 0.06229086, 0.7645073],
-    [-0.63379604, 0.15600538, 0.7576047],
-    [-0.6282432, 0.19616966, 0.75287986],
-    [-0.631151, 0.1761242, 0.7553996],
-    [-0.603629, 0.03125978, 0.79665226],
-    [-0.57229674, 0.031489953, 0.81944174],
-    [-0.6003643, 0.093822606, 0.79420394],
-    [-0.56914115, 0.094032906, 0.8168452],
-    [-0.53241056, 0.03151556, 0.8458994],
-    [-0.53247327, 0.094080195, 0.84120214],
-    [-0.56900597, 0.06276856, 0.81993437],
-    [-0.58972514, 0.15492976, 0.79260385],
-    [-0.5652887, 0.13328779, 0.8140535],
-    [-0.59806585, 0.21786655, 0.77126604],
-    [-0.55950457, 0.17494518, 0.81015354],
-    [-0.5364308, 0.15524723, 0.82954216],
-    [-0.5516121, 0.21888196, 0.8048694],
-    [-0.5205178, 0.21909471, 0.8252628],
-    [-0.5607578, 0.18222925, 0.8076775],
-    [-0.6022749, 0.121184535, 0.7890369],
-    [-0.739426, 0.28137675, 0.61161774],
-    [-0.7459645, 0.36566997, 0.55661696],
-    [-0.722172, 0.3488397, 0.5973092],
-    [-0.6867571, 0.37309206, 0.62383264],
-    [-0.724479, 0.3426699, 0.5980864],
-    [-0.70824456, 0.2814848, 0.6474225],
-    [-0.6975285, 0.33159938, 0.63521314],
-    [-0.6785289, 0.28141975, 0.67852885],
-    [-0.6474225, 0.2814848, 0.70824456],
-    [-0.66180485, 0.35277322, 0.66148716],
-    [-0.63554347, 0.33160666, 0.69722414],
-    [-0.67225814, 0.31040588, 0.6720992],
-    [-0.7380218, 0.4119511, 0.5344343],
-    [-0.70405823, 0.40663823, 0.5821919],
-    [-0.70701736, 0.4673896, 0.5307293],
-    [-0.6923032, 0.44491085, 0.56812906],
-    [-0.6770918, 0.40687314, 0.6131893],
-    [-0.67813694, 0.48661035, 0.5507637],
-    [-0.65583116, 0.4674735, 0.59275126],
-    [-0.69402945, 0.44221535, 0.5681274],
-    [-0.6458738, 0.40705565, 0.6458738],
-    [-0.63244194, 0.44770122, 0.63212395],
-    [-0.6393195, 0.42748648, 0.63916045],
-    [-0.6116178, 0.28137675, 0.739426],
-    [-0.62416285, 0.37309936, 0.6864529],
-    [-0.59748036, 0.34884682, 0.722027],
-    [-0.55680907, 0.36548474, 0.74591184],
-    [-0.5982611, 0.34262776, 0.72435474],
-    [-0.61688745, 0.48917478, 0.6165694],
-    [-0.6168875, 0.48917484, 0.61656946],
-    [-0.6133388, 0.4067483, 0.6770312],
-    [-0.5823454, 0.40651593, 0.70400196],
-    [-0.5929141, 0.46744078, 0.6557072],
-    [-0.56812227, 0.44483417, 0.69235796],
-    [-0.5344142, 0.41188684, 0.7380723],
-    [-0.55073935, 0.48655805, 0.6781944],
-    [-0.5307137, 0.4673574, 0.7070502],
-    [-0.56818676, 0.44214487, 0.6940257],
-    [-0.647725, 0.4013604, 0.64758176],
-    [-0.5803449, 0.28123412, 0.764269],
-    [-0.5693265, 0.3239224, 0.7556068],
-    [-0.5311748, 0.28516495, 0.797831],
-    [-0.5094646, 0.26666993, 0.8181278],
-    [-0.53466713, 0.34472784, 0.7715529],
-    [-0.5455595, 0.30065462, 0.7822861],
-    [-0.5151102, 0.39460042, 0.76088905],
-    [-0.5151102, 0.39460042, 0.76088905],
-    [-0.531013, 0.34807205, 0.7725743],
-    [-0.6831899, 0.25789997, 0.683183],
-    [-0.8458994, 0.53241056, 0.03151556],
-    [-0.84120214, 0.53247327, 0.094080195],
-    [-0.81944174, 0.57229674, 0.031489953],
-    [-0.8168453, 0.56914115, 0.094032906],
-    [-0.79665226, 0.60362905, 0.03125978],
-    [-0.79420394, 0.6003643, 0.093822606],
-    [-0.8199343, 0.56900597, 0.062768556],
-    [-0.8293967, 0.5366531, 0.15525565],
-    [-0.82522047, 0.52049017, 0.21932018],
-    [-0.8137997, 0.56565136, 0.13329963],
-    [-0.80990016, 0.5598676, 0.17495714],
-    [-0.8048269, 0.5515847, 0.21910737],
-    [-0.7924223, 0.5899191, 0.15512083],
-    [-0.77106327, 0.5982786, 0.21800068],
-    [-0.8075206, 0.56094605, 0.18234566],
-    [-0.76540303, 0.6428036, 0.03100915],
-    [-0.76286167, 0.63976383, 0.09351146],
-    [-0.7645073, 0.6415984, 0.062290855],
-    [-0.7575485, 0.6337491, 0.15646799],
-    [-0.7524859, 0.6285504, 0.19669628],
-    [-0.75517493, 0.6312817, 0.17661902],
-    [-0.7889482, 0.6023601, 0.12133882],
-    [-0.81792694, 0.50965697, 0.2669183],
-    [-0.7976628, 0.5312552, 0.28548548],
-    [-0.77153325, 0.5345115, 0.3450128],
-    [-0.7642176, 0.58030725, 0.28145143],
-    [-0.75566536, 0.5691334, 0.32412475],
-    [-0.78220624, 0.5455343, 0.30090824],
-    [-0.76067156, 0.51527786, 0.39480042],
-    [-0.7606717, 0.5152779, 0.39480048],
-    [-0.77242386, 0.5310833, 0.3482985],
-    [-0.74675465, 0.6209723, 0.23822431],
-    [-0.7467547, 0.62097234, 0.23822434],
-    [-0.73788226, 0.67420614, 0.031237818],
-    [-0.735096, 0.67141783, 0.093978785],
-    [-0.7067562, 0.7067562, 0.031487603],
-    [-0.7039447, 0.7039447, 0.09446677],
-    [-0.67420614, 0.73788226, 0.031237818],
-    [-0.6714177, 0.735096, 0.09397876],
-    [-0.7057108, 0.7057108, 0.062804855],
-    [-0.7298277, 0.665433, 0.15668592],
-    [-0.72007656, 0.65791845, 0.22052875],
-    [-0.6983523, 0.6983523, 0.1568703],
-    [-0.6924799, 0.6924799, 0.20234467],
-    [-0.68578, 0.68578, 0.24374485],
-    [-0.665433, 0.72982764, 0.15668593],
-    [-0.65791845, 0.72007656, 0.22052874],
-    [-0.69365317, 0.69365317, 0.19414084],
-    [-0.6209723, 0.7467547, 0.23822434],
-    [-0.62097234, 0.74675477, 0.23822437],
-    [-0.6950065, 0.69500655, 0.18420568],
-    [-0.74587893, 0.5565471, 0.36595076],
-    [-0.7393764, 0.6115759, 0.28159812],
-    [-0.72213846, 0.59731305, 0.34890276],
-    [-0.6866207, 0.6240265, 0.37301892],
-    [-0.7244028, 0.59810805, 0.34279317],
-    [-0.73787886, 0.534467, 0.41216493],
-    [-0.70701736, 0.5307293, 0.4673896],
-    [-0.7038641, 0.58223426, 0.4069135],
-    [-0.69213676, 0.56825644, 0.44500726],
-    [-0.67813694, 0.5507637, 0.48661032],
-    [-0.6769644, 0.61328274, 0.406944],
-    [-0.6557199, 0.5929463, 0.46738222],
-    [-0.69392186, 0.56819654, 0.4422953],
-    [-0.70819503, 0.64738035, 0.28170618],
-    [-0.6973894, 0.63540477, 0.33152497],
-    [-0.6785289, 0.67852885, 0.28141975],
-    [-0.66166675, 0.66166675, 0.35269552],
-    [-0.64738035, 0.70819515, 0.28170624],
-    [-0.63540477, 0.6973893, 0.33152494],
-    [-0.6721708, 0.6721708, 0.31043956],
-    [-0.6458738, 0.6458738, 0.40705562],
-    [-0.6323825, 0.6323825, 0.44741994],
-    [-0.6392875, 0.6392875, 0.42734426],
-    [-0.61683726, 0.61683726, 0.48890042],
-    [-0.61683726, 0.61683726, 0.48890042],
-    [-0.6240265, 0.6866207, 0.37301892],
-    [-0.6115759, 0.7393764, 0.28159812],
-    [-0.59731305, 0.72213846, 0.34890276],
-    [-0.5565471, 0.74587893, 0.36595076],
-    [-0.59810805, 0.7244028, 0.34279317],
-    [-0.61328274, 0.6769644, 0.40694398],
-    [-0.5929463, 0.6557199, 0.46738228],
-    [-0.58223426, 0.70386404, 0.40691346],
-    [-0.56825644, 0.69213676, 0.44500726],
-    [-0.5507637, 0.67813694, 0.48661032],
-    [-0.5344669, 0.73787886, 0.41216496],
-    [-0.5307293, 0.70701736, 0.4673896],
-    [-0.56819654, 0.69392186, 0.44229534],
-    [-0.64764833, 0.64764833, 0.40137666],
-    [-0.64280367, 0.7654031, 0.031009154],
-    [-0.63976383, 0.76286167, 0.09351146],
-    [-0.6415984, 0.7645073, 0.062290855],
-    [-0.6337491, 0.7575486, 0.156468],
-    [-0.6285504, 0.7524859, 0.19669631],
-    [-0.6312816, 0.75517493, 0.17661904],
-    [-0.603629, 0.79665226, 0.03125978],
-    [-0.6003643, 0.79420394, 0.093822606],
-    [-0.57229674, 0.81944174, 0.031489953],
-    [-0.56914115, 0.81684524, 0.094032906],
-    [-0.5324105, 0.8458994, 0.03151556],
-    [-0.53247327, 0.84120214, 0.094080195],
-    [-0.56900597, 0.81993437, 0.06276856],
-    [-0.5899191, 0.7924223, 0.15512083],
-    [-0.5982785, 0.77106327, 0.21800068],
-    [-0.56565136, 0.8137997, 0.13329963],
-    [-0.5598676, 0.80990016, 0.17495714],
-    [-0.5515847, 0.8048269, 0.21910739],
-    [-0.5366531, 0.8293967, 0.15525566],
-    [-0.52049017, 0.82522047, 0.21932018],
-    [-0.56094605, 0.8075206, 0.18234566],
-    [-0.6023601, 0.7889482, 0.12133884],
-    [-0.58030725, 0.7642177, 0.28145143],
-    [-0.5691334, 0.75566536, 0.32412472],
-    [-0.5312552, 0.7976628, 0.28548548],
-    [-0.5345115, 0.77153325, 0.3450128],
-    [-0.50965697, 0.81792694, 0.26691833],
-    [-0.54553425, 0.78220624, 0.30090824],
-    [-0.515278, 0.76067156, 0.39480042],
-    [-0.515278, 0.76067156, 0.39480042],
-    [-0.53108335, 0.7724239, 0.34829846],
-    [-0.6831648, 0.68316466, 0.25801513],
-    [-0.6943562, 0.5088563, 0.5088563],
-    [-0.6576295, 0.5326919, 0.5326941],
-    [-0.63638526, 0.5137616, 0.5753806],
-    [-0.63657665, 0.57526654, 0.51365227],
-    [-0.6568716, 0.5331593, 0.53316104],
-    [-0.6185422, 0.55560803, 0.55561256],
-    [-0.59742147, 0.5349535, 0.59742147],
-    [-0.5974675, 0.5974675, 0.53485054],
-    [-0.57735026, 0.57735026, 0.57735026],
-    [-0.5557188, 0.5557188, 0.6183473],
-    [-0.55560803, 0.61854213, 0.55561256],
-    [-0.5349534, 0.59742147, 0.5974215],
-    [-0.5773715, 0.5773715, 0.5773078],
-    [-0.5753806, 0.5137616, 0.63638526],
-    [-0.5327861, 0.5327861, 0.6574786],
-    [-0.5089309, 0.5089309, 0.69424677],
-    [-0.5137616, 0.5753806, 0.63638526],
-    [-0.53322995, 0.53322995, 0.6567584],
-    [-0.57526654, 0.63657665, 0.51365227],
-    [-0.53269184, 0.65762943, 0.5326941],
-    [-0.5137616, 0.63638526, 0.5753806],
-    [-0.5088563, 0.6943562, 0.5088563],
-    [-0.5331593, 0.65687174, 0.5331611],
-    [-0.5773707, 0.5773707, 0.5773094],
-    [-0.5773707, 0.5773707, 0.5773094],
-    [-0.49167034, 0.031501405, 0.8702115],
-    [-0.4875762, 0.09403111, 0.86800206],
-    [-0.48986408, 0.06279713, 0.86953425],
-    [-0.46022323, 0.031273916, 0.8872522],
-    [-0.45618913, 0.09381682, 0.88492364],
-    [-0.4069049, 0.031047983, 0.91294277],
-    [-0.40700433, 0.09363459, 0.90861446],
-    [-0.43296868, 0.06249937, 0.89923966],
-    [-0.47899368, 0.15655988, 0.8637442],
-    [-0.46501538, 0.21637973, 0.8584523],
-    [-0.430212, 0.2351516, 0.8715626],
-    [-0.45843437, 0.20285669, 0.8652671],
-    [-0.44760585, 0.15660593, 0.88041097],
-    [-0.44064656, 0.19375718, 0.8765209],
-    [-0.40704724, 0.15670547, 0.8998644],
-    [-0.4082647, 0.21645129, 0.88683075],
-    [-0.42611876, 0.18097666, 0.8863803],
-    [-0.36006904, 0.031270668, 0.9324015],
-    [-0.32900846, 0.031269234, 0.9438091],
-    [-0.3543135, 0.09383008, 0.9304073],
-    [-0.32312563, 0.0937832, 0.9416977],
-    [-0.28189346, 0.03129361, 0.9589352],
-    [-0.28204095, 0.09384788, 0.9548013],
-    [-0.32207158, 0.06261321, 0.9446425],
-    [-0.3402258, 0.15530512, 0.9274302],
-    [-0.31674463, 0.13325031, 0.9391045],
-    [-0.34384704, 0.21919031, 0.91308534],
-    [-0.30569702, 0.17527439, 0.9358569],
-    [-0.28417525, 0.15526843, 0.9461164],
-    [-0.2835968, 0.22344194, 0.9325484],
-    [-0.26451087, 0.20563965, 0.9422029],
-    [-0.30584067, 0.18122958, 0.93467486],
-    [-0.40784302, 0.12610145, 0.9043023],
-    [-0.4983138, 0.43602434, 0.7493772],
-    [-0.47463012, 0.47463006, 0.7412505],
-    [-0.43602434, 0.4983138, 0.7493772],
-    [-0.4699694, 0.4699694, 0.74716645],
-    [-0.4699694, 0.4699694, 0.74716645],
-    [-0.49770465, 0.30847806, 0.81063646],
-    [-0.47419563, 0.285504, 0.8328421],
-    [-0.48515105, 0.3443664, 0.80376625],
-    [-0.45373356, 0.34467536, 0.8217815],
-    [-0.40845034, 0.28680262, 0.86655223],
-    [-0.4070137, 0.34495628, 0.8457808],
-    [-0.45492628, 0.3195178, 0.8312342],
-    [-0.38822904, 0.26888818, 0.8814633],
-    [-0.38822898, 0.26888815, 0.88146317],
-    [-0.4701225, 0.40775257, 0.78276604],
-    [-0.44056633, 0.3785344, 0.81401044],
-    [-0.45753765, 0.45753765, 0.7624424],
-    [-0.4204447, 0.42044467, 0.80402267],
-    [-0.40275577, 0.40275583, 0.82193404],
-    [-0.4077526, 0.47012255, 0.7827661],
-    [-0.3785345, 0.4405664, 0.8140105],
-    [-0.42585832, 0.42585835, 0.798304],
-    [-0.37331337, 0.31067127, 0.8741399],
-    [-0.34948468, 0.34948468, 0.8693221],
-    [-0.3106713, 0.37331337, 0.8741399],
-    [-0.3447205, 0.34472048, 0.87311834],
-    [-0.3493115, 0.28666767, 0.8920779],
-    [-0.33197904, 0.33197904, 0.88293827],
-    [-0.28164735, 0.28164735, 0.91725105],
-    [-0.28666767, 0.3493115, 0.8920779],
-    [-0.31268921, 0.31268921, 0.896912],
-    [-0.34495628, 0.4070137, 0.8457808],
-    [-0.34436643, 0.48515105, 0.80376625],
-    [-0.34467536, 0.45373356, 0.8217815],
-    [-0.2868026, 0.40845034, 0.86655223],
-    [-0.30847806, 0.49770465, 0.81063646],
-    [-0.28550404, 0.47419563, 0.8328421],
-    [-0.31951776, 0.45492628, 0.8312342],
-    [-0.26888812, 0.38822898, 0.8814632],
-    [-0.26888812, 0.38822898, 0.8814632],
-    [-0.36083505, 0.36083505, 0.8599978],
-    [-0.21907744, 0.03153171, 0.9751979],
-    [-0.21948425, 0.09409147, 0.97106814],
-    [-0.15668547, 0.03129467, 0.98715264],
-    [-0.15668844, 0.09387083, 0.983177],
-    [-0.18817203, 0.06275991, 0.9801288],
-    [-0.21970937, 0.15666674, 0.9629035],
-    [-0.24690987, 0.24690987, 0.9370545],
-    [-0.22377947, 0.22377947, 0.9486019],
-    [-0.15667123, 0.15667123, 0.9751452],
-    [-0.15666673, 0.21970935, 0.9629035],
-    [-0.20104268, 0.2010427, 0.9587303],
-    [-0.09409098, 0.031052334, 0.9950792],
-    [-0.0936523, 0.0936523, 0.9911904],
-    [-0.03127881, 0.03127881, 0.9990212],
-    [-0.031052334, 0.09409098, 0.9950792],
-    [-0.06258039, 0.0625804, 0.99607605],
-    [-0.093870826, 0.1566884, 0.98317695],
-    [-0.09409147, 0.21948425, 0.9710682],
-    [-0.03129467, 0.15668549, 0.98715264],
-    [-0.03153171, 0.21907744, 0.9751979],
-    [-0.06275991, 0.18817203, 0.98012877],
-    [-0.12921645, 0.12921645, 0.98316133],
-    [-0.22344194, 0.2835968, 0.9325484],
-    [-0.20563968, 0.26451087, 0.9422029],
-    [-0.21919031, 0.34384707, 0.91308534],
-    [-0.17527437, 0.30569702, 0.9358569],
-    [-0.15526842, 0.28417522, 0.9461163],
-    [-0.1553051, 0.34022576, 0.9274302],
-    [-0.13325031, 0.31674463, 0.9391045],
-    [-0.18122959, 0.30584067, 0.93467486],
-    [-0.23515163, 0.430212, 0.8715626],
-    [-0.21637976, 0.46501538, 0.8584523],
-    [-0.15655987, 0.47899368, 0.86374426],
-    [-0.2028567, 0.45843437, 0.8652671],
-    [-0.21645129, 0.4082647, 0.88683075],
-    [-0.19375718, 0.44064656, 0.8765209],
-    [-0.15670547, 0.40704724, 0.8998644],
-    [-0.15660591, 0.44760588, 0.880411],
-    [-0.18097666, 0.42611873, 0.8863804],
-    [-0.09384788, 0.28204095, 0.9548013],
-    [-0.09383008, 0.3543135, 0.9304073],
-    [-0.0937832, 0.32312566, 0.9416977],
-    [-0.03129361, 0.28189346, 0.9589352],
-    [-0.031270664, 0.360069, 0.9324014],
-    [-0.031269234, 0.32900843, 0.9438091],
-    [-0.06261321, 0.32207158, 0.9446425],
-    [-0.09403112, 0.48757622, 0.86800206],
-    [-0.031501405, 0.49167037, 0.8702115],
-    [-0.06279714, 0.48986414, 0.86953425],
-    [-0.09363459, 0.40700433, 0.90861446],
-    [-0.09381681, 0.45618913, 0.88492364],
-    [-0.031047983, 0.4069049, 0.91294277],
-    [-0.031273916, 0.4602232, 0.8872523],
-    [-0.06249937, 0.43296865, 0.8992397],
-    [-0.12610146, 0.40784296, 0.90430224],
-    [-0.30614012, 0.3061401, 0.9014191],
-    [-0.4985524, 0.7492584, 0.43595585],
-    [-0.47463012, 0.7412505, 0.47463006],
-    [-0.43595585, 0.7492584, 0.4985524],
-    [-0.4700289, 0.74709153, 0.47002885],
-    [-0.4700289, 0.74709153, 0.47002885],
-    [-0.49167034, 0.8702115, 0.031501405],
-    [-0.4875762, 0.86800206, 0.09403111],
-    [-0.48986408, 0.86953425, 0.06279713],
-    [-0.47899368, 0.86374426, 0.15655987],
-    [-0.46501538, 0.8584523, 0.21637973],
-    [-0.43018106, 0.87149704, 0.23545098],
-    [-0.45842555, 0.8652482, 0.20295721],
-    [-0.4602232, 0.8872522, 0.031273916],
-    [-0.45618913, 0.88492364, 0.09381681],
-    [-0.4069049, 0.91294277, 0.031047983],
-    [-0.40700433, 0.90861446, 0.09363459],
-    [-0.43296868, 0.89923966, 0.06249937],
-    [-0.44760585, 0.880411, 0.15660593],
-    [-0.44064656, 0.8765209, 0.19375719],
-    [-0.40704724, 0.8998644, 0.15670547],
-    [-0.40824753, 0.88679457, 0.21663171],
-    [-0.4261152, 0.8863728, 0.18102211],
-    [-0.36006904, 0.9324015, 0.031270668],
-    [-0.35431355, 0.93040735, 0.09383009],
-    [-0.32900843, 0.9438091, 0.031269234],
-    [-0.32312563, 0.9416977, 0.0937832],
-    [-0.28189346, 0.9589352, 0.03129361],
-    [-0.28204095, 0.9548013, 0.09384788],
-    [-0.32207158, 0.9446425, 0.06261321],
-    [-0.3403962, 0.9273693, 0.15529533],
-    [-0.34384707, 0.91308534, 0.21919031],
-    [-0.31703112, 0.93901, 0.13323525],
-    [-0.30598453, 0.9357656, 0.17525989],
-    [-0.2835968, 0.9325484, 0.22344194],
-    [-0.28434896, 0.9460654, 0.15526031],
-    [-0.2645109, 0.9422029, 0.20563965],
-    [-0.30597225, 0.93463314, 0.18122295],
-    [-0.4078627, 0.90429014, 0.1261247],
-    [-0.4979435, 0.8105078, 0.3084306],
-    [-0.4851146, 0.8036993, 0.3445741],
-    [-0.47434115, 0.83276856, 0.28547677],
-    [-0.45369685, 0.8217146, 0.344883],
-    [-0.40844816, 0.86655223, 0.28680548],
-    [-0.4070137, 0.8457808, 0.34495628],
-    [-0.45498005, 0.83118224, 0.31957665],
-    [-0.47014332, 0.7826584, 0.40793523],
-    [-0.45728365, 0.7625565, 0.45760158],
-    [-0.44062606, 0.81380486, 0.378907],
-    [-0.42039618, 0.8039072, 0.420714],
-    [-0.40777215, 0.7826506, 0.47029766],
-    [-0.402885, 0.8218073, 0.402885],
-    [-0.378907, 0.81380486, 0.44062606],
-    [-0.42589802, 0.798189, 0.42603436],
-    [-0.38822573, 0.88146305, 0.26889327],
-    [-0.3882258, 0.88146317, 0.2688933],
-    [-0.37338692, 0.8743126, 0.3100962],
-    [-0.34950918, 0.86938053, 0.34931475],
-    [-0.3104197, 0.87433016, 0.37307698],
-    [-0.34467193, 0.87326556, 0.34439573],
-    [-0.3445741, 0.8036993, 0.4851146],
-    [-0.34495628, 0.8457808, 0.4070137],
-    [-0.344883, 0.8217146, 0.45369685],
-    [-0.3084306, 0.8105078, 0.4979435],
-    [-0.28682917, 0.8666205, 0.40828666],
-    [-0.28547677, 0.83276856, 0.47434115],
-    [-0.31958112, 0.8311948, 0.45495385],
-    [-0.3493525, 0.8921737, 0.2863197],
-    [-0.33201787, 0.8830307, 0.33169386],
-    [-0.28164735, 0.91725105, 0.28164735],
-    [-0.28651407, 0.8921842, 0.34916636],
-    [-0.31267092, 0.89698607, 0.31249464],
-    [-0.26893088, 0.8815713, 0.38795397],
-    [-0.26893088, 0.8815713, 0.38795397],
-    [-0.36085555, 0.86002004, 0.3607614],
-    [-0.21907744, 0.9751979, 0.03153171],
-    [-0.21948425, 0.9710682, 0.09409147],
-    [-0.15668549, 0.98715264, 0.03129467],
-    [-0.1566884, 0.98317695, 0.093870826],
-    [-0.18817203, 0.9801288, 0.062759906],
-    [-0.24690984, 0.93705446, 0.24690984],
-    [-0.21970935, 0.9629035, 0.15666673],
-    [-0.22377947, 0.9486019, 0.22377947],
-    [-0.15667123, 0.9751452, 0.15667123],
-    [-0.15666674, 0.9629035, 0.21970937],
-    [-0.20104268, 0.9587303, 0.20104268],
-    [-0.09409098, 0.9950792, 0.031052334],
-    [-0.0936523, 0.9911904, 0.0936523],
-    [-0.03127881, 0.9990212, 0.03127881],
-    [-0.031052334, 0.9950792, 0.09409098],
-    [-0.06258039, 0.99607605, 0.0625804],
-    [-0.09387083, 0.983177, 0.15668844],
-    [-0.09409147, 0.97106814, 0.21948425],
-    [-0.03129467, 0.98715264, 0.15668547],
-    [-0.03153171, 0.9751979, 0.21907744],
-    [-0.06275991, 0.98012877, 0.18817203],
-    [-0.12921645, 0.98316133, 0.12921645],
-    [-0.23545098, 0.87149704, 0.43018106],
-    [-0.21637973, 0.8584523, 0.46501538],
-    [-0.15655987, 0.86374426, 0.47899368],
-    [-0.20295721, 0.8652482, 0.45842555],
-    [-0.22344194, 0.9325484, 0.2835968],
-    [-0.21919031, 0.91308534, 0.34384704],
-    [-0.20563965, 0.9422029, 0.2645109],
-    [-0.17527439, 0.9358569, 0.30569702],
-    [-0.15530512, 0.9274302, 0.3402258],
-    [-0.15526843, 0.9461163, 0.28417522],
-    [-0.13325031, 0.9391045, 0.31674463],
-    [-0.18122958, 0.93467486, 0.30584067],
-    [-0.21663171, 0.88679457, 0.40824753],
-    [-0.19375719, 0.8765209, 0.44064656],
-    [-0.15670547, 0.8998644, 0.40704724],
-    [-0.15660593, 0.880411, 0.44760585],
-    [-0.18102211, 0.88637286, 0.42611519],
-    [-0.09403111, 0.86800206, 0.4875762],
-    [-0.031501405, 0.8702115, 0.49167034],
-    [-0.06279713, 0.86953425, 0.48986408],
-    [-0.09383009, 0.93040735, 0.35431355],
-    [-0.09384788, 0.9548013, 0.28204095],
-    [-0.0937832, 0.9416977, 0.32312563],
-    [-0.031270668, 0.9324015, 0.36006904],
-    [-0.03129361, 0.9589352, 0.28189346],
-    [-0.031269234, 0.9438091, 0.3290084],
-    [-0.06261322, 0.94464254, 0.32207158],
-    [-0.09363459, 0.90861446, 0.40700433],
-    [-0.09381681, 0.88492364, 0.45618913],
-    [-0.031047983, 0.91294277, 0.4069049],
-    [-0.031273916, 0.8872522, 0.46022323],
-    [-0.06249937, 0.8992397, 0.43296865],
-    [-0.1261261, 0.90429944, 0.4078416],
-    [-0.30616686, 0.90140903, 0.30614296],
-    [-0.48917478, 0.61688745, 0.6165694],
-    [-0.48917484, 0.6168875, 0.61656946],
-    [-0.48655805, 0.55073935, 0.6781944],
-    [-0.4673574, 0.53071374, 0.7070502],
-    [-0.4674408, 0.5929142, 0.6557072],
-    [-0.44483423, 0.5681224, 0.69235796],
-    [-0.4118869, 0.5344142, 0.7380723],
-    [-0.4067483, 0.6133388, 0.6770312],
-    [-0.40651593, 0.58234537, 0.70400196],
-    [-0.44214487, 0.5681868, 0.69402575],
-    [-0.48661032, 0.67813694, 0.5507637],
-    [-0.4674735, 0.6558311, 0.59275126],
-    [-0.4673896, 0.70701736, 0.5307293],
-    [-0.44491085, 0.6923032, 0.56812906],
-    [-0.40687314, 0.6770918, 0.6131893],
-    [-0.41195104, 0.7380218, 0.5344343],
-    [-0.4066382, 0.7040583, 0.5821919],
-    [-0.44221535, 0.6940294, 0.56812745],
-    [-0.44770122, 0.632442, 0.632124],
-    [-0.40705565, 0.6458738, 0.6458738],
-    [-0.42748648, 0.6393195, 0.63916045],
-    [-0.36548474, 0.55680907, 0.74591184],
-    [-0.37309933, 0.62416285, 0.6864529],
-    [-0.34884682, 0.59748036, 0.722027],
-    [-0.28137675, 0.61161774, 0.73942596],
-    [-0.3426278, 0.5982611, 0.7243548],
-    [-0.373092, 0.6867571, 0.6238326],
-    [-0.36566997, 0.7459645, 0.55661696],
-    [-0.3488397, 0.722172, 0.5973092],
-    [-0.28137675, 0.739426, 0.6116178],
-    [-0.3426699, 0.724479, 0.59808636],
-    [-0.3527732, 0.66180485, 0.6614872],
-    [-0.33160663, 0.6355435, 0.6972241],
-    [-0.33159938, 0.6975285, 0.63521326],
-    [-0.28141975, 0.67852885, 0.6785289],
-    [-0.2814848, 0.6474225, 0.70824456],
-    [-0.28148478, 0.70824444, 0.6474225],
-    [-0.31040585, 0.672258, 0.6720991],
-    [-0.4013604, 0.647725, 0.64758176],
-    [-0.39460036, 0.5151102, 0.760889],
-    [-0.39460036, 0.5151102, 0.760889],
-    [-0.34472787, 0.5346671, 0.77155286],
-    [-0.3239224, 0.5693265, 0.7556068],
-    [-0.28516498, 0.5311748, 0.797831],
-    [-0.2666699, 0.5094646, 0.8181278],
-    [-0.2812341, 0.58034486, 0.764269],
-    [-0.3006546, 0.5455595, 0.7822861],
-    [-0.34807202, 0.5310131, 0.7725743],
-    [-0.3947095, 0.76080793, 0.5151464],
-    [-0.3947095, 0.76080793, 0.5151464],
-    [-0.3450088, 0.77153325, 0.5345142],
-    [-0.32420808, 0.755528, 0.5692686],
-    [-0.28548548, 0.7976628, 0.5312552],
-    [-0.28145143, 0.7642177, 0.58030725],
-    [-0.26691833, 0.81792694, 0.50965697],
-    [-0.3009246, 0.78217995, 0.5455628],
-    [-0.34826043, 0.7724773, 0.53103065],
-    [-0.23801024, 0.62063086, 0.7471068],
-    [-0.23801024, 0.62063086, 0.7471068],
-    [-0.23801024, 0.7471068, 0.62063086],
-    [-0.23801024, 0.7471068, 0.62063086],
-    [-0.24374485, 0.68578, 0.68578],
-    [-0.22027145, 0.6577759, 0.72028565],
-    [-0.22027145, 0.72028553, 0.6577759],
-    [-0.20234467, 0.6924799, 0.6924799],
-    [-0.1564547, 0.66545653, 0.72985584],
-    [-0.15645471, 0.72985584, 0.66545653],
-    [-0.1568703, 0.6983523, 0.6983523],
-    [-0.19400178, 0.6936726, 0.6936726],
-    [-0.093978785, 0.67141783, 0.735096],
-    [-0.09397876, 0.735096, 0.6714177],
-    [-0.09446676, 0.7039447, 0.7039446],
-    [-0.031237818, 0.67420614, 0.73788226],
-    [-0.031237818, 0.73788226, 0.67420614],
-    [-0.031487603, 0.7067562, 0.7067561],
-    [-0.06280486, 0.7057109, 0.7057109],
-    [-0.1840661, 0.695025, 0.6950251],
-    [-0.21888196, 0.5516121, 0.8048694],
-    [-0.21909471, 0.5205178, 0.8252628],
-    [-0.21786655, 0.59806585, 0.77126616],
-    [-0.17494518, 0.55950457, 0.81015354],
-    [-0.15524723, 0.5364308, 0.82954216],
-    [-0.15492976, 0.58972514, 0.79260385],
-    [-0.13328779, 0.5652887, 0.8140535],
-    [-0.18222928, 0.5607579, 0.80767757],
-    [-0.19616964, 0.62824327, 0.75287974],
-    [-0.1560054, 0.63379604, 0.75760466],
-    [-0.17612422, 0.63115114, 0.7553996],
-    [-0.094080195, 0.53247327, 0.84120214],
-    [-0.093822606, 0.6003643, 0.79420394],
-    [-0.094032906, 0.56914115, 0.81684524],
-    [-0.03151556, 0.5324105, 0.8458994],
-    [-0.03125978, 0.60362905, 0.79665226],
-    [-0.031489953, 0.5722968, 0.81944174],
-    [-0.062768556, 0.5690059, 0.8199343],
-    [-0.09351146, 0.63976383, 0.7628616],
-    [-0.031009154, 0.6428037, 0.76540315],
-    [-0.062290855, 0.6415984, 0.7645073],
-    [-0.121184535, 0.60227495, 0.78903687],
-    [-0.21910739, 0.8048269, 0.5515847],
-    [-0.21786658, 0.77126604, 0.59806585],
-    [-0.21932015, 0.82522035, 0.52049017],
-    [-0.17501052, 0.8101897, 0.55943173],
-    [-0.1549694, 0.79262763, 0.5896828],
-    [-0.15528639, 0.8295634, 0.5363867],
-    [-0.13335358, 0.81409216, 0.56521755],
-    [-0.18232411, 0.8076839, 0.560718],
-    [-0.19616963, 0.75287974, 0.62824327],
-    [-0.1560054, 0.75760466, 0.63379604],
-    [-0.1761242, 0.7553996, 0.63115114],
-    [-0.093822606, 0.79420394, 0.6003643],
-    [-0.094080195, 0.84120214, 0.53247327],
-    [-0.0940329, 0.8168452, 0.56914115],
-    [-0.03125978, 0.79665226, 0.603629],
-    [-0.03151556, 0.8458994, 0.53241056],
-    [-0.031489953, 0.8194418, 0.57229674],
-    [-0.06276858, 0.81993437, 0.56900597],
-    [-0.09351146, 0.7628616, 0.63976383],
-    [-0.031009154, 0.7654031, 0.6428037],
-    [-0.062290855, 0.7645073, 0.6415984],
-    [-0.12120853, 0.7890399, 0.6022661],
-    [-0.25789994, 0.6831899, 0.683183],
-    [-0.57734746, 0.5773474, 0.57735586],
-    [0.031009154, -0.7654031, -0.6428037],
-    [0.09351146, -0.7628616, -0.63976383],
-    [0.062290855, -0.7645073, -0.6415984],
-    [0.031489953, -0.8194418, -0.57229674],
-    [0.03151556, -0.84589946, -0.5324105],
-    [0.03125978, -0.79665226, -0.603629],
-    [0.0940329, -0.8168452, -0.56914115],
-    [0.09408019, -0.84120214, -0.53247327],
-    [0.093822606, -0.79420394, -0.6003643],
-    [0.06276856, -0.81993437, -0.56900597],
-    [0.1560054, -0.75760466, -0.63379604],
-    [0.19616966, -0.75287986, -0.6282432],
-    [0.17612422, -0.7553996, -0.6311511],
-    [0.13335358, -0.81409216, -0.5652176],
-    [0.15528639, -0.8295634, -0.5363867],
-    [0.15496938, -0.7926277, -0.5896828],
-    [0.17501052, -0.8101897, -0.55943173],
-    [0.21932015, -0.82522035, -0.52049017],
-    [0.21786658, -0.77126604, -0.59806585],
-    [0.21910739, -0.8048269, -0.5515847],
-    [0.1823241, -0.8076838, -0.560718],
-    [0.12120853, -0.7890399, -0.602266],
-    [0.031009154, -0.6428037, -0.76540315],
-    [0.09351146, -0.63976383, -0.7628616],
-    [0.062290855, -0.6415984, -0.7645073],
-    [0.031489953, -0.5722968, -0.81944174],
-    [0.03125978, -0.60362905, -0.79665226],
-    [0.03151556, -0.5324105, -0.84589946],
-    [0.094032906, -0.56914115, -0.81684524],
-    [0.093822606, -0.6003643, -0.79420394],
-    [0.09408019, -0.53247327, -0.84120214],
-    [0.062768556, -0.56900597, -0.8199343],
-    [0.1560054, -0.63379604, -0.75760466],
-    [0.19616966, -0.6282432, -0.75287986],
-    [0.17612422, -0.6311511, -0.7553996],
-    [0.13328779, -0.5652887, -0.8140535],
-    [0.15492976, -0.58972514, -0.79260385],
-    [0.15524724, -0.53643084, -0.82954216],
-    [0.17494518, -0.55950457, -0.81015354],
-    [0.21786655, -0.59806585, -0.77126604],
-    [0.21909471, -0.5205178, -0.8252628],
-    [0.21888196, -0.5516121, -0.8048694],
-    [0.18222928, -0.5607578, -0.8076776],
-    [0.121184535, -0.6022749, -0.78903687],
-    [0.031487603, -0.7067562, -0.7067561],
-    [0.031237818, -0.73788226, -0.67420614],
-    [0.031237818, -0.67420614, -0.73788226],
-    [0.09446676, -0.7039447, -0.7039446],
-    [0.09397876, -0.735096, -0.6714177],
-    [0.093978785, -0.67141783, -0.735096],
-    [0.06280486, -0.7057109, -0.7057109],
-    [0.1568703, -0.6983523, -0.6983523],
-    [0.15645471, -0.72985584, -0.66545653],
-    [0.1564547, -0.66545653, -0.72985584],
-    [0.20234467, -0.6924799, -0.6924799],
-    [0.22027145, -0.72028565, -0.6577759],
-    [0.22027145, -0.6577759, -0.72028553],
-    [0.24374485, -0.68578, -0.68578],
-    [0.19400178, -0.6936726, -0.6936726],
-    [0.23801024, -0.7471068, -0.62063086],
-    [0.23801024, -0.7471068, -0.62063086],
-    [0.23801024, -0.62063086, -0.7471068],
-    [0.23801024, -0.62063086, -0.7471068],
-    [0.1840661, -0.695025, -0.6950251],
-    [0.26691833, -0.81792694, -0.50965697],
-    [0.28145143, -0.7642177, -0.58030725],
-    [0.28548548, -0.7976628, -0.5312552],
-    [0.32420805, -0.75552785, -0.5692685],
-    [0.3450088, -0.7715333, -0.5345142],
-    [0.3009246, -0.78217995, -0.5455628],
-    [0.3947095, -0.7608078, -0.5151464],
-    [0.39470956, -0.76080793, -0.51514643],
-    [0.34826043, -0.7724773, -0.53103065],
-    [0.2812341, -0.58034486, -0.764269],
-    [0.26666993, -0.5094646, -0.8181278],
-    [0.28516498, -0.5311748, -0.797831],
-    [0.3239224, -0.5693265, -0.7556068],
-    [0.34472787, -0.5346671, -0.7715529],
-    [0.30065462, -0.5455595, -0.7822861],
-    [0.39460042, -0.5151102, -0.76088905],
-    [0.39460042, -0.5151102, -0.76088905],
-    [0.34807205, -0.531013, -0.7725743],
-    [0.28148478, -0.70824444, -0.6474225],
-    [0.2814848, -0.6474225, -0.70824456],
-    [0.28141975, -0.67852885, -0.6785289],
-    [0.33159938, -0.6975285, -0.63521314],
-    [0.33160666, -0.63554347, -0.69722414],
-    [0.35277322, -0.6618048, -0.66148716],
-    [0.31040585, -0.67225796, -0.6720991],
-    [0.28137675, -0.739426, -0.6116178],
-    [0.34883973, -0.7221721, -0.5973092],
-    [0.36566997, -0.7459645, -0.55661696],
-    [0.373092, -0.6867571, -0.6238326],
-    [0.3426699, -0.7244791, -0.59808636],
-    [0.28137675, -0.61161774, -0.73942596],
-    [0.34884685, -0.5974804, -0.7220269],
-    [0.37309933, -0.62416285, -0.6864529],
-    [0.36548474, -0.55680907, -0.74591184],
-    [0.3426278, -0.5982611, -0.72435474],
-    [0.40705565, -0.6458738, -0.6458738],
-    [0.44770122, -0.63244194, -0.63212395],
-    [0.42748648, -0.6393195, -0.63916045],
-    [0.4066382, -0.7040583, -0.5821919],
-    [0.4119511, -0.7380218, -0.5344343],
-    [0.40687314, -0.6770918, -0.6131893],
-    [0.44491085, -0.6923032, -0.56812906],
-    [0.4673896, -0.70701736, -0.5307293],
-    [0.4674735, -0.6558311, -0.59275126],
-    [0.48661032, -0.67813694, -0.5507637],
-    [0.44221535, -0.6940294, -0.56812745],
-    [0.40651593, -0.58234537, -0.70400196],
-    [0.4067483, -0.6133388, -0.6770312],
-    [0.4118869, -0.5344142, -0.7380723],
-    [0.44483417, -0.56812227, -0.69235796],
-    [0.46744084, -0.5929143, -0.6557072],
-    [0.46735737, -0.53071374, -0.70705014],
-    [0.48655805, -0.55073935, -0.6781944],
-    [0.44214487, -0.5681868, -0.69402575],
-    [0.48917478, -0.61688745, -0.6165694],
-    [0.48917484, -0.6168875, -0.61656946],
-    [0.40136042, -0.647725, -0.64758176],
-    [0.25789994, -0.6831899, -0.683183],
-    [0.031273916, -0.8872522, -0.46022323],
-    [0.03104798, -0.91294277, -0.40690485],
-    [0.09381681, -0.88492364, -0.45618913],
-    [0.09363458, -0.90861446, -0.40700433],
-    [0.062499367, -0.89923966, -0.43296865],
-    [0.031269234, -0.9438091, -0.3290084],
-    [0.03129361, -0.95893526, -0.28189346],
-    [0.031270668, -0.9324015, -0.36006904],
-    [0.0937832, -0.9416977, -0.32312563],
-    [0.09384787, -0.95480126, -0.28204092],
-    [0.09383009, -0.93040735, -0.35431355],
-    [0.062613204, -0.9446425, -0.32207155],
-    [0.031501405, -0.8702115, -0.49167034],
-    [0.09403111, -0.86800206, -0.4875762],
-    [0.06279713, -0.86953425, -0.48986408],
-    [0.15660593, -0.880411, -0.44760585],
-    [0.15670547, -0.8998644, -0.40704724],
-    [0.19375718, -0.8765209, -0.44064656],
-    [0.21663171, -0.88679457, -0.40824753],
-    [0.18102211, -0.8863728, -0.4261152],
-    [0.13325031, -0.9391045, -0.31674463],
-    [0.15526845, -0.9461164, -0.28417525],
-    [0.15530512, -0.9274302, -0.3402258],
-    [0.17527439, -0.9358569, -0.30569702],
-    [0.20563965, -0.9422029, -0.26451087],
-    [0.21919033, -0.91308534, -0.34384707],
-    [0.22344194, -0.9325484, -0.2835968],
-    [0.18122962, -0.9346749, -0.3058407],
-    [0.15655987, -0.86374426, -0.47899368],
-    [0.21637973, -0.8584523, -0.46501538],
-    [0.23545098, -0.87149704, -0.43018106],
-    [0.20295721, -0.8652482, -0.45842555],
-    [0.12612614, -0.90429944, -0.40784165],
-    [0.03153171, -0.9751979, -0.21907744],
-    [0.031294666, -0.98715264, -0.15668546],
-    [0.09409146, -0.9710682, -0.21948425],
-    [0.09387083, -0.983177, -0.15668844],
-    [0.06275991, -0.9801288, -0.18817203],
-    [0.031052334, -0.9950792, -0.09409098],
-    [0.03127881, -0.9990212, -0.03127881],
-    [0.0936523, -0.9911904, -0.0936523],
-    [0.09409098, -0.9950792, -0.031052334],
-    [0.0625804, -0.99607605, -0.06258039],
-    [0.15666673, -0.9629035, -0.21970935],
-    [0.15667123, -0.9751453, -0.15667123],
-    [0.22377947, -0.9486019, -0.22377947],
-    [0.21970937, -0.96290356, -0.15666674],
-    [0.24690984, -0.93705446, -0.24690984],
-    [0.20104268, -0.9587303, -0.20104268],
-    [0.15668844, -0.983177, -0.09387083],
-    [0.15668546, -0.98715264, -0.031294666],
-    [0.21948425, -0.9710682, -0.09409146],
-    [0.21907742, -0.9751979, -0.03153171],
-    [0.18817198, -0.98012877, -0.062759906],
-    [0.12921643, -0.98316133, -0.12921643],
-    [0.26893088, -0.8815712, -0.38795394],
-    [0.2689309, -0.8815713, -0.387954],
-    [0.28651407, -0.8921842, -0.3491664],
-    [0.28164735, -0.91725105, -0.28164735],
-    [0.3320178, -0.8830307, -0.33169383],
-    [0.34935254, -0.8921737, -0.28631973],
-    [0.31267092, -0.89698607, -0.31249464],
-    [0.28547677, -0.83276856, -0.47434115],
-    [0.28682917, -0.8666205, -0.4082866],
-    [0.3084306, -0.8105078, -0.4979435],
-    [0.344883, -0.8217146, -0.45369685],
-    [0.3449563, -0.84578073, -0.40701374],
-    [0.3445741, -0.8036993, -0.4851146],
-    [0.31958112, -0.8311948, -0.4549538],
-    [0.3104197, -0.87433016, -0.37307698],
-    [0.34950918, -0.86938053, -0.34931475],
-    [0.37338692, -0.8743126, -0.3100962],
-    [0.34467193, -0.87326556, -0.34439573],
-    [0.38822573, -0.88146305, -0.2688933],
-    [0.3882258, -0.88146317, -0.26889333],
-    [0.378907, -0.81380486, -0.44062606],
-    [0.402885, -0.8218073, -0.402885],
-    [0.40777215, -0.7826506, -0.4702976],
-    [0.42039618, -0.8039072, -0.420714],
-    [0.44062606, -0.81380486, -0.378907],
-    [0.45728362, -0.7625566, -0.45760158],
-    [0.47014332, -0.7826584, -0.40793523],
-    [0.42589796, -0.798189, -0.42603427],
-    [0.4070137, -0.8457808, -0.3449563],
-    [0.40844816, -0.86655223, -0.28680548],
-    [0.45369685, -0.8217146, -0.344883],
-    [0.47434115, -0.83276856, -0.28547677],
-    [0.4851146, -0.8036992, -0.34457406],
-    [0.4979435, -0.8105078, -0.3084306],
-    [0.4549801, -0.8311822, -0.3195767],
-    [0.36085558, -0.86002004, -0.3607614],
-    [0.26451087, -0.9422029, -0.20563968],
-    [0.284349, -0.9460654, -0.15526031],
-    [0.2835968, -0.9325484, -0.22344194],
-    [0.30598453, -0.9357656, -0.17525989],
-    [0.31703112, -0.93901, -0.13323525],
-    [0.34384707, -0.91308534, -0.21919031],
-    [0.3403962, -0.9273693, -0.15529534],
-    [0.30597228, -0.9346332, -0.18122298],
-    [0.28204092, -0.95480126, -0.09384787],
-    [0.28189346, -0.95893526, -0.03129361],
-    [0.32312563, -0.9416977, -0.0937832],
-    [0.3290084, -0.9438091, -0.031269234],
-    [0.35431355, -0.93040735, -0.09383008],
-    [0.36006898, -0.9324014, -0.031270664],
-    [0.32207158, -0.9446425, -0.06261321],
-    [0.40824753, -0.88679457, -0.21663171],
-    [0.40704724, -0.8998644, -0.15670547],
-    [0.44064656, -0.8765209, -0.19375718],
-    [0.44760585, -0.880411, -0.15660593],
-    [0.4261152, -0.88637286, -0.18102211],
-    [0.40700433, -0.90861446, -0.09363458],
-    [0.40690485, -0.91294277, -0.03104798],
-    [0.45618913, -0.88492364, -0.09381681],
-    [0.46022323, -0.8872522, -0.031273916],
-    [0.43296865, -0.8992397, -0.062499367],
-    [0.43018106, -0.87149704, -0.23545098],
-    [0.46501538, -0.8584523, -0.21637973],
-    [0.47899362, -0.86374426, -0.15655987],
-    [0.45842555, -0.8652482, -0.20295721],
-    [0.4875762, -0.868002, -0.09403111],
-    [0.49167028, -0.8702115, -0.0315014],
-    [0.48986408, -0.86953425, -0.06279713],
-    [0.40786266, -0.9042902, -0.1261247],
-    [0.43595585, -0.7492584, -0.4985524],
-    [0.47463012, -0.7412505, -0.47463012],
-    [0.4985524, -0.7492584, -0.43595585],
-    [0.47002885, -0.7470914, -0.47002885],
-    [0.4700289, -0.7470915, -0.4700289],
-    [0.30616686, -0.90140903, -0.306143],
-    [0.031273916, -0.4602232, -0.8872523],
-    [0.03104798, -0.40690485, -0.91294277],
-    [0.09381681, -0.45618913, -0.88492364],
-    [0.09363458, -0.40700433, -0.90861446],
-    [0.062499367, -0.43296865, -0.89923966],
-    [0.031501405, -0.49167037, -0.8702115],
-    [0.09403112, -0.48757622, -0.86800206],
-    [0.06279714, -0.48986414, -0.86953425],
-    [0.031269234, -0.32900843, -0.9438091],
-    [0.031270664, -0.360069, -0.9324014],
-    [0.03129361, -0.28189346, -0.95893526],
-    [0.0937832, -0.32312566, -0.9416977],
-    [0.09383008, -0.3543135, -0.9304073],
-    [0.09384787, -0.28204092, -0.95480126],
-    [0.06261322, -0.3220716, -0.9446425],
-    [0.15660591, -0.44760588, -0.880411],
-    [0.15670547, -0.40704724, -0.8998644],
-    [0.19375718, -0.44064656, -0.8765209],
-    [0.21645129, -0.4082647, -0.88683075],
-    [0.18097666, -0.4261188, -0.88638043],
-    [0.15655987, -0.47899368, -0.86374426],
-    [0.21637973, -0.46501538, -0.8584523],
-    [0.23515163, -0.430212, -0.8715626],
-    [0.20285669, -0.45843437, -0.8652671],
-    [0.13325031, -0.31674463, -0.9391045],
-    [0.1553051, -0.3402258, -0.9274302],
-    [0.15526842, -0.28417522, -0.9461163],
-    [0.17527437, -0.30569702, -0.9358569],
-    [0.2191903, -0.34384704, -0.9130852],
-    [0.20563965, -0.26451087, -0.9422029],
-    [0.22344194, -0.2835968, -0.9325484],
-    [0.1812296, -0.3058407, -0.9346749],
-    [0.12610146, -0.40784302, -0.90430224],
-    [0.03153171, -0.21907744, -0.9751979],
-    [0.031294666, -0.15668546, -0.98715264],
-    [0.09409146, -0.21948425, -0.9710682],
-    [0.09387083, -0.15668844, -0.983177],
-    [0.06275991, -0.18817203, -0.9801288],
-    [0.031052334, -0.09409098, -0.9950792],
-    [0.03127881, -0.03127881, -0.9990212],
-    [0.0936523, -0.0936523, -0.9911904],
-    [0.09409098, -0.031052334, -0.9950792],
-    [0.0625804, -0.06258039, -0.99607605],
-    [0.15666673, -0.21970935, -0.9629035],
-    [0.15667123, -0.15667123, -0.9751453],
-    [0.22377947, -0.22377947, -0.9486019],
-    [0.24690987, -0.24690987, -0.9370545],
-    [0.21970937, -0.15666674, -0.96290356],
-    [0.20104268, -0.2010427, -0.9587303],
-    [0.15668844, -0.09387083, -0.983177],
-    [0.15668546, -0.031294666, -0.98715264],
-    [0.21948425, -0.09409146, -0.9710682],
-    [0.21907742, -0.03153171, -0.9751979],
-    [0.18817198, -0.062759906, -0.98012877],
-    [0.12921643, -0.12921643, -0.98316133],
-    [0.26888818, -0.38822904, -0.8814633],
-    [0.26888815, -0.38822898, -0.88146317],
-    [0.285504, -0.47419554, -0.8328422],
-    [0.30847806, -0.49770465, -0.81063646],
-    [0.28680262, -0.40845034, -0.86655223],
-    [0.34467536, -0.45373356, -0.8217815],
-    [0.34436643, -0.48515105, -0.80376625],
-    [0.3449563, -0.40701374, -0.84578073],
-    [0.31951776, -0.45492625, -0.8312342],
-    [0.28666767, -0.3493115, -0.8920779],
-    [0.28164735, -0.28164735, -0.91725105],
-    [0.33197904, -0.33197904, -0.88293827],
-    [0.3493115, -0.28666767, -0.8920779],
-    [0.31268921, -0.31268921, -0.8969119],
-    [0.3106713, -0.37331337, -0.8741399],
-    [0.34948465, -0.34948468, -0.8693221],
-    [0.37331337, -0.31067127, -0.8741399],
-    [0.34472048, -0.34472048, -0.87311834],
-    [0.3785344, -0.44056633, -0.81401044],
-    [0.4077525, -0.4701225, -0.78276604],
-    [0.4027558, -0.4027558, -0.8219339],
-    [0.4204447, -0.42044467, -0.80402267],
-    [0.45753765, -0.45753765, -0.7624424],
-    [0.4405664, -0.3785345, -0.8140105],
-    [0.47012252, -0.40775257, -0.78276604],
-    [0.42585832, -0.42585835, -0.798304],
-    [0.38822898, -0.26888812, -0.8814632],
-    [0.38822898, -0.26888812, -0.8814632],
-    [0.4070137, -0.3449563, -0.8457808],
-    [0.40845034, -0.28680262, -0.86655223],
-    [0.45373356, -0.34467536, -0.8217815],
-    [0.48515105, -0.3443664, -0.80376625],
-    [0.47419563, -0.285504, -0.8328421],
-    [0.49770465, -0.30847806, -0.81063646],
-    [0.45492628, -0.31951776, -0.8312342],
-    [0.36083508, -0.36083505, -0.8599977],
-    [0.43602434, -0.4983138, -0.7493772],
-    [0.47463012, -0.47463012, -0.7412505],
-    [0.4983138, -0.43602434, -0.7493772],
-    [0.4699694, -0.4699694, -0.74716645],
-    [0.4699694, -0.4699694, -0.74716645],
-    [0.26451087, -0.20563968, -0.9422029],
-    [0.2835968, -0.22344194, -0.9325484],
-    [0.28417525, -0.15526843, -0.9461164],
-    [0.30569702, -0.17527439, -0.9358569],
-    [0.34384707, -0.21919031, -0.91308534],
-    [0.31674463, -0.13325031, -0.9391045],
-    [0.3402258, -0.15530512, -0.9274302],
-    [0.30584067, -0.18122959, -0.93467486],
-    [0.28204092, -0.09384787, -0.95480126],
-    [0.28189346, -0.03129361, -0.95893526],
-    [0.32312563, -0.0937832, -0.9416977],
-    [0.3543135, -0.09383009, -0.93040735],
-    [0.32900846, -0.031269234, -0.9438091],
-    [0.360069, -0.031270664, -0.9324014],
-    [0.32207158, -0.06261321, -0.9446425],
-    [0.4082647, -0.2164513, -0.88683075],
-    [0.40704724, -0.15670547, -0.8998644],
-    [0.44064656, -0.19375718, -0.8765209],
-    [0.44760585, -0.15660593, -0.88041097],
-    [0.42611876, -0.18097667, -0.8863803],
-    [0.430212, -0.2351516, -0.8715626],
-    [0.46501538, -0.21637972, -0.8584523],
-    [0.47899368, -0.15655988, -0.86374426],
-    [0.45843437, -0.20285669, -0.8652671],
-    [0.40700433, -0.09363458, -0.90861446],
-    [0.40690485, -0.03104798, -0.91294277],
-    [0.45618913, -0.09381682, -0.88492364],
-    [0.46022323, -0.031273916, -0.8872522],
-    [0.43296865, -0.062499367, -0.8992397],
-    [0.48757622, -0.094031125, -0.8680021],
-    [0.49167028, -0.0315014, -0.8702115],
-    [0.48986408, -0.06279714, -0.86953425],
-    [0.4078429, -0.12610145, -0.90430224],
-    [0.3061401, -0.30614012, -0.9014191],
-    [0.5088563, -0.6943562, -0.5088563],
-    [0.5137616, -0.63638526, -0.5753806],
-    [0.5326918, -0.6576295, -0.53269404],
-    [0.57526654, -0.63657665, -0.51365227],
-    [0.5331593, -0.65687174, -0.5331611],
-    [0.5137616, -0.5753806, -0.63638526],
-    [0.5089309, -0.5089309, -0.69424677],
-    [0.5327861, -0.5327861, -0.65747863],
-    [0.5753806, -0.5137616, -0.63638526],
-    [0.53322995, -0.53322995, -0.6567585],
-    [0.5349534, -0.59742147, -0.59742147],
-    [0.55560803, -0.61854213, -0.55561256],
-    [0.5557188, -0.5557188, -0.6183473],
-    [0.57735026, -0.57735026, -0.57735026],
-    [0.5974675, -0.5974675, -0.53485054],
-    [0.59742147, -0.5349535, -0.59742147],
-    [0.6185422, -0.55560803, -0.55561256],
-    [0.5773715, -0.5773715, -0.5773078],
-    [0.63657665, -0.57526654, -0.51365227],
-    [0.63638526, -0.5137616, -0.5753806],
-    [0.6576295, -0.5326919, -0.5326941],
-    [0.6943562, -0.5088563, -0.5088563],
-    [0.6568716, -0.5331593, -0.53316104],
-    [0.5773707, -0.5773707, -0.5773095],
-    [0.5773707, -0.5773707, -0.5773095],
-    [0.51527786, -0.76067156, -0.39480042],
-    [0.5152779, -0.7606717, -0.39480048],
-    [0.50965697, -0.81792694, -0.2669183],
-    [0.5345115, -0.77153325, -0.3450128],
-    [0.5312552, -0.7976628, -0.28548548],
-    [0.5691334, -0.75566536, -0.32412475],
-    [0.58030725, -0.7642177, -0.28145143],
-    [0.54553425, -0.7822062, -0.30090824],
-    [0.5310833, -0.77242386, -0.3482985],
-    [0.52049017, -0.82522035, -0.21932015],
-    [0.5366531, -0.8293967, -0.15525566],
-    [0.5515847, -0.8048269, -0.21910737],
-    [0.5598676, -0.80990016, -0.17495714],
-    [0.56565136, -0.8137997, -0.13329963],
-    [0.5982785, -0.77106327, -0.21800068],
-    [0.5899191, -0.7924223, -0.15512083],
-    [0.56094617, -0.8075206, -0.18234569],
-    [0.53247327, -0.84120214, -0.094080195],
-    [0.5324105, -0.84589946, -0.03151556],
-    [0.56914115, -0.8168452, -0.0940329],
-    [0.57229674, -0.8194418, -0.031489953],
-    [0.6003643, -0.79420394, -0.093822606],
-    [0.603629, -0.7966522, -0.031259775],
-    [0.56900597, -0.81993437, -0.06276856],
-    [0.6285504, -0.7524859, -0.19669628],
-    [0.6337491, -0.7575485, -0.15646799],
-    [0.6312817, -0.75517493, -0.17661902],
-    [0.63976383, -0.7628616, -0.09351146],
-    [0.6428037, -0.7654031, -0.031009154],
-    [0.6415984, -0.7645073, -0.062290855],
-    [0.6023601, -0.78894806, -0.121338814],
-    [0.5307293, -0.70701736, -0.4673896],
-    [0.5344669, -0.73787886, -0.4121649],
-    [0.5507637, -0.67813694, -0.48661032],
-    [0.56825644, -0.69213676, -0.44500726],
-    [0.58223426, -0.70386404, -0.4069135],
-    [0.5929463, -0.6557199, -0.46738228],
-    [0.61328274, -0.6769644, -0.406944],
-    [0.56819654, -0.6939219, -0.4422954],
-    [0.5565471, -0.74587893, -0.36595076],
-    [0.5973131, -0.72213846, -0.34890273],
-    [0.6115759, -0.7393764, -0.2815981],
-    [0.6240265, -0.6866207, -0.37301892],
-    [0.59810805, -0.7244028, -0.34279317],
-    [0.61683726, -0.61683726, -0.48890042],
-    [0.61683726, -0.61683726, -0.48890042],
-    [0.6323825, -0.6323825, -0.44741994],
-    [0.6458738, -0.6458738, -0.40705565],
-    [0.6392875, -0.6392875, -0.4273443],
-    [0.63540477, -0.6973894, -0.33152497],
-    [0.64738035, -0.70819503, -0.28170618],
-    [0.66166675, -0.66166675, -0.35269552],
-    [0.6785289, -0.67852885, -0.28141975],
-    [0.6973893, -0.63540477, -0.33152494],
-    [0.70819503, -0.64738035, -0.28170618],
-    [0.6721708, -0.6721708, -0.31043956],
-    [0.6557199, -0.5929463, -0.46738222],
-    [0.6769644, -0.61328274, -0.406944],
-    [0.67813694, -0.5507637, -0.48661032],
-    [0.69213676, -0.56825644, -0.44500726],
-    [0.70386404, -0.58223426, -0.40691346],
-    [0.70701736, -0.5307293, -0.4673896],
-    [0.73787886, -0.5344669, -0.4121649],
-    [0.693922, -0.5681965, -0.44229534],
-    [0.6866207, -0.6240265, -0.37301892],
-    [0.72213846, -0.597313, -0.34890273],
-    [0.73937637, -0.6115759, -0.28159812],
-    [0.74587893, -0.5565471, -0.36595076],
-    [0.7244028, -0.59810805, -0.34279317],
-    [0.64764833, -0.64764833, -0.40137663],
-    [0.6209723, -0.7467547, -0.23822434],
-    [0.62097234, -0.74675477, -0.23822437],
-    [0.65791845, -0.72007656, -0.22052875],
-    [0.6654331, -0.7298277, -0.15668595],
-    [0.68578, -0.68578, -0.24374485],
-    [0.6924799, -0.6924799, -0.20234467],
-    [0.6983523, -0.6983523, -0.1568703],
-    [0.72007656, -0.65791845, -0.22052875],
-    [0.72982764, -0.665433, -0.15668593],
-    [0.69365317, -0.69365317, -0.19414084],
-    [0.6714177, -0.735096, -0.09397877],
-    [0.67420614, -0.73788226, -0.031237818],
-    [0.7039446, -0.7039447, -0.09446676],
-    [0.7067561, -0.7067562, -0.031487603],
-    [0.735096, -0.6714177, -0.09397877],
-    [0.73788226, -0.67420614, -0.031237818],
-    [0.7057108, -0.7057108, -0.062804855],
-    [0.74675465, -0.6209723, -0.23822431],
-    [0.7467547, -0.62097234, -0.23822434],
-    [0.6950065, -0.69500655, -0.18420568],
-    [0.76067156, -0.515278, -0.39480042],
-    [0.76067156, -0.515278, -0.39480042],
-    [0.75566536, -0.5691334, -0.32412472],
-    [0.7642177, -0.58030725, -0.28145146],
-    [0.7715333, -0.53451157, -0.34501287],
-    [0.7976628, -0.5312552, -0.28548548],
-    [0.81792694, -0.50965697, -0.26691833],
-    [0.78220624, -0.54553425, -0.30090824],
-    [0.7724239, -0.53108335, -0.34829846],
-    [0.7524859, -0.6285504, -0.19669631],
-    [0.7575485, -0.6337491, -0.156468],
-    [0.75517493, -0.6312817, -0.17661905],
-    [0.7628616, -0.63976383, -0.09351146],
-    [0.76540303, -0.6428036, -0.03100915],
-    [0.76450723, -0.6415984, -0.062290855],
-    [0.77106327, -0.5982785, -0.21800065],
-    [0.79242224, -0.58991903, -0.15512083],
-    [0.8048269, -0.5515847, -0.21910736],
-    [0.80990016, -0.5598676, -0.17495714],
-    [0.8137997, -0.56565136, -0.13329963],
-    [0.82522035, -0.52049017, -0.21932015],
-    [0.8293967, -0.53665316, -0.15525566],
-    [0.8075206, -0.5609462, -0.18234569],
-    [0.794204, -0.6003644, -0.09382261],
-    [0.79665226, -0.60362905, -0.03125978],
-    [0.81684524, -0.56914115, -0.094032906],
-    [0.81944174, -0.5722968, -0.031489953],
-    [0.84120214, -0.53247327, -0.09408019],
-    [0.84589946, -0.5324105, -0.03151556],
-    [0.8199343, -0.56900597, -0.062768556],
-    [0.78894806, -0.6023601, -0.12133882],
-    [0.6831648, -0.6831648, -0.2580152],
-    [0.5151102, -0.39460036, -0.760889],
-    [0.5151102, -0.39460036, -0.760889],
-    [0.53466713, -0.34472784, -0.7715529],
-    [0.5094646, -0.2666699, -0.8181278],
-    [0.5311748, -0.28516495, -0.797831],
-    [0.5693265, -0.3239224, -0.7556068],
-    [0.5803449, -0.28123412, -0.764269],
-    [0.5455595, -0.30065462, -0.7822861],
-    [0.5310131, -0.34807205, -0.7725743],
-    [0.5307137, -0.4673574, -0.7070502],
-    [0.55073935, -0.48655805, -0.6781944],
-    [0.5344142, -0.41188684, -0.7380723],
-    [0.5681224, -0.44483423, -0.69235796],
-    [0.5929142, -0.46744084, -0.6557072],
-    [0.58234537, -0.40651593, -0.70400196],
-    [0.6133388, -0.4067483, -0.6770313],
-    [0.5681869, -0.44214487, -0.6940257],
-    [0.61688745, -0.48917478, -0.6165694],
-    [0.6168875, -0.48917484, -0.61656946],
-    [0.55680907, -0.36548474, -0.74591184],
-    [0.5974804, -0.34884688, -0.72202694],
-    [0.62416285, -0.37309936, -0.6864529],
-    [0.6116178, -0.28137672, -0.73942596],
-    [0.5982611, -0.34262776, -0.7243547],
-    [0.632442, -0.44770122, -0.632124],
-    [0.6458738, -0.40705565, -0.6458738],
-    [0.6393195, -0.42748648, -0.63916045],
-    [0.6558311, -0.4674735, -0.59275126],
-    [0.67813694, -0.48661035, -0.5507637],
-    [0.6770918, -0.40687314, -0.6131893],
-    [0.6923032, -0.44491085, -0.56812906],
-    [0.70701736, -0.4673896, -0.5307293],
-    [0.70405823, -0.4066382, -0.5821918],
-    [0.7380218, -0.41195115, -0.5344343],
-    [0.69402945, -0.44221535, -0.56812745],
-    [0.6355435, -0.33160663, -0.6972241],
-    [0.6618048, -0.3527732, -0.6614872],
-    [0.6474225, -0.2814848, -0.70824456],
-    [0.6785289, -0.28141975, -0.67852885],
-    [0.6975285, -0.33159938, -0.63521326],
-    [0.70824456, -0.2814848, -0.6474225],
-    [0.672258, -0.31040585, -0.6720991],
-    [0.6867571, -0.37309206, -0.62383264],
-    [0.722172, -0.34883973, -0.5973092],
-    [0.7459645, -0.36566997, -0.55661696],
-    [0.739426, -0.28137675, -0.6116178],
-    [0.724479, -0.3426699, -0.5980864],
-    [0.64772505, -0.40136036, -0.64758176],
-    [0.5205178, -0.21909471, -0.8252628],
-    [0.5516121, -0.21888195, -0.8048694],
-    [0.5364308, -0.15524723, -0.82954216],
-    [0.55950457, -0.17494518, -0.81015354],
-    [0.59806585, -0.21786658, -0.77126616],
-    [0.5652887, -0.13328779, -0.8140535],
-    [0.58972514, -0.15492976, -0.79260385],
-    [0.5607578, -0.1822293, -0.8076776],
-    [0.53247327, -0.094080195, -0.84120214],
-    [0.5324105, -0.03151556, -0.84589946],
-    [0.56914115, -0.0940329, -0.8168452],
-    [0.6003644, -0.09382262, -0.794204],
-    [0.57229674, -0.031489953, -0.81944174],
-    [0.603629, -0.031259775, -0.7966522],
-    [0.5690059, -0.06276856, -0.8199343],
-    [0.62824327, -0.19616964, -0.75287974],
-    [0.6337961, -0.15600541, -0.7576048],
-    [0.63115114, -0.1761242, -0.7553995],
-    [0.63976383, -0.09351147, -0.7628616],
-    [0.6428037, -0.031009154, -0.76540315],
-    [0.6415984, -0.06229086, -0.7645073],
-    [0.6022749, -0.12118454, -0.7890369],
-    [0.62063086, -0.23801026, -0.7471068],
-    [0.62063086, -0.23801026, -0.7471068],
-    [0.6577759, -0.22027145, -0.72028553],
-    [0.68578, -0.24374485, -0.68578],
-    [0.6654565, -0.1564547, -0.72985584],
-    [0.6924799, -0.20234467, -0.6924799],
-    [0.72028565, -0.22027145, -0.6577759],
-    [0.6983523, -0.1568703, -0.6983523],
-    [0.72985584, -0.15645474, -0.66545653],
-    [0.6936726, -0.19400178, -0.6936726],
-    [0.7471068, -0.23801026, -0.62063086],
-    [0.7471068, -0.23801026, -0.62063086],
-    [0.6714177, -0.09397877, -0.735096],
-    [0.67420614, -0.031237818, -0.73788226],
-    [0.7039446, -0.09446676, -0.7039446],
-    [0.735096, -0.09397877, -0.6714177],
-    [0.7067562, -0.031487603, -0.7067562],
-    [0.73788226, -0.031237818, -0.67420614],
-    [0.7057109, -0.06280486, -0.7057109],
-    [0.695025, -0.18406612, -0.6950251],
-    [0.76080793, -0.3947095, -0.5151464],
-    [0.76080793, -0.3947095, -0.5151464],
-    [0.755528, -0.32420808, -0.5692686],
-    [0.77153325, -0.34500876, -0.53451425],
-    [0.7642177, -0.28145146, -0.5803073],
-    [0.7976628, -0.28548548, -0.5312552],
-    [0.81792694, -0.26691833, -0.50965697],
-    [0.78217995, -0.3009246, -0.5455628],
-    [0.7724773, -0.34826043, -0.53103065],
-    [0.75287974, -0.19616963, -0.62824327],
-    [0.7576048, -0.15600541, -0.6337961],
-    [0.7553995, -0.1761242, -0.63115114],
-    [0.77126616, -0.21786658, -0.59806585],
-    [0.8048269, -0.21910739, -0.5515847],
-    [0.79262763, -0.1549694, -0.5896828],
-    [0.8101897, -0.17501052, -0.55943173],
-    [0.82522035, -0.21932015, -0.52049017],
-    [0.81409216, -0.13335358, -0.56521755],
-    [0.8295634, -0.15528637, -0.53638667],
-    [0.8076838, -0.18232408, -0.56071794],
-    [0.7628616, -0.09351147, -0.63976383],
-    [0.76540315, -0.031009154, -0.6428037],
-    [0.7645073, -0.06229086, -0.6415984],
-    [0.794204, -0.09382261, -0.6003644],
-    [0.79665226, -0.03125978, -0.60362905],
-    [0.81684524, -0.094032906, -0.5691412],
-    [0.84120214, -0.094080195, -0.53247327],
-    [0.81944174, -0.031489953, -0.57229674],
-    [0.84589946, -0.03151556, -0.5324105],
-    [0.8199344, -0.06276857, -0.56900597],
-    [0.7890399, -0.12120854, -0.6022661],
-    [0.6831899, -0.25789994, -0.683183],
-    [0.7412505, -0.47463012, -0.47463012],
-    [0.7492584, -0.4985524, -0.43595585],
-    [0.7492584, -0.43595585, -0.4985524],
-    [0.7470914, -0.47002885, -0.47002885],
-    [0.7470915, -0.4700289, -0.4700289],
-    [0.7625565, -0.45728365, -0.45760158],
-    [0.7826584, -0.47014335, -0.40793523],
-    [0.7826506, -0.40777215, -0.4702976],
-    [0.80390716, -0.42039615, -0.420714],
-    [0.81380486, -0.44062606, -0.378907],
-    [0.81380486, -0.378907, -0.44062606],
-    [0.8218073, -0.40288505, -0.402885],
-    [0.79818904, -0.42589802, -0.4260343],
-    [0.8036992, -0.48511457, -0.3445741],
-    [0.8105078, -0.4979435, -0.3084306],
-    [0.82171464, -0.4536969, -0.34488305],
-    [0.83276856, -0.47434115, -0.28547677],
-    [0.84578073, -0.40701374, -0.3449563],
-    [0.86655223, -0.40844816, -0.28680548],
-    [0.8311822, -0.45498008, -0.31957668],
-    [0.8036992, -0.3445741, -0.48511457],
-    [0.8105078, -0.3084306, -0.4979435],
-    [0.82171464, -0.34488305, -0.4536969],
-    [0.8457808, -0.3449563, -0.4070137],
-    [0.83276856, -0.28547677, -0.47434115],
-    [0.8666205, -0.28682917, -0.4082866],
-    [0.8311948, -0.31958112, -0.4549538],
-    [0.86938053, -0.34950918, -0.34931475],
-    [0.8743127, -0.37338692, -0.3100962],
-    [0.87433016, -0.3104197, -0.37307698],
-    [0.87326556, -0.34467193, -0.34439573],
-    [0.88146305, -0.38822573, -0.26889327],
-    [0.88146317, -0.3882258, -0.2688933],
-    [0.8815713, -0.26893088, -0.38795397],
-    [0.8815713, -0.26893088, -0.38795397],
-    [0.8830307, -0.33201787, -0.33169386],
-    [0.8921737, -0.3493525, -0.28631973],
-    [0.8921842, -0.286514, -0.34916633],
-    [0.91725105, -0.28164735, -0.28164735],
-    [0.89698607, -0.31267092, -0.3124946],
-    [0.86002004, -0.36085558, -0.36076134],
-    [0.8584523, -0.46501538, -0.21637973],
-    [0.86374426, -0.47899368, -0.15655987],
-    [0.8714971, -0.43018106, -0.23545098],
-    [0.8652482, -0.45842555, -0.20295721],
-    [0.8680021, -0.48757622, -0.094031125],
-    [0.8702115, -0.49167037, -0.031501405],
-    [0.86953413, -0.48986408, -0.06279714],
-    [0.8765209, -0.44064656, -0.19375719],
-    [0.880411, -0.44760588, -0.15660591],
-    [0.88679457, -0.40824753, -0.21663171],
-    [0.8998644, -0.40704724, -0.15670547],
-    [0.8863728, -0.4261152, -0.18102211],
-    [0.88492364, -0.45618913, -0.09381681],
-    [0.8872523, -0.4602232, -0.031273916],
-    [0.90861446, -0.40700433, -0.09363458],
-    [0.91294277, -0.40690485, -0.03104798],
-    [0.8992397, -0.43296865, -0.062499367],
-    [0.91308534, -0.34384707, -0.21919033],
-    [0.9273693, -0.34039623, -0.15529534],
-    [0.9325484, -0.2835968, -0.22344194],
-    [0.9357656, -0.30598453, -0.17525989],
-    [0.93901, -0.31703112, -0.13323525],
-    [0.9422029, -0.2645109, -0.20563965],
-    [0.9460654, -0.284349, -0.15526032],
-    [0.93463314, -0.30597225, -0.18122296],
-    [0.9304074, -0.3543135, -0.09383009],
-    [0.9324014, -0.360069, -0.031270664],
-    [0.9416977, -0.32312566, -0.0937832],
-    [0.9438091, -0.32900843, -0.031269234],
-    [0.95480126, -0.28204092, -0.09384787],
-    [0.95893526, -0.28189346, -0.03129361],
-    [0.9446425, -0.32207158, -0.06261321],
-    [0.9042902, -0.4078627, -0.1261247],
-    [0.8584523, -0.21637973, -0.46501538],
-    [0.8714971, -0.23545098, -0.43018106],
-    [0.86374426, -0.15655987, -0.47899368],
-    [0.8652482, -0.20295721, -0.45842555],
-    [0.8680021, -0.094031125, -0.48757622],
-    [0.8702115, -0.0315014, -0.49167028],
-    [0.86953425, -0.06279714, -0.48986408],
-    [0.8765209, -0.19375719, -0.44064656],
-    [0.88679457, -0.21663171, -0.40824753],
-    [0.880411, -0.15660593, -0.44760588],
-    [0.8998644, -0.15670547, -0.40704724],
-    [0.8863728, -0.18102211, -0.4261152],
-    [0.91308534, -0.21919031, -0.34384707],
-    [0.9325484, -0.22344194, -0.2835968],
-    [0.9274302, -0.15530512, -0.3402258],
-    [0.9358569, -0.17527439, -0.30569702],
-    [0.9422029, -0.20563965, -0.2645109],
-    [0.9391045, -0.13325031, -0.31674463],
-    [0.9461164, -0.15526843, -0.28417525],
-    [0.93467486, -0.18122959, -0.3058407],
-    [0.88492364, -0.09381682, -0.45618913],
-    [0.90861446, -0.09363458, -0.40700433],
-    [0.8872522, -0.031273916, -0.46022323],
-    [0.91294277, -0.03104798, -0.40690485],
-    [0.89923966, -0.062499378, -0.43296868],
-    [0.9304073, -0.09383008, -0.35431352],
-    [0.9324014, -0.031270664, -0.360069],
-    [0.9416977, -0.0937832, -0.32312566],
-    [0.95480126, -0.09384787, -0.28204092],
-    [0.9438091, -0.031269234, -0.32900843],
-    [0.95893526, -0.03129361, -0.28189346],
-    [0.9446425, -0.06261322, -0.3220716],
-    [0.90429944, -0.12612613, -0.40784165],
-    [0.9370545, -0.24690987, -0.24690987],
-    [0.9486019, -0.22377947, -0.22377947],
-    [0.9629035, -0.21970935, -0.15666673],
-    [0.96290356, -0.15666674, -0.21970937],
-    [0.9751453, -0.15667123, -0.15667123],
-    [0.9587303, -0.2010427, -0.2010427],
-    [0.9710682, -0.21948425, -0.09409146],
-    [0.9751979, -0.21907744, -0.03153171],
-    [0.983177, -0.15668844, -0.09387083],
-    [0.98715264, -0.15668546, -0.031294666],
-    [0.9801288, -0.18817203, -0.062759906],
-    [0.9710682, -0.09409146, -0.21948425],
-    [0.983177, -0.09387083, -0.15668844],
-    [0.9751979, -0.03153171, -0.21907742],
-    [0.98715264, -0.031294666, -0.15668546],
-    [0.9801288, -0.062759906, -0.18817203],
-    [0.9911904, -0.0936523, -0.0936523],
-    [0.9950792, -0.09409098, -0.031052334],
-    [0.9950792, -0.031052334, -0.09409098],
-    [0.9990212, -0.03127881, -0.03127881],
-    [0.99607605, -0.0625804, -0.06258039],
-    [0.9831613, -0.12921643, -0.12921643],
-    [0.90140903, -0.30616686, -0.306143],
-    [0.57734746, -0.57734746, -0.57735586],
-    [0.03127881, -0.9990212, 0.03127881],
-    [0.031052338, -0.9950793, 0.09409099],
-    [0.09409098, -0.9950792, 0.031052334],
-    [0.09365229, -0.9911904, 0.0936523],
-    [0.06258039, -0.99607605, 0.0625804],
-    [0.031294666, -0.9871526, 0.15668546],
-    [0.031531706, -0.97519785, 0.21907741],
-    [0.09387083, -0.983177, 0.15668844],
-    [0.09409147, -0.97106814, 0.21948425],
-    [0.062759906, -0.98012877, 0.18817201],
-    [0.15668547, -0.9871527, 0.03129467],
-    [0.1566884, -0.98317695, 0.093870826],
-    [0.21907744, -0.97519785, 0.03153171],
-    [0.21948425, -0.9710682, 0.09409147],
-    [0.18817203, -0.9801288, 0.06275991],
-    [0.15667123, -0.9751453, 0.15667123],
-    [0.15666674, -0.9629035, 0.21970937],
-    [0.21970935, -0.9629035, 0.15666673],
-    [0.22377947, -0.9486019, 0.22377947],
-    [0.24690984, -0.93705446, 0.24690984],
-    [0.20104268, -0.9587303, 0.20104268],
-    [0.12921643, -0.98316133, 0.12921643],
-    [0.03129361, -0.9589352, 0.28189343],
-    [0.031269234, -0.9438091, 0.32900843],
-    [0.031270664, -0.9324014, 0.36006898],
-    [0.093847886, -0.9548013, 0.28204095],
-    [0.0937832, -0.9416977, 0.32312563],
-    [0.09383008, -0.93040735, 0.35431355],
-    [0.06261323, -0.94464254, 0.32207158],
-    [0.03104798, -0.91294277, 0.40690488],
-    [0.031273916, -0.8872523, 0.4602232],
-    [0.09363459, -0.90861446, 0.40700433],
-    [0.09381681, -0.88492364, 0.45618913],
-    [0.062499367, -0.8992397, 0.43296865],
-    [0.0315014, -0.8702115, 0.49167028],
-    [0.09403111, -0.868002, 0.4875762],
-    [0.06279713, -0.86953425, 0.48986408],
-    [0.15526843, -0.9461163, 0.28417522],
-    [0.13325031, -0.9391045, 0.31674463],
-    [0.17527439, -0.9358569, 0.30569702],
-    [0.1553051, -0.9274302, 0.3402258],
-    [0.20563965, -0.9422029, 0.2645109],
-    [0.22344194, -0.9325484, 0.2835968],
-    [0.21919031, -0.91308534, 0.34384704],
-    [0.18122962, -0.9346749, 0.30584073],
-    [0.15670548, -0.89986444, 0.4070473],
-    [0.15660593, -0.880411, 0.44760585],
-    [0.21663171, -0.88679457, 0.40824753],
-    [0.19375719, -0.8765209, 0.44064656],
-    [0.18102211, -0.8863728, 0.4261152],
-    [0.15655987, -0.86374426, 0.47899362],
-    [0.23545098, -0.87149704, 0.43018106],
-    [0.21637973, -0.8584523, 0.46501538],
-    [0.20295721, -0.8652482, 0.45842555],
-    [0.12612613, -0.90429944, 0.4078416],
-    [0.28189343, -0.9589352, 0.03129361],
-    [0.28204095, -0.9548013, 0.093847886],
-    [0.32900843, -0.9438091, 0.031269234],
-    [0.32312563, -0.9416977, 0.0937832],
-    [0.36006904, -0.9324015, 0.031270668],
-    [0.35431355, -0.93040735, 0.09383009],
-    [0.32207152, -0.9446425, 0.062613204],
-    [0.284349, -0.9460654, 0.15526031],
-    [0.2645109, -0.9422029, 0.20563965],
-    [0.30598453, -0.9357656, 0.17525989],
-    [0.2835968, -0.9325484, 0.22344194],
-    [0.31703112, -0.93901, 0.13323525],
-    [0.3403962, -0.9273693, 0.15529533],
-    [0.34384707, -0.91308534, 0.21919033],
-    [0.30597222, -0.93463314, 0.18122295],
-    [0.4069049, -0.91294277, 0.031047983],
-    [0.40700433, -0.90861446, 0.09363459],
-    [0.4602232, -0.8872523, 0.031273916],
-    [0.45618913, -0.88492364, 0.09381681],
-    [0.43296868, -0.89923966, 0.062499367],
-    [0.40704724, -0.8998644, 0.15670548],
-    [0.40824753, -0.88679457, 0.21663174],
-    [0.44760585, -0.880411, 0.15660593],
-    [0.44064656, -0.8765209, 0.19375719],
-    [0.42611519, -0.8863728, 0.18102211],
-    [0.49167034, -0.8702115, 0.031501405],
-    [0.4875762, -0.86800206, 0.09403111],
-    [0.48986408, -0.86953425, 0.06279713],
-    [0.43018106, -0.87149704, 0.23545098],
-    [0.47899368, -0.86374426, 0.15655987],
-    [0.46501538, -0.8584523, 0.21637973],
-    [0.45842555, -0.8652482, 0.20295721],
-    [0.40786266, -0.9042902, 0.1261247],
-    [0.28164735, -0.91725105, 0.28164735],
-    [0.28651407, -0.8921842, 0.3491664],
-    [0.3493525, -0.8921737, 0.2863197],
-    [0.33201787, -0.8830307, 0.33169386],
-    [0.31267098, -0.8969861, 0.31249467],
-    [0.26893088, -0.8815713, 0.38795397],
-    [0.26893088, -0.8815713, 0.38795397],
-    [0.3104197, -0.87433016, 0.37307698],
-    [0.37338692, -0.8743126, 0.3100962],
-    [0.34950918, -0.86938053, 0.34931475],
-    [0.34467193, -0.87326556, 0.34439573],
-    [0.28682917, -0.8666205, 0.40828666],
-    [0.28547674, -0.83276856, 0.4743411],
-    [0.3084306, -0.8105078, 0.4979435],
-    [0.3449563, -0.8457808, 0.4070137],
-    [0.344883, -0.8217146, 0.45369685],
-    [0.34457406, -0.8036992, 0.4851146],
-    [0.31958115, -0.8311948, 0.45495385],
-    [0.38822573, -0.88146305, 0.26889327],
-    [0.3882258, -0.88146317, 0.2688933],
-    [0.40844813, -0.86655223, 0.28680548],
-    [0.40701374, -0.8457808, 0.3449563],
-    [0.47434115, -0.83276856, 0.28547677],
-    [0.45369685, -0.8217146, 0.344883],
-    [0.4979435, -0.8105078, 0.3084306],
-    [0.4851146, -0.8036993, 0.3445741],
-    [0.45498008, -0.83118224, 0.31957665],
-    [0.40288505, -0.8218073, 0.402885],
-    [0.378907, -0.81380486, 0.44062606],
-    [0.42039618, -0.8039072, 0.420714],
-    [0.40777215, -0.7826506, 0.4702976],
-    [0.44062606, -0.81380486, 0.378907],
-    [0.47014332, -0.7826584, 0.40793523],
-    [0.45728365, -0.7625565, 0.45760158],
-    [0.425898, -0.798189, 0.42603427],
-    [0.36085558, -0.86002004, 0.3607614],
-    [0.43595585, -0.7492584, 0.4985524],
-    [0.4985524, -0.7492584, 0.43595585],
-    [0.47463012, -0.7412505, 0.47463012],
-    [0.47002885, -0.7470914, 0.47002885],
-    [0.4700289, -0.7470915, 0.4700289],
-    [0.30616686, -0.90140903, 0.30614296],
-    [0.03151556, -0.8458994, 0.53241056],
-    [0.031489953, -0.81944174, 0.57229674],
-    [0.031259775, -0.7966522, 0.603629],
-    [0.094080195, -0.84120214, 0.53247327],
-    [0.0940329, -0.8168452, 0.56914115],
-    [0.093822606, -0.79420394, 0.6003643],
-    [0.06276856, -0.81993437, 0.56900597],
-    [0.031009154, -0.7654031, 0.64280367],
-    [0.09351146, -0.76286167, 0.63976383],
-    [0.062290855, -0.7645073, 0.6415984],
-    [0.15528637, -0.8295634, 0.53638667],
-    [0.13335358, -0.81409216, 0.56521755],
-    [0.17501052, -0.8101897, 0.55943173],
-    [0.1549694, -0.7926277, 0.5896828],
-    [0.21932015, -0.82522035, 0.52049017],
-    [0.21910737, -0.8048269, 0.5515847],
-    [0.21786655, -0.77126604, 0.59806585],
-    [0.1823241, -0.8076838, 0.56071794],
-    [0.1560054, -0.7576047, 0.63379604],
-    [0.19616963, -0.75287974, 0.62824327],
-    [0.1761242, -0.7553996, 0.63115114],
-    [0.12120852, -0.7890399, 0.602266],
-    [0.031237818, -0.73788226, 0.67420614],
-    [0.031487603, -0.7067562, 0.7067562],
-    [0.031237818, -0.67420614, 0.73788226],
-    [0.09397877, -0.735096, 0.6714177],
-    [0.09446677, -0.7039447, 0.7039446],
-    [0.09397877, -0.6714177, 0.735096],
-    [0.06280486, -0.7057109, 0.7057109],
-    [0.23801024, -0.7471068, 0.62063086],
-    [0.23801024, -0.7471068, 0.62063086],
-    [0.15645471, -0.72985584, 0.66545653],
-    [0.1568703, -0.6983523, 0.6983523],
-    [0.15645471, -0.6654565, 0.72985584],
-    [0.22027145, -0.72028565, 0.6577759],
-    [0.20234467, -0.6924799, 0.6924799],
-    [0.24374485, -0.68578, 0.68578],
-    [0.22027145, -0.6577759, 0.72028565],
-    [0.19400178, -0.6936726, 0.6936726],
-    [0.23801024, -0.62063086, 0.7471068],
-    [0.23801024, -0.62063086, 0.7471068],
-    [0.1840661, -0.695025, 0.6950251],
-    [0.031009154, -0.6428037, 0.76540315],
-    [0.09351146, -0.63976383, 0.76286167],
-    [0.062290855, -0.6415984, 0.7645073],
-    [0.03125978, -0.60362905, 0.79665226],
-    [0.031489953, -0.57229674, 0.81944174],
-    [0.03151556, -0.5324105, 0.84589946],
-    [0.09382261, -0.6003644, 0.794204],
-    [0.094032906, -0.56914115, 0.8168453],
-    [0.094080195, -0.53247327, 0.84120214],
-    [0.06276857, -0.56900597, 0.8199343],
-    [0.1560054, -0.63379604, 0.75760466],
-    [0.19616964, -0.62824327, 0.75287974],
-    [0.17612422, -0.63115114, 0.7553996],
-    [0.15492976, -0.58972514, 0.79260385],
-    [0.13328779, -0.5652887, 0.8140535],
-    [0.17494518, -0.55950457, 0.81015354],
-    [0.15524724, -0.53643084, 0.82954216],
-    [0.21786655, -0.59806585, 0.77126604],
-    [0.21888196, -0.5516121, 0.8048694],
-    [0.21909471, -0.5205178, 0.8252628],
-    [0.18222927, -0.5607578, 0.8076775],
-    [0.12118454, -0.6022749, 0.78903687],
-    [0.26691833, -0.81792694, 0.50965697],
-    [0.28548548, -0.7976628, 0.5312552],
-    [0.28145143, -0.7642177, 0.58030725],
-    [0.3450088, -0.7715333, 0.5345142],
-    [0.32420808, -0.755528, 0.5692686],
-    [0.3009246, -0.78218, 0.5455628],
-    [0.3947095, -0.76080793, 0.5151464],
-    [0.3947095, -0.76080793, 0.5151464],
-    [0.34826043, -0.7724774, 0.53103065],
-    [0.28137672, -0.739426, 0.6116178],
-    [0.36566997, -0.7459645, 0.55661696],
-    [0.3488397, -0.722172, 0.5973092],
-    [0.373092, -0.6867571, 0.6238326],
-    [0.34266987, -0.724479, 0.59808636],
-    [0.28148478, -0.70824444, 0.6474225],
-    [0.28141975, -0.67852885, 0.6785289],
-    [0.2814848, -0.6474225, 0.70824456],
-    [0.33159938, -0.6975285, 0.63521326],
-    [0.3527732, -0.6618048, 0.6614872],
-    [0.33160663, -0.6355435, 0.6972241],
-    [0.31040585, -0.672258, 0.6720991],
-    [0.28137675, -0.6116178, 0.739426],
-    [0.37309933, -0.62416285, 0.6864529],
-    [0.34884685, -0.5974804, 0.72202694],
-    [0.36548474, -0.55680907, 0.74591184],
-    [0.34262776, -0.5982611, 0.7243547],
-    [0.4119511, -0.7380218, 0.5344343],
-    [0.40663823, -0.7040583, 0.5821918],
-    [0.40687314, -0.6770918, 0.6131893],
-    [0.4673896, -0.70701736, 0.5307293],
-    [0.44491085, -0.6923032, 0.56812906],
-    [0.48661032, -0.67813694, 0.5507637],
-    [0.4674735, -0.6558311, 0.59275126],
-    [0.44221538, -0.6940294, 0.56812745],
-    [0.40705562, -0.6458738, 0.6458738],
-    [0.44770122, -0.632442, 0.632124],
-    [0.42748648, -0.6393195, 0.63916045],
-    [0.48917478, -0.61688745, 0.6165694],
-    [0.48917484, -0.6168875, 0.61656946],
-    [0.40674835, -0.6133388, 0.67703134],
-    [0.40651596, -0.58234537, 0.70400196],
-    [0.4118869, -0.5344142, 0.7380723],
-    [0.4674408, -0.5929143, 0.6557072],
-    [0.44483423, -0.5681224, 0.69235796],
-    [0.48655805, -0.55073935, 0.6781944],
-    [0.46735737, -0.53071374, 0.70705014],
-    [0.4421448, -0.5681868, 0.6940257],
-    [0.40136042, -0.647725, 0.64758176],
-    [0.28123412, -0.5803449, 0.764269],
-    [0.28516498, -0.5311748, 0.797831],
-    [0.2666699, -0.5094646, 0.8181278],
-    [0.3239224, -0.5693265, 0.7556068],
-    [0.34472787, -0.5346671, 0.7715529],
-    [0.30065462, -0.5455595, 0.7822861],
-    [0.39460036, -0.5151102, 0.760889],
-    [0.39460036, -0.5151102, 0.760889],
-    [0.34807205, -0.5310131, 0.7725743],
-    [0.25789997, -0.6831899, 0.683183],
-    [0.031501405, -0.49167037, 0.8702115],
-    [0.094031125, -0.48757622, 0.8680021],
-    [0.062797144, -0.48986414, 0.86953425],
-    [0.031273916, -0.4602232, 0.8872522],
-    [0.03104798, -0.40690485, 0.91294277],
-    [0.09381683, -0.4561892, 0.8849237],
-    [0.09363458, -0.40700433, 0.90861446],
-    [0.062499374, -0.43296865, 0.89923966],
-    [0.031270664, -0.360069, 0.9324014],
-    [0.031269234, -0.32900846, 0.9438091],
-    [0.03129361, -0.28189346, 0.95893526],
-    [0.09383009, -0.3543135, 0.9304074],
-    [0.0937832, -0.32312563, 0.9416977],
-    [0.09384787, -0.28204092, 0.95480126],
-    [0.06261321, -0.32207158, 0.9446425],
-    [0.15655987, -0.47899368, 0.86374426],
-    [0.21637973, -0.46501538, 0.8584523],
-    [0.23515163, -0.430212, 0.8715626],
-    [0.20285669, -0.45843437, 0.8652671],
-    [0.15660593, -0.44760588, 0.880411],
-    [0.15670547, -0.40704724, 0.8998644],
-    [0.19375718, -0.44064656, 0.8765209],
-    [0.21645129, -0.4082647, 0.88683075],
-    [0.18097666, -0.42611876, 0.8863803],
-    [0.1553051, -0.3402258, 0.9274302],
-    [0.13325031, -0.31674463, 0.9391045],
-    [0.17527437, -0.30569702, 0.9358569],
-    [0.15526842, -0.28417522, 0.9461163],
-    [0.21919031, -0.34384707, 0.91308534],
-    [0.22344194, -0.2835968, 0.9325484],
-    [0.20563968, -0.26451087, 0.9422029],
-    [0.18122959, -0.30584067, 0.93467486],
-    [0.12610146, -0.40784302, 0.90430224],
-    [0.03153171, -0.21907742, 0.9751979],
-    [0.031294666, -0.15668546, 0.98715264],
-    [0.09409146, -0.21948425, 0.9710682],
-    [0.09387083, -0.15668844, 0.983177],
-    [0.06275991, -0.18817203, 0.9801288],
-    [0.031052334, -0.09409098, 0.9950792],
-    [0.03127881, -0.03127881, 0.9990212],
-    [0.0936523, -0.0936523, 0.9911904],
-    [0.09409098, -0.031052334, 0.9950792],
-    [0.0625804, -0.06258039, 0.99607605],
-    [0.15666674, -0.21970937, 0.96290356],
-    [0.15667123, -0.15667123, 0.9751453],
-    [0.24690987, -0.24690987, 0.9370545],
-    [0.22377947, -0.22377947, 0.9486019],
-    [0.21970935, -0.15666673, 0.9629035],
-    [0.20104268, -0.20104268, 0.9587303],
-    [0.15668844, -0.09387083, 0.983177],
-    [0.15668546, -0.031294666, 0.98715264],
-    [0.21948425, -0.09409146, 0.9710682],
-    [0.21907744, -0.03153171, 0.9751979],
-    [0.188172, -0.062759906, 0.98012877],
-    [0.12921643, -0.12921643, 0.98316133],
-    [0.43602434, -0.4983138, 0.7493772],
-    [0.47463012, -0.47463012, 0.7412505],
-    [0.4983138, -0.43602434, 0.7493772],
-    [0.4699694, -0.4699694, 0.74716645],
-    [0.4699694, -0.4699694, 0.74716645],
-    [0.30847806, -0.49770465, 0.81063646],
-    [0.285504, -0.47419554, 0.8328422],
-    [0.28680262, -0.40845034, 0.86655223],
-    [0.34436643, -0.48515105, 0.80376625],
-    [0.34467536, -0.45373356, 0.8217815],
-    [0.3449563, -0.4070137, 0.8457808],
-    [0.31951776, -0.45492625, 0.8312342],
-    [0.26888812, -0.38822898, 0.8814632],
-    [0.26888812, -0.38822898, 0.8814632],
-    [0.3106713, -0.37331337, 0.8741399],
-    [0.34948465, -0.34948465, 0.8693221],
-    [0.37331337, -0.31067127, 0.8741399],
-    [0.34472048, -0.34472042, 0.87311834],
-    [0.28666767, -0.3493115, 0.8920779],
-    [0.28164735, -0.28164735, 0.91725105],
-    [0.33197904, -0.33197904, 0.88293827],
-    [0.3493115, -0.28666767, 0.8920779],
-    [0.31268921, -0.31268921, 0.8969119],
-    [0.40775257, -0.47012252, 0.78276604],
-    [0.3785345, -0.4405664, 0.8140105],
-    [0.4204447, -0.42044467, 0.80402267],
-    [0.4027558, -0.4027558, 0.8219339],
-    [0.45753765, -0.45753765, 0.7624424],
-    [0.47012255, -0.4077526, 0.7827661],
-    [0.44056633, -0.3785344, 0.81401044],
-    [0.42585835, -0.42585832, 0.7983041],
-    [0.40701374, -0.3449563, 0.84578073],
-    [0.40845034, -0.28680262, 0.86655223],
-    [0.48515105, -0.3443664, 0.80376625],
-    [0.45373356, -0.34467536, 0.8217815],
-    [0.49770465, -0.30847806, 0.81063646],
-    [0.47419563, -0.285504, 0.8328421],
-    [0.45492628, -0.31951776, 0.8312342],
-    [0.38822904, -0.26888818, 0.8814633],
-    [0.38822898, -0.26888815, 0.88146317],
-    [0.36083505, -0.36083502, 0.85999775],
-    [0.2835968, -0.22344194, 0.9325484],
-    [0.26451087, -0.20563965, 0.9422029],
-    [0.30569702, -0.17527439, 0.9358569],
-    [0.28417525, -0.15526843, 0.9461164],
-    [0.34384704, -0.2191903, 0.9130852],
-    [0.3402258, -0.15530512, 0.9274302],
-    [0.31674463, -0.13325031, 0.9391045],
-    [0.30584073, -0.1812296, 0.9346749],
-    [0.28204092, -0.09384787, 0.95480126],
-    [0.28189346, -0.03129361, 0.95893526],
-    [0.35431352, -0.09383008, 0.9304073],
-    [0.32312563, -0.0937832, 0.9416977],
-    [0.360069, -0.031270664, 0.9324014],
-    [0.32900846, -0.031269234, 0.9438091],
-    [0.32207158, -0.06261321, 0.9446425],
-    [0.430212, -0.2351516, 0.8715626],
-    [0.46501538, -0.21637973, 0.8584523],
-    [0.47899368, -0.15655987, 0.86374426],
-    [0.45843437, -0.20285669, 0.8652671],
-    [0.4082647, -0.2164513, 0.88683075],
-    [0.40704724, -0.15670547, 0.8998644],
-    [0.44064656, -0.19375718, 0.8765209],
-    [0.44760585, -0.15660593, 0.88041097],
-    [0.42611876, -0.18097667, 0.8863803],
-    [0.48757622, -0.094031125, 0.8680021],
-    [0.49167028, -0.0315014, 0.8702115],
-    [0.48986408, -0.06279714, 0.86953425],
-    [0.40700433, -0.09363458, 0.90861446],
-    [0.40690485, -0.03104798, 0.91294277],
-    [0.45618913, -0.09381682, 0.88492364],
-    [0.46022323, -0.031273916, 0.8872522],
-    [0.43296865, -0.062499367, 0.8992397],
-    [0.4078429, -0.12610145, 0.90430224],
-    [0.3061401, -0.30614012, 0.9014191],
-    [0.5324105, -0.8458994, 0.03151556],
-    [0.53247327, -0.8412022, 0.09408019],
-    [0.57229674, -0.81944174, 0.031489953],
-    [0.56914115, -0.8168452, 0.0940329],
-    [0.603629, -0.79665226, 0.03125978],
-    [0.6003643, -0.79420394, 0.093822606],
-    [0.56900597, -0.81993437, 0.06276856],
-    [0.5366531, -0.8293968, 0.15525566],
-    [0.52049017, -0.82522035, 0.21932015],
-    [0.5598676, -0.80990016, 0.17495714],
-    [0.5515847, -0.8048269, 0.21910739],
-    [0.56565136, -0.8137997, 0.13329963],
-    [0.5899191, -0.7924223, 0.15512083],
-    [0.5982785, -0.77106327, 0.21800065],
-    [0.56094617, -0.8075206, 0.18234569],
-    [0.64280367, -0.7654031, 0.031009154],
-    [0.63976383, -0.76286167, 0.09351146],
-    [0.6415984, -0.7645073, 0.062290855],
-    [0.6337491, -0.7575485, 0.15646799],
-    [0.6285504, -0.7524859, 0.19669631],
-    [0.6312817, -0.75517493, 0.17661905],
-    [0.6023601, -0.78894806, 0.12133883],
-    [0.50965697, -0.81792694, 0.26691833],
-    [0.5312552, -0.7976628, 0.28548548],
-    [0.53451157, -0.77153325, 0.3450128],
-    [0.58030725, -0.7642177, 0.28145143],
-    [0.5691334, -0.75566536, 0.32412472],
-    [0.5455343, -0.7822062, 0.30090824],
-    [0.515278, -0.76067156, 0.39480042],
-    [0.515278, -0.76067156, 0.39480042],
-    [0.53108335, -0.77242386, 0.34829846],
-    [0.6209723, -0.7467547, 0.23822434],
-    [0.62097234, -0.74675477, 0.23822437],
-    [0.67420614, -0.73788226, 0.031237818],
-    [0.6714177, -0.735096, 0.09397876],
-    [0.7067562, -0.7067562, 0.031487603],
-    [0.7039446, -0.7039447, 0.09446677],
-    [0.73788226, -0.67420614, 0.031237818],
-    [0.735096, -0.67141783, 0.093978785],
-    [0.7057108, -0.7057108, 0.062804855],
-    [0.665433, -0.72982764, 0.15668593],
-    [0.65791845, -0.72007656, 0.22052874],
-    [0.68578, -0.68578, 0.24374485],
-    [0.6983523, -0.6983523, 0.1568703],
-    [0.6924799, -0.6924799, 0.20234467],
-    [0.7298277, -0.665433, 0.15668592],
-    [0.72007656, -0.65791845, 0.22052875],
-    [0.69365317, -0.69365317, 0.19414084],
-    [0.74675465, -0.6209723, 0.23822431],
-    [0.7467547, -0.62097234, 0.23822434],
-    [0.6950065, -0.69500655, 0.18420568],
-    [0.5565471, -0.74587893, 0.36595076],
-    [0.6115759, -0.7393764, 0.28159812],
-    [0.5973131, -0.72213846, 0.34890276],
-    [0.6240265, -0.6866207, 0.37301892],
-    [0.59810805, -0.7244028, 0.34279317],
-    [0.5344669, -0.73787886, 0.41216496],
-    [0.5307293, -0.70701736, 0.4673896],
-    [0.5507637, -0.67813694, 0.48661032],
-    [0.58223426, -0.70386404, 0.40691346],
-    [0.56825644, -0.69213676, 0.44500726],
-    [0.61328274, -0.6769644, 0.40694398],
-    [0.5929463, -0.6557199, 0.46738228],
-    [0.56819654, -0.6939219, 0.4422954],
-    [0.61683726, -0.61683726, 0.48890042],
-    [0.61683726, -0.61683726, 0.48890042],
-    [0.64738035, -0.70819503, 0.28170618],
-    [0.63540477, -0.6973893, 0.33152494],
-    [0.6785289, -0.67852885, 0.28141975],
-    [0.66166675, -0.66166675, 0.35269552],
-    [0.70819503, -0.64738035, 0.28170618],
-    [0.6973894, -0.63540477, 0.33152497],
-    [0.6721709, -0.6721708, 0.31043956],
-    [0.6458738, -0.6458738, 0.40705562],
-    [0.6323825, -0.6323825, 0.44741994],
-    [0.6392875, -0.6392875, 0.42734426],
-    [0.6866207, -0.6240265, 0.37301892],
-    [0.7393764, -0.6115759, 0.28159812],
-    [0.72213846, -0.59731305, 0.34890276],
-    [0.74587893, -0.5565471, 0.36595076],
-    [0.7244028, -0.59810805, 0.34279317],
-    [0.6769644, -0.61328274, 0.406944],
-    [0.6557199, -0.5929463, 0.46738228],
-    [0.67813694, -0.5507637, 0.48661032],
-    [0.70386404, -0.58223426, 0.4069135],
-    [0.69213676, -0.56825644, 0.44500726],
-    [0.73787886, -0.5344669, 0.41216496],
-    [0.70701736, -0.5307293, 0.4673896],
-    [0.69392186, -0.56819654, 0.44229534],
-    [0.64764833, -0.6476484, 0.40137666],
-    [0.76540303, -0.6428036, 0.03100915],
-    [0.76286167, -0.63976383, 0.09351146],
-    [0.7645073, -0.6415984, 0.062290855],
-    [0.7575485, -0.6337491, 0.15646799],
-    [0.7524859, -0.6285504, 0.19669628],
-    [0.75517493, -0.6312817, 0.17661902],
-    [0.79665226, -0.60362905, 0.03125978],
-    [0.79420394, -0.6003643, 0.093822606],
-    [0.81944174, -0.57229674, 0.031489953],
-    [0.8168453, -0.56914115, 0.094032906],
-    [0.8458994, -0.5324105, 0.03151556],
-    [0.8412022, -0.53247327, 0.09408019],
-    [0.8199343, -0.56900597, 0.06276856],
-    [0.7924223, -0.58991903, 0.15512083],
-    [0.77106327, -0.5982785, 0.21800068],
-    [0.80990016, -0.5598676, 0.17495714],
-    [0.8048269, -0.5515847, 0.21910737],
-    [0.8137997, -0.56565136, 0.13329963],
-    [0.8293967, -0.53665316, 0.15525566],
-    [0.82522035, -0.52049017, 0.21932015],
-    [0.80752057, -0.56094617, 0.18234569],
-    [0.78894806, -0.6023601, 0.12133882],
-    [0.7642176, -0.58030725, 0.28145146],
-    [0.75566536, -0.5691334, 0.32412475],
-    [0.7976628, -0.5312552, 0.28548548],
-    [0.77153325, -0.5345115, 0.3450128],
-    [0.81792694, -0.50965697, 0.2669183],
-    [0.78220624, -0.5455343, 0.30090824],
-    [0.76067156, -0.51527786, 0.39480042],
-    [0.7606717, -0.5152779, 0.39480048],
-    [0.77242386, -0.5310833, 0.3482985],
-    [0.6831648, -0.68316483, 0.2580152],
-    [0.5088563, -0.6943562, 0.5088563],
-    [0.53269184, -0.65762943, 0.5326941],
-    [0.5137616, -0.63638526, 0.5753806],
-    [0.57526654, -0.63657665, 0.51365227],
-    [0.5331593, -0.65687174, 0.5331611],
-    [0.55560803, -0.61854213, 0.55561256],
-    [0.5349534, -0.59742147, 0.5974215],
-    [0.5557188, -0.5557188, 0.6183473],
-    [0.5974675, -0.5974675, 0.53485054],
-    [0.57735026, -0.57735026, 0.57735026],
-    [0.6185422, -0.55560803, 0.55561256],
-    [0.59742147, -0.5349535, 0.59742147],
-    [0.5773715, -0.5773715, 0.5773078],
-    [0.5137616, -0.5753806, 0.63638526],
-    [0.5327861, -0.5327861, 0.65747863],
-    [0.5089309, -0.5089309, 0.69424677],
-    [0.5753806, -0.5137616, 0.63638526],
-    [0.53322995, -0.53322995, 0.6567585],
-    [0.63657665, -0.57526654, 0.51365227],
-    [0.6576295, -0.53269184, 0.5326941],
-    [0.63638526, -0.5137616, 0.5753806],
-    [0.6943562, -0.5088563, 0.5088563],
-    [0.65687174, -0.5331593, 0.5331611],
-    [0.5773707, -0.5773707, 0.5773095],
-    [0.5773707, -0.5773707, 0.5773095],
-    [0.7492584, -0.4985524, 0.43595585],
-    [0.7412505, -0.47463012, 0.47463012],
-    [0.7492584, -0.43595585, 0.4985524],
-    [0.7470914, -0.47002885, 0.47002885],
-    [0.7470915, -0.4700289, 0.4700289],
-    [0.8702115, -0.49167037, 0.031501405],
-    [0.86800206, -0.48757622, 0.09403112],
-    [0.86953413, -0.48986408, 0.06279713],
-    [0.86374426, -0.47899368, 0.15655987],
-    [0.8584523, -0.46501538, 0.21637973],
-    [0.8714971, -0.43018106, 0.23545098],
-    [0.8652482, -0.45842555, 0.20295721],
-    [0.8872522, -0.4602232, 0.031273916],
-    [0.8849237, -0.4561892, 0.09381683],
-    [0.91294277, -0.4069049, 0.031047983],
-    [0.90861446, -0.40700433, 0.09363458],
-    [0.89923966, -0.43296868, 0.062499374],
-    [0.880411, -0.44760588, 0.15660593],
-    [0.8765209, -0.44064656, 0.19375718],
-    [0.8998644, -0.40704724, 0.15670548],
-    [0.88679457, -0.40824753, 0.21663171],
-    [0.8863728, -0.4261152, 0.18102211],
-    [0.9324014, -0.360069, 0.031270664],
-    [0.9304073, -0.3543135, 0.09383008],
-    [0.9438091, -0.32900846, 0.031269234],
-    [0.9416977, -0.32312563, 0.0937832],
-    [0.9589352, -0.28189343, 0.03129361],
-    [0.9548013, -0.28204095, 0.093847886],
-    [0.9446425, -0.32207158, 0.06261322],
-    [0.9273693, -0.3403962, 0.15529533],
-    [0.91308534, -0.34384707, 0.21919033],
-    [0.9357656, -0.30598453, 0.17525989],
-    [0.9325484, -0.2835968, 0.22344194],
-    [0.93901, -0.31703112, 0.13323525],
-    [0.9460654, -0.284349, 0.15526032],
-    [0.9422029, -0.26451087, 0.20563968],
-    [0.93463314, -0.30597222, 0.18122296],
-    [0.9042902, -0.40786266, 0.1261247],
-    [0.8105078, -0.4979435, 0.3084306],
-    [0.8036992, -0.48511457, 0.3445741],
-    [0.83276856, -0.47434115, 0.28547677],
-    [0.8217146, -0.45369688, 0.344883],
-    [0.86655223, -0.40844816, 0.28680548],
-    [0.8457808, -0.40701374, 0.3449563],
-    [0.8311822, -0.45498008, 0.31957665],
-    [0.7826584, -0.47014335, 0.40793523],
-    [0.7625566, -0.45728362, 0.45760158],
-    [0.80390716, -0.42039615, 0.420714],
-    [0.78265065, -0.40777215, 0.4702976],
-    [0.81380486, -0.44062606, 0.378907],
-    [0.8218073, -0.402885, 0.40288505],
-    [0.81380486, -0.378907, 0.44062606],
-    [0.79818904, -0.425898, 0.42603436],
-    [0.8743127, -0.37338692, 0.3100962],
-    [0.86938053, -0.3495092, 0.34931475],
-    [0.87433016, -0.3104197, 0.37307698],
-    [0.87326556, -0.34467193, 0.34439573],
-    [0.80369925, -0.3445741, 0.4851146],
-    [0.8105078, -0.3084306, 0.4979435],
-    [0.8457808, -0.3449563, 0.4070137],
-    [0.82171464, -0.34488305, 0.4536969],
-    [0.8666205, -0.28682917, 0.4082866],
-    [0.83276856, -0.28547674, 0.4743411],
-    [0.8311948, -0.31958112, 0.4549538],
-    [0.88146305, -0.38822573, 0.2688933],
-    [0.88146317, -0.3882258, 0.26889333],
-    [0.8921737, -0.34935254, 0.28631976],
-    [0.8830307, -0.3320178, 0.33169383],
-    [0.91725105, -0.28164735, 0.28164735],
-    [0.8921842, -0.286514, 0.34916633],
-    [0.89698607, -0.31267092, 0.3124946],
-    [0.8815712, -0.26893088, 0.38795394],
-    [0.8815713, -0.2689309, 0.387954],
-    [0.86002004, -0.36085558, 0.36076143],
-    [0.97519785, -0.21907744, 0.03153171],
-    [0.9710682, -0.21948425, 0.09409147],
-    [0.9871527, -0.15668547, 0.03129467],
-    [0.983177, -0.15668842, 0.09387083],
-    [0.9801288, -0.18817203, 0.06275991],
-    [0.9370545, -0.24690987, 0.24690987],
-    [0.9629035, -0.21970935, 0.15666673],
-    [0.9486019, -0.22377947, 0.22377947],
-    [0.9751453, -0.15667123, 0.15667123],
-    [0.96290356, -0.15666674, 0.21970937],
-    [0.9587303, -0.20104268, 0.20104268],
-    [0.9950792, -0.09409098, 0.031052334],
-    [0.9911904, -0.0936523, 0.0936523],
-    [0.9990212, -0.03127881, 0.03127881],
-    [0.9950793, -0.031052338, 0.09409099],
-    [0.99607605, -0.0625804, 0.0625804],
-    [0.983177, -0.09387083, 0.15668844],
-    [0.97106814, -0.09409147, 0.21948425],
-    [0.9871526, -0.031294666, 0.15668547],
-    [0.97519785, -0.031531706, 0.21907741],
-    [0.98012877, -0.06275991, 0.18817203],
-    [0.98316133, -0.12921645, 0.12921645],
-    [0.8714971, -0.23545098, 0.43018106],
-    [0.8584523, -0.21637972, 0.46501538],
-    [0.86374426, -0.15655988, 0.47899368],
-    [0.8652482, -0.20295721, 0.45842555],
-    [0.8680021, -0.094031125, 0.48757622],
-    [0.8702115, -0.0315014, 0.49167028],
-    [0.86953425, -0.06279714, 0.48986408],
-    [0.9325484, -0.22344194, 0.2835968],
-    [0.91308534, -0.21919031, 0.34384704],
-    [0.9358569, -0.17527439, 0.30569702],
-    [0.9274302, -0.15530512, 0.3402258],
-    [0.9422029, -0.20563965, 0.26451087],
-    [0.9461164, -0.15526843, 0.28417525],
-    [0.9391045, -0.13325031, 0.31674463],
-    [0.93467486, -0.18122959, 0.3058407],
-    [0.88679457, -0.21663171, 0.40824753],
-    [0.8765209, -0.19375718, 0.44064656],
-    [0.89986444, -0.15670548, 0.4070473],
-    [0.880411, -0.15660593, 0.44760588],
-    [0.88637286, -0.18102211, 0.4261152],
-    [0.93040735, -0.09383009, 0.3543135],
-    [0.9324014, -0.031270664, 0.360069],
-    [0.9548013, -0.093847886, 0.28204095],
-    [0.9416977, -0.0937832, 0.32312566],
-    [0.95893526, -0.03129361, 0.28189343],
-    [0.9438091, -0.031269234, 0.32900843],
-    [0.94464254, -0.06261322, 0.32207158],
-    [0.90861434, -0.09363457, 0.4070043],
-    [0.88492364, -0.09381682, 0.45618913],
-    [0.91294277, -0.03104798, 0.40690488],
-    [0.8872522, -0.031273916, 0.46022323],
-    [0.89923966, -0.06249937, 0.43296868],
-    [0.90429944, -0.12612613, 0.40784165],
-    [0.90140903, -0.30616686, 0.306143],
-    [0.61688745, -0.48917478, 0.6165694],
-    [0.6168875, -0.48917484, 0.61656946],
-    [0.55073935, -0.48655805, 0.6781944],
-    [0.5307137, -0.4673574, 0.7070502],
-    [0.53441423, -0.4118869, 0.7380723],
-    [0.5929142, -0.46744084, 0.6557072],
-    [0.56812227, -0.44483417, 0.69235796],
-    [0.6133388, -0.4067483, 0.6770313],
-    [0.58234537, -0.40651593, 0.70400196],
-    [0.5681868, -0.44214487, 0.6940257],
-    [0.55680907, -0.36548474, 0.74591184],
-    [0.62416285, -0.37309936, 0.6864529],
-    [0.59748036, -0.34884685, 0.722027],
-    [0.6116178, -0.28137675, 0.739426],
-    [0.5982611, -0.34262776, 0.72435474],
-    [0.67813694, -0.48661035, 0.5507637],
-    [0.65583116, -0.4674735, 0.59275126],
-    [0.6770918, -0.40687314, 0.6131893],
-    [0.70701736, -0.4673896, 0.5307293],
-    [0.6923032, -0.44491085, 0.56812906],
-    [0.7380218, -0.41195104, 0.5344343],
-    [0.70405823, -0.4066382, 0.5821918],
-    [0.6940294, -0.44221535, 0.56812745],
-    [0.63244194, -0.44770122, 0.63212395],
-    [0.6458738, -0.40705565, 0.6458738],
-    [0.6393195, -0.42748648, 0.63916045],
-    [0.6867571, -0.37309206, 0.62383264],
-    [0.7459645, -0.36566997, 0.55661696],
-    [0.722172, -0.34883973, 0.5973092],
-    [0.73942596, -0.28137672, 0.6116178],
-    [0.724479, -0.3426699, 0.5980864],
-    [0.66180485, -0.35277322, 0.6614873],
-    [0.63554347, -0.33160666, 0.69722414],
-    [0.67852885, -0.28141975, 0.6785289],
-    [0.6474225, -0.2814848, 0.70824456],
-    [0.6975285, -0.33159938, 0.63521314],
-    [0.70824456, -0.2814848, 0.6474225],
-    [0.67225796, -0.31040585, 0.6720991],
-    [0.6477249, -0.40136042, 0.64758176],
-    [0.5151102, -0.39460042, 0.76088905],
-    [0.5151102, -0.39460042, 0.76088905],
-    [0.53466713, -0.34472784, 0.7715529],
-    [0.5311748, -0.28516495, 0.797831],
-    [0.5094646, -0.26666993, 0.8181278],
-    [0.5693265, -0.3239224, 0.7556068],
-    [0.5803449, -0.28123412, 0.764269],
-    [0.5455595, -0.30065462, 0.7822861],
-    [0.531013, -0.34807205, 0.7725743],
-    [0.62063086, -0.23801026, 0.7471068],
-    [0.62063086, -0.23801026, 0.7471068],
-    [0.7471068, -0.23801026, 0.62063086],
-    [0.7471068, -0.23801026, 0.62063086],
-    [0.68578, -0.24374485, 0.68578],
-    [0.6577759, -0.22027145, 0.72028565],
-    [0.66545653, -0.15645474, 0.72985584],
-    [0.72028553, -0.22027145, 0.6577759],
-    [0.6924799, -0.20234467, 0.6924799],
-    [0.72985584, -0.1564547, 0.6654565],
-    [0.6983523, -0.1568703, 0.6983523],
-    [0.6936726, -0.1940018, 0.6936725],
-    [0.6714177, -0.09397877, 0.735096],
-    [0.67420614, -0.031237818, 0.73788226],
-    [0.735096, -0.09397877, 0.6714177],
-    [0.7039446, -0.09446676, 0.7039446],
-    [0.73788226, -0.031237818, 0.67420614],
-    [0.7067562, -0.031487603, 0.7067562],
-    [0.7057109, -0.06280486, 0.7057109],
-    [0.6950251, -0.18406613, 0.695025],
-    [0.5516121, -0.21888196, 0.8048694],
-    [0.5205178, -0.21909471, 0.8252628],
-    [0.55950457, -0.17494518, 0.81015354],
-    [0.5364308, -0.15524723, 0.82954216],
-    [0.59806585, -0.21786658, 0.77126616],
-    [0.58972514, -0.15492976, 0.79260385],
-    [0.5652887, -0.13328779, 0.8140535],
-    [0.5607578, -0.18222927, 0.8076775],
-    [0.53247327, -0.09408019, 0.84120214],
-    [0.5324105, -0.03151556, 0.84589946],
-    [0.6003644, -0.09382261, 0.794204],
-    [0.56914115, -0.0940329, 0.8168452],
-    [0.60362905, -0.03125978, 0.79665226],
-    [0.57229674, -0.031489953, 0.81944174],
-    [0.56900597, -0.062768556, 0.8199343],
-    [0.6282432, -0.19616966, 0.75287986],
-    [0.6337961, -0.15600541, 0.7576048],
-    [0.6311511, -0.1761242, 0.75539964],
-    [0.63976383, -0.09351147, 0.7628616],
-    [0.6428037, -0.031009154, 0.76540315],
-    [0.6415984, -0.06229086, 0.7645073],
-    [0.6022749, -0.12118453, 0.7890369],
-    [0.7608078, -0.3947095, 0.5151464],
-    [0.76080793, -0.39470956, 0.51514643],
-    [0.77153325, -0.34500876, 0.53451425],
-    [0.75552785, -0.32420805, 0.5692685],
-    [0.7976628, -0.28548548, 0.5312552],
-    [0.7642177, -0.28145146, 0.5803073],
-    [0.81792694, -0.26691833, 0.50965697],
-    [0.78218, -0.3009246, 0.5455628],
-    [0.7724774, -0.34826043, 0.53103065],
-    [0.8048269, -0.21910737, 0.5515847],
-    [0.77126616, -0.21786655, 0.5980658],
-    [0.8101897, -0.17501052, 0.55943173],
-    [0.79262763, -0.1549694, 0.5896828],
-    [0.82522035, -0.21932015, 0.52049017],
-    [0.8295634, -0.15528639, 0.5363867],
-    [0.81409216, -0.13335358, 0.5652176],
-    [0.8076838, -0.18232408, 0.560718],
-    [0.75287986, -0.19616966, 0.6282432],
-    [0.7576048, -0.15600541, 0.6337961],
-    [0.75539964, -0.1761242, 0.6311511],
-    [0.794204, -0.09382262, 0.6003644],
-    [0.7966522, -0.031259775, 0.603629],
-    [0.84120214, -0.094080195, 0.53247327],
-    [0.81684524, -0.094032906, 0.5691412],
-    [0.8458994, -0.03151556, 0.53241056],
-    [0.81944174, -0.031489953, 0.57229674],
-    [0.8199343, -0.06276856, 0.56900597],
-    [0.7628616, -0.09351147, 0.63976383],
-    [0.76540315, -0.031009154, 0.6428037],
-    [0.7645073, -0.06229086, 0.6415984],
-    [0.78903997, -0.12120853, 0.6022661],
-    [0.6831899, -0.25789994, 0.683183],
-    [0.57734746, -0.57734746, 0.57735586],
-    [0.03127881, 0.03127881, -0.9990212],
-    [0.031052338, 0.09409099, -0.9950793],
-    [0.09409098, 0.031052334, -0.9950792],
-    [0.09365229, 0.0936523, -0.9911904],
-    [0.06258039, 0.0625804, -0.99607605],
-    [0.03129467, 0.15668547, -0.9871527],
-    [0.031531706, 0.21907741, -0.97519785],
-    [0.09387083, 0.15668844, -0.983177],
-    [0.09409147, 0.21948425, -0.97106814],
-    [0.06275991, 0.18817203, -0.9801288],
-    [0.15668547, 0.03129467, -0.9871527],
-    [0.1566884, 0.093870826, -0.98317695],
-    [0.21907744, 0.03153171, -0.97519785],
-    [0.21948425, 0.09409147, -0.9710682],
-    [0.18817203, 0.06275991, -0.9801288],
-    [0.15667123, 0.15667123, -0.9751453],
-    [0.15666674, 0.21970937, -0.9629035],
-    [0.21970935, 0.15666673, -0.9629035],
-    [0.22377947, 0.22377947, -0.9486019],
-    [0.24690987, 0.24690987, -0.9370545],
-    [0.20104268, 0.20104268, -0.9587303],
-    [0.12921643, 0.12921643, -0.98316133],
-    [0.03129361, 0.28189343, -0.9589352],
-    [0.031269234, 0.32900843, -0.9438091],
-    [0.031270664, 0.360069, -0.9324014],
-    [0.093847886, 0.28204095, -0.9548013],
-    [0.0937832, 0.32312566, -0.9416977],
-    [0.09383008, 0.3543135, -0.9304073],
-    [0.06261322, 0.32207158, -0.9446425],
-    [0.03104798, 0.40690488, -0.91294277],
-    [0.031273916, 0.4602232, -0.8872523],
-    [0.09363459, 0.40700433, -0.90861446],
-    [0.09381681, 0.45618913, -0.88492364],
-    [0.062499367, 0.43296865, -0.8992397],
-    [0.031501405, 0.49167037, -0.8702115],
-    [0.09403112, 0.48757622, -0.86800206],
-    [0.06279714, 0.48986414, -0.86953425],
-    [0.15526842, 0.28417522, -0.9461163],
-    [0.17527437, 0.30569702, -0.9358569],
-    [0.13325031, 0.31674463, -0.9391045],
-    [0.1553051, 0.3402258, -0.9274302],
-    [0.20563965, 0.26451087, -0.9422029],
-    [0.22344194, 0.2835968, -0.9325484],
-    [0.21919031, 0.34384704, -0.91308534],
-    [0.1812296, 0.30584073, -0.9346749],
-    [0.15670548, 0.4070473, -0.89986444],
-    [0.15660591, 0.44760588, -0.880411],
-    [0.21645129, 0.4082647, -0.88683075],
-    [0.19375718, 0.44064656, -0.8765209],
-    [0.18097666, 0.42611876, -0.8863803],
-    [0.15655987, 0.47899368, -0.86374426],
-    [0.23515163, 0.430212, -0.8715626],
-    [0.21637972, 0.46501538, -0.8584523],
-    [0.20285669, 0.45843437, -0.8652671],
-    [0.12610146, 0.40784302, -0.9043023],
-    [0.28189343, 0.03129361, -0.9589352],
-    [0.28204095, 0.093847886, -0.9548013],
-    [0.32900843, 0.031269234, -0.9438091],
-    [0.36006904, 0.031270668, -0.9324015],
-    [0.32312563, 0.0937832, -0.9416977],
-    [0.3543135, 0.09383008, -0.9304073],
-    [0.32207158, 0.06261322, -0.94464254],
-    [0.28417525, 0.15526843, -0.9461164],
-    [0.30569702, 0.17527439, -0.9358569],
-    [0.26451087, 0.20563968, -0.9422029],
-    [0.2835968, 0.22344194, -0.9325484],
-    [0.31674463, 0.13325031, -0.9391045],
-    [0.3402258, 0.15530512, -0.9274302],
-    [0.34384704, 0.2191903, -0.9130852],
-    [0.30584067, 0.18122958, -0.93467486],
-    [0.4069049, 0.031047983, -0.91294277],
-    [0.40700433, 0.09363459, -0.90861446],
-    [0.4602232, 0.031273916, -0.8872522],
-    [0.45618913, 0.09381681, -0.88492364],
-    [0.43296868, 0.062499367, -0.89923966],
-    [0.49167034, 0.031501405, -0.8702115],
-    [0.4875762, 0.09403111, -0.86800206],
-    [0.48986408, 0.06279713, -0.86953425],
-    [0.40704724, 0.15670548, -0.8998644],
-    [0.4082647, 0.21645129, -0.88683075],
-    [0.44760585, 0.15660593, -0.880411],
-    [0.44064656, 0.19375718, -0.8765209],
-    [0.42611876, 0.18097667, -0.88638043],
-    [0.430212, 0.2351516, -0.8715626],
-    [0.47899368, 0.15655988, -0.8637442],
-    [0.46501538, 0.21637973, -0.8584523],
-    [0.45843437, 0.20285669, -0.8652671],
-    [0.40784302, 0.12610146, -0.9043023],
-    [0.28164735, 0.28164735, -0.91725105],
-    [0.28666767, 0.3493115, -0.8920779],
-    [0.3493115, 0.28666767, -0.8920779],
-    [0.33197904, 0.33197904, -0.88293827],
-    [0.31268921, 0.31268921, -0.896912],
-    [0.3106713, 0.37331337, -0.8741399],
-    [0.37331337, 0.31067127, -0.8741399],
-    [0.34948468, 0.34948468, -0.8693221],
-    [0.3447205, 0.34472048, -0.87311834],
-    [0.26888818, 0.38822904, -0.8814633],
-    [0.26888815, 0.38822898, -0.88146317],
-    [0.2868026, 0.40845034, -0.86655223],
-    [0.28550404, 0.47419563, -0.8328421],
-    [0.30847806, 0.49770465, -0.81063646],
-    [0.3449563, 0.4070137, -0.8457808],
-    [0.34467536, 0.45373356, -0.8217815],
-    [0.34436643, 0.48515105, -0.80376625],
-    [0.3195178, 0.45492628, -0.8312342],
-    [0.38822898, 0.26888812, -0.8814632],
-    [0.38822898, 0.26888812, -0.8814632],
-    [0.40845034, 0.28680262, -0.86655223],
-    [0.40701374, 0.3449563, -0.8457808],
-    [0.47419563, 0.285504, -0.8328421],
-    [0.49770465, 0.30847806, -0.81063646],
-    [0.45373356, 0.34467536, -0.8217815],
-    [0.48515105, 0.3443664, -0.80376625],
-    [0.45492628, 0.3195178, -0.8312342],
-    [0.40275577, 0.40275583, -0.82193404],
-    [0.4204447, 0.42044467, -0.80402267],
-    [0.3785344, 0.44056633, -0.81401044],
-    [0.4077526, 0.47012255, -0.7827661],
-    [0.4405664, 0.3785345, -0.8140105],
-    [0.47012252, 0.40775257, -0.78276604],
-    [0.45753765, 0.45753765, -0.7624424],
-    [0.42585832, 0.42585835, -0.7983041],
-    [0.36083505, 0.36083502, -0.85999775],
-    [0.43602434, 0.4983138, -0.7493772],
-    [0.4983138, 0.43602434, -0.7493772],
-    [0.47463012, 0.47463012, -0.7412505],
-    [0.4699694, 0.4699694, -0.74716645],
-    [0.4699694, 0.4699694, -0.74716645],
-    [0.30614012, 0.3061401, -0.9014191],
-    [0.03151556, 0.53241056, -0.8458994],
-    [0.031489953, 0.5722968, -0.81944174],
-    [0.03125978, 0.60362905, -0.79665226],
-    [0.094080195, 0.53247327, -0.84120214],
-    [0.094032906, 0.56914115, -0.81684524],
-    [0.093822606, 0.6003643, -0.79420394],
-    [0.06276856, 0.56900597, -0.81993437],
-    [0.031009154, 0.6428037, -0.76540315],
-    [0.09351146, 0.63976383, -0.7628616],
-    [0.062290855, 0.6415984, -0.7645073],
-    [0.15524723, 0.5364308, -0.82954216],
-    [0.17494518, 0.55950457, -0.81015354],
-    [0.13328779, 0.5652887, -0.8140535],
-    [0.15492976, 0.58972514, -0.79260385],
-    [0.21909471, 0.5205178, -0.8252628],
-    [0.21888196, 0.5516121, -0.8048694],
-    [0.21786655, 0.59806585, -0.77126616],
-    [0.18222928, 0.5607579, -0.80767757],
-    [0.1560054, 0.63379604, -0.75760466],
-    [0.19616966, 0.6282432, -0.75287986],
-    [0.17612422, 0.6311511, -0.7553996],
-    [0.12118454, 0.60227495, -0.78903687],
-    [0.031237818, 0.67420614, -0.73788226],
-    [0.031487603, 0.7067562, -0.7067561],
-    [0.031237818, 0.73788226, -0.67420614],
-    [0.093978785, 0.67141783, -0.735096],
-    [0.09446676, 0.7039447, -0.7039446],
-    [0.09397876, 0.735096, -0.6714177],
-    [0.06280486, 0.7057109, -0.7057109],
-    [0.23801024, 0.62063086, -0.7471068],
-    [0.23801024, 0.62063086, -0.7471068],
-    [0.1564547, 0.66545653, -0.72985584],
-    [0.1568703, 0.6983523, -0.6983523],
-    [0.15645471, 0.72985584, -0.66545653],
-    [0.22027147, 0.6577759, -0.72028565],
-    [0.24374485, 0.68578, -0.68578],
-    [0.20234467, 0.6924799, -0.6924799],
-    [0.22027145, 0.72028565, -0.6577759],
-    [0.19400178, 0.6936726, -0.6936726],
-    [0.23801024, 0.7471068, -0.62063086],
-    [0.23801024, 0.7471068, -0.62063086],
-    [0.1840661, 0.695025, -0.6950251],
-    [0.031009154, 0.7654031, -0.6428037],
-    [0.09351146, 0.7628616, -0.63976383],
-    [0.062290855, 0.7645073, -0.6415984],
-    [0.03125978, 0.79665226, -0.603629],
-    [0.031489953, 0.8194418, -0.57229674],
-    [0.03151556, 0.84589946, -0.5324105],
-    [0.093822606, 0.79420394, -0.6003643],
-    [0.0940329, 0.8168452, -0.56914115],
-    [0.09408019, 0.84120214, -0.53247327],
-    [0.062768556, 0.81993425, -0.56900597],
-    [0.1560054, 0.75760466, -0.63379604],
-    [0.19616966, 0.75287986, -0.6282432],
-    [0.17612422, 0.7553996, -0.6311511],
-    [0.15496938, 0.7926277, -0.5896828],
-    [0.17501052, 0.8101897, -0.55943173],
-    [0.13335358, 0.81409216, -0.5652176],
-    [0.15528639, 0.8295634, -0.5363867],
-    [0.21786658, 0.77126604, -0.59806585],
-    [0.21910739, 0.8048269, -0.5515847],
-    [0.21932015, 0.82522035, -0.52049017],
-    [0.18232407, 0.8076838, -0.56071794],
-    [0.12120853, 0.7890399, -0.6022661],
-    [0.26666993, 0.5094646, -0.8181278],
-    [0.28516498, 0.5311748, -0.797831],
-    [0.2812341, 0.58034486, -0.764269],
-    [0.34472787, 0.5346671, -0.77155286],
-    [0.3239224, 0.5693265, -0.7556068],
-    [0.30065462, 0.5455595, -0.7822861],
-    [0.39460042, 0.5151102, -0.76088905],
-    [0.39460042, 0.5151102, -0.76088905],
-    [0.34807205, 0.531013, -0.7725743],
-    [0.28137675, 0.61161774, -0.73942596],
-    [0.36548474, 0.55680907, -0.74591184],
-    [0.34884685, 0.5974803, -0.722027],
-    [0.37309933, 0.62416285, -0.6864529],
-    [0.3426278, 0.5982611, -0.7243548],
-    [0.2814848, 0.6474225, -0.70824456],
-    [0.28141975, 0.67852885, -0.6785289],
-    [0.28148478, 0.70824444, -0.6474225],
-    [0.33160666, 0.63554347, -0.69722414],
-    [0.35277322, 0.66180485, -0.6614873],
-    [0.33159938, 0.6975285, -0.63521314],
-    [0.31040585, 0.67225796, -0.67209923],
-    [0.28137675, 0.739426, -0.6116178],
-    [0.373092, 0.6867571, -0.6238326],
-    [0.34883973, 0.7221721, -0.5973092],
-    [0.36566997, 0.7459645, -0.55661696],
-    [0.3426699, 0.7244791, -0.59808636],
-    [0.4118869, 0.53441423, -0.7380723],
-    [0.40651593, 0.58234537, -0.70400196],
-    [0.4067483, 0.6133388, -0.6770312],
-    [0.4673574, 0.53071374, -0.7070502],
-    [0.48655805, 0.55073935, -0.6781944],
-    [0.44483417, 0.56812227, -0.69235796],
-    [0.4674408, 0.5929142, -0.6557072],
-    [0.44214487, 0.5681868, -0.69402575],
-    [0.48917478, 0.61688745, -0.6165694],
-    [0.48917484, 0.6168875, -0.61656946],
-    [0.40705565, 0.6458738, -0.6458738],
-    [0.44770122, 0.63244194, -0.63212395],
-    [0.42748648, 0.6393195, -0.63916045],
-    [0.40687314, 0.6770918, -0.6131893],
-    [0.4066382, 0.7040583, -0.5821919],
-    [0.4119511, 0.7380218, -0.5344343],
-    [0.4674735, 0.6558311, -0.59275126],
-    [0.48661032, 0.67813694, -0.5507637],
-    [0.44491085, 0.6923032, -0.56812906],
-    [0.4673896, 0.70701736, -0.5307293],
-    [0.44221538, 0.6940294, -0.56812745],
-    [0.40136042, 0.6477249, -0.64758176],
-    [0.28145143, 0.7642177, -0.58030725],
-    [0.28548548, 0.7976628, -0.5312552],
-    [0.26691833, 0.81792694, -0.50965697],
-    [0.32420805, 0.75552785, -0.5692685],
-    [0.3450088, 0.7715333, -0.5345142],
-    [0.3009246, 0.78217995, -0.5455628],
-    [0.3947095, 0.7608078, -0.5151464],
-    [0.39470956, 0.76080793, -0.51514643],
-    [0.34826043, 0.7724773, -0.53103065],
-    [0.25789997, 0.68318987, -0.6831831],
-    [0.031501405, 0.8702115, -0.49167034],
-    [0.09403111, 0.86800206, -0.4875762],
-    [0.06279713, 0.86953425, -0.48986408],
-    [0.031273916, 0.8872522, -0.46022323],
-    [0.03104798, 0.91294277, -0.40690485],
-    [0.09381681, 0.88492364, -0.45618913],
-    [0.09363458, 0.90861446, -0.40700433],
-    [0.062499367, 0.89923966, -0.43296865],
-    [0.031270668, 0.9324015, -0.36006904],
-    [0.031269234, 0.9438091, -0.3290084],
-    [0.03129361, 0.95893526, -0.28189346],
-    [0.09383009, 0.93040735, -0.35431355],
-    [0.0937832, 0.9416977, -0.32312563],
-    [0.09384787, 0.95480126, -0.28204092],
-    [0.06261321, 0.9446425, -0.32207158],
-    [0.15655987, 0.86374426, -0.47899368],
-    [0.21637973, 0.8584523, -0.46501538],
-    [0.23545098, 0.87149704, -0.43018106],
-    [0.20295721, 0.8652482, -0.45842555],
-    [0.15660593, 0.880411, -0.44760585],
-    [0.15670547, 0.8998644, -0.40704724],
-    [0.19375718, 0.8765209, -0.44064656],
-    [0.21663171, 0.88679457, -0.40824753],
-    [0.18102211, 0.8863728, -0.4261152],
-    [0.15530512, 0.9274302, -0.3402258],
-    [0.17527439, 0.9358569, -0.30569702],
-    [0.13325031, 0.9391045, -0.31674463],
-    [0.15526845, 0.9461164, -0.28417525],
-    [0.21919033, 0.91308534, -0.34384707],
-    [0.22344194, 0.9325484, -0.2835968],
-    [0.20563965, 0.9422029, -0.26451087],
-    [0.18122959, 0.93467486, -0.3058407],
-    [0.12612613, 0.90429944, -0.40784165],
-    [0.03153171, 0.9751979, -0.21907744],
-    [0.031294666, 0.98715264, -0.15668546],
-    [0.09409146, 0.9710682, -0.21948425],
-    [0.09387083, 0.983177, -0.15668844],
-    [0.06275991, 0.9801288, -0.18817203],
-    [0.031052334, 0.9950792, -0.09409098],
-    [0.03127881, 0.9990212, -0.03127881],
-    [0.0936523, 0.9911904, -0.0936523],
-    [0.09409098, 0.9950792, -0.031052334],
-    [0.0625804, 0.99607605, -0.06258039],
-    [0.15666673, 0.9629035, -0.21970935],
-    [0.15667123, 0.9751453, -0.15667123],
-    [0.24690984, 0.93705446, -0.24690984],
-    [0.22377947, 0.9486019, -0.22377947],
-    [0.21970937, 0.96290356, -0.15666674],
-    [0.20104268, 0.9587303, -0.20104268],
-    [0.15668844, 0.983177, -0.09387083],
-    [0.15668546, 0.98715264, -0.031294666],
-    [0.21948425, 0.9710682, -0.09409146],
-    [0.21907742, 0.9751979, -0.03153171],
-    [0.18817198, 0.98012877, -0.062759906],
-    [0.12921643, 0.98316133, -0.12921643],
-    [0.43595585, 0.7492584, -0.4985524],
-    [0.47463012, 0.7412505, -0.47463012],
-    [0.4985524, 0.7492584, -0.43595585],
-    [0.47002885, 0.7470914, -0.47002885],
-    [0.4700289, 0.7470915, -0.4700289],
-    [0.3084306, 0.8105078, -0.4979435],
-    [0.28547677, 0.83276856, -0.47434115],
-    [0.28682917, 0.8666205, -0.4082866],
-    [0.3445741, 0.8036993, -0.4851146],
-    [0.344883, 0.8217146, -0.45369685],
-    [0.3449563, 0.84578073, -0.40701374],
-    [0.3195811, 0.8311948, -0.4549538],
-    [0.3104197, 0.87433016, -0.37307698],
-    [0.34950918, 0.86938053, -0.34931475],
-    [0.37338692, 0.8743126, -0.3100962],
-    [0.34467193, 0.87326556, -0.34439573],
-    [0.26893088, 0.8815712, -0.38795394],
-    [0.2689309, 0.8815713, -0.387954],
-    [0.28651407, 0.8921842, -0.3491664],
-    [0.28164735, 0.91725105, -0.28164735],
-    [0.3320178, 0.8830307, -0.33169383],
-    [0.34935254, 0.8921737, -0.28631973],
-    [0.31267092, 0.89698607, -0.31249464],
-    [0.40777215, 0.7826506, -0.4702976],
-    [0.42039618, 0.8039072, -0.420714],
-    [0.378907, 0.81380486, -0.44062606],
-    [0.402885, 0.8218073, -0.402885],
-    [0.45728362, 0.7625566, -0.45760158],
-    [0.47014335, 0.7826584, -0.40793523],
-    [0.44062606, 0.81380486, -0.378907],
-    [0.425898, 0.798189, -0.42603427],
-    [0.4070137, 0.8457808, -0.3449563],
-    [0.40844816, 0.86655223, -0.28680548],
-    [0.4851146, 0.8036992, -0.34457406],
-    [0.4979435, 0.8105078, -0.3084306],
-    [0.4536969, 0.82171464, -0.34488305],
-    [0.47434115, 0.83276856, -0.28547677],
-    [0.4549801, 0.8311822, -0.3195767],
-    [0.38822573, 0.88146305, -0.2688933],
-    [0.3882258, 0.88146317, -0.26889333],
-    [0.36085558, 0.86002004, -0.3607614],
-    [0.2835968, 0.9325484, -0.22344194],
-    [0.30598453, 0.9357656, -0.17525989],
-    [0.26451087, 0.9422029, -0.20563968],
-    [0.284349, 0.9460654, -0.15526031],
-    [0.34384707, 0.91308534, -0.21919031],
-    [0.3403962, 0.9273693, -0.15529534],
-    [0.31703112, 0.93901, -0.13323525],
-    [0.30597228, 0.9346332, -0.18122298],
-    [0.28204092, 0.95480126, -0.09384787],
-    [0.28189346, 0.95893526, -0.03129361],
-    [0.35431355, 0.93040735, -0.09383008],
-    [0.36006898, 0.9324014, -0.031270664],
-    [0.32312566, 0.9416977, -0.0937832],
-    [0.32900843, 0.9438091, -0.031269234],
-    [0.32207158, 0.9446425, -0.06261321],
-    [0.43018106, 0.87149704, -0.23545098],
-    [0.46501538, 0.8584523, -0.21637973],
-    [0.47899362, 0.86374426, -0.15655987],
-    [0.45842555, 0.8652482, -0.20295721],
-    [0.4875762, 0.868002, -0.09403111],
-    [0.49167028, 0.8702115, -0.0315014],
-    [0.48986408, 0.86953425, -0.06279713],
-    [0.40824753, 0.88679457, -0.21663171],
-    [0.40704724, 0.8998644, -0.15670547],
-    [0.44064656, 0.8765209, -0.19375718],
-    [0.44760588, 0.880411, -0.15660593],
-    [0.4261152, 0.88637286, -0.18102211],
-    [0.40700433, 0.90861446, -0.09363458],
-    [0.40690485, 0.91294277, -0.03104798],
-    [0.45618913, 0.88492364, -0.09381682],
-    [0.46022323, 0.8872522, -0.031273916],
-    [0.43296865, 0.8992397, -0.062499367],
-    [0.40786266, 0.9042902, -0.1261247],
-    [0.30616686, 0.90140903, -0.306143],
-    [0.5324105, 0.03151556, -0.8458994],
-    [0.53247327, 0.09408019, -0.8412022],
-    [0.57229674, 0.031489953, -0.81944174],
-    [0.603629, 0.03125978, -0.79665226],
-    [0.56914115, 0.094032906, -0.81684524],
-    [0.6003643, 0.093822606, -0.79420394],
-    [0.56900597, 0.06276856, -0.81993437],
-    [0.5364308, 0.15524723, -0.82954216],
-    [0.55950457, 0.17494518, -0.81015354],
-    [0.5205178, 0.21909474, -0.8252628],
-    [0.5516121, 0.21888196, -0.8048694],
-    [0.5652887, 0.13328779, -0.8140535],
-    [0.58972514, 0.15492976, -0.79260385],
-    [0.59806585, 0.21786655, -0.77126604],
-    [0.5607578, 0.18222927, -0.8076775],
-    [0.6428037, 0.031009158, -0.76540315],
-    [0.63976383, 0.09351146, -0.76286167],
-    [0.6415984, 0.06229086, -0.7645073],
-    [0.63379604, 0.1560054, -0.7576047],
-    [0.62824327, 0.19616964, -0.75287974],
-    [0.63115114, 0.17612422, -0.7553996],
-    [0.60227495, 0.12118454, -0.78903687],
-    [0.62063086, 0.23801026, -0.7471068],
-    [0.62063086, 0.23801026, -0.7471068],
-    [0.67420614, 0.031237818, -0.73788226],
-    [0.6714177, 0.09397876, -0.735096],
-    [0.7067562, 0.031487603, -0.7067562],
-    [0.73788226, 0.031237818, -0.67420614],
-    [0.7039447, 0.09446677, -0.7039447],
-    [0.735096, 0.093978785, -0.67141783],
-    [0.7057109, 0.06280486, -0.7057109],
-    [0.6654565, 0.15645471, -0.72985584],
-    [0.6577759, 0.22027145, -0.72028553],
-    [0.68578, 0.24374485, -0.68578],
-    [0.6983523, 0.1568703, -0.6983523],
-    [0.72985584, 0.1564547, -0.66545653],
-    [0.6924799, 0.20234467, -0.6924799],
-    [0.72028565, 0.22027145, -0.6577759],
-    [0.6936726, 0.19400176, -0.6936726],
-    [0.7471068, 0.23801026, -0.62063086],
-    [0.7471068, 0.23801026, -0.62063086],
-    [0.695025, 0.18406612, -0.6950251],
-    [0.5094646, 0.2666699, -0.8181278],
-    [0.5311748, 0.28516495, -0.797831],
-    [0.53466713, 0.34472784, -0.7715529],
-    [0.5803449, 0.2812341, -0.76426905],
-    [0.5693265, 0.3239224, -0.7556068],
-    [0.5455595, 0.3006546, -0.7822861],
-    [0.5151102, 0.39460036, -0.760889],
-    [0.5151102, 0.39460036, -0.760889],
-    [0.5310131, 0.34807202, -0.7725743],
-    [0.55680907, 0.36548474, -0.74591184],
-    [0.6116178, 0.28137675, -0.739426],
-    [0.5974804, 0.34884685, -0.72202694],
-    [0.62416285, 0.37309936, -0.6864529],
-    [0.5982611, 0.34262776, -0.7243547],
-    [0.5344142, 0.41188684, -0.7380723],
-    [0.5307137, 0.4673574, -0.7070502],
-    [0.55073935, 0.48655805, -0.6781944],
-    [0.58234537, 0.40651596, -0.7040019],
-    [0.6133388, 0.4067483, -0.6770312],
-    [0.5681224, 0.44483423, -0.69235796],
-    [0.5929141, 0.46744078, -0.6557072],
-    [0.5681868, 0.44214487, -0.6940257],
-    [0.61688745, 0.48917478, -0.6165694],
-    [0.6168875, 0.48917484, -0.61656946],
-    [0.6474225, 0.2814848, -0.70824456],
-    [0.6785289, 0.28141975, -0.67852885],
-    [0.6355435, 0.33160663, -0.6972241],
-    [0.66180485, 0.35277322, -0.6614872],
-    [0.70824456, 0.2814848, -0.6474225],
-    [0.6975285, 0.33159938, -0.63521326],
-    [0.672258, 0.31040585, -0.6720991],
-    [0.6867571, 0.37309206, -0.62383264],
-    [0.739426, 0.28137675, -0.61161774],
-    [0.722172, 0.34883973, -0.5973092],
-    [0.7459645, 0.36566997, -0.55661696],
-    [0.724479, 0.3426699, -0.5980864],
-    [0.6458738, 0.40705562, -0.6458738],
-    [0.632442, 0.44770122, -0.632124],
-    [0.6393195, 0.42748648, -0.63916045],
-    [0.6770918, 0.40687314, -0.6131893],
-    [0.6558311, 0.46747354, -0.59275126],
-    [0.67813694, 0.48661035, -0.5507637],
-    [0.7040582, 0.40663823, -0.5821918],
-    [0.7380218, 0.4119511, -0.5344343],
-    [0.6923032, 0.44491085, -0.56812906],
-    [0.70701736, 0.4673896, -0.5307293],
-    [0.6940294, 0.44221535, -0.56812745],
-    [0.647725, 0.40136042, -0.64758176],
-    [0.76540315, 0.031009154, -0.6428037],
-    [0.76286167, 0.09351146, -0.63976383],
-    [0.7645073, 0.062290855, -0.6415984],
-    [0.79665226, 0.03125978, -0.60362905],
-    [0.79420394, 0.093822606, -0.6003643],
-    [0.81944174, 0.031489953, -0.57229674],
-    [0.8458994, 0.03151556, -0.5324105],
-    [0.8168453, 0.094032906, -0.56914115],
-    [0.8412022, 0.09408019, -0.53247327],
-    [0.8199343, 0.06276856, -0.56900597],
-    [0.7576047, 0.1560054, -0.63379604],
-    [0.75287974, 0.19616963, -0.62824327],
-    [0.7553996, 0.1761242, -0.63115114],
-    [0.79262763, 0.1549694, -0.5896828],
-    [0.8101897, 0.17501052, -0.55943173],
-    [0.7712661, 0.21786653, -0.5980658],
-    [0.8048269, 0.21910737, -0.5515847],
-    [0.81409216, 0.13335358, -0.56521755],
-    [0.8295634, 0.15528637, -0.53638667],
-    [0.82522047, 0.21932018, -0.52049017],
-    [0.8076838, 0.18232408, -0.56071794],
-    [0.7890399, 0.12120854, -0.6022661],
-    [0.7642176, 0.28145143, -0.58030725],
-    [0.7976628, 0.28548548, -0.5312552],
-    [0.755528, 0.32420808, -0.5692686],
-    [0.77153325, 0.34500876, -0.5345142],
-    [0.81792694, 0.26691833, -0.50965697],
-    [0.78218, 0.3009246, -0.5455628],
-    [0.76080793, 0.3947095, -0.5151464],
-    [0.76080793, 0.3947095, -0.5151464],
-    [0.7724774, 0.34826043, -0.53103065],
-    [0.6831899, 0.25789997, -0.683183],
-    [0.7492584, 0.43595585, -0.4985524],
-    [0.7412505, 0.47463012, -0.47463012],
-    [0.7492584, 0.4985524, -0.43595585],
-    [0.7470914, 0.47002885, -0.47002885],
-    [0.7470915, 0.4700289, -0.4700289],
-    [0.8702115, 0.031501405, -0.49167034],
-    [0.86800206, 0.09403111, -0.4875762],
-    [0.86953425, 0.06279713, -0.48986408],
-    [0.8637442, 0.15655988, -0.47899368],
-    [0.8584523, 0.21637973, -0.46501538],
-    [0.8714971, 0.23545098, -0.43018106],
-    [0.8652482, 0.20295721, -0.45842555],
-    [0.8872522, 0.031273916, -0.4602232],
-    [0.91294277, 0.031047983, -0.4069049],
-    [0.8849237, 0.09381683, -0.4561892],
-    [0.90861446, 0.09363459, -0.40700433],
-    [0.89923966, 0.062499374, -0.43296868],
-    [0.9324015, 0.031270668, -0.36006904],
-    [0.9304073, 0.09383008, -0.3543135],
-    [0.9438091, 0.031269234, -0.3290084],
-    [0.9589352, 0.03129361, -0.28189343],
-    [0.9416977, 0.0937832, -0.32312563],
-    [0.9548013, 0.093847886, -0.28204095],
-    [0.9446425, 0.06261322, -0.32207158],
-    [0.880411, 0.15660593, -0.44760588],
-    [0.8998644, 0.15670548, -0.40704724],
-    [0.8765209, 0.19375719, -0.44064656],
-    [0.88679457, 0.21663174, -0.40824756],
-    [0.8863728, 0.18102214, -0.4261152],
-    [0.9274302, 0.15530512, -0.3402258],
-    [0.9358569, 0.17527439, -0.30569702],
-    [0.91308534, 0.21919033, -0.34384707],
-    [0.9325484, 0.22344194, -0.2835968],
-    [0.9391045, 0.13325031, -0.31674463],
-    [0.9461164, 0.15526843, -0.28417525],
-    [0.9422029, 0.20563965, -0.2645109],
-    [0.93467486, 0.18122959, -0.30584067],
-    [0.90429944, 0.12612613, -0.40784165],
-    [0.97519785, 0.03153171, -0.21907744],
-    [0.9871526, 0.031294666, -0.15668546],
-    [0.9710682, 0.09409147, -0.21948425],
-    [0.98317695, 0.093870826, -0.1566884],
-    [0.9801288, 0.062759906, -0.18817201],
-    [0.9950792, 0.031052334, -0.09409098],
-    [0.9990212, 0.03127881, -0.03127881],
-    [0.9911904, 0.0936523, -0.09365229],
-    [0.9950793, 0.09409099, -0.031052338],
-    [0.99607605, 0.0625804, -0.0625804],
-    [0.9370545, 0.24690987, -0.24690987],
-    [0.9629035, 0.15666673, -0.21970935],
-    [0.9751453, 0.15667123, -0.15667123],
-    [0.9486019, 0.22377947, -0.22377947],
-    [0.9629035, 0.21970937, -0.15666674],
-    [0.9587303, 0.20104268, -0.20104268],
-    [0.983177, 0.15668844, -0.09387083],
-    [0.9871527, 0.15668547, -0.03129467],
-    [0.97106814, 0.21948425, -0.09409147],
-    [0.97519785, 0.21907741, -0.031531706],
-    [0.98012877, 0.18817201, -0.06275991],
-    [0.98316133, 0.12921643, -0.12921645],
-    [0.8105078, 0.3084306, -0.4979435],
-    [0.8036993, 0.3445741, -0.4851146],
-    [0.83276856, 0.28547677, -0.47434115],
-    [0.8666205, 0.28682917, -0.4082866],
-    [0.8217146, 0.344883, -0.45369685],
-    [0.8457808, 0.3449563, -0.40701374],
-    [0.8311948, 0.3195811, -0.4549538],
-    [0.87433016, 0.3104197, -0.37307698],
-    [0.86938053, 0.34950918, -0.34931475],
-    [0.8743127, 0.37338692, -0.3100962],
-    [0.87326556, 0.34467193, -0.34439573],
-    [0.7826506, 0.40777215, -0.4702976],
-    [0.80390716, 0.42039615, -0.420714],
-    [0.7625565, 0.45728365, -0.45760158],
-    [0.7826584, 0.47014332, -0.40793523],
-    [0.81380486, 0.378907, -0.44062606],
-    [0.8218073, 0.402885, -0.402885],
-    [0.81380486, 0.44062606, -0.378907],
-    [0.79818904, 0.42589805, -0.4260343],
-    [0.8036992, 0.48511457, -0.3445741],
-    [0.8105078, 0.4979435, -0.3084306],
-    [0.8457808, 0.4070137, -0.3449563],
-    [0.86655223, 0.40844816, -0.28680548],
-    [0.8217146, 0.45369685, -0.344883],
-    [0.83276856, 0.47434115, -0.2854768],
-    [0.8311822, 0.45498008, -0.31957668],
-    [0.8815713, 0.26893088, -0.38795397],
-    [0.8815713, 0.26893088, -0.38795397],
-    [0.8921842, 0.286514, -0.3491663],
-    [0.91725105, 0.28164735, -0.28164735],
-    [0.8830307, 0.33201787, -0.33169386],
-    [0.8921737, 0.34935254, -0.28631976],
-    [0.89698607, 0.31267092, -0.3124946],
-    [0.88146305, 0.38822573, -0.26889327],
-    [0.88146317, 0.3882258, -0.2688933],
-    [0.86002004, 0.36085558, -0.36076134],
-    [0.8714971, 0.43018106, -0.23545098],
-    [0.8584523, 0.46501538, -0.21637972],
-    [0.86374426, 0.47899368, -0.15655987],
-    [0.8652482, 0.45842555, -0.20295721],
-    [0.8680021, 0.48757622, -0.094031125],
-    [0.8702115, 0.49167037, -0.031501405],
-    [0.86953413, 0.48986408, -0.06279714],
-    [0.9325484, 0.2835968, -0.22344194],
-    [0.9357656, 0.30598453, -0.17525989],
-    [0.91308534, 0.34384704, -0.21919031],
-    [0.9273693, 0.3403962, -0.15529534],
-    [0.9422029, 0.2645109, -0.20563965],
-    [0.9460654, 0.28434896, -0.15526032],
-    [0.93901, 0.31703112, -0.13323525],
-    [0.9346332, 0.30597228, -0.18122298],
-    [0.9304074, 0.3543135, -0.09383009],
-    [0.9324014, 0.360069, -0.031270664],
-    [0.9548013, 0.28204095, -0.093847886],
-    [0.9589352, 0.28189343, -0.03129361],
-    [0.9416977, 0.32312563, -0.0937832],
-    [0.9438091, 0.32900846, -0.031269234],
-    [0.9446425, 0.32207155, -0.06261321],
-    [0.88679457, 0.40824753, -0.21663174],
-    [0.89986444, 0.4070473, -0.15670548],
-    [0.8765209, 0.44064656, -0.19375719],
-    [0.88041097, 0.44760585, -0.15660593],
-    [0.8863728, 0.4261152, -0.18102214],
-    [0.90861446, 0.40700433, -0.09363459],
-    [0.91294277, 0.40690488, -0.03104798],
-    [0.88492364, 0.45618913, -0.09381682],
-    [0.8872522, 0.46022323, -0.031273916],
-    [0.8992397, 0.43296865, -0.06249937],
-    [0.9042902, 0.4078627, -0.1261247],
-    [0.90140903, 0.30616686, -0.306143],
-    [0.5089309, 0.5089309, -0.69424677],
-    [0.5327861, 0.5327861, -0.6574786],
-    [0.5137616, 0.5753806, -0.63638526],
-    [0.5753806, 0.5137616, -0.63638526],
-    [0.53322995, 0.53322995, -0.6567584],
-    [0.5557188, 0.5557188, -0.6183473],
-    [0.5349534, 0.59742147, -0.5974215],
-    [0.55560803, 0.61854213, -0.55561256],
-    [0.59742147, 0.5349535, -0.59742147],
-    [0.6185422, 0.55560803, -0.55561256],
-    [0.57735026, 0.57735026, -0.57735026],
-    [0.5974675, 0.5974675, -0.53485054],
-    [0.5773715, 0.5773715, -0.5773078],
-    [0.5137616, 0.63638526, -0.5753806],
-    [0.53269184, 0.6576295, -0.5326941],
-    [0.5088563, 0.6943562, -0.5088563],
-    [0.57526654, 0.63657665, -0.51365227],
-    [0.5331593, 0.65687174, -0.5331611],
-    [0.63638526, 0.5137616, -0.5753806],
-    [0.6576295, 0.5326919, -0.5326941],
-    [0.63657665, 0.57526654, -0.51365227],
-    [0.6943562, 0.5088563, -0.5088563],
-    [0.6568716, 0.5331593, -0.53316104],
-    [0.5773707, 0.5773707, -0.5773094],
-    [0.5773707, 0.5773707, -0.5773094],
-    [0.61683726, 0.61683726, -0.48890042],
-    [0.61683726, 0.61683726, -0.48890042],
-    [0.5507637, 0.67813694, -0.48661032],
-    [0.5307293, 0.70701736, -0.4673896],
-    [0.5344669, 0.73787886, -0.4121649],
-    [0.5929463, 0.6557199, -0.46738228],
-    [0.61328274, 0.6769644, -0.406944],
-    [0.56825644, 0.69213676, -0.44500726],
-    [0.58223426, 0.70386404, -0.40691346],
-    [0.56819654, 0.69392186, -0.44229534],
-    [0.5565471, 0.74587893, -0.36595076],
-    [0.6240265, 0.6866207, -0.37301892],
-    [0.59731305, 0.72213846, -0.34890273],
-    [0.6115759, 0.7393764, -0.2815981],
-    [0.59810805, 0.7244028, -0.34279317],
-    [0.67813694, 0.5507637, -0.48661032],
-    [0.6557199, 0.5929463, -0.46738222],
-    [0.6769644, 0.61328274, -0.406944],
-    [0.70701736, 0.5307293, -0.4673896],
-    [0.7378788, 0.5344669, -0.41216496],
-    [0.69213676, 0.56825644, -0.44500726],
-    [0.70386404, 0.58223426, -0.4069135],
-    [0.69392186, 0.56819654, -0.44229534],
-    [0.6866207, 0.6240265, -0.37301892],
-    [0.74587893, 0.5565471, -0.36595076],
-    [0.72213846, 0.5973131, -0.34890273],
-    [0.73937637, 0.6115759, -0.28159812],
-    [0.7244028, 0.59810805, -0.34279317],
-    [0.6323825, 0.6323825, -0.44741994],
-    [0.6458738, 0.6458738, -0.40705565],
-    [0.6392875, 0.6392875, -0.4273443],
-    [0.66166675, 0.66166675, -0.35269552],
-    [0.6785289, 0.67852885, -0.28141975],
-    [0.63540477, 0.6973894, -0.33152497],
-    [0.64738035, 0.70819515, -0.28170624],
-    [0.6973893, 0.63540477, -0.33152494],
-    [0.70819515, 0.64738035, -0.28170624],
-    [0.6721709, 0.6721708, -0.31043956],
-    [0.64764833, 0.64764833, -0.40137666],
-    [0.6209723, 0.7467547, -0.23822434],
-    [0.62097234, 0.74675477, -0.23822437],
-    [0.74675465, 0.6209723, -0.23822431],
-    [0.7467547, 0.62097234, -0.23822434],
-    [0.68578, 0.68578, -0.24374485],
-    [0.65791845, 0.72007656, -0.22052874],
-    [0.6654331, 0.7298277, -0.15668595],
-    [0.72007656, 0.65791845, -0.22052875],
-    [0.72982764, 0.665433, -0.15668593],
-    [0.6924799, 0.6924799, -0.20234467],
-    [0.6983523, 0.6983523, -0.1568703],
-    [0.6936531, 0.6936531, -0.19414082],
-    [0.6714177, 0.735096, -0.09397877],
-    [0.67420614, 0.73788226, -0.031237818],
-    [0.735096, 0.6714177, -0.09397877],
-    [0.73788226, 0.67420614, -0.031237818],
-    [0.7039446, 0.7039446, -0.09446676],
-    [0.7067562, 0.7067562, -0.031487603],
-    [0.7057108, 0.7057108, -0.062804855],
-    [0.69500655, 0.69500655, -0.1842057],
-    [0.51527786, 0.76067156, -0.39480042],
-    [0.5152779, 0.7606717, -0.39480048],
-    [0.5345115, 0.77153325, -0.3450128],
-    [0.5312552, 0.7976628, -0.28548548],
-    [0.50965697, 0.81792694, -0.2669183],
-    [0.5691334, 0.75566536, -0.32412475],
-    [0.5803073, 0.7642177, -0.28145146],
-    [0.54553425, 0.7822062, -0.30090824],
-    [0.5310833, 0.77242386, -0.3482985],
-    [0.5515847, 0.8048269, -0.21910737],
-    [0.5598676, 0.80990016, -0.17495714],
-    [0.52049017, 0.82522035, -0.21932015],
-    [0.5366531, 0.8293967, -0.15525566],
-    [0.5982785, 0.77106327, -0.21800068],
-    [0.5899191, 0.7924223, -0.15512083],
-    [0.56565136, 0.8137997, -0.13329963],
-    [0.56094617, 0.8075206, -0.18234569],
-    [0.53247327, 0.84120214, -0.094080195],
-    [0.5324105, 0.84589946, -0.03151556],
-    [0.6003643, 0.79420394, -0.093822606],
-    [0.603629, 0.7966522, -0.031259775],
-    [0.5691412, 0.81684524, -0.094032906],
-    [0.57229674, 0.81944174, -0.031489953],
-    [0.56900597, 0.81993437, -0.06276856],
-    [0.6285504, 0.7524859, -0.19669628],
-    [0.6337491, 0.7575485, -0.156468],
-    [0.6312817, 0.75517493, -0.17661904],
-    [0.63976383, 0.7628616, -0.09351147],
-    [0.6428036, 0.76540303, -0.03100915],
-    [0.6415984, 0.76450723, -0.06229086],
-    [0.6023601, 0.78894806, -0.12133883],
-    [0.76067156, 0.515278, -0.39480042],
-    [0.76067156, 0.515278, -0.39480042],
-    [0.77153325, 0.5345115, -0.3450128],
-    [0.7976628, 0.5312552, -0.28548548],
-    [0.75566536, 0.5691334, -0.32412472],
-    [0.7642176, 0.5803073, -0.28145146],
-    [0.81792694, 0.50965697, -0.26691833],
-    [0.78220624, 0.54553425, -0.30090824],
-    [0.7724239, 0.53108335, -0.34829846],
-    [0.8048269, 0.5515847, -0.21910736],
-    [0.80990016, 0.5598676, -0.17495714],
-    [0.77106327, 0.5982786, -0.21800068],
-    [0.7924223, 0.58991903, -0.15512083],
-    [0.82522035, 0.52049017, -0.21932015],
-    [0.8293967, 0.5366531, -0.15525565],
-    [0.8137997, 0.56565136, -0.13329963],
-    [0.8075206, 0.56094605, -0.18234564],
-    [0.794204, 0.6003644, -0.09382261],
-    [0.79665226, 0.60362905, -0.03125978],
-    [0.84120214, 0.53247327, -0.094080195],
-    [0.8458994, 0.53241056, -0.03151556],
-    [0.8168452, 0.56914115, -0.0940329],
-    [0.81944174, 0.57229674, -0.031489953],
-    [0.8199343, 0.56900597, -0.06276856],
-    [0.7524859, 0.6285504, -0.19669631],
-    [0.7575485, 0.6337491, -0.156468],
-    [0.75517493, 0.6312817, -0.17661905],
-    [0.7628616, 0.63976383, -0.09351147],
-    [0.76540303, 0.6428036, -0.03100915],
-    [0.76450723, 0.6415984, -0.06229086],
-    [0.7889482, 0.6023601, -0.12133883],
-    [0.6831648, 0.6831648, -0.2580152],
-    [0.57734746, 0.57734746, -0.57735586],
-    [0.03127881, 0.03127881, 0.9990212],
-    [0.031052338, 0.09409099, 0.9950793],
-    [0.09409098, 0.031052334, 0.9950792],
-    [0.0936523, 0.0936523, 0.9911904],
-    [0.06258039, 0.0625804, 0.99607605],
-    [0.03129467, 0.15668549, 0.9871527],
-    [0.031531706, 0.21907741, 0.97519785],
-    [0.09387083, 0.15668844, 0.983177],
-    [0.09409147, 0.21948425, 0.97106814],
-    [0.06275991, 0.18817203, 0.9801288],
-    [0.15668547, 0.03129467, 0.9871527],
-    [0.15668842, 0.09387083, 0.983177],
-    [0.21907744, 0.03153171, 0.97519785],
-    [0.21948425, 0.09409147, 0.9710682],
-    [0.18817203, 0.06275991, 0.9801288],
-    [0.15667123, 0.15667123, 0.9751453],
-    [0.15666674, 0.21970937, 0.96290356],
-    [0.21970935, 0.15666673, 0.9629035],
-    [0.24690987, 0.24690987, 0.9370545],
-    [0.22377947, 0.22377947, 0.9486019],
-    [0.20104268, 0.20104268, 0.9587303],
-    [0.12921643, 0.12921643, 0.98316133],
-    [0.03129361, 0.28189343, 0.95893526],
-    [0.031270664, 0.360069, 0.9324014],
-    [0.031269234, 0.32900846, 0.9438091],
-    [0.093847886, 0.28204095, 0.9548013],
-    [0.09383009, 0.3543135, 0.9304074],
-    [0.0937832, 0.32312563, 0.9416977],
-    [0.06261323, 0.32207158, 0.94464254],
-    [0.031501405, 0.49167037, 0.8702115],
-    [0.094031125, 0.48757622, 0.8680021],
-    [0.062797144, 0.48986414, 0.86953425],
-    [0.03104798, 0.40690488, 0.91294277],
-    [0.031273916, 0.4602232, 0.8872522],
-    [0.09363458, 0.40700436, 0.90861446],
-    [0.09381683, 0.4561892, 0.8849237],
-    [0.06249937, 0.43296868, 0.89923966],
-    [0.17527437, 0.30569702, 0.9358569],
-    [0.15526842, 0.28417522, 0.9461163],
-    [0.1553051, 0.3402258, 0.9274302],
-    [0.13325031, 0.31674463, 0.9391045],
-    [0.22344194, 0.2835968, 0.9325484],
-    [0.20563965, 0.2645109, 0.9422029],
-    [0.21919031, 0.34384704, 0.91308534],
-    [0.18122959, 0.3058407, 0.93467486],
-    [0.15655987, 0.47899368, 0.86374426],
-    [0.23515163, 0.430212, 0.8715626],
-    [0.21637972, 0.46501538, 0.8584523],
-    [0.20285669, 0.45843437, 0.8652671],
-    [0.15670548, 0.4070473, 0.89986444],
-    [0.15660593, 0.44760588, 0.880411],
-    [0.21645129, 0.4082647, 0.88683075],
-    [0.19375719, 0.44064656, 0.8765209],
-    [0.18097667, 0.42611876, 0.8863803],
-    [0.12610146, 0.40784302, 0.9043023],
-    [0.28189343, 0.03129361, 0.9589352],
-    [0.28204095, 0.093847886, 0.9548013],
-    [0.36006904, 0.031270668, 0.9324015],
-    [0.32900843, 0.031269234, 0.9438091],
-    [0.3543135, 0.09383008, 0.9304073],
-    [0.32312563, 0.0937832, 0.9416977],
-    [0.32207158, 0.06261322, 0.94464254],
-    [0.30569702, 0.17527439, 0.9358569],
-    [0.28417525, 0.15526843, 0.9461164],
-    [0.2835968, 0.22344194, 0.9325484],
-    [0.2645109, 0.20563965, 0.9422029],
-    [0.3402258, 0.15530512, 0.9274302],
-    [0.31674463, 0.13325031, 0.9391045],
-    [0.34384704, 0.2191903, 0.9130852],
-    [0.30584067, 0.18122958, 0.93467486],
-    [0.49167034, 0.031501405, 0.8702115],
-    [0.4875762, 0.09403111, 0.86800206],
-    [0.48986408, 0.06279713, 0.86953425],
-    [0.4069049, 0.031047983, 0.91294277],
-    [0.40700433, 0.09363458, 0.90861446],
-    [0.4602232, 0.031273916, 0.8872522],
-    [0.45618913, 0.09381681, 0.88492364],
-    [0.43296868, 0.062499367, 0.89923966],
-    [0.430212, 0.2351516, 0.8715626],
-    [0.47899368, 0.15655988, 0.8637442],
-    [0.46501538, 0.21637973, 0.8584523],
-    [0.45843437, 0.20285669, 0.8652671],
-    [0.40704724, 0.15670548, 0.8998644],
-    [0.4082647, 0.21645129, 0.88683075],
-    [0.44760585, 0.15660593, 0.880411],
-    [0.44064656, 0.19375719, 0.8765209],
-    [0.42611876, 0.18097667, 0.88638043],
-    [0.40784302, 0.12610146, 0.9043023],
-    [0.43602434, 0.4983138, 0.7493772],
-    [0.4983138, 0.43602434, 0.7493772],
-    [0.47463012, 0.47463012, 0.7412505],
-    [0.4699694, 0.4699694, 0.74716645],
-    [0.4699694, 0.4699694, 0.74716645],
-    [0.3106713, 0.37331337, 0.8741399],
-    [0.37331337, 0.31067127, 0.8741399],
-    [0.34948468, 0.34948468, 0.8693221],
-    [0.3447205, 0.34472048, 0.87311834],
-    [0.28164735, 0.28164735, 0.91725105],
-    [0.28666767, 0.3493115, 0.8920779],
-    [0.3493115, 0.28666767, 0.8920779],
-    [0.33197904, 0.33197904, 0.8829382],
-    [0.31268921, 0.31268921, 0.8969119],
-    [0.2868026, 0.40845034, 0.86655223],
-    [0.30847806, 0.49770465, 0.81063646],
-    [0.28550404, 0.47419563, 0.8328421],
-    [0.3449563, 0.4070137, 0.8457808],
-    [0.34436643, 0.48515105, 0.80376625],
-    [0.34467536, 0.45373356, 0.8217815],
-    [0.31951776, 0.45492625, 0.8312342],
-    [0.26888818, 0.38822904, 0.8814633],
-    [0.26888815, 0.38822898, 0.88146317],
-    [0.40845034, 0.28680262, 0.86655223],
-    [0.40701374, 0.3449563, 0.8457808],
-    [0.49770465, 0.30847806, 0.81063646],
-    [0.47419563, 0.285504, 0.8328421],
-    [0.48515105, 0.3443664, 0.80376625],
-    [0.45373356, 0.34467536, 0.8217815],
-    [0.45492628, 0.3195178, 0.8312342],
-    [0.38822904, 0.26888818, 0.8814633],
-    [0.38822898, 0.26888815, 0.88146317],
-    [0.4204447, 0.42044467, 0.80402267],
-    [0.40275577, 0.40275583, 0.82193404],
-    [0.4077526, 0.47012255, 0.7827661],
-    [0.3785344, 0.44056633, 0.81401044],
-    [0.4701225, 0.40775257, 0.78276604],
-    [0.44056633, 0.3785344, 0.81401044],
-    [0.45753765, 0.45753765, 0.7624424],
-    [0.42585832, 0.42585835, 0.798304],
-    [0.36083508, 0.36083505, 0.85999775],
-    [0.30614012, 0.30614012, 0.9014191],
-    [0.03127881, 0.9990212, 0.03127881],
-    [0.031052338, 0.9950793, 0.09409099],
-    [0.09409098, 0.9950792, 0.031052334],
-    [0.09365229, 0.9911904, 0.0936523],
-    [0.06258039, 0.99607605, 0.0625804],
-    [0.031294666, 0.9871526, 0.15668546],
-    [0.031531706, 0.97519785, 0.21907741],
-    [0.09387083, 0.983177, 0.15668844],
-    [0.09409147, 0.97106814, 0.21948425],
-    [0.062759906, 0.98012877, 0.18817201],
-    [0.15668547, 0.9871527, 0.03129467],
-    [0.1566884, 0.98317695, 0.093870826],
-    [0.21907744, 0.97519785, 0.03153171],
-    [0.21948425, 0.9710682, 0.09409147],
-    [0.18817203, 0.9801288, 0.06275991],
-    [0.15667123, 0.9751453, 0.15667123],
-    [0.15666674, 0.9629035, 0.21970937],
-    [0.24690984, 0.93705446, 0.24690984],
-    [0.21970935, 0.9629035, 0.15666673],
-    [0.22377947, 0.9486019, 0.22377947],
-    [0.20104268, 0.9587303, 0.20104268],
-    [0.12921643, 0.98316133, 0.12921643],
-    [0.0315014, 0.8702115, 0.49167028],
-    [0.09403111, 0.868002, 0.4875762],
-    [0.06279713, 0.86953425, 0.48986408],
-    [0.031270664, 0.9324014, 0.36006898],
-    [0.03129361, 0.9589352, 0.28189343],
-    [0.031269234, 0.9438091, 0.32900843],
-    [0.09383008, 0.93040735, 0.35431355],
-    [0.093847886, 0.9548013, 0.28204095],
-    [0.0937832, 0.9416977, 0.32312563],
-    [0.06261321, 0.9446425, 0.32207158],
-    [0.03104798, 0.91294277, 0.40690488],
-    [0.031273916, 0.8872523, 0.4602232],
-    [0.09363459, 0.90861446, 0.40700433],
-    [0.09381681, 0.88492364, 0.45618913],
-    [0.062499367, 0.8992397, 0.43296865],
-    [0.15655987, 0.86374426, 0.47899362],
-    [0.23545098, 0.87149704, 0.43018106],
-    [0.21637973, 0.8584523, 0.46501538],
-    [0.20295721, 0.8652482, 0.45842555],
-    [0.17527439, 0.9358569, 0.30569702],
-    [0.1553051, 0.9274302, 0.3402258],
-    [0.15526843, 0.9461163, 0.28417522],
-    [0.13325031, 0.9391045, 0.31674463],
-    [0.22344194, 0.9325484, 0.2835968],
-    [0.21919031, 0.91308534, 0.34384704],
-    [0.20563968, 0.9422029, 0.26451087],
-    [0.18122959, 0.93467486, 0.30584067],
-    [0.15670548, 0.89986444, 0.4070473],
-    [0.15660593, 0.880411, 0.44760585],
-    [0.21663171, 0.88679457, 0.40824753],
-    [0.19375718, 0.8765209, 0.44064656],
-    [0.18102211, 0.8863728, 0.4261152],
-    [0.1261261, 0.90429944, 0.4078416],
-    [0.43595585, 0.7492584, 0.4985524],
-    [0.4985524, 0.7492584, 0.43595585],
-    [0.47463012, 0.7412505, 0.47463012],
-    [0.47002885, 0.7470914, 0.47002885],
-    [0.4700289, 0.7470915, 0.4700289],
-    [0.28189343, 0.9589352, 0.03129361],
-    [0.28204095, 0.9548013, 0.093847886],
-    [0.36006904, 0.9324015, 0.031270668],
-    [0.35431355, 0.93040735, 0.09383009],
-    [0.3290084, 0.9438091, 0.031269234],
-    [0.32312563, 0.9416977, 0.0937832],
-    [0.32207152, 0.9446425, 0.062613204],
-    [0.30598453, 0.9357656, 0.17525989],
-    [0.2835968, 0.9325484, 0.22344194],
-    [0.284349, 0.9460654, 0.15526031],
-    [0.26451087, 0.9422029, 0.20563965],
-    [0.3403962, 0.9273693, 0.15529533],
-    [0.34384707, 0.91308534, 0.21919033],
-    [0.31703112, 0.93901, 0.13323525],
-    [0.30597225, 0.93463314, 0.18122295],
-    [0.49167034, 0.8702115, 0.031501405],
-    [0.4875762, 0.86800206, 0.09403111],
-    [0.48986408, 0.86953425, 0.06279713],
-    [0.43018106, 0.87149704, 0.23545098],
-    [0.47899368, 0.86374426, 0.15655987],
-    [0.46501538, 0.8584523, 0.21637973],
-    [0.45842555, 0.8652482, 0.20295721],
-    [0.4069049, 0.91294277, 0.031047983],
-    [0.40700433, 0.90861446, 0.09363459],
-    [0.4602232, 0.8872522, 0.031273916],
-    [0.4561892, 0.8849237, 0.09381683],
-    [0.43296868, 0.89923966, 0.062499374],
-    [0.40704724, 0.8998644, 0.15670548],
-    [0.40824753, 0.88679457, 0.21663174],
-    [0.44760588, 0.880411, 0.15660593],
-    [0.44064656, 0.8765209, 0.19375718],
-    [0.42611519, 0.8863728, 0.18102211],
-    [0.40786266, 0.9042902, 0.1261247],
-    [0.3104197, 0.87433016, 0.37307698],
-    [0.37338692, 0.8743126, 0.3100962],
-    [0.34950918, 0.86938053, 0.34931475],
-    [0.34467193, 0.87326556, 0.34439573],
-    [0.3084306, 0.8105078, 0.4979435],
-    [0.28682917, 0.8666205, 0.40828666],
-    [0.28547674, 0.83276856, 0.4743411],
-    [0.34457406, 0.8036992, 0.4851146],
-    [0.3449563, 0.8457808, 0.4070137],
-    [0.344883, 0.8217146, 0.45369685],
-    [0.31958112, 0.8311948, 0.45495385],
-    [0.28164735, 0.91725105, 0.28164735],
-    [0.28651407, 0.8921842, 0.3491664],
-    [0.3493525, 0.8921737, 0.2863197],
-    [0.33201784, 0.8830307, 0.33169386],
-    [0.31267095, 0.8969861, 0.31249467],
-    [0.26893088, 0.8815712, 0.38795394],
-    [0.2689309, 0.8815713, 0.387954],
-    [0.40844813, 0.86655223, 0.28680548],
-    [0.40701374, 0.8457808, 0.3449563],
-    [0.4979435, 0.8105078, 0.3084306],
-    [0.4851146, 0.8036993, 0.3445741],
-    [0.47434115, 0.83276856, 0.28547677],
-    [0.45369685, 0.8217146, 0.344883],
-    [0.45498008, 0.83118224, 0.31957665],
-    [0.42039618, 0.8039072, 0.420714],
-    [0.40777215, 0.7826506, 0.47029766],
-    [0.40288505, 0.8218073, 0.402885],
-    [0.378907, 0.81380486, 0.44062606],
-    [0.47014332, 0.7826584, 0.40793523],
-    [0.45728362, 0.7625566, 0.45760158],
-    [0.44062606, 0.81380486, 0.378907],
-    [0.425898, 0.798189, 0.42603427],
-    [0.38822573, 0.88146305, 0.2688933],
-    [0.3882258, 0.88146317, 0.26889333],
-    [0.36085558, 0.86002004, 0.3607614],
-    [0.30616686, 0.90140903, 0.30614296],
-    [0.031237818, 0.67420614, 0.73788226],
-    [0.031237818, 0.73788226, 0.67420614],
-    [0.031487603, 0.7067562, 0.7067562],
-    [0.09397877, 0.6714177, 0.735096],
-    [0.09397877, 0.735096, 0.6714177],
-    [0.09446677, 0.7039447, 0.7039446],
-    [0.06280486, 0.7057109, 0.7057109],
-    [0.23801024, 0.62063086, 0.7471068],
-    [0.23801024, 0.62063086, 0.7471068],
-    [0.23801024, 0.7471068, 0.62063086],
-    [0.23801024, 0.7471068, 0.62063086],
-    [0.15645471, 0.6654565, 0.72985584],
-    [0.15645471, 0.72985584, 0.66545653],
-    [0.1568703, 0.6983523, 0.6983523],
-    [0.24374485, 0.68578, 0.68578],
-    [0.22027145, 0.6577759, 0.72028565],
-    [0.22027145, 0.72028553, 0.6577759],
-    [0.20234467, 0.6924799, 0.6924799],
-    [0.19400178, 0.6936726, 0.6936726],
-    [0.1840661, 0.695025, 0.6950251],
-    [0.03151556, 0.53241056, 0.8458994],
-    [0.03125978, 0.60362905, 0.79665226],
-    [0.031489953, 0.57229674, 0.81944174],
-    [0.094080195, 0.53247327, 0.84120214],
-    [0.09382261, 0.6003644, 0.794204],
-    [0.094032906, 0.56914115, 0.8168453],
-    [0.06276856, 0.56900597, 0.8199343],
-    [0.031009154, 0.6428037, 0.76540315],
-    [0.09351146, 0.63976383, 0.76286167],
-    [0.062290855, 0.6415984, 0.7645073],
-    [0.17494518, 0.55950457, 0.81015354],
-    [0.15524723, 0.5364308, 0.82954216],
-    [0.15492976, 0.58972514, 0.79260385],
-    [0.13328779, 0.5652887, 0.8140535],
-    [0.21888196, 0.5516121, 0.8048694],
-    [0.21909471, 0.5205178, 0.8252628],
-    [0.21786655, 0.59806585, 0.77126616],
-    [0.18222928, 0.5607579, 0.80767757],
-    [0.1560054, 0.63379604, 0.75760466],
-    [0.19616963, 0.62824327, 0.75287974],
-    [0.1761242, 0.63115114, 0.7553996],
-    [0.12118454, 0.60227495, 0.78903687],
-    [0.031259775, 0.7966522, 0.603629],
-    [0.03151556, 0.8458994, 0.53241056],
-    [0.031489953, 0.81944174, 0.57229674],
-    [0.093822606, 0.79420394, 0.6003643],
-    [0.094080195, 0.84120214, 0.53247327],
-    [0.0940329, 0.8168452, 0.56914115],
-    [0.062768556, 0.81993425, 0.56900597],
-    [0.031009154, 0.7654031, 0.64280367],
-    [0.09351146, 0.76286167, 0.63976383],
-    [0.062290855, 0.7645073, 0.6415984],
-    [0.17501052, 0.8101897, 0.55943173],
-    [0.1549694, 0.7926277, 0.5896828],
-    [0.15528637, 0.8295634, 0.53638667],
-    [0.13335358, 0.81409216, 0.56521755],
-    [0.21910737, 0.8048269, 0.5515847],
-    [0.21786655, 0.77126604, 0.59806585],
-    [0.21932015, 0.82522035, 0.52049017],
-    [0.1823241, 0.8076839, 0.560718],
-    [0.1560054, 0.7576047, 0.63379604],
-    [0.19616964, 0.75287974, 0.62824327],
-    [0.17612422, 0.7553996, 0.63115114],
-    [0.12120854, 0.7890399, 0.60226613],
-    [0.28137675, 0.6116178, 0.739426],
-    [0.36548474, 0.55680907, 0.74591184],
-    [0.37309933, 0.62416285, 0.6864529],
-    [0.34884682, 0.59748036, 0.722027],
-    [0.3426278, 0.5982611, 0.7243548],
-    [0.28137672, 0.739426, 0.6116178],
-    [0.373092, 0.6867571, 0.6238326],
-    [0.36566997, 0.7459645, 0.55661696],
-    [0.3488397, 0.722172, 0.5973092],
-    [0.34266987, 0.724479, 0.59808636],
-    [0.28141975, 0.67852885, 0.6785289],
-    [0.2814848, 0.6474225, 0.70824456],
-    [0.28148478, 0.70824444, 0.6474225],
-    [0.3527732, 0.66180485, 0.6614872],
-    [0.33160666, 0.63554347, 0.6972241],
-    [0.33159935, 0.6975285, 0.6352132],
-    [0.31040585, 0.67225796, 0.6720991],
-    [0.48917478, 0.61688745, 0.6165694],
-    [0.48917484, 0.6168875, 0.61656946],
-    [0.4118869, 0.53441423, 0.7380723],
-    [0.40674835, 0.6133388, 0.67703134],
-    [0.40651596, 0.58234537, 0.70400196],
-    [0.48655805, 0.55073935, 0.6781944],
-    [0.4673574, 0.53071374, 0.7070502],
-    [0.4674408, 0.5929142, 0.6557072],
-    [0.44483423, 0.5681223, 0.69235796],
-    [0.44214487, 0.5681868, 0.6940257],
-    [0.40687314, 0.6770918, 0.6131893],
-    [0.4119511, 0.7380218, 0.5344343],
-    [0.40663823, 0.7040583, 0.5821918],
-    [0.48661032, 0.67813694, 0.5507637],
-    [0.4674735, 0.6558311, 0.59275126],
-    [0.4673896, 0.70701736, 0.5307293],
-    [0.44491085, 0.6923032, 0.56812906],
-    [0.44221538, 0.69402945, 0.5681274],
-    [0.40705562, 0.6458738, 0.6458738],
-    [0.44770122, 0.632442, 0.63212395],
-    [0.42748648, 0.6393195, 0.63916045],
-    [0.40136042, 0.647725, 0.64758176],
-    [0.28516498, 0.5311748, 0.797831],
-    [0.26666993, 0.50946456, 0.8181277],
-    [0.28123412, 0.5803449, 0.764269],
-    [0.34472787, 0.5346671, 0.77155286],
-    [0.3239224, 0.5693265, 0.7556068],
-    [0.30065465, 0.5455595, 0.78228617],
-    [0.39460036, 0.5151102, 0.760889],
-    [0.39460036, 0.5151102, 0.760889],
-    [0.34807205, 0.531013, 0.7725743],
-    [0.28548548, 0.7976628, 0.5312552],
-    [0.28145143, 0.7642177, 0.58030725],
-    [0.2669183, 0.81792694, 0.50965697],
-    [0.3450088, 0.7715333, 0.5345142],
-    [0.32420808, 0.755528, 0.5692686],
-    [0.3009246, 0.78218, 0.5455628],
-    [0.3947095, 0.7608078, 0.5151464],
-    [0.39470956, 0.76080793, 0.51514643],
-    [0.34826043, 0.7724774, 0.53103065],
-    [0.25789994, 0.6831899, 0.683183],
-    [0.7492584, 0.43595585, 0.4985524],
-    [0.7492584, 0.4985524, 0.43595585],
-    [0.7412505, 0.47463006, 0.47463012],
-    [0.74709153, 0.47002885, 0.4700289],
-    [0.74709153, 0.47002885, 0.4700289],
-    [0.9990212, 0.03127881, 0.03127881],
-    [0.9950792, 0.031052334, 0.09409098],
-    [0.9950792, 0.09409098, 0.031052334],
-    [0.9911904, 0.0936523, 0.0936523],
-    [0.99607605, 0.06258039, 0.0625804],
-    [0.98715264, 0.03129467, 0.15668549],
-    [0.9751979, 0.03153171, 0.21907744],
-    [0.98317695, 0.093870826, 0.1566884],
-    [0.9710682, 0.09409147, 0.21948425],
-    [0.9801288, 0.062759906, 0.18817203],
-    [0.98715264, 0.15668547, 0.03129467],
-    [0.983177, 0.15668844, 0.09387083],
-    [0.9751979, 0.21907744, 0.03153171],
-    [0.97106814, 0.21948425, 0.09409147],
-    [0.9801288, 0.18817203, 0.06275991],
-    [0.9370545, 0.24690987, 0.24690987],
-    [0.9751452, 0.15667123, 0.15667123],
-    [0.9629035, 0.15666673, 0.21970935],
-    [0.9629035, 0.21970937, 0.15666674],
-    [0.9486019, 0.22377947, 0.22377947],
-    [0.9587303, 0.2010427, 0.2010427],
-    [0.98316133, 0.12921645, 0.12921645],
-    [0.8702115, 0.031501405, 0.49167034],
-    [0.86800206, 0.09403111, 0.4875762],
-    [0.86953425, 0.06279713, 0.48986408],
-    [0.8637442, 0.15655988, 0.47899368],
-    [0.8714971, 0.23545098, 0.43018106],
-    [0.8584523, 0.21637973, 0.46501538],
-    [0.8652482, 0.20295721, 0.45842555],
-    [0.9324015, 0.031270668, 0.36006904],
-    [0.9304073, 0.09383008, 0.3543135],
-    [0.9589352, 0.03129361, 0.28189346],
-    [0.9438091, 0.031269234, 0.3290084],
-    [0.9548013, 0.09384788, 0.28204095],
-    [0.9416977, 0.0937832, 0.32312563],
-    [0.9446425, 0.06261321, 0.32207158],
-    [0.91294277, 0.031047983, 0.4069049],
-    [0.8872522, 0.031273916, 0.4602232],
-    [0.90861446, 0.09363459, 0.40700433],
-    [0.8849237, 0.09381683, 0.4561892],
-    [0.89923966, 0.062499374, 0.43296868],
-    [0.9358569, 0.17527439, 0.30569702],
-    [0.9274302, 0.15530512, 0.3402258],
-    [0.9325484, 0.22344194, 0.2835968],
-    [0.91308534, 0.21919031, 0.34384707],
-    [0.9461164, 0.15526843, 0.28417525],
-    [0.9391045, 0.13325031, 0.31674463],
-    [0.9422029, 0.20563968, 0.26451087],
-    [0.93467486, 0.18122959, 0.30584067],
-    [0.8998644, 0.15670547, 0.40704724],
-    [0.880411, 0.15660593, 0.44760588],
-    [0.88679457, 0.21663174, 0.40824756],
-    [0.8765209, 0.19375718, 0.44064656],
-    [0.8863728, 0.18102211, 0.4261152],
-    [0.90429944, 0.12612613, 0.40784165],
-    [0.8702115, 0.49167037, 0.031501405],
-    [0.86800206, 0.48757622, 0.09403112],
-    [0.86953413, 0.48986408, 0.06279713],
-    [0.8714971, 0.43018106, 0.23545098],
-    [0.86374426, 0.47899368, 0.15655987],
-    [0.8584523, 0.46501538, 0.21637976],
-    [0.8652482, 0.45842555, 0.20295723],
-    [0.9324014, 0.360069, 0.031270664],
-    [0.9304073, 0.3543135, 0.09383008],
-    [0.9589352, 0.28189346, 0.03129361],
-    [0.9548013, 0.28204095, 0.09384788],
-    [0.9438091, 0.32900843, 0.031269234],
-    [0.9416977, 0.32312563, 0.0937832],
-    [0.9446425, 0.32207158, 0.06261321],
-    [0.9357656, 0.30598453, 0.17525989],
-    [0.9325484, 0.2835968, 0.22344194],
-    [0.9273693, 0.3403962, 0.15529533],
-    [0.91308534, 0.34384704, 0.21919031],
-    [0.9460654, 0.28434896, 0.15526032],
-    [0.9422029, 0.26451087, 0.20563965],
-    [0.93901, 0.31703112, 0.13323525],
-    [0.9346332, 0.30597225, 0.18122298],
-    [0.91294277, 0.4069049, 0.031047983],
-    [0.90861446, 0.40700433, 0.09363459],
-    [0.8872522, 0.4602232, 0.031273916],
-    [0.88492364, 0.45618913, 0.09381681],
-    [0.89923966, 0.43296868, 0.062499367],
-    [0.8998644, 0.40704724, 0.15670547],
-    [0.88679457, 0.40824753, 0.21663174],
-    [0.880411, 0.44760585, 0.15660593],
-    [0.8765209, 0.44064656, 0.19375718],
-    [0.8863728, 0.42611519, 0.18102211],
-    [0.9042902, 0.4078627, 0.1261247],
-    [0.87433016, 0.3104197, 0.37307698],
-    [0.8743127, 0.37338692, 0.3100962],
-    [0.86938053, 0.34950918, 0.34931475],
-    [0.87326556, 0.34467193, 0.34439573],
-    [0.8105078, 0.3084306, 0.4979435],
-    [0.8036993, 0.3445741, 0.4851146],
-    [0.8666205, 0.28682917, 0.4082866],
-    [0.83276856, 0.28547677, 0.47434115],
-    [0.8457808, 0.34495628, 0.4070137],
-    [0.8217146, 0.344883, 0.45369685],
-    [0.8311948, 0.3195811, 0.4549538],
-    [0.8105078, 0.4979435, 0.3084306],
-    [0.8036992, 0.48511457, 0.3445741],
-    [0.86655223, 0.40844816, 0.28680548],
-    [0.8457808, 0.4070137, 0.34495628],
-    [0.83276856, 0.47434115, 0.2854768],
-    [0.8217146, 0.45369685, 0.344883],
-    [0.8311822, 0.45498008, 0.31957668],
-    [0.80390716, 0.42039615, 0.420714],
-    [0.78265065, 0.40777215, 0.4702976],
-    [0.7826584, 0.47014332, 0.40793523],
-    [0.7625566, 0.45728362, 0.45760158],
-    [0.8218073, 0.402885, 0.402885],
-    [0.81380486, 0.378907, 0.44062606],
-    [0.81380486, 0.44062606, 0.378907],
-    [0.79818904, 0.42589805, 0.4260343],
-    [0.91725105, 0.28164735, 0.28164735],
-    [0.8921842, 0.286514, 0.3491663],
-    [0.8921737, 0.34935254, 0.28631976],
-    [0.8830307, 0.33201784, 0.33169386],
-    [0.8969861, 0.31267095, 0.31249467],
-    [0.8815712, 0.26893088, 0.38795394],
-    [0.8815713, 0.2689309, 0.387954],
-    [0.88146305, 0.38822573, 0.2688933],
-    [0.88146317, 0.3882258, 0.26889333],
-    [0.86002004, 0.36085558, 0.36076134],
-    [0.90140903, 0.30616686, 0.306143],
-    [0.62063086, 0.23801026, 0.7471068],
-    [0.62063086, 0.23801026, 0.7471068],
-    [0.67420614, 0.031237818, 0.73788226],
-    [0.67141783, 0.093978785, 0.735096],
-    [0.73788226, 0.031237818, 0.67420614],
-    [0.7067562, 0.031487603, 0.7067562],
-    [0.735096, 0.09397876, 0.6714177],
-    [0.7039447, 0.09446677, 0.7039447],
-    [0.7057109, 0.06280486, 0.7057109],
-    [0.7471068, 0.23801026, 0.62063086],
-    [0.7471068, 0.23801026, 0.62063086],
-    [0.66545653, 0.1564547, 0.72985584],
-    [0.68578, 0.24374485, 0.68578],
-    [0.6577759, 0.22027145, 0.72028565],
-    [0.72985584, 0.15645471, 0.6654565],
-    [0.6983523, 0.1568703, 0.6983523],
-    [0.72028553, 0.22027145, 0.6577759],
-    [0.6924799, 0.20234467, 0.6924799],
-    [0.6936726, 0.19400176, 0.69367254],
-    [0.6950251, 0.18406613, 0.695025],
-    [0.5324105, 0.03151556, 0.8458994],
-    [0.53247327, 0.09408019, 0.8412022],
-    [0.60362905, 0.03125978, 0.79665226],
-    [0.57229674, 0.031489953, 0.81944174],
-    [0.6003643, 0.093822606, 0.79420394],
-    [0.56914115, 0.094032906, 0.81684524],
-    [0.56900597, 0.06276856, 0.81993437],
-    [0.55950457, 0.17494518, 0.81015354],
-    [0.5364308, 0.15524723, 0.82954216],
-    [0.5516121, 0.21888196, 0.8048694],
-    [0.5205178, 0.21909474, 0.8252628],
-    [0.58972514, 0.15492976, 0.79260385],
-    [0.5652887, 0.13328779, 0.8140535],
-    [0.59806585, 0.21786655, 0.77126604],
-    [0.5607578, 0.18222927, 0.8076775],
-    [0.6428037, 0.031009158, 0.76540315],
-    [0.63976383, 0.09351146, 0.76286167],
-    [0.6415984, 0.06229086, 0.7645073],
-    [0.63379604, 0.1560054, 0.7576047],
-    [0.62824327, 0.19616963, 0.75287974],
-    [0.63115114, 0.1761242, 0.7553996],
-    [0.60227495, 0.12118454, 0.78903687],
-    [0.55680907, 0.36548474, 0.74591184],
-    [0.61161774, 0.28137675, 0.739426],
-    [0.62416285, 0.37309936, 0.6864529],
-    [0.59748036, 0.34884682, 0.722027],
-    [0.5982611, 0.34262776, 0.72435474],
-    [0.61688745, 0.48917478, 0.6165694],
-    [0.6168875, 0.48917484, 0.61656946],
-    [0.5344142, 0.41188684, 0.7380723],
-    [0.55073935, 0.48655805, 0.6781944],
-    [0.5307137, 0.4673574, 0.7070502],
-    [0.6133388, 0.4067483, 0.6770312],
-    [0.58234537, 0.40651596, 0.7040019],
-    [0.5929141, 0.46744078, 0.6557072],
-    [0.5681223, 0.44483423, 0.69235796],
-    [0.5681868, 0.44214487, 0.6940257],
-    [0.6867571, 0.37309206, 0.62383264],
-    [0.739426, 0.28137675, 0.6116178],
-    [0.7459645, 0.36566997, 0.55661696],
-    [0.722172, 0.3488397, 0.5973092],
-    [0.724479, 0.3426699, 0.5980864],
-    [0.67852885, 0.28141975, 0.6785289],
-    [0.6474225, 0.2814848, 0.70824456],
-    [0.66180485, 0.35277322, 0.66148716],
-    [0.63554347, 0.33160666, 0.6972241],
-    [0.70824456, 0.2814848, 0.6474225],
-    [0.6975285, 0.33159935, 0.6352132],
-    [0.67225814, 0.31040588, 0.6720992],
-    [0.6770918, 0.40687314, 0.6131893],
-    [0.67813694, 0.48661035, 0.5507637],
-    [0.65583116, 0.4674735, 0.59275126],
-    [0.7380218, 0.4119511, 0.5344343],
-    [0.7040582, 0.40663823, 0.5821918],
-    [0.70701736, 0.4673896, 0.5307293],
-    [0.6923032, 0.44491085, 0.56812906],
-    [0.69402945, 0.44221535, 0.5681274],
-    [0.6458738, 0.40705562, 0.6458738],
-    [0.632442, 0.44770122, 0.63212395],
-    [0.6393195, 0.42748648, 0.63916045],
-    [0.647725, 0.4013604, 0.64758176],
-    [0.5311748, 0.28516495, 0.797831],
-    [0.50946456, 0.26666993, 0.8181277],
-    [0.53466713, 0.34472784, 0.7715529],
-    [0.5803449, 0.2812341, 0.76426905],
-    [0.5693265, 0.3239224, 0.7556068],
-    [0.5455595, 0.30065465, 0.78228617],
-    [0.5151102, 0.39460036, 0.760889],
-    [0.5151102, 0.39460036, 0.760889],
-    [0.531013, 0.34807205, 0.7725743],
-    [0.79665226, 0.03125978, 0.603629],
-    [0.79420394, 0.093822606, 0.6003643],
-    [0.8458994, 0.03151556, 0.5324105],
-    [0.81944174, 0.031489953, 0.57229674],
-    [0.84120214, 0.094080195, 0.53247327],
-    [0.8168453, 0.094032906, 0.56914115],
-    [0.8199343, 0.06276857, 0.56900597],
-    [0.76540315, 0.031009154, 0.6428037],
-    [0.76286167, 0.09351146, 0.63976383],
-    [0.7645073, 0.062290855, 0.6415984],
-    [0.8101897, 0.17501052, 0.55943173],
-    [0.79262763, 0.1549694, 0.5896828],
-    [0.8048269, 0.21910739, 0.5515847],
-    [0.7712661, 0.21786653, 0.5980658],
-    [0.8295634, 0.15528637, 0.53638667],
-    [0.81409216, 0.13335358, 0.56521755],
-    [0.82522047, 0.21932018, 0.52049017],
-    [0.8076838, 0.18232408, 0.56071794],
-    [0.7576047, 0.1560054, 0.63379604],
-    [0.75287974, 0.19616964, 0.62824327],
-    [0.7553996, 0.17612422, 0.63115114],
-    [0.7890399, 0.12120854, 0.6022661],
-    [0.7976628, 0.28548548, 0.5312552],
-    [0.7642176, 0.28145143, 0.58030725],
-    [0.77153325, 0.34500876, 0.5345142],
-    [0.755528, 0.32420808, 0.5692686],
-    [0.81792694, 0.2669183, 0.50965697],
-    [0.78218, 0.3009246, 0.5455628],
-    [0.7608078, 0.3947095, 0.5151464],
-    [0.76080793, 0.39470956, 0.51514643],
-    [0.7724774, 0.34826043, 0.53103065],
-    [0.68319, 0.25789997, 0.683183],
-    [0.6209723, 0.7467547, 0.23822434],
-    [0.62097234, 0.74675477, 0.23822437],
-    [0.74675465, 0.6209723, 0.23822431],
-    [0.7467547, 0.62097234, 0.23822434],
-    [0.67420614, 0.73788226, 0.031237818],
-    [0.6714177, 0.735096, 0.09397876],
-    [0.73788226, 0.67420614, 0.031237818],
-    [0.735096, 0.67141783, 0.093978785],
-    [0.7067562, 0.7067562, 0.031487603],
-    [0.7039447, 0.7039447, 0.09446677],
-    [0.7057108, 0.7057108, 0.062804855],
-    [0.68578, 0.68578, 0.24374485],
-    [0.665433, 0.72982764, 0.15668593],
-    [0.65791845, 0.72007656, 0.22052874],
-    [0.7298277, 0.665433, 0.15668592],
-    [0.72007656, 0.65791845, 0.22052875],
-    [0.6983523, 0.6983523, 0.1568703],
-    [0.6924799, 0.6924799, 0.20234467],
-    [0.69365317, 0.6936531, 0.1941408],
-    [0.69500655, 0.69500655, 0.1842057],
-    [0.61683726, 0.61683726, 0.48890042],
-    [0.61683726, 0.61683726, 0.48890042],
-    [0.5565471, 0.74587893, 0.36595076],
-    [0.6240265, 0.6866207, 0.37301892],
-    [0.6115759, 0.7393764, 0.28159812],
-    [0.59731305, 0.72213846, 0.34890276],
-    [0.59810805, 0.7244028, 0.34279317],
-    [0.5507637, 0.67813694, 0.48661032],
-    [0.5344669, 0.73787886, 0.41216496],
-    [0.5307293, 0.70701736, 0.4673896],
-    [0.61328274, 0.6769644, 0.40694398],
-    [0.5929463, 0.6557199, 0.46738228],
-    [0.58223426, 0.7038641, 0.4069135],
-    [0.56825644, 0.69213676, 0.44500726],
-    [0.5681965, 0.69392186, 0.4422953],
-    [0.6866207, 0.6240265, 0.37301892],
-    [0.74587893, 0.5565471, 0.36595076],
-    [0.7393764, 0.6115759, 0.28159812],
-    [0.72213846, 0.59731305, 0.34890276],
-    [0.7244028, 0.59810805, 0.34279317],
-    [0.67813694, 0.5507637, 0.48661032],
-    [0.6769644, 0.61328274, 0.406944],
-    [0.6557199, 0.5929463, 0.46738222],
-    [0.73787886, 0.534467, 0.41216493],
-    [0.70701736, 0.5307293, 0.4673896],
-    [0.70386404, 0.58223426, 0.40691346],
-    [0.69213676, 0.56825644, 0.44500726],
-    [0.69392186, 0.56819654, 0.4422953],
-    [0.6785289, 0.67852885, 0.28141975],
-    [0.66166675, 0.66166675, 0.35269552],
-    [0.64738035, 0.70819503, 0.28170618],
-    [0.63540477, 0.6973894, 0.33152497],
-    [0.70819515, 0.64738035, 0.28170624],
-    [0.6973894, 0.63540477, 0.33152497],
-    [0.6721709, 0.6721708, 0.31043956],
-    [0.6458738, 0.6458738, 0.40705562],
-    [0.6323825, 0.6323825, 0.44741994],
-    [0.6392875, 0.6392875, 0.42734426],
-    [0.64764833, 0.64764833, 0.40137666],
-    [0.5324105, 0.8458994, 0.03151556],
-    [0.53247327, 0.8412022, 0.09408019],
-    [0.603629, 0.79665226, 0.03125978],
-    [0.6003643, 0.79420394, 0.093822606],
-    [0.57229674, 0.81944174, 0.031489953],
-    [0.56914115, 0.8168453, 0.094032906],
-    [0.56900597, 0.81993437, 0.06276856],
-    [0.5598676, 0.80990016, 0.17495714],
-    [0.5515847, 0.8048269, 0.21910739],
-    [0.5366531, 0.8293968, 0.15525566],
-    [0.52049017, 0.82522047, 0.21932018],
-    [0.5899191, 0.7924223, 0.15512083],
-    [0.5982785, 0.77106327, 0.21800065],
-    [0.56565136, 0.8137997, 0.13329963],
-    [0.56094605, 0.8075206, 0.18234566],
-    [0.6428036, 0.76540303, 0.03100915],
-    [0.63976383, 0.76286167, 0.09351146],
-    [0.6415984, 0.7645073, 0.062290855],
-    [0.6337491, 0.7575485, 0.15646799],
-    [0.6285504, 0.7524859, 0.19669631],
-    [0.6312817, 0.75517493, 0.17661905],
-    [0.6023601, 0.7889482, 0.12133884],
-    [0.5312552, 0.7976628, 0.28548548],
-    [0.53451157, 0.77153325, 0.3450128],
-    [0.50965697, 0.81792694, 0.26691833],
-    [0.58030725, 0.7642176, 0.28145143],
-    [0.5691334, 0.75566536, 0.32412475],
-    [0.5455343, 0.7822062, 0.30090824],
-    [0.51527786, 0.76067156, 0.39480042],
-    [0.5152779, 0.7606717, 0.39480048],
-    [0.5310833, 0.77242386, 0.3482985],
-    [0.79665226, 0.60362905, 0.03125978],
-    [0.79420394, 0.6003643, 0.093822606],
-    [0.8458994, 0.53241056, 0.03151556],
-    [0.84120214, 0.53247327, 0.094080195],
-    [0.81944174, 0.57229674, 0.031489953],
-    [0.81684524, 0.56914115, 0.094032906],
-    [0.81993425, 0.56900597, 0.06276857],
-    [0.80990016, 0.5598676, 0.17495714],
-    [0.8048269, 0.5515847, 0.21910737],
-    [0.7924223, 0.5899191, 0.15512083],
-    [0.77106327, 0.5982786, 0.21800068],
-    [0.8293967, 0.5366531, 0.15525565],
-    [0.82522047, 0.52049017, 0.21932018],
-    [0.8137997, 0.56565136, 0.13329963],
-    [0.8075206, 0.56094605, 0.18234566],
-    [0.7654031, 0.64280367, 0.031009154],
-    [0.76286167, 0.63976383, 0.09351146],
-    [0.7645073, 0.6415984, 0.062290855],
-    [0.7575486, 0.6337491, 0.156468],
-    [0.7524859, 0.6285504, 0.19669631],
-    [0.75517493, 0.6312816, 0.17661904],
-    [0.7889481, 0.6023601, 0.12133883],
-    [0.7976628, 0.5312552, 0.28548548],
-    [0.77153325, 0.5345115, 0.3450128],
-    [0.7642177, 0.58030725, 0.28145143],
-    [0.75566536, 0.5691334, 0.32412475],
-    [0.81792694, 0.50965697, 0.26691833],
-    [0.78220624, 0.5455343, 0.30090824],
-    [0.76067156, 0.51527786, 0.39480042],
-    [0.7606717, 0.5152779, 0.39480048],
-    [0.77242386, 0.5310833, 0.3482985],
-    [0.6831648, 0.6831648, 0.2580152],
-    [0.5557188, 0.5557188, 0.6183473],
-    [0.55560803, 0.61854213, 0.55561256],
-    [0.5349534, 0.59742147, 0.5974215],
-    [0.6185422, 0.55560803, 0.55561256],
-    [0.59742147, 0.5349535, 0.59742147],
-    [0.5974675, 0.5974675, 0.53485054],
-    [0.57735026, 0.57735026, 0.57735026],
-    [0.5773715, 0.5773715, 0.5773078],
-    [0.5327861, 0.5327861, 0.6574786],
-    [0.5089309, 0.5089309, 0.69424677],
-    [0.5137616, 0.5753806, 0.63638526],
-    [0.5753806, 0.5137616, 0.63638526],
-    [0.53322995, 0.53322995, 0.6567584],
-    [0.53269184, 0.65762943, 0.5326941],
-    [0.5137616, 0.63638526, 0.5753806],
-    [0.5088563, 0.6943562, 0.5088563],
-    [0.57526654, 0.63657665, 0.51365227],
-    [0.5331593, 0.65687174, 0.5331611],
-    [0.6576295, 0.5326919, 0.5326941],
-    [0.63638526, 0.5137616, 0.5753806],
-    [0.63657665, 0.57526654, 0.51365227],
-    [0.6943562, 0.5088563, 0.5088563],
-    [0.6568716, 0.5331593, 0.53316104],
-    [0.5773707, 0.5773707, 0.5773094],
-    [0.5773707, 0.5773707, 0.5773094],
-    [0.57734746, 0.5773474, 0.57735586],
-];
-
-#[allow(dead_code)]
-pub const EXPECTED_COLORS: &[[f32; 4]] = &[
-    [0.40276918, 0.64721596, 0.64721596, 1.0],
-    [0.49048194, 0.57322705, 0.6563827, 1.0],
-    [0.4907689, 0.6562734, 0.5731065, 1.0],
-    [0.45999545, 0.6278566, 0.6278537, 1.0],
-    [0.46116078, 0.62742877, 0.62742645, 1.0],
-    [0.57322705, 0.49048194, 0.6563827, 1.0],
-    [0.64721596, 0.40276918, 0.64721596, 1.0],
-    [0.6562734, 0.4907689, 0.5731065, 1.0],
-    [0.62785673, 0.4599954, 0.6278537, 1.0],
-    [0.6274288, 0.46116066, 0.6274264, 1.0],
-    [0.5731065, 0.6562734, 0.4907689, 1.0],
-    [0.6562734, 0.5731065, 0.4907689, 1.0],
-    [0.6471624, 0.6471624, 0.40294126, 1.0],
-    [0.6277701, 0.6277701, 0.4602275, 1.0],
-    [0.6273636, 0.6273636, 0.46133476, 1.0],
-    [0.57735026, 0.57735026, 0.57735026, 1.0],
-    [0.54756, 0.54756, 0.63273704, 1.0],
-    [0.5476335, 0.63260967, 0.5476335, 1.0],
-    [0.5188704, 0.6044753, 0.6044692, 1.0],
-    [0.6326097, 0.5476335, 0.5476335, 1.0],
-    [0.6044753, 0.5188705, 0.6044692, 1.0],
-    [0.6043494, 0.6043494, 0.5191567, 1.0],
-    [0.5773213, 0.5773213, 0.5774082, 1.0],
-    [0.57732236, 0.57732236, 0.577406, 1.0],
-    [0.57732236, 0.57732236, 0.577406, 1.0],
-    [0.29492825, 0.5973308, 0.74579704, 1.0],
-    [0.29492816, 0.5973308, 0.74579716, 1.0],
-    [0.20217916, 0.54449105, 0.81403506, 1.0],
-    [0.2915877, 0.514194, 0.8065861, 1.0],
-    [0.27261308, 0.48525155, 0.8307906, 1.0],
-    [0.27348348, 0.5572077, 0.7840449, 1.0],
-    [0.23040622, 0.533771, 0.81363475, 1.0],
-    [0.25271907, 0.52734363, 0.8111978, 1.0],
-    [0.27270988, 0.561914, 0.78094935, 1.0],
-    [0.269334, 0.4041951, 0.87411976, 1.0],
-    [0.2549342, 0.38510746, 0.8869616, 1.0],
-    [0.26190138, 0.39443606, 0.8808109, 1.0],
-    [0.23622756, 0.35885254, 0.9030068, 1.0],
-    [0.22151828, 0.33728284, 0.91497004, 1.0],
-    [0.22838555, 0.347585, 0.9094089, 1.0],
-    [0.18862088, 0.43999508, 0.87796724, 1.0],
-    [0.18739006, 0.51410663, 0.83700615, 1.0],
-    [0.17435853, 0.47354585, 0.86333853, 1.0],
-    [0.25200292, 0.44219634, 0.8607886, 1.0],
-    [0.21582027, 0.42636448, 0.8784276, 1.0],
-    [0.2118243, 0.48667186, 0.84751457, 1.0],
-    [0.19921857, 0.4612447, 0.8646186, 1.0],
-    [0.20307444, 0.4632218, 0.8626624, 1.0],
-    [0.17960134, 0.4224997, 0.8883903, 1.0],
-    [0.16811268, 0.39822233, 0.90175223, 1.0],
-    [0.15391259, 0.4531437, 0.87804997, 1.0],
-    [0.14184006, 0.43038735, 0.8914303, 1.0],
-    [0.20344773, 0.3950755, 0.89583725, 1.0],
-    [0.1907106, 0.37173828, 0.9085374, 1.0],
-    [0.17195326, 0.4115766, 0.89500666, 1.0],
-    [0.21377276, 0.40276635, 0.8899891, 1.0],
-    [0.5973307, 0.29492828, 0.74579716, 1.0],
-    [0.5973307, 0.29492828, 0.74579716, 1.0],
-    [0.51419395, 0.29158768, 0.8065861, 1.0],
-    [0.48525155, 0.27261308, 0.8307906, 1.0],
-    [0.54449105, 0.20217916, 0.81403506, 1.0],
-    [0.5572075, 0.2734835, 0.7840449, 1.0],
-    [0.533771, 0.23040622, 0.81363475, 1.0],
-    [0.5273436, 0.25271913, 0.8111978, 1.0],
-    [0.56191397, 0.27270988, 0.7809495, 1.0],
-    [0.4041951, 0.269334, 0.87411976, 1.0],
-    [0.3851074, 0.25493416, 0.8869615, 1.0],
-    [0.39443606, 0.26190138, 0.8808109, 1.0],
-    [0.35885254, 0.23622756, 0.9030068, 1.0],
-    [0.33728272, 0.22151822, 0.91497004, 1.0],
-    [0.34758502, 0.22838552, 0.90940905, 1.0],
-    [0.43999508, 0.18862088, 0.87796724, 1.0],
-    [0.44219634, 0.25200292, 0.8607886, 1.0],
-    [0.42636442, 0.21582021, 0.8784276, 1.0],
-    [0.51410663, 0.18739006, 0.83700615, 1.0],
-    [0.4735459, 0.17435853, 0.86333853, 1.0],
-    [0.48667186, 0.2118243, 0.84751457, 1.0],
-    [0.4612447, 0.19921857, 0.8646186, 1.0],
-    [0.4632217, 0.20307447, 0.8626624, 1.0],
-    [0.4224997, 0.1796014, 0.8883903, 1.0],
-    [0.3982224, 0.16811262, 0.90175223, 1.0],
-    [0.39507553, 0.20344779, 0.89583725, 1.0],
-    [0.37173828, 0.19071063, 0.90853727, 1.0],
-    [0.4531437, 0.15391259, 0.87804997, 1.0],
-    [0.4303873, 0.14184012, 0.8914303, 1.0],
-    [0.4115766, 0.1719532, 0.89500666, 1.0],
-    [0.40276635, 0.21377288, 0.8899891, 1.0],
-    [0.48453426, 0.48453426, 0.72832215, 1.0],
-    [0.45626575, 0.45626575, 0.7639655, 1.0],
-    [0.47034106, 0.47034106, 0.74669844, 1.0],
-    [0.36932254, 0.444972, 0.8158436, 1.0],
-    [0.3426044, 0.4140061, 0.8433393, 1.0],
-    [0.444972, 0.3693227, 0.8158436, 1.0],
-    [0.4140061, 0.3426044, 0.8433393, 1.0],
-    [0.42031673, 0.42031673, 0.80415654, 1.0],
-    [0.37805873, 0.37805882, 0.8450699, 1.0],
-    [0.39453486, 0.39453492, 0.8298702, 1.0],
-    [0.4010761, 0.5624641, 0.72302985, 1.0],
-    [0.37793347, 0.5331593, 0.75690657, 1.0],
-    [0.381519, 0.61107945, 0.6935598, 1.0],
-    [0.3299854, 0.58606154, 0.7400281, 1.0],
-    [0.45685118, 0.54015017, 0.70677066, 1.0],
-    [0.41509846, 0.49692896, 0.76207274, 1.0],
-    [0.4267007, 0.5955621, 0.68061167, 1.0],
-    [0.3980844, 0.5616024, 0.7253492, 1.0],
-    [0.3411742, 0.49444202, 0.7994543, 1.0],
-    [0.30401844, 0.45309815, 0.8380185, 1.0],
-    [0.31203493, 0.54451525, 0.77854824, 1.0],
-    [0.39398742, 0.4726823, 0.7882546, 1.0],
-    [0.3368485, 0.49121213, 0.8032706, 1.0],
-    [0.5624641, 0.4010761, 0.72302985, 1.0],
-    [0.5331593, 0.37793347, 0.75690645, 1.0],
-    [0.5401502, 0.45685124, 0.70677054, 1.0],
-    [0.49692896, 0.41509846, 0.76207274, 1.0],
-    [0.61107945, 0.381519, 0.6935598, 1.0],
-    [0.58606154, 0.3299854, 0.7400281, 1.0],
-    [0.5955621, 0.4267007, 0.68061167, 1.0],
-    [0.5616024, 0.3980844, 0.7253492, 1.0],
-    [0.49444193, 0.34117422, 0.7994544, 1.0],
-    [0.45309815, 0.30401835, 0.8380185, 1.0],
-    [0.4726823, 0.39398742, 0.7882546, 1.0],
-    [0.54451525, 0.31203493, 0.77854824, 1.0],
-    [0.49121213, 0.3368485, 0.8032706, 1.0],
-    [0.51439345, 0.51439345, 0.6861478, 1.0],
-    [0.51439345, 0.51439345, 0.6861478, 1.0],
-    [0.4523828, 0.4523827, 0.76856995, 1.0],
-    [0.33848822, 0.33848822, 0.8779814, 1.0],
-    [0.31923515, 0.31923515, 0.89228797, 1.0],
-    [0.312388, 0.381755, 0.8698717, 1.0],
-    [0.28540465, 0.35342988, 0.89086, 1.0],
-    [0.381755, 0.312388, 0.8698717, 1.0],
-    [0.35342973, 0.2854046, 0.89086, 1.0],
-    [0.3582288, 0.3582288, 0.86217415, 1.0],
-    [0.33482915, 0.33482915, 0.88078314, 1.0],
-    [0.29675537, 0.2967553, 0.9076743, 1.0],
-    [0.27833474, 0.27833468, 0.91927123, 1.0],
-    [0.26503462, 0.32874426, 0.90646785, 1.0],
-    [0.24841687, 0.30876464, 0.91812503, 1.0],
-    [0.32874426, 0.26503462, 0.90646785, 1.0],
-    [0.30876464, 0.24841687, 0.91812503, 1.0],
-    [0.28698552, 0.28698552, 0.91393584, 1.0],
-    [0.285267, 0.42695394, 0.8580985, 1.0],
-    [0.28526694, 0.42695388, 0.8580985, 1.0],
-    [0.42695394, 0.28526694, 0.8580985, 1.0],
-    [0.42695394, 0.2852669, 0.85809857, 1.0],
-    [0.3305083, 0.33050823, 0.8840411, 1.0],
-    [0.36553058, 0.36553058, 0.8560227, 1.0],
-    [0.29472175, 0.7458115, 0.5974146, 1.0],
-    [0.29472166, 0.7458115, 0.5974147, 1.0],
-    [0.20217916, 0.81403506, 0.54449105, 1.0],
-    [0.29178545, 0.80657965, 0.514092, 1.0],
-    [0.27348295, 0.7840482, 0.5572033, 1.0],
-    [0.27261317, 0.83079064, 0.48525152, 1.0],
-    [0.23040622, 0.81363475, 0.533771, 1.0],
-    [0.2527559, 0.8111992, 0.5273238, 1.0],
-    [0.2726312, 0.78095317, 0.561947, 1.0],
-    [0.26876533, 0.8742376, 0.40431887, 1.0],
-    [0.2547738, 0.8870955, 0.3849051, 1.0],
-    [0.26154107, 0.8809363, 0.3943949, 1.0],
-    [0.18739006, 0.83700615, 0.51410663, 1.0],
-    [0.18830773, 0.877834, 0.44039503, 1.0],
-    [0.17417563, 0.86324483, 0.473784, 1.0],
-    [0.25173572, 0.8607861, 0.44235334, 1.0],
-    [0.2118243, 0.84751457, 0.48667186, 1.0],
-    [0.21556422, 0.87841195, 0.42652598, 1.0],
-    [0.19889483, 0.8644745, 0.4616544, 1.0],
-    [0.20288275, 0.8626022, 0.46341783, 1.0],
-    [0.23622756, 0.9030068, 0.35885254, 1.0],
-    [0.22151817, 0.91497004, 0.33728272, 1.0],
-    [0.22838552, 0.90940905, 0.34758502, 1.0],
-    [0.1796014, 0.8883903, 0.4224997, 1.0],
-    [0.15391259, 0.87804997, 0.4531437, 1.0],
-    [0.16811268, 0.90175223, 0.3982224, 1.0],
-    [0.14184006, 0.8914303, 0.43038735, 1.0],
-    [0.20344773, 0.89583725, 0.3950755, 1.0],
-    [0.1907106, 0.9085374, 0.37173828, 1.0],
-    [0.17195314, 0.89500666, 0.4115766, 1.0],
-    [0.21365076, 0.8899999, 0.40280685, 1.0],
-    [0.5974011, 0.74587333, 0.29459298, 1.0],
-    [0.5974011, 0.74587333, 0.29459298, 1.0],
-    [0.5139138, 0.8067495, 0.29162943, 1.0],
-    [0.48512486, 0.8308994, 0.272507, 1.0],
-    [0.55693376, 0.78426266, 0.27341703, 1.0],
-    [0.54454356, 0.8140701, 0.20189641, 1.0],
-    [0.5337014, 0.8137542, 0.23014523, 1.0],
-    [0.527209, 0.81132984, 0.2525759, 1.0],
-    [0.56188023, 0.78105664, 0.27247238, 1.0],
-    [0.48452505, 0.72805536, 0.48494425, 1.0],
-    [0.45626575, 0.7639655, 0.45626575, 1.0],
-    [0.47033703, 0.7465729, 0.4705444, 1.0],
-    [0.40082034, 0.72308517, 0.5625754, 1.0],
-    [0.381519, 0.6935598, 0.61107945, 1.0],
-    [0.37760967, 0.7571055, 0.5331062, 1.0],
-    [0.3297514, 0.7401758, 0.5860065, 1.0],
-    [0.45670962, 0.7066588, 0.5404161, 1.0],
-    [0.42670074, 0.6806116, 0.59556216, 1.0],
-    [0.4149094, 0.7621172, 0.4970186, 1.0],
-    [0.39791662, 0.7254023, 0.56165284, 1.0],
-    [0.36916485, 0.8157794, 0.4452204, 1.0],
-    [0.342485, 0.8434484, 0.4138826, 1.0],
-    [0.44483927, 0.8158109, 0.3695546, 1.0],
-    [0.42016873, 0.80410504, 0.42056328, 1.0],
-    [0.4138826, 0.8434484, 0.342485, 1.0],
-    [0.37805873, 0.8450699, 0.37805882, 1.0],
-    [0.3944195, 0.8298888, 0.3946107, 1.0],
-    [0.34111592, 0.7994916, 0.494422, 1.0],
-    [0.3118709, 0.7787459, 0.5443266, 1.0],
-    [0.30390683, 0.8381287, 0.45296925, 1.0],
-    [0.39382577, 0.7881822, 0.47293773, 1.0],
-    [0.3367208, 0.8033389, 0.49118823, 1.0],
-    [0.56255686, 0.7231499, 0.40072948, 1.0],
-    [0.5402059, 0.70671004, 0.45687917, 1.0],
-    [0.53288853, 0.7572305, 0.37766603, 1.0],
-    [0.49679714, 0.7622326, 0.41496262, 1.0],
-    [0.6110844, 0.6935842, 0.38146657, 1.0],
-    [0.59557426, 0.68065786, 0.4266101, 1.0],
-    [0.5860004, 0.740217, 0.32967013, 1.0],
-    [0.5615615, 0.72547567, 0.39791143, 1.0],
-    [0.51437867, 0.6858496, 0.5148057, 1.0],
-    [0.51437867, 0.6858496, 0.5148057, 1.0],
-    [0.49423653, 0.79952294, 0.3413112, 1.0],
-    [0.4725475, 0.7882146, 0.39422894, 1.0],
-    [0.4529692, 0.83812857, 0.30390686, 1.0],
-    [0.5440532, 0.7789195, 0.31191406, 1.0],
-    [0.4909807, 0.80340016, 0.33687687, 1.0],
-    [0.45227972, 0.76858294, 0.45246363, 1.0],
-    [0.40431887, 0.8742376, 0.26876533, 1.0],
-    [0.3849051, 0.8870955, 0.2547738, 1.0],
-    [0.3943949, 0.8809363, 0.26154107, 1.0],
-    [0.35885254, 0.9030068, 0.23622756, 1.0],
-    [0.33728272, 0.91497004, 0.22151817, 1.0],
-    [0.34758502, 0.90940905, 0.22838552, 1.0],
-    [0.44235337, 0.8607861, 0.2517357, 1.0],
-    [0.44030792, 0.87787056, 0.18834047, 1.0],
-    [0.4264723, 0.87843317, 0.21558404, 1.0],
-    [0.51398516, 0.8370984, 0.18731111, 1.0],
-    [0.48655307, 0.847604, 0.21173939, 1.0],
-    [0.4737308, 0.86327016, 0.17419435, 1.0],
-    [0.46156353, 0.8645157, 0.19892646, 1.0],
-    [0.46334425, 0.86264336, 0.20287547, 1.0],
-    [0.42249966, 0.88839036, 0.17960136, 1.0],
-    [0.3950755, 0.89583725, 0.20344773, 1.0],
-    [0.3982224, 0.90175223, 0.16811268, 1.0],
-    [0.37173828, 0.90853727, 0.19071063, 1.0],
-    [0.45314366, 0.8780499, 0.15391257, 1.0],
-    [0.43038735, 0.89143044, 0.14184013, 1.0],
-    [0.41157663, 0.89500654, 0.17195323, 1.0],
-    [0.40279037, 0.89000773, 0.21364981, 1.0],
-    [0.33848822, 0.8779814, 0.33848822, 1.0],
-    [0.31208053, 0.86995214, 0.38182342, 1.0],
-    [0.31923515, 0.89228797, 0.31923515, 1.0],
-    [0.28531772, 0.89092577, 0.35333422, 1.0],
-    [0.38182336, 0.869952, 0.31208062, 1.0],
-    [0.3582288, 0.86217415, 0.3582288, 1.0],
-    [0.35333425, 0.89092577, 0.2853177, 1.0],
-    [0.33476785, 0.88082975, 0.33476785, 1.0],
-    [0.28479683, 0.8581182, 0.42722827, 1.0],
-    [0.28479683, 0.8581182, 0.42722827, 1.0],
-    [0.29675534, 0.9076742, 0.29675534, 1.0],
-    [0.26503462, 0.90646785, 0.32874426, 1.0],
-    [0.27833468, 0.91927123, 0.27833468, 1.0],
-    [0.24841684, 0.9181249, 0.3087646, 1.0],
-    [0.32874426, 0.90646785, 0.26503462, 1.0],
-    [0.30876464, 0.91812503, 0.24841687, 1.0],
-    [0.2869855, 0.9139359, 0.2869855, 1.0],
-    [0.42722827, 0.8581182, 0.28479683, 1.0],
-    [0.42722827, 0.8581182, 0.28479683, 1.0],
-    [0.3304484, 0.8840858, 0.33044833, 1.0],
-    [0.3654667, 0.8560738, 0.36547455, 1.0],
-    [0.12339329, 0.6368381, 0.7610594, 1.0],
-    [0.12339318, 0.6368381, 0.76105946, 1.0],
-    [0.123263896, 0.7609163, 0.63703394, 1.0],
-    [0.123263784, 0.7609164, 0.63703394, 1.0],
-    [0.12285258, 0.7015802, 0.70192045, 1.0],
-    [0.11096034, 0.66955906, 0.7344239, 1.0],
-    [0.11095173, 0.7342384, 0.6697638, 1.0],
-    [0.08118463, 0.70477265, 0.70477265, 1.0],
-    [0.10537458, 0.7030798, 0.7032601, 1.0],
-    [0.21743038, 0.653212, 0.7252848, 1.0],
-    [0.21743038, 0.7252848, 0.653212, 1.0],
-    [0.20646994, 0.6918706, 0.6918708, 1.0],
-    [0.2956249, 0.67569995, 0.6753041, 1.0],
-    [0.2638515, 0.643243, 0.7187633, 1.0],
-    [0.26385456, 0.71894383, 0.6430402, 1.0],
-    [0.23272507, 0.68788, 0.68750274, 1.0],
-    [0.24125203, 0.68630195, 0.6861391, 1.0],
-    [0.20458664, 0.62689567, 0.7517621, 1.0],
-    [0.18526152, 0.58233285, 0.79155964, 1.0],
-    [0.17193963, 0.6611225, 0.7303107, 1.0],
-    [0.14254862, 0.6318944, 0.76183283, 1.0],
-    [0.22924702, 0.60130143, 0.76542956, 1.0],
-    [0.21647614, 0.5735499, 0.79004973, 1.0],
-    [0.1901128, 0.61376965, 0.7662531, 1.0],
-    [0.20458667, 0.75176203, 0.6268956, 1.0],
-    [0.17193957, 0.7303107, 0.6611226, 1.0],
-    [0.18526152, 0.79155964, 0.58233285, 1.0],
-    [0.14246441, 0.7617471, 0.63201684, 1.0],
-    [0.22924702, 0.76542956, 0.60130143, 1.0],
-    [0.21647614, 0.79004973, 0.5735499, 1.0],
-    [0.19009638, 0.7662393, 0.61379224, 1.0],
-    [0.14055744, 0.6999824, 0.7001916, 1.0],
-    [0.13364877, 0.6663049, 0.73360467, 1.0],
-    [0.13363488, 0.73328644, 0.66665787, 1.0],
-    [0.135459, 0.7004417, 0.70073694, 1.0],
-    [0.15302911, 0.6987268, 0.69882977, 1.0],
-    [0.12485059, 0.5655661, 0.81519765, 1.0],
-    [0.117791384, 0.54409087, 0.8307167, 1.0],
-    [0.114550576, 0.59878385, 0.7926765, 1.0],
-    [0.09667989, 0.5724896, 0.81419194, 1.0],
-    [0.11289688, 0.5701963, 0.8137141, 1.0],
-    [0.108247995, 0.5115423, 0.85241234, 1.0],
-    [0.101148486, 0.48424572, 0.86906564, 1.0],
-    [0.08407403, 0.5455516, 0.8338495, 1.0],
-    [0.0764069, 0.5205376, 0.8504132, 1.0],
-    [0.09157813, 0.51535857, 0.8520675, 1.0],
-    [0.055185, 0.61796445, 0.7842668, 1.0],
-    [0.053312957, 0.6784271, 0.73273087, 1.0],
-    [0.04865786, 0.645635, 0.7620942, 1.0],
-    [0.08491086, 0.64102554, 0.7628083, 1.0],
-    [0.06761456, 0.61404914, 0.7863662, 1.0],
-    [0.06371229, 0.676688, 0.73350805, 1.0],
-    [0.059486993, 0.6427228, 0.7637859, 1.0],
-    [0.060787503, 0.6454067, 0.7614165, 1.0],
-    [0.05147641, 0.59762776, 0.80011946, 1.0],
-    [0.04762923, 0.5687541, 0.8211275, 1.0],
-    [0.039065886, 0.62054175, 0.7831996, 1.0],
-    [0.03403493, 0.59517497, 0.802875, 1.0],
-    [0.06242309, 0.5791672, 0.8128153, 1.0],
-    [0.05812542, 0.550252, 0.8329731, 1.0],
-    [0.047797617, 0.5853472, 0.8093726, 1.0],
-    [0.1475492, 0.55766755, 0.8168452, 1.0],
-    [0.13616017, 0.5206409, 0.8428485, 1.0],
-    [0.13355741, 0.5922321, 0.79462165, 1.0],
-    [0.1384904, 0.5566001, 0.81915617, 1.0],
-    [0.12581037, 0.48840347, 0.86350083, 1.0],
-    [0.11783222, 0.4615016, 0.8792792, 1.0],
-    [0.12136442, 0.47454825, 0.8718226, 1.0],
-    [0.090298496, 0.55865866, 0.82446754, 1.0],
-    [0.12485059, 0.81519765, 0.5655661, 1.0],
-    [0.114550576, 0.7926765, 0.59878385, 1.0],
-    [0.117791444, 0.8307167, 0.5440909, 1.0],
-    [0.09667989, 0.81419194, 0.5724896, 1.0],
-    [0.11289688, 0.8137141, 0.5701963, 1.0],
-    [0.053312957, 0.73273087, 0.6784271, 1.0],
-    [0.055091545, 0.7841397, 0.61813426, 1.0],
-    [0.048607353, 0.7620159, 0.6457315, 1.0],
-    [0.084910855, 0.76280826, 0.6410255, 1.0],
-    [0.06371229, 0.73350805, 0.676688, 1.0],
-    [0.067552015, 0.78628916, 0.6141547, 1.0],
-    [0.05939322, 0.76365334, 0.6428887, 1.0],
-    [0.060744274, 0.7613556, 0.64548254, 1.0],
-    [0.108247995, 0.85241234, 0.5115423, 1.0],
-    [0.08407403, 0.8338495, 0.5455516, 1.0],
-    [0.10114854, 0.86906564, 0.48424572, 1.0],
-    [0.0764069, 0.8504132, 0.5205376, 1.0],
-    [0.09157819, 0.8520675, 0.51535857, 1.0],
-    [0.05147641, 0.80011946, 0.5976278, 1.0],
-    [0.039065886, 0.7831996, 0.62054175, 1.0],
-    [0.04762923, 0.8211275, 0.5687541, 1.0],
-    [0.03403493, 0.802875, 0.59517497, 1.0],
-    [0.062423196, 0.8128153, 0.5791672, 1.0],
-    [0.05812542, 0.8329731, 0.550252, 1.0],
-    [0.047797617, 0.8093726, 0.5853472, 1.0],
-    [0.1475492, 0.8168452, 0.55766755, 1.0],
-    [0.13355741, 0.79462165, 0.5922321, 1.0],
-    [0.13616015, 0.8428484, 0.52064085, 1.0],
-    [0.1384904, 0.81915617, 0.5566001, 1.0],
-    [0.12581037, 0.86350083, 0.48840347, 1.0],
-    [0.117832206, 0.8792791, 0.46150166, 1.0],
-    [0.12136431, 0.8718226, 0.47454825, 1.0],
-    [0.090288945, 0.8244586, 0.55867344, 1.0],
-    [0.04677043, 0.7063329, 0.7063329, 1.0],
-    [0.032270756, 0.67878556, 0.73362714, 1.0],
-    [0.032270703, 0.7336272, 0.6787855, 1.0],
-    [0.02083544, 0.7069533, 0.7069533, 1.0],
-    [0.058999106, 0.705875, 0.705875, 1.0],
-    [0.03650089, 0.70663553, 0.70663553, 1.0],
-    [0.02418796, 0.6525375, 0.7573702, 1.0],
-    [0.019931898, 0.6275789, 0.7782978, 1.0],
-    [0.0135893645, 0.6812146, 0.7319577, 1.0],
-    [0.010002464, 0.6565723, 0.75419676, 1.0],
-    [0.01602369, 0.6546407, 0.7557704, 1.0],
-    [0.02418796, 0.7573702, 0.6525375, 1.0],
-    [0.0135893645, 0.7319577, 0.6812146, 1.0],
-    [0.019931898, 0.7782978, 0.627579, 1.0],
-    [0.010002464, 0.75419676, 0.6565723, 1.0],
-    [0.01602369, 0.7557704, 0.6546407, 1.0],
-    [0.006872859, 0.70709014, 0.70709014, 1.0],
-    [0.003709647, 0.68294096, 0.73046416, 1.0],
-    [0.003709647, 0.73046416, 0.68294096, 1.0],
-    [0.00071448355, 0.70710665, 0.70710665, 1.0],
-    [0.0029598714, 0.7071037, 0.7071037, 1.0],
-    [0.01367236, 0.70704067, 0.70704067, 1.0],
-    [0.32888326, 0.6677708, 0.6677708, 1.0],
-    [0.31528807, 0.6305313, 0.7092416, 1.0],
-    [0.31528807, 0.7092416, 0.6305313, 1.0],
-    [0.31972748, 0.6699904, 0.6699904, 1.0],
-    [0.31972742, 0.6699904, 0.6699904, 1.0],
-    [0.09997201, 0.7035522, 0.7035765, 1.0],
-    [0.7458115, 0.2947216, 0.5974146, 1.0],
-    [0.7458115, 0.2947216, 0.5974146, 1.0],
-    [0.80657965, 0.29178533, 0.51409197, 1.0],
-    [0.78404814, 0.27348292, 0.5572033, 1.0],
-    [0.81403506, 0.20217916, 0.54449105, 1.0],
-    [0.8307906, 0.27261308, 0.48525155, 1.0],
-    [0.81363475, 0.23040622, 0.533771, 1.0],
-    [0.8111992, 0.2527559, 0.5273238, 1.0],
-    [0.78095317, 0.2726312, 0.561947, 1.0],
-    [0.7458733, 0.597401, 0.29459304, 1.0],
-    [0.7458733, 0.597401, 0.29459304, 1.0],
-    [0.8067495, 0.5139138, 0.29162943, 1.0],
-    [0.78426266, 0.5569339, 0.27341697, 1.0],
-    [0.8308994, 0.48512483, 0.27250704, 1.0],
-    [0.81407017, 0.54454356, 0.20189641, 1.0],
-    [0.8137542, 0.5337014, 0.23014523, 1.0],
-    [0.81132984, 0.527209, 0.2525759, 1.0],
-    [0.78105664, 0.5618802, 0.27247238, 1.0],
-    [0.72805536, 0.484525, 0.48494416, 1.0],
-    [0.7639655, 0.45626575, 0.45626575, 1.0],
-    [0.7465729, 0.47033703, 0.4705444, 1.0],
-    [0.72308517, 0.40082034, 0.5625754, 1.0],
-    [0.6935598, 0.381519, 0.61107945, 1.0],
-    [0.7066588, 0.45670962, 0.5404161, 1.0],
-    [0.68061167, 0.4267007, 0.5955621, 1.0],
-    [0.75710547, 0.3776096, 0.53310627, 1.0],
-    [0.74017596, 0.3297514, 0.5860065, 1.0],
-    [0.7621172, 0.4149094, 0.4970186, 1.0],
-    [0.72540224, 0.39791662, 0.56165284, 1.0],
-    [0.7231499, 0.56255674, 0.4007295, 1.0],
-    [0.70671004, 0.54020584, 0.45687917, 1.0],
-    [0.69358426, 0.61108434, 0.3814666, 1.0],
-    [0.68065786, 0.59557426, 0.4266101, 1.0],
-    [0.7572305, 0.53288853, 0.37766603, 1.0],
-    [0.76223266, 0.49679723, 0.41496262, 1.0],
-    [0.74021685, 0.5860004, 0.32967013, 1.0],
-    [0.7254757, 0.5615616, 0.39791137, 1.0],
-    [0.6858496, 0.51437867, 0.5148057, 1.0],
-    [0.6858496, 0.51437867, 0.5148057, 1.0],
-    [0.81577945, 0.3691649, 0.4452203, 1.0],
-    [0.8158109, 0.4448392, 0.36955467, 1.0],
-    [0.804105, 0.4201687, 0.4205633, 1.0],
-    [0.84344834, 0.34248507, 0.41388255, 1.0],
-    [0.8434484, 0.4138826, 0.342485, 1.0],
-    [0.8450699, 0.37805882, 0.37805873, 1.0],
-    [0.8298888, 0.39441955, 0.3946108, 1.0],
-    [0.7994917, 0.3411159, 0.49442202, 1.0],
-    [0.7787459, 0.3118709, 0.5443266, 1.0],
-    [0.7881822, 0.3938257, 0.47293776, 1.0],
-    [0.8381287, 0.30390683, 0.45296925, 1.0],
-    [0.8033389, 0.33672073, 0.4911883, 1.0],
-    [0.7995229, 0.49423644, 0.3413112, 1.0],
-    [0.7882146, 0.4725475, 0.39422894, 1.0],
-    [0.7789195, 0.5440532, 0.31191406, 1.0],
-    [0.83812857, 0.4529692, 0.3039068, 1.0],
-    [0.80340016, 0.4909807, 0.33687687, 1.0],
-    [0.76858294, 0.45227972, 0.45246363, 1.0],
-    [0.8742376, 0.26876542, 0.40431875, 1.0],
-    [0.8870955, 0.25477386, 0.38490516, 1.0],
-    [0.8809363, 0.26154113, 0.39439484, 1.0],
-    [0.83700615, 0.18739006, 0.51410663, 1.0],
-    [0.8607861, 0.2517358, 0.4423533, 1.0],
-    [0.84751457, 0.2118243, 0.48667186, 1.0],
-    [0.8778339, 0.18830772, 0.44039503, 1.0],
-    [0.8632449, 0.17417565, 0.473784, 1.0],
-    [0.87841195, 0.21556422, 0.42652598, 1.0],
-    [0.8644745, 0.19889483, 0.4616544, 1.0],
-    [0.8626021, 0.20288275, 0.4634179, 1.0],
-    [0.9030068, 0.2362275, 0.35885254, 1.0],
-    [0.91497004, 0.22151822, 0.33728278, 1.0],
-    [0.90940905, 0.22838552, 0.34758502, 1.0],
-    [0.8883903, 0.1796014, 0.4224997, 1.0],
-    [0.87804997, 0.15391253, 0.4531437, 1.0],
-    [0.89583725, 0.20344779, 0.39507553, 1.0],
-    [0.90175223, 0.16811268, 0.3982224, 1.0],
-    [0.8914303, 0.14184012, 0.43038735, 1.0],
-    [0.9085374, 0.19071066, 0.37173834, 1.0],
-    [0.89500666, 0.1719532, 0.4115766, 1.0],
-    [0.8899999, 0.2136507, 0.4028068, 1.0],
-    [0.8742376, 0.40431875, 0.26876542, 1.0],
-    [0.8870954, 0.38490513, 0.25477389, 1.0],
-    [0.8809363, 0.39439484, 0.26154113, 1.0],
-    [0.8607861, 0.4423533, 0.2517358, 1.0],
-    [0.8370984, 0.51398516, 0.18731111, 1.0],
-    [0.847604, 0.48655307, 0.21173939, 1.0],
-    [0.87787056, 0.44030792, 0.18834047, 1.0],
-    [0.87843317, 0.4264723, 0.21558404, 1.0],
-    [0.86327016, 0.4737308, 0.17419435, 1.0],
-    [0.8645157, 0.46156353, 0.19892646, 1.0],
-    [0.86264336, 0.46334425, 0.20287554, 1.0],
-    [0.9030068, 0.35885254, 0.2362275, 1.0],
-    [0.91497004, 0.33728272, 0.22151817, 1.0],
-    [0.90940905, 0.34758502, 0.22838552, 1.0],
-    [0.88839036, 0.42249972, 0.17960131, 1.0],
-    [0.89583725, 0.3950755, 0.20344773, 1.0],
-    [0.87804997, 0.4531437, 0.15391253, 1.0],
-    [0.90175223, 0.3982224, 0.16811268, 1.0],
-    [0.9085374, 0.37173828, 0.1907106, 1.0],
-    [0.8914303, 0.43038735, 0.14184012, 1.0],
-    [0.89500654, 0.41157663, 0.17195323, 1.0],
-    [0.89000773, 0.40279037, 0.21364981, 1.0],
-    [0.8779814, 0.33848822, 0.33848822, 1.0],
-    [0.86995214, 0.31208053, 0.38182342, 1.0],
-    [0.869952, 0.38182336, 0.31208062, 1.0],
-    [0.86217415, 0.3582288, 0.3582288, 1.0],
-    [0.89228797, 0.31923515, 0.31923515, 1.0],
-    [0.89092577, 0.2853177, 0.35333416, 1.0],
-    [0.89092577, 0.35333425, 0.2853177, 1.0],
-    [0.88082975, 0.33476785, 0.33476785, 1.0],
-    [0.8581182, 0.2847968, 0.42722824, 1.0],
-    [0.8581182, 0.2847968, 0.42722824, 1.0],
-    [0.8581182, 0.42722824, 0.2847968, 1.0],
-    [0.8581182, 0.42722824, 0.2847968, 1.0],
-    [0.9076743, 0.2967553, 0.29675537, 1.0],
-    [0.90646785, 0.26503462, 0.32874426, 1.0],
-    [0.90646785, 0.32874426, 0.26503462, 1.0],
-    [0.91927123, 0.27833468, 0.27833468, 1.0],
-    [0.9181249, 0.24841684, 0.3087646, 1.0],
-    [0.91812503, 0.30876464, 0.24841687, 1.0],
-    [0.9139359, 0.2869855, 0.2869855, 1.0],
-    [0.8840858, 0.3304484, 0.33044833, 1.0],
-    [0.85607386, 0.36546665, 0.36547464, 1.0],
-    [0.63683796, 0.123393275, 0.7610594, 1.0],
-    [0.636838, 0.12339317, 0.7610594, 1.0],
-    [0.653212, 0.21743038, 0.7252848, 1.0],
-    [0.6756999, 0.29562503, 0.67530406, 1.0],
-    [0.643243, 0.2638515, 0.7187633, 1.0],
-    [0.7252848, 0.21743038, 0.653212, 1.0],
-    [0.6918706, 0.20646994, 0.6918708, 1.0],
-    [0.71894383, 0.26385456, 0.6430402, 1.0],
-    [0.68788, 0.23272502, 0.6875028, 1.0],
-    [0.6863021, 0.24125211, 0.68613917, 1.0],
-    [0.6268956, 0.20458667, 0.75176203, 1.0],
-    [0.5823329, 0.18526152, 0.7915597, 1.0],
-    [0.60130143, 0.22924702, 0.76542956, 1.0],
-    [0.5735499, 0.21647614, 0.79004973, 1.0],
-    [0.6611226, 0.17193957, 0.7303107, 1.0],
-    [0.6318944, 0.14254862, 0.76183283, 1.0],
-    [0.6137697, 0.1901128, 0.76625305, 1.0],
-    [0.7609164, 0.123263784, 0.63703394, 1.0],
-    [0.7609164, 0.123263784, 0.63703394, 1.0],
-    [0.70158017, 0.12285259, 0.70192045, 1.0],
-    [0.669559, 0.11096034, 0.7344239, 1.0],
-    [0.7342384, 0.11095173, 0.6697638, 1.0],
-    [0.70477265, 0.08118463, 0.70477265, 1.0],
-    [0.7030799, 0.1053746, 0.7032602, 1.0],
-    [0.75176203, 0.20458667, 0.6268956, 1.0],
-    [0.73031074, 0.17193958, 0.6611226, 1.0],
-    [0.76542956, 0.22924702, 0.60130143, 1.0],
-    [0.79155964, 0.18526152, 0.58233285, 1.0],
-    [0.7617471, 0.14246441, 0.63201684, 1.0],
-    [0.79004973, 0.21647614, 0.5735499, 1.0],
-    [0.7662393, 0.19009638, 0.61379224, 1.0],
-    [0.6999824, 0.14055744, 0.7001916, 1.0],
-    [0.6663049, 0.13364889, 0.73360467, 1.0],
-    [0.73328644, 0.13363488, 0.66665787, 1.0],
-    [0.7004417, 0.135459, 0.70073694, 1.0],
-    [0.6987268, 0.15302917, 0.6988297, 1.0],
-    [0.5655661, 0.12485059, 0.81519765, 1.0],
-    [0.5440909, 0.11779139, 0.8307167, 1.0],
-    [0.59878385, 0.114550576, 0.7926765, 1.0],
-    [0.5724896, 0.09667983, 0.81419194, 1.0],
-    [0.5701963, 0.11289688, 0.8137141, 1.0],
-    [0.5115423, 0.108247995, 0.85241234, 1.0],
-    [0.48424572, 0.10114854, 0.86906564, 1.0],
-    [0.54555166, 0.08407413, 0.8338495, 1.0],
-    [0.52053756, 0.0764069, 0.8504132, 1.0],
-    [0.51535857, 0.091578186, 0.8520675, 1.0],
-    [0.55766755, 0.1475492, 0.8168452, 1.0],
-    [0.5206409, 0.13616017, 0.8428485, 1.0],
-    [0.5922321, 0.13355748, 0.79462165, 1.0],
-    [0.5566001, 0.1384904, 0.81915617, 1.0],
-    [0.48840353, 0.12581049, 0.86350083, 1.0],
-    [0.46150166, 0.117832206, 0.8792791, 1.0],
-    [0.47454825, 0.12136431, 0.8718226, 1.0],
-    [0.61796445, 0.055185, 0.7842668, 1.0],
-    [0.6410255, 0.084910855, 0.76280826, 1.0],
-    [0.6140492, 0.06761456, 0.7863662, 1.0],
-    [0.6784271, 0.053312957, 0.73273087, 1.0],
-    [0.645635, 0.04865786, 0.7620942, 1.0],
-    [0.676688, 0.06371229, 0.73350805, 1.0],
-    [0.6427228, 0.059486993, 0.7637859, 1.0],
-    [0.6454067, 0.060787447, 0.76141655, 1.0],
-    [0.5976278, 0.05147641, 0.80011946, 1.0],
-    [0.56875414, 0.04762923, 0.8211275, 1.0],
-    [0.5791671, 0.062423144, 0.8128153, 1.0],
-    [0.550252, 0.058125414, 0.832973, 1.0],
-    [0.62054175, 0.039065834, 0.7831996, 1.0],
-    [0.595175, 0.03403493, 0.802875, 1.0],
-    [0.5853472, 0.047797617, 0.8093726, 1.0],
-    [0.55865866, 0.090298496, 0.82446754, 1.0],
-    [0.6677708, 0.32888326, 0.6677708, 1.0],
-    [0.6305313, 0.31528807, 0.7092416, 1.0],
-    [0.7092416, 0.31528807, 0.6305313, 1.0],
-    [0.6699904, 0.31972748, 0.6699904, 1.0],
-    [0.6699904, 0.31972742, 0.6699904, 1.0],
-    [0.81519765, 0.12485059, 0.5655661, 1.0],
-    [0.7926765, 0.114550576, 0.59878385, 1.0],
-    [0.8307167, 0.11779139, 0.5440909, 1.0],
-    [0.81419194, 0.09667989, 0.5724896, 1.0],
-    [0.8137141, 0.11289688, 0.5701963, 1.0],
-    [0.73273087, 0.053312957, 0.6784271, 1.0],
-    [0.7628082, 0.084910855, 0.6410255, 1.0],
-    [0.73350805, 0.06371229, 0.676688, 1.0],
-    [0.7841397, 0.055091545, 0.61813426, 1.0],
-    [0.7620159, 0.048607353, 0.6457315, 1.0],
-    [0.7862892, 0.067552015, 0.6141547, 1.0],
-    [0.76365334, 0.05939322, 0.6428887, 1.0],
-    [0.7613556, 0.060744274, 0.64548254, 1.0],
-    [0.8168452, 0.1475492, 0.55766755, 1.0],
-    [0.79462165, 0.13355748, 0.5922321, 1.0],
-    [0.8428484, 0.13616015, 0.52064085, 1.0],
-    [0.81915617, 0.1384904, 0.5566001, 1.0],
-    [0.85241234, 0.108247995, 0.5115423, 1.0],
-    [0.8338495, 0.08407403, 0.5455516, 1.0],
-    [0.86906564, 0.101148486, 0.48424572, 1.0],
-    [0.8504132, 0.0764069, 0.52053756, 1.0],
-    [0.8520675, 0.09157813, 0.5153585, 1.0],
-    [0.80011946, 0.05147641, 0.5976278, 1.0],
-    [0.7831996, 0.039065834, 0.62054175, 1.0],
-    [0.8128153, 0.062423144, 0.5791671, 1.0],
-    [0.8211275, 0.04762923, 0.5687541, 1.0],
-    [0.802875, 0.03403498, 0.595175, 1.0],
-    [0.832973, 0.058125414, 0.550252, 1.0],
-    [0.8093726, 0.047797617, 0.5853472, 1.0],
-    [0.86350083, 0.12581049, 0.48840353, 1.0],
-    [0.8792791, 0.117832206, 0.46150166, 1.0],
-    [0.8718226, 0.12136431, 0.47454825, 1.0],
-    [0.8244586, 0.090288945, 0.55867344, 1.0],
-    [0.7063329, 0.04677043, 0.7063329, 1.0],
-    [0.6787855, 0.032270703, 0.7336272, 1.0],
-    [0.705875, 0.058999162, 0.705875, 1.0],
-    [0.73362714, 0.032270756, 0.67878556, 1.0],
-    [0.7069533, 0.02083544, 0.7069533, 1.0],
-    [0.70663553, 0.036500886, 0.70663553, 1.0],
-    [0.6525375, 0.02418801, 0.7573702, 1.0],
-    [0.627579, 0.019931946, 0.7782978, 1.0],
-    [0.6812146, 0.0135893645, 0.7319577, 1.0],
-    [0.6565723, 0.010002511, 0.75419676, 1.0],
-    [0.6546407, 0.016023738, 0.7557704, 1.0],
-    [0.7573702, 0.02418796, 0.6525375, 1.0],
-    [0.7319577, 0.0135893645, 0.6812146, 1.0],
-    [0.7782978, 0.019931946, 0.6275789, 1.0],
-    [0.75419676, 0.010002511, 0.6565723, 1.0],
-    [0.7557703, 0.01602369, 0.6546407, 1.0],
-    [0.70709014, 0.006872859, 0.70709014, 1.0],
-    [0.68294096, 0.0037096022, 0.73046416, 1.0],
-    [0.73046416, 0.003709647, 0.68294096, 1.0],
-    [0.70710665, 0.00071448355, 0.70710665, 1.0],
-    [0.7071037, 0.0029598714, 0.7071037, 1.0],
-    [0.70704067, 0.013672312, 0.70704067, 1.0],
-    [0.7035522, 0.09997201, 0.7035765, 1.0],
-    [0.67546326, 0.67546326, 0.29580197, 1.0],
-    [0.65308005, 0.7254957, 0.21712326, 1.0],
-    [0.6431775, 0.7188836, 0.2636836, 1.0],
-    [0.7254957, 0.65308, 0.21712321, 1.0],
-    [0.7188836, 0.6431775, 0.26368356, 1.0],
-    [0.6918981, 0.691898, 0.20628662, 1.0],
-    [0.6877204, 0.68772054, 0.23255351, 1.0],
-    [0.68625087, 0.68625087, 0.24107996, 1.0],
-    [0.6368335, 0.76106334, 0.12339282, 1.0],
-    [0.6368334, 0.76106334, 0.123392925, 1.0],
-    [0.62676764, 0.75189734, 0.20448186, 1.0],
-    [0.60117334, 0.76556325, 0.22913615, 1.0],
-    [0.5824621, 0.79148597, 0.18516979, 1.0],
-    [0.5737758, 0.7899308, 0.21631141, 1.0],
-    [0.6611226, 0.7303107, 0.17193957, 1.0],
-    [0.63189155, 0.7618353, 0.1425485, 1.0],
-    [0.61378306, 0.76626056, 0.19003958, 1.0],
-    [0.76106334, 0.6368334, 0.123392865, 1.0],
-    [0.76106334, 0.6368334, 0.123392865, 1.0],
-    [0.75189734, 0.62676764, 0.20448186, 1.0],
-    [0.76556325, 0.60117334, 0.22913615, 1.0],
-    [0.73031074, 0.6611226, 0.17193958, 1.0],
-    [0.7914859, 0.5824621, 0.18516977, 1.0],
-    [0.7899308, 0.5737758, 0.21631141, 1.0],
-    [0.7618353, 0.63189155, 0.1425485, 1.0],
-    [0.76626056, 0.61378306, 0.19003958, 1.0],
-    [0.70174, 0.70174, 0.12297055, 1.0],
-    [0.6697511, 0.7342301, 0.11108377, 1.0],
-    [0.7342301, 0.6697511, 0.11108377, 1.0],
-    [0.70477265, 0.70477265, 0.08118463, 1.0],
-    [0.7031632, 0.7031632, 0.10546568, 1.0],
-    [0.70007926, 0.70007926, 0.14063446, 1.0],
-    [0.6666309, 0.73326576, 0.13388227, 1.0],
-    [0.73326576, 0.666631, 0.13388228, 1.0],
-    [0.70057076, 0.70057076, 0.1356514, 1.0],
-    [0.69877493, 0.69877493, 0.15305912, 1.0],
-    [0.66777086, 0.66777086, 0.3288833, 1.0],
-    [0.6307803, 0.7090981, 0.31511313, 1.0],
-    [0.7090981, 0.6307803, 0.31511313, 1.0],
-    [0.67001843, 0.67001843, 0.3196101, 1.0],
-    [0.67001843, 0.67001843, 0.3196101, 1.0],
-    [0.5655661, 0.81519765, 0.12485059, 1.0],
-    [0.5440909, 0.8307167, 0.11779139, 1.0],
-    [0.5986886, 0.79275596, 0.11449907, 1.0],
-    [0.5724896, 0.81419194, 0.09667983, 1.0],
-    [0.57017344, 0.8137316, 0.112885594, 1.0],
-    [0.55766755, 0.81684524, 0.1475492, 1.0],
-    [0.5206409, 0.8428485, 0.13616017, 1.0],
-    [0.59207016, 0.7947587, 0.13346009, 1.0],
-    [0.5565481, 0.8191964, 0.13846031, 1.0],
-    [0.5115423, 0.85241234, 0.108247995, 1.0],
-    [0.48424572, 0.86906564, 0.10114854, 1.0],
-    [0.5455516, 0.8338495, 0.08407403, 1.0],
-    [0.52053756, 0.8504132, 0.0764069, 1.0],
-    [0.5153585, 0.8520675, 0.09157813, 1.0],
-    [0.48840347, 0.86350083, 0.12581037, 1.0],
-    [0.46150166, 0.8792791, 0.117832206, 1.0],
-    [0.47454825, 0.8718226, 0.12136431, 1.0],
-    [0.6410255, 0.76280826, 0.084910855, 1.0],
-    [0.61813426, 0.7841397, 0.055091545, 1.0],
-    [0.6141547, 0.78628916, 0.067552015, 1.0],
-    [0.6784271, 0.73273087, 0.053312957, 1.0],
-    [0.676688, 0.73350805, 0.06371229, 1.0],
-    [0.6457315, 0.7620159, 0.048607353, 1.0],
-    [0.6428887, 0.76365334, 0.05939322, 1.0],
-    [0.64548254, 0.7613556, 0.06074433, 1.0],
-    [0.59762776, 0.80011946, 0.05147641, 1.0],
-    [0.5791672, 0.8128153, 0.062423144, 1.0],
-    [0.5687541, 0.8211275, 0.04762923, 1.0],
-    [0.550252, 0.8329731, 0.05812542, 1.0],
-    [0.62054175, 0.7831996, 0.039065834, 1.0],
-    [0.595175, 0.802875, 0.03403493, 1.0],
-    [0.5853472, 0.8093726, 0.047797617, 1.0],
-    [0.5586619, 0.8244669, 0.09028466, 1.0],
-    [0.81519765, 0.5655661, 0.12485059, 1.0],
-    [0.79275596, 0.5986886, 0.11449907, 1.0],
-    [0.8307167, 0.5440909, 0.11779139, 1.0],
-    [0.81419194, 0.5724896, 0.09667989, 1.0],
-    [0.8137316, 0.57017344, 0.112885594, 1.0],
-    [0.8168452, 0.55766755, 0.1475492, 1.0],
-    [0.7947586, 0.59207016, 0.13346009, 1.0],
-    [0.8428485, 0.5206409, 0.13616017, 1.0],
-    [0.8191964, 0.5565481, 0.13846031, 1.0],
-    [0.76280826, 0.6410255, 0.084910974, 1.0],
-    [0.73273087, 0.6784271, 0.053312957, 1.0],
-    [0.73350805, 0.676688, 0.06371229, 1.0],
-    [0.7841397, 0.61813426, 0.055091545, 1.0],
-    [0.7862892, 0.6141547, 0.067552015, 1.0],
-    [0.7620159, 0.64573157, 0.04860741, 1.0],
-    [0.76365334, 0.6428887, 0.05939322, 1.0],
-    [0.7613556, 0.64548254, 0.060744274, 1.0],
-    [0.85241234, 0.5115423, 0.108247936, 1.0],
-    [0.8338495, 0.5455516, 0.08407403, 1.0],
-    [0.86906564, 0.48424572, 0.10114854, 1.0],
-    [0.8504132, 0.52053756, 0.0764069, 1.0],
-    [0.8520675, 0.5153585, 0.09157813, 1.0],
-    [0.86350083, 0.48840347, 0.12581037, 1.0],
-    [0.8792792, 0.4615016, 0.11783222, 1.0],
-    [0.8718226, 0.47454825, 0.12136431, 1.0],
-    [0.80011946, 0.5976278, 0.05147641, 1.0],
-    [0.8128153, 0.5791672, 0.06242309, 1.0],
-    [0.7831996, 0.62054175, 0.039065834, 1.0],
-    [0.8211275, 0.5687541, 0.04762923, 1.0],
-    [0.8329731, 0.550252, 0.05812542, 1.0],
-    [0.802875, 0.595175, 0.03403498, 1.0],
-    [0.8093726, 0.58534724, 0.047797572, 1.0],
-    [0.8244668, 0.5586619, 0.0902846, 1.0],
-    [0.7063329, 0.7063329, 0.04677043, 1.0],
-    [0.705875, 0.705875, 0.058999106, 1.0],
-    [0.6787855, 0.7336272, 0.032270703, 1.0],
-    [0.73362714, 0.67878556, 0.032270756, 1.0],
-    [0.7069533, 0.7069533, 0.02083544, 1.0],
-    [0.70663553, 0.70663553, 0.03650089, 1.0],
-    [0.6525375, 0.7573702, 0.02418801, 1.0],
-    [0.627579, 0.7782978, 0.019931946, 1.0],
-    [0.6812146, 0.7319577, 0.0135893645, 1.0],
-    [0.6565723, 0.75419676, 0.0100024175, 1.0],
-    [0.6546407, 0.7557704, 0.016023738, 1.0],
-    [0.7573702, 0.6525375, 0.02418796, 1.0],
-    [0.7319577, 0.6812146, 0.0135893645, 1.0],
-    [0.7782978, 0.6275789, 0.019931946, 1.0],
-    [0.75419676, 0.6565723, 0.0100024175, 1.0],
-    [0.7557704, 0.65464073, 0.016023738, 1.0],
-    [0.70709014, 0.70709014, 0.006872859, 1.0],
-    [0.68294096, 0.73046416, 0.0037096022, 1.0],
-    [0.73046416, 0.68294096, 0.003709647, 1.0],
-    [0.70710665, 0.70710665, 0.00071448355, 1.0],
-    [0.7071037, 0.7071037, 0.0029598714, 1.0],
-    [0.70704067, 0.70704067, 0.013672312, 1.0],
-    [0.70356524, 0.70356524, 0.09995973, 1.0],
-    [0.5773541, 0.57735413, 0.57734257, 1.0],
-    [0.21019977, 0.32004932, 0.92378813, 1.0],
-    [0.20173608, 0.30645669, 0.9302617, 1.0],
-    [0.20556502, 0.312854, 0.92728925, 1.0],
-    [0.19598857, 0.2960633, 0.9348448, 1.0],
-    [0.19378959, 0.29082406, 0.9369456, 1.0],
-    [0.19475234, 0.29330638, 0.9359716, 1.0],
-    [0.15962347, 0.3781133, 0.9118941, 1.0],
-    [0.15391189, 0.36206713, 0.9193576, 1.0],
-    [0.1348231, 0.40909556, 0.9024763, 1.0],
-    [0.13229431, 0.38949615, 0.9114773, 1.0],
-    [0.18101604, 0.35284138, 0.9180066, 1.0],
-    [0.17402215, 0.33793387, 0.92494166, 1.0],
-    [0.15511057, 0.371263, 0.9154805, 1.0],
-    [0.15164334, 0.35373774, 0.9229702, 1.0],
-    [0.13579296, 0.3688044, 0.91953444, 1.0],
-    [0.1322261, 0.3627637, 0.9224526, 1.0],
-    [0.16696984, 0.32985783, 0.92914736, 1.0],
-    [0.17572513, 0.30834964, 0.93490165, 1.0],
-    [0.14980204, 0.34683213, 0.9258871, 1.0],
-    [0.14858538, 0.3413788, 0.92810714, 1.0],
-    [0.15086532, 0.34413028, 0.92672217, 1.0],
-    [0.17466417, 0.32908222, 0.9280072, 1.0],
-    [0.13283746, 0.3577461, 0.9243225, 1.0],
-    [0.17224379, 0.30659404, 0.9361262, 1.0],
-    [0.17282896, 0.30477145, 0.9366134, 1.0],
-    [0.14628604, 0.3388938, 0.9293822, 1.0],
-    [0.15849185, 0.32291847, 0.93306166, 1.0],
-    [0.1561121, 0.32575586, 0.93247634, 1.0],
-    [0.15999013, 0.32403478, 0.9324187, 1.0],
-    [0.15999013, 0.32403478, 0.9324187, 1.0],
-    [0.1574334, 0.32438886, 0.93273073, 1.0],
-    [0.32004926, 0.21019971, 0.92378813, 1.0],
-    [0.30645669, 0.20173608, 0.9302617, 1.0],
-    [0.312854, 0.20556502, 0.92728925, 1.0],
-    [0.2960633, 0.19598857, 0.9348448, 1.0],
-    [0.29082406, 0.19378959, 0.9369456, 1.0],
-    [0.29330638, 0.19475234, 0.9359716, 1.0],
-    [0.3781133, 0.15962347, 0.9118941, 1.0],
-    [0.36206713, 0.153912, 0.9193576, 1.0],
-    [0.35284144, 0.18101604, 0.9180066, 1.0],
-    [0.33793387, 0.17402215, 0.92494166, 1.0],
-    [0.40909556, 0.1348231, 0.9024763, 1.0],
-    [0.38949615, 0.13229437, 0.9114773, 1.0],
-    [0.371263, 0.15511051, 0.9154805, 1.0],
-    [0.35373774, 0.15164334, 0.9229702, 1.0],
-    [0.32985777, 0.16696978, 0.92914736, 1.0],
-    [0.30834964, 0.17572513, 0.93490165, 1.0],
-    [0.36880445, 0.13579296, 0.91953444, 1.0],
-    [0.3627637, 0.1322261, 0.9224526, 1.0],
-    [0.34683213, 0.14980204, 0.9258871, 1.0],
-    [0.3413788, 0.14858538, 0.92810714, 1.0],
-    [0.34413028, 0.15086527, 0.92672217, 1.0],
-    [0.32908222, 0.17466417, 0.9280072, 1.0],
-    [0.30659404, 0.17224374, 0.9361262, 1.0],
-    [0.30477142, 0.17282893, 0.9366133, 1.0],
-    [0.3577461, 0.13283746, 0.9243225, 1.0],
-    [0.3388938, 0.14628604, 0.9293822, 1.0],
-    [0.32291844, 0.15849185, 0.93306166, 1.0],
-    [0.32575586, 0.1561121, 0.93247634, 1.0],
-    [0.32403487, 0.15999013, 0.9324187, 1.0],
-    [0.32403484, 0.15999006, 0.9324187, 1.0],
-    [0.32438883, 0.15743342, 0.9327307, 1.0],
-    [0.2637718, 0.2637718, 0.9278195, 1.0],
-    [0.25264642, 0.25264636, 0.93399113, 1.0],
-    [0.23555031, 0.2927724, 0.9267148, 1.0],
-    [0.22591667, 0.28022295, 0.932972, 1.0],
-    [0.2927724, 0.23555031, 0.9267148, 1.0],
-    [0.28022307, 0.22591667, 0.932972, 1.0],
-    [0.2578345, 0.2578345, 0.9311513, 1.0],
-    [0.24464191, 0.24464191, 0.9382434, 1.0],
-    [0.24051666, 0.24051666, 0.9403741, 1.0],
-    [0.21893346, 0.27111557, 0.9373176, 1.0],
-    [0.21564065, 0.2635245, 0.9402414, 1.0],
-    [0.27111557, 0.21893351, 0.9373176, 1.0],
-    [0.2635245, 0.21564065, 0.9402414, 1.0],
-    [0.23791105, 0.23791105, 0.9416989, 1.0],
-    [0.2411605, 0.2411605, 0.9400443, 1.0],
-    [0.19192997, 0.2872581, 0.9384272, 1.0],
-    [0.19192989, 0.28725803, 0.9384272, 1.0],
-    [0.28725806, 0.19192989, 0.9384272, 1.0],
-    [0.28725803, 0.19192985, 0.9384272, 1.0],
-    [0.24199852, 0.24199846, 0.93961346, 1.0],
-    [0.21440826, 0.2590928, 0.9417537, 1.0],
-    [0.21411398, 0.2579715, 0.94212836, 1.0],
-    [0.2590928, 0.21440826, 0.9417537, 1.0],
-    [0.25797153, 0.21411392, 0.9421284, 1.0],
-    [0.2364318, 0.23643185, 0.94244367, 1.0],
-    [0.235801, 0.235801, 0.9427597, 1.0],
-    [0.2358425, 0.2358425, 0.94273895, 1.0],
-    [0.23710772, 0.23710772, 0.94210404, 1.0],
-    [0.23902984, 0.23902984, 0.9411319, 1.0],
-    [0.23797417, 0.23797417, 0.941667, 1.0],
-    [0.19103216, 0.28470752, 0.93938714, 1.0],
-    [0.19364691, 0.28064007, 0.9400756, 1.0],
-    [0.17424159, 0.30405957, 0.9365829, 1.0],
-    [0.21498938, 0.25793126, 0.94194, 1.0],
-    [0.19292995, 0.28134173, 0.9400133, 1.0],
-    [0.1977922, 0.27902997, 0.9396917, 1.0],
-    [0.20001124, 0.28049326, 0.93878603, 1.0],
-    [0.17360774, 0.30833125, 0.9353033, 1.0],
-    [0.18682602, 0.29923952, 0.93570924, 1.0],
-    [0.21615623, 0.2587682, 0.9414433, 1.0],
-    [0.22051886, 0.2607267, 0.93988997, 1.0],
-    [0.20293921, 0.28324983, 0.9373288, 1.0],
-    [0.19922496, 0.28105906, 0.93878394, 1.0],
-    [0.28470752, 0.19103216, 0.93938714, 1.0],
-    [0.28064004, 0.19364691, 0.9400756, 1.0],
-    [0.25793126, 0.21498938, 0.94194, 1.0],
-    [0.30405957, 0.17424159, 0.9365829, 1.0],
-    [0.28134173, 0.19292995, 0.9400133, 1.0],
-    [0.27902997, 0.1977922, 0.9396917, 1.0],
-    [0.28049326, 0.20001124, 0.93878603, 1.0],
-    [0.2587682, 0.21615623, 0.9414433, 1.0],
-    [0.26072666, 0.22051883, 0.93988997, 1.0],
-    [0.30833125, 0.17360774, 0.9353033, 1.0],
-    [0.29923952, 0.18682602, 0.93570924, 1.0],
-    [0.28324983, 0.20293921, 0.9373288, 1.0],
-    [0.28105906, 0.19922496, 0.93878394, 1.0],
-    [0.24182841, 0.24182841, 0.9397011, 1.0],
-    [0.24182841, 0.24182841, 0.9397011, 1.0],
-    [0.23690167, 0.23690167, 0.9422077, 1.0],
-    [0.23725349, 0.23725344, 0.9420306, 1.0],
-    [0.18914212, 0.30393538, 0.9337284, 1.0],
-    [0.22522892, 0.2632254, 0.93807477, 1.0],
-    [0.20895118, 0.2852015, 0.9354142, 1.0],
-    [0.21536912, 0.28799915, 0.9330984, 1.0],
-    [0.20935464, 0.28483585, 0.93543524, 1.0],
-    [0.2632254, 0.22522892, 0.93807477, 1.0],
-    [0.30393538, 0.18914212, 0.9337284, 1.0],
-    [0.28520152, 0.20895118, 0.9354142, 1.0],
-    [0.28799915, 0.21536912, 0.9330984, 1.0],
-    [0.28483585, 0.20935456, 0.9354353, 1.0],
-    [0.24589363, 0.24589363, 0.9375888, 1.0],
-    [0.25056282, 0.25056282, 0.93511313, 1.0],
-    [0.22948906, 0.2673509, 0.93587303, 1.0],
-    [0.23518516, 0.27167878, 0.93320876, 1.0],
-    [0.2673509, 0.2294891, 0.93587303, 1.0],
-    [0.2716788, 0.23518516, 0.93320876, 1.0],
-    [0.25591713, 0.25591713, 0.9322086, 1.0],
-    [0.2505577, 0.2505577, 0.9351158, 1.0],
-    [0.24138236, 0.2764108, 0.930232, 1.0],
-    [0.2764108, 0.24138236, 0.930232, 1.0],
-    [0.26184362, 0.26184362, 0.9289111, 1.0],
-    [0.26818883, 0.26818883, 0.92528343, 1.0],
-    [0.26172706, 0.26172706, 0.92897683, 1.0],
-    [0.25055787, 0.25055787, 0.9351157, 1.0],
-    [0.25055787, 0.25055787, 0.9351157, 1.0],
-    [0.09641152, 0.46156737, 0.8818505, 1.0],
-    [0.09379048, 0.4432214, 0.89149207, 1.0],
-    [0.07299513, 0.49729413, 0.86450577, 1.0],
-    [0.073260225, 0.4753815, 0.87672424, 1.0],
-    [0.08337323, 0.46918496, 0.8791555, 1.0],
-    [0.09289803, 0.42910588, 0.8984643, 1.0],
-    [0.093256764, 0.42244786, 0.9015769, 1.0],
-    [0.07680983, 0.45482916, 0.8872602, 1.0],
-    [0.08338415, 0.43586934, 0.89613897, 1.0],
-    [0.086213216, 0.4354279, 0.8960858, 1.0],
-    [0.045623343, 0.54480124, 0.8373231, 1.0],
-    [0.04527973, 0.5256859, 0.8494728, 1.0],
-    [0.032659788, 0.5711268, 0.82021177, 1.0],
-    [0.034523826, 0.54839414, 0.835507, 1.0],
-    [0.055610795, 0.526447, 0.84838736, 1.0],
-    [0.05470729, 0.5075784, 0.859867, 1.0],
-    [0.04387656, 0.5373285, 0.842231, 1.0],
-    [0.04604639, 0.5156298, 0.85557324, 1.0],
-    [0.03965679, 0.52620095, 0.84943503, 1.0],
-    [0.04089067, 0.5203489, 0.8529742, 1.0],
-    [0.054514717, 0.49508142, 0.86713463, 1.0],
-    [0.06265161, 0.4729817, 0.8788419, 1.0],
-    [0.04701031, 0.5079194, 0.8601209, 1.0],
-    [0.047522414, 0.5047354, 0.861965, 1.0],
-    [0.047658034, 0.5060056, 0.8612125, 1.0],
-    [0.11215205, 0.43925464, 0.8913346, 1.0],
-    [0.10861524, 0.42165074, 0.90022963, 1.0],
-    [0.109997064, 0.43010062, 0.89605474, 1.0],
-    [0.10680095, 0.40837893, 0.906543, 1.0],
-    [0.105921336, 0.40033352, 0.91022736, 1.0],
-    [0.094032355, 0.41696662, 0.9040447, 1.0],
-    [0.10161437, 0.40839344, 0.9071325, 1.0],
-    [0.07501303, 0.46409053, 0.8826058, 1.0],
-    [0.08385164, 0.4327619, 0.8976001, 1.0],
-    [0.08385156, 0.43276188, 0.8976001, 1.0],
-    [0.074592374, 0.4501067, 0.88985384, 1.0],
-    [0.0782707, 0.44698423, 0.89111096, 1.0],
-    [0.056232013, 0.48815626, 0.8709429, 1.0],
-    [0.07046729, 0.46632704, 0.8818012, 1.0],
-    [0.069362566, 0.4628006, 0.88374454, 1.0],
-    [0.0752797, 0.4647071, 0.8822587, 1.0],
-    [0.0752797, 0.4647071, 0.8822587, 1.0],
-    [0.11955067, 0.3757838, 0.9189636, 1.0],
-    [0.121903524, 0.37353745, 0.91957027, 1.0],
-    [0.093809724, 0.41584298, 0.9045852, 1.0],
-    [0.10434701, 0.4012233, 0.91001743, 1.0],
-    [0.13399261, 0.3550112, 0.9252097, 1.0],
-    [0.13509038, 0.3543341, 0.9253096, 1.0],
-    [0.11742648, 0.37910554, 0.91787255, 1.0],
-    [0.124159, 0.37300286, 0.91948545, 1.0],
-    [0.116082475, 0.38898677, 0.9139005, 1.0],
-    [0.11785851, 0.39314184, 0.91189295, 1.0],
-    [0.142992, 0.34859994, 0.9262998, 1.0],
-    [0.15091307, 0.34493676, 0.92641455, 1.0],
-    [0.12676713, 0.3746935, 0.91844165, 1.0],
-    [0.13585, 0.3701605, 0.91898096, 1.0],
-    [0.13016284, 0.37028092, 0.9197552, 1.0],
-    [0.08622497, 0.42987394, 0.89876235, 1.0],
-    [0.08687044, 0.4326187, 0.897382, 1.0],
-    [0.08151697, 0.44730306, 0.8906598, 1.0],
-    [0.08443492, 0.4366025, 0.8956835, 1.0],
-    [0.09887973, 0.41999015, 0.90212584, 1.0],
-    [0.11112066, 0.4083174, 0.9060514, 1.0],
-    [0.08419388, 0.45017868, 0.88896036, 1.0],
-    [0.10154482, 0.4338666, 0.8952366, 1.0],
-    [0.12005138, 0.4008378, 0.9082492, 1.0],
-    [0.11410205, 0.41642597, 0.9019812, 1.0],
-    [0.104520716, 0.42130142, 0.90087765, 1.0],
-    [0.09270863, 0.42330492, 0.90123135, 1.0],
-    [0.019166602, 0.60348123, 0.7971469, 1.0],
-    [0.021522215, 0.58062166, 0.81388897, 1.0],
-    [0.009643251, 0.63299614, 0.774095, 1.0],
-    [0.012179035, 0.61051685, 0.7919097, 1.0],
-    [0.014856905, 0.6069722, 0.7945842, 1.0],
-    [0.026578253, 0.55904907, 0.8287084, 1.0],
-    [0.03544447, 0.53528684, 0.84392637, 1.0],
-    [0.01736052, 0.5890481, 0.8079115, 1.0],
-    [0.025008835, 0.5685392, 0.82227594, 1.0],
-    [0.043171197, 0.51650727, 0.85519385, 1.0],
-    [0.02859795, 0.55363935, 0.8322653, 1.0],
-    [0.0035852606, 0.6600416, 0.7512205, 1.0],
-    [0.006202088, 0.63808024, 0.769945, 1.0],
-    [0.00069179694, 0.68465424, 0.72886753, 1.0],
-    [0.0033669479, 0.6629915, 0.7486194, 1.0],
-    [0.0027692257, 0.66155905, 0.74988794, 1.0],
-    [0.011448502, 0.6166454, 0.7871578, 1.0],
-    [0.019041333, 0.5962184, 0.80259645, 1.0],
-    [0.008514996, 0.6420371, 0.76662624, 1.0],
-    [0.015927982, 0.62195194, 0.7828935, 1.0],
-    [0.013129658, 0.61926913, 0.7850691, 1.0],
-    [0.011807739, 0.6106174, 0.7918377, 1.0],
-    [0.03867486, 0.5315455, 0.8461463, 1.0],
-    [0.035033844, 0.5492244, 0.83494025, 1.0],
-    [0.03860042, 0.54941493, 0.83465755, 1.0],
-    [0.04491573, 0.51710665, 0.8547416, 1.0],
-    [0.05583461, 0.5015978, 0.86329716, 1.0],
-    [0.04149828, 0.53356767, 0.8447387, 1.0],
-    [0.045760263, 0.53263843, 0.84510493, 1.0],
-    [0.0423451, 0.53074425, 0.8464736, 1.0],
-    [0.07007721, 0.48492604, 0.8717431, 1.0],
-    [0.074586436, 0.4870036, 0.8702094, 1.0],
-    [0.060929794, 0.51312196, 0.8561504, 1.0],
-    [0.071199365, 0.502129, 0.86185676, 1.0],
-    [0.06892904, 0.49681205, 0.8651164, 1.0],
-    [0.028777976, 0.5769466, 0.8162748, 1.0],
-    [0.0363306, 0.5647017, 0.8244951, 1.0],
-    [0.025549311, 0.60270154, 0.79755765, 1.0],
-    [0.03414703, 0.5886945, 0.80763406, 1.0],
-    [0.042668812, 0.55559254, 0.83035916, 1.0],
-    [0.040449962, 0.5796729, 0.8138446, 1.0],
-    [0.03413726, 0.5780579, 0.81528133, 1.0],
-    [0.05452086, 0.54073936, 0.83942145, 1.0],
-    [0.06694447, 0.52716285, 0.84712327, 1.0],
-    [0.050895624, 0.5664712, 0.8225084, 1.0],
-    [0.06420861, 0.551679, 0.8315813, 1.0],
-    [0.05874006, 0.5465329, 0.83537495, 1.0],
-    [0.0789914, 0.46997222, 0.87913966, 1.0],
-    [0.08488907, 0.46995324, 0.8786, 1.0],
-    [0.07977345, 0.49380764, 0.8659042, 1.0],
-    [0.08081236, 0.47799665, 0.8746363, 1.0],
-    [0.07556832, 0.51866394, 0.85163206, 1.0],
-    [0.072782755, 0.5431143, 0.8364983, 1.0],
-    [0.07392016, 0.5310067, 0.84413725, 1.0],
-    [0.057658974, 0.5265034, 0.8482155, 1.0],
-    [0.16265847, 0.3252938, 0.93151826, 1.0],
-    [0.1630778, 0.33111626, 0.92939097, 1.0],
-    [0.15471168, 0.34802446, 0.9246314, 1.0],
-    [0.15976767, 0.3347939, 0.9286481, 1.0],
-    [0.15976763, 0.3347939, 0.9286481, 1.0],
-    [0.06651336, 0.4680872, 0.8811756, 1.0],
-    [0.14505127, 0.36706135, 0.91881776, 1.0],
-    [0.14505127, 0.36706135, 0.91881776, 1.0],
-    [0.1078579, 0.43426883, 0.89430267, 1.0],
-    [0.135667, 0.38894367, 0.9112176, 1.0],
-    [0.14164107, 0.39153716, 0.9091955, 1.0],
-    [0.11889414, 0.4198515, 0.8997716, 1.0],
-    [0.13458398, 0.41014567, 0.90203536, 1.0],
-    [0.12736234, 0.408759, 0.9037118, 1.0],
-    [0.13547367, 0.38806498, 0.9116208, 1.0],
-    [0.10172791, 0.45438313, 0.8849788, 1.0],
-    [0.096753545, 0.47952744, 0.87217677, 1.0],
-    [0.10335323, 0.4818017, 0.8701639, 1.0],
-    [0.11165879, 0.44674972, 0.8876637, 1.0],
-    [0.12751064, 0.43601042, 0.89086246, 1.0],
-    [0.10697207, 0.46494234, 0.8788547, 1.0],
-    [0.114429496, 0.4662937, 0.8771979, 1.0],
-    [0.10850573, 0.46133694, 0.88056505, 1.0],
-    [0.1348475, 0.43863067, 0.88849264, 1.0],
-    [0.13068375, 0.4550268, 0.8808362, 1.0],
-    [0.13266774, 0.44685835, 0.8847129, 1.0],
-    [0.088848725, 0.5068697, 0.8574316, 1.0],
-    [0.1005719, 0.4974744, 0.861629, 1.0],
-    [0.084701456, 0.5323279, 0.84229016, 1.0],
-    [0.097311884, 0.5219785, 0.84738946, 1.0],
-    [0.11120574, 0.48966998, 0.864787, 1.0],
-    [0.10790397, 0.51405007, 0.850946, 1.0],
-    [0.098050125, 0.51034516, 0.8543617, 1.0],
-    [0.12556356, 0.47998103, 0.86824656, 1.0],
-    [0.122080214, 0.50424635, 0.8548871, 1.0],
-    [0.12360841, 0.49219674, 0.8616631, 1.0],
-    [0.114678696, 0.47771445, 0.8709981, 1.0],
-    [0.17788748, 0.32338002, 0.92939836, 1.0],
-    [0.18188678, 0.32812613, 0.92695767, 1.0],
-    [0.15743807, 0.35339302, 0.9221316, 1.0],
-    [0.17250903, 0.34587973, 0.92228395, 1.0],
-    [0.19331785, 0.3083528, 0.9314219, 1.0],
-    [0.20076112, 0.31063423, 0.92908627, 1.0],
-    [0.18464115, 0.33915406, 0.9224327, 1.0],
-    [0.18080524, 0.32960805, 0.92664343, 1.0],
-    [0.20862566, 0.3134844, 0.9263924, 1.0],
-    [0.19840051, 0.33219928, 0.92210686, 1.0],
-    [0.20339133, 0.32284606, 0.9243389, 1.0],
-    [0.14943352, 0.37313756, 0.9156626, 1.0],
-    [0.1590162, 0.37269476, 0.9142277, 1.0],
-    [0.14608519, 0.40288067, 0.90351886, 1.0],
-    [0.17711288, 0.35446444, 0.9181427, 1.0],
-    [0.15745665, 0.37565556, 0.9132855, 1.0],
-    [0.1687661, 0.3729388, 0.91237867, 1.0],
-    [0.16023298, 0.3946107, 0.9047695, 1.0],
-    [0.1863449, 0.3566208, 0.91547644, 1.0],
-    [0.19592884, 0.35932285, 0.9124139, 1.0],
-    [0.1733972, 0.38759252, 0.9053759, 1.0],
-    [0.18690051, 0.38088322, 0.9055365, 1.0],
-    [0.17829794, 0.37515247, 0.9096541, 1.0],
-    [0.22041948, 0.29389754, 0.930075, 1.0],
-    [0.22041945, 0.29389748, 0.93007505, 1.0],
-    [0.22789912, 0.29814297, 0.9269157, 1.0],
-    [0.23565054, 0.30292174, 0.92342156, 1.0],
-    [0.21240348, 0.32588926, 0.9212388, 1.0],
-    [0.22550307, 0.32043815, 0.92003685, 1.0],
-    [0.24799487, 0.28342336, 0.9263745, 1.0],
-    [0.25383678, 0.28810614, 0.9233427, 1.0],
-    [0.24594809, 0.3106738, 0.91814554, 1.0],
-    [0.23531479, 0.30400082, 0.9231524, 1.0],
-    [0.20447741, 0.34107068, 0.9175291, 1.0],
-    [0.21359314, 0.3455281, 0.913777, 1.0],
-    [0.20210531, 0.3739553, 0.9051579, 1.0],
-    [0.23206462, 0.3322463, 0.9141982, 1.0],
-    [0.21271421, 0.3480679, 0.9130178, 1.0],
-    [0.19702032, 0.33480707, 0.9214593, 1.0],
-    [0.25200197, 0.3146321, 0.91515124, 1.0],
-    [0.25200197, 0.3146321, 0.91515124, 1.0],
-    [0.2231524, 0.35030794, 0.9096633, 1.0],
-    [0.21512628, 0.3684584, 0.90441096, 1.0],
-    [0.23920488, 0.33684337, 0.91066873, 1.0],
-    [0.23550487, 0.35908294, 0.90310407, 1.0],
-    [0.24273261, 0.36287522, 0.89966786, 1.0],
-    [0.23094112, 0.35539883, 0.90573615, 1.0],
-    [0.2409985, 0.33500648, 0.9108734, 1.0],
-    [0.1398283, 0.42131507, 0.8960701, 1.0],
-    [0.1398283, 0.42131507, 0.8960701, 1.0],
-    [0.15093401, 0.42074177, 0.8945364, 1.0],
-    [0.16218299, 0.42067537, 0.8925967, 1.0],
-    [0.14321049, 0.4471854, 0.8829021, 1.0],
-    [0.15711118, 0.4391384, 0.88457537, 1.0],
-    [0.1676583, 0.40351486, 0.89948124, 1.0],
-    [0.17828825, 0.4062146, 0.896216, 1.0],
-    [0.17125954, 0.43182775, 0.8855478, 1.0],
-    [0.16118039, 0.42409232, 0.89116025, 1.0],
-    [0.13800292, 0.47199577, 0.8707325, 1.0],
-    [0.1516525, 0.46385378, 0.8728351, 1.0],
-    [0.13428396, 0.49630067, 0.85770243, 1.0],
-    [0.14778993, 0.48811388, 0.8601762, 1.0],
-    [0.16555, 0.45648172, 0.8741954, 1.0],
-    [0.16159177, 0.48050013, 0.861979, 1.0],
-    [0.14951079, 0.47613308, 0.8665702, 1.0],
-    [0.19520567, 0.39208436, 0.89897966, 1.0],
-    [0.19520567, 0.39208436, 0.89897966, 1.0],
-    [0.16002919, 0.4281441, 0.8894286, 1.0],
-    [0.1892944, 0.4093015, 0.8925469, 1.0],
-    [0.18459384, 0.42543563, 0.8859626, 1.0],
-    [0.18686377, 0.4173865, 0.8893089, 1.0],
-    [0.17880042, 0.44992852, 0.8749827, 1.0],
-    [0.174644, 0.47376874, 0.8631586, 1.0],
-    [0.1765453, 0.46190682, 0.8691799, 1.0],
-    [0.2032495, 0.3955081, 0.89569134, 1.0],
-    [0.20272176, 0.41755942, 0.8857472, 1.0],
-    [0.21134001, 0.42136237, 0.88192356, 1.0],
-    [0.22229177, 0.38724527, 0.8947779, 1.0],
-    [0.23476683, 0.3823513, 0.89369565, 1.0],
-    [0.21619406, 0.40493485, 0.88841885, 1.0],
-    [0.22418652, 0.4085306, 0.8847842, 1.0],
-    [0.21615314, 0.40242884, 0.88956666, 1.0],
-    [0.19500142, 0.4421675, 0.8754784, 1.0],
-    [0.20760143, 0.43652362, 0.87541354, 1.0],
-    [0.1906263, 0.46589524, 0.8640621, 1.0],
-    [0.20317788, 0.46008497, 0.8643151, 1.0],
-    [0.22214626, 0.43044958, 0.87485087, 1.0],
-    [0.21887413, 0.4533383, 0.86404777, 1.0],
-    [0.20602348, 0.44801477, 0.8699639, 1.0],
-    [0.19568036, 0.43237635, 0.88020444, 1.0],
-    [0.16972598, 0.39756837, 0.90173864, 1.0],
-    [0.46156737, 0.09641147, 0.8818505, 1.0],
-    [0.44322145, 0.09379054, 0.89149207, 1.0],
-    [0.49729413, 0.07299513, 0.86450577, 1.0],
-    [0.4753815, 0.073260225, 0.87672424, 1.0],
-    [0.46918496, 0.08337323, 0.8791555, 1.0],
-    [0.42910588, 0.09289803, 0.8984643, 1.0],
-    [0.42244786, 0.093256764, 0.9015769, 1.0],
-    [0.45482916, 0.07680983, 0.8872602, 1.0],
-    [0.43586934, 0.08338415, 0.89613897, 1.0],
-    [0.4354279, 0.086213216, 0.8960858, 1.0],
-    [0.43925464, 0.11215205, 0.8913346, 1.0],
-    [0.4216508, 0.10861524, 0.90022963, 1.0],
-    [0.43010062, 0.10999696, 0.89605474, 1.0],
-    [0.40837893, 0.10680095, 0.906543, 1.0],
-    [0.40033352, 0.105921336, 0.91022736, 1.0],
-    [0.41696662, 0.0940324, 0.9040447, 1.0],
-    [0.40839344, 0.10161437, 0.9071325, 1.0],
-    [0.54480124, 0.045623343, 0.8373231, 1.0],
-    [0.5256859, 0.04527973, 0.8494728, 1.0],
-    [0.526447, 0.055610746, 0.84838736, 1.0],
-    [0.5075784, 0.054707244, 0.85986704, 1.0],
-    [0.5711268, 0.03265979, 0.8202119, 1.0],
-    [0.54839414, 0.034523826, 0.835507, 1.0],
-    [0.5373285, 0.04387656, 0.842231, 1.0],
-    [0.5156298, 0.04604639, 0.85557324, 1.0],
-    [0.49508148, 0.054514717, 0.86713463, 1.0],
-    [0.4729817, 0.06265161, 0.8788419, 1.0],
-    [0.52620095, 0.03965679, 0.84943503, 1.0],
-    [0.5203489, 0.04089067, 0.8529743, 1.0],
-    [0.5079194, 0.04701031, 0.8601209, 1.0],
-    [0.5047354, 0.047522414, 0.861965, 1.0],
-    [0.5060056, 0.047658034, 0.8612125, 1.0],
-    [0.46409053, 0.07501307, 0.8826058, 1.0],
-    [0.4327619, 0.08385164, 0.8976001, 1.0],
-    [0.43276182, 0.08385155, 0.8976001, 1.0],
-    [0.3757838, 0.11955067, 0.9189636, 1.0],
-    [0.37353745, 0.121903524, 0.91957027, 1.0],
-    [0.3550112, 0.13399261, 0.9252097, 1.0],
-    [0.35433412, 0.1350903, 0.92530966, 1.0],
-    [0.41584298, 0.093809724, 0.9045852, 1.0],
-    [0.4012233, 0.104347005, 0.9100174, 1.0],
-    [0.3791056, 0.11742644, 0.91787255, 1.0],
-    [0.37300286, 0.124159, 0.91948545, 1.0],
-    [0.34859994, 0.142992, 0.9262998, 1.0],
-    [0.34493676, 0.150913, 0.92641455, 1.0],
-    [0.38898677, 0.116082475, 0.9139005, 1.0],
-    [0.39314184, 0.11785851, 0.91189295, 1.0],
-    [0.37469345, 0.12676708, 0.91844165, 1.0],
-    [0.3701605, 0.13585003, 0.91898096, 1.0],
-    [0.37028095, 0.13016288, 0.9197552, 1.0],
-    [0.45010674, 0.074592374, 0.88985384, 1.0],
-    [0.44698432, 0.0782707, 0.89111096, 1.0],
-    [0.48815626, 0.056232013, 0.8709429, 1.0],
-    [0.46632704, 0.07046729, 0.8818012, 1.0],
-    [0.4628006, 0.069362566, 0.88374454, 1.0],
-    [0.4647071, 0.07527977, 0.8822587, 1.0],
-    [0.4647071, 0.0752797, 0.8822587, 1.0],
-    [0.42987394, 0.086225055, 0.89876235, 1.0],
-    [0.4326187, 0.08687044, 0.897382, 1.0],
-    [0.44730306, 0.08151697, 0.8906598, 1.0],
-    [0.4366025, 0.08443492, 0.8956835, 1.0],
-    [0.41999015, 0.09887973, 0.90212584, 1.0],
-    [0.4083174, 0.11112066, 0.9060514, 1.0],
-    [0.40083784, 0.12005132, 0.9082493, 1.0],
-    [0.45017865, 0.08419387, 0.88896036, 1.0],
-    [0.4338666, 0.10154482, 0.8952366, 1.0],
-    [0.41642597, 0.11410205, 0.9019812, 1.0],
-    [0.42130142, 0.104520716, 0.90087765, 1.0],
-    [0.42330492, 0.09270863, 0.90123135, 1.0],
-    [0.3252938, 0.16265847, 0.93151826, 1.0],
-    [0.33111626, 0.1630778, 0.92939097, 1.0],
-    [0.34802446, 0.15471168, 0.9246314, 1.0],
-    [0.3347939, 0.1597676, 0.9286481, 1.0],
-    [0.3347939, 0.1597676, 0.9286481, 1.0],
-    [0.60348123, 0.019166555, 0.7971469, 1.0],
-    [0.58062166, 0.021522215, 0.81388897, 1.0],
-    [0.63299614, 0.009643296, 0.774095, 1.0],
-    [0.61051685, 0.012179077, 0.79190964, 1.0],
-    [0.6069722, 0.014856905, 0.7945842, 1.0],
-    [0.55904907, 0.026578253, 0.8287084, 1.0],
-    [0.53528684, 0.03544447, 0.84392637, 1.0],
-    [0.51650727, 0.04317124, 0.85519385, 1.0],
-    [0.5890481, 0.01736056, 0.8079115, 1.0],
-    [0.5685392, 0.025008876, 0.82227594, 1.0],
-    [0.55363935, 0.02859795, 0.8322653, 1.0],
-    [0.6600416, 0.0035852606, 0.7512205, 1.0],
-    [0.63808024, 0.006202088, 0.769945, 1.0],
-    [0.68465424, 0.00069179694, 0.72886753, 1.0],
-    [0.6629915, 0.0033669886, 0.7486194, 1.0],
-    [0.66155905, 0.0027692257, 0.74988794, 1.0],
-    [0.6166454, 0.011448502, 0.7871578, 1.0],
-    [0.5962184, 0.019041333, 0.80259645, 1.0],
-    [0.6420371, 0.008514957, 0.76662624, 1.0],
-    [0.6219518, 0.015927939, 0.7828934, 1.0],
-    [0.61926913, 0.013129658, 0.7850691, 1.0],
-    [0.6106174, 0.011807739, 0.7918377, 1.0],
-    [0.5315455, 0.03867486, 0.8461463, 1.0],
-    [0.51710665, 0.04491573, 0.8547416, 1.0],
-    [0.5015978, 0.05583461, 0.86329716, 1.0],
-    [0.54922444, 0.035033885, 0.83494025, 1.0],
-    [0.54941493, 0.03860042, 0.83465755, 1.0],
-    [0.53356767, 0.04149828, 0.8447387, 1.0],
-    [0.53263843, 0.045760263, 0.84510493, 1.0],
-    [0.53074425, 0.0423451, 0.8464736, 1.0],
-    [0.48492604, 0.07007721, 0.8717431, 1.0],
-    [0.4870036, 0.074586436, 0.8702094, 1.0],
-    [0.51312196, 0.060929824, 0.85615027, 1.0],
-    [0.502129, 0.07119933, 0.86185676, 1.0],
-    [0.49681208, 0.06892901, 0.8651165, 1.0],
-    [0.46997222, 0.078991435, 0.87913966, 1.0],
-    [0.46995324, 0.08488907, 0.8786, 1.0],
-    [0.49380764, 0.07977345, 0.8659042, 1.0],
-    [0.47799665, 0.08081236, 0.8746363, 1.0],
-    [0.5769466, 0.028777976, 0.8162748, 1.0],
-    [0.5647017, 0.0363306, 0.8244951, 1.0],
-    [0.5555925, 0.04266877, 0.8303591, 1.0],
-    [0.60270154, 0.025549348, 0.79755765, 1.0],
-    [0.5886946, 0.03414703, 0.80763406, 1.0],
-    [0.57967293, 0.040449932, 0.81384474, 1.0],
-    [0.57805794, 0.034137297, 0.81528133, 1.0],
-    [0.54073936, 0.054520793, 0.8394216, 1.0],
-    [0.52716285, 0.0669445, 0.84712327, 1.0],
-    [0.5664712, 0.050895624, 0.8225084, 1.0],
-    [0.551679, 0.06420865, 0.8315813, 1.0],
-    [0.5465329, 0.058740024, 0.83537495, 1.0],
-    [0.51866394, 0.07556835, 0.85163206, 1.0],
-    [0.5431143, 0.072782755, 0.8364984, 1.0],
-    [0.5310067, 0.07392016, 0.84413725, 1.0],
-    [0.5265034, 0.057658974, 0.8482155, 1.0],
-    [0.4680872, 0.06651336, 0.8811756, 1.0],
-    [0.36706135, 0.14505135, 0.91881776, 1.0],
-    [0.3670613, 0.14505127, 0.9188178, 1.0],
-    [0.38894367, 0.135667, 0.9112176, 1.0],
-    [0.3915372, 0.14164113, 0.9091954, 1.0],
-    [0.43426883, 0.1078579, 0.89430267, 1.0],
-    [0.4198515, 0.11889414, 0.8997716, 1.0],
-    [0.41014567, 0.13458395, 0.90203536, 1.0],
-    [0.40875894, 0.12736233, 0.9037118, 1.0],
-    [0.38806498, 0.13547371, 0.9116208, 1.0],
-    [0.32338002, 0.17788748, 0.92939836, 1.0],
-    [0.32812613, 0.18188678, 0.92695767, 1.0],
-    [0.30835286, 0.19331785, 0.9314219, 1.0],
-    [0.31063423, 0.20076117, 0.92908627, 1.0],
-    [0.35339308, 0.15743807, 0.9221316, 1.0],
-    [0.3458797, 0.17250897, 0.922284, 1.0],
-    [0.33915406, 0.18464115, 0.9224327, 1.0],
-    [0.32960805, 0.18080524, 0.92664343, 1.0],
-    [0.3134844, 0.2086257, 0.9263924, 1.0],
-    [0.33219928, 0.19840051, 0.92210686, 1.0],
-    [0.32284606, 0.20339133, 0.9243389, 1.0],
-    [0.29389754, 0.22041948, 0.930075, 1.0],
-    [0.29389748, 0.22041945, 0.93007505, 1.0],
-    [0.29814297, 0.22789909, 0.9269157, 1.0],
-    [0.30292177, 0.23565061, 0.92342156, 1.0],
-    [0.28342336, 0.24799487, 0.9263745, 1.0],
-    [0.28810614, 0.25383675, 0.9233427, 1.0],
-    [0.32588926, 0.21240348, 0.9212388, 1.0],
-    [0.32043815, 0.22550307, 0.92003685, 1.0],
-    [0.31067374, 0.24594809, 0.91814554, 1.0],
-    [0.30400082, 0.23531482, 0.9231524, 1.0],
-    [0.37313756, 0.14943352, 0.9156626, 1.0],
-    [0.3726948, 0.1590162, 0.9142277, 1.0],
-    [0.35446447, 0.17711288, 0.9181426, 1.0],
-    [0.40288067, 0.14608519, 0.90351886, 1.0],
-    [0.37565556, 0.15745665, 0.9132855, 1.0],
-    [0.3729388, 0.1687661, 0.9123786, 1.0],
-    [0.35662082, 0.18634495, 0.9154765, 1.0],
-    [0.35932285, 0.19592887, 0.9124139, 1.0],
-    [0.3946107, 0.16023302, 0.90476936, 1.0],
-    [0.38759252, 0.17339723, 0.90537596, 1.0],
-    [0.38088322, 0.18690051, 0.9055365, 1.0],
-    [0.37515247, 0.17829797, 0.9096541, 1.0],
-    [0.34107068, 0.20447741, 0.9175291, 1.0],
-    [0.3455281, 0.21359311, 0.9137769, 1.0],
-    [0.3322463, 0.23206462, 0.9141982, 1.0],
-    [0.3739553, 0.20210534, 0.9051579, 1.0],
-    [0.3480679, 0.21271421, 0.9130178, 1.0],
-    [0.33480704, 0.19702032, 0.9214593, 1.0],
-    [0.3146321, 0.25200197, 0.91515124, 1.0],
-    [0.3146321, 0.25200197, 0.91515124, 1.0],
-    [0.35030794, 0.2231524, 0.9096633, 1.0],
-    [0.33684337, 0.23920491, 0.9106688, 1.0],
-    [0.3684584, 0.2151263, 0.9044109, 1.0],
-    [0.35908294, 0.23550487, 0.90310407, 1.0],
-    [0.36287522, 0.24273263, 0.899668, 1.0],
-    [0.3553988, 0.23094112, 0.90573615, 1.0],
-    [0.33500645, 0.2409985, 0.9108734, 1.0],
-    [0.45438313, 0.10172791, 0.8849788, 1.0],
-    [0.44674972, 0.11165879, 0.8876637, 1.0],
-    [0.43601042, 0.1275107, 0.89086246, 1.0],
-    [0.47952744, 0.096753545, 0.87217677, 1.0],
-    [0.4818017, 0.10335323, 0.870164, 1.0],
-    [0.46494234, 0.10697207, 0.8788547, 1.0],
-    [0.4662937, 0.114429496, 0.8771979, 1.0],
-    [0.46133694, 0.10850577, 0.88056505, 1.0],
-    [0.43863067, 0.13484746, 0.88849276, 1.0],
-    [0.4550268, 0.13068375, 0.8808362, 1.0],
-    [0.4468584, 0.13266775, 0.88471293, 1.0],
-    [0.5068697, 0.088848725, 0.8574316, 1.0],
-    [0.4974744, 0.1005719, 0.861629, 1.0],
-    [0.48967004, 0.11120579, 0.86478704, 1.0],
-    [0.5323279, 0.084701456, 0.84229016, 1.0],
-    [0.52197856, 0.09731186, 0.84738946, 1.0],
-    [0.5140501, 0.10790399, 0.85094607, 1.0],
-    [0.51034516, 0.098050095, 0.8543617, 1.0],
-    [0.47998103, 0.12556356, 0.86824656, 1.0],
-    [0.50424635, 0.122080244, 0.8548871, 1.0],
-    [0.49219674, 0.12360841, 0.8616631, 1.0],
-    [0.47771445, 0.114678696, 0.8709981, 1.0],
-    [0.4213151, 0.1398283, 0.8960701, 1.0],
-    [0.4213151, 0.1398283, 0.8960701, 1.0],
-    [0.42074177, 0.15093395, 0.8945364, 1.0],
-    [0.42067537, 0.16218299, 0.8925967, 1.0],
-    [0.40351486, 0.1676583, 0.89948124, 1.0],
-    [0.4062146, 0.17828825, 0.896216, 1.0],
-    [0.4471854, 0.14321047, 0.88290197, 1.0],
-    [0.4391384, 0.15711118, 0.88457537, 1.0],
-    [0.43182775, 0.17125951, 0.8855478, 1.0],
-    [0.42409232, 0.16118039, 0.89116025, 1.0],
-    [0.3920844, 0.19520567, 0.89897966, 1.0],
-    [0.3920844, 0.19520567, 0.89897966, 1.0],
-    [0.47199577, 0.13800292, 0.8707325, 1.0],
-    [0.4638538, 0.1516525, 0.8728351, 1.0],
-    [0.45648172, 0.16554993, 0.8741954, 1.0],
-    [0.49630067, 0.13428396, 0.85770243, 1.0],
-    [0.48811388, 0.14778993, 0.8601762, 1.0],
-    [0.48050013, 0.16159177, 0.861979, 1.0],
-    [0.47613308, 0.14951079, 0.8665702, 1.0],
-    [0.4281441, 0.16002919, 0.8894286, 1.0],
-    [0.40930146, 0.18929443, 0.89254695, 1.0],
-    [0.42543563, 0.18459384, 0.88596255, 1.0],
-    [0.4173865, 0.1868638, 0.8893089, 1.0],
-    [0.39550814, 0.2032495, 0.8956913, 1.0],
-    [0.38724527, 0.22229177, 0.8947779, 1.0],
-    [0.38235134, 0.23476683, 0.89369565, 1.0],
-    [0.41755942, 0.20272176, 0.8857472, 1.0],
-    [0.42136237, 0.21134001, 0.88192356, 1.0],
-    [0.40493485, 0.21619406, 0.88841885, 1.0],
-    [0.4085306, 0.22418652, 0.8847842, 1.0],
-    [0.40242884, 0.21615314, 0.8895666, 1.0],
-    [0.44992855, 0.17880043, 0.87498266, 1.0],
-    [0.47376868, 0.17464396, 0.8631585, 1.0],
-    [0.46190682, 0.1765453, 0.8691799, 1.0],
-    [0.4421675, 0.19500145, 0.8754784, 1.0],
-    [0.43652362, 0.20760143, 0.8754135, 1.0],
-    [0.43044958, 0.22214626, 0.8748509, 1.0],
-    [0.46589524, 0.19062626, 0.86406213, 1.0],
-    [0.46008497, 0.20317788, 0.8643151, 1.0],
-    [0.45333824, 0.21887413, 0.8640477, 1.0],
-    [0.44801477, 0.20602351, 0.8699639, 1.0],
-    [0.43237635, 0.19568036, 0.88020444, 1.0],
-    [0.39756835, 0.16972598, 0.90173864, 1.0],
-    [0.27751237, 0.27751237, 0.91976833, 1.0],
-    [0.2633141, 0.29600725, 0.918175, 1.0],
-    [0.29600725, 0.2633141, 0.918175, 1.0],
-    [0.2787915, 0.2787915, 0.91899437, 1.0],
-    [0.2787915, 0.2787915, 0.91899437, 1.0],
-    [0.28221366, 0.28221366, 0.9169029, 1.0],
-    [0.27147225, 0.30342624, 0.9133649, 1.0],
-    [0.28007343, 0.31112894, 0.90816176, 1.0],
-    [0.30342624, 0.27147222, 0.9133649, 1.0],
-    [0.31112897, 0.2800735, 0.9081617, 1.0],
-    [0.29248592, 0.29248595, 0.91044164, 1.0],
-    [0.2974036, 0.29740354, 0.90724975, 1.0],
-    [0.29098266, 0.29098263, 0.9114045, 1.0],
-    [0.25909787, 0.3299478, 0.9077459, 1.0],
-    [0.2715511, 0.32576433, 0.90561444, 1.0],
-    [0.25086853, 0.34537664, 0.90431195, 1.0],
-    [0.25822282, 0.35088935, 0.9001097, 1.0],
-    [0.28977498, 0.3201006, 0.90197885, 1.0],
-    [0.28386796, 0.34224325, 0.89570564, 1.0],
-    [0.26874802, 0.33551103, 0.902888, 1.0],
-    [0.29004988, 0.3466312, 0.89203024, 1.0],
-    [0.29004988, 0.3466312, 0.89203024, 1.0],
-    [0.3299478, 0.25909787, 0.9077459, 1.0],
-    [0.32576433, 0.2715511, 0.90561444, 1.0],
-    [0.32010067, 0.28977498, 0.90197897, 1.0],
-    [0.34537664, 0.25086853, 0.90431195, 1.0],
-    [0.35088935, 0.25822285, 0.9001097, 1.0],
-    [0.34224325, 0.28386796, 0.89570564, 1.0],
-    [0.33551103, 0.26874802, 0.902888, 1.0],
-    [0.34663117, 0.29004988, 0.89203024, 1.0],
-    [0.34663123, 0.2900499, 0.89203024, 1.0],
-    [0.31223196, 0.31223196, 0.8972304, 1.0],
-    [0.29943186, 0.32936233, 0.895467, 1.0],
-    [0.3293623, 0.29943186, 0.895467, 1.0],
-    [0.3135576, 0.3135576, 0.8963053, 1.0],
-    [0.31710154, 0.31710154, 0.89380825, 1.0],
-    [0.30632263, 0.33581325, 0.8907277, 1.0],
-    [0.33581325, 0.30632263, 0.8907277, 1.0],
-    [0.33107278, 0.33107278, 0.8836184, 1.0],
-    [0.32246202, 0.32246202, 0.8899643, 1.0],
-    [0.30907282, 0.30907282, 0.89941543, 1.0],
-    [0.25638425, 0.3755396, 0.89063853, 1.0],
-    [0.24680379, 0.39954257, 0.8828667, 1.0],
-    [0.27125385, 0.36411452, 0.89097804, 1.0],
-    [0.2579483, 0.37968022, 0.8884287, 1.0],
-    [0.26414487, 0.38073403, 0.88615406, 1.0],
-    [0.25889814, 0.39528507, 0.88131803, 1.0],
-    [0.27819875, 0.36837798, 0.8870756, 1.0],
-    [0.2743205, 0.3901373, 0.87894326, 1.0],
-    [0.2687867, 0.38360298, 0.88351715, 1.0],
-    [0.23962857, 0.42366505, 0.8735479, 1.0],
-    [0.23462287, 0.44701675, 0.8632081, 1.0],
-    [0.2369844, 0.435379, 0.868495, 1.0],
-    [0.2516501, 0.41933897, 0.872254, 1.0],
-    [0.24659143, 0.44255248, 0.86217165, 1.0],
-    [0.27021563, 0.41301164, 0.86971545, 1.0],
-    [0.26657698, 0.43551245, 0.85980564, 1.0],
-    [0.25861007, 0.4275727, 0.8662, 1.0],
-    [0.30265772, 0.36015698, 0.8824315, 1.0],
-    [0.29916278, 0.38301173, 0.8739586, 1.0],
-    [0.3062288, 0.38846928, 0.8690889, 1.0],
-    [0.32526776, 0.3525798, 0.8774328, 1.0],
-    [0.33077478, 0.3572512, 0.87347555, 1.0],
-    [0.31334186, 0.37220213, 0.8736603, 1.0],
-    [0.31970018, 0.37727228, 0.86917055, 1.0],
-    [0.3137426, 0.37006372, 0.87442464, 1.0],
-    [0.28978956, 0.4066967, 0.86638314, 1.0],
-    [0.30121773, 0.40327802, 0.86408025, 1.0],
-    [0.2838641, 0.42971432, 0.85718536, 1.0],
-    [0.29518563, 0.4261684, 0.85512924, 1.0],
-    [0.3161305, 0.39899534, 0.8607347, 1.0],
-    [0.3121761, 0.42111713, 0.8515906, 1.0],
-    [0.29960737, 0.4142738, 0.85942584, 1.0],
-    [0.2719662, 0.4013646, 0.87460893, 1.0],
-    [0.37553963, 0.25638428, 0.89063865, 1.0],
-    [0.3641145, 0.27125385, 0.89097804, 1.0],
-    [0.39954257, 0.24680379, 0.8828667, 1.0],
-    [0.37968022, 0.2579483, 0.8884287, 1.0],
-    [0.38073403, 0.26414487, 0.88615406, 1.0],
-    [0.36837798, 0.27819875, 0.8870756, 1.0],
-    [0.39528507, 0.25889814, 0.88131803, 1.0],
-    [0.3901373, 0.2743205, 0.87894326, 1.0],
-    [0.38360298, 0.26878667, 0.8835171, 1.0],
-    [0.36015698, 0.30265772, 0.88243145, 1.0],
-    [0.3525798, 0.32526776, 0.8774328, 1.0],
-    [0.3572512, 0.33077478, 0.87347555, 1.0],
-    [0.38301176, 0.29916278, 0.8739586, 1.0],
-    [0.38846928, 0.3062288, 0.8690889, 1.0],
-    [0.37220213, 0.31334186, 0.8736603, 1.0],
-    [0.37727228, 0.3197002, 0.8691705, 1.0],
-    [0.37006372, 0.3137426, 0.87442464, 1.0],
-    [0.4236651, 0.2396286, 0.8735479, 1.0],
-    [0.44701675, 0.2346228, 0.8632081, 1.0],
-    [0.435379, 0.2369844, 0.868495, 1.0],
-    [0.41933897, 0.2516501, 0.872254, 1.0],
-    [0.41301164, 0.27021563, 0.86971545, 1.0],
-    [0.44255248, 0.24659143, 0.86217165, 1.0],
-    [0.43551245, 0.26657698, 0.85980564, 1.0],
-    [0.4275727, 0.25861007, 0.8662, 1.0],
-    [0.4066967, 0.28978956, 0.86638314, 1.0],
-    [0.40327802, 0.3012177, 0.86408025, 1.0],
-    [0.39899534, 0.3161305, 0.8607347, 1.0],
-    [0.42971432, 0.2838641, 0.85718536, 1.0],
-    [0.4261684, 0.29518563, 0.85512924, 1.0],
-    [0.42111713, 0.3121761, 0.8515906, 1.0],
-    [0.41427377, 0.29960734, 0.85942584, 1.0],
-    [0.4013646, 0.2719662, 0.874609, 1.0],
-    [0.34068215, 0.34068215, 0.8762827, 1.0],
-    [0.347064, 0.347064, 0.87125957, 1.0],
-    [0.34307006, 0.370788, 0.863029, 1.0],
-    [0.370788, 0.3430701, 0.863029, 1.0],
-    [0.36550552, 0.36550552, 0.856044, 1.0],
-    [0.3533242, 0.3533242, 0.8662125, 1.0],
-    [0.3385561, 0.39294642, 0.8549695, 1.0],
-    [0.33453575, 0.41487756, 0.8461457, 1.0],
-    [0.36073112, 0.38760173, 0.8483148, 1.0],
-    [0.3564181, 0.40941322, 0.83984935, 1.0],
-    [0.3474814, 0.40116072, 0.8475415, 1.0],
-    [0.39294642, 0.3385561, 0.8549695, 1.0],
-    [0.38760173, 0.36073115, 0.8483148, 1.0],
-    [0.41487756, 0.33453572, 0.8461457, 1.0],
-    [0.40941322, 0.3564181, 0.83984935, 1.0],
-    [0.40116072, 0.3474814, 0.8475415, 1.0],
-    [0.3827363, 0.3827363, 0.8408482, 1.0],
-    [0.37812334, 0.40443546, 0.8327393, 1.0],
-    [0.40443546, 0.37812334, 0.8327393, 1.0],
-    [0.39973438, 0.39973438, 0.8248787, 1.0],
-    [0.39120898, 0.39120898, 0.83301324, 1.0],
-    [0.3730213, 0.3730213, 0.84953535, 1.0],
-    [0.32428044, 0.32428044, 0.8886419, 1.0],
-    [0.25056347, 0.2505635, 0.9351127, 1.0],
-    [0.21019971, 0.92378813, 0.32004926, 1.0],
-    [0.20173608, 0.9302617, 0.30645669, 1.0],
-    [0.20556504, 0.9272893, 0.31285402, 1.0],
-    [0.15962347, 0.9118941, 0.3781133, 1.0],
-    [0.1348231, 0.9024763, 0.40909556, 1.0],
-    [0.15391193, 0.9193576, 0.36206713, 1.0],
-    [0.13229433, 0.9114774, 0.38949618, 1.0],
-    [0.18101604, 0.9180066, 0.35284138, 1.0],
-    [0.17402215, 0.92494166, 0.33793387, 1.0],
-    [0.15511057, 0.9154805, 0.371263, 1.0],
-    [0.19601564, 0.9348166, 0.2961349, 1.0],
-    [0.1935539, 0.93688554, 0.29117405, 1.0],
-    [0.1946462, 0.93592685, 0.29351974, 1.0],
-    [0.15138555, 0.9228946, 0.35404512, 1.0],
-    [0.13564909, 0.91948277, 0.368986, 1.0],
-    [0.132226, 0.9224526, 0.3627637, 1.0],
-    [0.16681765, 0.9290981, 0.3300736, 1.0],
-    [0.14955533, 0.9258144, 0.34713256, 1.0],
-    [0.17558236, 0.93486726, 0.30853552, 1.0],
-    [0.14858538, 0.92810714, 0.3413788, 1.0],
-    [0.15073335, 0.92668056, 0.34429997, 1.0],
-    [0.17460348, 0.9279808, 0.3291889, 1.0],
-    [0.13283746, 0.9243225, 0.3577461, 1.0],
-    [0.17224374, 0.9361262, 0.30659404, 1.0],
-    [0.14628606, 0.92938226, 0.33889383, 1.0],
-    [0.1729188, 0.9366321, 0.3046628, 1.0],
-    [0.15849234, 0.93306184, 0.32291758, 1.0],
-    [0.15612979, 0.93248135, 0.32573313, 1.0],
-    [0.1599059, 0.9323978, 0.32413664, 1.0],
-    [0.1599058, 0.9323978, 0.32413658, 1.0],
-    [0.15739931, 0.9327216, 0.3244313, 1.0],
-    [0.3200492, 0.92378813, 0.21019967, 1.0],
-    [0.30645669, 0.9302617, 0.20173608, 1.0],
-    [0.31285402, 0.9272893, 0.20556504, 1.0],
-    [0.2961349, 0.9348166, 0.19601564, 1.0],
-    [0.29117405, 0.93688554, 0.1935539, 1.0],
-    [0.29351974, 0.93592685, 0.1946462, 1.0],
-    [0.3781133, 0.9118941, 0.15962347, 1.0],
-    [0.35284144, 0.9180066, 0.18101604, 1.0],
-    [0.36206713, 0.9193576, 0.15391189, 1.0],
-    [0.33793387, 0.92494166, 0.17402215, 1.0],
-    [0.40909556, 0.9024763, 0.1348231, 1.0],
-    [0.38949615, 0.9114773, 0.13229437, 1.0],
-    [0.371263, 0.9154805, 0.15511051, 1.0],
-    [0.35401028, 0.92290115, 0.15142691, 1.0],
-    [0.330052, 0.92910117, 0.16684306, 1.0],
-    [0.30853552, 0.93486726, 0.17558227, 1.0],
-    [0.36896542, 0.91948783, 0.13567132, 1.0],
-    [0.34709716, 0.9258214, 0.14959331, 1.0],
-    [0.3628041, 0.922438, 0.13221635, 1.0],
-    [0.34141645, 0.9280945, 0.14857848, 1.0],
-    [0.3442959, 0.9266794, 0.1507501, 1.0],
-    [0.32918832, 0.9279798, 0.17460945, 1.0],
-    [0.26377177, 0.9278194, 0.26377177, 1.0],
-    [0.23555031, 0.9267148, 0.2927724, 1.0],
-    [0.25264636, 0.93399113, 0.25264636, 1.0],
-    [0.22591667, 0.932972, 0.28022295, 1.0],
-    [0.2927724, 0.9267148, 0.23555031, 1.0],
-    [0.28022307, 0.932972, 0.22591667, 1.0],
-    [0.25783446, 0.9311514, 0.25783446, 1.0],
-    [0.24464191, 0.9382434, 0.24464191, 1.0],
-    [0.21895137, 0.9373045, 0.2711469, 1.0],
-    [0.24051666, 0.9403741, 0.24051666, 1.0],
-    [0.215521, 0.9402239, 0.26368502, 1.0],
-    [0.27114695, 0.9373045, 0.21895137, 1.0],
-    [0.26368502, 0.9402239, 0.21552108, 1.0],
-    [0.23791103, 0.9416988, 0.23791103, 1.0],
-    [0.24117179, 0.9400385, 0.24117179, 1.0],
-    [0.1916878, 0.93838614, 0.28755394, 1.0],
-    [0.1916878, 0.93838614, 0.28755394, 1.0],
-    [0.28755394, 0.93838614, 0.1916878, 1.0],
-    [0.28755394, 0.93838614, 0.1916878, 1.0],
-    [0.24201071, 0.93960714, 0.24201067, 1.0],
-    [0.30661666, 0.93612045, 0.17223443, 1.0],
-    [0.3046862, 0.93662804, 0.17289953, 1.0],
-    [0.35793534, 0.9242676, 0.13270906, 1.0],
-    [0.3390243, 0.9293488, 0.14619565, 1.0],
-    [0.322882, 0.9330705, 0.15851332, 1.0],
-    [0.32579786, 0.93246645, 0.15608367, 1.0],
-    [0.32418314, 0.932389, 0.1598626, 1.0],
-    [0.32418314, 0.932389, 0.1598626, 1.0],
-    [0.32448503, 0.9327109, 0.15735243, 1.0],
-    [0.21440811, 0.9417502, 0.25910562, 1.0],
-    [0.21400191, 0.94212115, 0.2580907, 1.0],
-    [0.25910562, 0.9417502, 0.21440811, 1.0],
-    [0.2364318, 0.94244367, 0.23643185, 1.0],
-    [0.2578594, 0.9421351, 0.21421924, 1.0],
-    [0.23569115, 0.9427593, 0.23591246, 1.0],
-    [0.2357857, 0.9427388, 0.23590006, 1.0],
-    [0.19102794, 0.9393829, 0.28472474, 1.0],
-    [0.19363223, 0.94007397, 0.2806554, 1.0],
-    [0.17421865, 0.9365825, 0.30407405, 1.0],
-    [0.21488261, 0.9419323, 0.2580484, 1.0],
-    [0.1928934, 0.94000894, 0.2813814, 1.0],
-    [0.23710772, 0.94210404, 0.23710772, 1.0],
-    [0.2389427, 0.9411238, 0.23914942, 1.0],
-    [0.23792848, 0.9416635, 0.23803338, 1.0],
-    [0.19770017, 0.93968683, 0.27911144, 1.0],
-    [0.17353776, 0.9352947, 0.30839673, 1.0],
-    [0.19991456, 0.9387771, 0.28059208, 1.0],
-    [0.18682602, 0.93570924, 0.29923952, 1.0],
-    [0.21608114, 0.94144005, 0.2588429, 1.0],
-    [0.22043177, 0.9398809, 0.26083288, 1.0],
-    [0.20293918, 0.9373288, 0.2832498, 1.0],
-    [0.19916496, 0.93877894, 0.2811181, 1.0],
-    [0.2847247, 0.9393829, 0.19102794, 1.0],
-    [0.28053862, 0.9400876, 0.19373514, 1.0],
-    [0.25782415, 0.94194573, 0.2150932, 1.0],
-    [0.30398875, 0.93659854, 0.17428142, 1.0],
-    [0.28127185, 0.94002193, 0.1929892, 1.0],
-    [0.27903622, 0.93969727, 0.19775715, 1.0],
-    [0.25876758, 0.9414466, 0.21614234, 1.0],
-    [0.28061125, 0.9387767, 0.19988948, 1.0],
-    [0.26073623, 0.9398876, 0.2205176, 1.0],
-    [0.30842286, 0.9352909, 0.1735115, 1.0],
-    [0.2992555, 0.9357075, 0.18680884, 1.0],
-    [0.28327477, 0.9373276, 0.20290984, 1.0],
-    [0.2810945, 0.9387832, 0.19917801, 1.0],
-    [0.24174936, 0.93969005, 0.24195005, 1.0],
-    [0.24174932, 0.93969005, 0.24195, 1.0],
-    [0.23685291, 0.94220793, 0.23694922, 1.0],
-    [0.23725574, 0.9420281, 0.23726092, 1.0],
-    [0.09641152, 0.8818505, 0.46156737, 1.0],
-    [0.07299513, 0.86450577, 0.49729413, 1.0],
-    [0.09379054, 0.89149207, 0.44322145, 1.0],
-    [0.073260225, 0.87672424, 0.4753815, 1.0],
-    [0.08337323, 0.8791555, 0.46918496, 1.0],
-    [0.045623343, 0.8373231, 0.54480124, 1.0],
-    [0.032659788, 0.82021177, 0.5711268, 1.0],
-    [0.04527973, 0.8494728, 0.5256859, 1.0],
-    [0.034523826, 0.835507, 0.54839414, 1.0],
-    [0.055610746, 0.84838736, 0.526447, 1.0],
-    [0.054707292, 0.85986704, 0.50757843, 1.0],
-    [0.04387656, 0.8422309, 0.5373284, 1.0],
-    [0.09289803, 0.8984643, 0.42910588, 1.0],
-    [0.07680983, 0.8872602, 0.45482916, 1.0],
-    [0.093256764, 0.9015769, 0.42244786, 1.0],
-    [0.08338415, 0.89613897, 0.43586934, 1.0],
-    [0.086213216, 0.8960858, 0.4354279, 1.0],
-    [0.04596964, 0.8554837, 0.5157853, 1.0],
-    [0.039616514, 0.84938085, 0.5262914, 1.0],
-    [0.04089067, 0.8529742, 0.5203489, 1.0],
-    [0.054465335, 0.8670837, 0.49517608, 1.0],
-    [0.046938226, 0.8600344, 0.5080724, 1.0],
-    [0.06265161, 0.8788419, 0.4729817, 1.0],
-    [0.047522414, 0.861965, 0.5047354, 1.0],
-    [0.047625173, 0.8611732, 0.50607556, 1.0],
-    [0.11215204, 0.89133453, 0.4392546, 1.0],
-    [0.10861524, 0.90022963, 0.4216508, 1.0],
-    [0.10999696, 0.89605474, 0.43010062, 1.0],
-    [0.106800996, 0.9065431, 0.4083789, 1.0],
-    [0.105921336, 0.91022736, 0.40033352, 1.0],
-    [0.094032355, 0.9040447, 0.41696662, 1.0],
-    [0.10161437, 0.9071325, 0.40839344, 1.0],
-    [0.07500518, 0.88259983, 0.46410328, 1.0],
-    [0.019166602, 0.7971469, 0.60348123, 1.0],
-    [0.0096433405, 0.77409494, 0.6329961, 1.0],
-    [0.021522215, 0.81388897, 0.58062166, 1.0],
-    [0.012179077, 0.79190964, 0.61051685, 1.0],
-    [0.014856905, 0.7945842, 0.6069722, 1.0],
-    [0.0035852606, 0.7512205, 0.6600416, 1.0],
-    [0.00069179694, 0.72886753, 0.68465424, 1.0],
-    [0.006202088, 0.769945, 0.63808024, 1.0],
-    [0.0033669479, 0.7486194, 0.6629915, 1.0],
-    [0.0027692257, 0.74988794, 0.66155905, 1.0],
-    [0.026578253, 0.8287084, 0.55904907, 1.0],
-    [0.01736052, 0.8079115, 0.5890481, 1.0],
-    [0.035444465, 0.8439263, 0.5352868, 1.0],
-    [0.025008876, 0.82227594, 0.5685392, 1.0],
-    [0.043171197, 0.85519385, 0.51650727, 1.0],
-    [0.02859795, 0.8322653, 0.55363935, 1.0],
-    [0.011448502, 0.7871578, 0.6166454, 1.0],
-    [0.008514917, 0.76662624, 0.6420371, 1.0],
-    [0.019041333, 0.80259645, 0.5962184, 1.0],
-    [0.015927982, 0.7828935, 0.62195194, 1.0],
-    [0.013129658, 0.7850691, 0.61926913, 1.0],
-    [0.011807739, 0.7918377, 0.6106174, 1.0],
-    [0.08376672, 0.8975364, 0.4329106, 1.0],
-    [0.08376664, 0.8975364, 0.43291053, 1.0],
-    [0.074571915, 0.88983077, 0.45015588, 1.0],
-    [0.056232013, 0.8709429, 0.48815626, 1.0],
-    [0.07824801, 0.8910693, 0.44707143, 1.0],
-    [0.07046239, 0.8817757, 0.46637622, 1.0],
-    [0.06935149, 0.883722, 0.46284533, 1.0],
-    [0.075335786, 0.88228256, 0.46465257, 1.0],
-    [0.07533571, 0.88228256, 0.46465254, 1.0],
-    [0.11955067, 0.9189636, 0.3757838, 1.0],
-    [0.09375633, 0.9045496, 0.41593254, 1.0],
-    [0.12190351, 0.9195702, 0.3735374, 1.0],
-    [0.10434701, 0.91001743, 0.4012233, 1.0],
-    [0.13399261, 0.9252097, 0.3550112, 1.0],
-    [0.1350903, 0.92530966, 0.35433412, 1.0],
-    [0.117416665, 0.9178676, 0.37912053, 1.0],
-    [0.08618801, 0.8987237, 0.42996195, 1.0],
-    [0.0868555, 0.89735705, 0.4326736, 1.0],
-    [0.081505984, 0.890617, 0.44739038, 1.0],
-    [0.08441422, 0.89564794, 0.43667945, 1.0],
-    [0.124159, 0.91948545, 0.37300286, 1.0],
-    [0.1160825, 0.9139006, 0.38898677, 1.0],
-    [0.11785851, 0.91189295, 0.39314184, 1.0],
-    [0.14301632, 0.9263168, 0.3485452, 1.0],
-    [0.1268008, 0.9184801, 0.3745875, 1.0],
-    [0.15095177, 0.9264505, 0.34482327, 1.0],
-    [0.1358624, 0.9190012, 0.37010556, 1.0],
-    [0.1301776, 0.9197716, 0.37023497, 1.0],
-    [0.09887973, 0.90212584, 0.41999015, 1.0],
-    [0.08422845, 0.8889726, 0.45014814, 1.0],
-    [0.11112063, 0.90605146, 0.40831733, 1.0],
-    [0.10154482, 0.8952366, 0.43386656, 1.0],
-    [0.12005139, 0.9082493, 0.40083784, 1.0],
-    [0.11410205, 0.9019812, 0.41642597, 1.0],
-    [0.1045268, 0.90087897, 0.4212972, 1.0],
-    [0.09270098, 0.90121937, 0.42333236, 1.0],
-    [0.03867486, 0.8461463, 0.5315455, 1.0],
-    [0.035063528, 0.834971, 0.5491756, 1.0],
-    [0.03865415, 0.83470625, 0.549337, 1.0],
-    [0.04491573, 0.8547416, 0.51710665, 1.0],
-    [0.04155048, 0.8447857, 0.5334892, 1.0],
-    [0.05583461, 0.86329716, 0.5015978, 1.0],
-    [0.045794286, 0.8451314, 0.5325936, 1.0],
-    [0.042368937, 0.8464948, 0.53070843, 1.0],
-    [0.028777976, 0.8162748, 0.5769466, 1.0],
-    [0.025549348, 0.79755765, 0.60270154, 1.0],
-    [0.0363306, 0.8244951, 0.5647017, 1.0],
-    [0.03414703, 0.80763406, 0.5886945, 1.0],
-    [0.04266874, 0.8303592, 0.5555926, 1.0],
-    [0.040449966, 0.81384474, 0.57967293, 1.0],
-    [0.034137297, 0.81528133, 0.57805794, 1.0],
-    [0.07007721, 0.8717431, 0.48492604, 1.0],
-    [0.060929786, 0.85615027, 0.51312196, 1.0],
-    [0.074586436, 0.8702094, 0.4870036, 1.0],
-    [0.07119933, 0.86185676, 0.502129, 1.0],
-    [0.068929, 0.8651164, 0.49681205, 1.0],
-    [0.054520793, 0.8394216, 0.54073936, 1.0],
-    [0.050895624, 0.8225084, 0.5664712, 1.0],
-    [0.0669445, 0.84712327, 0.52716285, 1.0],
-    [0.06420865, 0.8315813, 0.551679, 1.0],
-    [0.058740024, 0.83537495, 0.5465329, 1.0],
-    [0.0789914, 0.87913966, 0.46997222, 1.0],
-    [0.08488907, 0.8786, 0.46995327, 1.0],
-    [0.07977345, 0.8659042, 0.49380764, 1.0],
-    [0.08081236, 0.8746363, 0.47799665, 1.0],
-    [0.07556832, 0.85163206, 0.51866394, 1.0],
-    [0.072782755, 0.8364984, 0.5431143, 1.0],
-    [0.07392023, 0.84413725, 0.5310067, 1.0],
-    [0.057663873, 0.8482184, 0.5264982, 1.0],
-    [0.16265847, 0.93151826, 0.3252938, 1.0],
-    [0.1630778, 0.92939097, 0.33111626, 1.0],
-    [0.15471168, 0.9246314, 0.34802446, 1.0],
-    [0.15976767, 0.9286481, 0.3347939, 1.0],
-    [0.15976763, 0.9286481, 0.3347939, 1.0],
-    [0.0665119, 0.8811724, 0.46809307, 1.0],
-    [0.18914212, 0.9337284, 0.30393538, 1.0],
-    [0.22533141, 0.9380753, 0.26313588, 1.0],
-    [0.2089515, 0.9354143, 0.28520057, 1.0],
-    [0.21527055, 0.933095, 0.2880837, 1.0],
-    [0.20935492, 0.9354354, 0.2848352, 1.0],
-    [0.26313588, 0.9380753, 0.22533141, 1.0],
-    [0.30387643, 0.9337353, 0.18920189, 1.0],
-    [0.28512818, 0.9354184, 0.20903169, 1.0],
-    [0.28799915, 0.9330984, 0.21536912, 1.0],
-    [0.28478095, 0.9354385, 0.20941503, 1.0],
-    [0.24590383, 0.9375834, 0.24590383, 1.0],
-    [0.2294898, 0.9358733, 0.26734906, 1.0],
-    [0.25056282, 0.93511313, 0.25056282, 1.0],
-    [0.23515002, 0.9332029, 0.27172938, 1.0],
-    [0.26726586, 0.9358724, 0.22959049, 1.0],
-    [0.2716788, 0.93320876, 0.23518513, 1.0],
-    [0.25594592, 0.93219346, 0.25594333, 1.0],
-    [0.2505465, 0.9351117, 0.25058421, 1.0],
-    [0.24141486, 0.9302162, 0.27643588, 1.0],
-    [0.2764108, 0.930232, 0.24138236, 1.0],
-    [0.26186872, 0.9288973, 0.26186746, 1.0],
-    [0.2682089, 0.9252718, 0.2682089, 1.0],
-    [0.26174587, 0.92896646, 0.26174486, 1.0],
-    [0.2505471, 0.9351118, 0.2505834, 1.0],
-    [0.2505471, 0.9351118, 0.2505834, 1.0],
-    [0.14512455, 0.9188378, 0.36698234, 1.0],
-    [0.14512448, 0.9188378, 0.3669823, 1.0],
-    [0.10785789, 0.8943026, 0.43426877, 1.0],
-    [0.1356673, 0.91121805, 0.38894212, 1.0],
-    [0.11889414, 0.8997716, 0.4198515, 1.0],
-    [0.14157046, 0.909175, 0.39161032, 1.0],
-    [0.13458395, 0.90203536, 0.41014567, 1.0],
-    [0.12734868, 0.9037069, 0.40877393, 1.0],
-    [0.13550228, 0.91163015, 0.38803294, 1.0],
-    [0.10172791, 0.8849788, 0.45438313, 1.0],
-    [0.096833676, 0.8721977, 0.4794732, 1.0],
-    [0.103490874, 0.87019527, 0.48171547, 1.0],
-    [0.11165879, 0.8876637, 0.44674972, 1.0],
-    [0.10710877, 0.87888294, 0.46485743, 1.0],
-    [0.12761258, 0.8909031, 0.43589753, 1.0],
-    [0.11453268, 0.87725073, 0.4661689, 1.0],
-    [0.10858507, 0.88059014, 0.46127048, 1.0],
-    [0.088848725, 0.8574316, 0.5068697, 1.0],
-    [0.084701456, 0.84229016, 0.5323279, 1.0],
-    [0.100571856, 0.86162895, 0.49747434, 1.0],
-    [0.09731189, 0.84738946, 0.52197856, 1.0],
-    [0.11120574, 0.864787, 0.48966998, 1.0],
-    [0.10790397, 0.850946, 0.51405007, 1.0],
-    [0.098050125, 0.8543617, 0.51034516, 1.0],
-    [0.13505542, 0.8886142, 0.43832052, 1.0],
-    [0.13073124, 0.88092697, 0.45483732, 1.0],
-    [0.13279505, 0.8848201, 0.44660836, 1.0],
-    [0.12556356, 0.86824656, 0.47998103, 1.0],
-    [0.122080274, 0.8548871, 0.50424635, 1.0],
-    [0.12360845, 0.8616631, 0.49219674, 1.0],
-    [0.11472677, 0.87103486, 0.47763577, 1.0],
-    [0.17788748, 0.92939836, 0.32338002, 1.0],
-    [0.15752618, 0.9221639, 0.35326943, 1.0],
-    [0.1819726, 0.926969, 0.32804653, 1.0],
-    [0.1726245, 0.9223209, 0.34572387, 1.0],
-    [0.19331785, 0.9314219, 0.30835286, 1.0],
-    [0.2007989, 0.9290744, 0.3106455, 1.0],
-    [0.18470493, 0.922441, 0.33909687, 1.0],
-    [0.18086267, 0.92665297, 0.3295497, 1.0],
-    [0.14949758, 0.91570544, 0.37300658, 1.0],
-    [0.15903674, 0.9142377, 0.37266183, 1.0],
-    [0.14612284, 0.903556, 0.40278363, 1.0],
-    [0.17716715, 0.9181339, 0.3544601, 1.0],
-    [0.1575015, 0.9133054, 0.3755882, 1.0],
-    [0.20861915, 0.9263603, 0.31358352, 1.0],
-    [0.19840051, 0.92210686, 0.33219928, 1.0],
-    [0.2033899, 0.924322, 0.32289532, 1.0],
-    [0.1688224, 0.91237044, 0.37293327, 1.0],
-    [0.16027048, 0.9048048, 0.39451465, 1.0],
-    [0.18639866, 0.9154656, 0.35662082, 1.0],
-    [0.1733972, 0.9053759, 0.38759252, 1.0],
-    [0.19598158, 0.91240096, 0.35932666, 1.0],
-    [0.18693486, 0.9055684, 0.3807903, 1.0],
-    [0.17833874, 0.9096594, 0.37512022, 1.0],
-    [0.22040515, 0.93004674, 0.29399773, 1.0],
-    [0.22040515, 0.93004674, 0.29399773, 1.0],
-    [0.22787836, 0.9269114, 0.29817206, 1.0],
-    [0.21244754, 0.9212595, 0.32580206, 1.0],
-    [0.23561446, 0.92344606, 0.30287495, 1.0],
-    [0.22559097, 0.9200763, 0.32026312, 1.0],
-    [0.24799156, 0.9263807, 0.28340614, 1.0],
-    [0.25383428, 0.9233467, 0.2880955, 1.0],
-    [0.24595624, 0.9181778, 0.31057236, 1.0],
-    [0.23532803, 0.92316824, 0.30394274, 1.0],
-    [0.20452833, 0.917516, 0.3410756, 1.0],
-    [0.21366851, 0.91377866, 0.34547678, 1.0],
-    [0.20213965, 0.90518826, 0.37386328, 1.0],
-    [0.23221685, 0.9142403, 0.3320238, 1.0],
-    [0.21279311, 0.9130341, 0.34797683, 1.0],
-    [0.19705535, 0.9214599, 0.33478466, 1.0],
-    [0.14000249, 0.8961276, 0.421135, 1.0],
-    [0.14000243, 0.8961275, 0.42113498, 1.0],
-    [0.15104263, 0.8945887, 0.42059138, 1.0],
-    [0.14323455, 0.8829457, 0.44709128, 1.0],
-    [0.16218297, 0.89259666, 0.4206753, 1.0],
-    [0.15711118, 0.88457537, 0.4391384, 1.0],
-    [0.1676583, 0.89948124, 0.40351486, 1.0],
-    [0.17824344, 0.8962552, 0.40614772, 1.0],
-    [0.17128208, 0.88558817, 0.43173605, 1.0],
-    [0.16119681, 0.89118433, 0.4240355, 1.0],
-    [0.13800292, 0.8707325, 0.47199577, 1.0],
-    [0.13428396, 0.85770243, 0.49630067, 1.0],
-    [0.15165253, 0.8728351, 0.4638538, 1.0],
-    [0.14778993, 0.8601762, 0.48811388, 1.0],
-    [0.16555, 0.8741954, 0.45648172, 1.0],
-    [0.16159177, 0.861979, 0.48050013, 1.0],
-    [0.14951082, 0.8665702, 0.47613308, 1.0],
-    [0.1950769, 0.89901465, 0.3920685, 1.0],
-    [0.19507687, 0.8990147, 0.39206848, 1.0],
-    [0.16004506, 0.8894531, 0.4280873, 1.0],
-    [0.2519546, 0.9151828, 0.31457785, 1.0],
-    [0.2519546, 0.9151828, 0.31457785, 1.0],
-    [0.22324951, 0.9096816, 0.35019848, 1.0],
-    [0.21515864, 0.9044395, 0.36836934, 1.0],
-    [0.23929426, 0.9106946, 0.33671018, 1.0],
-    [0.23549831, 0.90314925, 0.35897347, 1.0],
-    [0.2426756, 0.89970994, 0.36280936, 1.0],
-    [0.23097232, 0.9057676, 0.35529822, 1.0],
-    [0.24099085, 0.91090375, 0.3349297, 1.0],
-    [0.18919823, 0.89263046, 0.40916356, 1.0],
-    [0.18463963, 0.8860405, 0.4252534, 1.0],
-    [0.18683842, 0.8893892, 0.41722688, 1.0],
-    [0.17880043, 0.87498266, 0.44992855, 1.0],
-    [0.17464405, 0.8631586, 0.47376874, 1.0],
-    [0.1765453, 0.86917996, 0.46190685, 1.0],
-    [0.2031705, 0.89571464, 0.3954959, 1.0],
-    [0.20265396, 0.88578004, 0.41752276, 1.0],
-    [0.2111951, 0.8819311, 0.4214192, 1.0],
-    [0.22231844, 0.89480966, 0.38715637, 1.0],
-    [0.21604857, 0.8884289, 0.40499026, 1.0],
-    [0.2347939, 0.8937264, 0.38226297, 1.0],
-    [0.22409874, 0.88479173, 0.40856245, 1.0],
-    [0.2160846, 0.8895888, 0.4024165, 1.0],
-    [0.19500145, 0.8754784, 0.4421675, 1.0],
-    [0.1906263, 0.8640621, 0.46589524, 1.0],
-    [0.20760143, 0.8754135, 0.43652362, 1.0],
-    [0.20317791, 0.8643151, 0.46008497, 1.0],
-    [0.22214626, 0.87485087, 0.43044958, 1.0],
-    [0.21887413, 0.86404777, 0.4533383, 1.0],
-    [0.20602348, 0.8699639, 0.44801477, 1.0],
-    [0.19565399, 0.8802307, 0.4323348, 1.0],
-    [0.16974473, 0.90175694, 0.397519, 1.0],
-    [0.46156737, 0.8818505, 0.09641152, 1.0],
-    [0.4432214, 0.89149207, 0.09379048, 1.0],
-    [0.49729413, 0.86450577, 0.07299513, 1.0],
-    [0.4753815, 0.87672424, 0.073260225, 1.0],
-    [0.46918496, 0.8791555, 0.08337323, 1.0],
-    [0.4392546, 0.89133453, 0.11215204, 1.0],
-    [0.4216508, 0.90022963, 0.10861524, 1.0],
-    [0.43010062, 0.89605474, 0.10999696, 1.0],
-    [0.42910588, 0.8984643, 0.09289803, 1.0],
-    [0.42244786, 0.9015769, 0.093256764, 1.0],
-    [0.45482916, 0.8872602, 0.07680983, 1.0],
-    [0.43591195, 0.8961197, 0.08336807, 1.0],
-    [0.43543947, 0.89608055, 0.08621033, 1.0],
-    [0.4083789, 0.9065431, 0.106800996, 1.0],
-    [0.40033352, 0.91022736, 0.105921336, 1.0],
-    [0.41703233, 0.90401727, 0.09400435, 1.0],
-    [0.40841657, 0.90712285, 0.10160753, 1.0],
-    [0.5448013, 0.8373231, 0.045623343, 1.0],
-    [0.526447, 0.84838736, 0.055610746, 1.0],
-    [0.5256859, 0.8494728, 0.04527973, 1.0],
-    [0.5075784, 0.859867, 0.05470729, 1.0],
-    [0.5711268, 0.8202119, 0.03265979, 1.0],
-    [0.54839414, 0.835507, 0.034523826, 1.0],
-    [0.5373285, 0.842231, 0.043876514, 1.0],
-    [0.5157853, 0.8554837, 0.04596964, 1.0],
-    [0.49517608, 0.8670837, 0.054465335, 1.0],
-    [0.4729817, 0.8788419, 0.06265161, 1.0],
-    [0.5262914, 0.84938085, 0.039616514, 1.0],
-    [0.5080724, 0.8600344, 0.046938226, 1.0],
-    [0.5203489, 0.8529742, 0.04089067, 1.0],
-    [0.5047354, 0.86196506, 0.04752242, 1.0],
-    [0.50607556, 0.8611732, 0.047625173, 1.0],
-    [0.46411043, 0.8825961, 0.075004265, 1.0],
-    [0.43276137, 0.8976003, 0.08385182, 1.0],
-    [0.43276146, 0.89760035, 0.08385192, 1.0],
-    [0.3758688, 0.9189365, 0.11949203, 1.0],
-    [0.35515213, 0.9251703, 0.13389106, 1.0],
-    [0.37356663, 0.919562, 0.12187547, 1.0],
-    [0.3543612, 0.9253031, 0.13506414, 1.0],
-    [0.41584247, 0.9045855, 0.09380996, 1.0],
-    [0.4012233, 0.9100174, 0.104347005, 1.0],
-    [0.37915346, 0.917857, 0.11739352, 1.0],
-    [0.37312868, 0.9194489, 0.12405051, 1.0],
-    [0.34865433, 0.9262877, 0.14293787, 1.0],
-    [0.34480366, 0.92645013, 0.150998, 1.0],
-    [0.38909188, 0.9138654, 0.116006166, 1.0],
-    [0.37472993, 0.91843385, 0.12671539, 1.0],
-    [0.3933607, 0.91181606, 0.117723286, 1.0],
-    [0.37021193, 0.91896874, 0.13579218, 1.0],
-    [0.37034518, 0.9197379, 0.13010228, 1.0],
-    [0.45003074, 0.88988817, 0.074641325, 1.0],
-    [0.44692692, 0.89113563, 0.07831796, 1.0],
-    [0.48815626, 0.8709429, 0.056232013, 1.0],
-    [0.46620718, 0.88185936, 0.07053381, 1.0],
-    [0.4627358, 0.88377523, 0.069404654, 1.0],
-    [0.42975828, 0.89880943, 0.08631015, 1.0],
-    [0.4325866, 0.8973947, 0.08689957, 1.0],
-    [0.44710687, 0.8907476, 0.08163437, 1.0],
-    [0.43648812, 0.8957315, 0.084517114, 1.0],
-    [0.46465433, 0.8822817, 0.07533543, 1.0],
-    [0.46465433, 0.8822817, 0.07533543, 1.0],
-    [0.41999015, 0.90212584, 0.09887973, 1.0],
-    [0.40841863, 0.9060118, 0.11107128, 1.0],
-    [0.40093827, 0.9082114, 0.120002426, 1.0],
-    [0.45014906, 0.8889721, 0.08422827, 1.0],
-    [0.43388942, 0.8952295, 0.10150937, 1.0],
-    [0.4164601, 0.9019731, 0.11404173, 1.0],
-    [0.4213413, 0.90086186, 0.10449647, 1.0],
-    [0.4232969, 0.90123415, 0.09271862, 1.0],
-    [0.32542285, 0.9314889, 0.16256852, 1.0],
-    [0.33111626, 0.92939097, 0.1630778, 1.0],
-    [0.3480387, 0.92463416, 0.15466347, 1.0],
-    [0.3348415, 0.92863834, 0.15972504, 1.0],
-    [0.3348415, 0.92863834, 0.15972504, 1.0],
-    [0.60348123, 0.7971469, 0.019166555, 1.0],
-    [0.58062166, 0.81388897, 0.021522215, 1.0],
-    [0.6329961, 0.77409494, 0.009643296, 1.0],
-    [0.61051685, 0.79190964, 0.012179077, 1.0],
-    [0.6069722, 0.7945842, 0.014856905, 1.0],
-    [0.55904907, 0.8287084, 0.026578253, 1.0],
-    [0.53528684, 0.84392637, 0.03544447, 1.0],
-    [0.51650727, 0.85519385, 0.043171197, 1.0],
-    [0.5890481, 0.8079115, 0.01736056, 1.0],
-    [0.5685392, 0.82227594, 0.025008876, 1.0],
-    [0.55363935, 0.8322653, 0.02859795, 1.0],
-    [0.6600416, 0.7512205, 0.0035852606, 1.0],
-    [0.6380802, 0.7699449, 0.0062020877, 1.0],
-    [0.68465424, 0.72886753, 0.00069179694, 1.0],
-    [0.66299146, 0.7486193, 0.0033669884, 1.0],
-    [0.66155905, 0.74988794, 0.0027692257, 1.0],
-    [0.6166454, 0.7871578, 0.011448502, 1.0],
-    [0.5962184, 0.80259645, 0.019041333, 1.0],
-    [0.6420371, 0.76662624, 0.008514957, 1.0],
-    [0.6219518, 0.7828934, 0.015927939, 1.0],
-    [0.61926913, 0.7850691, 0.013129658, 1.0],
-    [0.6106174, 0.7918377, 0.011807739, 1.0],
-    [0.5315455, 0.8461463, 0.03867486, 1.0],
-    [0.51710665, 0.8547416, 0.04491573, 1.0],
-    [0.5015979, 0.8632972, 0.055834617, 1.0],
-    [0.54922444, 0.83494025, 0.035033885, 1.0],
-    [0.53356767, 0.8447387, 0.04149828, 1.0],
-    [0.54941493, 0.83465755, 0.03860042, 1.0],
-    [0.5326385, 0.84510493, 0.045760266, 1.0],
-    [0.53074425, 0.84647346, 0.042345095, 1.0],
-    [0.4850077, 0.8716997, 0.07005048, 1.0],
-    [0.4870036, 0.8702094, 0.074586436, 1.0],
-    [0.51312196, 0.8561504, 0.06092983, 1.0],
-    [0.502129, 0.86185676, 0.07119933, 1.0],
-    [0.49683207, 0.86510545, 0.06892341, 1.0],
-    [0.47010916, 0.8790707, 0.07894323, 1.0],
-    [0.46995324, 0.8786, 0.08488907, 1.0],
-    [0.49380764, 0.8659042, 0.07977345, 1.0],
-    [0.47804138, 0.8746131, 0.0807984, 1.0],
-    [0.57694656, 0.81627476, 0.028777974, 1.0],
-    [0.5647016, 0.82449496, 0.036330596, 1.0],
-    [0.55559254, 0.83035916, 0.042668737, 1.0],
-    [0.6027015, 0.79755753, 0.025549347, 1.0],
-    [0.5886945, 0.80763406, 0.03414703, 1.0],
-    [0.57967293, 0.81384474, 0.040449966, 1.0],
-    [0.57805794, 0.81528133, 0.034137297, 1.0],
-    [0.54073936, 0.8394216, 0.054520793, 1.0],
-    [0.52716285, 0.84712327, 0.06694447, 1.0],
-    [0.5664712, 0.8225084, 0.050895624, 1.0],
-    [0.551679, 0.8315813, 0.06420865, 1.0],
-    [0.5465329, 0.83537495, 0.058740024, 1.0],
-    [0.51866394, 0.85163206, 0.07556832, 1.0],
-    [0.5431143, 0.8364984, 0.072782755, 1.0],
-    [0.5310067, 0.84413725, 0.07392016, 1.0],
-    [0.5265138, 0.84820914, 0.057656795, 1.0],
-    [0.46810228, 0.8811681, 0.06650614, 1.0],
-    [0.36712858, 0.91879857, 0.14500254, 1.0],
-    [0.36712858, 0.91879857, 0.14500254, 1.0],
-    [0.38905358, 0.91117513, 0.13563548, 1.0],
-    [0.39166787, 0.9091483, 0.14158282, 1.0],
-    [0.43443808, 0.89423424, 0.10774438, 1.0],
-    [0.42002943, 0.89970195, 0.11879235, 1.0],
-    [0.4102425, 0.901997, 0.13454573, 1.0],
-    [0.4088944, 0.90366024, 0.12729332, 1.0],
-    [0.38816696, 0.91158646, 0.13541242, 1.0],
-    [0.3234015, 0.92939454, 0.17786863, 1.0],
-    [0.30838752, 0.93141717, 0.1932854, 1.0],
-    [0.3281697, 0.9269488, 0.18185341, 1.0],
-    [0.31061953, 0.92908037, 0.20081164, 1.0],
-    [0.35343245, 0.9221216, 0.15740809, 1.0],
-    [0.34591198, 0.92226946, 0.17252237, 1.0],
-    [0.33918825, 0.92241734, 0.1846555, 1.0],
-    [0.32963437, 0.9266353, 0.18079896, 1.0],
-    [0.29383567, 0.9300621, 0.22055604, 1.0],
-    [0.2938356, 0.9300622, 0.22055599, 1.0],
-    [0.31342018, 0.9263831, 0.20876338, 1.0],
-    [0.33219928, 0.92210686, 0.19840051, 1.0],
-    [0.32281345, 0.9243351, 0.20346045, 1.0],
-    [0.29810095, 0.9269113, 0.2279722, 1.0],
-    [0.28340614, 0.9263807, 0.24799156, 1.0],
-    [0.3028924, 0.92342967, 0.2356566, 1.0],
-    [0.2880955, 0.9233467, 0.25383428, 1.0],
-    [0.3258123, 0.9212484, 0.21248016, 1.0],
-    [0.32036394, 0.9200438, 0.22558022, 1.0],
-    [0.31065157, 0.9181535, 0.24594672, 1.0],
-    [0.3039631, 0.9231565, 0.23534775, 1.0],
-    [0.373191, 0.91563934, 0.14944229, 1.0],
-    [0.37265298, 0.91423315, 0.1590831, 1.0],
-    [0.3543893, 0.91814536, 0.17724934, 1.0],
-    [0.40288067, 0.90351886, 0.14608519, 1.0],
-    [0.37563777, 0.9132835, 0.15751007, 1.0],
-    [0.3728619, 0.9123847, 0.16890293, 1.0],
-    [0.35655203, 0.91547495, 0.18648392, 1.0],
-    [0.3592601, 0.91240823, 0.19607002, 1.0],
-    [0.3946107, 0.9047695, 0.16023298, 1.0],
-    [0.38759252, 0.9053759, 0.1733972, 1.0],
-    [0.38088325, 0.9055364, 0.18690051, 1.0],
-    [0.3751172, 0.9096549, 0.17836761, 1.0],
-    [0.3410099, 0.9175204, 0.20461825, 1.0],
-    [0.34550044, 0.91377074, 0.21366455, 1.0],
-    [0.3321439, 0.9142109, 0.23216143, 1.0],
-    [0.37395528, 0.90515786, 0.2021053, 1.0],
-    [0.34802052, 0.91301787, 0.2127914, 1.0],
-    [0.33477706, 0.9214569, 0.19708276, 1.0],
-    [0.3145946, 0.91516495, 0.25199854, 1.0],
-    [0.3145946, 0.91516495, 0.25199854, 1.0],
-    [0.3501887, 0.9096973, 0.22320077, 1.0],
-    [0.33671212, 0.9106942, 0.23929283, 1.0],
-    [0.36836934, 0.9044395, 0.21515867, 1.0],
-    [0.35897347, 0.90314925, 0.23549831, 1.0],
-    [0.36280936, 0.89970994, 0.2426756, 1.0],
-    [0.35529643, 0.90577096, 0.2309621, 1.0],
-    [0.33493644, 0.91089636, 0.2410087, 1.0],
-    [0.45448193, 0.8849317, 0.10169595, 1.0],
-    [0.44684795, 0.88761836, 0.1116268, 1.0],
-    [0.43601042, 0.89086246, 0.12751064, 1.0],
-    [0.4795335, 0.8721724, 0.09676233, 1.0],
-    [0.46495345, 0.87884706, 0.106986545, 1.0],
-    [0.48181206, 0.8701562, 0.103369124, 1.0],
-    [0.46630055, 0.87719303, 0.114438936, 1.0],
-    [0.4613697, 0.88054806, 0.10850443, 1.0],
-    [0.43863067, 0.88849264, 0.1348475, 1.0],
-    [0.4550268, 0.8808362, 0.13068375, 1.0],
-    [0.44685835, 0.8847129, 0.13266774, 1.0],
-    [0.5068697, 0.8574316, 0.088848725, 1.0],
-    [0.49747434, 0.86162895, 0.100571826, 1.0],
-    [0.48966998, 0.864787, 0.11120574, 1.0],
-    [0.5323279, 0.84229016, 0.084701456, 1.0],
-    [0.52197856, 0.84738946, 0.09731189, 1.0],
-    [0.51405007, 0.850946, 0.10790397, 1.0],
-    [0.51034516, 0.8543617, 0.098050125, 1.0],
-    [0.47998103, 0.86824656, 0.12556353, 1.0],
-    [0.50424635, 0.8548871, 0.122080214, 1.0],
-    [0.49219674, 0.8616631, 0.12360841, 1.0],
-    [0.47772244, 0.8709936, 0.11467916, 1.0],
-    [0.4213151, 0.8960701, 0.1398283, 1.0],
-    [0.4213151, 0.8960701, 0.1398283, 1.0],
-    [0.42074177, 0.8945364, 0.15093395, 1.0],
-    [0.40351486, 0.89948124, 0.1676583, 1.0],
-    [0.42067537, 0.8925967, 0.16218299, 1.0],
-    [0.40621457, 0.89621586, 0.17828824, 1.0],
-    [0.4471854, 0.88290197, 0.14321047, 1.0],
-    [0.4391384, 0.88457537, 0.15711118, 1.0],
-    [0.43182775, 0.8855478, 0.17125951, 1.0],
-    [0.4240923, 0.8911602, 0.16118036, 1.0],
-    [0.3920844, 0.89897966, 0.19520567, 1.0],
-    [0.3920844, 0.89897966, 0.19520567, 1.0],
-    [0.47199577, 0.8707325, 0.13800292, 1.0],
-    [0.4638538, 0.8728351, 0.15165253, 1.0],
-    [0.45648172, 0.8741954, 0.16555, 1.0],
-    [0.49630067, 0.85770243, 0.13428396, 1.0],
-    [0.48811388, 0.8601762, 0.14778993, 1.0],
-    [0.48050013, 0.861979, 0.16159177, 1.0],
-    [0.47613308, 0.8665702, 0.14951079, 1.0],
-    [0.42814413, 0.8894287, 0.16002917, 1.0],
-    [0.4093015, 0.8925469, 0.1892944, 1.0],
-    [0.42543557, 0.8859625, 0.18459383, 1.0],
-    [0.4173865, 0.8893089, 0.18686377, 1.0],
-    [0.39550814, 0.8956913, 0.2032495, 1.0],
-    [0.3871564, 0.8948097, 0.22231847, 1.0],
-    [0.38226297, 0.8937264, 0.2347939, 1.0],
-    [0.41753712, 0.8857532, 0.20274137, 1.0],
-    [0.40489846, 0.88842714, 0.2162275, 1.0],
-    [0.42132598, 0.8819331, 0.2113724, 1.0],
-    [0.40850914, 0.8847891, 0.22420648, 1.0],
-    [0.40238687, 0.88958025, 0.21617529, 1.0],
-    [0.44992852, 0.8749827, 0.17880042, 1.0],
-    [0.47376874, 0.8631586, 0.17464402, 1.0],
-    [0.46190682, 0.8691799, 0.1765453, 1.0],
-    [0.4421675, 0.8754784, 0.19500145, 1.0],
-    [0.43652362, 0.8754135, 0.20760143, 1.0],
-    [0.43044958, 0.8748509, 0.22214626, 1.0],
-    [0.46589524, 0.8640621, 0.1906263, 1.0],
-    [0.46008497, 0.8643151, 0.20317791, 1.0],
-    [0.4533383, 0.86404777, 0.21887416, 1.0],
-    [0.44801477, 0.8699639, 0.20602348, 1.0],
-    [0.4323658, 0.8802085, 0.19568528, 1.0],
-    [0.39756677, 0.9017387, 0.16972901, 1.0],
-    [0.27751237, 0.91976833, 0.27751237, 1.0],
-    [0.26320982, 0.9181858, 0.29606676, 1.0],
-    [0.29606676, 0.9181858, 0.26320982, 1.0],
-    [0.2787752, 0.91900426, 0.2787752, 1.0],
-    [0.27877516, 0.91900426, 0.27877516, 1.0],
-    [0.28232256, 0.91688675, 0.28215718, 1.0],
-    [0.27148378, 0.9133844, 0.30335745, 1.0],
-    [0.28008834, 0.9082039, 0.31099215, 1.0],
-    [0.3034409, 0.9133801, 0.27140462, 1.0],
-    [0.29253545, 0.9104613, 0.29237506, 1.0],
-    [0.31099215, 0.9082039, 0.28008834, 1.0],
-    [0.29736763, 0.9072735, 0.2973677, 1.0],
-    [0.29098728, 0.9114236, 0.2909182, 1.0],
-    [0.2591319, 0.907767, 0.32986322, 1.0],
-    [0.2507692, 0.90431976, 0.3454279, 1.0],
-    [0.27158532, 0.90563446, 0.3256803, 1.0],
-    [0.25816333, 0.9001153, 0.350919, 1.0],
-    [0.28977498, 0.90197885, 0.3201006, 1.0],
-    [0.28386903, 0.89570576, 0.3422419, 1.0],
-    [0.26873225, 0.9028986, 0.33549538, 1.0],
-    [0.29005158, 0.8920305, 0.34662893, 1.0],
-    [0.29005155, 0.89203054, 0.3466289, 1.0],
-    [0.32986322, 0.907767, 0.2591319, 1.0],
-    [0.3256803, 0.90563446, 0.27158532, 1.0],
-    [0.32010067, 0.90197897, 0.28977498, 1.0],
-    [0.3454279, 0.90431976, 0.2507692, 1.0],
-    [0.35091898, 0.9001153, 0.2581633, 1.0],
-    [0.34221432, 0.89569604, 0.2839331, 1.0],
-    [0.33549038, 0.9028972, 0.26874292, 1.0],
-    [0.31220555, 0.8972163, 0.31229886, 1.0],
-    [0.29935065, 0.89541173, 0.3295864, 1.0],
-    [0.32943153, 0.89541924, 0.29949844, 1.0],
-    [0.31354347, 0.89626896, 0.31367564, 1.0],
-    [0.34658438, 0.89201313, 0.29015806, 1.0],
-    [0.34658438, 0.89201313, 0.29015806, 1.0],
-    [0.31705892, 0.8937839, 0.3172127, 1.0],
-    [0.30627596, 0.8906933, 0.33594707, 1.0],
-    [0.33585542, 0.8906978, 0.30636343, 1.0],
-    [0.33107284, 0.88361853, 0.33107284, 1.0],
-    [0.32245022, 0.8899428, 0.32253295, 1.0],
-    [0.30905744, 0.89941025, 0.30910298, 1.0],
-    [0.25638425, 0.89063853, 0.37553957, 1.0],
-    [0.24680379, 0.8828667, 0.39954257, 1.0],
-    [0.27128917, 0.89101434, 0.36399958, 1.0],
-    [0.25795898, 0.88844204, 0.37964186, 1.0],
-    [0.23962863, 0.8735479, 0.4236651, 1.0],
-    [0.23462284, 0.86320806, 0.4470167, 1.0],
-    [0.2369844, 0.868495, 0.435379, 1.0],
-    [0.26414487, 0.88615406, 0.38073403, 1.0],
-    [0.25889817, 0.8813181, 0.3952851, 1.0],
-    [0.27821907, 0.88709766, 0.3683094, 1.0],
-    [0.2743205, 0.87894326, 0.3901373, 1.0],
-    [0.26879126, 0.8835232, 0.3835859, 1.0],
-    [0.2516501, 0.872254, 0.41933897, 1.0],
-    [0.24659146, 0.8621717, 0.4425525, 1.0],
-    [0.27021566, 0.86971545, 0.41301167, 1.0],
-    [0.26657698, 0.85980564, 0.43551245, 1.0],
-    [0.2586101, 0.8662, 0.4275727, 1.0],
-    [0.30265772, 0.8824315, 0.36015698, 1.0],
-    [0.29909912, 0.8739708, 0.38303357, 1.0],
-    [0.3061229, 0.86911047, 0.3885046, 1.0],
-    [0.32526776, 0.8774328, 0.3525798, 1.0],
-    [0.31323537, 0.87368405, 0.3722363, 1.0],
-    [0.33077478, 0.87347555, 0.3572512, 1.0],
-    [0.31963643, 0.8691853, 0.37729222, 1.0],
-    [0.31369385, 0.8744355, 0.37007934, 1.0],
-    [0.28978953, 0.86638314, 0.4066967, 1.0],
-    [0.28386414, 0.85718536, 0.42971432, 1.0],
-    [0.30121773, 0.86408025, 0.40327802, 1.0],
-    [0.29518563, 0.85512924, 0.4261684, 1.0],
-    [0.3161305, 0.8607347, 0.39899534, 1.0],
-    [0.31217608, 0.8515905, 0.4211171, 1.0],
-    [0.29960737, 0.85942584, 0.4142738, 1.0],
-    [0.2719603, 0.8746135, 0.40135857, 1.0],
-    [0.37553957, 0.89063853, 0.25638425, 1.0],
-    [0.36399958, 0.89101434, 0.27128917, 1.0],
-    [0.39954257, 0.8828667, 0.24680379, 1.0],
-    [0.37964186, 0.88844204, 0.25795898, 1.0],
-    [0.38073403, 0.88615406, 0.26414487, 1.0],
-    [0.3683094, 0.88709766, 0.27821907, 1.0],
-    [0.39528507, 0.88131803, 0.25889814, 1.0],
-    [0.3901373, 0.87894326, 0.2743205, 1.0],
-    [0.3835859, 0.8835232, 0.26879126, 1.0],
-    [0.36015698, 0.8824315, 0.30265772, 1.0],
-    [0.3525798, 0.8774328, 0.32526776, 1.0],
-    [0.3572512, 0.87347555, 0.33077478, 1.0],
-    [0.38301176, 0.8739586, 0.29916278, 1.0],
-    [0.37220213, 0.8736603, 0.31334186, 1.0],
-    [0.38846928, 0.8690889, 0.3062288, 1.0],
-    [0.37727228, 0.86917055, 0.31970018, 1.0],
-    [0.37006372, 0.8744246, 0.3137426, 1.0],
-    [0.42366514, 0.873548, 0.23962866, 1.0],
-    [0.44701675, 0.8632081, 0.23462287, 1.0],
-    [0.435379, 0.868495, 0.2369844, 1.0],
-    [0.41933897, 0.872254, 0.2516501, 1.0],
-    [0.4130116, 0.8697154, 0.27021563, 1.0],
-    [0.44255248, 0.86217165, 0.24659143, 1.0],
-    [0.43551245, 0.85980564, 0.26657698, 1.0],
-    [0.4275727, 0.8662, 0.25861007, 1.0],
-    [0.4066967, 0.86638314, 0.28978953, 1.0],
-    [0.40327802, 0.86408025, 0.30121773, 1.0],
-    [0.39899534, 0.8607347, 0.3161305, 1.0],
-    [0.42971432, 0.85718536, 0.28386414, 1.0],
-    [0.4261684, 0.85512924, 0.29518563, 1.0],
-    [0.42111713, 0.8515906, 0.3121761, 1.0],
-    [0.41427377, 0.85942584, 0.29960737, 1.0],
-    [0.40135545, 0.87461245, 0.2719683, 1.0],
-    [0.34068215, 0.87628263, 0.34068215, 1.0],
-    [0.34706396, 0.87125945, 0.34706396, 1.0],
-    [0.34307006, 0.863029, 0.370788, 1.0],
-    [0.370788, 0.863029, 0.3430701, 1.0],
-    [0.36550552, 0.856044, 0.36550552, 1.0],
-    [0.35332423, 0.8662125, 0.35332423, 1.0],
-    [0.3385561, 0.8549695, 0.39294642, 1.0],
-    [0.33453575, 0.8461457, 0.41487756, 1.0],
-    [0.36073112, 0.8483148, 0.38760173, 1.0],
-    [0.3564181, 0.83984935, 0.40941322, 1.0],
-    [0.3474814, 0.8475415, 0.40116072, 1.0],
-    [0.39294642, 0.8549695, 0.3385561, 1.0],
-    [0.38760173, 0.8483148, 0.36073112, 1.0],
-    [0.41487756, 0.8461457, 0.33453572, 1.0],
-    [0.40941322, 0.83984935, 0.3564181, 1.0],
-    [0.40116072, 0.8475415, 0.3474814, 1.0],
-    [0.3827363, 0.8408482, 0.3827363, 1.0],
-    [0.37812334, 0.8327393, 0.40443546, 1.0],
-    [0.40443546, 0.8327393, 0.37812334, 1.0],
-    [0.39973438, 0.8248787, 0.39973438, 1.0],
-    [0.39120898, 0.83301324, 0.39120898, 1.0],
-    [0.3730213, 0.84953535, 0.3730213, 1.0],
-    [0.3242707, 0.8886449, 0.3242819, 1.0],
-    [0.250565, 0.9351133, 0.25056002, 1.0],
-    [0.00067114277, 0.7071066, 0.7071066, 1.0],
-    [0.0032731618, 0.6858264, 0.7277579, 1.0],
-    [0.0032731618, 0.7277579, 0.6858264, 1.0],
-    [0.005695819, 0.7070953, 0.7070953, 1.0],
-    [0.0026112322, 0.7071044, 0.7071044, 1.0],
-    [0.008290079, 0.66546845, 0.7463799, 1.0],
-    [0.015529164, 0.645866, 0.76329273, 1.0],
-    [0.010566617, 0.68706405, 0.72652, 1.0],
-    [0.017656395, 0.6676983, 0.7442226, 1.0],
-    [0.0124643445, 0.6666241, 0.7452898, 1.0],
-    [0.008290079, 0.7463799, 0.66546845, 1.0],
-    [0.01056658, 0.72652, 0.68706405, 1.0],
-    [0.015529164, 0.76329273, 0.645866, 1.0],
-    [0.017656432, 0.7442226, 0.6676983, 1.0],
-    [0.0124643445, 0.7452898, 0.6666241, 1.0],
-    [0.015192695, 0.7070252, 0.7070252, 1.0],
-    [0.02206349, 0.68793833, 0.7254336, 1.0],
-    [0.02206349, 0.7254336, 0.68793833, 1.0],
-    [0.02968503, 0.7067952, 0.7067952, 1.0],
-    [0.035672866, 0.7066567, 0.7066567, 1.0],
-    [0.024290135, 0.70689815, 0.70689815, 1.0],
-    [0.010543661, 0.70706743, 0.70706743, 1.0],
-    [0.024951976, 0.62663925, 0.77890986, 1.0],
-    [0.03338471, 0.6127081, 0.78960395, 1.0],
-    [0.026810218, 0.6488309, 0.7604602, 1.0],
-    [0.033942472, 0.6367796, 0.7702984, 1.0],
-    [0.039573435, 0.6037251, 0.79620975, 1.0],
-    [0.039943915, 0.6278223, 0.7773312, 1.0],
-    [0.03261925, 0.62614167, 0.7790267, 1.0],
-    [0.04984866, 0.59037435, 0.8055887, 1.0],
-    [0.06294445, 0.5757364, 0.81520885, 1.0],
-    [0.05121387, 0.6128897, 0.788507, 1.0],
-    [0.063059896, 0.59939307, 0.79796696, 1.0],
-    [0.056393217, 0.5946565, 0.8019997, 1.0],
-    [0.031179141, 0.668483, 0.74307346, 1.0],
-    [0.0350311, 0.65192014, 0.75747806, 1.0],
-    [0.033062574, 0.68968105, 0.72335815, 1.0],
-    [0.036488395, 0.66856545, 0.7427575, 1.0],
-    [0.04097829, 0.65237105, 0.756791, 1.0],
-    [0.03801082, 0.689443, 0.7233419, 1.0],
-    [0.047845643, 0.671152, 0.7397742, 1.0],
-    [0.037073553, 0.6703755, 0.7410953, 1.0],
-    [0.05489231, 0.6340824, 0.7713146, 1.0],
-    [0.06440693, 0.6229123, 0.77963585, 1.0],
-    [0.060717903, 0.6525423, 0.7553157, 1.0],
-    [0.065854214, 0.6366577, 0.7683294, 1.0],
-    [0.06125007, 0.636623, 0.768739, 1.0],
-    [0.071382225, 0.56731415, 0.8204019, 1.0],
-    [0.07130119, 0.59096164, 0.8035425, 1.0],
-    [0.07110876, 0.5792645, 0.81203216, 1.0],
-    [0.07238215, 0.6143907, 0.78567475, 1.0],
-    [0.06783759, 0.65220326, 0.7550026, 1.0],
-    [0.074131235, 0.63704145, 0.7672567, 1.0],
-    [0.071096815, 0.63469595, 0.7694844, 1.0],
-    [0.053009026, 0.623767, 0.77981067, 1.0],
-    [0.024951976, 0.77890986, 0.62663925, 1.0],
-    [0.026810218, 0.7604602, 0.6488309, 1.0],
-    [0.03338471, 0.7896038, 0.6127081, 1.0],
-    [0.033942472, 0.7702984, 0.6367796, 1.0],
-    [0.03957347, 0.79620975, 0.6037251, 1.0],
-    [0.039943915, 0.7773312, 0.6278223, 1.0],
-    [0.03261925, 0.7790267, 0.62614167, 1.0],
-    [0.031206371, 0.74312013, 0.6684299, 1.0],
-    [0.03306257, 0.72335804, 0.68968105, 1.0],
-    [0.035081197, 0.75755113, 0.65183246, 1.0],
-    [0.036536, 0.7428336, 0.6684784, 1.0],
-    [0.03801082, 0.7233419, 0.689443, 1.0],
-    [0.041009795, 0.75683385, 0.65231955, 1.0],
-    [0.047845643, 0.7397742, 0.671152, 1.0],
-    [0.037095204, 0.7411301, 0.67033577, 1.0],
-    [0.04984866, 0.8055887, 0.59037435, 1.0],
-    [0.05121387, 0.788507, 0.6128897, 1.0],
-    [0.06294449, 0.81520885, 0.5757364, 1.0],
-    [0.06305987, 0.79796696, 0.5993931, 1.0],
-    [0.05639325, 0.8019997, 0.5946565, 1.0],
-    [0.05489231, 0.7713146, 0.6340824, 1.0],
-    [0.06071791, 0.7553157, 0.65254235, 1.0],
-    [0.06440693, 0.77963585, 0.6229123, 1.0],
-    [0.065854214, 0.7683294, 0.6366577, 1.0],
-    [0.06125007, 0.7687389, 0.636623, 1.0],
-    [0.071382225, 0.8204019, 0.56731415, 1.0],
-    [0.07130119, 0.8035425, 0.59096164, 1.0],
-    [0.07110882, 0.81203216, 0.5792645, 1.0],
-    [0.06783759, 0.7550026, 0.65220326, 1.0],
-    [0.072382115, 0.78567475, 0.6143906, 1.0],
-    [0.074131235, 0.7672567, 0.63704145, 1.0],
-    [0.071096815, 0.7694844, 0.634696, 1.0],
-    [0.053013675, 0.7798154, 0.6237607, 1.0],
-    [0.04560651, 0.706371, 0.706371, 1.0],
-    [0.057737358, 0.6889521, 0.72250354, 1.0],
-    [0.05774294, 0.72260284, 0.6888476, 1.0],
-    [0.06198174, 0.70583296, 0.70566136, 1.0],
-    [0.05540976, 0.7060678, 0.70597297, 1.0],
-    [0.06284994, 0.6734191, 0.7365844, 1.0],
-    [0.06284988, 0.6734191, 0.7365844, 1.0],
-    [0.06290126, 0.7366577, 0.67333424, 1.0],
-    [0.0629012, 0.7366577, 0.6733343, 1.0],
-    [0.06606581, 0.68889993, 0.7218394, 1.0],
-    [0.06607492, 0.72200155, 0.6887291, 1.0],
-    [0.06828627, 0.70550704, 0.70540535, 1.0],
-    [0.06650364, 0.70561385, 0.70546895, 1.0],
-    [0.06974728, 0.67291313, 0.736426, 1.0],
-    [0.0851837, 0.6547912, 0.75099415, 1.0],
-    [0.07898424, 0.688827, 0.72061, 1.0],
-    [0.08966277, 0.6763648, 0.7310891, 1.0],
-    [0.09484417, 0.65489244, 0.7497469, 1.0],
-    [0.09751877, 0.66796106, 0.73777914, 1.0],
-    [0.085624166, 0.66933984, 0.7380058, 1.0],
-    [0.06977902, 0.736469, 0.67286277, 1.0],
-    [0.07898424, 0.72061, 0.688827, 1.0],
-    [0.08518371, 0.75099427, 0.65479124, 1.0],
-    [0.0896628, 0.7310891, 0.6763647, 1.0],
-    [0.09484417, 0.7497469, 0.65489244, 1.0],
-    [0.09751883, 0.73777914, 0.66796106, 1.0],
-    [0.08562981, 0.7380126, 0.66933167, 1.0],
-    [0.08945572, 0.7042718, 0.7042718, 1.0],
-    [0.09296454, 0.6884683, 0.7192837, 1.0],
-    [0.09296454, 0.7192837, 0.6884683, 1.0],
-    [0.09714705, 0.70368344, 0.7038409, 1.0],
-    [0.10617148, 0.6876729, 0.7182155, 1.0],
-    [0.106167674, 0.71814, 0.6877524, 1.0],
-    [0.114443414, 0.70238435, 0.7025376, 1.0],
-    [0.09957649, 0.7035588, 0.70362604, 1.0],
-    [0.0725451, 0.7052681, 0.70521927, 1.0],
-    [0.11993912, 0.6868716, 0.71681386, 1.0],
-    [0.11993912, 0.71681386, 0.6868716, 1.0],
-    [0.12313003, 0.7017261, 0.7017261, 1.0],
-    [0.12076258, 0.7019317, 0.7019317, 1.0],
-    [0.12076258, 0.7019317, 0.7019317, 1.0],
-    [0.05329785, 0.70610815, 0.7060953, 1.0],
-    [0.083133176, 0.5564752, 0.8266947, 1.0],
-    [0.0955791, 0.5460226, 0.8323005, 1.0],
-    [0.08403648, 0.5789917, 0.8109911, 1.0],
-    [0.09531225, 0.5693258, 0.8165683, 1.0],
-    [0.106053166, 0.5378391, 0.8363503, 1.0],
-    [0.10557163, 0.5611217, 0.8209733, 1.0],
-    [0.094592355, 0.558295, 0.82423234, 1.0],
-    [0.12008022, 0.5277299, 0.8408816, 1.0],
-    [0.11945605, 0.55084527, 0.82601434, 1.0],
-    [0.11957236, 0.5393824, 0.83352816, 1.0],
-    [0.08831719, 0.5986486, 0.79612803, 1.0],
-    [0.09576064, 0.58378744, 0.8062395, 1.0],
-    [0.0893178, 0.62311023, 0.7770174, 1.0],
-    [0.09675506, 0.5989571, 0.7949143, 1.0],
-    [0.1049526, 0.5845381, 0.8045496, 1.0],
-    [0.09843013, 0.614823, 0.78249866, 1.0],
-    [0.113110855, 0.6022454, 0.79025716, 1.0],
-    [0.09775076, 0.60095346, 0.7932841, 1.0],
-    [0.12023377, 0.57340574, 0.8104011, 1.0],
-    [0.12140797, 0.5876677, 0.7999417, 1.0],
-    [0.1207443, 0.5805808, 0.8051998, 1.0],
-    [0.10725272, 0.5700257, 0.8145967, 1.0],
-    [0.091992505, 0.64011115, 0.7627549, 1.0],
-    [0.10068951, 0.639699, 0.76200193, 1.0],
-    [0.115117416, 0.6256507, 0.7715629, 1.0],
-    [0.111268885, 0.6550521, 0.74734586, 1.0],
-    [0.11822676, 0.6403871, 0.7588984, 1.0],
-    [0.10720022, 0.6402505, 0.7606493, 1.0],
-    [0.11537427, 0.6727376, 0.7308303, 1.0],
-    [0.11537419, 0.6727376, 0.73083025, 1.0],
-    [0.11084002, 0.65681905, 0.7458574, 1.0],
-    [0.13213481, 0.5198519, 0.84397537, 1.0],
-    [0.14548117, 0.5117312, 0.84673864, 1.0],
-    [0.13145992, 0.54289234, 0.8294493, 1.0],
-    [0.14465092, 0.53475004, 0.83253735, 1.0],
-    [0.15916398, 0.503803, 0.84902847, 1.0],
-    [0.15817504, 0.52662647, 0.83525157, 1.0],
-    [0.14488724, 0.5232503, 0.8397719, 1.0],
-    [0.12306176, 0.602435, 0.7886241, 1.0],
-    [0.12306176, 0.602435, 0.7886241, 1.0],
-    [0.13206692, 0.5653626, 0.81420124, 1.0],
-    [0.14523558, 0.5570032, 0.8177128, 1.0],
-    [0.13464747, 0.5874078, 0.79801136, 1.0],
-    [0.1465264, 0.57289016, 0.80642855, 1.0],
-    [0.1587368, 0.5487235, 0.8207953, 1.0],
-    [0.14833106, 0.58712363, 0.79579127, 1.0],
-    [0.16016348, 0.5710963, 0.8051067, 1.0],
-    [0.14623533, 0.5699955, 0.8085297, 1.0],
-    [0.17445306, 0.5692996, 0.8034078, 1.0],
-    [0.17445306, 0.5692996, 0.8034078, 1.0],
-    [0.1459205, 0.56653666, 0.81101376, 1.0],
-    [0.17211096, 0.49678004, 0.85063934, 1.0],
-    [0.17109123, 0.51935434, 0.8372567, 1.0],
-    [0.1714389, 0.5081395, 0.8440398, 1.0],
-    [0.17149772, 0.5413712, 0.8231074, 1.0],
-    [0.17255378, 0.55521137, 0.8136127, 1.0],
-    [0.17196278, 0.5483263, 0.818393, 1.0],
-    [0.18792814, 0.48894274, 0.85183203, 1.0],
-    [0.20035039, 0.4831841, 0.8522868, 1.0],
-    [0.18683288, 0.5113708, 0.8388047, 1.0],
-    [0.19909069, 0.5055293, 0.83952546, 1.0],
-    [0.21591255, 0.4763092, 0.8523564, 1.0],
-    [0.21327099, 0.499085, 0.8398985, 1.0],
-    [0.20035632, 0.49404952, 0.8460334, 1.0],
-    [0.18893205, 0.5318465, 0.82549614, 1.0],
-    [0.19916372, 0.5192177, 0.8311118, 1.0],
-    [0.1837994, 0.5569149, 0.80997753, 1.0],
-    [0.19999419, 0.5334498, 0.82184774, 1.0],
-    [0.20948942, 0.5220624, 0.8267799, 1.0],
-    [0.20165196, 0.5481636, 0.8116977, 1.0],
-    [0.2134158, 0.5426147, 0.812418, 1.0],
-    [0.19927919, 0.536364, 0.8201228, 1.0],
-    [0.18512076, 0.5218542, 0.8327055, 1.0],
-    [0.12555578, 0.6174225, 0.77654696, 1.0],
-    [0.12176367, 0.6545896, 0.7461139, 1.0],
-    [0.13173339, 0.63955843, 0.7573713, 1.0],
-    [0.1457285, 0.63879704, 0.7554479, 1.0],
-    [0.13086624, 0.63773793, 0.75905496, 1.0],
-    [0.13844033, 0.60807496, 0.7817154, 1.0],
-    [0.1419793, 0.6250491, 0.7675646, 1.0],
-    [0.15049578, 0.59989303, 0.78579855, 1.0],
-    [0.16289875, 0.5920749, 0.78924745, 1.0],
-    [0.15591636, 0.62366205, 0.76598686, 1.0],
-    [0.16658403, 0.6086443, 0.775759, 1.0],
-    [0.15247117, 0.60962343, 0.7778894, 1.0],
-    [0.12465648, 0.67184544, 0.7301263, 1.0],
-    [0.13844262, 0.65803045, 0.7401551, 1.0],
-    [0.13686888, 0.6855013, 0.7150909, 1.0],
-    [0.14282198, 0.6706765, 0.72787005, 1.0],
-    [0.14916971, 0.6499149, 0.74522406, 1.0],
-    [0.14817344, 0.6843785, 0.71391225, 1.0],
-    [0.15694727, 0.6691947, 0.7263236, 1.0],
-    [0.14215624, 0.6700642, 0.72856396, 1.0],
-    [0.1613987, 0.64128816, 0.75013334, 1.0],
-    [0.16613455, 0.6543543, 0.7377125, 1.0],
-    [0.16369805, 0.64787734, 0.74394745, 1.0],
-    [0.17693317, 0.58374983, 0.79242086, 1.0],
-    [0.17029445, 0.622161, 0.76414365, 1.0],
-    [0.18097788, 0.6064584, 0.77424496, 1.0],
-    [0.19607197, 0.6041037, 0.7724083, 1.0],
-    [0.18079767, 0.6042332, 0.77602476, 1.0],
-    [0.17171626, 0.6674684, 0.7245684, 1.0],
-    [0.17171623, 0.6674684, 0.7245684, 1.0],
-    [0.17395157, 0.63286954, 0.75446475, 1.0],
-    [0.18573721, 0.6254986, 0.757795, 1.0],
-    [0.18096338, 0.6523268, 0.7360175, 1.0],
-    [0.19052757, 0.6374044, 0.74660224, 1.0],
-    [0.20356527, 0.6173107, 0.7599268, 1.0],
-    [0.19648504, 0.65014917, 0.7339617, 1.0],
-    [0.20408793, 0.63813907, 0.74237907, 1.0],
-    [0.19053942, 0.63635254, 0.747496, 1.0],
-    [0.16075005, 0.63946843, 0.75182414, 1.0],
-    [0.18899707, 0.5770203, 0.79456127, 1.0],
-    [0.19221121, 0.59087163, 0.783534, 1.0],
-    [0.2075252, 0.56887746, 0.79580885, 1.0],
-    [0.21614172, 0.5581253, 0.801111, 1.0],
-    [0.20478734, 0.5917984, 0.77963895, 1.0],
-    [0.20172939, 0.5773699, 0.7911696, 1.0],
-    [0.21100606, 0.60687834, 0.7662736, 1.0],
-    [0.21100606, 0.60687834, 0.7662736, 1.0],
-    [0.20607564, 0.5923508, 0.7788796, 1.0],
-    [0.14908443, 0.59194237, 0.79207194, 1.0],
-    [0.083133176, 0.8266948, 0.5564752, 1.0],
-    [0.08403648, 0.8109911, 0.5789917, 1.0],
-    [0.0955791, 0.8323004, 0.5460226, 1.0],
-    [0.09531221, 0.8165682, 0.56932575, 1.0],
-    [0.106053166, 0.8363503, 0.5378391, 1.0],
-    [0.10557163, 0.8209733, 0.5611217, 1.0],
-    [0.094592355, 0.82423234, 0.558295, 1.0],
-    [0.088394016, 0.79618007, 0.59856814, 1.0],
-    [0.08931773, 0.7770174, 0.62311023, 1.0],
-    [0.095894195, 0.8063191, 0.58365554, 1.0],
-    [0.09688575, 0.79499775, 0.5988254, 1.0],
-    [0.09843013, 0.78249866, 0.614823, 1.0],
-    [0.10504057, 0.804547, 0.58452594, 1.0],
-    [0.11319595, 0.7902561, 0.6022307, 1.0],
-    [0.09782327, 0.79331535, 0.6009003, 1.0],
-    [0.12008025, 0.8408816, 0.5277299, 1.0],
-    [0.11945602, 0.82601434, 0.55084527, 1.0],
-    [0.11957236, 0.83352816, 0.5393824, 1.0],
-    [0.12024765, 0.8102837, 0.5735686, 1.0],
-    [0.12156552, 0.79985565, 0.58775234, 1.0],
-    [0.12083017, 0.80509746, 0.58070475, 1.0],
-    [0.10729057, 0.8145778, 0.57004523, 1.0],
-    [0.091992505, 0.7627549, 0.64011115, 1.0],
-    [0.10068951, 0.76200193, 0.639699, 1.0],
-    [0.11126885, 0.7473443, 0.65505385, 1.0],
-    [0.115117446, 0.7715629, 0.6256507, 1.0],
-    [0.11817008, 0.7588962, 0.6404001, 1.0],
-    [0.10718913, 0.76064837, 0.6402535, 1.0],
-    [0.11543035, 0.7308327, 0.6727253, 1.0],
-    [0.115430295, 0.7308327, 0.6727253, 1.0],
-    [0.11086227, 0.74585754, 0.6568151, 1.0],
-    [0.12320664, 0.78862077, 0.60240966, 1.0],
-    [0.12320659, 0.78862077, 0.60240966, 1.0],
-    [0.13213481, 0.84397537, 0.5198519, 1.0],
-    [0.13145992, 0.8294493, 0.54289234, 1.0],
-    [0.14548117, 0.84673864, 0.5117312, 1.0],
-    [0.14465089, 0.83253735, 0.53475004, 1.0],
-    [0.15916398, 0.84902847, 0.503803, 1.0],
-    [0.15817504, 0.83525157, 0.52662647, 1.0],
-    [0.14488727, 0.8397719, 0.5232503, 1.0],
-    [0.13207321, 0.8141437, 0.5654437, 1.0],
-    [0.13472906, 0.79796743, 0.5874488, 1.0],
-    [0.14523558, 0.8177128, 0.5570032, 1.0],
-    [0.14652638, 0.80642843, 0.57289004, 1.0],
-    [0.14833106, 0.79579127, 0.58712363, 1.0],
-    [0.15873984, 0.8207401, 0.5488053, 1.0],
-    [0.16010006, 0.80502546, 0.57122844, 1.0],
-    [0.14623998, 0.80849475, 0.57004386, 1.0],
-    [0.17431374, 0.8033273, 0.56945574, 1.0],
-    [0.17431371, 0.8033274, 0.56945574, 1.0],
-    [0.1459247, 0.81097907, 0.56658536, 1.0],
-    [0.1217964, 0.7460296, 0.65467966, 1.0],
-    [0.125573, 0.77648544, 0.6174964, 1.0],
-    [0.13174632, 0.7573561, 0.6395739, 1.0],
-    [0.14578383, 0.755496, 0.6387275, 1.0],
-    [0.13089591, 0.75902736, 0.63776463, 1.0],
-    [0.124713495, 0.7300775, 0.67188793, 1.0],
-    [0.13686888, 0.7150909, 0.6855013, 1.0],
-    [0.13851795, 0.7400921, 0.6580856, 1.0],
-    [0.14288723, 0.727868, 0.67066497, 1.0],
-    [0.14817344, 0.7139123, 0.6843785, 1.0],
-    [0.14921929, 0.74522096, 0.6499073, 1.0],
-    [0.15699099, 0.72638005, 0.6691231, 1.0],
-    [0.14219837, 0.72855574, 0.67006433, 1.0],
-    [0.13845672, 0.7816552, 0.60814875, 1.0],
-    [0.14203656, 0.76761127, 0.6249789, 1.0],
-    [0.15049578, 0.78579855, 0.59989303, 1.0],
-    [0.15597208, 0.7660306, 0.6235944, 1.0],
-    [0.16291074, 0.78918844, 0.59215, 1.0],
-    [0.16663967, 0.77579844, 0.6085787, 1.0],
-    [0.15250543, 0.7778903, 0.6096137, 1.0],
-    [0.1613987, 0.75013334, 0.64128816, 1.0],
-    [0.16616018, 0.7378239, 0.65422225, 1.0],
-    [0.16371201, 0.7440026, 0.64781064, 1.0],
-    [0.17173724, 0.7246821, 0.66733956, 1.0],
-    [0.17173724, 0.7246821, 0.66733956, 1.0],
-    [0.17034842, 0.7641846, 0.622096, 1.0],
-    [0.1769444, 0.7923631, 0.58382475, 1.0],
-    [0.18104085, 0.77424264, 0.6064426, 1.0],
-    [0.19616117, 0.7722887, 0.60422766, 1.0],
-    [0.18085313, 0.77598876, 0.6042628, 1.0],
-    [0.17397028, 0.7544064, 0.6329339, 1.0],
-    [0.18095186, 0.7360346, 0.65231085, 1.0],
-    [0.18577302, 0.75767815, 0.6256295, 1.0],
-    [0.19047514, 0.74653107, 0.6375034, 1.0],
-    [0.1964755, 0.7339408, 0.6501757, 1.0],
-    [0.20354095, 0.7598375, 0.6174285, 1.0],
-    [0.20408176, 0.742367, 0.638155, 1.0],
-    [0.19053374, 0.7474448, 0.63641435, 1.0],
-    [0.16077968, 0.7518295, 0.63945466, 1.0],
-    [0.17211099, 0.85063934, 0.49678004, 1.0],
-    [0.17109123, 0.8372568, 0.51935434, 1.0],
-    [0.1714389, 0.8440398, 0.5081395, 1.0],
-    [0.17150262, 0.82299924, 0.541534, 1.0],
-    [0.17241767, 0.8134604, 0.55547667, 1.0],
-    [0.17189668, 0.81826335, 0.54854035, 1.0],
-    [0.18792814, 0.85183203, 0.48894274, 1.0],
-    [0.18683288, 0.8388047, 0.5113708, 1.0],
-    [0.20035039, 0.8522868, 0.4831841, 1.0],
-    [0.19909069, 0.83952546, 0.5055293, 1.0],
-    [0.21591257, 0.8523564, 0.4763092, 1.0],
-    [0.21327099, 0.8398985, 0.499085, 1.0],
-    [0.20035632, 0.8460334, 0.49404952, 1.0],
-    [0.18885012, 0.8254448, 0.5319553, 1.0],
-    [0.18371294, 0.8099325, 0.55700904, 1.0],
-    [0.19902284, 0.83110094, 0.5192891, 1.0],
-    [0.19985424, 0.8218351, 0.5335217, 1.0],
-    [0.20165567, 0.811644, 0.5482416, 1.0],
-    [0.2094043, 0.82677484, 0.5221045, 1.0],
-    [0.2134192, 0.8123652, 0.54269236, 1.0],
-    [0.19920318, 0.82009023, 0.53644204, 1.0],
-    [0.18508188, 0.83266777, 0.5219283, 1.0],
-    [0.18900624, 0.79450536, 0.5770944, 1.0],
-    [0.19228004, 0.7834894, 0.5909085, 1.0],
-    [0.2074867, 0.7957232, 0.5690114, 1.0],
-    [0.20483913, 0.7795667, 0.59187555, 1.0],
-    [0.21606308, 0.80104506, 0.55825055, 1.0],
-    [0.20173268, 0.79110307, 0.5774598, 1.0],
-    [0.21094044, 0.76620585, 0.6069867, 1.0],
-    [0.21094044, 0.76620585, 0.6069867, 1.0],
-    [0.2060445, 0.77881265, 0.5924496, 1.0],
-    [0.14909224, 0.7920419, 0.5919806, 1.0],
-    [0.14178905, 0.69996285, 0.69996285, 1.0],
-    [0.15601811, 0.69844717, 0.6984481, 1.0],
-    [0.16417205, 0.68346334, 0.71128434, 1.0],
-    [0.1640995, 0.711293, 0.68347174, 1.0],
-    [0.15630858, 0.6984148, 0.6984156, 1.0],
-    [0.17084348, 0.69671005, 0.6967121, 1.0],
-    [0.17879306, 0.6817031, 0.7094463, 1.0],
-    [0.17877625, 0.7094812, 0.68167126, 1.0],
-    [0.1861568, 0.6947466, 0.6947466, 1.0],
-    [0.1941464, 0.67983335, 0.70720136, 1.0],
-    [0.19418739, 0.7072596, 0.679761, 1.0],
-    [0.20162745, 0.6925844, 0.69258446, 1.0],
-    [0.18614899, 0.69476163, 0.6947336, 1.0],
-    [0.18711816, 0.6670733, 0.7211102, 1.0],
-    [0.20265898, 0.6648622, 0.7189489, 1.0],
-    [0.21154799, 0.6500334, 0.72986573, 1.0],
-    [0.20932108, 0.67818654, 0.70444846, 1.0],
-    [0.2024939, 0.6651449, 0.71873397, 1.0],
-    [0.18716128, 0.7211671, 0.66699976, 1.0],
-    [0.20269404, 0.71899366, 0.6648032, 1.0],
-    [0.20932108, 0.70444846, 0.67818654, 1.0],
-    [0.21157584, 0.72989804, 0.64998806, 1.0],
-    [0.20252016, 0.7187675, 0.66510063, 1.0],
-    [0.18614928, 0.69476116, 0.69473416, 1.0],
-    [0.18614928, 0.69476116, 0.69473416, 1.0],
-    [0.23153578, 0.4698319, 0.8518505, 1.0],
-    [0.23034266, 0.4917844, 0.83969665, 1.0],
-    [0.23081098, 0.48086253, 0.8458708, 1.0],
-    [0.24343415, 0.46509463, 0.8511326, 1.0],
-    [0.24211007, 0.48697826, 0.8391871, 1.0],
-    [0.2632945, 0.45771623, 0.8492184, 1.0],
-    [0.26028395, 0.4800297, 0.83774924, 1.0],
-    [0.25213742, 0.4724534, 0.8445202, 1.0],
-    [0.23109956, 0.5130081, 0.82668954, 1.0],
-    [0.23416464, 0.5324137, 0.8134511, 1.0],
-    [0.24627168, 0.53385276, 0.80891997, 1.0],
-    [0.23705095, 0.5265074, 0.8164538, 1.0],
-    [0.24273567, 0.5082413, 0.8262991, 1.0],
-    [0.2439068, 0.52053934, 0.8182593, 1.0],
-    [0.2575854, 0.50248605, 0.82532275, 1.0],
-    [0.2548776, 0.52396095, 0.81271285, 1.0],
-    [0.2496912, 0.5138336, 0.82074916, 1.0],
-    [0.28043407, 0.4519291, 0.84682757, 1.0],
-    [0.2916765, 0.44828728, 0.84496355, 1.0],
-    [0.27941987, 0.47335333, 0.83538085, 1.0],
-    [0.29061618, 0.46961603, 0.83366835, 1.0],
-    [0.3085479, 0.44311464, 0.8416933, 1.0],
-    [0.3052185, 0.46501622, 0.8310244, 1.0],
-    [0.2925377, 0.45853576, 0.83914644, 1.0],
-    [0.2817126, 0.49329606, 0.8229806, 1.0],
-    [0.29104432, 0.4827274, 0.8259948, 1.0],
-    [0.27784994, 0.5162698, 0.81010175, 1.0],
-    [0.29311228, 0.49616286, 0.8172562, 1.0],
-    [0.30156583, 0.4866966, 0.81986856, 1.0],
-    [0.29889628, 0.51044196, 0.8062939, 1.0],
-    [0.30627668, 0.5020595, 0.80878353, 1.0],
-    [0.2928101, 0.49826598, 0.8160841, 1.0],
-    [0.25855866, 0.4916995, 0.83149207, 1.0],
-    [0.21640946, 0.61944944, 0.7546187, 1.0],
-    [0.22437571, 0.6297871, 0.74365574, 1.0],
-    [0.23782827, 0.63183814, 0.7377115, 1.0],
-    [0.22612232, 0.6271202, 0.7453783, 1.0],
-    [0.22612232, 0.6271202, 0.7453783, 1.0],
-    [0.21936974, 0.57145756, 0.7907675, 1.0],
-    [0.22864734, 0.55900455, 0.7970159, 1.0],
-    [0.22309034, 0.5825304, 0.7815939, 1.0],
-    [0.23452373, 0.5772975, 0.7821293, 1.0],
-    [0.25247163, 0.5492035, 0.7966389, 1.0],
-    [0.25131395, 0.5700069, 0.7822618, 1.0],
-    [0.23474392, 0.5682695, 0.7886476, 1.0],
-    [0.26028696, 0.53986716, 0.8004962, 1.0],
-    [0.26028696, 0.5398671, 0.8004962, 1.0],
-    [0.22716656, 0.6035249, 0.7642989, 1.0],
-    [0.23845759, 0.5875978, 0.7732186, 1.0],
-    [0.23078522, 0.6200949, 0.7498136, 1.0],
-    [0.24473107, 0.59981674, 0.7617917, 1.0],
-    [0.25139898, 0.5904643, 0.7669096, 1.0],
-    [0.24827707, 0.6162927, 0.74735653, 1.0],
-    [0.25912204, 0.6006488, 0.75635755, 1.0],
-    [0.24269263, 0.6027177, 0.7601524, 1.0],
-    [0.26428136, 0.55272603, 0.7903476, 1.0],
-    [0.27155194, 0.5633306, 0.78033215, 1.0],
-    [0.28443006, 0.5666551, 0.7733056, 1.0],
-    [0.27335992, 0.56097084, 0.78140014, 1.0],
-    [0.27353212, 0.54088086, 0.7953792, 1.0],
-    [0.27820018, 0.55470836, 0.7841577, 1.0],
-    [0.29739648, 0.5305993, 0.7937379, 1.0],
-    [0.2934395, 0.5550587, 0.7783335, 1.0],
-    [0.28554237, 0.54535496, 0.78806955, 1.0],
-    [0.27162525, 0.5834426, 0.7653851, 1.0],
-    [0.27016973, 0.61199254, 0.74328566, 1.0],
-    [0.27067333, 0.60044575, 0.75246316, 1.0],
-    [0.29173592, 0.5761316, 0.76351976, 1.0],
-    [0.2823191, 0.6114493, 0.73920614, 1.0],
-    [0.29065052, 0.5996895, 0.74558365, 1.0],
-    [0.2793632, 0.5973011, 0.7517895, 1.0],
-    [0.29843515, 0.5666661, 0.7680012, 1.0],
-    [0.29843515, 0.5666661, 0.7680012, 1.0],
-    [0.2672484, 0.568994, 0.7777045, 1.0],
-    [0.33073008, 0.43686607, 0.83652, 1.0],
-    [0.3271704, 0.45861256, 0.82621676, 1.0],
-    [0.35249898, 0.43107325, 0.83061445, 1.0],
-    [0.34894335, 0.45261917, 0.820594, 1.0],
-    [0.3397563, 0.44477326, 0.82869923, 1.0],
-    [0.3240058, 0.48029125, 0.81507087, 1.0],
-    [0.31072482, 0.51447475, 0.79922825, 1.0],
-    [0.3196407, 0.5039414, 0.80241686, 1.0],
-    [0.34572265, 0.47417742, 0.8097108, 1.0],
-    [0.34281012, 0.49580488, 0.797909, 1.0],
-    [0.3284399, 0.49373138, 0.8052059, 1.0],
-    [0.37409732, 0.42577556, 0.8238728, 1.0],
-    [0.37055936, 0.44713867, 0.8140963, 1.0],
-    [0.39554328, 0.4210865, 0.8162301, 1.0],
-    [0.39179438, 0.4423961, 0.8067111, 1.0],
-    [0.3829505, 0.43408063, 0.8154282, 1.0],
-    [0.3671221, 0.4686373, 0.80349267, 1.0],
-    [0.3640295, 0.4900365, 0.7920523, 1.0],
-    [0.38825676, 0.46359912, 0.79645, 1.0],
-    [0.38506886, 0.48471257, 0.7853506, 1.0],
-    [0.3760687, 0.47675547, 0.7945291, 1.0],
-    [0.3564978, 0.4622999, 0.81190395, 1.0],
-    [0.31741473, 0.52466464, 0.7899208, 1.0],
-    [0.32423818, 0.51614195, 0.79275906, 1.0],
-    [0.31677783, 0.54520446, 0.7761469, 1.0],
-    [0.33303404, 0.527256, 0.78172207, 1.0],
-    [0.3406274, 0.5178275, 0.78474694, 1.0],
-    [0.3385639, 0.5371786, 0.7725372, 1.0],
-    [0.34683314, 0.5269003, 0.77594, 1.0],
-    [0.3310069, 0.5279164, 0.78213716, 1.0],
-    [0.3088376, 0.57750434, 0.755717, 1.0],
-    [0.3143503, 0.58769286, 0.7455206, 1.0],
-    [0.33412132, 0.58589023, 0.7383058, 1.0],
-    [0.3190695, 0.583762, 0.74660337, 1.0],
-    [0.3157703, 0.56753093, 0.7603932, 1.0],
-    [0.3225792, 0.5764053, 0.7507994, 1.0],
-    [0.33596557, 0.5605627, 0.7568993, 1.0],
-    [0.33488005, 0.5747898, 0.74664044, 1.0],
-    [0.327268, 0.5698531, 0.75376594, 1.0],
-    [0.3614218, 0.5113463, 0.779679, 1.0],
-    [0.358706, 0.536103, 0.76414883, 1.0],
-    [0.35984564, 0.52539414, 0.77102023, 1.0],
-    [0.3823456, 0.50595963, 0.7731861, 1.0],
-    [0.37931392, 0.53254616, 0.75664765, 1.0],
-    [0.3804625, 0.5219591, 0.76341796, 1.0],
-    [0.37029746, 0.5222601, 0.7681954, 1.0],
-    [0.35472062, 0.5824416, 0.7313925, 1.0],
-    [0.37526417, 0.57797754, 0.72465086, 1.0],
-    [0.36499372, 0.5802277, 0.7280902, 1.0],
-    [0.35704803, 0.55426663, 0.75186783, 1.0],
-    [0.35558224, 0.57140213, 0.73963565, 1.0],
-    [0.37780574, 0.5485687, 0.7458788, 1.0],
-    [0.37615386, 0.5670009, 0.73281527, 1.0],
-    [0.36660808, 0.56036, 0.74269456, 1.0],
-    [0.34619448, 0.5577163, 0.7543884, 1.0],
-    [0.28803918, 0.5422125, 0.7893282, 1.0],
-    [0.21632364, 0.75462717, 0.619469, 1.0],
-    [0.22437571, 0.74365574, 0.6297871, 1.0],
-    [0.23784915, 0.7376367, 0.63191766, 1.0],
-    [0.22610004, 0.7453566, 0.6271542, 1.0],
-    [0.22610004, 0.7453566, 0.6271542, 1.0],
-    [0.23153578, 0.8518505, 0.4698319, 1.0],
-    [0.23034264, 0.8396966, 0.49178436, 1.0],
-    [0.23081098, 0.8458708, 0.48086253, 1.0],
-    [0.23109956, 0.82668954, 0.5130081, 1.0],
-    [0.23416464, 0.8134511, 0.5324137, 1.0],
-    [0.24627289, 0.8088516, 0.5339557, 1.0],
-    [0.23705071, 0.8164314, 0.52654225, 1.0],
-    [0.24343415, 0.8511326, 0.46509463, 1.0],
-    [0.24211007, 0.8391871, 0.48697826, 1.0],
-    [0.2632945, 0.8492184, 0.45771623, 1.0],
-    [0.26028395, 0.83774924, 0.4800297, 1.0],
-    [0.25213742, 0.8445202, 0.4724534, 1.0],
-    [0.24273562, 0.826299, 0.50824124, 1.0],
-    [0.2439068, 0.8182593, 0.52053934, 1.0],
-    [0.2575854, 0.82532275, 0.50248605, 1.0],
-    [0.25487736, 0.812673, 0.52402294, 1.0],
-    [0.2496908, 0.8207395, 0.51384944, 1.0],
-    [0.28043407, 0.84682757, 0.4519291, 1.0],
-    [0.27941984, 0.83538085, 0.47335333, 1.0],
-    [0.29167646, 0.8449635, 0.44828725, 1.0],
-    [0.29061618, 0.83366835, 0.46961603, 1.0],
-    [0.3085479, 0.8416933, 0.44311464, 1.0],
-    [0.3052185, 0.8310244, 0.46501622, 1.0],
-    [0.2925377, 0.83914644, 0.45853576, 1.0],
-    [0.2816522, 0.8229907, 0.49331352, 1.0],
-    [0.27784994, 0.81010175, 0.5162698, 1.0],
-    [0.2909432, 0.8260139, 0.48275566, 1.0],
-    [0.29301146, 0.817275, 0.4961915, 1.0],
-    [0.29889628, 0.8062939, 0.51044196, 1.0],
-    [0.30150512, 0.819881, 0.48671335, 1.0],
-    [0.30627668, 0.80878353, 0.5020595, 1.0],
-    [0.29276398, 0.81609255, 0.4982791, 1.0],
-    [0.2585505, 0.83148813, 0.49171042, 1.0],
-    [0.21928276, 0.79077387, 0.57148206, 1.0],
-    [0.22309871, 0.7815389, 0.58260095, 1.0],
-    [0.22859468, 0.797021, 0.55901873, 1.0],
-    [0.2345318, 0.782075, 0.5773678, 1.0],
-    [0.25247234, 0.79663825, 0.5492043, 1.0],
-    [0.25131395, 0.7822618, 0.5700069, 1.0],
-    [0.23472281, 0.7886321, 0.5682998, 1.0],
-    [0.22715537, 0.76424515, 0.6035973, 1.0],
-    [0.23087524, 0.74980354, 0.62007356, 1.0],
-    [0.23842745, 0.7731158, 0.587745, 1.0],
-    [0.24474274, 0.7617155, 0.5999088, 1.0],
-    [0.24826711, 0.74730283, 0.61636174, 1.0],
-    [0.25135025, 0.7668736, 0.59053195, 1.0],
-    [0.2589901, 0.75634, 0.60072786, 1.0],
-    [0.24267496, 0.76010084, 0.6027898, 1.0],
-    [0.26028797, 0.8004949, 0.5398685, 1.0],
-    [0.26028794, 0.80049497, 0.5398685, 1.0],
-    [0.26427272, 0.79048735, 0.5525303, 1.0],
-    [0.27154812, 0.780375, 0.56327295, 1.0],
-    [0.28452396, 0.7733571, 0.56653774, 1.0],
-    [0.2733863, 0.781479, 0.56084794, 1.0],
-    [0.27009842, 0.7432973, 0.6120098, 1.0],
-    [0.27162525, 0.7653851, 0.5834426, 1.0],
-    [0.27060196, 0.7524755, 0.6004625, 1.0],
-    [0.28233105, 0.73913413, 0.6115308, 1.0],
-    [0.29173103, 0.76356333, 0.57607645, 1.0],
-    [0.2906568, 0.74554145, 0.59973884, 1.0],
-    [0.2793407, 0.7517829, 0.5973201, 1.0],
-    [0.27352908, 0.7954607, 0.5407626, 1.0],
-    [0.27819517, 0.78422767, 0.55461186, 1.0],
-    [0.29739648, 0.7937379, 0.5305993, 1.0],
-    [0.293497, 0.7783621, 0.5549882, 1.0],
-    [0.28555495, 0.78811413, 0.54528373, 1.0],
-    [0.29842812, 0.7680721, 0.5665737, 1.0],
-    [0.29842812, 0.7680721, 0.5665737, 1.0],
-    [0.26724318, 0.77772355, 0.5689703, 1.0],
-    [0.33073008, 0.83652, 0.43686607, 1.0],
-    [0.3271704, 0.82621676, 0.45861256, 1.0],
-    [0.35249898, 0.83061445, 0.43107325, 1.0],
-    [0.34894338, 0.820594, 0.45261917, 1.0],
-    [0.3397563, 0.82869923, 0.44477326, 1.0],
-    [0.31072485, 0.79922825, 0.51447475, 1.0],
-    [0.32400584, 0.81507087, 0.48029125, 1.0],
-    [0.3196407, 0.80241686, 0.5039414, 1.0],
-    [0.34572265, 0.8097108, 0.47417742, 1.0],
-    [0.34281012, 0.797909, 0.49580488, 1.0],
-    [0.3284399, 0.8052059, 0.49373138, 1.0],
-    [0.37409732, 0.8238728, 0.42577556, 1.0],
-    [0.37055936, 0.8140963, 0.44713867, 1.0],
-    [0.39554328, 0.8162301, 0.4210865, 1.0],
-    [0.39179438, 0.8067111, 0.4423961, 1.0],
-    [0.3829505, 0.8154282, 0.43408063, 1.0],
-    [0.3671221, 0.80349267, 0.4686373, 1.0],
-    [0.3640295, 0.7920523, 0.4900365, 1.0],
-    [0.38825676, 0.79645, 0.46359912, 1.0],
-    [0.38506886, 0.7853506, 0.48471257, 1.0],
-    [0.3760687, 0.7945291, 0.47675547, 1.0],
-    [0.3564978, 0.81190395, 0.4622999, 1.0],
-    [0.30873662, 0.7557393, 0.5775291, 1.0],
-    [0.31435028, 0.7455206, 0.58769286, 1.0],
-    [0.33412135, 0.7383058, 0.5858903, 1.0],
-    [0.31903586, 0.7466112, 0.58377045, 1.0],
-    [0.31741473, 0.7899208, 0.52466464, 1.0],
-    [0.31677783, 0.7761469, 0.54520446, 1.0],
-    [0.32423818, 0.79275906, 0.51614195, 1.0],
-    [0.33303404, 0.78172207, 0.527256, 1.0],
-    [0.33856383, 0.7725371, 0.5371786, 1.0],
-    [0.34062737, 0.7847469, 0.51782745, 1.0],
-    [0.34683314, 0.77594, 0.5269003, 1.0],
-    [0.3310069, 0.78213716, 0.5279164, 1.0],
-    [0.31570956, 0.76040745, 0.56754553, 1.0],
-    [0.3225792, 0.7507994, 0.5764053, 1.0],
-    [0.33596557, 0.7568993, 0.5605627, 1.0],
-    [0.33488002, 0.74664044, 0.5747898, 1.0],
-    [0.32725278, 0.7537697, 0.5698568, 1.0],
-    [0.35472062, 0.7313925, 0.5824416, 1.0],
-    [0.37526417, 0.72465086, 0.57797754, 1.0],
-    [0.36499372, 0.7280902, 0.5802277, 1.0],
-    [0.358706, 0.76414883, 0.536103, 1.0],
-    [0.3614218, 0.779679, 0.5113463, 1.0],
-    [0.35984564, 0.77102023, 0.52539414, 1.0],
-    [0.37931392, 0.75664765, 0.53254616, 1.0],
-    [0.3823456, 0.7731861, 0.50595963, 1.0],
-    [0.3804625, 0.76341796, 0.5219591, 1.0],
-    [0.37029743, 0.7681954, 0.5222601, 1.0],
-    [0.357048, 0.7518678, 0.55426663, 1.0],
-    [0.35558224, 0.73963565, 0.57140213, 1.0],
-    [0.37780574, 0.7458788, 0.5485687, 1.0],
-    [0.37615386, 0.7328152, 0.5670009, 1.0],
-    [0.36660808, 0.74269456, 0.56036, 1.0],
-    [0.34618625, 0.75439066, 0.5577182, 1.0],
-    [0.28802973, 0.78932863, 0.54221684, 1.0],
-    [0.21804342, 0.69016105, 0.69002527, 1.0],
-    [0.21804337, 0.69016105, 0.69002527, 1.0],
-    [0.21923651, 0.66215605, 0.7165786, 1.0],
-    [0.22628154, 0.6502902, 0.72520286, 1.0],
-    [0.22580746, 0.67540276, 0.7020272, 1.0],
-    [0.23394762, 0.6608089, 0.713162, 1.0],
-    [0.24587665, 0.6415035, 0.72664833, 1.0],
-    [0.24703294, 0.6718022, 0.6983241, 1.0],
-    [0.24729227, 0.6593299, 0.7100216, 1.0],
-    [0.23489657, 0.66031784, 0.71330494, 1.0],
-    [0.21921761, 0.71656513, 0.6621767, 1.0],
-    [0.22579652, 0.70208883, 0.6753423, 1.0],
-    [0.2262699, 0.72519547, 0.65030265, 1.0],
-    [0.23392051, 0.71315473, 0.66082644, 1.0],
-    [0.2469901, 0.69837517, 0.6717648, 1.0],
-    [0.2458539, 0.7266393, 0.6415226, 1.0],
-    [0.24725075, 0.71007216, 0.6592911, 1.0],
-    [0.23487215, 0.7133224, 0.6603076, 1.0],
-    [0.23268914, 0.6877645, 0.68763053, 1.0],
-    [0.24685916, 0.6852228, 0.6852228, 1.0],
-    [0.23975776, 0.68651575, 0.68644917, 1.0],
-    [0.26179278, 0.6423192, 0.72034055, 1.0],
-    [0.25863874, 0.6700766, 0.6957754, 1.0],
-    [0.26714167, 0.6553812, 0.7064777, 1.0],
-    [0.29002848, 0.6504313, 0.70201325, 1.0],
-    [0.2692707, 0.65467453, 0.70632464, 1.0],
-    [0.2586426, 0.6959032, 0.6699425, 1.0],
-    [0.26173082, 0.7204022, 0.6422755, 1.0],
-    [0.26714545, 0.70653945, 0.65531313, 1.0],
-    [0.29002848, 0.70201325, 0.6504313, 1.0],
-    [0.26925763, 0.70638657, 0.6546132, 1.0],
-    [0.2655372, 0.68178725, 0.68165696, 1.0],
-    [0.27281764, 0.6675787, 0.6927548, 1.0],
-    [0.27282146, 0.6928811, 0.6674459, 1.0],
-    [0.28972948, 0.67677796, 0.676778, 1.0],
-    [0.2897718, 0.66439325, 0.6889223, 1.0],
-    [0.28977183, 0.6889222, 0.66439325, 1.0],
-    [0.279946, 0.6788659, 0.6788014, 1.0],
-    [0.24883142, 0.6848958, 0.6848363, 1.0],
-    [0.25218856, 0.6311426, 0.7335256, 1.0],
-    [0.25218856, 0.6311426, 0.7335256, 1.0],
-    [0.26924732, 0.6305853, 0.7279203, 1.0],
-    [0.27596104, 0.640567, 0.7166028, 1.0],
-    [0.28972092, 0.62058145, 0.7286566, 1.0],
-    [0.29638645, 0.610073, 0.7348239, 1.0],
-    [0.29038358, 0.6384641, 0.71277, 1.0],
-    [0.2842214, 0.6281318, 0.7243401, 1.0],
-    [0.26814395, 0.62971973, 0.7290761, 1.0],
-    [0.2521499, 0.7335115, 0.6311746, 1.0],
-    [0.2521499, 0.7335115, 0.6311746, 1.0],
-    [0.26915252, 0.727968, 0.6305708, 1.0],
-    [0.27586383, 0.716621, 0.6405884, 1.0],
-    [0.28961045, 0.72863734, 0.6206558, 1.0],
-    [0.2903103, 0.7127849, 0.63848084, 1.0],
-    [0.2962985, 0.7347735, 0.6101763, 1.0],
-    [0.28412923, 0.72434163, 0.6281719, 1.0],
-    [0.2680786, 0.7290692, 0.62975544, 1.0],
-    [0.30456564, 0.6477626, 0.6983148, 1.0],
-    [0.30456564, 0.6477626, 0.6983148, 1.0],
-    [0.30456564, 0.6983148, 0.6477626, 1.0],
-    [0.30456564, 0.6983148, 0.6477626, 1.0],
-    [0.3023659, 0.6740085, 0.6740085, 1.0],
-    [0.31026787, 0.65965855, 0.6845323, 1.0],
-    [0.31026787, 0.6845322, 0.65965855, 1.0],
-    [0.31616095, 0.67083615, 0.67083615, 1.0],
-    [0.33143434, 0.65436846, 0.67967135, 1.0],
-    [0.33143434, 0.67967135, 0.65436846, 1.0],
-    [0.3312211, 0.6671929, 0.6671929, 1.0],
-    [0.31893066, 0.6701803, 0.6701803, 1.0],
-    [0.3520004, 0.6493664, 0.6741061, 1.0],
-    [0.35200042, 0.6741062, 0.6493664, 1.0],
-    [0.35176367, 0.66191477, 0.66191477, 1.0],
-    [0.37255397, 0.6438445, 0.6683322, 1.0],
-    [0.37255397, 0.6683322, 0.6438445, 1.0],
-    [0.37239325, 0.65624815, 0.65624815, 1.0],
-    [0.3621448, 0.65910965, 0.65910965, 1.0],
-    [0.32222506, 0.66939193, 0.669392, 1.0],
-    [0.31181926, 0.61939746, 0.7204968, 1.0],
-    [0.3122779, 0.60804313, 0.7299083, 1.0],
-    [0.31153697, 0.6365366, 0.7055253, 1.0],
-    [0.32640132, 0.6169582, 0.71611786, 1.0],
-    [0.33335966, 0.60631233, 0.72198105, 1.0],
-    [0.3326224, 0.625721, 0.70557475, 1.0],
-    [0.34015903, 0.61432976, 0.71196264, 1.0],
-    [0.32395127, 0.6182778, 0.7160924, 1.0],
-    [0.31849155, 0.6451382, 0.69452137, 1.0],
-    [0.33178827, 0.64227223, 0.6909435, 1.0],
-    [0.3251362, 0.6437212, 0.6927549, 1.0],
-    [0.3537241, 0.5983672, 0.718913, 1.0],
-    [0.3526892, 0.62287056, 0.698314, 1.0],
-    [0.35307795, 0.6115334, 0.7080698, 1.0],
-    [0.37433735, 0.5923053, 0.7134746, 1.0],
-    [0.373199, 0.61778456, 0.6921451, 1.0],
-    [0.37359703, 0.6065042, 0.7018389, 1.0],
-    [0.36337873, 0.6083272, 0.70561594, 1.0],
-    [0.35236394, 0.63739765, 0.68524736, 1.0],
-    [0.37284338, 0.63210964, 0.67928284, 1.0],
-    [0.36260352, 0.6347911, 0.6823188, 1.0],
-    [0.34363735, 0.6265269, 0.69955516, 1.0],
-    [0.31174392, 0.7205137, 0.61941564, 1.0],
-    [0.31153694, 0.70552516, 0.6365366, 1.0],
-    [0.31220245, 0.72992575, 0.6080608, 1.0],
-    [0.32638067, 0.71614355, 0.61693925, 1.0],
-    [0.3326098, 0.7055904, 0.62571, 1.0],
-    [0.3333474, 0.7219964, 0.6063008, 1.0],
-    [0.34013838, 0.71198857, 0.61431116, 1.0],
-    [0.3239202, 0.7161093, 0.6182744, 1.0],
-    [0.31849155, 0.69452137, 0.6451382, 1.0],
-    [0.33178827, 0.6909435, 0.64227223, 1.0],
-    [0.3251362, 0.6927549, 0.6437212, 1.0],
-    [0.35268924, 0.6983141, 0.6228706, 1.0],
-    [0.3537241, 0.718913, 0.5983672, 1.0],
-    [0.35307795, 0.7080698, 0.6115334, 1.0],
-    [0.37319902, 0.69214517, 0.61778456, 1.0],
-    [0.37433735, 0.7134746, 0.59230536, 1.0],
-    [0.37359703, 0.7018389, 0.6065042, 1.0],
-    [0.36337873, 0.70561594, 0.6083272, 1.0],
-    [0.35236394, 0.68524736, 0.63739765, 1.0],
-    [0.3728434, 0.67928284, 0.6321097, 1.0],
-    [0.36260352, 0.6823188, 0.6347911, 1.0],
-    [0.34362954, 0.6995595, 0.6265263, 1.0],
-    [0.2976281, 0.67506343, 0.67506063, 1.0],
-    [0.18615784, 0.6947446, 0.69474834, 1.0],
-    [0.92378813, 0.21019971, 0.3200492, 1.0],
-    [0.9302617, 0.20173612, 0.30645669, 1.0],
-    [0.9272893, 0.20556504, 0.31285402, 1.0],
-    [0.9118941, 0.15962341, 0.3781133, 1.0],
-    [0.9024763, 0.13482305, 0.40909556, 1.0],
-    [0.9180066, 0.18101604, 0.35284144, 1.0],
-    [0.9193576, 0.153912, 0.36206713, 1.0],
-    [0.9114773, 0.13229437, 0.38949615, 1.0],
-    [0.92494166, 0.17402215, 0.33793387, 1.0],
-    [0.9154805, 0.15511051, 0.371263, 1.0],
-    [0.9348166, 0.19601569, 0.2961349, 1.0],
-    [0.93688554, 0.1935539, 0.29117405, 1.0],
-    [0.93592685, 0.1946462, 0.2935197, 1.0],
-    [0.9228946, 0.15138555, 0.35404512, 1.0],
-    [0.9194828, 0.13564909, 0.36898592, 1.0],
-    [0.92909807, 0.16681759, 0.3300736, 1.0],
-    [0.9258144, 0.14955533, 0.34713256, 1.0],
-    [0.9224526, 0.1322261, 0.3627637, 1.0],
-    [0.93486726, 0.17558236, 0.30853552, 1.0],
-    [0.92810714, 0.14858538, 0.3413788, 1.0],
-    [0.92668056, 0.15073335, 0.34429994, 1.0],
-    [0.9279808, 0.17460348, 0.32918897, 1.0],
-    [0.92378813, 0.3200492, 0.21019967, 1.0],
-    [0.9302617, 0.30645669, 0.20173612, 1.0],
-    [0.9272893, 0.31285402, 0.20556504, 1.0],
-    [0.9118941, 0.37811324, 0.15962347, 1.0],
-    [0.9180066, 0.35284138, 0.18101604, 1.0],
-    [0.9024763, 0.40909556, 0.13482305, 1.0],
-    [0.9193576, 0.36206713, 0.15391193, 1.0],
-    [0.92494166, 0.33793387, 0.17402215, 1.0],
-    [0.9114773, 0.38949615, 0.13229437, 1.0],
-    [0.9154805, 0.371263, 0.15511057, 1.0],
-    [0.9348166, 0.2961349, 0.19601569, 1.0],
-    [0.93688554, 0.29117405, 0.1935539, 1.0],
-    [0.93592685, 0.2935197, 0.1946462, 1.0],
-    [0.92290115, 0.35401028, 0.15142691, 1.0],
-    [0.92910117, 0.330052, 0.16684306, 1.0],
-    [0.91948783, 0.36896536, 0.13567132, 1.0],
-    [0.9258214, 0.34709716, 0.14959331, 1.0],
-    [0.93486726, 0.30853552, 0.17558236, 1.0],
-    [0.922438, 0.3628041, 0.13221635, 1.0],
-    [0.9280945, 0.34141645, 0.14857848, 1.0],
-    [0.9266794, 0.3442959, 0.1507501, 1.0],
-    [0.92797977, 0.32918832, 0.17460953, 1.0],
-    [0.9278194, 0.26377177, 0.2637718, 1.0],
-    [0.9267148, 0.23555031, 0.2927724, 1.0],
-    [0.9267148, 0.2927724, 0.23555031, 1.0],
-    [0.93399113, 0.25264636, 0.25264642, 1.0],
-    [0.932972, 0.22591667, 0.28022307, 1.0],
-    [0.932972, 0.28022295, 0.22591667, 1.0],
-    [0.9311514, 0.25783446, 0.25783446, 1.0],
-    [0.9382434, 0.24464191, 0.24464191, 1.0],
-    [0.9373045, 0.21895137, 0.2711469, 1.0],
-    [0.9373045, 0.2711469, 0.21895137, 1.0],
-    [0.9403741, 0.24051666, 0.24051666, 1.0],
-    [0.9402239, 0.215521, 0.26368502, 1.0],
-    [0.9402239, 0.26368502, 0.21552108, 1.0],
-    [0.9416988, 0.23791103, 0.23791103, 1.0],
-    [0.9400385, 0.24117179, 0.24117179, 1.0],
-    [0.93838614, 0.1916878, 0.28755394, 1.0],
-    [0.93838614, 0.1916878, 0.28755394, 1.0],
-    [0.93838614, 0.28755394, 0.1916878, 1.0],
-    [0.93838614, 0.28755394, 0.1916878, 1.0],
-    [0.93960714, 0.24201071, 0.24201067, 1.0],
-    [0.9243225, 0.13283746, 0.3577461, 1.0],
-    [0.9361262, 0.17224374, 0.30659404, 1.0],
-    [0.92938226, 0.14628606, 0.33889383, 1.0],
-    [0.9366321, 0.1729188, 0.3046628, 1.0],
-    [0.93306184, 0.1584923, 0.3229176, 1.0],
-    [0.93248135, 0.15612984, 0.32573313, 1.0],
-    [0.9323978, 0.1599059, 0.32413664, 1.0],
-    [0.9323978, 0.1599058, 0.32413658, 1.0],
-    [0.9327216, 0.15739936, 0.3244313, 1.0],
-    [0.93612045, 0.3066167, 0.17223443, 1.0],
-    [0.9242676, 0.35793534, 0.13270906, 1.0],
-    [0.9293488, 0.3390243, 0.14619565, 1.0],
-    [0.93662804, 0.3046862, 0.17289953, 1.0],
-    [0.9330705, 0.322882, 0.15851328, 1.0],
-    [0.93246645, 0.32579786, 0.15608363, 1.0],
-    [0.932389, 0.32418314, 0.1598626, 1.0],
-    [0.932389, 0.32418314, 0.1598626, 1.0],
-    [0.9327109, 0.3244851, 0.15735243, 1.0],
-    [0.9417502, 0.2144082, 0.25910562, 1.0],
-    [0.9417502, 0.25910562, 0.21440811, 1.0],
-    [0.94244367, 0.23643185, 0.2364318, 1.0],
-    [0.94212115, 0.21400191, 0.2580907, 1.0],
-    [0.9421351, 0.2578594, 0.21421924, 1.0],
-    [0.9427593, 0.23569115, 0.2359125, 1.0],
-    [0.94273865, 0.23578581, 0.23590009, 1.0],
-    [0.9393829, 0.19102794, 0.2847247, 1.0],
-    [0.94007397, 0.19363219, 0.2806554, 1.0],
-    [0.9365825, 0.17421865, 0.30407405, 1.0],
-    [0.9419323, 0.21488264, 0.25804844, 1.0],
-    [0.9400089, 0.19289334, 0.28138143, 1.0],
-    [0.93938285, 0.2847247, 0.19102797, 1.0],
-    [0.9400876, 0.28053865, 0.19373517, 1.0],
-    [0.94194573, 0.25782415, 0.2150932, 1.0],
-    [0.93659854, 0.30398875, 0.17428142, 1.0],
-    [0.94002193, 0.28127185, 0.19298916, 1.0],
-    [0.94210404, 0.23710772, 0.23710772, 1.0],
-    [0.9411238, 0.2389427, 0.23914942, 1.0],
-    [0.9416635, 0.23792848, 0.23803338, 1.0],
-    [0.93968683, 0.19770011, 0.27911142, 1.0],
-    [0.9352947, 0.17353776, 0.30839673, 1.0],
-    [0.94144005, 0.21608114, 0.2588429, 1.0],
-    [0.9387771, 0.19991456, 0.28059208, 1.0],
-    [0.93570924, 0.18682602, 0.29923952, 1.0],
-    [0.9398809, 0.22043177, 0.26083288, 1.0],
-    [0.9373288, 0.20293921, 0.28324983, 1.0],
-    [0.938779, 0.19916497, 0.28111812, 1.0],
-    [0.93969727, 0.27903622, 0.19775712, 1.0],
-    [0.9414466, 0.25876758, 0.21614234, 1.0],
-    [0.935291, 0.30842286, 0.1735115, 1.0],
-    [0.9387767, 0.28061125, 0.19988948, 1.0],
-    [0.9398876, 0.2607361, 0.2205176, 1.0],
-    [0.9357075, 0.29925543, 0.18680887, 1.0],
-    [0.9373276, 0.28327477, 0.20290984, 1.0],
-    [0.9387832, 0.2810945, 0.19917797, 1.0],
-    [0.93969005, 0.24174936, 0.24195005, 1.0],
-    [0.93969005, 0.24174932, 0.24195, 1.0],
-    [0.94220793, 0.23685291, 0.23694922, 1.0],
-    [0.9420281, 0.23725574, 0.23726092, 1.0],
-    [0.8818505, 0.09641152, 0.46156737, 1.0],
-    [0.8645057, 0.072995126, 0.49729422, 1.0],
-    [0.89149207, 0.09379054, 0.44322145, 1.0],
-    [0.87672424, 0.073260225, 0.4753815, 1.0],
-    [0.8791554, 0.083373226, 0.46918494, 1.0],
-    [0.8373231, 0.045623343, 0.5448013, 1.0],
-    [0.8202119, 0.032659743, 0.5711268, 1.0],
-    [0.84838736, 0.055610746, 0.526447, 1.0],
-    [0.8494728, 0.04527973, 0.5256859, 1.0],
-    [0.835507, 0.03452387, 0.54839414, 1.0],
-    [0.85986704, 0.054707244, 0.5075784, 1.0],
-    [0.8422309, 0.04387656, 0.5373284, 1.0],
-    [0.89133453, 0.11215204, 0.4392546, 1.0],
-    [0.90022963, 0.10861524, 0.4216508, 1.0],
-    [0.89605474, 0.10999696, 0.43010062, 1.0],
-    [0.8984643, 0.09289803, 0.42910588, 1.0],
-    [0.8872602, 0.07680983, 0.45482916, 1.0],
-    [0.9015769, 0.093256764, 0.42244786, 1.0],
-    [0.89613897, 0.08338415, 0.43586934, 1.0],
-    [0.8960858, 0.086213216, 0.4354279, 1.0],
-    [0.8554837, 0.04596964, 0.5157853, 1.0],
-    [0.84938085, 0.039616514, 0.5262914, 1.0],
-    [0.8670837, 0.054465335, 0.49517608, 1.0],
-    [0.8600344, 0.046938226, 0.5080724, 1.0],
-    [0.8529742, 0.04089067, 0.5203489, 1.0],
-    [0.8788419, 0.06265161, 0.4729817, 1.0],
-    [0.861965, 0.047522414, 0.5047354, 1.0],
-    [0.8611732, 0.04762513, 0.5060755, 1.0],
-    [0.906543, 0.10680095, 0.40837893, 1.0],
-    [0.91022736, 0.105921336, 0.40033352, 1.0],
-    [0.9040447, 0.0940324, 0.41696662, 1.0],
-    [0.9071325, 0.10161437, 0.40839344, 1.0],
-    [0.88259983, 0.07500518, 0.46410328, 1.0],
-    [0.7971469, 0.019166555, 0.60348123, 1.0],
-    [0.77409494, 0.009643296, 0.6329961, 1.0],
-    [0.81388897, 0.021522215, 0.58062166, 1.0],
-    [0.7919097, 0.012179035, 0.61051685, 1.0],
-    [0.7945842, 0.014856905, 0.6069722, 1.0],
-    [0.7512205, 0.0035852606, 0.6600416, 1.0],
-    [0.72886753, 0.00069179694, 0.68465424, 1.0],
-    [0.769945, 0.006202088, 0.63808024, 1.0],
-    [0.7486194, 0.0033669886, 0.6629915, 1.0],
-    [0.74988794, 0.0027692257, 0.66155905, 1.0],
-    [0.8287084, 0.026578253, 0.55904907, 1.0],
-    [0.8079115, 0.01736052, 0.5890481, 1.0],
-    [0.8439263, 0.035444465, 0.5352868, 1.0],
-    [0.82227594, 0.025008835, 0.5685392, 1.0],
-    [0.85519385, 0.04317124, 0.51650727, 1.0],
-    [0.8322653, 0.02859795, 0.55363935, 1.0],
-    [0.7871578, 0.011448502, 0.6166454, 1.0],
-    [0.76662624, 0.008514957, 0.6420371, 1.0],
-    [0.80259645, 0.019041294, 0.5962184, 1.0],
-    [0.7828934, 0.015927939, 0.6219518, 1.0],
-    [0.7850691, 0.013129658, 0.61926913, 1.0],
-    [0.7918377, 0.011807739, 0.6106174, 1.0],
-    [0.8975364, 0.08376672, 0.4329106, 1.0],
-    [0.8975364, 0.08376664, 0.43291053, 1.0],
-    [0.88983077, 0.074571915, 0.4501558, 1.0],
-    [0.8709429, 0.056232013, 0.48815626, 1.0],
-    [0.8910693, 0.07824801, 0.44707143, 1.0],
-    [0.8817757, 0.07046238, 0.46637616, 1.0],
-    [0.883722, 0.06935149, 0.46284533, 1.0],
-    [0.9189636, 0.11955067, 0.3757838, 1.0],
-    [0.9045496, 0.09375633, 0.41593254, 1.0],
-    [0.9252097, 0.13399261, 0.3550112, 1.0],
-    [0.9195702, 0.12190351, 0.3735374, 1.0],
-    [0.91001743, 0.10434705, 0.4012233, 1.0],
-    [0.92530966, 0.1350903, 0.35433412, 1.0],
-    [0.91786766, 0.117416665, 0.37912053, 1.0],
-    [0.8987237, 0.08618801, 0.42996195, 1.0],
-    [0.89735705, 0.0868555, 0.4326736, 1.0],
-    [0.890617, 0.081506066, 0.44739038, 1.0],
-    [0.89564794, 0.08441422, 0.43667945, 1.0],
-    [0.88228256, 0.075335786, 0.46465257, 1.0],
-    [0.88228256, 0.07533571, 0.46465254, 1.0],
-    [0.91948545, 0.124159, 0.37300286, 1.0],
-    [0.9139005, 0.11608249, 0.3889868, 1.0],
-    [0.9263168, 0.14301632, 0.3485452, 1.0],
-    [0.9184801, 0.12680076, 0.37458748, 1.0],
-    [0.91189295, 0.11785851, 0.39314184, 1.0],
-    [0.9264505, 0.15095177, 0.34482327, 1.0],
-    [0.9190012, 0.1358624, 0.37010556, 1.0],
-    [0.91977155, 0.13017765, 0.37023503, 1.0],
-    [0.90212584, 0.09887973, 0.41999015, 1.0],
-    [0.8889726, 0.08422845, 0.45014814, 1.0],
-    [0.9060514, 0.11112066, 0.4083174, 1.0],
-    [0.8952366, 0.10154482, 0.43386656, 1.0],
-    [0.9082493, 0.12005139, 0.40083784, 1.0],
-    [0.9019812, 0.11410205, 0.41642597, 1.0],
-    [0.900879, 0.10452681, 0.4212972, 1.0],
-    [0.90121937, 0.09270098, 0.42333236, 1.0],
-    [0.8461463, 0.03867486, 0.5315455, 1.0],
-    [0.834971, 0.035063528, 0.5491756, 1.0],
-    [0.8547416, 0.04491573, 0.51710665, 1.0],
-    [0.8447857, 0.04155048, 0.5334892, 1.0],
-    [0.83470625, 0.03865415, 0.549337, 1.0],
-    [0.86329716, 0.05583461, 0.5015978, 1.0],
-    [0.8451314, 0.045794286, 0.5325936, 1.0],
-    [0.8464948, 0.042368937, 0.53070843, 1.0],
-    [0.8162748, 0.028778015, 0.5769466, 1.0],
-    [0.79755765, 0.025549311, 0.60270154, 1.0],
-    [0.8244951, 0.0363306, 0.5647017, 1.0],
-    [0.80763406, 0.03414703, 0.5886946, 1.0],
-    [0.8303591, 0.04266877, 0.5555925, 1.0],
-    [0.81384474, 0.040449966, 0.57967293, 1.0],
-    [0.81528133, 0.034137297, 0.57805794, 1.0],
-    [0.8717431, 0.07007721, 0.48492604, 1.0],
-    [0.85615027, 0.060929824, 0.51312196, 1.0],
-    [0.8702094, 0.074586436, 0.4870036, 1.0],
-    [0.86185676, 0.07119933, 0.502129, 1.0],
-    [0.8651164, 0.068929, 0.49681205, 1.0],
-    [0.8394216, 0.054520793, 0.54073936, 1.0],
-    [0.8225083, 0.050895624, 0.5664712, 1.0],
-    [0.84712327, 0.0669445, 0.52716285, 1.0],
-    [0.8315813, 0.06420865, 0.551679, 1.0],
-    [0.83537495, 0.058740024, 0.5465329, 1.0],
-    [0.87913966, 0.078991435, 0.46997222, 1.0],
-    [0.8786, 0.08488907, 0.46995324, 1.0],
-    [0.8659042, 0.07977345, 0.49380764, 1.0],
-    [0.8746363, 0.08081236, 0.47799665, 1.0],
-    [0.85163206, 0.075568385, 0.51866394, 1.0],
-    [0.8364984, 0.07278276, 0.54311436, 1.0],
-    [0.84413725, 0.07392016, 0.5310067, 1.0],
-    [0.8482184, 0.057663873, 0.5264982, 1.0],
-    [0.93151826, 0.16265847, 0.3252938, 1.0],
-    [0.92939097, 0.1630778, 0.33111626, 1.0],
-    [0.9246314, 0.15471168, 0.34802446, 1.0],
-    [0.9286481, 0.15976767, 0.3347939, 1.0],
-    [0.9286481, 0.15976763, 0.3347939, 1.0],
-    [0.8811724, 0.0665119, 0.46809307, 1.0],
-    [0.8818505, 0.46156737, 0.09641147, 1.0],
-    [0.8645057, 0.49729422, 0.072995126, 1.0],
-    [0.89149207, 0.44322145, 0.09379054, 1.0],
-    [0.87672424, 0.4753815, 0.073260225, 1.0],
-    [0.8791554, 0.46918494, 0.083373226, 1.0],
-    [0.89133453, 0.4392546, 0.11215204, 1.0],
-    [0.90022963, 0.42165074, 0.10861524, 1.0],
-    [0.89605474, 0.4301006, 0.10999695, 1.0],
-    [0.8373231, 0.54480124, 0.045623343, 1.0],
-    [0.84838736, 0.526447, 0.055610795, 1.0],
-    [0.8202119, 0.5711268, 0.032659743, 1.0],
-    [0.8494729, 0.5256859, 0.045279734, 1.0],
-    [0.859867, 0.5075784, 0.05470729, 1.0],
-    [0.835507, 0.54839414, 0.03452387, 1.0],
-    [0.842231, 0.5373284, 0.04387656, 1.0],
-    [0.8984643, 0.42910588, 0.09289803, 1.0],
-    [0.8872602, 0.45482916, 0.07680983, 1.0],
-    [0.9015769, 0.42244786, 0.093256764, 1.0],
-    [0.8961197, 0.43591195, 0.08336807, 1.0],
-    [0.89608055, 0.43543944, 0.08621024, 1.0],
-    [0.906543, 0.40837893, 0.10680095, 1.0],
-    [0.91022736, 0.40033352, 0.105921336, 1.0],
-    [0.90401727, 0.41703233, 0.09400435, 1.0],
-    [0.90712285, 0.40841657, 0.10160753, 1.0],
-    [0.8554837, 0.5157853, 0.04596964, 1.0],
-    [0.8670837, 0.49517608, 0.054465335, 1.0],
-    [0.84938085, 0.52629143, 0.03961656, 1.0],
-    [0.8600344, 0.5080724, 0.046938226, 1.0],
-    [0.8788419, 0.4729817, 0.0626517, 1.0],
-    [0.8529742, 0.5203489, 0.04089067, 1.0],
-    [0.86196506, 0.5047354, 0.04752242, 1.0],
-    [0.8611732, 0.5060755, 0.04762513, 1.0],
-    [0.8825961, 0.46411043, 0.07500431, 1.0],
-    [0.7971469, 0.60348123, 0.019166555, 1.0],
-    [0.77409494, 0.6329961, 0.009643296, 1.0],
-    [0.81388897, 0.58062166, 0.021522215, 1.0],
-    [0.7919097, 0.61051685, 0.012179035, 1.0],
-    [0.7945842, 0.6069722, 0.014856905, 1.0],
-    [0.7512205, 0.6600416, 0.0035852606, 1.0],
-    [0.72886753, 0.68465424, 0.00069179694, 1.0],
-    [0.7699449, 0.6380802, 0.0062020877, 1.0],
-    [0.7486193, 0.66299146, 0.0033669884, 1.0],
-    [0.74988794, 0.66155905, 0.0027692257, 1.0],
-    [0.8287084, 0.55904907, 0.026578253, 1.0],
-    [0.8079115, 0.5890481, 0.01736052, 1.0],
-    [0.84392637, 0.53528684, 0.03544447, 1.0],
-    [0.85519385, 0.51650727, 0.043171197, 1.0],
-    [0.82227594, 0.5685392, 0.025008835, 1.0],
-    [0.8322653, 0.5536393, 0.02859795, 1.0],
-    [0.7871578, 0.6166454, 0.011448502, 1.0],
-    [0.76662624, 0.6420371, 0.008514957, 1.0],
-    [0.80259645, 0.5962184, 0.019041294, 1.0],
-    [0.7828934, 0.6219518, 0.015927939, 1.0],
-    [0.7850691, 0.61926913, 0.013129658, 1.0],
-    [0.7918377, 0.6106174, 0.011807739, 1.0],
-    [0.8976003, 0.43276137, 0.08385182, 1.0],
-    [0.89760035, 0.43276146, 0.08385192, 1.0],
-    [0.9189365, 0.37586886, 0.11949199, 1.0],
-    [0.9251703, 0.35515213, 0.13389106, 1.0],
-    [0.9045855, 0.41584247, 0.09380996, 1.0],
-    [0.919562, 0.37356663, 0.12187547, 1.0],
-    [0.9253031, 0.3543612, 0.13506414, 1.0],
-    [0.9100174, 0.40122327, 0.10434704, 1.0],
-    [0.917857, 0.3791535, 0.11739354, 1.0],
-    [0.88988817, 0.45003074, 0.074641325, 1.0],
-    [0.8709429, 0.48815626, 0.056232013, 1.0],
-    [0.89113563, 0.44692692, 0.07831796, 1.0],
-    [0.8818593, 0.46620718, 0.07053381, 1.0],
-    [0.88377523, 0.4627358, 0.06940462, 1.0],
-    [0.89880943, 0.42975828, 0.08631015, 1.0],
-    [0.8973947, 0.4325866, 0.08689957, 1.0],
-    [0.8907476, 0.4471069, 0.08163437, 1.0],
-    [0.8957315, 0.43648812, 0.084517114, 1.0],
-    [0.9194489, 0.37312868, 0.12405051, 1.0],
-    [0.92628765, 0.34865433, 0.14293785, 1.0],
-    [0.9138654, 0.38909185, 0.11600625, 1.0],
-    [0.91843385, 0.37473, 0.12671539, 1.0],
-    [0.92645013, 0.34480366, 0.150998, 1.0],
-    [0.91181606, 0.3933607, 0.117723286, 1.0],
-    [0.91896886, 0.37021193, 0.13579224, 1.0],
-    [0.9197379, 0.37034523, 0.13010229, 1.0],
-    [0.8822817, 0.46465433, 0.07533543, 1.0],
-    [0.8822817, 0.46465433, 0.07533543, 1.0],
-    [0.90212584, 0.41999015, 0.09887973, 1.0],
-    [0.8889721, 0.450149, 0.08422827, 1.0],
-    [0.9060118, 0.40841863, 0.11107128, 1.0],
-    [0.9082114, 0.40093827, 0.120002426, 1.0],
-    [0.8952295, 0.43388945, 0.10150937, 1.0],
-    [0.9019731, 0.4164601, 0.11404173, 1.0],
-    [0.90086186, 0.4213413, 0.10449647, 1.0],
-    [0.90123415, 0.4232969, 0.092718706, 1.0],
-    [0.9314889, 0.32542285, 0.16256852, 1.0],
-    [0.92939097, 0.33111626, 0.1630778, 1.0],
-    [0.92463416, 0.3480387, 0.15466347, 1.0],
-    [0.92863834, 0.3348415, 0.15972504, 1.0],
-    [0.92863834, 0.3348415, 0.15972504, 1.0],
-    [0.8461463, 0.5315455, 0.03867486, 1.0],
-    [0.8547416, 0.51710665, 0.04491573, 1.0],
-    [0.83494025, 0.5492244, 0.035033844, 1.0],
-    [0.8447387, 0.53356767, 0.04149828, 1.0],
-    [0.8632972, 0.5015979, 0.055834617, 1.0],
-    [0.83465755, 0.54941493, 0.03860042, 1.0],
-    [0.84510493, 0.53263843, 0.045760263, 1.0],
-    [0.84647346, 0.53074425, 0.042345133, 1.0],
-    [0.81627476, 0.57694656, 0.028778011, 1.0],
-    [0.79755753, 0.6027015, 0.02554931, 1.0],
-    [0.82449496, 0.5647016, 0.036330596, 1.0],
-    [0.83035916, 0.55559254, 0.042668775, 1.0],
-    [0.80763406, 0.5886945, 0.03414703, 1.0],
-    [0.81384474, 0.57967293, 0.040449966, 1.0],
-    [0.81528133, 0.57805794, 0.034137297, 1.0],
-    [0.8716997, 0.4850077, 0.07005048, 1.0],
-    [0.8561504, 0.51312196, 0.06092983, 1.0],
-    [0.8702094, 0.4870036, 0.074586436, 1.0],
-    [0.86185676, 0.502129, 0.071199365, 1.0],
-    [0.86510545, 0.49683207, 0.06892344, 1.0],
-    [0.8790707, 0.4701092, 0.07894323, 1.0],
-    [0.8786, 0.46995327, 0.08488907, 1.0],
-    [0.8659042, 0.49380764, 0.07977345, 1.0],
-    [0.8746131, 0.4780414, 0.0807984, 1.0],
-    [0.8394216, 0.54073936, 0.054520793, 1.0],
-    [0.8225084, 0.5664713, 0.050895628, 1.0],
-    [0.84712327, 0.52716285, 0.0669445, 1.0],
-    [0.8315813, 0.551679, 0.06420865, 1.0],
-    [0.83537495, 0.5465329, 0.058740024, 1.0],
-    [0.85163206, 0.51866394, 0.07556832, 1.0],
-    [0.8364984, 0.54311436, 0.07278276, 1.0],
-    [0.84413725, 0.5310067, 0.07392016, 1.0],
-    [0.84820914, 0.5265139, 0.057656802, 1.0],
-    [0.881168, 0.46810228, 0.06650614, 1.0],
-    [0.9337284, 0.18914212, 0.30393538, 1.0],
-    [0.9380753, 0.22533141, 0.26313588, 1.0],
-    [0.93541425, 0.20895152, 0.28520066, 1.0],
-    [0.933095, 0.21527055, 0.2880837, 1.0],
-    [0.9354354, 0.20935486, 0.28483516, 1.0],
-    [0.9380753, 0.26313588, 0.22533141, 1.0],
-    [0.9337353, 0.30387643, 0.18920189, 1.0],
-    [0.9354184, 0.28512818, 0.20903164, 1.0],
-    [0.9330984, 0.28799915, 0.21536912, 1.0],
-    [0.9354385, 0.28478095, 0.20941499, 1.0],
-    [0.9375834, 0.24590386, 0.24590386, 1.0],
-    [0.9358733, 0.22948985, 0.26734906, 1.0],
-    [0.9358724, 0.26726586, 0.22959049, 1.0],
-    [0.93511313, 0.25056282, 0.25056282, 1.0],
-    [0.9332029, 0.23515002, 0.27172938, 1.0],
-    [0.93320876, 0.27167878, 0.23518516, 1.0],
-    [0.93219346, 0.25594592, 0.25594333, 1.0],
-    [0.9351117, 0.2505465, 0.25058421, 1.0],
-    [0.9302162, 0.24141486, 0.27643588, 1.0],
-    [0.930232, 0.2764108, 0.24138236, 1.0],
-    [0.9288973, 0.2618687, 0.26186746, 1.0],
-    [0.9252718, 0.2682089, 0.2682089, 1.0],
-    [0.9289665, 0.2617459, 0.26174495, 1.0],
-    [0.9351118, 0.2505471, 0.25058338, 1.0],
-    [0.9351118, 0.2505471, 0.25058338, 1.0],
-    [0.9188378, 0.14512455, 0.36698234, 1.0],
-    [0.9188378, 0.14512448, 0.3669823, 1.0],
-    [0.8943026, 0.10785789, 0.43426877, 1.0],
-    [0.91121805, 0.1356673, 0.38894212, 1.0],
-    [0.8997716, 0.11889414, 0.4198515, 1.0],
-    [0.909175, 0.14157046, 0.39161032, 1.0],
-    [0.90203536, 0.13458395, 0.41014567, 1.0],
-    [0.9037069, 0.1273487, 0.40877393, 1.0],
-    [0.91163015, 0.13550228, 0.38803294, 1.0],
-    [0.8849788, 0.10172791, 0.45438313, 1.0],
-    [0.8721976, 0.096833676, 0.4794732, 1.0],
-    [0.8876637, 0.11165879, 0.44674972, 1.0],
-    [0.87888294, 0.10710877, 0.46485743, 1.0],
-    [0.87019527, 0.103490874, 0.48171547, 1.0],
-    [0.8909031, 0.12761258, 0.43589753, 1.0],
-    [0.87725073, 0.11453268, 0.4661689, 1.0],
-    [0.8805901, 0.10858505, 0.4612704, 1.0],
-    [0.8574316, 0.088848725, 0.5068697, 1.0],
-    [0.8422901, 0.08470143, 0.5323279, 1.0],
-    [0.86162895, 0.10057189, 0.49747434, 1.0],
-    [0.84738946, 0.09731186, 0.52197856, 1.0],
-    [0.86478704, 0.11120579, 0.48967004, 1.0],
-    [0.85094595, 0.10790401, 0.51405007, 1.0],
-    [0.8543617, 0.098050095, 0.51034516, 1.0],
-    [0.8886142, 0.13505542, 0.43832052, 1.0],
-    [0.88092697, 0.13073124, 0.45483735, 1.0],
-    [0.8848201, 0.13279505, 0.44660836, 1.0],
-    [0.86824656, 0.12556356, 0.47998103, 1.0],
-    [0.8548871, 0.122080244, 0.50424635, 1.0],
-    [0.8616631, 0.12360841, 0.49219674, 1.0],
-    [0.87103486, 0.11472677, 0.47763577, 1.0],
-    [0.92939836, 0.17788748, 0.32338002, 1.0],
-    [0.9221639, 0.15752614, 0.35326943, 1.0],
-    [0.9314219, 0.19331785, 0.30835286, 1.0],
-    [0.926969, 0.1819726, 0.32804653, 1.0],
-    [0.9223209, 0.1726245, 0.3457238, 1.0],
-    [0.9290744, 0.2007989, 0.31064543, 1.0],
-    [0.922441, 0.18470493, 0.33909687, 1.0],
-    [0.92665297, 0.18086264, 0.32954964, 1.0],
-    [0.91570544, 0.14949758, 0.37300658, 1.0],
-    [0.9142377, 0.15903674, 0.37266183, 1.0],
-    [0.903556, 0.14612281, 0.40278363, 1.0],
-    [0.9181339, 0.17716715, 0.3544601, 1.0],
-    [0.9133054, 0.1575015, 0.3755882, 1.0],
-    [0.93004674, 0.22040515, 0.29399773, 1.0],
-    [0.93004674, 0.22040515, 0.29399773, 1.0],
-    [0.9263603, 0.20861915, 0.31358352, 1.0],
-    [0.92210686, 0.19840051, 0.33219928, 1.0],
-    [0.924322, 0.2033899, 0.32289532, 1.0],
-    [0.91237044, 0.1688224, 0.37293327, 1.0],
-    [0.9048047, 0.16027053, 0.3945146, 1.0],
-    [0.9154656, 0.18639866, 0.35662082, 1.0],
-    [0.90537596, 0.17339723, 0.38759252, 1.0],
-    [0.91240096, 0.19598158, 0.35932666, 1.0],
-    [0.9055684, 0.18693487, 0.38079035, 1.0],
-    [0.9096594, 0.17833877, 0.37512022, 1.0],
-    [0.9269114, 0.22787836, 0.29817212, 1.0],
-    [0.9212595, 0.21244754, 0.32580206, 1.0],
-    [0.9263807, 0.24799156, 0.28340614, 1.0],
-    [0.92344606, 0.23561446, 0.30287495, 1.0],
-    [0.9200763, 0.22559097, 0.32026315, 1.0],
-    [0.9233467, 0.25383428, 0.2880955, 1.0],
-    [0.9181778, 0.24595624, 0.31057236, 1.0],
-    [0.92316824, 0.23532802, 0.3039427, 1.0],
-    [0.917516, 0.20452833, 0.3410756, 1.0],
-    [0.91377866, 0.21366854, 0.34547678, 1.0],
-    [0.9051883, 0.20213968, 0.3738633, 1.0],
-    [0.9142403, 0.23221685, 0.3320238, 1.0],
-    [0.9130341, 0.21279311, 0.34797683, 1.0],
-    [0.9214599, 0.19705535, 0.3347847, 1.0],
-    [0.8961276, 0.14000244, 0.421135, 1.0],
-    [0.8961275, 0.1400024, 0.42113495, 1.0],
-    [0.8945887, 0.15104263, 0.42059138, 1.0],
-    [0.8829458, 0.1432345, 0.44709122, 1.0],
-    [0.89948124, 0.1676583, 0.40351486, 1.0],
-    [0.89259666, 0.16218297, 0.4206753, 1.0],
-    [0.88457537, 0.15711118, 0.4391384, 1.0],
-    [0.8962552, 0.17824344, 0.40614772, 1.0],
-    [0.88558817, 0.17128214, 0.43173605, 1.0],
-    [0.89118433, 0.16119681, 0.4240355, 1.0],
-    [0.8707325, 0.13800292, 0.47199577, 1.0],
-    [0.85770243, 0.13428396, 0.49630067, 1.0],
-    [0.8728351, 0.1516525, 0.4638538, 1.0],
-    [0.8601762, 0.14778993, 0.48811388, 1.0],
-    [0.8741954, 0.16555, 0.45648172, 1.0],
-    [0.861979, 0.16159177, 0.48050013, 1.0],
-    [0.8665702, 0.14951082, 0.47613308, 1.0],
-    [0.89901465, 0.1950769, 0.3920685, 1.0],
-    [0.89901465, 0.19507687, 0.3920685, 1.0],
-    [0.8894531, 0.16004504, 0.42808735, 1.0],
-    [0.9151828, 0.2519546, 0.31457785, 1.0],
-    [0.9151828, 0.2519546, 0.31457785, 1.0],
-    [0.9096816, 0.22324951, 0.35019848, 1.0],
-    [0.9044395, 0.21515867, 0.36836934, 1.0],
-    [0.9106946, 0.23929429, 0.33671013, 1.0],
-    [0.90314925, 0.23549831, 0.35897347, 1.0],
-    [0.89970994, 0.2426756, 0.36280936, 1.0],
-    [0.9057677, 0.23097235, 0.35529822, 1.0],
-    [0.9109037, 0.24099082, 0.33492967, 1.0],
-    [0.89263046, 0.18919823, 0.40916356, 1.0],
-    [0.8860405, 0.18463963, 0.4252534, 1.0],
-    [0.8893892, 0.18683842, 0.41722688, 1.0],
-    [0.87498266, 0.17880043, 0.44992855, 1.0],
-    [0.8631586, 0.17464405, 0.47376874, 1.0],
-    [0.86917996, 0.1765453, 0.46190685, 1.0],
-    [0.89571464, 0.2031705, 0.3954959, 1.0],
-    [0.88578004, 0.20265399, 0.41752276, 1.0],
-    [0.89480966, 0.22231844, 0.38715637, 1.0],
-    [0.8884289, 0.21604857, 0.40499026, 1.0],
-    [0.8819311, 0.2111951, 0.4214192, 1.0],
-    [0.8937264, 0.2347939, 0.38226297, 1.0],
-    [0.88479173, 0.22409873, 0.40856245, 1.0],
-    [0.8895889, 0.2160846, 0.40241656, 1.0],
-    [0.8754784, 0.19500142, 0.4421675, 1.0],
-    [0.86406213, 0.19062626, 0.46589524, 1.0],
-    [0.8754135, 0.20760143, 0.43652362, 1.0],
-    [0.8643151, 0.20317788, 0.46008497, 1.0],
-    [0.87485087, 0.22214626, 0.43044958, 1.0],
-    [0.8640477, 0.21887413, 0.45333824, 1.0],
-    [0.8699639, 0.20602348, 0.44801477, 1.0],
-    [0.8802307, 0.19565399, 0.4323348, 1.0],
-    [0.90175694, 0.16974473, 0.397519, 1.0],
-    [0.91879857, 0.36712858, 0.14500254, 1.0],
-    [0.91879857, 0.36712858, 0.14500254, 1.0],
-    [0.91117525, 0.38905358, 0.13563548, 1.0],
-    [0.89423424, 0.43443808, 0.10774438, 1.0],
-    [0.89970195, 0.42002943, 0.11879235, 1.0],
-    [0.9091483, 0.39166787, 0.14158282, 1.0],
-    [0.901997, 0.4102425, 0.13454573, 1.0],
-    [0.90366024, 0.4088944, 0.12729332, 1.0],
-    [0.91158646, 0.38816696, 0.13541242, 1.0],
-    [0.92939454, 0.3234015, 0.17786863, 1.0],
-    [0.93141717, 0.30838752, 0.1932854, 1.0],
-    [0.9221216, 0.3534325, 0.15740809, 1.0],
-    [0.9269488, 0.3281697, 0.18185341, 1.0],
-    [0.92908037, 0.31061953, 0.20081164, 1.0],
-    [0.92226946, 0.34591198, 0.17252237, 1.0],
-    [0.92241734, 0.3391883, 0.18465553, 1.0],
-    [0.92663527, 0.32963434, 0.18079898, 1.0],
-    [0.9300621, 0.29383567, 0.22055604, 1.0],
-    [0.9300622, 0.2938356, 0.22055599, 1.0],
-    [0.91563934, 0.373191, 0.14944229, 1.0],
-    [0.9142332, 0.37265295, 0.15908314, 1.0],
-    [0.91814536, 0.3543893, 0.17724934, 1.0],
-    [0.90351886, 0.40288067, 0.14608523, 1.0],
-    [0.9132835, 0.37563777, 0.15751013, 1.0],
-    [0.9263831, 0.31342018, 0.20876338, 1.0],
-    [0.92210686, 0.33219928, 0.19840051, 1.0],
-    [0.9243351, 0.32281345, 0.20346045, 1.0],
-    [0.9269113, 0.29810095, 0.2279722, 1.0],
-    [0.9263807, 0.2834061, 0.24799156, 1.0],
-    [0.9212484, 0.3258123, 0.21248016, 1.0],
-    [0.92342967, 0.3028924, 0.2356566, 1.0],
-    [0.9233467, 0.2880955, 0.25383428, 1.0],
-    [0.92004377, 0.32036397, 0.22558022, 1.0],
-    [0.9181535, 0.31065148, 0.24594672, 1.0],
-    [0.92315644, 0.30396312, 0.23534769, 1.0],
-    [0.9123847, 0.3728619, 0.16890293, 1.0],
-    [0.91547495, 0.35655206, 0.18648398, 1.0],
-    [0.9047695, 0.3946107, 0.16023298, 1.0],
-    [0.90537584, 0.3875925, 0.1733972, 1.0],
-    [0.91240823, 0.3592601, 0.19607002, 1.0],
-    [0.9055365, 0.38088322, 0.18690051, 1.0],
-    [0.9096549, 0.37511715, 0.1783676, 1.0],
-    [0.9175204, 0.34100989, 0.20461822, 1.0],
-    [0.91377074, 0.34550044, 0.21366455, 1.0],
-    [0.9142109, 0.3321439, 0.23216143, 1.0],
-    [0.90515786, 0.37395528, 0.2021053, 1.0],
-    [0.91301787, 0.34802055, 0.2127914, 1.0],
-    [0.9214569, 0.33477706, 0.19708276, 1.0],
-    [0.8849317, 0.45448193, 0.10169595, 1.0],
-    [0.88761836, 0.44684795, 0.1116268, 1.0],
-    [0.8721724, 0.4795335, 0.09676233, 1.0],
-    [0.87884706, 0.46495345, 0.106986545, 1.0],
-    [0.89086246, 0.4360104, 0.12751064, 1.0],
-    [0.8701562, 0.48181206, 0.103369124, 1.0],
-    [0.87719303, 0.46630055, 0.114438936, 1.0],
-    [0.88054806, 0.4613697, 0.10850439, 1.0],
-    [0.8574316, 0.5068697, 0.088848725, 1.0],
-    [0.8422901, 0.5323279, 0.08470143, 1.0],
-    [0.86162895, 0.49747434, 0.10057189, 1.0],
-    [0.864787, 0.48966998, 0.11120574, 1.0],
-    [0.84738946, 0.52197856, 0.09731189, 1.0],
-    [0.85094595, 0.51405007, 0.10790401, 1.0],
-    [0.8543617, 0.51034516, 0.098050125, 1.0],
-    [0.88849264, 0.43863067, 0.1348475, 1.0],
-    [0.88083625, 0.4550268, 0.13068369, 1.0],
-    [0.8847129, 0.44685835, 0.13266777, 1.0],
-    [0.86824656, 0.47998103, 0.12556356, 1.0],
-    [0.8548871, 0.50424635, 0.122080214, 1.0],
-    [0.8616631, 0.49219674, 0.12360841, 1.0],
-    [0.8709936, 0.47772244, 0.11467913, 1.0],
-    [0.8960701, 0.42131507, 0.1398283, 1.0],
-    [0.8960701, 0.42131507, 0.1398283, 1.0],
-    [0.8945364, 0.42074177, 0.15093401, 1.0],
-    [0.89948124, 0.40351486, 0.1676583, 1.0],
-    [0.88290197, 0.4471854, 0.14321047, 1.0],
-    [0.8925967, 0.42067537, 0.16218299, 1.0],
-    [0.89621586, 0.40621457, 0.17828824, 1.0],
-    [0.88457537, 0.4391384, 0.15711118, 1.0],
-    [0.8855478, 0.43182772, 0.17125951, 1.0],
-    [0.8911602, 0.4240923, 0.16118036, 1.0],
-    [0.89897966, 0.39208436, 0.19520567, 1.0],
-    [0.89897966, 0.39208436, 0.19520567, 1.0],
-    [0.8707325, 0.47199577, 0.13800292, 1.0],
-    [0.85770243, 0.49630067, 0.13428396, 1.0],
-    [0.8728351, 0.4638538, 0.15165253, 1.0],
-    [0.8741954, 0.45648172, 0.16555, 1.0],
-    [0.8601762, 0.48811388, 0.14778993, 1.0],
-    [0.861979, 0.48050013, 0.16159177, 1.0],
-    [0.8665702, 0.47613308, 0.14951079, 1.0],
-    [0.8894287, 0.42814413, 0.16002917, 1.0],
-    [0.91516495, 0.3145946, 0.25199854, 1.0],
-    [0.91516495, 0.3145946, 0.25199854, 1.0],
-    [0.9096973, 0.3501887, 0.22320077, 1.0],
-    [0.9106942, 0.33671212, 0.2392928, 1.0],
-    [0.9044395, 0.36836934, 0.21515864, 1.0],
-    [0.90314925, 0.35897347, 0.23549831, 1.0],
-    [0.89970994, 0.36280936, 0.2426756, 1.0],
-    [0.90577096, 0.35529643, 0.2309621, 1.0],
-    [0.91089636, 0.33493644, 0.2410087, 1.0],
-    [0.8925469, 0.4093015, 0.1892944, 1.0],
-    [0.88596255, 0.42543557, 0.1845938, 1.0],
-    [0.8893089, 0.41738653, 0.1868638, 1.0],
-    [0.89569134, 0.39550808, 0.20324947, 1.0],
-    [0.8948097, 0.3871564, 0.22231847, 1.0],
-    [0.8857532, 0.41753712, 0.20274137, 1.0],
-    [0.88842714, 0.40489846, 0.2162275, 1.0],
-    [0.8937264, 0.38226297, 0.2347939, 1.0],
-    [0.8819331, 0.42132598, 0.2113724, 1.0],
-    [0.8847891, 0.40850917, 0.2242065, 1.0],
-    [0.8895803, 0.40238696, 0.21617533, 1.0],
-    [0.87498266, 0.44992855, 0.17880043, 1.0],
-    [0.8631585, 0.47376868, 0.17464396, 1.0],
-    [0.8691799, 0.46190682, 0.1765453, 1.0],
-    [0.8754784, 0.4421675, 0.19500142, 1.0],
-    [0.86406213, 0.46589524, 0.19062626, 1.0],
-    [0.8754135, 0.43652362, 0.2076014, 1.0],
-    [0.87485087, 0.43044958, 0.22214626, 1.0],
-    [0.8643151, 0.46008497, 0.20317791, 1.0],
-    [0.8640477, 0.45333824, 0.21887413, 1.0],
-    [0.8699639, 0.44801477, 0.20602348, 1.0],
-    [0.88020855, 0.43236586, 0.1956853, 1.0],
-    [0.9017387, 0.39756677, 0.16972901, 1.0],
-    [0.91976833, 0.27751237, 0.27751237, 1.0],
-    [0.9181858, 0.26320982, 0.29606676, 1.0],
-    [0.9181858, 0.29606676, 0.26320982, 1.0],
-    [0.91900426, 0.2787752, 0.2787752, 1.0],
-    [0.91900426, 0.27877516, 0.27877516, 1.0],
-    [0.91688675, 0.28232256, 0.28215718, 1.0],
-    [0.9133844, 0.27148378, 0.30335745, 1.0],
-    [0.9133801, 0.3034409, 0.27140462, 1.0],
-    [0.9104613, 0.29253548, 0.29237506, 1.0],
-    [0.9082039, 0.28008834, 0.31099215, 1.0],
-    [0.9082039, 0.31099215, 0.28008834, 1.0],
-    [0.9072735, 0.29736763, 0.2973677, 1.0],
-    [0.9114237, 0.29098728, 0.2909182, 1.0],
-    [0.907767, 0.2591319, 0.32986322, 1.0],
-    [0.90431976, 0.2507692, 0.3454279, 1.0],
-    [0.90563446, 0.2715853, 0.32568023, 1.0],
-    [0.9001153, 0.2581633, 0.35091898, 1.0],
-    [0.9019789, 0.28977498, 0.32010067, 1.0],
-    [0.89570576, 0.28386903, 0.3422419, 1.0],
-    [0.9028986, 0.26873225, 0.3354954, 1.0],
-    [0.907767, 0.32986322, 0.2591319, 1.0],
-    [0.90431976, 0.3454279, 0.2507692, 1.0],
-    [0.90563446, 0.32568023, 0.2715853, 1.0],
-    [0.90197897, 0.32010067, 0.289775, 1.0],
-    [0.9001153, 0.35091898, 0.2581633, 1.0],
-    [0.89569604, 0.34221432, 0.2839331, 1.0],
-    [0.9028972, 0.33549038, 0.26874292, 1.0],
-    [0.8972163, 0.31220555, 0.31229886, 1.0],
-    [0.89541173, 0.29935065, 0.3295864, 1.0],
-    [0.89541924, 0.32943153, 0.29949844, 1.0],
-    [0.89626896, 0.31354347, 0.31367564, 1.0],
-    [0.8920305, 0.29005158, 0.34662893, 1.0],
-    [0.89203054, 0.29005155, 0.3466289, 1.0],
-    [0.89201313, 0.34658438, 0.29015806, 1.0],
-    [0.89201313, 0.34658438, 0.29015806, 1.0],
-    [0.8937839, 0.31705892, 0.3172127, 1.0],
-    [0.8906933, 0.306276, 0.33594707, 1.0],
-    [0.8906978, 0.33585542, 0.30636343, 1.0],
-    [0.88361853, 0.33107284, 0.33107284, 1.0],
-    [0.8899428, 0.32245022, 0.32253295, 1.0],
-    [0.89941025, 0.30905744, 0.309103, 1.0],
-    [0.89063853, 0.25638425, 0.37553957, 1.0],
-    [0.8828667, 0.24680379, 0.39954257, 1.0],
-    [0.8910144, 0.27128917, 0.36399958, 1.0],
-    [0.88844204, 0.25795898, 0.37964186, 1.0],
-    [0.873548, 0.2396286, 0.4236651, 1.0],
-    [0.8632081, 0.2346228, 0.44701675, 1.0],
-    [0.868495, 0.23698442, 0.43537903, 1.0],
-    [0.88615406, 0.26414487, 0.38073403, 1.0],
-    [0.88131803, 0.25889814, 0.39528507, 1.0],
-    [0.88709766, 0.27821907, 0.3683094, 1.0],
-    [0.87894326, 0.2743205, 0.3901373, 1.0],
-    [0.8835232, 0.26879126, 0.3835859, 1.0],
-    [0.872254, 0.2516501, 0.41933897, 1.0],
-    [0.86217165, 0.24659143, 0.44255248, 1.0],
-    [0.86971545, 0.27021563, 0.41301164, 1.0],
-    [0.85980564, 0.266577, 0.43551245, 1.0],
-    [0.8662, 0.2586101, 0.4275727, 1.0],
-    [0.8824315, 0.30265772, 0.36015698, 1.0],
-    [0.8739708, 0.2990991, 0.38303357, 1.0],
-    [0.8774328, 0.32526776, 0.3525798, 1.0],
-    [0.87368405, 0.31323537, 0.3722363, 1.0],
-    [0.86911047, 0.3061229, 0.3885046, 1.0],
-    [0.87347555, 0.33077478, 0.3572512, 1.0],
-    [0.8691853, 0.31963643, 0.37729222, 1.0],
-    [0.8744355, 0.31369385, 0.3700793, 1.0],
-    [0.8663832, 0.28978956, 0.4066967, 1.0],
-    [0.85718536, 0.2838641, 0.42971432, 1.0],
-    [0.86408025, 0.3012177, 0.40327802, 1.0],
-    [0.85512924, 0.29518563, 0.4261684, 1.0],
-    [0.8607347, 0.3161305, 0.39899534, 1.0],
-    [0.8515905, 0.31217605, 0.4211171, 1.0],
-    [0.85942584, 0.29960737, 0.4142738, 1.0],
-    [0.8746135, 0.2719603, 0.40135857, 1.0],
-    [0.89063853, 0.37553957, 0.25638425, 1.0],
-    [0.8910144, 0.36399958, 0.27128917, 1.0],
-    [0.8828667, 0.39954257, 0.24680379, 1.0],
-    [0.88844204, 0.37964186, 0.25795898, 1.0],
-    [0.873548, 0.4236651, 0.2396286, 1.0],
-    [0.8632081, 0.44701675, 0.23462287, 1.0],
-    [0.868495, 0.435379, 0.2369844, 1.0],
-    [0.88615406, 0.38073403, 0.26414487, 1.0],
-    [0.88709766, 0.3683094, 0.27821907, 1.0],
-    [0.88131803, 0.39528507, 0.25889814, 1.0],
-    [0.87894326, 0.3901373, 0.2743205, 1.0],
-    [0.8835232, 0.3835859, 0.26879126, 1.0],
-    [0.8824315, 0.36015698, 0.30265772, 1.0],
-    [0.8774328, 0.3525798, 0.32526776, 1.0],
-    [0.8739586, 0.38301173, 0.29916278, 1.0],
-    [0.8736603, 0.37220213, 0.31334186, 1.0],
-    [0.87347555, 0.3572512, 0.33077478, 1.0],
-    [0.8690889, 0.38846928, 0.3062288, 1.0],
-    [0.86917055, 0.37727228, 0.31970018, 1.0],
-    [0.8744246, 0.37006372, 0.3137426, 1.0],
-    [0.872254, 0.41933897, 0.2516501, 1.0],
-    [0.8697154, 0.4130116, 0.27021563, 1.0],
-    [0.8621717, 0.4425525, 0.24659146, 1.0],
-    [0.85980564, 0.43551245, 0.266577, 1.0],
-    [0.8662, 0.4275727, 0.2586101, 1.0],
-    [0.86638314, 0.4066967, 0.28978956, 1.0],
-    [0.85718536, 0.42971432, 0.2838641, 1.0],
-    [0.86408025, 0.40327802, 0.3012177, 1.0],
-    [0.8607347, 0.39899534, 0.3161305, 1.0],
-    [0.85512924, 0.4261684, 0.29518563, 1.0],
-    [0.8515906, 0.42111713, 0.31217608, 1.0],
-    [0.85942584, 0.41427377, 0.29960734, 1.0],
-    [0.87461257, 0.4013555, 0.2719683, 1.0],
-    [0.8762827, 0.34068215, 0.34068215, 1.0],
-    [0.87125945, 0.34706396, 0.34706396, 1.0],
-    [0.863029, 0.3430701, 0.370788, 1.0],
-    [0.863029, 0.370788, 0.34307006, 1.0],
-    [0.856044, 0.36550552, 0.36550552, 1.0],
-    [0.8662125, 0.3533242, 0.3533242, 1.0],
-    [0.8549695, 0.3385561, 0.39294642, 1.0],
-    [0.84614563, 0.3345357, 0.4148775, 1.0],
-    [0.8483148, 0.36073112, 0.38760173, 1.0],
-    [0.83984935, 0.3564181, 0.40941322, 1.0],
-    [0.8475415, 0.3474814, 0.40116072, 1.0],
-    [0.8549695, 0.39294642, 0.3385561, 1.0],
-    [0.8483148, 0.38760173, 0.36073112, 1.0],
-    [0.84614563, 0.4148775, 0.3345357, 1.0],
-    [0.83984935, 0.40941322, 0.3564181, 1.0],
-    [0.8475415, 0.40116072, 0.3474814, 1.0],
-    [0.8408482, 0.3827363, 0.3827363, 1.0],
-    [0.8327393, 0.37812334, 0.40443546, 1.0],
-    [0.8327393, 0.40443546, 0.37812334, 1.0],
-    [0.8248787, 0.39973438, 0.39973438, 1.0],
-    [0.83301324, 0.39120898, 0.39120898, 1.0],
-    [0.8495353, 0.3730213, 0.3730213, 1.0],
-    [0.8886449, 0.3242707, 0.32428187, 1.0],
-    [0.9351133, 0.25056496, 0.25056, 1.0],
-    [0.7071066, 0.00067114277, 0.7071066, 1.0],
-    [0.6858264, 0.0032731222, 0.7277579, 1.0],
-    [0.7277579, 0.0032731618, 0.6858264, 1.0],
-    [0.7070953, 0.005695819, 0.7070953, 1.0],
-    [0.7071044, 0.0026112322, 0.7071044, 1.0],
-    [0.66546845, 0.008290119, 0.7463799, 1.0],
-    [0.6458661, 0.015529205, 0.7632928, 1.0],
-    [0.68706405, 0.01056658, 0.72652, 1.0],
-    [0.6676983, 0.017656432, 0.7442226, 1.0],
-    [0.6666241, 0.012464382, 0.7452898, 1.0],
-    [0.7463799, 0.008290041, 0.66546845, 1.0],
-    [0.72652, 0.010566617, 0.68706405, 1.0],
-    [0.76329273, 0.015529201, 0.645866, 1.0],
-    [0.7442226, 0.017656395, 0.6676983, 1.0],
-    [0.7452898, 0.0124643445, 0.6666241, 1.0],
-    [0.7070252, 0.015192659, 0.7070252, 1.0],
-    [0.68793833, 0.02206349, 0.7254336, 1.0],
-    [0.7254336, 0.02206349, 0.68793833, 1.0],
-    [0.7067952, 0.02968503, 0.7067952, 1.0],
-    [0.7066567, 0.0356729, 0.7066567, 1.0],
-    [0.70689815, 0.024290135, 0.70689815, 1.0],
-    [0.70706743, 0.010543661, 0.70706743, 1.0],
-    [0.62663925, 0.024951976, 0.77890986, 1.0],
-    [0.6127081, 0.03338471, 0.78960395, 1.0],
-    [0.6037251, 0.03957347, 0.79620975, 1.0],
-    [0.6488309, 0.026810218, 0.7604602, 1.0],
-    [0.6367796, 0.033942472, 0.7702984, 1.0],
-    [0.6278222, 0.039943878, 0.7773311, 1.0],
-    [0.62614167, 0.032619216, 0.7790267, 1.0],
-    [0.59037435, 0.04984866, 0.8055887, 1.0],
-    [0.5757364, 0.06294445, 0.81520885, 1.0],
-    [0.6128897, 0.05121387, 0.788507, 1.0],
-    [0.59939307, 0.063059896, 0.79796696, 1.0],
-    [0.5946565, 0.056393217, 0.8019997, 1.0],
-    [0.56731415, 0.071382225, 0.82040185, 1.0],
-    [0.59096164, 0.07130122, 0.8035425, 1.0],
-    [0.5792645, 0.07110876, 0.81203216, 1.0],
-    [0.66848314, 0.031179182, 0.7430736, 1.0],
-    [0.65192014, 0.0350311, 0.75747806, 1.0],
-    [0.66856545, 0.036488395, 0.7427575, 1.0],
-    [0.65237105, 0.04097829, 0.756791, 1.0],
-    [0.68968105, 0.033062574, 0.72335815, 1.0],
-    [0.689443, 0.03801082, 0.7233419, 1.0],
-    [0.671152, 0.047845643, 0.7397742, 1.0],
-    [0.6703755, 0.03707352, 0.7410953, 1.0],
-    [0.6340824, 0.054892275, 0.7713146, 1.0],
-    [0.6229123, 0.06440693, 0.77963585, 1.0],
-    [0.6525423, 0.06071791, 0.7553157, 1.0],
-    [0.6366577, 0.065854214, 0.7683294, 1.0],
-    [0.636623, 0.06125007, 0.768739, 1.0],
-    [0.6143906, 0.072382115, 0.78567475, 1.0],
-    [0.65220326, 0.06783762, 0.7550026, 1.0],
-    [0.63704145, 0.074131235, 0.7672567, 1.0],
-    [0.63469595, 0.071096815, 0.7694844, 1.0],
-    [0.6237671, 0.053009033, 0.77981067, 1.0],
-    [0.77890986, 0.024951976, 0.62663925, 1.0],
-    [0.7604602, 0.026810218, 0.6488309, 1.0],
-    [0.78960395, 0.03338471, 0.6127081, 1.0],
-    [0.7702984, 0.033942472, 0.6367796, 1.0],
-    [0.79620975, 0.039573435, 0.6037251, 1.0],
-    [0.7773311, 0.039943878, 0.6278222, 1.0],
-    [0.7790267, 0.03261925, 0.62614167, 1.0],
-    [0.74312013, 0.031206371, 0.6684299, 1.0],
-    [0.72335815, 0.033062574, 0.68968105, 1.0],
-    [0.7428336, 0.036536, 0.6684784, 1.0],
-    [0.7233419, 0.03801082, 0.689443, 1.0],
-    [0.75755113, 0.035081197, 0.65183246, 1.0],
-    [0.75683385, 0.041009795, 0.65231955, 1.0],
-    [0.7397742, 0.047845643, 0.671152, 1.0],
-    [0.7411301, 0.037095238, 0.67033577, 1.0],
-    [0.8055887, 0.04984866, 0.59037435, 1.0],
-    [0.788507, 0.05121387, 0.6128897, 1.0],
-    [0.81520885, 0.06294445, 0.5757364, 1.0],
-    [0.7979671, 0.0630599, 0.5993931, 1.0],
-    [0.8019997, 0.05639325, 0.5946565, 1.0],
-    [0.7713146, 0.05489231, 0.6340824, 1.0],
-    [0.7553157, 0.06071791, 0.65254235, 1.0],
-    [0.77963585, 0.06440693, 0.6229123, 1.0],
-    [0.7683294, 0.065854214, 0.6366577, 1.0],
-    [0.7687389, 0.06125007, 0.636623, 1.0],
-    [0.8204019, 0.071382225, 0.56731415, 1.0],
-    [0.8035425, 0.07130119, 0.59096164, 1.0],
-    [0.81203216, 0.07110876, 0.5792645, 1.0],
-    [0.7550026, 0.06783762, 0.65220326, 1.0],
-    [0.78567475, 0.072382115, 0.6143906, 1.0],
-    [0.7672567, 0.074131235, 0.63704145, 1.0],
-    [0.7694844, 0.071096815, 0.63469595, 1.0],
-    [0.7798154, 0.053013675, 0.6237607, 1.0],
-    [0.706371, 0.04560651, 0.706371, 1.0],
-    [0.6889521, 0.057737358, 0.7225036, 1.0],
-    [0.7226028, 0.05774294, 0.6888476, 1.0],
-    [0.7058331, 0.06198174, 0.70566136, 1.0],
-    [0.7060678, 0.05540976, 0.70597297, 1.0],
-    [0.6734191, 0.06284988, 0.7365844, 1.0],
-    [0.6734191, 0.06284988, 0.7365844, 1.0],
-    [0.68889993, 0.06606581, 0.7218394, 1.0],
-    [0.72200155, 0.06607498, 0.6887291, 1.0],
-    [0.70550704, 0.06828627, 0.70540535, 1.0],
-    [0.70561385, 0.06650364, 0.70546895, 1.0],
-    [0.6729131, 0.06974726, 0.736426, 1.0],
-    [0.6547912, 0.08518371, 0.75099427, 1.0],
-    [0.65489244, 0.09484417, 0.7497469, 1.0],
-    [0.688827, 0.07898424, 0.72061, 1.0],
-    [0.6763647, 0.0896628, 0.7310891, 1.0],
-    [0.66796106, 0.09751883, 0.73777914, 1.0],
-    [0.66933984, 0.085624166, 0.7380059, 1.0],
-    [0.7366577, 0.06290126, 0.67333424, 1.0],
-    [0.7366577, 0.0629012, 0.67333424, 1.0],
-    [0.73646903, 0.06977903, 0.6728628, 1.0],
-    [0.7206101, 0.07898424, 0.688827, 1.0],
-    [0.75099427, 0.08518371, 0.65479124, 1.0],
-    [0.7310891, 0.0896628, 0.6763647, 1.0],
-    [0.7497469, 0.09484417, 0.65489244, 1.0],
-    [0.73777914, 0.09751877, 0.66796106, 1.0],
-    [0.7380127, 0.08562979, 0.66933167, 1.0],
-    [0.70427185, 0.08945572, 0.7042718, 1.0],
-    [0.6884683, 0.09296454, 0.7192837, 1.0],
-    [0.7036835, 0.09714702, 0.7038409, 1.0],
-    [0.6876729, 0.10617151, 0.7182155, 1.0],
-    [0.7192837, 0.09296454, 0.6884683, 1.0],
-    [0.71814, 0.106167674, 0.6877524, 1.0],
-    [0.70238435, 0.11444347, 0.7025376, 1.0],
-    [0.7035588, 0.09957649, 0.70362604, 1.0],
-    [0.7052681, 0.0725451, 0.70521927, 1.0],
-    [0.6868716, 0.11993912, 0.71681386, 1.0],
-    [0.71681386, 0.11993912, 0.6868716, 1.0],
-    [0.7017261, 0.12313003, 0.7017261, 1.0],
-    [0.7019317, 0.12076263, 0.7019317, 1.0],
-    [0.7019317, 0.12076261, 0.7019317, 1.0],
-    [0.7061082, 0.053297855, 0.7060953, 1.0],
-    [0.5564752, 0.083133176, 0.8266948, 1.0],
-    [0.5460226, 0.0955791, 0.8323004, 1.0],
-    [0.5378391, 0.106053196, 0.83635026, 1.0],
-    [0.5789917, 0.08403648, 0.8109911, 1.0],
-    [0.56932575, 0.09531227, 0.8165682, 1.0],
-    [0.5611217, 0.10557163, 0.8209733, 1.0],
-    [0.558295, 0.094592325, 0.82423234, 1.0],
-    [0.5277299, 0.12008022, 0.8408816, 1.0],
-    [0.55084527, 0.11945602, 0.82601434, 1.0],
-    [0.5393824, 0.11957236, 0.83352816, 1.0],
-    [0.5986486, 0.08831719, 0.79612803, 1.0],
-    [0.58378744, 0.09576064, 0.8062394, 1.0],
-    [0.5989571, 0.09675506, 0.7949143, 1.0],
-    [0.5845381, 0.104952574, 0.8045496, 1.0],
-    [0.62311023, 0.0893178, 0.7770174, 1.0],
-    [0.614823, 0.09843013, 0.78249866, 1.0],
-    [0.6022454, 0.113110885, 0.79025716, 1.0],
-    [0.60095346, 0.09775076, 0.7932841, 1.0],
-    [0.57340574, 0.12023374, 0.8104011, 1.0],
-    [0.5876677, 0.12140803, 0.7999417, 1.0],
-    [0.5805808, 0.1207443, 0.80519986, 1.0],
-    [0.5700257, 0.10725272, 0.8145967, 1.0],
-    [0.5198519, 0.13213481, 0.84397537, 1.0],
-    [0.5117312, 0.14548117, 0.84673864, 1.0],
-    [0.503803, 0.15916398, 0.84902847, 1.0],
-    [0.54289234, 0.13145992, 0.82944924, 1.0],
-    [0.53475004, 0.14465089, 0.83253735, 1.0],
-    [0.52662647, 0.15817504, 0.83525157, 1.0],
-    [0.5232503, 0.14488724, 0.8397719, 1.0],
-    [0.602435, 0.12306176, 0.7886241, 1.0],
-    [0.602435, 0.12306176, 0.7886241, 1.0],
-    [0.5653626, 0.13206692, 0.81420124, 1.0],
-    [0.5570032, 0.14523558, 0.8177128, 1.0],
-    [0.5487235, 0.1587368, 0.8207953, 1.0],
-    [0.5874078, 0.13464741, 0.79801136, 1.0],
-    [0.57289016, 0.1465264, 0.80642855, 1.0],
-    [0.58712363, 0.14833106, 0.79579127, 1.0],
-    [0.5710963, 0.16016348, 0.8051067, 1.0],
-    [0.5699955, 0.14623533, 0.8085297, 1.0],
-    [0.5692996, 0.17445306, 0.8034078, 1.0],
-    [0.5692996, 0.17445306, 0.8034078, 1.0],
-    [0.56653666, 0.1459205, 0.81101376, 1.0],
-    [0.49678004, 0.17211096, 0.8506394, 1.0],
-    [0.51935434, 0.17109123, 0.8372568, 1.0],
-    [0.5081395, 0.1714389, 0.8440398, 1.0],
-    [0.48894274, 0.18792811, 0.85183203, 1.0],
-    [0.4831841, 0.20035039, 0.8522868, 1.0],
-    [0.47630915, 0.21591255, 0.8523563, 1.0],
-    [0.5113708, 0.18683285, 0.8388047, 1.0],
-    [0.5055292, 0.19909067, 0.8395254, 1.0],
-    [0.499085, 0.21327099, 0.8398985, 1.0],
-    [0.49404952, 0.20035632, 0.8460334, 1.0],
-    [0.5413712, 0.17149772, 0.82310736, 1.0],
-    [0.55521137, 0.17255375, 0.81361264, 1.0],
-    [0.5483263, 0.17196274, 0.818393, 1.0],
-    [0.5318465, 0.18893205, 0.82549614, 1.0],
-    [0.5192177, 0.19916372, 0.8311118, 1.0],
-    [0.5334498, 0.19999419, 0.82184774, 1.0],
-    [0.5220624, 0.20948939, 0.8267799, 1.0],
-    [0.5569149, 0.1837994, 0.80997753, 1.0],
-    [0.5481636, 0.20165196, 0.8116977, 1.0],
-    [0.5426147, 0.2134158, 0.812418, 1.0],
-    [0.536364, 0.19927919, 0.8201228, 1.0],
-    [0.5218542, 0.18512076, 0.83270544, 1.0],
-    [0.64011115, 0.091992505, 0.7627549, 1.0],
-    [0.639699, 0.10068951, 0.76200193, 1.0],
-    [0.6256507, 0.115117416, 0.7715629, 1.0],
-    [0.6550522, 0.111268856, 0.74734586, 1.0],
-    [0.6403871, 0.1182267, 0.75889844, 1.0],
-    [0.6402505, 0.10720019, 0.7606493, 1.0],
-    [0.6727376, 0.11537421, 0.7308303, 1.0],
-    [0.6727376, 0.11537421, 0.7308303, 1.0],
-    [0.65681905, 0.11083999, 0.7458574, 1.0],
-    [0.6174225, 0.12555578, 0.77654696, 1.0],
-    [0.6545896, 0.12176367, 0.7461139, 1.0],
-    [0.63955843, 0.13173339, 0.7573713, 1.0],
-    [0.6387971, 0.14572853, 0.755448, 1.0],
-    [0.6377379, 0.13086624, 0.75905496, 1.0],
-    [0.60807496, 0.13844039, 0.7817154, 1.0],
-    [0.59989303, 0.15049581, 0.78579855, 1.0],
-    [0.5920749, 0.16289875, 0.78924745, 1.0],
-    [0.6250491, 0.1419793, 0.76756465, 1.0],
-    [0.623662, 0.15591638, 0.7659868, 1.0],
-    [0.6086443, 0.166584, 0.775759, 1.0],
-    [0.6096234, 0.15247121, 0.7778893, 1.0],
-    [0.58374983, 0.17693317, 0.79242086, 1.0],
-    [0.622161, 0.17029445, 0.76414365, 1.0],
-    [0.6064585, 0.18097785, 0.7742449, 1.0],
-    [0.6041037, 0.19607197, 0.7724083, 1.0],
-    [0.6042332, 0.18079767, 0.77602476, 1.0],
-    [0.67184544, 0.12465648, 0.7301263, 1.0],
-    [0.6580305, 0.1384426, 0.7401551, 1.0],
-    [0.6499149, 0.14916971, 0.74522406, 1.0],
-    [0.6855013, 0.13686888, 0.7150909, 1.0],
-    [0.6706765, 0.14282198, 0.72787005, 1.0],
-    [0.6843785, 0.14817344, 0.7139123, 1.0],
-    [0.6691947, 0.1569473, 0.7263236, 1.0],
-    [0.6700643, 0.14215627, 0.72856396, 1.0],
-    [0.64128816, 0.1613987, 0.75013334, 1.0],
-    [0.6543543, 0.16613452, 0.7377125, 1.0],
-    [0.64787734, 0.16369805, 0.74394745, 1.0],
-    [0.6674684, 0.17171626, 0.7245684, 1.0],
-    [0.6674684, 0.17171623, 0.7245684, 1.0],
-    [0.63286954, 0.17395157, 0.75446475, 1.0],
-    [0.6254986, 0.18573722, 0.757795, 1.0],
-    [0.61731076, 0.20356527, 0.7599268, 1.0],
-    [0.6523268, 0.1809633, 0.7360175, 1.0],
-    [0.6374044, 0.19052753, 0.74660224, 1.0],
-    [0.65014917, 0.19648504, 0.7339617, 1.0],
-    [0.63813907, 0.2040879, 0.742379, 1.0],
-    [0.63635254, 0.19053939, 0.747496, 1.0],
-    [0.63946843, 0.16075005, 0.75182414, 1.0],
-    [0.5770203, 0.18899707, 0.79456127, 1.0],
-    [0.56887746, 0.2075252, 0.79580885, 1.0],
-    [0.5581253, 0.21614173, 0.80111104, 1.0],
-    [0.59087163, 0.19221121, 0.783534, 1.0],
-    [0.59179837, 0.20478736, 0.7796389, 1.0],
-    [0.5773699, 0.20172939, 0.7911696, 1.0],
-    [0.60687834, 0.21100606, 0.7662736, 1.0],
-    [0.60687834, 0.21100606, 0.7662736, 1.0],
-    [0.5923508, 0.20607562, 0.7788796, 1.0],
-    [0.59194237, 0.14908443, 0.79207194, 1.0],
-    [0.4698319, 0.23153578, 0.8518505, 1.0],
-    [0.49178436, 0.23034261, 0.83969665, 1.0],
-    [0.4808626, 0.23081098, 0.8458708, 1.0],
-    [0.46509463, 0.24343415, 0.8511326, 1.0],
-    [0.45771623, 0.26329452, 0.8492184, 1.0],
-    [0.4869783, 0.24211006, 0.839187, 1.0],
-    [0.4800297, 0.26028395, 0.83774924, 1.0],
-    [0.4724534, 0.25213745, 0.8445202, 1.0],
-    [0.4519291, 0.28043407, 0.84682757, 1.0],
-    [0.44828728, 0.2916765, 0.84496355, 1.0],
-    [0.44311464, 0.3085479, 0.8416933, 1.0],
-    [0.47335327, 0.27941984, 0.8353808, 1.0],
-    [0.46961603, 0.29061618, 0.83366835, 1.0],
-    [0.46501622, 0.3052185, 0.8310244, 1.0],
-    [0.45853576, 0.2925377, 0.83914644, 1.0],
-    [0.5130082, 0.23109959, 0.82668966, 1.0],
-    [0.5324137, 0.23416464, 0.8134511, 1.0],
-    [0.53385276, 0.24627168, 0.80891997, 1.0],
-    [0.5265074, 0.23705095, 0.8164538, 1.0],
-    [0.50824124, 0.24273562, 0.826299, 1.0],
-    [0.50248605, 0.2575854, 0.82532275, 1.0],
-    [0.52053934, 0.2439068, 0.8182593, 1.0],
-    [0.52396095, 0.2548776, 0.81271285, 1.0],
-    [0.5138336, 0.2496912, 0.82074916, 1.0],
-    [0.49329606, 0.2817126, 0.8229806, 1.0],
-    [0.4827274, 0.29104432, 0.8259948, 1.0],
-    [0.49616286, 0.29311228, 0.8172562, 1.0],
-    [0.48669663, 0.30156586, 0.81986856, 1.0],
-    [0.5162698, 0.27784994, 0.81010175, 1.0],
-    [0.51044196, 0.29889628, 0.8062939, 1.0],
-    [0.5020596, 0.30627668, 0.80878353, 1.0],
-    [0.49826598, 0.2928101, 0.8160841, 1.0],
-    [0.4916995, 0.25855866, 0.831492, 1.0],
-    [0.43686607, 0.3307301, 0.83652, 1.0],
-    [0.43107325, 0.35249898, 0.83061445, 1.0],
-    [0.45861256, 0.3271704, 0.82621676, 1.0],
-    [0.45261917, 0.34894335, 0.820594, 1.0],
-    [0.44477326, 0.3397563, 0.82869923, 1.0],
-    [0.42577556, 0.37409732, 0.8238728, 1.0],
-    [0.4210865, 0.39554328, 0.8162301, 1.0],
-    [0.44713867, 0.37055936, 0.8140963, 1.0],
-    [0.4423961, 0.39179438, 0.8067111, 1.0],
-    [0.43408063, 0.3829505, 0.8154282, 1.0],
-    [0.48029125, 0.3240058, 0.81507087, 1.0],
-    [0.47417742, 0.34572265, 0.80971086, 1.0],
-    [0.51447475, 0.31072482, 0.79922825, 1.0],
-    [0.5039414, 0.3196407, 0.80241686, 1.0],
-    [0.49580488, 0.34281012, 0.797909, 1.0],
-    [0.49373138, 0.3284399, 0.8052059, 1.0],
-    [0.4686373, 0.3671221, 0.80349267, 1.0],
-    [0.46359912, 0.38825676, 0.79645, 1.0],
-    [0.4900365, 0.3640295, 0.7920523, 1.0],
-    [0.48471257, 0.38506886, 0.7853506, 1.0],
-    [0.47675547, 0.37606874, 0.7945291, 1.0],
-    [0.4622999, 0.3564978, 0.81190395, 1.0],
-    [0.61944944, 0.21640946, 0.7546187, 1.0],
-    [0.6297871, 0.22437571, 0.74365574, 1.0],
-    [0.63183814, 0.23782827, 0.7377115, 1.0],
-    [0.6271202, 0.22612232, 0.7453783, 1.0],
-    [0.6271202, 0.22612232, 0.7453783, 1.0],
-    [0.57145756, 0.21936974, 0.7907675, 1.0],
-    [0.55900455, 0.22864735, 0.7970159, 1.0],
-    [0.5492035, 0.25247163, 0.7966389, 1.0],
-    [0.5825304, 0.22309034, 0.7815939, 1.0],
-    [0.5772975, 0.23452373, 0.7821293, 1.0],
-    [0.5700069, 0.25131395, 0.7822618, 1.0],
-    [0.5682695, 0.23474394, 0.78864765, 1.0],
-    [0.5398671, 0.26028696, 0.8004963, 1.0],
-    [0.5398671, 0.26028696, 0.8004963, 1.0],
-    [0.55272603, 0.26428136, 0.7903476, 1.0],
-    [0.5633306, 0.27155194, 0.78033215, 1.0],
-    [0.5666551, 0.2844301, 0.7733056, 1.0],
-    [0.56097084, 0.27335995, 0.78140014, 1.0],
-    [0.54088086, 0.27353212, 0.7953792, 1.0],
-    [0.5305993, 0.29739648, 0.7937379, 1.0],
-    [0.55470836, 0.27820018, 0.7841577, 1.0],
-    [0.5550587, 0.2934395, 0.7783335, 1.0],
-    [0.54535496, 0.28554237, 0.7880695, 1.0],
-    [0.603525, 0.22716656, 0.76429904, 1.0],
-    [0.5875977, 0.2384575, 0.7732185, 1.0],
-    [0.59981674, 0.2447311, 0.7617917, 1.0],
-    [0.5904643, 0.251399, 0.7669097, 1.0],
-    [0.6200949, 0.23078522, 0.7498136, 1.0],
-    [0.6162927, 0.24827707, 0.74735653, 1.0],
-    [0.60064876, 0.25912207, 0.7563575, 1.0],
-    [0.6027177, 0.24269263, 0.7601524, 1.0],
-    [0.5834427, 0.27162525, 0.76538503, 1.0],
-    [0.5761316, 0.2917359, 0.76351976, 1.0],
-    [0.61199254, 0.27016973, 0.74328566, 1.0],
-    [0.60044575, 0.27067333, 0.75246316, 1.0],
-    [0.6114493, 0.2823191, 0.73920614, 1.0],
-    [0.5996895, 0.29065052, 0.74558365, 1.0],
-    [0.5973011, 0.2793632, 0.7517895, 1.0],
-    [0.5666661, 0.29843515, 0.7680012, 1.0],
-    [0.56666625, 0.2984352, 0.76800126, 1.0],
-    [0.568994, 0.26724842, 0.7777045, 1.0],
-    [0.52466464, 0.31741473, 0.7899208, 1.0],
-    [0.51614195, 0.32423818, 0.79275906, 1.0],
-    [0.527256, 0.33303404, 0.78172207, 1.0],
-    [0.5178275, 0.34062737, 0.78474694, 1.0],
-    [0.54520446, 0.31677783, 0.7761468, 1.0],
-    [0.5371786, 0.33856383, 0.7725371, 1.0],
-    [0.5269003, 0.34683314, 0.77594, 1.0],
-    [0.5279164, 0.3310069, 0.7821372, 1.0],
-    [0.5113463, 0.3614218, 0.779679, 1.0],
-    [0.50595963, 0.3823456, 0.7731861, 1.0],
-    [0.536103, 0.358706, 0.76414883, 1.0],
-    [0.52539414, 0.35984564, 0.77102023, 1.0],
-    [0.53254616, 0.37931392, 0.75664765, 1.0],
-    [0.5219591, 0.3804625, 0.76341796, 1.0],
-    [0.5222601, 0.37029746, 0.7681954, 1.0],
-    [0.57750434, 0.30883762, 0.755717, 1.0],
-    [0.58769286, 0.3143503, 0.7455206, 1.0],
-    [0.58589023, 0.33412132, 0.7383058, 1.0],
-    [0.583762, 0.31906953, 0.74660337, 1.0],
-    [0.56753093, 0.3157703, 0.7603932, 1.0],
-    [0.5605627, 0.33596557, 0.7568993, 1.0],
-    [0.5764053, 0.3225792, 0.7507994, 1.0],
-    [0.5747898, 0.33488005, 0.74664044, 1.0],
-    [0.5698531, 0.32726797, 0.75376594, 1.0],
-    [0.58244157, 0.3547206, 0.7313925, 1.0],
-    [0.5779775, 0.37526417, 0.72465086, 1.0],
-    [0.5802277, 0.36499372, 0.7280902, 1.0],
-    [0.55426663, 0.35704803, 0.75186783, 1.0],
-    [0.5485687, 0.37780577, 0.7458788, 1.0],
-    [0.57140213, 0.35558224, 0.73963565, 1.0],
-    [0.5670009, 0.37615386, 0.7328152, 1.0],
-    [0.56036, 0.36660808, 0.74269456, 1.0],
-    [0.55771625, 0.3461945, 0.7543884, 1.0],
-    [0.5422125, 0.28803918, 0.7893282, 1.0],
-    [0.8266947, 0.083133176, 0.5564752, 1.0],
-    [0.8109911, 0.08403644, 0.5789917, 1.0],
-    [0.8323004, 0.0955791, 0.5460226, 1.0],
-    [0.8165682, 0.09531227, 0.56932575, 1.0],
-    [0.83635026, 0.106053166, 0.5378391, 1.0],
-    [0.8209733, 0.10557163, 0.5611217, 1.0],
-    [0.82423234, 0.094592325, 0.558295, 1.0],
-    [0.79618007, 0.088393986, 0.59856814, 1.0],
-    [0.7770174, 0.0893178, 0.62311023, 1.0],
-    [0.79499775, 0.09688575, 0.5988254, 1.0],
-    [0.78249866, 0.09843013, 0.614823, 1.0],
-    [0.8063191, 0.095894195, 0.58365554, 1.0],
-    [0.804547, 0.10504057, 0.58452594, 1.0],
-    [0.7902561, 0.11319595, 0.6022307, 1.0],
-    [0.79331535, 0.097823255, 0.60090023, 1.0],
-    [0.8408816, 0.12008022, 0.5277299, 1.0],
-    [0.82601434, 0.11945602, 0.55084527, 1.0],
-    [0.83352816, 0.11957236, 0.5393824, 1.0],
-    [0.8102837, 0.12024765, 0.5735686, 1.0],
-    [0.79985565, 0.12156552, 0.58775234, 1.0],
-    [0.80509746, 0.12083017, 0.58070475, 1.0],
-    [0.8145778, 0.10729063, 0.57004523, 1.0],
-    [0.7627549, 0.091992505, 0.64011115, 1.0],
-    [0.76200193, 0.10068951, 0.639699, 1.0],
-    [0.7473443, 0.11126885, 0.65505385, 1.0],
-    [0.7715629, 0.115117416, 0.6256507, 1.0],
-    [0.7588962, 0.11817008, 0.64040005, 1.0],
-    [0.76064837, 0.107189156, 0.6402535, 1.0],
-    [0.73083276, 0.11543035, 0.6727253, 1.0],
-    [0.73083276, 0.11543035, 0.6727253, 1.0],
-    [0.74585754, 0.11086227, 0.65681505, 1.0],
-    [0.7886207, 0.12320659, 0.60240966, 1.0],
-    [0.78862077, 0.12320657, 0.60240966, 1.0],
-    [0.84397537, 0.13213481, 0.5198519, 1.0],
-    [0.82944924, 0.13145992, 0.54289234, 1.0],
-    [0.84673864, 0.14548117, 0.5117312, 1.0],
-    [0.83253735, 0.14465089, 0.53475004, 1.0],
-    [0.84902847, 0.15916398, 0.503803, 1.0],
-    [0.83525157, 0.15817498, 0.52662647, 1.0],
-    [0.8397719, 0.14488727, 0.5232503, 1.0],
-    [0.8141437, 0.13207324, 0.5654437, 1.0],
-    [0.79796743, 0.13472906, 0.5874488, 1.0],
-    [0.79579127, 0.14833106, 0.58712363, 1.0],
-    [0.8177128, 0.14523558, 0.5570032, 1.0],
-    [0.80642843, 0.14652638, 0.57289004, 1.0],
-    [0.8207401, 0.15873984, 0.5488053, 1.0],
-    [0.80502546, 0.16010006, 0.57122844, 1.0],
-    [0.80849475, 0.14623998, 0.57004386, 1.0],
-    [0.8033274, 0.17431375, 0.56945574, 1.0],
-    [0.8033273, 0.17431371, 0.56945574, 1.0],
-    [0.810979, 0.14592469, 0.5665854, 1.0],
-    [0.7460296, 0.1217964, 0.65467966, 1.0],
-    [0.77648544, 0.125573, 0.6174964, 1.0],
-    [0.75735605, 0.1317463, 0.63957375, 1.0],
-    [0.755496, 0.14578383, 0.6387275, 1.0],
-    [0.75902736, 0.13089591, 0.63776463, 1.0],
-    [0.7300775, 0.124713495, 0.67188793, 1.0],
-    [0.7150909, 0.13686888, 0.6855013, 1.0],
-    [0.7139123, 0.14817344, 0.6843785, 1.0],
-    [0.7400921, 0.13851798, 0.6580856, 1.0],
-    [0.727868, 0.14288723, 0.67066497, 1.0],
-    [0.74522096, 0.14921929, 0.6499073, 1.0],
-    [0.72638005, 0.15699099, 0.6691231, 1.0],
-    [0.7285556, 0.14219832, 0.6700643, 1.0],
-    [0.7246821, 0.17173724, 0.66733956, 1.0],
-    [0.7246821, 0.17173724, 0.66733956, 1.0],
-    [0.7816552, 0.13845672, 0.60814875, 1.0],
-    [0.76761127, 0.14203662, 0.6249789, 1.0],
-    [0.78579855, 0.15049581, 0.59989303, 1.0],
-    [0.7660306, 0.15597208, 0.6235944, 1.0],
-    [0.78918844, 0.16291076, 0.59215003, 1.0],
-    [0.77579844, 0.16663967, 0.6085787, 1.0],
-    [0.7778903, 0.15250543, 0.6096137, 1.0],
-    [0.75013334, 0.1613987, 0.64128816, 1.0],
-    [0.7378239, 0.16616018, 0.65422225, 1.0],
-    [0.7440026, 0.16371201, 0.64781064, 1.0],
-    [0.7641846, 0.17034842, 0.622096, 1.0],
-    [0.7923631, 0.1769444, 0.58382475, 1.0],
-    [0.77424264, 0.18104085, 0.6064426, 1.0],
-    [0.7722887, 0.19616117, 0.60422766, 1.0],
-    [0.77598876, 0.18085313, 0.6042628, 1.0],
-    [0.7544064, 0.17397028, 0.6329339, 1.0],
-    [0.7360346, 0.18095186, 0.65231085, 1.0],
-    [0.7339408, 0.1964755, 0.6501757, 1.0],
-    [0.75767815, 0.18577302, 0.6256295, 1.0],
-    [0.74653107, 0.19047514, 0.6375034, 1.0],
-    [0.7598375, 0.20354095, 0.6174285, 1.0],
-    [0.742367, 0.20408176, 0.638155, 1.0],
-    [0.7474448, 0.19053374, 0.63641435, 1.0],
-    [0.7518295, 0.16077965, 0.63945466, 1.0],
-    [0.85063934, 0.17211102, 0.49678004, 1.0],
-    [0.8372568, 0.17109123, 0.51935434, 1.0],
-    [0.8440398, 0.1714389, 0.5081395, 1.0],
-    [0.82299924, 0.17150262, 0.541534, 1.0],
-    [0.8134604, 0.17241767, 0.55547667, 1.0],
-    [0.81826335, 0.17189665, 0.54854035, 1.0],
-    [0.85183203, 0.18792811, 0.48894274, 1.0],
-    [0.8388047, 0.18683288, 0.5113708, 1.0],
-    [0.8522868, 0.20035039, 0.4831841, 1.0],
-    [0.8395254, 0.19909067, 0.5055292, 1.0],
-    [0.8523564, 0.21591257, 0.4763092, 1.0],
-    [0.8398986, 0.21327099, 0.499085, 1.0],
-    [0.8460334, 0.20035632, 0.49404952, 1.0],
-    [0.8254448, 0.18885015, 0.5319553, 1.0],
-    [0.8099325, 0.18371294, 0.55700904, 1.0],
-    [0.8218351, 0.19985424, 0.5335217, 1.0],
-    [0.811644, 0.20165567, 0.5482416, 1.0],
-    [0.83110094, 0.19902284, 0.5192891, 1.0],
-    [0.82677495, 0.2094043, 0.5221046, 1.0],
-    [0.8123652, 0.21341921, 0.5426924, 1.0],
-    [0.82009023, 0.19920312, 0.53644204, 1.0],
-    [0.8326677, 0.18508188, 0.5219283, 1.0],
-    [0.79450536, 0.18900624, 0.5770944, 1.0],
-    [0.7834893, 0.19228002, 0.5909085, 1.0],
-    [0.7957232, 0.2074867, 0.5690114, 1.0],
-    [0.7795667, 0.20483913, 0.59187555, 1.0],
-    [0.80104506, 0.21606308, 0.55825055, 1.0],
-    [0.79110307, 0.20173265, 0.5774598, 1.0],
-    [0.76620585, 0.2109405, 0.6069867, 1.0],
-    [0.76620585, 0.21094047, 0.6069867, 1.0],
-    [0.7788126, 0.20604452, 0.59244967, 1.0],
-    [0.792042, 0.14909221, 0.5919806, 1.0],
-    [0.69996285, 0.14178905, 0.69996285, 1.0],
-    [0.69844717, 0.15601815, 0.69844824, 1.0],
-    [0.68346334, 0.16417205, 0.71128434, 1.0],
-    [0.711293, 0.1640995, 0.68347174, 1.0],
-    [0.6984148, 0.15630852, 0.69841564, 1.0],
-    [0.69671, 0.17084348, 0.696712, 1.0],
-    [0.68170303, 0.17879306, 0.7094464, 1.0],
-    [0.67983335, 0.1941464, 0.70720136, 1.0],
-    [0.7094812, 0.17877625, 0.68167126, 1.0],
-    [0.6947466, 0.1861568, 0.6947466, 1.0],
-    [0.7072596, 0.19418739, 0.679761, 1.0],
-    [0.69258446, 0.20162745, 0.69258446, 1.0],
-    [0.69476163, 0.18614899, 0.6947336, 1.0],
-    [0.6670733, 0.18711816, 0.7211102, 1.0],
-    [0.6648622, 0.20265898, 0.7189489, 1.0],
-    [0.6500334, 0.21154799, 0.72986573, 1.0],
-    [0.67818654, 0.20932108, 0.70444846, 1.0],
-    [0.6651448, 0.20249388, 0.7187339, 1.0],
-    [0.7211671, 0.18716128, 0.66699976, 1.0],
-    [0.71899366, 0.20269404, 0.6648032, 1.0],
-    [0.70444846, 0.20932108, 0.67818654, 1.0],
-    [0.72989804, 0.21157584, 0.64998806, 1.0],
-    [0.7187675, 0.20252016, 0.66510063, 1.0],
-    [0.69476104, 0.18614925, 0.6947341, 1.0],
-    [0.69476104, 0.18614925, 0.6947341, 1.0],
-    [0.75462717, 0.21632364, 0.619469, 1.0],
-    [0.74365574, 0.22437571, 0.6297871, 1.0],
-    [0.7376367, 0.23784915, 0.63191766, 1.0],
-    [0.7453566, 0.22610006, 0.6271542, 1.0],
-    [0.7453566, 0.22610004, 0.6271542, 1.0],
-    [0.8518505, 0.23153578, 0.4698319, 1.0],
-    [0.83969665, 0.23034263, 0.4917844, 1.0],
-    [0.84587073, 0.23081098, 0.48086253, 1.0],
-    [0.82668954, 0.23109956, 0.5130081, 1.0],
-    [0.8134511, 0.23416464, 0.5324137, 1.0],
-    [0.80885166, 0.24627289, 0.5339557, 1.0],
-    [0.8164314, 0.23705071, 0.52654225, 1.0],
-    [0.8511326, 0.24343415, 0.46509463, 1.0],
-    [0.839187, 0.24211006, 0.4869783, 1.0],
-    [0.8492184, 0.2632945, 0.45771623, 1.0],
-    [0.83774924, 0.26028395, 0.4800297, 1.0],
-    [0.8445202, 0.25213742, 0.4724534, 1.0],
-    [0.826299, 0.24273562, 0.50824124, 1.0],
-    [0.8182593, 0.2439068, 0.52053934, 1.0],
-    [0.82532275, 0.2575854, 0.50248605, 1.0],
-    [0.812673, 0.25487736, 0.52402294, 1.0],
-    [0.8207395, 0.2496908, 0.51384944, 1.0],
-    [0.84682757, 0.28043407, 0.4519291, 1.0],
-    [0.83538085, 0.27941987, 0.47335333, 1.0],
-    [0.8449635, 0.29167646, 0.44828725, 1.0],
-    [0.83366835, 0.29061618, 0.46961603, 1.0],
-    [0.8416933, 0.30854794, 0.44311464, 1.0],
-    [0.8310244, 0.3052185, 0.46501622, 1.0],
-    [0.83914644, 0.2925377, 0.45853576, 1.0],
-    [0.8229907, 0.2816522, 0.49331352, 1.0],
-    [0.81010175, 0.27784994, 0.5162698, 1.0],
-    [0.817275, 0.29301146, 0.4961915, 1.0],
-    [0.8062939, 0.29889628, 0.51044196, 1.0],
-    [0.8260139, 0.2909432, 0.48275566, 1.0],
-    [0.819881, 0.30150512, 0.48671335, 1.0],
-    [0.80878353, 0.30627668, 0.5020596, 1.0],
-    [0.81609255, 0.29276398, 0.4982791, 1.0],
-    [0.83148813, 0.2585505, 0.49171042, 1.0],
-    [0.79077387, 0.21928276, 0.57148206, 1.0],
-    [0.7815389, 0.22309871, 0.58260095, 1.0],
-    [0.797021, 0.22859468, 0.55901873, 1.0],
-    [0.782075, 0.2345318, 0.5773678, 1.0],
-    [0.79663825, 0.25247234, 0.5492043, 1.0],
-    [0.7822618, 0.25131392, 0.5700069, 1.0],
-    [0.7886321, 0.23472278, 0.5682998, 1.0],
-    [0.76424515, 0.22715537, 0.6035973, 1.0],
-    [0.74980354, 0.23087524, 0.62007356, 1.0],
-    [0.7617155, 0.24474275, 0.5999088, 1.0],
-    [0.74730283, 0.24826711, 0.6163617, 1.0],
-    [0.7731158, 0.23842745, 0.587745, 1.0],
-    [0.7668736, 0.25135025, 0.59053195, 1.0],
-    [0.75634, 0.2589901, 0.60072786, 1.0],
-    [0.76010084, 0.24267496, 0.60278976, 1.0],
-    [0.79048735, 0.26427272, 0.5525303, 1.0],
-    [0.780375, 0.27154812, 0.56327295, 1.0],
-    [0.7733571, 0.28452396, 0.56653774, 1.0],
-    [0.781479, 0.2733863, 0.56084794, 1.0],
-    [0.7432973, 0.27009842, 0.6120098, 1.0],
-    [0.73913413, 0.28233105, 0.6115308, 1.0],
-    [0.7653851, 0.27162525, 0.5834426, 1.0],
-    [0.7524755, 0.2706019, 0.6004625, 1.0],
-    [0.76356333, 0.29173103, 0.5760764, 1.0],
-    [0.74554145, 0.29065683, 0.59973884, 1.0],
-    [0.7517829, 0.2793407, 0.59732, 1.0],
-    [0.8004949, 0.26028797, 0.5398685, 1.0],
-    [0.80049497, 0.26028794, 0.53986853, 1.0],
-    [0.7954607, 0.27352905, 0.5407626, 1.0],
-    [0.78422767, 0.27819523, 0.55461186, 1.0],
-    [0.7937379, 0.29739648, 0.5305993, 1.0],
-    [0.7783621, 0.293497, 0.5549882, 1.0],
-    [0.78811413, 0.28555495, 0.54528373, 1.0],
-    [0.7680721, 0.29842815, 0.56657374, 1.0],
-    [0.7680721, 0.29842812, 0.5665737, 1.0],
-    [0.77772355, 0.26724318, 0.5689703, 1.0],
-    [0.83652, 0.33073008, 0.43686607, 1.0],
-    [0.82621676, 0.3271704, 0.45861256, 1.0],
-    [0.8306145, 0.35249898, 0.43107325, 1.0],
-    [0.820594, 0.34894335, 0.45261917, 1.0],
-    [0.82869923, 0.3397563, 0.44477326, 1.0],
-    [0.79922825, 0.31072482, 0.51447475, 1.0],
-    [0.81507087, 0.32400584, 0.48029125, 1.0],
-    [0.80241686, 0.3196407, 0.5039414, 1.0],
-    [0.80971086, 0.34572265, 0.47417742, 1.0],
-    [0.797909, 0.34281012, 0.49580488, 1.0],
-    [0.8052059, 0.3284399, 0.49373138, 1.0],
-    [0.8238728, 0.37409732, 0.42577556, 1.0],
-    [0.8140963, 0.37055936, 0.44713867, 1.0],
-    [0.8162301, 0.39554328, 0.4210865, 1.0],
-    [0.8067111, 0.39179438, 0.4423961, 1.0],
-    [0.8154282, 0.3829505, 0.43408063, 1.0],
-    [0.80349267, 0.3671221, 0.4686373, 1.0],
-    [0.7920523, 0.3640295, 0.4900365, 1.0],
-    [0.79645, 0.38825676, 0.46359912, 1.0],
-    [0.7853506, 0.3850689, 0.48471257, 1.0],
-    [0.7945291, 0.3760687, 0.47675547, 1.0],
-    [0.81190395, 0.3564978, 0.4622999, 1.0],
-    [0.7557393, 0.30873662, 0.5775291, 1.0],
-    [0.7455206, 0.3143503, 0.58769286, 1.0],
-    [0.7383058, 0.33412135, 0.5858903, 1.0],
-    [0.7466112, 0.31903586, 0.58377045, 1.0],
-    [0.73139256, 0.35472062, 0.5824416, 1.0],
-    [0.72465086, 0.37526417, 0.5779775, 1.0],
-    [0.7280902, 0.36499372, 0.5802277, 1.0],
-    [0.7899208, 0.31741473, 0.52466464, 1.0],
-    [0.7761469, 0.31677783, 0.54520446, 1.0],
-    [0.78172207, 0.33303404, 0.527256, 1.0],
-    [0.7725371, 0.33856383, 0.5371786, 1.0],
-    [0.79275906, 0.32423818, 0.51614195, 1.0],
-    [0.78474694, 0.34062737, 0.5178275, 1.0],
-    [0.77594, 0.34683314, 0.5269003, 1.0],
-    [0.78213716, 0.3310069, 0.5279164, 1.0],
-    [0.76040745, 0.31570956, 0.56754553, 1.0],
-    [0.7507994, 0.3225792, 0.5764053, 1.0],
-    [0.7568993, 0.33596557, 0.5605627, 1.0],
-    [0.74664044, 0.33488002, 0.5747898, 1.0],
-    [0.7537697, 0.32725278, 0.5698569, 1.0],
-    [0.7641489, 0.35870603, 0.536103, 1.0],
-    [0.75664765, 0.37931392, 0.53254616, 1.0],
-    [0.779679, 0.3614218, 0.5113463, 1.0],
-    [0.77102023, 0.35984564, 0.52539414, 1.0],
-    [0.7731861, 0.3823456, 0.50595963, 1.0],
-    [0.76341796, 0.3804625, 0.5219591, 1.0],
-    [0.7681954, 0.37029743, 0.5222601, 1.0],
-    [0.7518678, 0.357048, 0.55426663, 1.0],
-    [0.73963565, 0.35558224, 0.57140213, 1.0],
-    [0.7458788, 0.37780574, 0.5485687, 1.0],
-    [0.7328152, 0.37615386, 0.5670009, 1.0],
-    [0.7426945, 0.36660808, 0.56036, 1.0],
-    [0.75439066, 0.34618625, 0.5577183, 1.0],
-    [0.78932863, 0.28802973, 0.5422169, 1.0],
-    [0.69016105, 0.21804342, 0.69002527, 1.0],
-    [0.69016105, 0.21804337, 0.69002527, 1.0],
-    [0.66215605, 0.21923651, 0.7165786, 1.0],
-    [0.65029025, 0.22628157, 0.725203, 1.0],
-    [0.6415036, 0.24587665, 0.72664833, 1.0],
-    [0.67540276, 0.22580746, 0.7020272, 1.0],
-    [0.66080886, 0.23394765, 0.713162, 1.0],
-    [0.6718022, 0.24703294, 0.6983241, 1.0],
-    [0.6593299, 0.24729227, 0.7100216, 1.0],
-    [0.66031784, 0.23489657, 0.71330494, 1.0],
-    [0.6423192, 0.26179278, 0.72034055, 1.0],
-    [0.6700766, 0.25863874, 0.6957754, 1.0],
-    [0.6553812, 0.26714167, 0.7064777, 1.0],
-    [0.6504313, 0.29002848, 0.70201325, 1.0],
-    [0.65467453, 0.2692707, 0.70632464, 1.0],
-    [0.71656513, 0.21921758, 0.6621767, 1.0],
-    [0.70208883, 0.22579652, 0.6753423, 1.0],
-    [0.69837517, 0.2469901, 0.6717648, 1.0],
-    [0.72519547, 0.2262699, 0.65030265, 1.0],
-    [0.71315473, 0.23392051, 0.66082644, 1.0],
-    [0.7266393, 0.2458539, 0.6415226, 1.0],
-    [0.7100721, 0.24725075, 0.659291, 1.0],
-    [0.7133224, 0.23487215, 0.6603076, 1.0],
-    [0.6877645, 0.23268914, 0.68763053, 1.0],
-    [0.6852228, 0.24685916, 0.6852228, 1.0],
-    [0.68651575, 0.23975776, 0.68644917, 1.0],
-    [0.69590306, 0.25864255, 0.66994244, 1.0],
-    [0.7204022, 0.26173082, 0.6422755, 1.0],
-    [0.7065395, 0.26714545, 0.6553132, 1.0],
-    [0.70201325, 0.29002848, 0.6504313, 1.0],
-    [0.70638657, 0.26925763, 0.6546132, 1.0],
-    [0.68178725, 0.2655372, 0.68165696, 1.0],
-    [0.6675787, 0.27281767, 0.69275486, 1.0],
-    [0.67677796, 0.28972948, 0.676778, 1.0],
-    [0.66439325, 0.2897718, 0.6889223, 1.0],
-    [0.6928812, 0.2728215, 0.66744596, 1.0],
-    [0.6889223, 0.2897718, 0.66439325, 1.0],
-    [0.67886585, 0.279946, 0.6788014, 1.0],
-    [0.68489575, 0.24883142, 0.6848363, 1.0],
-    [0.6311426, 0.25218856, 0.7335256, 1.0],
-    [0.6311426, 0.25218856, 0.7335256, 1.0],
-    [0.6305854, 0.26924732, 0.7279203, 1.0],
-    [0.62058145, 0.28972092, 0.7286566, 1.0],
-    [0.610073, 0.29638642, 0.7348239, 1.0],
-    [0.640567, 0.27596104, 0.7166028, 1.0],
-    [0.6384641, 0.29038355, 0.71277, 1.0],
-    [0.6281318, 0.28422138, 0.7243401, 1.0],
-    [0.62971973, 0.26814395, 0.7290761, 1.0],
-    [0.6477626, 0.3045656, 0.6983148, 1.0],
-    [0.6477626, 0.3045656, 0.6983148, 1.0],
-    [0.6983148, 0.3045656, 0.6477626, 1.0],
-    [0.6983148, 0.3045656, 0.6477626, 1.0],
-    [0.6740085, 0.3023659, 0.6740085, 1.0],
-    [0.65965855, 0.31026787, 0.6845323, 1.0],
-    [0.65436846, 0.3314343, 0.67967135, 1.0],
-    [0.6845322, 0.31026787, 0.65965855, 1.0],
-    [0.67083615, 0.31616095, 0.67083615, 1.0],
-    [0.67967135, 0.33143434, 0.65436846, 1.0],
-    [0.6671929, 0.3312211, 0.6671929, 1.0],
-    [0.6701803, 0.31893066, 0.67018026, 1.0],
-    [0.6493664, 0.35200042, 0.6741062, 1.0],
-    [0.6438445, 0.37255397, 0.6683322, 1.0],
-    [0.6741062, 0.35200042, 0.6493664, 1.0],
-    [0.66191477, 0.35176367, 0.66191477, 1.0],
-    [0.6683322, 0.37255397, 0.6438445, 1.0],
-    [0.65624815, 0.37239325, 0.65624815, 1.0],
-    [0.65910965, 0.3621448, 0.65910965, 1.0],
-    [0.669392, 0.32222506, 0.66939193, 1.0],
-    [0.61939746, 0.31181926, 0.7204968, 1.0],
-    [0.60804313, 0.3122779, 0.7299083, 1.0],
-    [0.6169582, 0.32640132, 0.71611786, 1.0],
-    [0.60631233, 0.33335966, 0.72198105, 1.0],
-    [0.6365366, 0.31153694, 0.7055253, 1.0],
-    [0.625721, 0.3326224, 0.70557475, 1.0],
-    [0.61432976, 0.34015903, 0.71196264, 1.0],
-    [0.6182778, 0.32395127, 0.7160924, 1.0],
-    [0.5983672, 0.3537241, 0.718913, 1.0],
-    [0.5923053, 0.37433735, 0.71347463, 1.0],
-    [0.6228706, 0.3526892, 0.698314, 1.0],
-    [0.6115334, 0.35307795, 0.7080698, 1.0],
-    [0.61778456, 0.373199, 0.6921451, 1.0],
-    [0.6065042, 0.37359703, 0.7018389, 1.0],
-    [0.6083272, 0.36337873, 0.70561594, 1.0],
-    [0.6451382, 0.31849152, 0.6945214, 1.0],
-    [0.64227223, 0.3317882, 0.6909435, 1.0],
-    [0.6437212, 0.3251362, 0.692755, 1.0],
-    [0.63739765, 0.35236394, 0.68524736, 1.0],
-    [0.63210964, 0.37284338, 0.67928284, 1.0],
-    [0.6347911, 0.36260352, 0.6823188, 1.0],
-    [0.6265269, 0.34363735, 0.6995552, 1.0],
-    [0.7335114, 0.2521499, 0.6311746, 1.0],
-    [0.7335115, 0.25214985, 0.6311747, 1.0],
-    [0.727968, 0.26915255, 0.6305708, 1.0],
-    [0.716621, 0.27586386, 0.64058834, 1.0],
-    [0.72863734, 0.28961045, 0.6206558, 1.0],
-    [0.7127849, 0.2903103, 0.63848084, 1.0],
-    [0.7347735, 0.2962985, 0.6101763, 1.0],
-    [0.7243416, 0.28412917, 0.6281718, 1.0],
-    [0.7290692, 0.2680786, 0.62975544, 1.0],
-    [0.7205137, 0.31174392, 0.61941564, 1.0],
-    [0.7055253, 0.31153697, 0.6365366, 1.0],
-    [0.71614355, 0.32638067, 0.61693925, 1.0],
-    [0.7055904, 0.3326098, 0.62571, 1.0],
-    [0.72992575, 0.31220245, 0.6080608, 1.0],
-    [0.7219964, 0.3333474, 0.6063008, 1.0],
-    [0.71198857, 0.34013835, 0.61431116, 1.0],
-    [0.7161093, 0.32392022, 0.61827445, 1.0],
-    [0.6945214, 0.31849152, 0.6451382, 1.0],
-    [0.6909435, 0.3317882, 0.64227223, 1.0],
-    [0.692755, 0.3251362, 0.6437212, 1.0],
-    [0.698314, 0.3526892, 0.6228706, 1.0],
-    [0.6921451, 0.37319902, 0.61778456, 1.0],
-    [0.718913, 0.3537241, 0.5983672, 1.0],
-    [0.7080698, 0.35307795, 0.6115334, 1.0],
-    [0.7134746, 0.37433735, 0.59230536, 1.0],
-    [0.7018389, 0.37359703, 0.6065042, 1.0],
-    [0.70561594, 0.36337873, 0.6083272, 1.0],
-    [0.68524736, 0.35236394, 0.63739765, 1.0],
-    [0.67928284, 0.37284338, 0.63210964, 1.0],
-    [0.6823188, 0.36260352, 0.6347911, 1.0],
-    [0.69955957, 0.34362954, 0.6265263, 1.0],
-    [0.67506343, 0.2976281, 0.67506063, 1.0],
-    [0.6947446, 0.18615782, 0.6947483, 1.0],
-    [0.7071066, 0.7071066, 0.00067114277, 1.0],
-    [0.68582636, 0.7277578, 0.003273122, 1.0],
-    [0.7277578, 0.68582636, 0.0032731616, 1.0],
-    [0.7070953, 0.7070953, 0.005695819, 1.0],
-    [0.7071043, 0.7071043, 0.002611232, 1.0],
-    [0.66546845, 0.7463799, 0.008290041, 1.0],
-    [0.6458661, 0.7632928, 0.015529205, 1.0],
-    [0.68706405, 0.72652, 0.01056658, 1.0],
-    [0.6676983, 0.7442226, 0.017656432, 1.0],
-    [0.6666241, 0.7452898, 0.0124643445, 1.0],
-    [0.7463799, 0.66546845, 0.008290041, 1.0],
-    [0.72652, 0.68706405, 0.010566617, 1.0],
-    [0.76329273, 0.645866, 0.015529201, 1.0],
-    [0.7442226, 0.6676983, 0.017656395, 1.0],
-    [0.7452898, 0.6666241, 0.0124643445, 1.0],
-    [0.7070252, 0.7070252, 0.015192659, 1.0],
-    [0.68793833, 0.7254336, 0.02206349, 1.0],
-    [0.7254336, 0.68793833, 0.02206349, 1.0],
-    [0.7067952, 0.7067952, 0.02968503, 1.0],
-    [0.7066567, 0.7066567, 0.035672866, 1.0],
-    [0.70689815, 0.70689815, 0.024290135, 1.0],
-    [0.7070675, 0.7070675, 0.010543662, 1.0],
-    [0.62663925, 0.77890986, 0.024951976, 1.0],
-    [0.6127081, 0.78960395, 0.03338471, 1.0],
-    [0.6037251, 0.79620975, 0.03957347, 1.0],
-    [0.6488309, 0.7604602, 0.026810218, 1.0],
-    [0.6367796, 0.7702984, 0.033942472, 1.0],
-    [0.6278222, 0.77733105, 0.03994391, 1.0],
-    [0.62614167, 0.7790267, 0.03261925, 1.0],
-    [0.59037435, 0.8055887, 0.04984866, 1.0],
-    [0.5757364, 0.81520885, 0.06294445, 1.0],
-    [0.6128897, 0.788507, 0.05121387, 1.0],
-    [0.59939307, 0.79796696, 0.063059896, 1.0],
-    [0.5946565, 0.8019997, 0.056393217, 1.0],
-    [0.56731415, 0.8204019, 0.071382225, 1.0],
-    [0.59096164, 0.8035425, 0.07130119, 1.0],
-    [0.5792645, 0.8120321, 0.07110875, 1.0],
-    [0.66848314, 0.7430736, 0.031179182, 1.0],
-    [0.66856545, 0.7427575, 0.036488395, 1.0],
-    [0.65192014, 0.75747806, 0.0350311, 1.0],
-    [0.65237105, 0.756791, 0.04097829, 1.0],
-    [0.68968105, 0.72335815, 0.033062574, 1.0],
-    [0.689443, 0.7233419, 0.03801082, 1.0],
-    [0.671152, 0.7397742, 0.047845643, 1.0],
-    [0.6703755, 0.7410953, 0.03707352, 1.0],
-    [0.63408244, 0.77131474, 0.054892283, 1.0],
-    [0.6229123, 0.77963585, 0.06440693, 1.0],
-    [0.652483, 0.75536823, 0.060701985, 1.0],
-    [0.6366577, 0.7683294, 0.065854214, 1.0],
-    [0.6366076, 0.7687519, 0.061246898, 1.0],
-    [0.6143906, 0.78567475, 0.072382115, 1.0],
-    [0.6521059, 0.7550893, 0.06780932, 1.0],
-    [0.63704145, 0.7672567, 0.074131235, 1.0],
-    [0.63466245, 0.7695127, 0.07108903, 1.0],
-    [0.6237584, 0.77981764, 0.05300787, 1.0],
-    [0.77890986, 0.62663925, 0.024951976, 1.0],
-    [0.7604602, 0.6488309, 0.026810218, 1.0],
-    [0.78960395, 0.6127081, 0.03338471, 1.0],
-    [0.79620975, 0.6037251, 0.039573435, 1.0],
-    [0.7702984, 0.6367796, 0.033942472, 1.0],
-    [0.77733105, 0.6278222, 0.03994391, 1.0],
-    [0.7790267, 0.62614167, 0.032619216, 1.0],
-    [0.74307346, 0.668483, 0.031179141, 1.0],
-    [0.7427575, 0.66856545, 0.036488395, 1.0],
-    [0.72335804, 0.68968105, 0.03306257, 1.0],
-    [0.7233419, 0.689443, 0.03801082, 1.0],
-    [0.75747806, 0.65192014, 0.0350311, 1.0],
-    [0.756791, 0.65237105, 0.04097829, 1.0],
-    [0.7397742, 0.671152, 0.047845706, 1.0],
-    [0.7410953, 0.6703755, 0.037073553, 1.0],
-    [0.8055887, 0.59037435, 0.04984866, 1.0],
-    [0.788507, 0.6128897, 0.05121387, 1.0],
-    [0.81520885, 0.5757364, 0.06294449, 1.0],
-    [0.79796696, 0.59939307, 0.063059896, 1.0],
-    [0.8019997, 0.5946565, 0.05639325, 1.0],
-    [0.8204019, 0.56731415, 0.071382225, 1.0],
-    [0.8035425, 0.59096164, 0.07130119, 1.0],
-    [0.81203216, 0.5792645, 0.07110876, 1.0],
-    [0.77131474, 0.63408244, 0.054892316, 1.0],
-    [0.75536823, 0.652483, 0.060701985, 1.0],
-    [0.77963585, 0.6229123, 0.06440693, 1.0],
-    [0.7683294, 0.6366577, 0.065854214, 1.0],
-    [0.7687519, 0.6366076, 0.061246835, 1.0],
-    [0.7550893, 0.6521059, 0.06780932, 1.0],
-    [0.78567475, 0.6143907, 0.07238215, 1.0],
-    [0.7672567, 0.63704145, 0.074131235, 1.0],
-    [0.7695127, 0.63466245, 0.07108903, 1.0],
-    [0.77981764, 0.6237584, 0.05300787, 1.0],
-    [0.706371, 0.706371, 0.04560651, 1.0],
-    [0.68895435, 0.72249734, 0.057787556, 1.0],
-    [0.72249734, 0.68895435, 0.057787556, 1.0],
-    [0.70574534, 0.70574534, 0.06202483, 1.0],
-    [0.7060191, 0.7060191, 0.055445045, 1.0],
-    [0.68890303, 0.7218284, 0.06615343, 1.0],
-    [0.7218284, 0.68890303, 0.06615343, 1.0],
-    [0.70545495, 0.70545495, 0.06831301, 1.0],
-    [0.7055382, 0.7055382, 0.06657135, 1.0],
-    [0.6733319, 0.73665977, 0.06290117, 1.0],
-    [0.67333186, 0.73665977, 0.06290123, 1.0],
-    [0.67286134, 0.7364703, 0.06977904, 1.0],
-    [0.6548377, 0.7509574, 0.08515048, 1.0],
-    [0.6549687, 0.74968743, 0.09478736, 1.0],
-    [0.688827, 0.72061, 0.07898424, 1.0],
-    [0.676301, 0.7311516, 0.08963453, 1.0],
-    [0.6678972, 0.73784065, 0.09749126, 1.0],
-    [0.6693302, 0.73801655, 0.08560705, 1.0],
-    [0.73665977, 0.67333186, 0.0629012, 1.0],
-    [0.73665977, 0.67333186, 0.0629012, 1.0],
-    [0.7364703, 0.6728614, 0.06977904, 1.0],
-    [0.7206101, 0.688827, 0.07898424, 1.0],
-    [0.7509574, 0.65483767, 0.08515048, 1.0],
-    [0.74968743, 0.6549687, 0.09478736, 1.0],
-    [0.7311516, 0.676301, 0.08963453, 1.0],
-    [0.73784065, 0.6678972, 0.09749126, 1.0],
-    [0.73801655, 0.6693302, 0.08560705, 1.0],
-    [0.7042753, 0.70427537, 0.08940078, 1.0],
-    [0.7037656, 0.7037656, 0.09709783, 1.0],
-    [0.68839175, 0.7193683, 0.092876665, 1.0],
-    [0.6877142, 0.71818316, 0.10612293, 1.0],
-    [0.7193683, 0.68839175, 0.09287663, 1.0],
-    [0.7181832, 0.6877142, 0.10612298, 1.0],
-    [0.7024571, 0.7024571, 0.11449036, 1.0],
-    [0.7035959, 0.70359594, 0.09952772, 1.0],
-    [0.7052431, 0.7052431, 0.07255517, 1.0],
-    [0.6869498, 0.7167471, 0.11989023, 1.0],
-    [0.7167471, 0.6869498, 0.11989023, 1.0],
-    [0.7017261, 0.7017261, 0.12313003, 1.0],
-    [0.7019344, 0.7019344, 0.12073215, 1.0],
-    [0.7019344, 0.7019344, 0.12073215, 1.0],
-    [0.70610195, 0.7061019, 0.053293027, 1.0],
-    [0.5564752, 0.8266948, 0.083133176, 1.0],
-    [0.5460226, 0.8323005, 0.0955791, 1.0],
-    [0.5378391, 0.8363503, 0.106053166, 1.0],
-    [0.5789917, 0.8109911, 0.08403648, 1.0],
-    [0.56932575, 0.8165682, 0.09531224, 1.0],
-    [0.5611217, 0.8209733, 0.10557163, 1.0],
-    [0.558295, 0.82423234, 0.094592325, 1.0],
-    [0.5277299, 0.8408816, 0.12008019, 1.0],
-    [0.55084527, 0.82601434, 0.11945605, 1.0],
-    [0.5393824, 0.83352816, 0.11957236, 1.0],
-    [0.59862417, 0.79614526, 0.08832757, 1.0],
-    [0.598917, 0.7949425, 0.09677241, 1.0],
-    [0.58374673, 0.8062667, 0.09577945, 1.0],
-    [0.5845141, 0.80456555, 0.104963936, 1.0],
-    [0.6230341, 0.7770803, 0.08930171, 1.0],
-    [0.6147469, 0.7825604, 0.09841472, 1.0],
-    [0.60224545, 0.7902573, 0.11311084, 1.0],
-    [0.60091287, 0.79331434, 0.097755164, 1.0],
-    [0.57340574, 0.8104011, 0.12023377, 1.0],
-    [0.5876677, 0.7999417, 0.12140797, 1.0],
-    [0.5805808, 0.8051998, 0.1207443, 1.0],
-    [0.5700152, 0.8146037, 0.10725459, 1.0],
-    [0.5198519, 0.84397537, 0.13213481, 1.0],
-    [0.5117312, 0.84673864, 0.1454812, 1.0],
-    [0.503803, 0.84902847, 0.15916398, 1.0],
-    [0.54289234, 0.8294493, 0.13145992, 1.0],
-    [0.53475004, 0.83253735, 0.14465092, 1.0],
-    [0.52662647, 0.83525157, 0.15817504, 1.0],
-    [0.5232503, 0.8397719, 0.14488724, 1.0],
-    [0.602435, 0.7886241, 0.12306176, 1.0],
-    [0.602435, 0.7886241, 0.12306176, 1.0],
-    [0.5653625, 0.8142011, 0.13206689, 1.0],
-    [0.55700314, 0.8177127, 0.14523557, 1.0],
-    [0.5487235, 0.8207953, 0.15873677, 1.0],
-    [0.5874078, 0.79801136, 0.13464741, 1.0],
-    [0.58712363, 0.79579127, 0.14833106, 1.0],
-    [0.57289016, 0.80642855, 0.1465264, 1.0],
-    [0.57109624, 0.80510664, 0.16016346, 1.0],
-    [0.5699955, 0.8085297, 0.14623533, 1.0],
-    [0.5692996, 0.8034078, 0.17445306, 1.0],
-    [0.5692996, 0.8034078, 0.17445306, 1.0],
-    [0.5665367, 0.8110138, 0.14592049, 1.0],
-    [0.49678004, 0.85063934, 0.17211096, 1.0],
-    [0.51935434, 0.8372567, 0.17109123, 1.0],
-    [0.5081394, 0.8440397, 0.17143889, 1.0],
-    [0.48894274, 0.85183203, 0.18792814, 1.0],
-    [0.4831841, 0.8522868, 0.20035039, 1.0],
-    [0.47630915, 0.8523563, 0.21591255, 1.0],
-    [0.5113708, 0.8388047, 0.18683288, 1.0],
-    [0.5055293, 0.83952546, 0.19909069, 1.0],
-    [0.499085, 0.8398985, 0.21327099, 1.0],
-    [0.49404952, 0.84603333, 0.20035632, 1.0],
-    [0.5413712, 0.82310736, 0.17149772, 1.0],
-    [0.55521137, 0.8136127, 0.17255378, 1.0],
-    [0.5483263, 0.818393, 0.17196277, 1.0],
-    [0.53185284, 0.8254886, 0.1889473, 1.0],
-    [0.53346026, 0.8218346, 0.20002006, 1.0],
-    [0.5192288, 0.83109885, 0.19918896, 1.0],
-    [0.522069, 0.82677186, 0.20950486, 1.0],
-    [0.5569149, 0.80997753, 0.1837994, 1.0],
-    [0.5482416, 0.811644, 0.20165567, 1.0],
-    [0.5426924, 0.8123652, 0.21341921, 1.0],
-    [0.53639144, 0.82010186, 0.19929151, 1.0],
-    [0.52186143, 0.8327005, 0.18512344, 1.0],
-    [0.64009047, 0.76278263, 0.09190606, 1.0],
-    [0.6396296, 0.7620693, 0.10061999, 1.0],
-    [0.625579, 0.77162373, 0.11509869, 1.0],
-    [0.6549383, 0.74744654, 0.11126324, 1.0],
-    [0.64029485, 0.7589808, 0.11819705, 1.0],
-    [0.6401765, 0.76071763, 0.10715782, 1.0],
-    [0.6727203, 0.73085123, 0.115341134, 1.0],
-    [0.6727203, 0.73085123, 0.115341134, 1.0],
-    [0.65677446, 0.7459025, 0.11080074, 1.0],
-    [0.6174225, 0.77654696, 0.12555581, 1.0],
-    [0.65449196, 0.74619585, 0.12178727, 1.0],
-    [0.63951534, 0.7573974, 0.13179258, 1.0],
-    [0.6387117, 0.7554966, 0.14584975, 1.0],
-    [0.6376809, 0.75909394, 0.13091765, 1.0],
-    [0.608075, 0.78171545, 0.13844034, 1.0],
-    [0.59989303, 0.78579855, 0.15049578, 1.0],
-    [0.5920749, 0.7892474, 0.16289875, 1.0],
-    [0.6249642, 0.76761115, 0.14210208, 1.0],
-    [0.6235778, 0.76602983, 0.1560417, 1.0],
-    [0.6085612, 0.7757964, 0.16671316, 1.0],
-    [0.60958153, 0.77790976, 0.15253462, 1.0],
-    [0.58374983, 0.79242086, 0.17693317, 1.0],
-    [0.6220775, 0.7641831, 0.17042217, 1.0],
-    [0.60641736, 0.7742617, 0.18104352, 1.0],
-    [0.60413474, 0.7723666, 0.19614044, 1.0],
-    [0.6042104, 0.776027, 0.18086381, 1.0],
-    [0.6718354, 0.7301391, 0.12463627, 1.0],
-    [0.65796065, 0.7402127, 0.1384663, 1.0],
-    [0.6498455, 0.7452797, 0.149195, 1.0],
-    [0.68549824, 0.7150961, 0.13685593, 1.0],
-    [0.68437386, 0.7139213, 0.14815119, 1.0],
-    [0.6706609, 0.7278885, 0.14280081, 1.0],
-    [0.66914445, 0.7263596, 0.15699552, 1.0],
-    [0.6700326, 0.72859275, 0.14215821, 1.0],
-    [0.6673832, 0.72461843, 0.17183687, 1.0],
-    [0.66738313, 0.7246184, 0.17183682, 1.0],
-    [0.64128816, 0.75013334, 0.1613987, 1.0],
-    [0.6542693, 0.73776037, 0.16625668, 1.0],
-    [0.64783496, 0.74397093, 0.1637595, 1.0],
-    [0.6328827, 0.7544407, 0.17400703, 1.0],
-    [0.6255119, 0.75777036, 0.1857933, 1.0],
-    [0.6173395, 0.7599056, 0.20355669, 1.0],
-    [0.65230024, 0.7360262, 0.18102436, 1.0],
-    [0.6501757, 0.7339408, 0.1964755, 1.0],
-    [0.63743633, 0.74657583, 0.19052403, 1.0],
-    [0.638155, 0.742367, 0.20408176, 1.0],
-    [0.63636714, 0.7474782, 0.19056064, 1.0],
-    [0.6394304, 0.75184464, 0.16080584, 1.0],
-    [0.5770944, 0.79450536, 0.18900624, 1.0],
-    [0.5690114, 0.7957232, 0.2074867, 1.0],
-    [0.55825055, 0.80104506, 0.21606308, 1.0],
-    [0.590968, 0.7834576, 0.19222699, 1.0],
-    [0.59187454, 0.7795677, 0.20483822, 1.0],
-    [0.5774715, 0.79109746, 0.20172155, 1.0],
-    [0.6069268, 0.76623946, 0.21099065, 1.0],
-    [0.60692674, 0.7662394, 0.2109906, 1.0],
-    [0.5924251, 0.7788259, 0.20606495, 1.0],
-    [0.5919406, 0.7920728, 0.1490872, 1.0],
-    [0.4698319, 0.8518505, 0.23153578, 1.0],
-    [0.4917844, 0.83969665, 0.23034266, 1.0],
-    [0.48086253, 0.8458708, 0.23081098, 1.0],
-    [0.46509463, 0.8511326, 0.24343415, 1.0],
-    [0.45771623, 0.8492184, 0.26329452, 1.0],
-    [0.48697826, 0.8391871, 0.24211007, 1.0],
-    [0.4800297, 0.83774924, 0.26028395, 1.0],
-    [0.4724534, 0.8445202, 0.25213745, 1.0],
-    [0.4519291, 0.84682757, 0.28043407, 1.0],
-    [0.44828728, 0.84496355, 0.29167652, 1.0],
-    [0.44311464, 0.8416933, 0.3085479, 1.0],
-    [0.47335333, 0.83538085, 0.27941984, 1.0],
-    [0.46961603, 0.83366835, 0.29061618, 1.0],
-    [0.4650162, 0.83102435, 0.30521846, 1.0],
-    [0.45853576, 0.83914644, 0.2925377, 1.0],
-    [0.5130081, 0.82668954, 0.23109956, 1.0],
-    [0.5324136, 0.813451, 0.23416461, 1.0],
-    [0.5339557, 0.8088516, 0.24627289, 1.0],
-    [0.52654225, 0.8164314, 0.23705071, 1.0],
-    [0.50824124, 0.826299, 0.24273562, 1.0],
-    [0.50248605, 0.82532275, 0.2575854, 1.0],
-    [0.52053934, 0.8182593, 0.2439068, 1.0],
-    [0.52402294, 0.812673, 0.25487736, 1.0],
-    [0.51384944, 0.8207395, 0.2496908, 1.0],
-    [0.49329606, 0.8229806, 0.2817126, 1.0],
-    [0.49616286, 0.8172562, 0.29311228, 1.0],
-    [0.4827274, 0.8259948, 0.29104432, 1.0],
-    [0.4866966, 0.81986856, 0.30156583, 1.0],
-    [0.51626986, 0.8101018, 0.27784997, 1.0],
-    [0.51044196, 0.8062939, 0.29889628, 1.0],
-    [0.5020595, 0.8087836, 0.3062767, 1.0],
-    [0.49826598, 0.8160841, 0.29281008, 1.0],
-    [0.4917081, 0.8314872, 0.2585581, 1.0],
-    [0.43686607, 0.83652, 0.33073008, 1.0],
-    [0.43107325, 0.83061445, 0.35249898, 1.0],
-    [0.45861256, 0.82621676, 0.3271704, 1.0],
-    [0.45261917, 0.820594, 0.34894335, 1.0],
-    [0.44477326, 0.82869923, 0.3397563, 1.0],
-    [0.42577556, 0.8238728, 0.37409732, 1.0],
-    [0.4210865, 0.8162301, 0.39554328, 1.0],
-    [0.4471387, 0.81409633, 0.3705594, 1.0],
-    [0.4423961, 0.8067111, 0.39179438, 1.0],
-    [0.43408063, 0.8154282, 0.3829505, 1.0],
-    [0.48029125, 0.81507087, 0.32400584, 1.0],
-    [0.47417742, 0.80971086, 0.34572265, 1.0],
-    [0.51447475, 0.79922825, 0.31072485, 1.0],
-    [0.5039414, 0.80241686, 0.3196407, 1.0],
-    [0.49580488, 0.797909, 0.34281012, 1.0],
-    [0.49373138, 0.8052059, 0.3284399, 1.0],
-    [0.4686373, 0.80349267, 0.3671221, 1.0],
-    [0.46359912, 0.79645, 0.38825676, 1.0],
-    [0.4900365, 0.7920523, 0.3640295, 1.0],
-    [0.48471257, 0.7853506, 0.38506886, 1.0],
-    [0.47675547, 0.7945291, 0.37606874, 1.0],
-    [0.4622999, 0.81190395, 0.3564978, 1.0],
-    [0.619469, 0.75462717, 0.21632364, 1.0],
-    [0.6297871, 0.74365574, 0.22437571, 1.0],
-    [0.63191766, 0.7376367, 0.23784915, 1.0],
-    [0.6271542, 0.7453566, 0.22610006, 1.0],
-    [0.6271542, 0.7453566, 0.22610004, 1.0],
-    [0.57148206, 0.79077387, 0.21928276, 1.0],
-    [0.5590188, 0.79702103, 0.22859469, 1.0],
-    [0.549189, 0.7966305, 0.25252962, 1.0],
-    [0.58260095, 0.7815389, 0.22309871, 1.0],
-    [0.5773678, 0.782075, 0.2345318, 1.0],
-    [0.5700069, 0.7822617, 0.25131392, 1.0],
-    [0.5682971, 0.7886312, 0.23473223, 1.0],
-    [0.5526027, 0.7904032, 0.26437283, 1.0],
-    [0.563316, 0.78032213, 0.2716109, 1.0],
-    [0.56660616, 0.7732687, 0.28462756, 1.0],
-    [0.5609093, 0.781404, 0.27347526, 1.0],
-    [0.53984374, 0.8004805, 0.26038393, 1.0],
-    [0.5398437, 0.8004804, 0.26038387, 1.0],
-    [0.5408069, 0.7954101, 0.27358836, 1.0],
-    [0.5305993, 0.7937379, 0.29739648, 1.0],
-    [0.5546844, 0.7841395, 0.27829885, 1.0],
-    [0.55503005, 0.77830905, 0.29355857, 1.0],
-    [0.5453238, 0.78806615, 0.28561082, 1.0],
-    [0.6035634, 0.76428753, 0.22710282, 1.0],
-    [0.59984255, 0.7618017, 0.24463624, 1.0],
-    [0.587745, 0.7731158, 0.23842745, 1.0],
-    [0.59053195, 0.7668736, 0.25135025, 1.0],
-    [0.6200097, 0.7498898, 0.23076653, 1.0],
-    [0.6163306, 0.7473468, 0.24821228, 1.0],
-    [0.60072786, 0.75634, 0.2589901, 1.0],
-    [0.60276145, 0.76013786, 0.24262916, 1.0],
-    [0.5834426, 0.7653851, 0.27162525, 1.0],
-    [0.5761312, 0.7635205, 0.291735, 1.0],
-    [0.6120098, 0.7432973, 0.27009842, 1.0],
-    [0.6115308, 0.73913413, 0.28233105, 1.0],
-    [0.6004625, 0.7524755, 0.2706019, 1.0],
-    [0.59973884, 0.74554145, 0.2906568, 1.0],
-    [0.597329, 0.7517754, 0.27934164, 1.0],
-    [0.5666657, 0.76800233, 0.2984335, 1.0],
-    [0.56666565, 0.7680023, 0.29843345, 1.0],
-    [0.5689909, 0.77769786, 0.26727372, 1.0],
-    [0.52466464, 0.7899208, 0.31741473, 1.0],
-    [0.527354, 0.78165835, 0.33302844, 1.0],
-    [0.51614195, 0.79275906, 0.32423818, 1.0],
-    [0.5178865, 0.7847097, 0.34062338, 1.0],
-    [0.54520446, 0.7761469, 0.31677783, 1.0],
-    [0.53723663, 0.772498, 0.3385613, 1.0],
-    [0.52699745, 0.77587634, 0.34682766, 1.0],
-    [0.5279613, 0.78210807, 0.33100438, 1.0],
-    [0.5113463, 0.779679, 0.3614218, 1.0],
-    [0.50595963, 0.7731861, 0.3823456, 1.0],
-    [0.536103, 0.76414883, 0.358706, 1.0],
-    [0.53254616, 0.75664765, 0.37931392, 1.0],
-    [0.52539414, 0.77102023, 0.35984564, 1.0],
-    [0.5219591, 0.76341796, 0.3804625, 1.0],
-    [0.5222601, 0.7681954, 0.37029746, 1.0],
-    [0.5775291, 0.7557393, 0.30873662, 1.0],
-    [0.58769286, 0.7455206, 0.3143503, 1.0],
-    [0.58589023, 0.7383058, 0.33412132, 1.0],
-    [0.58377045, 0.7466112, 0.31903586, 1.0],
-    [0.5824416, 0.73139244, 0.35472062, 1.0],
-    [0.5779775, 0.72465086, 0.37526417, 1.0],
-    [0.5802277, 0.7280902, 0.36499372, 1.0],
-    [0.56754553, 0.76040745, 0.31570956, 1.0],
-    [0.5605627, 0.7568993, 0.33596557, 1.0],
-    [0.5764053, 0.7507994, 0.3225792, 1.0],
-    [0.5747898, 0.74664044, 0.33488002, 1.0],
-    [0.5698569, 0.7537697, 0.32725278, 1.0],
-    [0.55426663, 0.75186783, 0.35704803, 1.0],
-    [0.5485687, 0.7458788, 0.37780577, 1.0],
-    [0.57140213, 0.73963565, 0.35558224, 1.0],
-    [0.5670009, 0.7328152, 0.37615386, 1.0],
-    [0.56035995, 0.7426945, 0.36660805, 1.0],
-    [0.5577255, 0.75438553, 0.34618613, 1.0],
-    [0.5422223, 0.78932214, 0.2880373, 1.0],
-    [0.8266947, 0.5564752, 0.083133176, 1.0],
-    [0.8109911, 0.5789917, 0.08403644, 1.0],
-    [0.8323004, 0.5460226, 0.0955791, 1.0],
-    [0.83635026, 0.5378391, 0.106053166, 1.0],
-    [0.8165682, 0.56932575, 0.09531224, 1.0],
-    [0.8209733, 0.5611217, 0.10557163, 1.0],
-    [0.82423234, 0.558295, 0.094592325, 1.0],
-    [0.79614526, 0.59862417, 0.08832757, 1.0],
-    [0.7949425, 0.598917, 0.09677241, 1.0],
-    [0.7770803, 0.6230341, 0.08930171, 1.0],
-    [0.7825604, 0.6147469, 0.09841472, 1.0],
-    [0.8062667, 0.58374673, 0.09577945, 1.0],
-    [0.80456555, 0.5845141, 0.104963936, 1.0],
-    [0.7902573, 0.60224545, 0.1131109, 1.0],
-    [0.79331434, 0.60091287, 0.09775519, 1.0],
-    [0.8408816, 0.5277299, 0.12008019, 1.0],
-    [0.82601434, 0.55084527, 0.11945602, 1.0],
-    [0.83352816, 0.5393824, 0.11957236, 1.0],
-    [0.8104011, 0.57340574, 0.12023374, 1.0],
-    [0.7999417, 0.5876677, 0.12140803, 1.0],
-    [0.80519986, 0.5805808, 0.1207443, 1.0],
-    [0.8146037, 0.5700152, 0.10725459, 1.0],
-    [0.7886241, 0.602435, 0.12306176, 1.0],
-    [0.7886241, 0.602435, 0.12306176, 1.0],
-    [0.84397537, 0.5198519, 0.13213481, 1.0],
-    [0.82944924, 0.54289234, 0.13145992, 1.0],
-    [0.84673864, 0.5117312, 0.14548117, 1.0],
-    [0.84902847, 0.503803, 0.15916398, 1.0],
-    [0.83253735, 0.53475004, 0.14465089, 1.0],
-    [0.83525157, 0.52662647, 0.15817498, 1.0],
-    [0.8397719, 0.5232503, 0.14488724, 1.0],
-    [0.8142011, 0.5653625, 0.13206689, 1.0],
-    [0.79801136, 0.5874078, 0.13464747, 1.0],
-    [0.79579127, 0.58712363, 0.14833106, 1.0],
-    [0.8177127, 0.55700314, 0.14523557, 1.0],
-    [0.8207953, 0.5487235, 0.15873677, 1.0],
-    [0.80642855, 0.57289016, 0.1465264, 1.0],
-    [0.80510664, 0.57109624, 0.16016346, 1.0],
-    [0.8085297, 0.5699955, 0.14623533, 1.0],
-    [0.8034078, 0.5692996, 0.17445306, 1.0],
-    [0.8034078, 0.5692996, 0.17445306, 1.0],
-    [0.8110138, 0.5665367, 0.14592049, 1.0],
-    [0.76278275, 0.64009047, 0.09190607, 1.0],
-    [0.7620693, 0.6396296, 0.10061999, 1.0],
-    [0.7474466, 0.6549383, 0.11126321, 1.0],
-    [0.77162373, 0.625579, 0.11509866, 1.0],
-    [0.7589808, 0.64029485, 0.11819705, 1.0],
-    [0.76071763, 0.6401765, 0.10715782, 1.0],
-    [0.73085123, 0.6727203, 0.115341164, 1.0],
-    [0.73085123, 0.6727203, 0.115341164, 1.0],
-    [0.7459025, 0.65677446, 0.11080074, 1.0],
-    [0.74619585, 0.65449196, 0.12178727, 1.0],
-    [0.77654696, 0.6174225, 0.12555578, 1.0],
-    [0.7573974, 0.6395153, 0.13179259, 1.0],
-    [0.7554966, 0.6387117, 0.14584975, 1.0],
-    [0.75909394, 0.6376809, 0.13091771, 1.0],
-    [0.73013914, 0.6718354, 0.124636285, 1.0],
-    [0.7150962, 0.6854983, 0.13685594, 1.0],
-    [0.7139213, 0.68437386, 0.14815119, 1.0],
-    [0.7402127, 0.65796065, 0.13846633, 1.0],
-    [0.7452797, 0.6498455, 0.149195, 1.0],
-    [0.7278886, 0.6706609, 0.14280081, 1.0],
-    [0.72635955, 0.66914445, 0.15699552, 1.0],
-    [0.72859275, 0.6700326, 0.14215823, 1.0],
-    [0.72461843, 0.6673832, 0.17183687, 1.0],
-    [0.7246184, 0.66738313, 0.17183682, 1.0],
-    [0.78171545, 0.608075, 0.13844034, 1.0],
-    [0.78579855, 0.59989303, 0.15049581, 1.0],
-    [0.7676111, 0.6249641, 0.14210209, 1.0],
-    [0.7660298, 0.6235778, 0.15604171, 1.0],
-    [0.78924745, 0.5920749, 0.16289875, 1.0],
-    [0.7757964, 0.6085612, 0.1667131, 1.0],
-    [0.7779097, 0.6095814, 0.15253465, 1.0],
-    [0.7641831, 0.6220776, 0.17042214, 1.0],
-    [0.79242086, 0.58374983, 0.17693318, 1.0],
-    [0.7742617, 0.60641736, 0.18104352, 1.0],
-    [0.7723666, 0.60413474, 0.19614044, 1.0],
-    [0.7760271, 0.6042105, 0.1808638, 1.0],
-    [0.75013334, 0.64128816, 0.1613987, 1.0],
-    [0.73776037, 0.6542693, 0.16625665, 1.0],
-    [0.74397093, 0.64783496, 0.1637595, 1.0],
-    [0.7544407, 0.6328827, 0.17400703, 1.0],
-    [0.7360262, 0.65230024, 0.18102436, 1.0],
-    [0.73394066, 0.6501757, 0.19647548, 1.0],
-    [0.75777024, 0.6255119, 0.18579331, 1.0],
-    [0.7599056, 0.6173395, 0.20355669, 1.0],
-    [0.74657583, 0.63743633, 0.19052403, 1.0],
-    [0.742367, 0.638155, 0.20408176, 1.0],
-    [0.7474782, 0.6363671, 0.19056064, 1.0],
-    [0.7518447, 0.6394304, 0.16080584, 1.0],
-    [0.8506394, 0.49678004, 0.17211096, 1.0],
-    [0.8372568, 0.51935434, 0.17109123, 1.0],
-    [0.8440397, 0.5081394, 0.17143889, 1.0],
-    [0.85183215, 0.4889428, 0.18792814, 1.0],
-    [0.8388047, 0.5113708, 0.18683288, 1.0],
-    [0.8522868, 0.4831841, 0.20035039, 1.0],
-    [0.8523564, 0.4763092, 0.21591257, 1.0],
-    [0.8395254, 0.5055292, 0.19909067, 1.0],
-    [0.8398986, 0.499085, 0.21327099, 1.0],
-    [0.8460334, 0.49404952, 0.20035632, 1.0],
-    [0.8231074, 0.5413712, 0.17149772, 1.0],
-    [0.81361264, 0.55521137, 0.17255375, 1.0],
-    [0.818393, 0.5483263, 0.17196274, 1.0],
-    [0.8254886, 0.53185284, 0.1889473, 1.0],
-    [0.8218346, 0.53346026, 0.20002006, 1.0],
-    [0.8099775, 0.55691487, 0.1837994, 1.0],
-    [0.811644, 0.5482416, 0.20165567, 1.0],
-    [0.83109885, 0.5192288, 0.19918899, 1.0],
-    [0.82677186, 0.5220689, 0.20950487, 1.0],
-    [0.8123652, 0.54269236, 0.2134192, 1.0],
-    [0.82010186, 0.53639144, 0.19929151, 1.0],
-    [0.8327005, 0.52186143, 0.18512344, 1.0],
-    [0.79450536, 0.5770944, 0.18900624, 1.0],
-    [0.7957232, 0.5690114, 0.2074867, 1.0],
-    [0.7834575, 0.5909679, 0.19222693, 1.0],
-    [0.7795677, 0.5918745, 0.20483822, 1.0],
-    [0.80104506, 0.55825055, 0.21606308, 1.0],
-    [0.7910974, 0.5774714, 0.20172152, 1.0],
-    [0.76623946, 0.60692674, 0.21099062, 1.0],
-    [0.76623946, 0.60692674, 0.21099062, 1.0],
-    [0.7788259, 0.5924251, 0.20606495, 1.0],
-    [0.7920728, 0.5919406, 0.14908724, 1.0],
-    [0.75462717, 0.619469, 0.21632364, 1.0],
-    [0.74365574, 0.6297871, 0.22437571, 1.0],
-    [0.7376367, 0.63191766, 0.23784915, 1.0],
-    [0.7453566, 0.6271542, 0.22610006, 1.0],
-    [0.7453566, 0.6271542, 0.22610004, 1.0],
-    [0.8518505, 0.4698319, 0.23153578, 1.0],
-    [0.83969665, 0.4917844, 0.23034266, 1.0],
-    [0.8458708, 0.48086253, 0.23081098, 1.0],
-    [0.82668954, 0.5130081, 0.23109956, 1.0],
-    [0.813451, 0.5324136, 0.23416461, 1.0],
-    [0.80885166, 0.5339557, 0.24627289, 1.0],
-    [0.8164314, 0.52654225, 0.23705071, 1.0],
-    [0.8511326, 0.46509463, 0.24343415, 1.0],
-    [0.8492184, 0.45771623, 0.2632945, 1.0],
-    [0.8391871, 0.48697832, 0.24211007, 1.0],
-    [0.83774924, 0.4800297, 0.26028395, 1.0],
-    [0.8445202, 0.4724534, 0.25213742, 1.0],
-    [0.84682757, 0.4519291, 0.28043407, 1.0],
-    [0.83538085, 0.47335333, 0.27941987, 1.0],
-    [0.84496355, 0.44828728, 0.29167652, 1.0],
-    [0.8416933, 0.44311464, 0.30854794, 1.0],
-    [0.83366835, 0.46961603, 0.29061618, 1.0],
-    [0.83102435, 0.4650162, 0.30521846, 1.0],
-    [0.83914644, 0.45853576, 0.2925377, 1.0],
-    [0.826299, 0.50824124, 0.24273562, 1.0],
-    [0.82532275, 0.50248605, 0.2575854, 1.0],
-    [0.8182593, 0.52053934, 0.2439068, 1.0],
-    [0.812673, 0.524023, 0.25487733, 1.0],
-    [0.8207395, 0.51384944, 0.2496908, 1.0],
-    [0.8229806, 0.49329606, 0.2817126, 1.0],
-    [0.8172562, 0.49616286, 0.29311228, 1.0],
-    [0.8101018, 0.51626986, 0.27784997, 1.0],
-    [0.8062939, 0.51044196, 0.29889628, 1.0],
-    [0.8259948, 0.4827274, 0.29104432, 1.0],
-    [0.81986856, 0.4866966, 0.30156583, 1.0],
-    [0.8087836, 0.5020595, 0.3062767, 1.0],
-    [0.8160841, 0.49826598, 0.2928101, 1.0],
-    [0.8314872, 0.4917081, 0.2585581, 1.0],
-    [0.8365201, 0.4368661, 0.3307301, 1.0],
-    [0.83061445, 0.43107325, 0.35249898, 1.0],
-    [0.82621676, 0.45861256, 0.3271704, 1.0],
-    [0.820594, 0.45261917, 0.34894338, 1.0],
-    [0.82869923, 0.44477326, 0.3397563, 1.0],
-    [0.8238728, 0.42577556, 0.37409732, 1.0],
-    [0.8162301, 0.4210865, 0.39554328, 1.0],
-    [0.81409633, 0.4471387, 0.3705594, 1.0],
-    [0.8067111, 0.4423961, 0.39179438, 1.0],
-    [0.8154282, 0.43408063, 0.3829505, 1.0],
-    [0.79922825, 0.51447475, 0.31072482, 1.0],
-    [0.81507087, 0.48029125, 0.32400584, 1.0],
-    [0.80971086, 0.47417742, 0.34572265, 1.0],
-    [0.80241686, 0.5039414, 0.3196407, 1.0],
-    [0.797909, 0.49580488, 0.34281012, 1.0],
-    [0.8052059, 0.49373138, 0.3284399, 1.0],
-    [0.80349267, 0.4686373, 0.3671221, 1.0],
-    [0.79645, 0.4635991, 0.38825673, 1.0],
-    [0.7920523, 0.4900365, 0.3640295, 1.0],
-    [0.7853506, 0.48471257, 0.3850689, 1.0],
-    [0.7945291, 0.47675547, 0.3760687, 1.0],
-    [0.81190395, 0.4622999, 0.3564978, 1.0],
-    [0.79077387, 0.57148206, 0.21928276, 1.0],
-    [0.7815389, 0.58260095, 0.22309871, 1.0],
-    [0.79702103, 0.5590188, 0.22859469, 1.0],
-    [0.7966305, 0.549189, 0.25252962, 1.0],
-    [0.782075, 0.5773678, 0.2345318, 1.0],
-    [0.7822618, 0.5700069, 0.25131392, 1.0],
-    [0.7886312, 0.5682971, 0.23473223, 1.0],
-    [0.7904032, 0.5526027, 0.26437283, 1.0],
-    [0.78032213, 0.563316, 0.2716109, 1.0],
-    [0.7732687, 0.56660616, 0.28462756, 1.0],
-    [0.781404, 0.5609093, 0.27347526, 1.0],
-    [0.76428753, 0.6035634, 0.22710282, 1.0],
-    [0.76180184, 0.5998426, 0.24463627, 1.0],
-    [0.7498898, 0.6200097, 0.23076653, 1.0],
-    [0.7473468, 0.6163306, 0.24821228, 1.0],
-    [0.7731158, 0.587745, 0.23842745, 1.0],
-    [0.7668736, 0.59053195, 0.25135025, 1.0],
-    [0.75634, 0.60072786, 0.2589901, 1.0],
-    [0.7601379, 0.6027615, 0.24262914, 1.0],
-    [0.7432973, 0.6120098, 0.27009842, 1.0],
-    [0.73913413, 0.6115308, 0.28233105, 1.0],
-    [0.7653851, 0.5834426, 0.27162525, 1.0],
-    [0.7635205, 0.5761312, 0.291735, 1.0],
-    [0.7524755, 0.6004625, 0.27060196, 1.0],
-    [0.74554145, 0.59973884, 0.2906568, 1.0],
-    [0.7517754, 0.597329, 0.27934167, 1.0],
-    [0.8004804, 0.5398437, 0.26038387, 1.0],
-    [0.8004804, 0.5398437, 0.26038387, 1.0],
-    [0.7954101, 0.5408069, 0.27358842, 1.0],
-    [0.7937379, 0.5305993, 0.29739648, 1.0],
-    [0.78413963, 0.5546845, 0.27829885, 1.0],
-    [0.77830905, 0.55503005, 0.29355857, 1.0],
-    [0.78806615, 0.5453238, 0.28561082, 1.0],
-    [0.7680022, 0.56666565, 0.2984335, 1.0],
-    [0.7680023, 0.56666565, 0.29843348, 1.0],
-    [0.77769786, 0.5689909, 0.26727375, 1.0],
-    [0.7557393, 0.5775291, 0.30873662, 1.0],
-    [0.7455206, 0.58769286, 0.31435034, 1.0],
-    [0.7383058, 0.58589023, 0.33412132, 1.0],
-    [0.7466112, 0.58377045, 0.31903586, 1.0],
-    [0.7313925, 0.58244157, 0.3547206, 1.0],
-    [0.7246508, 0.5779775, 0.37526414, 1.0],
-    [0.7280902, 0.5802277, 0.36499372, 1.0],
-    [0.7899208, 0.52466464, 0.31741473, 1.0],
-    [0.78165835, 0.527354, 0.33302844, 1.0],
-    [0.7761469, 0.54520446, 0.31677783, 1.0],
-    [0.772498, 0.53723663, 0.3385613, 1.0],
-    [0.79275906, 0.51614195, 0.32423818, 1.0],
-    [0.7847097, 0.5178865, 0.34062338, 1.0],
-    [0.77587634, 0.52699745, 0.34682766, 1.0],
-    [0.78210807, 0.5279613, 0.33100438, 1.0],
-    [0.7641489, 0.536103, 0.358706, 1.0],
-    [0.75664765, 0.53254616, 0.37931392, 1.0],
-    [0.779679, 0.5113463, 0.3614218, 1.0],
-    [0.7731861, 0.50595963, 0.3823456, 1.0],
-    [0.77102023, 0.52539414, 0.35984564, 1.0],
-    [0.76341796, 0.5219591, 0.3804625, 1.0],
-    [0.7681954, 0.5222601, 0.37029746, 1.0],
-    [0.76040745, 0.56754553, 0.31570953, 1.0],
-    [0.7568993, 0.5605627, 0.33596557, 1.0],
-    [0.7507994, 0.5764053, 0.3225792, 1.0],
-    [0.74664044, 0.5747898, 0.33488005, 1.0],
-    [0.7537697, 0.5698569, 0.32725275, 1.0],
-    [0.75186783, 0.55426663, 0.35704803, 1.0],
-    [0.7458788, 0.5485687, 0.37780574, 1.0],
-    [0.73963565, 0.57140213, 0.35558224, 1.0],
-    [0.7328152, 0.5670009, 0.37615386, 1.0],
-    [0.7426945, 0.56035995, 0.36660805, 1.0],
-    [0.75438553, 0.5577255, 0.34618613, 1.0],
-    [0.78932214, 0.5422223, 0.2880373, 1.0],
-    [0.69995844, 0.69995844, 0.1418319, 1.0],
-    [0.6984412, 0.6984412, 0.15607597, 1.0],
-    [0.6834634, 0.71128434, 0.16417207, 1.0],
-    [0.71128434, 0.6834634, 0.16417207, 1.0],
-    [0.69841045, 0.69841045, 0.15635195, 1.0],
-    [0.69670206, 0.69670206, 0.17091665, 1.0],
-    [0.68170303, 0.7094463, 0.17879303, 1.0],
-    [0.6797603, 0.70726085, 0.19418575, 1.0],
-    [0.7094463, 0.6817031, 0.17879306, 1.0],
-    [0.70726085, 0.6797603, 0.19418575, 1.0],
-    [0.6947466, 0.6947466, 0.1861568, 1.0],
-    [0.69257903, 0.69257903, 0.20166469, 1.0],
-    [0.69474447, 0.69474447, 0.18617243, 1.0],
-    [0.6670733, 0.7211102, 0.18711816, 1.0],
-    [0.6648028, 0.71899426, 0.20269322, 1.0],
-    [0.64998806, 0.72989804, 0.21157584, 1.0],
-    [0.678114, 0.7045065, 0.20936088, 1.0],
-    [0.6651003, 0.71876806, 0.20251952, 1.0],
-    [0.7211102, 0.6670733, 0.18711816, 1.0],
-    [0.71899426, 0.6648028, 0.20269322, 1.0],
-    [0.7045065, 0.678114, 0.20936088, 1.0],
-    [0.72989804, 0.64998806, 0.21157584, 1.0],
-    [0.71876806, 0.66510034, 0.20251957, 1.0],
-    [0.6947445, 0.6947445, 0.18617183, 1.0],
-    [0.6947445, 0.6947445, 0.18617183, 1.0],
-    [0.6900777, 0.6900777, 0.21814096, 1.0],
-    [0.6900777, 0.6900777, 0.21814096, 1.0],
-    [0.6621767, 0.71656513, 0.21921761, 1.0],
-    [0.65030265, 0.72519547, 0.2262699, 1.0],
-    [0.64157265, 0.7266207, 0.24577843, 1.0],
-    [0.67540383, 0.7020196, 0.22582808, 1.0],
-    [0.671816, 0.6983348, 0.24696508, 1.0],
-    [0.66089594, 0.7131017, 0.23388551, 1.0],
-    [0.6593576, 0.71004385, 0.24715437, 1.0],
-    [0.66035056, 0.71329206, 0.23484339, 1.0],
-    [0.64229435, 0.7204203, 0.26163435, 1.0],
-    [0.6700076, 0.6958314, 0.258667, 1.0],
-    [0.65532494, 0.70653665, 0.26712376, 1.0],
-    [0.6504475, 0.70202905, 0.28995392, 1.0],
-    [0.65464157, 0.7063764, 0.26921517, 1.0],
-    [0.71656513, 0.6621767, 0.21921761, 1.0],
-    [0.7020195, 0.6754037, 0.22582811, 1.0],
-    [0.6983348, 0.671816, 0.24696508, 1.0],
-    [0.72519547, 0.65030265, 0.2262699, 1.0],
-    [0.7266207, 0.64157265, 0.24577843, 1.0],
-    [0.7131017, 0.66089594, 0.23388551, 1.0],
-    [0.71004385, 0.6593576, 0.24715434, 1.0],
-    [0.71329206, 0.66035056, 0.23484339, 1.0],
-    [0.6958314, 0.6700076, 0.258667, 1.0],
-    [0.7204203, 0.64229435, 0.26163435, 1.0],
-    [0.70653665, 0.655325, 0.26712376, 1.0],
-    [0.70202905, 0.65044755, 0.28995392, 1.0],
-    [0.7063764, 0.65464157, 0.26921517, 1.0],
-    [0.6876808, 0.6876808, 0.23278777, 1.0],
-    [0.6852228, 0.6852228, 0.24685916, 1.0],
-    [0.6864737, 0.6864737, 0.2398073, 1.0],
-    [0.6817169, 0.6817169, 0.26556373, 1.0],
-    [0.676778, 0.67677796, 0.28972948, 1.0],
-    [0.66751003, 0.69280976, 0.27284607, 1.0],
-    [0.66441005, 0.6889374, 0.28969726, 1.0],
-    [0.69280976, 0.66751003, 0.27284607, 1.0],
-    [0.6889374, 0.66441005, 0.28969726, 1.0],
-    [0.678836, 0.678836, 0.27993456, 1.0],
-    [0.684867, 0.684867, 0.24882579, 1.0],
-    [0.6479223, 0.69819903, 0.30449098, 1.0],
-    [0.6479223, 0.69819903, 0.30449095, 1.0],
-    [0.69819903, 0.6479223, 0.30449098, 1.0],
-    [0.698199, 0.6479223, 0.30449095, 1.0],
-    [0.6740085, 0.6740085, 0.3023659, 1.0],
-    [0.6597491, 0.6844842, 0.31018138, 1.0],
-    [0.65438783, 0.67968994, 0.33135793, 1.0],
-    [0.6844842, 0.6597491, 0.31018138, 1.0],
-    [0.67969, 0.6543878, 0.33135793, 1.0],
-    [0.67083615, 0.67083615, 0.31616095, 1.0],
-    [0.6671929, 0.6671929, 0.3312211, 1.0],
-    [0.6701913, 0.6701913, 0.31888458, 1.0],
-    [0.6493663, 0.6741061, 0.3520004, 1.0],
-    [0.6438445, 0.6683322, 0.37255397, 1.0],
-    [0.6741061, 0.6493663, 0.3520004, 1.0],
-    [0.6683322, 0.6438445, 0.37255397, 1.0],
-    [0.6619147, 0.6619147, 0.35176364, 1.0],
-    [0.65624815, 0.65624815, 0.37239325, 1.0],
-    [0.6591097, 0.6591097, 0.36214483, 1.0],
-    [0.6694031, 0.6694031, 0.3221788, 1.0],
-    [0.63123983, 0.7334668, 0.25211617, 1.0],
-    [0.6312399, 0.7334669, 0.2521162, 1.0],
-    [0.6305705, 0.72796875, 0.26915118, 1.0],
-    [0.6206557, 0.7286373, 0.28961045, 1.0],
-    [0.6101763, 0.7347735, 0.2962985, 1.0],
-    [0.64052415, 0.71666694, 0.27589396, 1.0],
-    [0.6384807, 0.71278477, 0.29031026, 1.0],
-    [0.62815887, 0.7243506, 0.28413522, 1.0],
-    [0.6297813, 0.729052, 0.2680647, 1.0],
-    [0.61941564, 0.7205137, 0.31174392, 1.0],
-    [0.6170924, 0.71600664, 0.32639158, 1.0],
-    [0.60806084, 0.7299258, 0.3122025, 1.0],
-    [0.6063936, 0.721916, 0.33335274, 1.0],
-    [0.63663393, 0.70545834, 0.3114897, 1.0],
-    [0.6258146, 0.7055227, 0.33255637, 1.0],
-    [0.6144629, 0.7118523, 0.34014928, 1.0],
-    [0.6183606, 0.7160397, 0.3239094, 1.0],
-    [0.5983672, 0.718913, 0.3537241, 1.0],
-    [0.5923053, 0.71347463, 0.37433735, 1.0],
-    [0.62287056, 0.698314, 0.3526892, 1.0],
-    [0.61778456, 0.6921451, 0.37319902, 1.0],
-    [0.6115334, 0.7080698, 0.35307795, 1.0],
-    [0.6065042, 0.7018389, 0.37359703, 1.0],
-    [0.6083272, 0.70561594, 0.36337873, 1.0],
-    [0.645322, 0.6944321, 0.31831348, 1.0],
-    [0.64230996, 0.69098186, 0.3316354, 1.0],
-    [0.64383185, 0.69272983, 0.32497078, 1.0],
-    [0.63739765, 0.68524736, 0.35236394, 1.0],
-    [0.6321097, 0.67928284, 0.3728434, 1.0],
-    [0.6347911, 0.6823188, 0.36260352, 1.0],
-    [0.62657535, 0.69953734, 0.3435853, 1.0],
-    [0.7334668, 0.6312399, 0.25211617, 1.0],
-    [0.7334668, 0.6312399, 0.25211617, 1.0],
-    [0.72796875, 0.6305705, 0.26915118, 1.0],
-    [0.7286373, 0.6206557, 0.28961045, 1.0],
-    [0.7166669, 0.6405241, 0.27589396, 1.0],
-    [0.71278477, 0.6384807, 0.29031026, 1.0],
-    [0.7347735, 0.6101763, 0.2962985, 1.0],
-    [0.7243506, 0.6281588, 0.2841352, 1.0],
-    [0.729052, 0.62978125, 0.26806474, 1.0],
-    [0.7205137, 0.61941564, 0.31174392, 1.0],
-    [0.71600664, 0.6170924, 0.32639158, 1.0],
-    [0.70545834, 0.63663393, 0.3114897, 1.0],
-    [0.7055227, 0.6258146, 0.33255637, 1.0],
-    [0.7299258, 0.60806084, 0.3122025, 1.0],
-    [0.721916, 0.6063936, 0.33335274, 1.0],
-    [0.7118523, 0.6144629, 0.34014928, 1.0],
-    [0.7160398, 0.6183606, 0.3239095, 1.0],
-    [0.698314, 0.6228706, 0.3526892, 1.0],
-    [0.6921451, 0.61778456, 0.373199, 1.0],
-    [0.718913, 0.5983672, 0.3537241, 1.0],
-    [0.7134746, 0.59230536, 0.37433735, 1.0],
-    [0.7080698, 0.6115334, 0.35307795, 1.0],
-    [0.7018389, 0.6065042, 0.37359703, 1.0],
-    [0.70561594, 0.6083272, 0.36337873, 1.0],
-    [0.6944321, 0.645322, 0.31831348, 1.0],
-    [0.69098186, 0.64230996, 0.3316354, 1.0],
-    [0.69272983, 0.64383185, 0.32497078, 1.0],
-    [0.68524736, 0.63739765, 0.35236394, 1.0],
-    [0.67928284, 0.6321097, 0.3728434, 1.0],
-    [0.6823188, 0.6347911, 0.36260352, 1.0],
-    [0.69953734, 0.6265753, 0.34358528, 1.0],
-    [0.6750706, 0.6750706, 0.29758948, 1.0],
-    [0.69474685, 0.69474685, 0.18615472, 1.0],
-    [0.4167623, 0.4167623, 0.8078481, 1.0],
-    [0.41273656, 0.4379713, 0.7986424, 1.0],
-    [0.4379713, 0.41273656, 0.7986424, 1.0],
-    [0.43376398, 0.43376398, 0.7897453, 1.0],
-    [0.4252883, 0.4252883, 0.79891163, 1.0],
-    [0.40924826, 0.45900702, 0.78856105, 1.0],
-    [0.40611443, 0.47995123, 0.7776362, 1.0],
-    [0.43012905, 0.45483002, 0.77981967, 1.0],
-    [0.42686886, 0.47579187, 0.7690286, 1.0],
-    [0.41806865, 0.4674033, 0.77894336, 1.0],
-    [0.45900702, 0.40924826, 0.78856105, 1.0],
-    [0.45483002, 0.43012905, 0.77981967, 1.0],
-    [0.47995123, 0.4061144, 0.7776361, 1.0],
-    [0.47579187, 0.42686886, 0.7690286, 1.0],
-    [0.4674033, 0.41806865, 0.77894336, 1.0],
-    [0.45102105, 0.45102105, 0.7701688, 1.0],
-    [0.44757605, 0.47197062, 0.75955206, 1.0],
-    [0.47197062, 0.44757605, 0.75955206, 1.0],
-    [0.47601172, 0.47601172, 0.7394766, 1.0],
-    [0.46956316, 0.46956316, 0.74767697, 1.0],
-    [0.46325606, 0.46325606, 0.7555049, 1.0],
-    [0.44348902, 0.44348902, 0.7788677, 1.0],
-    [0.4031369, 0.50109744, 0.76575583, 1.0],
-    [0.39998534, 0.527512, 0.749495, 1.0],
-    [0.40117267, 0.5169958, 0.7561586, 1.0],
-    [0.42381352, 0.4967293, 0.75739163, 1.0],
-    [0.4207941, 0.5210015, 0.74262357, 1.0],
-    [0.42202356, 0.5105127, 0.74918157, 1.0],
-    [0.41179588, 0.51234406, 0.75360984, 1.0],
-    [0.3959482, 0.5725869, 0.7178922, 1.0],
-    [0.4165141, 0.56634265, 0.7111765, 1.0],
-    [0.40624025, 0.569481, 0.7146049, 1.0],
-    [0.39827532, 0.54346216, 0.7389355, 1.0],
-    [0.39671722, 0.56172824, 0.72600055, 1.0],
-    [0.4188202, 0.5388288, 0.7309263, 1.0],
-    [0.4173214, 0.5555765, 0.7191506, 1.0],
-    [0.40776742, 0.5499466, 0.7288927, 1.0],
-    [0.44958594, 0.49947742, 0.74053687, 1.0],
-    [0.44396898, 0.49350777, 0.74789137, 1.0],
-    [0.44155085, 0.51222646, 0.7366525, 1.0],
-    [0.43528, 0.5057599, 0.74480754, 1.0],
-    [0.46646085, 0.48939604, 0.7368214, 1.0],
-    [0.461504, 0.48403922, 0.7434514, 1.0],
-    [0.46239057, 0.50966793, 0.72555745, 1.0],
-    [0.45153922, 0.4991733, 0.73955274, 1.0],
-    [0.4371824, 0.55906314, 0.70449984, 1.0],
-    [0.46440008, 0.5377401, 0.7036818, 1.0],
-    [0.45715338, 0.5505984, 0.6984642, 1.0],
-    [0.45292193, 0.5491568, 0.702345, 1.0],
-    [0.4394838, 0.5345997, 0.7218429, 1.0],
-    [0.43811944, 0.5483495, 0.712295, 1.0],
-    [0.45901883, 0.5313982, 0.71198153, 1.0],
-    [0.45049527, 0.54366535, 0.70815384, 1.0],
-    [0.44678384, 0.53952533, 0.7136502, 1.0],
-    [0.4294316, 0.5368719, 0.72619355, 1.0],
-    [0.50109744, 0.4031369, 0.76575583, 1.0],
-    [0.4967293, 0.42381352, 0.75739163, 1.0],
-    [0.527512, 0.39998534, 0.749495, 1.0],
-    [0.5169958, 0.40117267, 0.7561586, 1.0],
-    [0.5210015, 0.4207941, 0.74262357, 1.0],
-    [0.5105127, 0.42202356, 0.74918157, 1.0],
-    [0.51234406, 0.41179588, 0.75360984, 1.0],
-    [0.49947742, 0.44958594, 0.74053687, 1.0],
-    [0.49350777, 0.44396895, 0.74789137, 1.0],
-    [0.48939604, 0.46646085, 0.7368214, 1.0],
-    [0.48403922, 0.461504, 0.7434514, 1.0],
-    [0.51222646, 0.44155085, 0.7366525, 1.0],
-    [0.5057599, 0.43528, 0.74480754, 1.0],
-    [0.50966793, 0.46239057, 0.7255574, 1.0],
-    [0.4991733, 0.45153922, 0.73955274, 1.0],
-    [0.5725869, 0.3959482, 0.7178922, 1.0],
-    [0.5663427, 0.41651413, 0.7111765, 1.0],
-    [0.569481, 0.40624022, 0.7146049, 1.0],
-    [0.54346216, 0.39827532, 0.7389355, 1.0],
-    [0.5388288, 0.4188202, 0.7309263, 1.0],
-    [0.56172824, 0.39671722, 0.72600055, 1.0],
-    [0.5555765, 0.41732138, 0.7191506, 1.0],
-    [0.5499466, 0.40776742, 0.7288927, 1.0],
-    [0.5377401, 0.46440008, 0.7036818, 1.0],
-    [0.55906314, 0.43718243, 0.70449984, 1.0],
-    [0.5505984, 0.45715338, 0.6984642, 1.0],
-    [0.5491568, 0.45292193, 0.702345, 1.0],
-    [0.5345997, 0.4394838, 0.7218429, 1.0],
-    [0.5313982, 0.45901883, 0.71198153, 1.0],
-    [0.5483495, 0.43811944, 0.712295, 1.0],
-    [0.54366535, 0.45049527, 0.70815384, 1.0],
-    [0.5395253, 0.4467838, 0.7136502, 1.0],
-    [0.5368719, 0.4294316, 0.72619355, 1.0],
-    [0.5290586, 0.5520072, 0.6445038, 1.0],
-    [0.5520072, 0.5290586, 0.6445038, 1.0],
-    [0.5427813, 0.5427813, 0.6409188, 1.0],
-    [0.5413156, 0.5413156, 0.6433933, 1.0],
-    [0.5413156, 0.5413156, 0.6433933, 1.0],
-    [0.49132425, 0.51480657, 0.7025487, 1.0],
-    [0.51480657, 0.49132425, 0.7025487, 1.0],
-    [0.5051528, 0.5051528, 0.6997436, 1.0],
-    [0.5037742, 0.5037742, 0.7017285, 1.0],
-    [0.48576906, 0.48576906, 0.72667515, 1.0],
-    [0.48437712, 0.5079599, 0.712289, 1.0],
-    [0.5079599, 0.48437712, 0.712289, 1.0],
-    [0.5001015, 0.5001015, 0.70696324, 1.0],
-    [0.49457747, 0.49457747, 0.71469307, 1.0],
-    [0.48212743, 0.5277053, 0.6993427, 1.0],
-    [0.4865058, 0.55686224, 0.67321354, 1.0],
-    [0.47957617, 0.54997027, 0.68376863, 1.0],
-    [0.5013923, 0.5245269, 0.6880969, 1.0],
-    [0.4987157, 0.5509422, 0.6691379, 1.0],
-    [0.49973148, 0.54026157, 0.67704195, 1.0],
-    [0.49136785, 0.54179186, 0.6819231, 1.0],
-    [0.476973, 0.521833, 0.707239, 1.0],
-    [0.476973, 0.52183306, 0.707239, 1.0],
-    [0.5277053, 0.48212746, 0.6993427, 1.0],
-    [0.52452695, 0.50139225, 0.6880969, 1.0],
-    [0.55686224, 0.4865058, 0.67321354, 1.0],
-    [0.54997027, 0.4795761, 0.68376863, 1.0],
-    [0.5509422, 0.4987157, 0.6691379, 1.0],
-    [0.54026157, 0.49973148, 0.67704195, 1.0],
-    [0.54179186, 0.4913679, 0.6819231, 1.0],
-    [0.521833, 0.476973, 0.707239, 1.0],
-    [0.52183306, 0.476973, 0.707239, 1.0],
-    [0.526097, 0.526097, 0.6681647, 1.0],
-    [0.52079475, 0.5207948, 0.67642117, 1.0],
-    [0.52027476, 0.5433253, 0.6588716, 1.0],
-    [0.511403, 0.53441536, 0.6729541, 1.0],
-    [0.54332525, 0.5202747, 0.6588716, 1.0],
-    [0.53441536, 0.511403, 0.6729541, 1.0],
-    [0.5374368, 0.5374368, 0.6498641, 1.0],
-    [0.5277323, 0.5277324, 0.6655803, 1.0],
-    [0.5084495, 0.5084495, 0.69495195, 1.0],
-    [0.49271125, 0.49271125, 0.7172665, 1.0],
-    [0.41676226, 0.80784804, 0.41676226, 1.0],
-    [0.41273656, 0.7986424, 0.4379713, 1.0],
-    [0.4379713, 0.7986424, 0.41273656, 1.0],
-    [0.43376398, 0.7897453, 0.43376398, 1.0],
-    [0.4252883, 0.79891163, 0.4252883, 1.0],
-    [0.40924826, 0.788561, 0.45900702, 1.0],
-    [0.40611443, 0.7776362, 0.47995123, 1.0],
-    [0.43012905, 0.77981967, 0.45483002, 1.0],
-    [0.42686886, 0.7690286, 0.47579187, 1.0],
-    [0.41806865, 0.77894336, 0.4674033, 1.0],
-    [0.45900702, 0.78856105, 0.40924826, 1.0],
-    [0.45483002, 0.77981967, 0.43012905, 1.0],
-    [0.47995126, 0.7776362, 0.40611443, 1.0],
-    [0.47579187, 0.7690286, 0.42686886, 1.0],
-    [0.4674033, 0.77894336, 0.41806865, 1.0],
-    [0.45102105, 0.7701688, 0.45102105, 1.0],
-    [0.44757605, 0.75955206, 0.47197062, 1.0],
-    [0.47601172, 0.73947656, 0.47601172, 1.0],
-    [0.47197062, 0.75955206, 0.44757605, 1.0],
-    [0.46956322, 0.747677, 0.46956322, 1.0],
-    [0.46325606, 0.7555049, 0.46325606, 1.0],
-    [0.44348902, 0.7788677, 0.44348902, 1.0],
-    [0.39594823, 0.7178923, 0.5725869, 1.0],
-    [0.41651413, 0.7111765, 0.5663427, 1.0],
-    [0.40624022, 0.7146049, 0.569481, 1.0],
-    [0.39998534, 0.749495, 0.527512, 1.0],
-    [0.4031369, 0.76575583, 0.50109744, 1.0],
-    [0.40117267, 0.7561586, 0.5169958, 1.0],
-    [0.4207941, 0.74262357, 0.5210015, 1.0],
-    [0.42381352, 0.75739163, 0.4967293, 1.0],
-    [0.42202353, 0.7491815, 0.51051265, 1.0],
-    [0.41179588, 0.75360984, 0.51234406, 1.0],
-    [0.39827532, 0.7389355, 0.54346216, 1.0],
-    [0.39671722, 0.7260006, 0.56172824, 1.0],
-    [0.4188202, 0.7309263, 0.5388288, 1.0],
-    [0.41732138, 0.7191506, 0.5555765, 1.0],
-    [0.40776742, 0.72889274, 0.5499466, 1.0],
-    [0.4371824, 0.70449984, 0.55906314, 1.0],
-    [0.4644993, 0.703637, 0.537713, 1.0],
-    [0.45715338, 0.6984642, 0.5505984, 1.0],
-    [0.4529551, 0.70233065, 0.54914784, 1.0],
-    [0.44958594, 0.74053687, 0.49947742, 1.0],
-    [0.44155085, 0.7366525, 0.51222646, 1.0],
-    [0.44396898, 0.74789137, 0.49350777, 1.0],
-    [0.43528, 0.74480754, 0.5057599, 1.0],
-    [0.46646085, 0.7368214, 0.48939604, 1.0],
-    [0.46239057, 0.72555745, 0.50966793, 1.0],
-    [0.46150407, 0.7434514, 0.48403922, 1.0],
-    [0.45153922, 0.73955274, 0.4991733, 1.0],
-    [0.4394838, 0.7218429, 0.5345997, 1.0],
-    [0.43811944, 0.712295, 0.5483495, 1.0],
-    [0.45907858, 0.711955, 0.5313821, 1.0],
-    [0.45049527, 0.70815384, 0.54366535, 1.0],
-    [0.44679886, 0.71364385, 0.53952134, 1.0],
-    [0.4294397, 0.72619027, 0.53686976, 1.0],
-    [0.52902967, 0.64445543, 0.55209124, 1.0],
-    [0.55209124, 0.64445543, 0.52902967, 1.0],
-    [0.5427813, 0.6409188, 0.5427813, 1.0],
-    [0.5413343, 0.6433618, 0.5413343, 1.0],
-    [0.5413342, 0.6433618, 0.5413342, 1.0],
-    [0.50109744, 0.76575583, 0.4031369, 1.0],
-    [0.4967293, 0.75739163, 0.42381352, 1.0],
-    [0.527512, 0.749495, 0.39998534, 1.0],
-    [0.5210015, 0.74262357, 0.4207941, 1.0],
-    [0.5169958, 0.7561586, 0.40117267, 1.0],
-    [0.5105127, 0.74918157, 0.42202356, 1.0],
-    [0.51234406, 0.75360984, 0.41179588, 1.0],
-    [0.4995741, 0.7404823, 0.4495684, 1.0],
-    [0.48939604, 0.7368214, 0.46646085, 1.0],
-    [0.49356622, 0.7478592, 0.44395837, 1.0],
-    [0.48403922, 0.7434514, 0.461504, 1.0],
-    [0.5122841, 0.7366185, 0.44154066, 1.0],
-    [0.50966793, 0.72555745, 0.46239057, 1.0],
-    [0.50585675, 0.74475175, 0.4352628, 1.0],
-    [0.49921754, 0.7395278, 0.4515312, 1.0],
-    [0.5725869, 0.7178922, 0.3959482, 1.0],
-    [0.5663427, 0.7111765, 0.41651413, 1.0],
-    [0.569481, 0.7146049, 0.40624022, 1.0],
-    [0.537713, 0.703637, 0.4644993, 1.0],
-    [0.55906314, 0.70449984, 0.4371824, 1.0],
-    [0.5505984, 0.6984642, 0.45715338, 1.0],
-    [0.54914784, 0.70233065, 0.4529551, 1.0],
-    [0.54346216, 0.7389355, 0.39827532, 1.0],
-    [0.5388288, 0.7309263, 0.4188202, 1.0],
-    [0.56172824, 0.72600055, 0.39671722, 1.0],
-    [0.5555765, 0.7191506, 0.4173214, 1.0],
-    [0.5499466, 0.7288927, 0.40776742, 1.0],
-    [0.5345997, 0.7218429, 0.4394838, 1.0],
-    [0.531382, 0.71195495, 0.4590786, 1.0],
-    [0.5483495, 0.712295, 0.43811944, 1.0],
-    [0.54366535, 0.70815384, 0.45049527, 1.0],
-    [0.53952134, 0.71364385, 0.44679886, 1.0],
-    [0.53687704, 0.7261857, 0.42943856, 1.0],
-    [0.49125054, 0.7026495, 0.5147395, 1.0],
-    [0.5148579, 0.7026459, 0.49113137, 1.0],
-    [0.5051682, 0.6997741, 0.50509536, 1.0],
-    [0.5037721, 0.70180595, 0.5036686, 1.0],
-    [0.48648402, 0.6731599, 0.5569463, 1.0],
-    [0.48214206, 0.69937503, 0.52764904, 1.0],
-    [0.47956294, 0.6837369, 0.5500212, 1.0],
-    [0.49878553, 0.6691034, 0.55092067, 1.0],
-    [0.5013923, 0.6880969, 0.5245269, 1.0],
-    [0.4998015, 0.6770074, 0.54024017, 1.0],
-    [0.49138793, 0.68190354, 0.5417983, 1.0],
-    [0.48576906, 0.72667515, 0.48576906, 1.0],
-    [0.48433244, 0.7123484, 0.50791913, 1.0],
-    [0.50799054, 0.7123464, 0.4842606, 1.0],
-    [0.50012696, 0.7070133, 0.5000053, 1.0],
-    [0.49458033, 0.71473515, 0.4945139, 1.0],
-    [0.4769975, 0.707292, 0.5217388, 1.0],
-    [0.47699744, 0.707292, 0.5217388, 1.0],
-    [0.5277044, 0.69934267, 0.4821285, 1.0],
-    [0.52452695, 0.6880969, 0.50139225, 1.0],
-    [0.5569462, 0.67315984, 0.48648396, 1.0],
-    [0.5509207, 0.66910344, 0.49878556, 1.0],
-    [0.5500212, 0.6837369, 0.479563, 1.0],
-    [0.54024017, 0.6770074, 0.4998015, 1.0],
-    [0.5418074, 0.681898, 0.49138567, 1.0],
-    [0.5260714, 0.66811216, 0.52618915, 1.0],
-    [0.52027637, 0.6588218, 0.5433842, 1.0],
-    [0.5208333, 0.67636186, 0.52083325, 1.0],
-    [0.5115252, 0.6728568, 0.5344208, 1.0],
-    [0.5433259, 0.65882313, 0.52033544, 1.0],
-    [0.5373487, 0.64991295, 0.53746593, 1.0],
-    [0.5344208, 0.6728568, 0.5115252, 1.0],
-    [0.52773976, 0.6655286, 0.5277902, 1.0],
-    [0.52183175, 0.7072388, 0.47697484, 1.0],
-    [0.5218317, 0.7072388, 0.47697484, 1.0],
-    [0.5084593, 0.6949632, 0.50842416, 1.0],
-    [0.49271998, 0.7172607, 0.49271095, 1.0],
-    [0.3929658, 0.63797677, 0.6622413, 1.0],
-    [0.3929658, 0.6622413, 0.63797677, 1.0],
-    [0.3929655, 0.6502223, 0.6502223, 1.0],
-    [0.41345602, 0.63169205, 0.6557585, 1.0],
-    [0.41345602, 0.6557585, 0.63169205, 1.0],
-    [0.41353038, 0.64381385, 0.64381385, 1.0],
-    [0.40318972, 0.647085, 0.647085, 1.0],
-    [0.4610113, 0.60349184, 0.65058905, 1.0],
-    [0.4610113, 0.60349184, 0.65058905, 1.0],
-    [0.4610113, 0.65058905, 0.60349184, 1.0],
-    [0.4610113, 0.65058905, 0.60349184, 1.0],
-    [0.43390173, 0.6248792, 0.6490418, 1.0],
-    [0.43390173, 0.6490418, 0.6248792, 1.0],
-    [0.43394727, 0.63705957, 0.63705957, 1.0],
-    [0.46253416, 0.6269219, 0.6269219, 1.0],
-    [0.45487365, 0.6179597, 0.6412611, 1.0],
-    [0.45487365, 0.64126104, 0.6179597, 1.0],
-    [0.4488796, 0.63186514, 0.63186514, 1.0],
-    [0.44613543, 0.63283604, 0.63283604, 1.0],
-    [0.44287035, 0.63398176, 0.6339818, 1.0],
-    [0.39499784, 0.5868054, 0.70684946, 1.0],
-    [0.39364836, 0.61213094, 0.685811, 1.0],
-    [0.394214, 0.6008991, 0.6953528, 1.0],
-    [0.41544154, 0.5819071, 0.6991369, 1.0],
-    [0.41411343, 0.6058866, 0.67927283, 1.0],
-    [0.41469094, 0.59477973, 0.6886715, 1.0],
-    [0.4044889, 0.5971625, 0.69266564, 1.0],
-    [0.39311337, 0.6263844, 0.67313033, 1.0],
-    [0.41353673, 0.62011474, 0.66666704, 1.0],
-    [0.4033333, 0.62328625, 0.66995263, 1.0],
-    [0.44128463, 0.585717, 0.6798555, 1.0],
-    [0.43532473, 0.5789638, 0.68941516, 1.0],
-    [0.43421733, 0.59768677, 0.6739628, 1.0],
-    [0.42757174, 0.59055895, 0.684414, 1.0],
-    [0.45581284, 0.58024055, 0.6749486, 1.0],
-    [0.45656255, 0.56944835, 0.6835782, 1.0],
-    [0.45468122, 0.59662575, 0.6612888, 1.0],
-    [0.44364125, 0.5856872, 0.6783458, 1.0],
-    [0.43399966, 0.61337686, 0.65985835, 1.0],
-    [0.4471987, 0.60873324, 0.65532976, 1.0],
-    [0.44060567, 0.6110702, 0.6576168, 1.0],
-    [0.42301345, 0.60452473, 0.67498845, 1.0],
-    [0.3936484, 0.685811, 0.61213094, 1.0],
-    [0.39499784, 0.70684946, 0.5868054, 1.0],
-    [0.394214, 0.6953528, 0.6008991, 1.0],
-    [0.41411343, 0.67927283, 0.6058865, 1.0],
-    [0.41544154, 0.6991369, 0.5819071, 1.0],
-    [0.41469097, 0.6886715, 0.5947798, 1.0],
-    [0.40448892, 0.69266564, 0.5971625, 1.0],
-    [0.3931134, 0.67313033, 0.62638444, 1.0],
-    [0.41353673, 0.66666704, 0.62011474, 1.0],
-    [0.4033333, 0.66995263, 0.62328625, 1.0],
-    [0.4413074, 0.6798663, 0.58568734, 1.0],
-    [0.43423095, 0.6739697, 0.597669, 1.0],
-    [0.43533847, 0.6894216, 0.5789459, 1.0],
-    [0.42759457, 0.6844254, 0.5905293, 1.0],
-    [0.4558872, 0.674918, 0.58021766, 1.0],
-    [0.45468122, 0.6612887, 0.59662575, 1.0],
-    [0.45663702, 0.68354744, 0.5694256, 1.0],
-    [0.44367293, 0.6783423, 0.58566713, 1.0],
-    [0.43399966, 0.6598584, 0.61337686, 1.0],
-    [0.4471987, 0.65532976, 0.60873324, 1.0],
-    [0.44060567, 0.6576168, 0.6110702, 1.0],
-    [0.42302138, 0.67498785, 0.60451984, 1.0],
-    [0.47540504, 0.59792817, 0.6453464, 1.0],
-    [0.50413287, 0.57476926, 0.64458543, 1.0],
-    [0.5058758, 0.5983724, 0.6213212, 1.0],
-    [0.49798012, 0.589773, 0.63575435, 1.0],
-    [0.49589437, 0.5903116, 0.6368838, 1.0],
-    [0.47540504, 0.6453464, 0.59792817, 1.0],
-    [0.5058754, 0.6214362, 0.5982535, 1.0],
-    [0.50419813, 0.64460087, 0.57469475, 1.0],
-    [0.49797976, 0.63581073, 0.58971244, 1.0],
-    [0.49591053, 0.6369304, 0.59024775, 1.0],
-    [0.4750251, 0.6222343, 0.62223434, 1.0],
-    [0.4751358, 0.61081445, 0.6333654, 1.0],
-    [0.4751358, 0.63336533, 0.61081445, 1.0],
-    [0.4989056, 0.61287713, 0.61275995, 1.0],
-    [0.49188215, 0.6041534, 0.62693757, 1.0],
-    [0.49188167, 0.62705284, 0.60403407, 1.0],
-    [0.48468682, 0.61852694, 0.6184682, 1.0],
-    [0.545765, 0.5925701, 0.5924536, 1.0],
-    [0.5457649, 0.5925701, 0.5924536, 1.0],
-    [0.5201195, 0.56525683, 0.6402816, 1.0],
-    [0.5173325, 0.59330624, 0.6167292, 1.0],
-    [0.5175449, 0.58224356, 0.62700856, 1.0],
-    [0.54532915, 0.5688734, 0.615629, 1.0],
-    [0.5390384, 0.56231254, 0.62709033, 1.0],
-    [0.5382749, 0.58430004, 0.60733324, 1.0],
-    [0.5307506, 0.5760397, 0.62167686, 1.0],
-    [0.5298311, 0.5761378, 0.6223699, 1.0],
-    [0.5173758, 0.61674845, 0.59324837, 1.0],
-    [0.5201407, 0.64026004, 0.5652616, 1.0],
-    [0.5175882, 0.62702715, 0.58218503, 1.0],
-    [0.5453469, 0.6156064, 0.5688809, 1.0],
-    [0.53828734, 0.60737926, 0.5842408, 1.0],
-    [0.53904915, 0.627077, 0.56231713, 1.0],
-    [0.5307767, 0.62165433, 0.5760399, 1.0],
-    [0.52985597, 0.62237, 0.5761148, 1.0],
-    [0.5173276, 0.6051331, 0.6051331, 1.0],
-    [0.5313055, 0.59910524, 0.59898853, 1.0],
-    [0.52433187, 0.6021405, 0.6020821, 1.0],
-    [0.51538247, 0.6059887, 0.60593605, 1.0],
-    [0.4780128, 0.56951535, 0.66869724, 1.0],
-    [0.4724367, 0.56299305, 0.6781168, 1.0],
-    [0.47578278, 0.5868567, 0.65515643, 1.0],
-    [0.49764153, 0.56793207, 0.655596, 1.0],
-    [0.49008247, 0.58092487, 0.64988106, 1.0],
-    [0.48281792, 0.5737294, 0.66160506, 1.0],
-    [0.51471436, 0.5591917, 0.6499029, 1.0],
-    [0.51471436, 0.5591917, 0.6499029, 1.0],
-    [0.49883047, 0.56652457, 0.6559101, 1.0],
-    [0.4781167, 0.6686132, 0.569527, 1.0],
-    [0.47585502, 0.65512574, 0.58683234, 1.0],
-    [0.47251356, 0.67801934, 0.56304604, 1.0],
-    [0.4977381, 0.65557903, 0.56786704, 1.0],
-    [0.4901782, 0.6498387, 0.58089155, 1.0],
-    [0.48290706, 0.6615505, 0.5737175, 1.0],
-    [0.5147501, 0.64986724, 0.55920017, 1.0],
-    [0.5147501, 0.6498673, 0.55920017, 1.0],
-    [0.49889266, 0.6558644, 0.5665226, 1.0],
-    [0.46721923, 0.62518376, 0.6251812, 1.0],
-    [0.64445543, 0.52902967, 0.55209124, 1.0],
-    [0.64445543, 0.55209124, 0.52902967, 1.0],
-    [0.6409188, 0.5427813, 0.5427813, 1.0],
-    [0.6433618, 0.5413342, 0.5413342, 1.0],
-    [0.6433618, 0.5413342, 0.5413342, 1.0],
-    [0.80784804, 0.41676226, 0.41676226, 1.0],
-    [0.7986424, 0.41273656, 0.4379713, 1.0],
-    [0.7986424, 0.4379713, 0.41273656, 1.0],
-    [0.7897453, 0.43376398, 0.43376398, 1.0],
-    [0.79891163, 0.4252883, 0.4252883, 1.0],
-    [0.788561, 0.40924826, 0.45900702, 1.0],
-    [0.7776361, 0.4061144, 0.47995123, 1.0],
-    [0.77981967, 0.43012905, 0.45483002, 1.0],
-    [0.7690286, 0.42686886, 0.47579187, 1.0],
-    [0.77894336, 0.41806865, 0.4674033, 1.0],
-    [0.788561, 0.45900702, 0.40924826, 1.0],
-    [0.77981967, 0.45483002, 0.43012905, 1.0],
-    [0.7776361, 0.47995123, 0.4061144, 1.0],
-    [0.7690286, 0.47579187, 0.42686886, 1.0],
-    [0.77894336, 0.4674033, 0.41806865, 1.0],
-    [0.7394766, 0.47601172, 0.47601172, 1.0],
-    [0.7701687, 0.45102105, 0.45102105, 1.0],
-    [0.75955206, 0.44757605, 0.47197062, 1.0],
-    [0.75955206, 0.47197062, 0.44757605, 1.0],
-    [0.747677, 0.46956322, 0.46956322, 1.0],
-    [0.7555049, 0.46325606, 0.46325606, 1.0],
-    [0.7788677, 0.44348902, 0.44348902, 1.0],
-    [0.7178922, 0.3959482, 0.5725869, 1.0],
-    [0.7111765, 0.41651413, 0.5663427, 1.0],
-    [0.7146049, 0.40624022, 0.569481, 1.0],
-    [0.70449984, 0.43718243, 0.55906314, 1.0],
-    [0.70363706, 0.4644993, 0.537713, 1.0],
-    [0.6984642, 0.45715338, 0.5505984, 1.0],
-    [0.70233065, 0.4529551, 0.54914784, 1.0],
-    [0.749495, 0.39998534, 0.527512, 1.0],
-    [0.7426236, 0.42079413, 0.5210015, 1.0],
-    [0.76575583, 0.4031369, 0.50109744, 1.0],
-    [0.75615865, 0.4011727, 0.5169958, 1.0],
-    [0.75739163, 0.42381352, 0.4967293, 1.0],
-    [0.7491815, 0.42202353, 0.51051265, 1.0],
-    [0.75360984, 0.41179588, 0.51234406, 1.0],
-    [0.7389355, 0.39827532, 0.54346216, 1.0],
-    [0.72600055, 0.39671722, 0.56172824, 1.0],
-    [0.7309263, 0.4188202, 0.5388288, 1.0],
-    [0.7191506, 0.4173214, 0.5555765, 1.0],
-    [0.7288927, 0.40776742, 0.5499466, 1.0],
-    [0.74053687, 0.44958594, 0.49947742, 1.0],
-    [0.7366525, 0.44155085, 0.51222646, 1.0],
-    [0.7368214, 0.46646085, 0.48939604, 1.0],
-    [0.72555745, 0.46239057, 0.50966793, 1.0],
-    [0.74789137, 0.44396895, 0.49350777, 1.0],
-    [0.74480754, 0.43528, 0.5057599, 1.0],
-    [0.7434514, 0.46150407, 0.48403922, 1.0],
-    [0.73955274, 0.45153922, 0.4991733, 1.0],
-    [0.7218429, 0.4394838, 0.5345997, 1.0],
-    [0.712295, 0.43811944, 0.5483495, 1.0],
-    [0.71195495, 0.4590786, 0.5313821, 1.0],
-    [0.70815384, 0.45049527, 0.54366535, 1.0],
-    [0.71364385, 0.44679886, 0.53952134, 1.0],
-    [0.7261902, 0.4294397, 0.53686976, 1.0],
-    [0.7178922, 0.5725869, 0.3959482, 1.0],
-    [0.7111765, 0.5663427, 0.41651413, 1.0],
-    [0.71460485, 0.569481, 0.40624022, 1.0],
-    [0.70363706, 0.537713, 0.4644993, 1.0],
-    [0.70449984, 0.55906314, 0.4371824, 1.0],
-    [0.6984642, 0.5505984, 0.45715338, 1.0],
-    [0.7023306, 0.5491478, 0.45295513, 1.0],
-    [0.749495, 0.527512, 0.39998534, 1.0],
-    [0.74262357, 0.5210015, 0.4207941, 1.0],
-    [0.76575583, 0.50109744, 0.4031369, 1.0],
-    [0.75739163, 0.4967293, 0.42381352, 1.0],
-    [0.7561586, 0.5169958, 0.40117267, 1.0],
-    [0.74918157, 0.5105127, 0.42202356, 1.0],
-    [0.75360984, 0.51234406, 0.41179588, 1.0],
-    [0.7404823, 0.4995741, 0.4495684, 1.0],
-    [0.7368214, 0.48939604, 0.46646085, 1.0],
-    [0.7366185, 0.5122841, 0.44154066, 1.0],
-    [0.72555745, 0.50966793, 0.46239057, 1.0],
-    [0.7478592, 0.49356622, 0.44395837, 1.0],
-    [0.7434514, 0.48403922, 0.461504, 1.0],
-    [0.74475175, 0.50585675, 0.4352628, 1.0],
-    [0.7395279, 0.49921754, 0.4515312, 1.0],
-    [0.7389355, 0.54346216, 0.39827532, 1.0],
-    [0.7309263, 0.5388288, 0.4188202, 1.0],
-    [0.72600055, 0.56172824, 0.39671722, 1.0],
-    [0.71915066, 0.5555765, 0.4173214, 1.0],
-    [0.7288927, 0.5499466, 0.40776742, 1.0],
-    [0.7218429, 0.5345997, 0.4394838, 1.0],
-    [0.71195495, 0.531382, 0.4590786, 1.0],
-    [0.712295, 0.5483495, 0.43811944, 1.0],
-    [0.70815384, 0.54366535, 0.45049527, 1.0],
-    [0.71364385, 0.53952134, 0.44679886, 1.0],
-    [0.7261857, 0.53687704, 0.42943856, 1.0],
-    [0.7026495, 0.49125054, 0.5147395, 1.0],
-    [0.7026459, 0.5148579, 0.49113137, 1.0],
-    [0.6997741, 0.5051682, 0.50509536, 1.0],
-    [0.70180595, 0.5037721, 0.5036686, 1.0],
-    [0.6731599, 0.48648402, 0.5569463, 1.0],
-    [0.6691034, 0.49878553, 0.55092067, 1.0],
-    [0.69937503, 0.48214206, 0.52764904, 1.0],
-    [0.6837369, 0.479563, 0.5500212, 1.0],
-    [0.6880969, 0.5013923, 0.5245269, 1.0],
-    [0.6770074, 0.4998015, 0.54024017, 1.0],
-    [0.6819036, 0.49138793, 0.5417983, 1.0],
-    [0.67315984, 0.5569462, 0.48648396, 1.0],
-    [0.66910344, 0.5509207, 0.49878556, 1.0],
-    [0.69934267, 0.5277045, 0.4821285, 1.0],
-    [0.6880969, 0.5245269, 0.5013923, 1.0],
-    [0.6837369, 0.5500212, 0.479563, 1.0],
-    [0.6770074, 0.54024017, 0.4998015, 1.0],
-    [0.681898, 0.5418074, 0.4913857, 1.0],
-    [0.6681122, 0.5260714, 0.52618915, 1.0],
-    [0.6588218, 0.52027637, 0.5433842, 1.0],
-    [0.65882313, 0.5433259, 0.52033544, 1.0],
-    [0.64991295, 0.5373487, 0.53746593, 1.0],
-    [0.6763619, 0.5208333, 0.5208333, 1.0],
-    [0.6728568, 0.5115252, 0.5344208, 1.0],
-    [0.6728568, 0.5344208, 0.5115252, 1.0],
-    [0.66552854, 0.52773976, 0.5277902, 1.0],
-    [0.72667515, 0.48576906, 0.48576906, 1.0],
-    [0.7123484, 0.48433244, 0.5079191, 1.0],
-    [0.7123463, 0.50799054, 0.48426056, 1.0],
-    [0.7070133, 0.50012696, 0.5000053, 1.0],
-    [0.71473515, 0.49458033, 0.4945139, 1.0],
-    [0.707292, 0.4769975, 0.5217388, 1.0],
-    [0.707292, 0.47699744, 0.5217388, 1.0],
-    [0.7072388, 0.52183175, 0.47697484, 1.0],
-    [0.7072388, 0.5218317, 0.47697484, 1.0],
-    [0.6949632, 0.5084593, 0.50842416, 1.0],
-    [0.71726066, 0.49271995, 0.49271095, 1.0],
-    [0.60349184, 0.4610113, 0.65058905, 1.0],
-    [0.60349184, 0.4610113, 0.65058905, 1.0],
-    [0.63797677, 0.3929658, 0.6622413, 1.0],
-    [0.6316921, 0.41345602, 0.6557585, 1.0],
-    [0.6622413, 0.3929658, 0.63797677, 1.0],
-    [0.6502223, 0.3929655, 0.6502223, 1.0],
-    [0.6557585, 0.41345602, 0.63169205, 1.0],
-    [0.64381385, 0.41353038, 0.64381385, 1.0],
-    [0.647085, 0.40318972, 0.647085, 1.0],
-    [0.65058905, 0.4610113, 0.60349184, 1.0],
-    [0.65058905, 0.4610113, 0.60349184, 1.0],
-    [0.6248792, 0.43390173, 0.6490418, 1.0],
-    [0.6269219, 0.46253416, 0.6269219, 1.0],
-    [0.6179597, 0.45487365, 0.6412611, 1.0],
-    [0.6490418, 0.43390173, 0.6248792, 1.0],
-    [0.63705957, 0.43394727, 0.63705957, 1.0],
-    [0.64126104, 0.45487365, 0.6179597, 1.0],
-    [0.63186514, 0.4488796, 0.63186514, 1.0],
-    [0.6328361, 0.4461355, 0.6328361, 1.0],
-    [0.6339818, 0.4428704, 0.63398176, 1.0],
-    [0.58680534, 0.39499784, 0.70684946, 1.0],
-    [0.5819071, 0.41544154, 0.6991369, 1.0],
-    [0.61213094, 0.39364836, 0.685811, 1.0],
-    [0.6008991, 0.394214, 0.6953528, 1.0],
-    [0.6058865, 0.41411343, 0.67927283, 1.0],
-    [0.5947798, 0.41469097, 0.6886715, 1.0],
-    [0.5971625, 0.4044889, 0.69266564, 1.0],
-    [0.585717, 0.44128463, 0.6798555, 1.0],
-    [0.5789638, 0.43532473, 0.68941516, 1.0],
-    [0.58024055, 0.45581284, 0.6749486, 1.0],
-    [0.56944835, 0.45656255, 0.6835782, 1.0],
-    [0.59768677, 0.43421733, 0.6739628, 1.0],
-    [0.59055895, 0.42757174, 0.684414, 1.0],
-    [0.59662575, 0.45468122, 0.6612887, 1.0],
-    [0.5856872, 0.44364125, 0.6783458, 1.0],
-    [0.6263844, 0.39311337, 0.67313033, 1.0],
-    [0.62011474, 0.41353673, 0.66666704, 1.0],
-    [0.62328625, 0.4033333, 0.66995263, 1.0],
-    [0.61337686, 0.43399966, 0.6598584, 1.0],
-    [0.60873324, 0.4471987, 0.65532976, 1.0],
-    [0.6110702, 0.44060567, 0.6576168, 1.0],
-    [0.60452473, 0.42301345, 0.67498845, 1.0],
-    [0.57476926, 0.50413287, 0.64458543, 1.0],
-    [0.59792817, 0.47540504, 0.6453464, 1.0],
-    [0.5983724, 0.5058758, 0.6213212, 1.0],
-    [0.589773, 0.49798012, 0.63575435, 1.0],
-    [0.5903116, 0.49589437, 0.6368838, 1.0],
-    [0.5925701, 0.545765, 0.5924536, 1.0],
-    [0.5925701, 0.5457649, 0.5924536, 1.0],
-    [0.5652568, 0.5201194, 0.6402816, 1.0],
-    [0.5688734, 0.54532915, 0.615629, 1.0],
-    [0.56231254, 0.5390384, 0.62709033, 1.0],
-    [0.59330624, 0.5173325, 0.6167292, 1.0],
-    [0.58224356, 0.5175449, 0.62700856, 1.0],
-    [0.5843, 0.5382749, 0.60733324, 1.0],
-    [0.5760397, 0.5307506, 0.62167686, 1.0],
-    [0.5761378, 0.5298311, 0.6223699, 1.0],
-    [0.62143606, 0.5058754, 0.5982534, 1.0],
-    [0.6453464, 0.47540504, 0.59792817, 1.0],
-    [0.64460087, 0.50419813, 0.57469475, 1.0],
-    [0.63581073, 0.49797976, 0.58971244, 1.0],
-    [0.6369304, 0.49591058, 0.59024775, 1.0],
-    [0.6222343, 0.4750251, 0.62223434, 1.0],
-    [0.61081445, 0.4751358, 0.6333654, 1.0],
-    [0.6128772, 0.49890563, 0.61276, 1.0],
-    [0.6041534, 0.49188215, 0.62693757, 1.0],
-    [0.6333654, 0.4751358, 0.61081445, 1.0],
-    [0.62705284, 0.49188167, 0.60403407, 1.0],
-    [0.61852705, 0.48468682, 0.6184682, 1.0],
-    [0.61674845, 0.5173758, 0.59324837, 1.0],
-    [0.6156064, 0.545347, 0.5688809, 1.0],
-    [0.60737926, 0.53828734, 0.5842408, 1.0],
-    [0.64026004, 0.5201407, 0.5652616, 1.0],
-    [0.62702715, 0.5175882, 0.58218503, 1.0],
-    [0.627077, 0.53904915, 0.56231713, 1.0],
-    [0.62165433, 0.5307767, 0.5760399, 1.0],
-    [0.62237, 0.5298559, 0.5761148, 1.0],
-    [0.6051331, 0.5173276, 0.6051331, 1.0],
-    [0.59910524, 0.5313055, 0.59898853, 1.0],
-    [0.6021405, 0.52433187, 0.6020821, 1.0],
-    [0.6059887, 0.51538247, 0.60593605, 1.0],
-    [0.56951535, 0.4780128, 0.66869724, 1.0],
-    [0.56299305, 0.4724367, 0.6781168, 1.0],
-    [0.56793207, 0.4976415, 0.655596, 1.0],
-    [0.5868567, 0.47578272, 0.6551565, 1.0],
-    [0.58092487, 0.49008247, 0.64988106, 1.0],
-    [0.5737294, 0.48281792, 0.66160506, 1.0],
-    [0.5591917, 0.51471436, 0.6499029, 1.0],
-    [0.5591917, 0.51471436, 0.6499029, 1.0],
-    [0.56652457, 0.49883047, 0.6559101, 1.0],
-    [0.685811, 0.3936484, 0.61213094, 1.0],
-    [0.67927283, 0.41411343, 0.6058865, 1.0],
-    [0.70684946, 0.39499784, 0.58680534, 1.0],
-    [0.6953528, 0.394214, 0.6008991, 1.0],
-    [0.6991369, 0.41544154, 0.5819071, 1.0],
-    [0.6886715, 0.41469094, 0.59477973, 1.0],
-    [0.6926657, 0.40448892, 0.5971625, 1.0],
-    [0.67313033, 0.39311337, 0.6263844, 1.0],
-    [0.66666704, 0.41353673, 0.62011474, 1.0],
-    [0.66995263, 0.4033333, 0.62328625, 1.0],
-    [0.6798663, 0.4413074, 0.58568734, 1.0],
-    [0.6739697, 0.43423098, 0.59766906, 1.0],
-    [0.674918, 0.4558872, 0.58021766, 1.0],
-    [0.66128874, 0.45468122, 0.59662575, 1.0],
-    [0.6894216, 0.43533847, 0.5789459, 1.0],
-    [0.6844254, 0.42759457, 0.5905293, 1.0],
-    [0.6835475, 0.45663702, 0.5694256, 1.0],
-    [0.67834234, 0.44367296, 0.58566713, 1.0],
-    [0.6598584, 0.43399966, 0.61337686, 1.0],
-    [0.65532976, 0.4471987, 0.60873324, 1.0],
-    [0.6576168, 0.44060567, 0.6110702, 1.0],
-    [0.67498785, 0.42302138, 0.60451984, 1.0],
-    [0.6686132, 0.4781167, 0.569527, 1.0],
-    [0.65512574, 0.47585502, 0.58683234, 1.0],
-    [0.65557903, 0.49773806, 0.56786704, 1.0],
-    [0.6498387, 0.4901782, 0.58089155, 1.0],
-    [0.67801934, 0.47251356, 0.56304604, 1.0],
-    [0.6615505, 0.48290706, 0.5737175, 1.0],
-    [0.64986724, 0.5147501, 0.55920017, 1.0],
-    [0.6498673, 0.5147501, 0.55920017, 1.0],
-    [0.6558644, 0.49889266, 0.5665226, 1.0],
-    [0.6251838, 0.46721923, 0.6251812, 1.0],
-    [0.603602, 0.6504396, 0.46107802, 1.0],
-    [0.603602, 0.6504396, 0.461078, 1.0],
-    [0.6504396, 0.603602, 0.461078, 1.0],
-    [0.65043956, 0.603602, 0.461078, 1.0],
-    [0.63797677, 0.6622413, 0.3929658, 1.0],
-    [0.63169205, 0.6557585, 0.41345602, 1.0],
-    [0.6622413, 0.63797677, 0.3929658, 1.0],
-    [0.6557585, 0.6316921, 0.41345602, 1.0],
-    [0.6502223, 0.6502223, 0.3929655, 1.0],
-    [0.64381385, 0.64381385, 0.41353038, 1.0],
-    [0.647085, 0.647085, 0.40318972, 1.0],
-    [0.6269219, 0.6269219, 0.46253416, 1.0],
-    [0.6248546, 0.6490149, 0.43397757, 1.0],
-    [0.61799645, 0.64116615, 0.4549575, 1.0],
-    [0.6490149, 0.6248546, 0.43397757, 1.0],
-    [0.64116615, 0.61799645, 0.4549575, 1.0],
-    [0.63705957, 0.63705957, 0.43394727, 1.0],
-    [0.6318651, 0.6318651, 0.44887957, 1.0],
-    [0.63282, 0.63282, 0.44618118, 1.0],
-    [0.6339658, 0.6339658, 0.44291618, 1.0],
-    [0.59255624, 0.59255624, 0.54566854, 1.0],
-    [0.59255624, 0.59255624, 0.54566854, 1.0],
-    [0.5746591, 0.6445582, 0.50429314, 1.0],
-    [0.5983262, 0.62138724, 0.5058496, 1.0],
-    [0.5979019, 0.6453165, 0.47547865, 1.0],
-    [0.58971125, 0.6357955, 0.49800074, 1.0],
-    [0.5902502, 0.63689625, 0.4959514, 1.0],
-    [0.5688809, 0.6156064, 0.5453469, 1.0],
-    [0.5652657, 0.6401984, 0.5202122, 1.0],
-    [0.56231713, 0.627077, 0.53904915, 1.0],
-    [0.5932798, 0.61669856, 0.5173994, 1.0],
-    [0.5843129, 0.607339, 0.53825444, 1.0],
-    [0.58219093, 0.62694526, 0.5176808, 1.0],
-    [0.5760823, 0.6215884, 0.53080803, 1.0],
-    [0.576137, 0.622327, 0.5298824, 1.0],
-    [0.62138724, 0.5983262, 0.5058496, 1.0],
-    [0.6445582, 0.5746591, 0.50429314, 1.0],
-    [0.6453165, 0.5979019, 0.47547865, 1.0],
-    [0.6357955, 0.58971125, 0.49800074, 1.0],
-    [0.63689625, 0.5902502, 0.4959514, 1.0],
-    [0.6156064, 0.5688809, 0.5453469, 1.0],
-    [0.61669856, 0.5932798, 0.5173994, 1.0],
-    [0.607339, 0.5843129, 0.5382544, 1.0],
-    [0.6401984, 0.5652657, 0.5202122, 1.0],
-    [0.627077, 0.56231713, 0.53904915, 1.0],
-    [0.62694526, 0.58219093, 0.5176808, 1.0],
-    [0.6215884, 0.5760823, 0.53080803, 1.0],
-    [0.622327, 0.576137, 0.5298824, 1.0],
-    [0.62223434, 0.6222343, 0.4750251, 1.0],
-    [0.61282927, 0.61282927, 0.49887943, 1.0],
-    [0.6107879, 0.63333577, 0.47520944, 1.0],
-    [0.60410666, 0.6270033, 0.49185568, 1.0],
-    [0.63333577, 0.6107878, 0.47520936, 1.0],
-    [0.6270033, 0.60410666, 0.49185568, 1.0],
-    [0.61849326, 0.61849326, 0.48469812, 1.0],
-    [0.6051331, 0.6051331, 0.5173276, 1.0],
-    [0.5990901, 0.5990901, 0.53120816, 1.0],
-    [0.6021326, 0.6021326, 0.524283, 1.0],
-    [0.60596, 0.60596, 0.5153881, 1.0],
-    [0.58680534, 0.70684946, 0.39499784, 1.0],
-    [0.5819071, 0.69913685, 0.4154415, 1.0],
-    [0.61213094, 0.685811, 0.3936484, 1.0],
-    [0.6058865, 0.67927283, 0.41411343, 1.0],
-    [0.6008991, 0.6953528, 0.394214, 1.0],
-    [0.59477973, 0.6886715, 0.41469094, 1.0],
-    [0.5971625, 0.69266564, 0.4044889, 1.0],
-    [0.58584327, 0.67974865, 0.44128153, 1.0],
-    [0.58021766, 0.674918, 0.4558872, 1.0],
-    [0.5790406, 0.6893521, 0.43532267, 1.0],
-    [0.5694256, 0.6835475, 0.45663702, 1.0],
-    [0.59774244, 0.6738751, 0.43427667, 1.0],
-    [0.5966931, 0.66119975, 0.45472214, 1.0],
-    [0.5906856, 0.68430644, 0.42756882, 1.0],
-    [0.58574563, 0.67827284, 0.44367567, 1.0],
-    [0.62638444, 0.67313033, 0.3931134, 1.0],
-    [0.62011474, 0.66666704, 0.41353673, 1.0],
-    [0.62328625, 0.66995263, 0.4033333, 1.0],
-    [0.61332816, 0.65980387, 0.43415135, 1.0],
-    [0.60881066, 0.65514225, 0.4473681, 1.0],
-    [0.61108476, 0.6574957, 0.4407663, 1.0],
-    [0.6045439, 0.67494047, 0.42306256, 1.0],
-    [0.5695269, 0.66861314, 0.47811663, 1.0],
-    [0.56786597, 0.6555789, 0.49773946, 1.0],
-    [0.563046, 0.6780193, 0.47251353, 1.0],
-    [0.58683234, 0.65512574, 0.47585502, 1.0],
-    [0.580848, 0.64989674, 0.49015287, 1.0],
-    [0.5737084, 0.6615619, 0.48290223, 1.0],
-    [0.5592421, 0.64980936, 0.51477766, 1.0],
-    [0.5592421, 0.64980936, 0.51477766, 1.0],
-    [0.5665393, 0.6558413, 0.49890432, 1.0],
-    [0.685811, 0.61213094, 0.39364836, 1.0],
-    [0.67927283, 0.6058865, 0.41411343, 1.0],
-    [0.70684946, 0.5868054, 0.39499784, 1.0],
-    [0.6991369, 0.5819071, 0.41544154, 1.0],
-    [0.6953528, 0.6008991, 0.394214, 1.0],
-    [0.6886715, 0.5947798, 0.41469097, 1.0],
-    [0.69266564, 0.5971625, 0.40448892, 1.0],
-    [0.67974865, 0.58584327, 0.44128153, 1.0],
-    [0.674918, 0.58021766, 0.4558872, 1.0],
-    [0.6738751, 0.59774244, 0.43427667, 1.0],
-    [0.66119975, 0.5966931, 0.45472214, 1.0],
-    [0.6893521, 0.5790406, 0.43532267, 1.0],
-    [0.6835475, 0.5694256, 0.45663702, 1.0],
-    [0.68430644, 0.5906856, 0.42756882, 1.0],
-    [0.67827284, 0.58574563, 0.44367567, 1.0],
-    [0.67313033, 0.62638444, 0.3931134, 1.0],
-    [0.66666704, 0.62011474, 0.41353673, 1.0],
-    [0.66995263, 0.62328625, 0.4033333, 1.0],
-    [0.65980387, 0.61332816, 0.43415135, 1.0],
-    [0.65514225, 0.60881066, 0.4473681, 1.0],
-    [0.6574957, 0.61108476, 0.4407663, 1.0],
-    [0.67494047, 0.6045439, 0.42306256, 1.0],
-    [0.66861314, 0.5695269, 0.47811663, 1.0],
-    [0.6555789, 0.56786597, 0.49773946, 1.0],
-    [0.65512574, 0.58683234, 0.47585502, 1.0],
-    [0.64989674, 0.580848, 0.49015287, 1.0],
-    [0.6780193, 0.563046, 0.47251353, 1.0],
-    [0.6615619, 0.5737084, 0.4829022, 1.0],
-    [0.64980936, 0.5592421, 0.51477766, 1.0],
-    [0.64980936, 0.5592421, 0.51477766, 1.0],
-    [0.6558413, 0.5665393, 0.49890432, 1.0],
-    [0.6251682, 0.6251682, 0.46725732, 1.0],
-    [0.5696055, 0.5696055, 0.5925361, 1.0],
-    [0.5695667, 0.5926093, 0.5695684, 1.0],
-    [0.5620017, 0.5848735, 0.58487356, 1.0],
-    [0.5926093, 0.5695667, 0.5695684, 1.0],
-    [0.5848735, 0.56200176, 0.5848735, 1.0],
-    [0.5848912, 0.5848912, 0.5619649, 1.0],
-    [0.57735026, 0.57735026, 0.57735026, 1.0],
-    [0.577358, 0.577358, 0.5773347, 1.0],
-    [0.5617164, 0.5617164, 0.6074121, 1.0],
-    [0.5537886, 0.5537886, 0.62180084, 1.0],
-    [0.55456007, 0.57713395, 0.5994827, 1.0],
-    [0.57713395, 0.55456007, 0.5994827, 1.0],
-    [0.5618664, 0.5618664, 0.6071345, 1.0],
-    [0.5616845, 0.60747033, 0.5616853, 1.0],
-    [0.55456007, 0.5994827, 0.57713395, 1.0],
-    [0.55376416, 0.62184435, 0.55376416, 1.0],
-    [0.5770946, 0.5995554, 0.5545224, 1.0],
-    [0.5618424, 0.60717815, 0.5618431, 1.0],
-    [0.60747033, 0.56168455, 0.5616853, 1.0],
-    [0.5994827, 0.55456007, 0.57713395, 1.0],
-    [0.5995554, 0.5770946, 0.5545224, 1.0],
-    [0.62184435, 0.55376416, 0.55376416, 1.0],
-    [0.60717815, 0.5618425, 0.56184316, 1.0],
-    [0.57735777, 0.57735777, 0.5773353, 1.0],
-    [0.57735777, 0.57735777, 0.5773353, 1.0],
-    [0.5773493, 0.57734925, 0.57735234, 1.0],
-];
-
-#[allow(dead_code)]
-pub const EXPECTED_INDICES: &[u32] = &[
-    36, 39, 54, 36, 54, 47, 67, 78, 85, 67, 85, 70, 
-    89, 96, 109, 89, 109, 104, 89, 117, 122, 89, 122, 96, 
-    89, 104, 124, 89, 124, 117, 33, 104, 109, 36, 133, 140, 
-    36, 140, 39, 36, 47, 142, 36, 142, 133, 64, 122, 117, 
-    67, 70, 140, 67, 140, 133, 67, 133, 144, 67, 144, 78, 
-    96, 133, 142, 96, 142, 109, 96, 122, 144, 96, 144, 133, 
-    64, 78, 144, 64, 144, 122, 33, 109, 142, 33, 142, 47, 
-    158, 166, 176, 158, 176, 169, 189, 197, 209, 189, 209, 204, 
-    189, 217, 219, 189, 219, 197, 189, 204, 224, 189, 224, 217, 
-    228, 231, 246, 228, 246, 239, 155, 209, 197, 158, 255, 257, 
-    158, 257, 166, 158, 169, 264, 158, 264, 255, 204, 209, 257, 
-    204, 257, 255, 204, 255, 266, 204, 266, 224, 186, 217, 224, 
-    228, 255, 264, 228, 264, 231, 228, 239, 266, 228, 266, 255, 
-    186, 224, 266, 186, 266, 239, 155, 166, 257, 155, 257, 209, 
-    285, 292, 303, 285, 303, 299, 272, 299, 303, 272, 303, 277, 
-    270, 277, 303, 270, 303, 292, 309, 314, 329, 309, 329, 322, 
-    309, 333, 336, 309, 336, 314, 342, 350, 362, 342, 362, 355, 
-    342, 355, 369, 342, 369, 366, 376, 381, 391, 376, 391, 386, 
-    272, 277, 350, 272, 350, 342, 272, 342, 366, 272, 366, 299, 
-    322, 329, 381, 322, 381, 376, 270, 309, 322, 270, 322, 277, 
-    270, 292, 333, 270, 333, 309, 350, 376, 386, 350, 386, 362, 
-    277, 322, 376, 277, 376, 350, 419, 427, 437, 419, 437, 435, 
-    419, 444, 449, 419, 449, 427, 419, 435, 454, 419, 454, 444, 
-    458, 469, 476, 458, 476, 466, 480, 488, 498, 480, 498, 491, 
-    444, 507, 509, 444, 509, 449, 444, 454, 511, 444, 511, 507, 
-    407, 427, 449, 458, 466, 509, 458, 509, 507, 458, 507, 518, 
-    458, 518, 469, 416, 454, 435, 480, 507, 511, 480, 511, 488, 
-    480, 491, 518, 480, 518, 507, 416, 488, 511, 416, 511, 454, 
-    407, 449, 509, 407, 509, 466, 539, 544, 555, 539, 555, 551, 
-    530, 551, 555, 530, 555, 537, 522, 537, 555, 522, 555, 544, 
-    561, 566, 573, 561, 573, 570, 561, 581, 588, 561, 588, 566, 
-    599, 616, 623, 599, 623, 607, 599, 611, 626, 599, 626, 616, 
-    633, 643, 648, 633, 648, 638, 539, 599, 607, 539, 607, 544, 
-    539, 551, 611, 539, 611, 599, 581, 633, 638, 581, 638, 588, 
-    522, 561, 570, 522, 570, 537, 522, 544, 581, 522, 581, 561, 
-    607, 623, 643, 607, 643, 633, 544, 607, 633, 544, 633, 581, 
-    669, 676, 685, 669, 685, 681, 660, 681, 685, 660, 685, 667, 
-    658, 667, 685, 658, 685, 676, 696, 700, 708, 696, 708, 705, 
-    696, 705, 723, 696, 723, 716, 729, 746, 749, 729, 749, 733, 
-    729, 741, 756, 729, 756, 746, 763, 768, 778, 763, 778, 773, 
-    669, 729, 733, 669, 733, 676, 669, 681, 741, 669, 741, 729, 
-    716, 723, 768, 716, 768, 763, 660, 667, 700, 660, 700, 696, 
-    660, 696, 716, 660, 716, 681, 741, 763, 773, 741, 773, 756, 
-    681, 716, 763, 681, 763, 741, 14, 435, 437, 14, 437, 22, 
-    9, 22, 437, 9, 437, 427, 64, 530, 537, 78, 570, 573, 
-    78, 573, 85, 64, 537, 570, 64, 570, 78, 64, 117, 594, 
-    64, 594, 530, 186, 667, 658, 239, 246, 708, 239, 708, 700, 
-    186, 658, 691, 186, 691, 217, 186, 239, 700, 186, 700, 667, 
-    14, 22, 219, 14, 219, 217, 4, 197, 219, 4, 219, 22, 
-    33, 292, 285, 47, 54, 336, 47, 336, 333, 33, 47, 333, 
-    33, 333, 292, 33, 285, 397, 33, 397, 104, 416, 658, 676, 
-    488, 733, 749, 488, 749, 498, 416, 435, 691, 416, 691, 658, 
-    416, 676, 733, 416, 733, 488, 9, 117, 124, 9, 124, 22, 
-    4, 22, 124, 4, 124, 104, 155, 285, 299, 166, 366, 369, 
-    166, 369, 176, 155, 299, 366, 155, 366, 166, 155, 197, 397, 
-    155, 397, 285, 407, 551, 530, 466, 476, 626, 466, 626, 611, 
-    407, 530, 594, 407, 594, 427, 407, 466, 611, 407, 611, 551, 
-    4, 104, 397, 4, 397, 197, 9, 427, 594, 9, 594, 117, 
-    14, 217, 691, 14, 691, 435, 784, 787, 802, 784, 802, 794, 
-    815, 825, 833, 815, 833, 818, 870, 873, 886, 870, 886, 878, 
-    870, 891, 899, 870, 899, 873, 873, 901, 886, 873, 899, 901, 
-    784, 850, 858, 784, 858, 787, 787, 860, 802, 787, 858, 860, 
-    812, 878, 886, 815, 818, 858, 815, 858, 850, 818, 862, 858, 
-    818, 833, 862, 843, 899, 891, 858, 870, 878, 858, 878, 860, 
-    858, 862, 891, 858, 891, 870, 833, 843, 891, 833, 891, 862, 
-    802, 860, 878, 802, 878, 812, 933, 938, 953, 933, 953, 945, 
-    933, 956, 960, 933, 960, 938, 977, 985, 996, 977, 996, 989, 
-    968, 989, 996, 968, 996, 970, 963, 989, 968, 963, 977, 989, 
-    1002, 1008, 1018, 1002, 1018, 1013, 1027, 1032, 1044, 1027, 1044, 1039, 
-    1032, 1051, 1044, 1032, 1048, 1051, 945, 953, 1008, 945, 1008, 1002, 
-    968, 970, 1032, 968, 1032, 1027, 970, 1048, 1032, 970, 996, 1048, 
-    938, 963, 968, 938, 968, 953, 938, 960, 977, 938, 977, 963, 
-    1008, 1027, 1039, 1008, 1039, 1018, 953, 968, 1027, 953, 1027, 1008, 
-    1075, 1078, 1088, 1075, 1088, 1085, 1097, 1100, 1112, 1097, 1112, 1105, 
-    1097, 1114, 1122, 1097, 1122, 1100, 1100, 1127, 1112, 1100, 1122, 1127, 
-    1160, 1178, 1163, 1160, 1171, 1178, 1067, 1097, 1105, 1075, 1139, 1147, 
-    1075, 1147, 1078, 1078, 1154, 1088, 1078, 1147, 1154, 1105, 1112, 1147, 
-    1105, 1147, 1139, 1112, 1156, 1147, 1112, 1127, 1156, 1122, 1137, 1127, 
-    1147, 1160, 1163, 1147, 1163, 1154, 1147, 1156, 1171, 1147, 1171, 1160, 
-    1127, 1137, 1171, 1127, 1171, 1156, 1067, 1139, 1075, 1067, 1105, 1139, 
-    1185, 1190, 1197, 1185, 1197, 1193, 1185, 1204, 1212, 1185, 1212, 1190, 
-    1235, 1237, 1248, 1235, 1248, 1241, 1222, 1241, 1248, 1222, 1248, 1230, 
-    1215, 1241, 1222, 1215, 1235, 1241, 1259, 1270, 1275, 1259, 1275, 1265, 
-    1284, 1300, 1305, 1284, 1305, 1289, 1289, 1308, 1293, 1289, 1305, 1308, 
-    1204, 1259, 1265, 1204, 1265, 1212, 1235, 1284, 1289, 1235, 1289, 1237, 
-    1237, 1293, 1248, 1237, 1289, 1293, 1190, 1215, 1222, 1190, 1222, 1197, 
-    1190, 1212, 1235, 1190, 1235, 1215, 1265, 1275, 1300, 1265, 1300, 1284, 
-    1212, 1265, 1284, 1212, 1284, 1235, 1327, 1330, 1340, 1327, 1340, 1332, 
-    1327, 1345, 1352, 1327, 1352, 1330, 1330, 1357, 1340, 1330, 1352, 1357, 
-    1375, 1385, 1388, 1375, 1388, 1378, 1412, 1430, 1420, 1412, 1423, 1430, 
-    1345, 1391, 1399, 1345, 1399, 1352, 1352, 1401, 1357, 1352, 1399, 1401, 
-    1319, 1345, 1327, 1375, 1378, 1399, 1375, 1399, 1391, 1378, 1408, 1399, 
-    1378, 1388, 1408, 1340, 1357, 1367, 1399, 1412, 1420, 1399, 1420, 1401, 
-    1399, 1408, 1423, 1399, 1423, 1412, 1357, 1401, 1420, 1357, 1420, 1367, 
-    1319, 1391, 1345, 1319, 1375, 1391, 1461, 1463, 1472, 1461, 1472, 1467, 
-    1452, 1467, 1472, 1452, 1472, 1454, 1445, 1467, 1452, 1445, 1461, 1467, 
-    1477, 1482, 1490, 1477, 1490, 1485, 1482, 1505, 1490, 1482, 1498, 1505, 
-    1510, 1526, 1531, 1510, 1531, 1515, 1515, 1538, 1523, 1515, 1531, 1538, 
-    1545, 1560, 1550, 1545, 1555, 1560, 1461, 1510, 1515, 1461, 1515, 1463, 
-    1463, 1523, 1472, 1463, 1515, 1523, 1498, 1550, 1505, 1498, 1545, 1550, 
-    1452, 1454, 1482, 1452, 1482, 1477, 1454, 1498, 1482, 1454, 1472, 1498, 
-    1523, 1555, 1545, 1523, 1538, 1555, 1472, 1545, 1498, 1472, 1523, 1545, 
-    825, 1193, 1197, 825, 1197, 833, 843, 1222, 1230, 833, 1197, 1222, 
-    833, 1222, 843, 843, 1254, 899, 843, 1230, 1254, 921, 1332, 1340, 
-    921, 1340, 926, 913, 1332, 921, 913, 1327, 1332, 1137, 1445, 1452, 
-    1171, 1485, 1178, 1171, 1477, 1485, 1122, 1437, 1445, 1122, 1445, 1137, 
-    1137, 1477, 1171, 1137, 1452, 1477, 794, 802, 960, 794, 960, 956, 
-    812, 985, 977, 802, 812, 977, 802, 977, 960, 812, 1057, 985, 
-    812, 886, 1057, 921, 926, 1122, 921, 1122, 1114, 908, 1114, 1097, 
-    908, 921, 1114, 1367, 1461, 1445, 1420, 1526, 1510, 1420, 1430, 1526, 
-    1340, 1367, 1445, 1340, 1445, 1437, 1367, 1510, 1461, 1367, 1420, 1510, 
-    899, 913, 921, 899, 921, 901, 886, 901, 921, 886, 921, 908, 
-    985, 1067, 996, 1048, 1075, 1085, 1048, 1085, 1051, 996, 1067, 1075, 
-    996, 1075, 1048, 985, 1057, 1097, 985, 1097, 1067, 1230, 1248, 1319, 
-    1293, 1308, 1385, 1293, 1385, 1375, 1230, 1319, 1327, 1230, 1327, 1254, 
-    1248, 1293, 1375, 1248, 1375, 1319, 886, 908, 1097, 886, 1097, 1057, 
-    899, 1254, 1327, 899, 1327, 913, 926, 1437, 1122, 926, 1340, 1437, 
-    1566, 1573, 1584, 1566, 1584, 1576, 1597, 1600, 1615, 1597, 1615, 1607, 
-    1652, 1657, 1668, 1652, 1668, 1660, 1660, 1683, 1681, 1660, 1668, 1683, 
-    1652, 1660, 1681, 1652, 1681, 1673, 1576, 1633, 1631, 1576, 1584, 1633, 
-    1566, 1576, 1631, 1566, 1631, 1623, 1594, 1668, 1657, 1631, 1633, 1657, 
-    1631, 1657, 1652, 1631, 1652, 1673, 1631, 1673, 1635, 1597, 1623, 1631, 
-    1597, 1631, 1600, 1600, 1635, 1615, 1600, 1631, 1635, 1645, 1673, 1681, 
-    1615, 1635, 1673, 1615, 1673, 1645, 1584, 1594, 1657, 1584, 1657, 1633, 
-    1690, 1697, 1710, 1690, 1710, 1702, 1690, 1702, 1717, 1690, 1717, 1713, 
-    1723, 1728, 1739, 1723, 1739, 1734, 1756, 1760, 1775, 1756, 1775, 1768, 
-    1742, 1760, 1756, 1742, 1747, 1760, 1747, 1749, 1775, 1747, 1775, 1760, 
-    1784, 1791, 1801, 1784, 1801, 1796, 1796, 1808, 1805, 1796, 1801, 1808, 
-    1702, 1710, 1747, 1702, 1747, 1742, 1702, 1742, 1756, 1702, 1756, 1717, 
-    1734, 1739, 1791, 1734, 1791, 1784, 1697, 1723, 1734, 1697, 1734, 1710, 
-    1747, 1784, 1796, 1747, 1796, 1749, 1749, 1805, 1775, 1749, 1796, 1805, 
-    1710, 1734, 1784, 1710, 1784, 1747, 1857, 1864, 1870, 1857, 1870, 1867, 
-    1879, 1884, 1894, 1879, 1894, 1887, 1887, 1909, 1904, 1887, 1894, 1909, 
-    1879, 1887, 1904, 1879, 1904, 1896, 1942, 1960, 1953, 1942, 1945, 1960, 
-    1849, 1884, 1879, 1867, 1927, 1920, 1867, 1870, 1927, 1857, 1867, 1920, 
-    1857, 1920, 1912, 1904, 1909, 1939, 1920, 1927, 1945, 1920, 1945, 1942, 
-    1920, 1942, 1953, 1920, 1953, 1929, 1884, 1912, 1920, 1884, 1920, 1894, 
-    1894, 1929, 1909, 1894, 1920, 1929, 1909, 1929, 1953, 1909, 1953, 1939, 
-    1849, 1912, 1884, 1849, 1857, 1912, 1967, 1970, 1979, 1967, 1979, 1975, 
-    1967, 1975, 1994, 1967, 1994, 1986, 2017, 2021, 2030, 2017, 2030, 2023, 
-    1997, 2021, 2017, 1997, 2004, 2021, 2004, 2012, 2030, 2004, 2030, 2021, 
-    2041, 2047, 2057, 2041, 2057, 2052, 2071, 2090, 2087, 2071, 2075, 2090, 
-    2066, 2071, 2087, 2066, 2087, 2082, 1986, 1994, 2047, 1986, 2047, 2041, 
-    2023, 2075, 2071, 2023, 2030, 2075, 2017, 2023, 2071, 2017, 2071, 2066, 
-    1975, 1979, 2004, 1975, 2004, 1997, 1975, 1997, 2017, 1975, 2017, 1994, 
-    2047, 2066, 2082, 2047, 2082, 2057, 1994, 2017, 2066, 1994, 2066, 2047, 
-    2109, 2111, 2122, 2109, 2122, 2114, 2114, 2139, 2134, 2114, 2122, 2139, 
-    2109, 2114, 2134, 2109, 2134, 2127, 2157, 2160, 2170, 2157, 2170, 2167, 
-    2194, 2212, 2205, 2194, 2202, 2212, 2134, 2183, 2181, 2134, 2139, 2183, 
-    2127, 2134, 2181, 2127, 2181, 2173, 2122, 2149, 2139, 2181, 2183, 2202, 
-    2181, 2202, 2194, 2181, 2194, 2205, 2181, 2205, 2190, 2101, 2109, 2127, 
-    2157, 2173, 2181, 2157, 2181, 2160, 2160, 2190, 2170, 2160, 2181, 2190, 
-    2101, 2173, 2157, 2101, 2127, 2173, 2139, 2149, 2202, 2139, 2202, 2183, 
-    2243, 2247, 2254, 2243, 2254, 2249, 2227, 2247, 2243, 2227, 2234, 2247, 
-    2234, 2236, 2254, 2234, 2254, 2247, 2259, 2262, 2272, 2259, 2272, 2267, 
-    2267, 2287, 2280, 2267, 2272, 2287, 2297, 2320, 2313, 2297, 2305, 2320, 
-    2292, 2297, 2313, 2292, 2313, 2308, 2327, 2342, 2337, 2327, 2332, 2342, 
-    2249, 2305, 2297, 2249, 2254, 2305, 2243, 2249, 2297, 2243, 2297, 2292, 
-    2280, 2332, 2327, 2280, 2287, 2332, 2234, 2259, 2267, 2234, 2267, 2236, 
-    2236, 2280, 2254, 2236, 2267, 2280, 2305, 2337, 2320, 2305, 2327, 2337, 
-    2254, 2327, 2305, 2254, 2280, 2327, 1607, 1615, 1979, 1607, 1979, 1970, 
-    1645, 2012, 2004, 1645, 2036, 2012, 1645, 1681, 2036, 1615, 1645, 2004, 
-    1615, 2004, 1979, 1825, 2111, 2109, 1825, 1833, 2111, 1833, 1838, 2122, 
-    1833, 2122, 2111, 1939, 2234, 2227, 1953, 2262, 2259, 1953, 1960, 2262, 
-    1939, 2259, 2234, 1939, 1953, 2259, 1904, 1939, 2227, 1904, 2227, 2219, 
-    1681, 1683, 1833, 1681, 1833, 1825, 1668, 1820, 1833, 1668, 1833, 1683, 
-    1768, 1775, 1849, 1805, 1808, 1864, 1805, 1864, 1857, 1775, 1805, 1857, 
-    1775, 1857, 1849, 1768, 1849, 1879, 1768, 1879, 1814, 2012, 2101, 2030, 
-    2075, 2157, 2167, 2075, 2167, 2090, 2012, 2036, 2109, 2012, 2109, 2101, 
-    2030, 2101, 2157, 2030, 2157, 2075, 1573, 1713, 1717, 1573, 1717, 1584, 
-    1594, 1756, 1768, 1584, 1717, 1756, 1584, 1756, 1594, 1594, 1814, 1668, 
-    1594, 1768, 1814, 1833, 1896, 1904, 1833, 1904, 1838, 1820, 1896, 1833, 
-    1820, 1879, 1896, 2149, 2227, 2243, 2202, 2308, 2212, 2202, 2292, 2308, 
-    2122, 2219, 2227, 2122, 2227, 2149, 2149, 2292, 2202, 2149, 2243, 2292, 
-    1668, 1814, 1879, 1668, 1879, 1820, 1838, 2219, 2122, 1838, 1904, 2219, 
-    1681, 1825, 2109, 1681, 2109, 2036, 2350, 2355, 2366, 2350, 2366, 2360, 
-    2374, 2379, 2392, 2374, 2392, 2387, 2379, 2399, 2392, 2379, 2395, 2399, 
-    2407, 2415, 2425, 2407, 2425, 2420, 2420, 2432, 2428, 2420, 2425, 2432, 
-    2446, 2453, 2468, 2446, 2468, 2460, 2438, 2453, 2446, 2438, 2440, 2453, 
-    2438, 2460, 2442, 2438, 2446, 2460, 2360, 2366, 2415, 2360, 2415, 2407, 
-    2387, 2392, 2440, 2387, 2440, 2438, 2392, 2453, 2440, 2392, 2399, 2453, 
-    2355, 2374, 2387, 2355, 2387, 2366, 2415, 2438, 2442, 2415, 2442, 2425, 
-    2425, 2460, 2432, 2425, 2442, 2460, 2366, 2387, 2438, 2366, 2438, 2415, 
-    2482, 2485, 2496, 2482, 2496, 2493, 2529, 2547, 2532, 2529, 2539, 2547, 
-    2553, 2560, 2571, 2553, 2571, 2568, 2568, 2586, 2578, 2568, 2571, 2586, 
-    2560, 2586, 2571, 2560, 2576, 2586, 2493, 2523, 2515, 2493, 2496, 2523, 
-    2485, 2523, 2496, 2485, 2513, 2523, 2506, 2553, 2568, 2515, 2523, 2560, 
-    2515, 2560, 2553, 2523, 2576, 2560, 2523, 2525, 2576, 2513, 2529, 2532, 
-    2513, 2532, 2523, 2523, 2547, 2525, 2523, 2532, 2547, 2576, 2596, 2586, 
-    2525, 2547, 2596, 2525, 2596, 2576, 2493, 2553, 2506, 2493, 2515, 2553, 
-    2604, 2612, 2618, 2604, 2618, 2615, 2653, 2661, 2671, 2653, 2671, 2668, 
-    2668, 2686, 2678, 2668, 2671, 2686, 2661, 2686, 2671, 2661, 2673, 2686, 
-    2690, 2708, 2700, 2690, 2693, 2708, 2615, 2645, 2637, 2615, 2618, 2645, 
-    2612, 2645, 2618, 2612, 2630, 2645, 2628, 2661, 2653, 2637, 2645, 2693, 
-    2637, 2693, 2690, 2645, 2708, 2693, 2645, 2647, 2708, 2678, 2686, 2718, 
-    2630, 2653, 2668, 2630, 2668, 2645, 2645, 2678, 2647, 2645, 2668, 2678, 
-    2647, 2678, 2718, 2647, 2718, 2708, 2612, 2653, 2630, 2612, 2628, 2653, 
-    2747, 2752, 2761, 2747, 2761, 2756, 2752, 2776, 2761, 2752, 2768, 2776, 
-    2803, 2808, 2817, 2803, 2817, 2815, 2789, 2808, 2803, 2789, 2791, 2808, 
-    2789, 2815, 2799, 2789, 2803, 2815, 2823, 2839, 2829, 2823, 2834, 2839, 
-    2852, 2872, 2867, 2852, 2857, 2872, 2848, 2872, 2857, 2848, 2864, 2872, 
-    2768, 2829, 2776, 2768, 2823, 2829, 2815, 2857, 2852, 2815, 2817, 2857, 
-    2808, 2857, 2817, 2808, 2848, 2857, 2756, 2761, 2791, 2756, 2791, 2789, 
-    2761, 2808, 2791, 2761, 2776, 2808, 2829, 2864, 2848, 2829, 2839, 2864, 
-    2776, 2848, 2808, 2776, 2829, 2848, 2882, 2886, 2896, 2882, 2896, 2891, 
-    2891, 2911, 2903, 2891, 2896, 2911, 2933, 2940, 2947, 2933, 2947, 2945, 
-    2919, 2940, 2933, 2919, 2927, 2940, 2919, 2945, 2929, 2919, 2933, 2945, 
-    2953, 2969, 2964, 2953, 2959, 2969, 2982, 3002, 2997, 2982, 2987, 3002, 
-    2974, 3002, 2987, 2974, 2990, 3002, 2903, 2959, 2953, 2903, 2911, 2959, 
-    2945, 2987, 2982, 2945, 2947, 2987, 2940, 2987, 2947, 2940, 2974, 2987, 
-    2886, 2919, 2929, 2886, 2929, 2896, 2896, 2945, 2911, 2896, 2929, 2945, 
-    2959, 2997, 2969, 2959, 2982, 2997, 2911, 2982, 2959, 2911, 2945, 2982, 
-    3006, 3014, 3025, 3006, 3025, 3022, 3022, 3042, 3035, 3022, 3025, 3042, 
-    3014, 3042, 3025, 3014, 3030, 3042, 3089, 3102, 3092, 3089, 3099, 3102, 
-    3111, 3124, 3121, 3111, 3114, 3124, 3035, 3073, 3065, 3035, 3042, 3073, 
-    3030, 3073, 3042, 3030, 3063, 3073, 3022, 3035, 3061, 3065, 3073, 3114, 
-    3065, 3114, 3111, 3073, 3124, 3114, 3073, 3080, 3124, 3014, 3052, 3030, 
-    3063, 3089, 3092, 3063, 3092, 3073, 3073, 3102, 3080, 3073, 3092, 3102, 
-    3030, 3089, 3063, 3030, 3052, 3089, 3035, 3111, 3061, 3035, 3065, 3111, 
-    2539, 2756, 2547, 2539, 2747, 2756, 2596, 2789, 2799, 2586, 2799, 2782, 
-    2586, 2596, 2799, 2547, 2789, 2596, 2547, 2756, 2789, 2700, 2886, 2882, 
-    2700, 2708, 2886, 2718, 2927, 2919, 2708, 2919, 2886, 2708, 2718, 2919, 
-    2686, 2927, 2718, 2686, 2879, 2927, 2732, 3014, 3006, 2732, 2737, 3014, 
-    2732, 3022, 2742, 2732, 3006, 3022, 2428, 2432, 2612, 2428, 2612, 2604, 
-    2460, 2468, 2628, 2432, 2460, 2628, 2432, 2628, 2612, 2468, 2661, 2628, 
-    2468, 2474, 2661, 2578, 2586, 2737, 2578, 2737, 2732, 2568, 2732, 2724, 
-    2568, 2578, 2732, 2799, 2815, 3052, 2852, 3099, 3089, 2852, 2867, 3099, 
-    2782, 2799, 3052, 2782, 3052, 3014, 2815, 3089, 3052, 2815, 2852, 3089, 
-    2395, 2482, 2493, 2395, 2493, 2399, 2453, 2506, 2468, 2399, 2493, 2506, 
-    2399, 2506, 2453, 2468, 2568, 2474, 2468, 2506, 2568, 2673, 2732, 2742, 
-    2673, 2742, 2686, 2661, 2732, 2673, 2661, 2724, 2732, 2927, 3061, 2940, 
-    2974, 3121, 2990, 2974, 3111, 3121, 2879, 3022, 3061, 2879, 3061, 2927, 
-    2940, 3111, 2974, 2940, 3061, 3111, 2474, 2568, 2724, 2474, 2724, 2661, 
-    2686, 3022, 2879, 2686, 2742, 3022, 2586, 3014, 2737, 2586, 2782, 3014, 
-    3130, 3140, 3148, 3130, 3148, 3137, 3152, 3159, 3170, 3152, 3170, 3162, 
-    3229, 3247, 3237, 3229, 3245, 3247, 3216, 3229, 3237, 3216, 3237, 3221, 
-    3216, 3226, 3245, 3216, 3245, 3229, 3186, 3216, 3221, 3186, 3221, 3188, 
-    3186, 3190, 3226, 3186, 3226, 3216, 3140, 3188, 3148, 3140, 3186, 3188, 
-    3130, 3178, 3186, 3130, 3186, 3140, 3200, 3221, 3237, 3162, 3190, 3186, 
-    3162, 3170, 3190, 3152, 3162, 3186, 3152, 3186, 3178, 3209, 3245, 3226, 
-    3170, 3209, 3226, 3170, 3226, 3190, 3148, 3188, 3221, 3148, 3221, 3200, 
-    3254, 3269, 3277, 3254, 3277, 3261, 3254, 3264, 3281, 3254, 3281, 3269, 
-    3287, 3298, 3303, 3287, 3303, 3292, 3306, 3322, 3311, 3306, 3318, 3322, 
-    3318, 3332, 3339, 3318, 3339, 3322, 3311, 3322, 3339, 3311, 3339, 3324, 
-    3360, 3372, 3365, 3360, 3369, 3372, 3348, 3360, 3365, 3348, 3365, 3355, 
-    3269, 3306, 3311, 3269, 3311, 3277, 3269, 3281, 3318, 3269, 3318, 3306, 
-    3298, 3348, 3355, 3298, 3355, 3303, 3261, 3277, 3298, 3261, 3298, 3287, 
-    3324, 3369, 3360, 3324, 3339, 3369, 3311, 3324, 3360, 3311, 3360, 3348, 
-    3277, 3311, 3348, 3277, 3348, 3298, 3384, 3399, 3403, 3384, 3403, 3387, 
-    3384, 3394, 3411, 3384, 3411, 3399, 3417, 3422, 3433, 3417, 3433, 3428, 
-    3436, 3452, 3443, 3436, 3448, 3452, 3448, 3462, 3469, 3448, 3469, 3452, 
-    3443, 3452, 3469, 3443, 3469, 3460, 3495, 3507, 3499, 3495, 3504, 3507, 
-    3483, 3490, 3504, 3483, 3504, 3495, 3399, 3436, 3443, 3399, 3443, 3403, 
-    3399, 3411, 3448, 3399, 3448, 3436, 3428, 3433, 3490, 3428, 3490, 3483, 
-    3394, 3417, 3428, 3394, 3428, 3411, 3462, 3499, 3469, 3462, 3495, 3499, 
-    3448, 3483, 3495, 3448, 3495, 3462, 3411, 3428, 3483, 3411, 3483, 3448, 
-    3551, 3561, 3564, 3551, 3564, 3558, 3583, 3603, 3590, 3583, 3598, 3603, 
-    3573, 3583, 3590, 3573, 3590, 3578, 3573, 3580, 3598, 3573, 3598, 3583, 
-    3636, 3654, 3639, 3636, 3647, 3654, 3598, 3633, 3603, 3614, 3636, 3639, 
-    3614, 3639, 3621, 3614, 3623, 3647, 3614, 3647, 3636, 3543, 3573, 3578, 
-    3561, 3621, 3564, 3561, 3614, 3621, 3551, 3606, 3614, 3551, 3614, 3561, 
-    3590, 3623, 3614, 3590, 3603, 3623, 3578, 3590, 3614, 3578, 3614, 3606, 
-    3543, 3606, 3551, 3543, 3578, 3606, 3603, 3633, 3647, 3603, 3647, 3623, 
-    3683, 3703, 3691, 3683, 3698, 3703, 3673, 3683, 3691, 3673, 3691, 3675, 
-    3673, 3680, 3698, 3673, 3698, 3683, 3712, 3719, 3725, 3712, 3725, 3722, 
-    3758, 3776, 3766, 3758, 3769, 3776, 3691, 3703, 3755, 3736, 3758, 3766, 
-    3736, 3766, 3738, 3736, 3745, 3769, 3736, 3769, 3758, 3698, 3738, 3703, 
-    3698, 3736, 3738, 3680, 3728, 3736, 3680, 3736, 3698, 3665, 3680, 3673, 
-    3722, 3745, 3736, 3722, 3725, 3745, 3712, 3722, 3736, 3712, 3736, 3728, 
-    3665, 3728, 3680, 3665, 3712, 3728, 3703, 3738, 3766, 3703, 3766, 3755, 
-    3791, 3809, 3798, 3791, 3805, 3809, 3805, 3813, 3818, 3805, 3818, 3809, 
-    3798, 3809, 3818, 3798, 3818, 3811, 3831, 3851, 3836, 3831, 3844, 3851, 
-    3823, 3831, 3836, 3823, 3836, 3826, 3864, 3884, 3872, 3864, 3877, 3884, 
-    3856, 3859, 3877, 3856, 3877, 3864, 3891, 3906, 3896, 3891, 3901, 3906, 
-    3813, 3872, 3818, 3813, 3864, 3872, 3805, 3856, 3864, 3805, 3864, 3813, 
-    3844, 3896, 3851, 3844, 3891, 3896, 3811, 3844, 3831, 3811, 3818, 3844, 
-    3798, 3811, 3831, 3798, 3831, 3823, 3872, 3901, 3891, 3872, 3884, 3901, 
-    3818, 3891, 3844, 3818, 3872, 3891, 3245, 3519, 3527, 3245, 3527, 3247, 
-    3237, 3247, 3527, 3237, 3527, 3514, 3332, 3543, 3339, 3369, 3551, 3558, 
-    3369, 3558, 3372, 3339, 3543, 3551, 3339, 3551, 3369, 3332, 3378, 3573, 
-    3332, 3573, 3543, 3460, 3469, 3665, 3499, 3507, 3719, 3499, 3719, 3712, 
-    3460, 3665, 3673, 3460, 3673, 3475, 3469, 3499, 3712, 3469, 3712, 3665, 
-    3159, 3387, 3403, 3159, 3403, 3170, 3209, 3443, 3460, 3209, 3475, 3245, 
-    3209, 3460, 3475, 3170, 3403, 3443, 3170, 3443, 3209, 3519, 3675, 3527, 
-    3519, 3673, 3675, 3527, 3675, 3691, 3527, 3691, 3532, 3633, 3791, 3798, 
-    3647, 3826, 3654, 3647, 3823, 3826, 3633, 3823, 3647, 3633, 3798, 3823, 
-    3598, 3783, 3791, 3598, 3791, 3633, 3137, 3148, 3281, 3137, 3281, 3264, 
-    3200, 3332, 3318, 3200, 3378, 3332, 3200, 3237, 3378, 3148, 3200, 3318, 
-    3148, 3318, 3281, 3514, 3580, 3573, 3514, 3527, 3580, 3527, 3532, 3598, 
-    3527, 3598, 3580, 3755, 3805, 3791, 3766, 3859, 3856, 3766, 3776, 3859, 
-    3755, 3856, 3805, 3755, 3766, 3856, 3691, 3755, 3791, 3691, 3791, 3783, 
-    3532, 3783, 3598, 3532, 3691, 3783, 3237, 3514, 3573, 3237, 3573, 3378, 
-    3245, 3475, 3673, 3245, 3673, 3519, 3914, 3924, 3930, 3914, 3930, 3919, 
-    3938, 3954, 3959, 3938, 3959, 3943, 3943, 3963, 3946, 3943, 3959, 3963, 
-    3984, 3996, 3989, 3984, 3992, 3996, 3971, 3984, 3989, 3971, 3989, 3979, 
-    4002, 4015, 4004, 4002, 4008, 4015, 4008, 4024, 4032, 4008, 4032, 4015, 
-    4002, 4024, 4008, 4002, 4017, 4024, 3924, 3971, 3979, 3924, 3979, 3930, 
-    3954, 4002, 4004, 3954, 4004, 3959, 3959, 4015, 3963, 3959, 4004, 4015, 
-    3919, 3930, 3954, 3919, 3954, 3938, 3989, 4024, 4017, 3989, 3996, 4024, 
-    3979, 3989, 4017, 3979, 4017, 4002, 3930, 3979, 4002, 3930, 4002, 3954, 
-    4046, 4057, 4060, 4046, 4060, 4049, 4084, 4102, 4091, 4084, 4094, 4102, 
-    4137, 4150, 4140, 4137, 4142, 4150, 4117, 4137, 4140, 4117, 4140, 4124, 
-    4124, 4150, 4129, 4124, 4140, 4150, 4070, 4117, 4124, 4070, 4124, 4078, 
-    4078, 4129, 4080, 4078, 4124, 4129, 4057, 4078, 4060, 4057, 4070, 4078, 
-    4049, 4078, 4068, 4049, 4060, 4078, 4112, 4137, 4117, 4078, 4102, 4094, 
-    4078, 4080, 4102, 4068, 4078, 4094, 4068, 4094, 4084, 4129, 4150, 4160, 
-    4080, 4129, 4160, 4080, 4160, 4102, 4057, 4117, 4070, 4057, 4112, 4117, 
-    4164, 4180, 4185, 4164, 4185, 4169, 4169, 4193, 4176, 4169, 4185, 4193, 
-    4199, 4215, 4204, 4199, 4210, 4215, 4228, 4239, 4230, 4228, 4234, 4239, 
-    4234, 4254, 4256, 4234, 4256, 4239, 4228, 4254, 4234, 4228, 4247, 4254, 
-    4276, 4289, 4281, 4276, 4284, 4289, 4265, 4289, 4272, 4265, 4281, 4289, 
-    4180, 4228, 4230, 4180, 4230, 4185, 4185, 4239, 4193, 4185, 4230, 4239, 
-    4210, 4272, 4215, 4210, 4265, 4272, 4176, 4210, 4199, 4176, 4193, 4210, 
-    4254, 4281, 4256, 4254, 4276, 4281, 4239, 4281, 4265, 4239, 4256, 4281, 
-    4193, 4265, 4210, 4193, 4239, 4265, 4298, 4309, 4312, 4298, 4312, 4306, 
-    4364, 4380, 4367, 4364, 4372, 4380, 4347, 4364, 4367, 4347, 4367, 4355, 
-    4355, 4380, 4357, 4355, 4367, 4380, 4384, 4402, 4387, 4384, 4394, 4402, 
-    4331, 4384, 4387, 4331, 4387, 4339, 4339, 4402, 4341, 4339, 4387, 4402, 
-    4372, 4412, 4380, 4309, 4339, 4312, 4309, 4331, 4339, 4306, 4339, 4324, 
-    4306, 4312, 4339, 4322, 4347, 4355, 4339, 4372, 4364, 4339, 4341, 4372, 
-    4324, 4339, 4364, 4324, 4364, 4347, 4306, 4347, 4322, 4306, 4324, 4347, 
-    4341, 4402, 4412, 4341, 4412, 4372, 4455, 4475, 4460, 4455, 4467, 4475, 
-    4446, 4455, 4460, 4446, 4460, 4450, 4483, 4502, 4491, 4483, 4495, 4502, 
-    4495, 4509, 4511, 4495, 4511, 4502, 4483, 4509, 4495, 4483, 4504, 4509, 
-    4517, 4533, 4523, 4517, 4528, 4533, 4549, 4566, 4554, 4549, 4561, 4566, 
-    4538, 4566, 4541, 4538, 4554, 4566, 4467, 4523, 4475, 4467, 4517, 4523, 
-    4509, 4554, 4511, 4509, 4549, 4554, 4502, 4554, 4538, 4502, 4511, 4554, 
-    4460, 4509, 4504, 4460, 4475, 4509, 4450, 4460, 4504, 4450, 4504, 4483, 
-    4523, 4561, 4549, 4523, 4533, 4561, 4475, 4549, 4509, 4475, 4523, 4549, 
-    4591, 4606, 4594, 4591, 4599, 4606, 4570, 4591, 4594, 4570, 4594, 4578, 
-    4578, 4606, 4583, 4578, 4594, 4606, 4644, 4657, 4651, 4644, 4654, 4657, 
-    4675, 4688, 4678, 4675, 4685, 4688, 4591, 4667, 4599, 4620, 4675, 4678, 
-    4620, 4678, 4628, 4628, 4688, 4635, 4628, 4678, 4688, 4599, 4628, 4606, 
-    4599, 4620, 4628, 4583, 4628, 4618, 4583, 4606, 4628, 4578, 4583, 4616, 
-    4628, 4657, 4654, 4628, 4635, 4657, 4618, 4628, 4654, 4618, 4654, 4644, 
-    4583, 4644, 4616, 4583, 4618, 4644, 4599, 4675, 4620, 4599, 4667, 4675, 
-    3992, 4298, 4306, 3992, 4306, 3996, 4024, 4322, 4032, 3996, 4306, 4322, 
-    3996, 4322, 4024, 4032, 4355, 4038, 4032, 4322, 4355, 4142, 4426, 4431, 
-    4142, 4431, 4150, 4137, 4426, 4142, 4137, 4418, 4426, 4247, 4616, 4254, 
-    4276, 4651, 4284, 4276, 4644, 4651, 4221, 4578, 4616, 4221, 4616, 4247, 
-    4254, 4644, 4276, 4254, 4616, 4644, 4091, 4180, 4164, 4091, 4102, 4180, 
-    4160, 4247, 4228, 4150, 4247, 4160, 4150, 4221, 4247, 4102, 4228, 4180, 
-    4102, 4160, 4228, 4394, 4450, 4402, 4394, 4446, 4450, 4412, 4483, 4491, 
-    4402, 4483, 4412, 4402, 4450, 4483, 4380, 4491, 4443, 4380, 4412, 4491, 
-    4426, 4578, 4431, 4426, 4570, 4578, 4426, 4591, 4570, 4426, 4436, 4591, 
-    3946, 3963, 4057, 3946, 4057, 4046, 4015, 4032, 4112, 4032, 4137, 4112, 
-    4032, 4038, 4137, 3963, 4015, 4112, 3963, 4112, 4057, 4355, 4426, 4418, 
-    4355, 4357, 4426, 4357, 4380, 4436, 4357, 4436, 4426, 4491, 4502, 4667, 
-    4538, 4685, 4675, 4538, 4541, 4685, 4502, 4675, 4667, 4502, 4538, 4675, 
-    4443, 4491, 4667, 4443, 4667, 4591, 4380, 4591, 4436, 4380, 4443, 4591, 
-    4038, 4355, 4418, 4038, 4418, 4137, 4150, 4578, 4221, 4150, 4431, 4578, 
-    4696, 4701, 4712, 4696, 4712, 4706, 4725, 4745, 4741, 4725, 4728, 4745, 
-    4720, 4725, 4741, 4720, 4741, 4736, 4766, 4778, 4769, 4766, 4774, 4778, 
-    4753, 4761, 4774, 4753, 4774, 4766, 4784, 4797, 4788, 4784, 4790, 4797, 
-    4784, 4806, 4799, 4784, 4788, 4806, 4788, 4797, 4814, 4788, 4814, 4806, 
-    4706, 4712, 4761, 4706, 4761, 4753, 4741, 4797, 4790, 4741, 4745, 4797, 
-    4736, 4741, 4790, 4736, 4790, 4784, 4701, 4720, 4736, 4701, 4736, 4712, 
-    4774, 4806, 4778, 4774, 4799, 4806, 4761, 4784, 4799, 4761, 4799, 4774, 
-    4712, 4736, 4784, 4712, 4784, 4761, 4828, 4831, 4842, 4828, 4842, 4839, 
-    4866, 4884, 4876, 4866, 4873, 4884, 4919, 4932, 4921, 4919, 4924, 4932, 
-    4906, 4932, 4924, 4906, 4911, 4932, 4899, 4906, 4924, 4899, 4924, 4919, 
-    4860, 4911, 4906, 4860, 4862, 4911, 4852, 4860, 4906, 4852, 4906, 4899, 
-    4860, 4884, 4862, 4860, 4876, 4884, 4850, 4866, 4876, 4850, 4876, 4860, 
-    4911, 4942, 4932, 4839, 4860, 4852, 4839, 4842, 4860, 4831, 4860, 4842, 
-    4831, 4850, 4860, 4894, 4899, 4919, 4839, 4899, 4894, 4839, 4852, 4899, 
-    4862, 4884, 4942, 4862, 4942, 4911, 4951, 4975, 4967, 4951, 4958, 4975, 
-    4946, 4951, 4967, 4946, 4967, 4962, 4981, 4997, 4992, 4981, 4986, 4997, 
-    5010, 5021, 5014, 5010, 5016, 5021, 5010, 5036, 5029, 5010, 5014, 5036, 
-    5014, 5021, 5038, 5014, 5038, 5036, 5058, 5071, 5061, 5058, 5066, 5071, 
-    5047, 5071, 5066, 5047, 5054, 5071, 4967, 5021, 5016, 4967, 4975, 5021, 
-    4962, 4967, 5016, 4962, 5016, 5010, 4992, 5054, 5047, 4992, 4997, 5054, 
-    4958, 4992, 4975, 4958, 4981, 4992, 5036, 5066, 5058, 5036, 5038, 5066, 
-    5021, 5066, 5038, 5021, 5047, 5066, 4975, 5047, 5021, 4975, 4992, 5047, 
-    5080, 5088, 5094, 5080, 5094, 5091, 5146, 5162, 5151, 5146, 5154, 5162, 
-    5137, 5162, 5154, 5137, 5139, 5162, 5129, 5137, 5154, 5129, 5154, 5146, 
-    5166, 5184, 5173, 5166, 5176, 5184, 5112, 5184, 5176, 5112, 5114, 5184, 
-    5104, 5112, 5176, 5104, 5176, 5166, 5151, 5162, 5194, 5112, 5151, 5114, 
-    5112, 5146, 5151, 5097, 5129, 5146, 5097, 5146, 5112, 5091, 5112, 5104, 
-    5091, 5094, 5112, 5088, 5112, 5094, 5088, 5097, 5112, 5124, 5137, 5129, 
-    5088, 5129, 5097, 5088, 5124, 5129, 5114, 5151, 5194, 5114, 5194, 5184, 
-    5212, 5232, 5219, 5212, 5224, 5232, 5203, 5207, 5224, 5203, 5224, 5212, 
-    5238, 5254, 5243, 5238, 5249, 5254, 5262, 5281, 5266, 5262, 5274, 5281, 
-    5262, 5288, 5283, 5262, 5266, 5288, 5266, 5281, 5290, 5266, 5290, 5288, 
-    5306, 5323, 5313, 5306, 5318, 5323, 5295, 5323, 5318, 5295, 5298, 5323, 
-    5224, 5288, 5232, 5224, 5283, 5288, 5207, 5262, 5283, 5207, 5283, 5224, 
-    5249, 5313, 5254, 5249, 5306, 5313, 5219, 5249, 5238, 5219, 5232, 5249, 
-    5288, 5318, 5306, 5288, 5290, 5318, 5281, 5318, 5290, 5281, 5295, 5318, 
-    5232, 5306, 5249, 5232, 5288, 5306, 5373, 5388, 5378, 5373, 5381, 5388, 
-    5360, 5388, 5381, 5360, 5365, 5388, 5352, 5360, 5381, 5352, 5381, 5373, 
-    5426, 5439, 5436, 5426, 5433, 5439, 5457, 5470, 5464, 5457, 5467, 5470, 
-    5373, 5378, 5449, 5401, 5470, 5467, 5401, 5408, 5470, 5393, 5401, 5467, 
-    5393, 5467, 5457, 5360, 5418, 5365, 5401, 5439, 5408, 5401, 5436, 5439, 
-    5391, 5426, 5436, 5391, 5436, 5401, 5378, 5401, 5393, 5378, 5388, 5401, 
-    5365, 5401, 5388, 5365, 5391, 5401, 5365, 5426, 5391, 5365, 5418, 5426, 
-    5378, 5457, 5449, 5378, 5393, 5457, 4769, 4778, 5088, 4769, 5088, 5080, 
-    4806, 4814, 5124, 4814, 5137, 5124, 4814, 4820, 5137, 4778, 4806, 5124, 
-    4778, 5124, 5088, 4919, 5338, 5330, 4919, 4921, 5338, 4921, 4932, 5343, 
-    4921, 5343, 5338, 5029, 5036, 5418, 5058, 5433, 5426, 5058, 5061, 5433, 
-    5036, 5426, 5418, 5036, 5058, 5426, 5003, 5029, 5418, 5003, 5418, 5360, 
-    4728, 4828, 4839, 4728, 4839, 4745, 4797, 4894, 4814, 4814, 4919, 4820, 
-    4814, 4894, 4919, 4745, 4839, 4894, 4745, 4894, 4797, 5137, 5338, 5139, 
-    5137, 5330, 5338, 5139, 5338, 5348, 5139, 5348, 5162, 5274, 5449, 5281, 
-    5295, 5464, 5298, 5295, 5457, 5464, 5281, 5457, 5295, 5281, 5449, 5457, 
-    5200, 5373, 5449, 5200, 5449, 5274, 4873, 4962, 4884, 4873, 4946, 4962, 
-    4942, 5010, 5029, 4932, 5029, 5003, 4932, 4942, 5029, 4884, 5010, 4942, 
-    4884, 4962, 5010, 5173, 5207, 5203, 5173, 5184, 5207, 5194, 5274, 5262, 
-    5184, 5262, 5207, 5184, 5194, 5262, 5162, 5274, 5194, 5162, 5200, 5274, 
-    5338, 5360, 5352, 5338, 5343, 5360, 5338, 5373, 5348, 5338, 5352, 5373, 
-    5162, 5373, 5200, 5162, 5348, 5373, 4932, 5360, 5343, 4932, 5003, 5360, 
-    4820, 4919, 5330, 4820, 5330, 5137, 5478, 5494, 5483, 5478, 5488, 5494, 
-    5505, 5527, 5522, 5505, 5510, 5527, 5502, 5527, 5510, 5502, 5518, 5527, 
-    5546, 5560, 5551, 5546, 5555, 5560, 5535, 5560, 5543, 5535, 5551, 5560, 
-    5570, 5584, 5575, 5570, 5582, 5584, 5570, 5593, 5591, 5570, 5575, 5593, 
-    5570, 5601, 5582, 5570, 5591, 5601, 5488, 5543, 5494, 5488, 5535, 5543, 
-    5522, 5584, 5582, 5522, 5527, 5584, 5518, 5584, 5527, 5518, 5575, 5584, 
-    5483, 5518, 5502, 5483, 5494, 5518, 5555, 5593, 5560, 5555, 5591, 5593, 
-    5543, 5593, 5575, 5543, 5560, 5593, 5494, 5575, 5518, 5494, 5543, 5575, 
-    5608, 5624, 5618, 5608, 5613, 5624, 5635, 5657, 5652, 5635, 5640, 5657, 
-    5628, 5657, 5640, 5628, 5644, 5657, 5681, 5695, 5685, 5681, 5690, 5695, 
-    5670, 5695, 5690, 5670, 5678, 5695, 5700, 5714, 5707, 5700, 5712, 5714, 
-    5700, 5729, 5721, 5700, 5707, 5729, 5700, 5731, 5712, 5700, 5721, 5731, 
-    5618, 5678, 5670, 5618, 5624, 5678, 5652, 5714, 5712, 5652, 5657, 5714, 
-    5644, 5714, 5657, 5644, 5707, 5714, 5613, 5652, 5624, 5613, 5635, 5652, 
-    5685, 5731, 5721, 5685, 5695, 5731, 5678, 5731, 5695, 5678, 5712, 5731, 
-    5624, 5712, 5678, 5624, 5652, 5712, 5760, 5774, 5763, 5760, 5771, 5774, 
-    5782, 5796, 5793, 5782, 5785, 5796, 5816, 5835, 5824, 5816, 5832, 5835, 
-    5807, 5835, 5832, 5807, 5814, 5835, 5802, 5835, 5814, 5802, 5824, 5835, 
-    5744, 5814, 5807, 5744, 5752, 5814, 5742, 5814, 5752, 5742, 5802, 5814, 
-    5744, 5796, 5752, 5744, 5793, 5796, 5740, 5796, 5785, 5740, 5752, 5796, 
-    5807, 5832, 5854, 5742, 5774, 5771, 5742, 5752, 5774, 5740, 5774, 5752, 
-    5740, 5763, 5774, 5802, 5845, 5824, 5742, 5845, 5802, 5742, 5771, 5845, 
-    5744, 5854, 5793, 5744, 5807, 5854, 5865, 5881, 5870, 5865, 5875, 5881, 
-    5896, 5914, 5901, 5896, 5909, 5914, 5885, 5914, 5889, 5885, 5901, 5914, 
-    5929, 5947, 5937, 5929, 5942, 5947, 5918, 5947, 5942, 5918, 5922, 5947, 
-    5952, 5974, 5959, 5952, 5966, 5974, 5952, 5981, 5979, 5952, 5959, 5981, 
-    5952, 5983, 5966, 5952, 5979, 5983, 5875, 5937, 5881, 5875, 5929, 5937, 
-    5909, 5981, 5914, 5909, 5979, 5981, 5889, 5981, 5959, 5889, 5914, 5981, 
-    5870, 5909, 5896, 5870, 5881, 5909, 5937, 5983, 5979, 5937, 5947, 5983, 
-    5922, 5983, 5947, 5922, 5966, 5983, 5881, 5979, 5909, 5881, 5937, 5979, 
-    6012, 6026, 6020, 6012, 6023, 6026, 6047, 6065, 6054, 6047, 6062, 6065, 
-    6034, 6065, 6062, 6034, 6042, 6065, 6032, 6065, 6042, 6032, 6054, 6065, 
-    6082, 6096, 6085, 6082, 6093, 6096, 5996, 6096, 6093, 5996, 6004, 6096, 
-    5994, 6096, 6004, 5994, 6085, 6096, 6047, 6106, 6062, 5996, 6054, 6004, 
-    5996, 6047, 6054, 5987, 6054, 6032, 5987, 6004, 6054, 5994, 6026, 6023, 
-    5994, 6004, 6026, 5987, 6026, 6004, 5987, 6020, 6026, 6032, 6042, 6075, 
-    5987, 6075, 6020, 5987, 6032, 6075, 5996, 6106, 6047, 5996, 6093, 6106, 
-    6147, 6165, 6155, 6147, 6162, 6165, 6134, 6165, 6162, 6134, 6142, 6165, 
-    6129, 6165, 6142, 6129, 6155, 6165, 6173, 6187, 6184, 6173, 6181, 6187, 
-    6204, 6218, 6212, 6204, 6215, 6218, 6118, 6218, 6215, 6118, 6126, 6218, 
-    6111, 6218, 6126, 6111, 6212, 6218, 6147, 6155, 6228, 6118, 6187, 6126, 
-    6118, 6184, 6187, 6109, 6187, 6181, 6109, 6126, 6187, 6134, 6197, 6142, 
-    6111, 6162, 6147, 6111, 6126, 6162, 6109, 6162, 6126, 6109, 6134, 6162, 
-    6109, 6197, 6134, 6109, 6181, 6197, 6111, 6228, 6212, 6111, 6147, 6228, 
-    5546, 6020, 5555, 5546, 6012, 6020, 5591, 6075, 5601, 5566, 6075, 6042, 
-    5566, 5601, 6075, 5555, 6075, 5591, 5555, 6020, 6075, 5681, 6181, 6173, 
-    5681, 5685, 6181, 5721, 5729, 6197, 5685, 6197, 6181, 5685, 5721, 6197, 
-    5663, 6197, 5729, 5663, 6142, 6197, 5816, 6242, 6237, 5816, 5824, 6242, 
-    5816, 6247, 5832, 5816, 6237, 6247, 5505, 5771, 5760, 5505, 5522, 5771, 
-    5582, 5601, 5845, 5566, 5845, 5601, 5566, 5824, 5845, 5522, 5845, 5771, 
-    5522, 5582, 5845, 5918, 6212, 5922, 5918, 6204, 6212, 5966, 6228, 5974, 
-    5922, 6228, 5966, 5922, 6212, 6228, 5860, 6228, 6155, 5860, 5974, 6228, 
-    6034, 6242, 6042, 6034, 6237, 6242, 6034, 6252, 6237, 6034, 6062, 6252, 
-    5628, 5793, 5644, 5628, 5782, 5793, 5707, 5854, 5729, 5663, 5854, 5832, 
-    5663, 5729, 5854, 5644, 5854, 5707, 5644, 5793, 5854, 5885, 6093, 6082, 
-    5885, 5889, 6093, 5959, 5974, 6106, 5889, 6106, 6093, 5889, 5959, 6106, 
-    5860, 6106, 5974, 5860, 6062, 6106, 6129, 6247, 6237, 6129, 6142, 6247, 
-    6129, 6252, 6155, 6129, 6237, 6252, 5860, 6252, 6062, 5860, 6155, 6252, 
-    5663, 6247, 6142, 5663, 5832, 6247, 5566, 6242, 5824, 5566, 6042, 6242, 
-    469, 3130, 3137, 469, 3137, 476, 491, 498, 3159, 491, 3159, 3152, 
-    491, 3152, 3178, 491, 3178, 518, 469, 518, 3178, 469, 3178, 3130, 
-    616, 3254, 3261, 616, 3261, 623, 616, 626, 3264, 616, 3264, 3254, 
-    643, 3287, 3292, 643, 3292, 648, 623, 3261, 3287, 623, 3287, 643, 
-    746, 3384, 3387, 746, 3387, 749, 746, 756, 3394, 746, 3394, 3384, 
-    773, 778, 3422, 773, 3422, 3417, 756, 773, 3417, 756, 3417, 3394, 
-    476, 3137, 3264, 476, 3264, 626, 498, 749, 3387, 498, 3387, 3159, 
-    1270, 3914, 3919, 1270, 3919, 1275, 1300, 3938, 3943, 1300, 3943, 1305, 
-    1305, 3946, 1308, 1305, 3943, 3946, 1275, 3919, 3938, 1275, 3938, 1300, 
-    1385, 4046, 4049, 1385, 4049, 1388, 1423, 4091, 1430, 1423, 4084, 4091, 
-    1408, 4068, 4084, 1408, 4084, 1423, 1388, 4068, 1408, 1388, 4049, 4068, 
-    1526, 4164, 4169, 1526, 4169, 1531, 1531, 4176, 1538, 1531, 4169, 4176, 
-    1555, 4204, 1560, 1555, 4199, 4204, 1538, 4199, 1555, 1538, 4176, 4199, 
-    1308, 3946, 4046, 1308, 4046, 1385, 1430, 4164, 1526, 1430, 4091, 4164, 
-    2052, 2057, 4701, 2052, 4701, 4696, 2087, 4728, 4725, 2087, 2090, 4728, 
-    2082, 2087, 4725, 2082, 4725, 4720, 2057, 2082, 4720, 2057, 4720, 4701, 
-    2167, 2170, 4831, 2167, 4831, 4828, 2205, 4873, 4866, 2205, 2212, 4873, 
-    2170, 4850, 4831, 2170, 2190, 4850, 2190, 2205, 4866, 2190, 4866, 4850, 
-    2313, 4958, 4951, 2313, 2320, 4958, 2308, 2313, 4951, 2308, 4951, 4946, 
-    2337, 4986, 4981, 2337, 2342, 4986, 2320, 4981, 4958, 2320, 2337, 4981, 
-    2212, 4946, 4873, 2212, 2308, 4946, 2090, 2167, 4828, 2090, 4828, 4728, 
-    2834, 5483, 2839, 2834, 5478, 5483, 2867, 5510, 5505, 2867, 2872, 5510, 
-    2864, 5510, 2872, 2864, 5502, 5510, 2839, 5502, 2864, 2839, 5483, 5502, 
-    2964, 5613, 5608, 2964, 2969, 5613, 2997, 5640, 5635, 2997, 3002, 5640, 
-    2990, 5640, 3002, 2990, 5628, 5640, 2969, 5635, 5613, 2969, 2997, 5635, 
-    3099, 5763, 3102, 3099, 5760, 5763, 3121, 5785, 5782, 3121, 3124, 5785, 
-    3080, 5763, 5740, 3080, 3102, 5763, 3080, 5785, 3124, 3080, 5740, 5785, 
-    2990, 5782, 5628, 2990, 3121, 5782, 2867, 5760, 3099, 2867, 5505, 5760, 
-    231, 1597, 1607, 231, 1607, 246, 169, 176, 1573, 169, 1573, 1566, 
-    231, 264, 1623, 231, 1623, 1597, 169, 1566, 1623, 169, 1623, 264, 
-    705, 708, 1970, 705, 1970, 1967, 705, 1967, 1986, 705, 1986, 723, 
-    768, 2041, 2052, 768, 2052, 778, 723, 1986, 2041, 723, 2041, 768, 
-    355, 362, 1697, 355, 1697, 1690, 355, 1690, 1713, 355, 1713, 369, 
-    386, 391, 1728, 386, 1728, 1723, 362, 386, 1723, 362, 1723, 1697, 
-    176, 369, 1713, 176, 1713, 1573, 246, 1607, 1970, 246, 1970, 708, 
-    1013, 1018, 2355, 1013, 2355, 2350, 1039, 1044, 2379, 1039, 2379, 2374, 
-    1044, 2395, 2379, 1044, 1051, 2395, 1018, 1039, 2374, 1018, 2374, 2355, 
-    1085, 1088, 2485, 1085, 2485, 2482, 1163, 2539, 2529, 1163, 1178, 2539, 
-    1154, 1163, 2529, 1154, 2529, 2513, 1088, 2513, 2485, 1088, 1154, 2513, 
-    1485, 1490, 2752, 1485, 2752, 2747, 1490, 2768, 2752, 1490, 1505, 2768, 
-    1550, 2834, 2823, 1550, 1560, 2834, 1505, 2823, 2768, 1505, 1550, 2823, 
-    1051, 1085, 2482, 1051, 2482, 2395, 1178, 2747, 2539, 1178, 1485, 2747, 
-    3422, 4696, 4706, 3422, 4706, 3433, 3504, 4769, 3507, 3504, 4766, 4769, 
-    3490, 4753, 4766, 3490, 4766, 3504, 3433, 4706, 4753, 3433, 4753, 3490, 
-    3719, 5080, 5091, 3719, 5091, 3725, 3769, 5173, 3776, 3769, 5166, 5173, 
-    3725, 5104, 3745, 3725, 5091, 5104, 3745, 5104, 5166, 3745, 5166, 3769, 
-    3877, 5219, 3884, 3877, 5212, 5219, 3859, 5203, 5212, 3859, 5212, 3877, 
-    3901, 5243, 3906, 3901, 5238, 5243, 3884, 5238, 3901, 3884, 5219, 5238, 
-    3776, 5203, 3859, 3776, 5173, 5203, 3507, 4769, 5080, 3507, 5080, 3719, 
-    4528, 5870, 4533, 4528, 5865, 5870, 4561, 5901, 4566, 4561, 5896, 5901, 
-    4541, 5901, 5885, 4541, 4566, 5901, 4533, 5896, 4561, 4533, 5870, 5896, 
-    4204, 5488, 5478, 4204, 4215, 5488, 4284, 5551, 4289, 4284, 5546, 5551, 
-    4272, 5551, 5535, 4272, 4289, 5551, 4215, 5535, 5488, 4215, 4272, 5535, 
-    4685, 6085, 4688, 4685, 6082, 6085, 4651, 6023, 6012, 4651, 4657, 6023, 
-    4635, 6023, 4657, 4635, 5994, 6023, 4635, 6085, 5994, 4635, 4688, 6085, 
-    4541, 6082, 4685, 4541, 5885, 6082, 4284, 6012, 5546, 4284, 4651, 6012, 
-    39, 784, 794, 39, 794, 54, 70, 85, 825, 70, 825, 815, 
-    70, 815, 850, 70, 850, 140, 39, 140, 850, 39, 850, 784, 
-    314, 933, 945, 314, 945, 329, 314, 336, 956, 314, 956, 933, 
-    381, 1002, 1013, 381, 1013, 391, 329, 945, 1002, 329, 1002, 381, 
-    566, 1185, 1193, 566, 1193, 573, 566, 588, 1204, 566, 1204, 1185, 
-    638, 648, 1270, 638, 1270, 1259, 588, 638, 1259, 588, 1259, 1204, 
-    54, 794, 956, 54, 956, 336, 85, 573, 1193, 85, 1193, 825, 
-    1728, 2350, 2360, 1728, 2360, 1739, 1791, 2407, 2420, 1791, 2420, 1801, 
-    1801, 2428, 1808, 1801, 2420, 2428, 1739, 2360, 2407, 1739, 2407, 1791, 
-    1864, 2604, 2615, 1864, 2615, 1870, 1945, 2700, 1960, 1945, 2690, 2700, 
-    1927, 2637, 2690, 1927, 2690, 1945, 1870, 2637, 1927, 1870, 2615, 2637, 
-    2262, 2882, 2891, 2262, 2891, 2272, 2272, 2903, 2287, 2272, 2891, 2903, 
-    2332, 2964, 2342, 2332, 2953, 2964, 2287, 2953, 2332, 2287, 2903, 2953, 
-    1808, 2428, 2604, 1808, 2604, 1864, 1960, 2882, 2262, 1960, 2700, 2882, 
-    3292, 3303, 3924, 3292, 3924, 3914, 3365, 3992, 3984, 3365, 3372, 3992, 
-    3355, 3365, 3984, 3355, 3984, 3971, 3303, 3355, 3971, 3303, 3971, 3924, 
-    3558, 3564, 4309, 3558, 4309, 4298, 3639, 4394, 4384, 3639, 3654, 4394, 
-    3564, 4331, 4309, 3564, 3621, 4331, 3621, 3639, 4384, 3621, 4384, 4331, 
-    3836, 4467, 4455, 3836, 3851, 4467, 3826, 3836, 4455, 3826, 4455, 4446, 
-    3896, 4528, 4517, 3896, 3906, 4528, 3851, 4517, 4467, 3851, 3896, 4517, 
-    3654, 4446, 4394, 3654, 3826, 4446, 3372, 3558, 4298, 3372, 4298, 3992, 
-    4986, 5618, 4997, 4986, 5608, 5618, 5061, 5690, 5681, 5061, 5071, 5690, 
-    5054, 5690, 5071, 5054, 5670, 5690, 4997, 5670, 5054, 4997, 5618, 5670, 
-    5243, 5875, 5865, 5243, 5254, 5875, 5313, 5942, 5929, 5313, 5323, 5942, 
-    5298, 5942, 5323, 5298, 5918, 5942, 5254, 5929, 5875, 5254, 5313, 5929, 
-    5433, 6184, 5439, 5433, 6173, 6184, 5464, 6215, 6204, 5464, 5470, 6215, 
-    5408, 6184, 6118, 5408, 5439, 6184, 5408, 6215, 5470, 5408, 6118, 6215, 
-    5298, 6204, 5918, 5298, 5464, 6204, 5061, 6173, 5433, 5061, 5681, 6173, 
-    391, 1013, 2350, 391, 2350, 1728, 3906, 5865, 4528, 3906, 5243, 5865, 
-    648, 3292, 3914, 648, 3914, 1270, 2342, 5608, 4986, 2342, 2964, 5608, 
-    778, 2052, 4696, 778, 4696, 3422, 1560, 5478, 2834, 1560, 4204, 5478
-];
@@ -1,7 +1,12 @@
-//This is a work in progress. It more closely resembles MDC from the original paper but is slow and doesn't produce correct vertices.
+// Manifold Dual Contouring, closer to the original paper than `manifold_dual_contouring`: every
+// vertex is solved once (with the Leven QEF solver by default) and kept inside the cell it represents.
+// Vertices live in an arena, so clustering links parents by index instead of by lock. Each
+// subtree is clustered as soon as it is built, which leaves the arena in the post-order the
+// vertex buffer uses: arena indices are vertex buffer indices.
 
 use crate::{
     manifold_dual_contouring::{
+        mdc::{GridBounds, MeshBuffers, flat_shade},
         sampler::Sampler,
         tables::{
             T_CELL_PROC_EDGE_MASK, T_CORNER_DELTAS, T_EDGE_PAIRS, T_EDGE_PROC_EDGE_MASK,
@@ -9,103 +14,246 @@ use crate::{
             T_INTERNAL_EDGES, T_PROCESS_EDGE_MASK, TRANSFORMED_EDGES_TABLE,
         },
     },
-    manifold_dual_contouring_2::{
-        octree::{MeshVertex, OctreeNodeType},
-        qef::QefData,
-        solver::LevenQefSolver,
-    },
+    manifold_dual_contouring_2::{octree::OctreeNodeType, qef::QefData},
+    qef::solver::{QefSolveOptions, QefSolverKind},
 };
-use glam::{IVec3, Vec3};
+use glam::Vec3;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 
-pub fn mdc_mesh_generation<S: Sampler>(sampler: S, resolution: i32) -> (Vec<MeshVertex>, Vec<i32>) {
-    let half_res = resolution / 2;
-    let mut root = build_octree(
-        IVec3::new(-half_res, -half_res, -half_res),
-        resolution,
+pub fn mdc_mesh_generation<S: Sampler + Sync>(
+    threshold: f32,
+    mesh_buffers: &mut MeshBuffers,
+    flat_shading: bool,
+    bounds: GridBounds,
+    enforce_manifold: bool,
+    sampler: &S,
+) {
+    mdc_mesh_generation_with_options(
+        threshold,
+        mesh_buffers,
+        flat_shading,
+        bounds,
+        enforce_manifold,
+        QefSolveOptions::new(QefSolverKind::Leven),
         sampler,
     );
-    cluster_cell_base(&mut root, 0.5);
-    let mut vertices = Vec::new();
-    generate_vertex_buffer(&mut root, &mut vertices);
-    let mut indexes = Vec::new();
-    let mut tri_count = Vec::new();
-    process_cell(&root, &mut indexes, &mut tri_count, 0.5, true);
-    (vertices, indexes)
 }
 
-pub(crate) fn generate_vertex_buffer(node: &mut MdcOctreeNode, vertices: &mut Vec<MeshVertex>) {
-    if node.node_type != OctreeNodeType::NodeLeaf {
-        for i in 0..8 {
-            if let Some(child) = &mut node.children[i] {
-                generate_vertex_buffer(child, vertices);
-            }
-        }
-    }
-    if node.vertices.is_empty() {
-        return;
-    }
-    for (_, vertex) in node.vertices.iter_mut().enumerate() {
-        if vertex.is_none() {
-            continue;
-        }
-        let v = vertex.as_mut().unwrap();
-        v.index = vertices.len() as i32;
-        let mut nc = v.normal * 0.5 + Vec3::new(1.0, 1.0, 1.0) * 0.5;
-        nc = nc.normalize();
-        let color = nc;
-        let pos_vec4 = v.qef.as_mut().unwrap().solve();
-        let pos = Vec3::new(pos_vec4.x, pos_vec4.y, pos_vec4.z);
-        let mesh_vertex = MeshVertex::new(pos, v.normal, color);
-        vertices.push(mesh_vertex);
+/// `mdc_mesh_generation` with an explicit QEF solver backend and its SVD settings.
+pub fn mdc_mesh_generation_with_options<S: Sampler + Sync>(
+    threshold: f32,
+    mesh_buffers: &mut MeshBuffers,
+    flat_shading: bool,
+    bounds: GridBounds,
+    enforce_manifold: bool,
+    qef: QefSolveOptions,
+    sampler: &S,
+) {
+    let (root, vertices) = build_octree(bounds, qef, sampler);
+    generate_vertex_buffer(&vertices, mesh_buffers);
+    mesh_buffers.indices.clear();
+    let mut tri_count = Vec::new();
+    process_cell(
+        &root,
+        &vertices,
+        &mut mesh_buffers.indices,
+        &mut tri_count,
+        threshold,
+        enforce_manifold,
+    );
+    if flat_shading {
+        flat_shade(mesh_buffers, &tri_count);
     }
 }
 
-pub(crate) fn build_octree<S: Sampler>(min: IVec3, size: i32, sampler: S) -> MdcOctreeNode {
-    let mut root = MdcOctreeNode::new(min, size, OctreeNodeType::NodeInternal, 0);
+pub(crate) fn generate_vertex_buffer(vertices: &[MdcVertex], mesh_buffers: &mut MeshBuffers) {
+    vertices
+        .into_par_iter()
+        .map(|v| v.pos.to_array())
+        .collect_into_vec(&mut mesh_buffers.positions);
+    vertices
+        .into_par_iter()
+        .map(|v| v.normal.to_array())
+        .collect_into_vec(&mut mesh_buffers.normals);
+    vertices
+        .into_par_iter()
+        .map(|v| {
+            let nc = (v.normal * 0.5 + Vec3::ONE * 0.5).normalize();
+            [nc.x, nc.y, nc.z, 1.0]
+        })
+        .collect_into_vec(&mut mesh_buffers.colors);
+}
+
+/// Settings shared by every node of one octree build.
+pub(crate) struct BuildSettings<'a, S> {
+    pub(crate) voxel_size: f32,
+    /// Cells whose min corner is not below `limit` lie outside the grid.
+    pub(crate) limit: Vec3,
+    pub(crate) qef: QefSolveOptions,
+    pub(crate) sampler: &'a S,
+}
+
+pub(crate) fn build_octree<S: Sampler + Sync>(
+    bounds: GridBounds,
+    qef: QefSolveOptions,
+    sampler: &S,
+) -> (MdcOctreeNode, Vec<MdcVertex>) {
+    let mut root = MdcOctreeNode::new(
+        bounds.min,
+        bounds.octree_size(),
+        OctreeNodeType::NodeInternal,
+        0,
+    );
     let mut n_index = 1;
-    construct_nodes(&mut root, &mut n_index, &sampler);
-    root
+    let mut vertices = Vec::new();
+    // The octree is padded up to a power of two; cells starting past `limit` are never built.
+    let settings = BuildSettings {
+        voxel_size: bounds.cell_size,
+        limit: bounds.max() - Vec3::splat(bounds.cell_size * 0.5),
+        qef,
+        sampler,
+    };
+    construct_nodes(&mut root, &mut n_index, 4, &settings, &mut vertices);
+    (root, vertices)
 }
 
-pub(crate) fn construct_nodes<S: Sampler>(
+/// Builds and clusters the children of `node`. The top `threaded` levels build each child as a
+/// rayon task with its own arena, appended in child order so the result matches a serial build.
+pub(crate) fn construct_nodes<S: Sampler + Sync>(
     node: &mut MdcOctreeNode,
     n_index: &mut i32,
-    sampler: &S,
+    threaded: i32,
+    settings: &BuildSettings<S>,
+    vertices: &mut Vec<MdcVertex>,
 ) {
     if node.size == 1 {
-        construct_leaf(node, n_index, sampler);
+        construct_leaf(node, n_index, settings, vertices);
         return;
     }
+    let (voxel_size, limit) = (settings.voxel_size, settings.limit);
     let child_size = node.size / 2;
+    let child_min = |i: usize| node.min + T_CORNER_DELTAS[i] * (child_size as f32 * voxel_size);
+    if threaded > 0 && node.size > 2 {
+        // A subtree always takes the same number of node indices, so every task can start
+        // from the index a serial build would reach it with.
+        let mut first_index = [0; 8];
+        for (i, first) in first_index.iter_mut().enumerate() {
+            node.index = *n_index;
+            *n_index += 1;
+            *first = *n_index;
+            if child_min(i).cmplt(limit).all() {
+                *n_index += index_count(child_min(i), child_size, voxel_size, limit);
+            }
+        }
+        let results: Vec<_> = (0..8)
+            .into_par_iter()
+            .filter(|&i| child_min(i).cmplt(limit).all())
+            .map(|i| {
+                let mut child = MdcOctreeNode::new(
+                    child_min(i),
+                    child_size,
+                    OctreeNodeType::NodeInternal,
+                    i as i32,
+                );
+                let mut child_vertices = Vec::new();
+                let mut child_index = first_index[i];
+                construct_child(
+                    &mut child,
+                    &mut child_index,
+                    threaded - 1,
+                    settings,
+                    &mut child_vertices,
+                );
+                (i, child, child_vertices)
+            })
+            .collect();
+        for (i, mut child, mut child_vertices) in results {
+            if child.is_empty() {
+                continue;
+            }
+            let offset = vertices.len();
+            child.offset_vertices(offset);
+            for vertex in &mut child_vertices {
+                if let Some(parent) = &mut vertex.parent {
+                    *parent += offset;
+                }
+            }
+            vertices.append(&mut child_vertices);
+            node.children[i] = Some(Box::new(child));
+        }
+        return;
+    }
     for i in 0..8 {
         node.index = *n_index;
         *n_index += 1;
-        let child_min = (node.min.as_vec3() + T_CORNER_DELTAS[i] * child_size as f32).as_ivec3();
+        if !child_min(i).cmplt(limit).all() {
+            continue;
+        }
         let mut child = MdcOctreeNode::new(
-            child_min,
+            child_min(i),
             child_size,
             OctreeNodeType::NodeInternal,
             i as i32,
         );
-        construct_nodes(&mut child, n_index, sampler);
-        if !child.vertices.is_empty() || !child.children.iter().all(|c| c.is_none()) {
+        construct_child(&mut child, n_index, 0, settings, vertices);
+        if !child.is_empty() {
             node.children[i] = Some(Box::new(child));
         }
     }
 }
 
-pub(crate) fn construct_leaf<S: Sampler>(leaf: &mut MdcOctreeNode, n_index: &mut i32, sampler: &S) {
+/// Builds `child`'s subtree, then clusters `child` itself; the root is never clustered.
+fn construct_child<S: Sampler + Sync>(
+    child: &mut MdcOctreeNode,
+    n_index: &mut i32,
+    threaded: i32,
+    settings: &BuildSettings<S>,
+    vertices: &mut Vec<MdcVertex>,
+) {
+    construct_nodes(child, n_index, threaded, settings, vertices);
+    if child.node_type == OctreeNodeType::NodeInternal {
+        cluster_children(child, settings.voxel_size, &settings.qef, vertices);
+    }
+}
+
+/// Node indices a serial build of the subtree at `min` consumes.
+fn index_count(min: Vec3, size: i32, voxel_size: f32, limit: Vec3) -> i32 {
+    if size == 1 {
+        return 1;
+    }
+    let child_size = size / 2;
+    // Subtrees entirely inside the limit are full, and all full subtrees of a size are alike.
+    if (min + Vec3::splat((size - 1) as f32 * voxel_size))
+        .cmplt(limit)
+        .all()
+    {
+        return 8 + 8 * index_count(min, child_size, voxel_size, limit);
+    }
+    8 + T_CORNER_DELTAS
+        .iter()
+        .map(|delta| min + *delta * (child_size as f32 * voxel_size))
+        .filter(|child_min| child_min.cmplt(limit).all())
+        .map(|child_min| index_count(child_min, child_size, voxel_size, limit))
+        .sum::<i32>()
+}
+
+pub(crate) fn construct_leaf<S: Sampler>(
+    leaf: &mut MdcOctreeNode,
+    n_index: &mut i32,
+    settings: &BuildSettings<S>,
+    vertices: &mut Vec<MdcVertex>,
+) {
     if leaf.size != 1 {
         return;
     }
+    let (voxel_size, sampler) = (settings.voxel_size, settings.sampler);
     leaf.index = *n_index;
     *n_index += 1;
     leaf.node_type = OctreeNodeType::NodeLeaf;
     let mut corners = 0;
     let mut samples = [0.0; 8];
-    for i in 0..8 {
-        let vertex_pos = leaf.min.as_vec3() + T_CORNER_DELTAS[i];
-        let sample = sampler.sample(vertex_pos);
+    for (i, delta) in T_CORNER_DELTAS.iter().enumerate() {
+        let sample = sampler.sample(leaf.min + *delta * voxel_size);
         samples[i] = sample;
         if sample < 0.0 {
             corners |= 1 << i;
@@ -133,50 +281,33 @@ pub(crate) fn construct_leaf<S: Sampler>(leaf: &mut MdcOctreeNode, n_index: &mut
         v_edges[v_index][e_index] = code;
         e_index += 1;
     }
-    leaf.vertices = vec![None; v_index];
-    for i in 0..v_index {
+    leaf.vertices = Vec::with_capacity(v_index);
+    for edges in v_edges.iter().take(v_index) {
         let mut mdc_vertex = MdcVertex::new();
-        mdc_vertex.qef = Some(QefData::new(LevenQefSolver::new()));
         let mut normal = Vec3::ZERO;
         let mut ei = [0; 12];
         let mut k = 0;
-        while v_edges[i][k] != -1 {
-            let edge_code = v_edges[i][k] as usize;
-            ei[edge_code] = 1;
-            let edge_pair = T_EDGE_PAIRS[edge_code];
-            let a_iv =
-                leaf.min.as_vec3() + T_CORNER_DELTAS[edge_pair[0] as usize] * leaf.size as f32;
-            let b_iv =
-                leaf.min.as_vec3() + T_CORNER_DELTAS[edge_pair[1] as usize] * leaf.size as f32;
-            let intersect = get_intersection(
-                &a_iv,
-                &b_iv,
-                samples[edge_pair[0] as usize],
-                samples[edge_pair[1] as usize],
-            );
+        while edges[k] != -1 {
+            let edge = edges[k] as usize;
+            ei[edge] = 1;
+            let [c1, c2, _] = T_EDGE_PAIRS[edge].map(|c| c as usize);
+            let a = leaf.min + T_CORNER_DELTAS[c1] * voxel_size;
+            let b = leaf.min + T_CORNER_DELTAS[c2] * voxel_size;
+            let intersect = get_intersection(&a, &b, samples[c1], samples[c2]);
             let n = calculate_surface_normal(&intersect, sampler);
-            normal = normal + n;
-            mdc_vertex
-                .qef
-                .as_mut()
-                .unwrap()
-                .qef_add_point3(intersect, n);
+            normal += n;
+            mdc_vertex.qef.qef_add_point3(intersect, n);
             k += 1;
         }
-        normal = normal / k as f32;
-        normal = normal.normalize();
-        mdc_vertex.index = 0;
-        mdc_vertex.parent = None;
-        mdc_vertex.collapsible = true;
-        mdc_vertex.normal = normal;
+        normal /= k as f32;
+        mdc_vertex.normal = normal.normalize();
         mdc_vertex.euler = 1;
         mdc_vertex.eis = ei;
         mdc_vertex.in_cell = leaf.child_index;
         mdc_vertex.face_prop2 = true;
-        let solved = mdc_vertex.qef.as_mut().unwrap().solve();
-        mdc_vertex.pos = Vec3::new(solved.x, solved.y, solved.z);
-        mdc_vertex.error = mdc_vertex.qef.as_mut().unwrap().get_error();
-        leaf.vertices[i] = Some(Box::new(mdc_vertex));
+        mdc_vertex.solve_in_cell(leaf.min, voxel_size, &settings.qef);
+        leaf.vertices.push(vertices.len());
+        vertices.push(mdc_vertex);
     }
 }
 
@@ -195,479 +326,460 @@ where
     let dx = sampler.sample(*p + x_offset) - sampler.sample(*p - x_offset);
     let dy = sampler.sample(*p + y_offset) - sampler.sample(*p - y_offset);
     let dz = sampler.sample(*p + z_offset) - sampler.sample(*p - z_offset);
-    let mut v = Vec3::new(dx, dy, dz);
-    v = v.normalize();
-    v
+    Vec3::new(dx, dy, dz).normalize()
 }
 
 pub(crate) fn process_cell(
     node: &MdcOctreeNode,
-    indexes: &mut Vec<i32>,
+    vertices: &[MdcVertex],
+    indexes: &mut Vec<u32>,
     tri_count: &mut Vec<i32>,
     threshold: f32,
     enforce_manifold: bool,
 ) {
-    if node.node_type == OctreeNodeType::NodeInternal {
-        for i in 0..8 {
-            if let Some(child) = &node.children[i] {
-                process_cell(child, indexes, tri_count, threshold, enforce_manifold);
-            }
-        }
-        for i in 0..12 {
-            let face_nodes = [
-                node.children[T_EDGE_PAIRS[i][0] as usize].as_ref(),
-                node.children[T_EDGE_PAIRS[i][1] as usize].as_ref(),
-            ];
-            process_face(
-                &face_nodes,
-                T_EDGE_PAIRS[i][2] as usize,
-                indexes,
-                tri_count,
-                threshold,
-                enforce_manifold,
-            );
-        }
-        for i in 0..6 {
-            let edge_nodes = [
-                node.children[T_CELL_PROC_EDGE_MASK[i][0] as usize].as_ref(),
-                node.children[T_CELL_PROC_EDGE_MASK[i][1] as usize].as_ref(),
-                node.children[T_CELL_PROC_EDGE_MASK[i][2] as usize].as_ref(),
-                node.children[T_CELL_PROC_EDGE_MASK[i][3] as usize].as_ref(),
-            ];
-            process_edge(
-                &edge_nodes,
-                T_CELL_PROC_EDGE_MASK[i][4] as usize,
-                indexes,
-                tri_count,
-                threshold,
-                enforce_manifold,
-            );
-        }
+    if node.node_type != OctreeNodeType::NodeInternal {
+        return;
+    }
+    for child in node.children.iter().flatten() {
+        process_cell(
+            child,
+            vertices,
+            indexes,
+            tri_count,
+            threshold,
+            enforce_manifold,
+        );
+    }
+    for pair in &T_EDGE_PAIRS {
+        let face_nodes = [
+            node.children[pair[0] as usize].as_deref(),
+            node.children[pair[1] as usize].as_deref(),
+        ];
+        process_face(
+            &face_nodes,
+            pair[2] as usize,
+            vertices,
+            indexes,
+            tri_count,
+            threshold,
+            enforce_manifold,
+        );
+    }
+    for mask in &T_CELL_PROC_EDGE_MASK {
+        let edge_nodes = [0, 1, 2, 3].map(|j| node.children[mask[j] as usize].as_deref());
+        process_edge(
+            &edge_nodes,
+            mask[4] as usize,
+            vertices,
+            indexes,
+            tri_count,
+            threshold,
+            enforce_manifold,
+        );
     }
 }
 
 pub(crate) fn process_face(
-    nodes: &[Option<&Box<MdcOctreeNode>>; 2],
+    nodes: &[Option<&MdcOctreeNode>; 2],
     direction: usize,
-    indexes: &mut Vec<i32>,
+    vertices: &[MdcVertex],
+    indexes: &mut Vec<u32>,
     tri_count: &mut Vec<i32>,
     threshold: f32,
     enforce_manifold: bool,
 ) {
-    if nodes[0].is_none() || nodes[1].is_none() {
+    let (Some(node0), Some(node1)) = (nodes[0], nodes[1]) else {
+        return;
+    };
+    if node0.node_type == OctreeNodeType::NodeLeaf && node1.node_type == OctreeNodeType::NodeLeaf {
         return;
     }
-    let n0 = nodes[0].unwrap();
-    let n1 = nodes[1].unwrap();
-    if n0.node_type != OctreeNodeType::NodeLeaf || n1.node_type != OctreeNodeType::NodeLeaf {
-        for i in 0..4 {
-            let mut face_nodes: [Option<&Box<MdcOctreeNode>>; 2] = [None, None];
-            for j in 0..2 {
-                if nodes[j].is_none() {
-                    continue;
-                }
-                let n = nodes[j].unwrap();
-                if n.node_type == OctreeNodeType::NodeLeaf {
-                    face_nodes[j] = nodes[j];
-                } else {
-                    face_nodes[j] =
-                        n.children[T_FACE_PROC_FACE_MASK[direction][i][j] as usize].as_ref();
-                }
-            }
-            process_face(
-                &face_nodes,
-                T_FACE_PROC_FACE_MASK[direction][i][2] as usize,
-                indexes,
-                tri_count,
-                threshold,
-                enforce_manifold,
-            );
-        }
-        let orders = [[0, 0, 1, 1], [0, 1, 0, 1]];
-        for i in 0..4 {
-            let mut edge_nodes = [None, None, None, None];
-            for j in 0..4 {
-                let order_idx = T_FACE_PROC_EDGE_MASK[direction][i][0];
-                let node_idx = orders[order_idx as usize][j];
-                if nodes[node_idx].is_none() {
-                    continue;
-                }
-                let n = nodes[node_idx].unwrap();
-                if n.node_type == OctreeNodeType::NodeLeaf {
-                    edge_nodes[j] = Some(n);
-                } else {
-                    edge_nodes[j] =
-                        n.children[T_FACE_PROC_EDGE_MASK[direction][i][1 + j] as usize].as_ref();
-                }
-            }
-            process_edge(
-                &edge_nodes,
-                T_FACE_PROC_EDGE_MASK[direction][i][5] as usize,
-                indexes,
-                tri_count,
-                threshold,
-                enforce_manifold,
-            );
-        }
+    for mask in &T_FACE_PROC_FACE_MASK[direction] {
+        let face_nodes = [0, 1].map(|j| nodes[j].and_then(|n| n.child_or_self(mask[j])));
+        process_face(
+            &face_nodes,
+            mask[2] as usize,
+            vertices,
+            indexes,
+            tri_count,
+            threshold,
+            enforce_manifold,
+        );
+    }
+    let orders = [[0, 0, 1, 1], [0, 1, 0, 1]];
+    for mask in &T_FACE_PROC_EDGE_MASK[direction] {
+        let order = orders[mask[0] as usize];
+        let edge_nodes =
+            [0, 1, 2, 3].map(|j| nodes[order[j]].and_then(|n| n.child_or_self(mask[1 + j])));
+        process_edge(
+            &edge_nodes,
+            mask[5] as usize,
+            vertices,
+            indexes,
+            tri_count,
+            threshold,
+            enforce_manifold,
+        );
     }
 }
 
-pub(crate) fn cluster_cell_base(node: &mut MdcOctreeNode, error: f32) {
-    if node.node_type != OctreeNodeType::NodeInternal {
+pub(crate) fn process_edge(
+    nodes: &[Option<&MdcOctreeNode>; 4],
+    direction: usize,
+    vertices: &[MdcVertex],
+    indexes: &mut Vec<u32>,
+    tri_count: &mut Vec<i32>,
+    threshold: f32,
+    enforce_manifold: bool,
+) {
+    if nodes.iter().any(|n| n.is_none()) {
         return;
     }
-    for i in 0..8 {
-        if let Some(child) = &mut node.children[i] {
-            cluster_cell(child, error);
-        }
-    }
-}
-pub(crate) fn cluster_cell(node: &mut MdcOctreeNode, error: f32) {
-    use std::collections::HashMap;
-    if node.node_type != OctreeNodeType::NodeInternal {
+    if nodes
+        .iter()
+        .all(|n| n.is_some_and(|n| n.node_type == OctreeNodeType::NodeLeaf))
+    {
+        process_indexes(
+            nodes,
+            direction,
+            vertices,
+            indexes,
+            tri_count,
+            threshold,
+            enforce_manifold,
+        );
         return;
     }
-    let mut signs = [-1; 8];
-    let mut mid_sign = -1;
-    for i in 0..8 {
-        if node.children[i].is_none() {
+    for mask in &T_EDGE_PROC_EDGE_MASK[direction] {
+        let edge_nodes = [0, 1, 2, 3].map(|j| nodes[j].and_then(|n| n.child_or_self(mask[j])));
+        process_edge(
+            &edge_nodes,
+            mask[4] as usize,
+            vertices,
+            indexes,
+            tri_count,
+            threshold,
+            enforce_manifold,
+        );
+    }
+}
+
+pub(crate) fn process_indexes(
+    nodes: &[Option<&MdcOctreeNode>; 4],
+    direction: usize,
+    vertices: &[MdcVertex],
+    indexes: &mut Vec<u32>,
+    tri_count: &mut Vec<i32>,
+    threshold: f32,
+    enforce_manifold: bool,
+) {
+    let mut min_size = i32::MAX;
+    let mut indices = [-1i32; 4];
+    let mut flip = false;
+    let mut sign_changed = false;
+    for i in 0..4 {
+        let Some(node) = nodes[i] else {
             continue;
+        };
+        let edge = T_PROCESS_EDGE_MASK[direction][i] as usize;
+        let m1 = (node.corners >> T_EDGE_PAIRS[edge][0]) & 1;
+        let m2 = (node.corners >> T_EDGE_PAIRS[edge][1]) & 1;
+        if node.size < min_size {
+            min_size = node.size;
+            flip = m1 == 1;
+            sign_changed = m1 != m2;
         }
-        if let Some(child) = &mut node.children[i] {
-            cluster_cell(child, error);
-            if child.node_type != OctreeNodeType::NodeInternal {
-                mid_sign = ((child.corners >> (7 - i)) & 1) as i32;
-                signs[i] = ((child.corners >> i) & 1) as i32;
+        let (index, found) = node.edge_vertex(edge);
+        if !found {
+            continue;
+        }
+        let Some(&vertex) = node.vertices.get(index) else {
+            return;
+        };
+        // Climb to the highest ancestor that is accurate enough and, if required, manifold.
+        let mut highest = vertex;
+        while let Some(parent) = vertices[highest].parent {
+            let p = &vertices[parent];
+            if p.error <= threshold && (!enforce_manifold || (p.euler == 1 && p.face_prop2)) {
+                highest = parent;
+            } else {
+                break;
             }
         }
+        indices[i] = highest as i32;
     }
-    node.corners = 0;
-    for i in 0..8 {
-        if signs[i] == -1 {
-            node.corners |= (mid_sign << i) as u32;
-        } else {
-            node.corners |= (signs[i] << i) as u32;
+    if !sign_changed {
+        return;
+    }
+    let triangles = if flip {
+        [[0, 3, 1], [0, 2, 3]]
+    } else {
+        [[0, 1, 3], [0, 3, 2]]
+    };
+    let mut count = 0;
+    for triangle in triangles {
+        let [a, b, c] = triangle.map(|k| indices[k]);
+        if a != -1 && b != -1 && c != -1 && a != b && b != c && a != c {
+            indexes.extend_from_slice(&[a as u32, b as u32, c as u32]);
+            count += 1;
+        }
+    }
+    if count > 0 {
+        tri_count.push(count);
+    }
+}
+
+/// Clusters the top-level vertices of already clustered children into `node`'s vertices.
+pub(crate) fn cluster_children(
+    node: &mut MdcOctreeNode,
+    voxel_size: f32,
+    qef: &QefSolveOptions,
+    vertices: &mut Vec<MdcVertex>,
+) {
+    let mut signs = [-1i32; 8];
+    let mut mid_sign = -1i32;
+    for (i, child) in node.children.iter().enumerate() {
+        if let Some(child) = child
+            && child.node_type != OctreeNodeType::NodeInternal
+        {
+            mid_sign = ((child.corners >> (7 - i)) & 1) as i32;
+            signs[i] = ((child.corners >> i) & 1) as i32;
         }
     }
+    node.corners = 0;
+    for (i, sign) in signs.iter().enumerate() {
+        let sign = if *sign == -1 { mid_sign } else { *sign };
+        node.corners |= (sign << i) as u8;
+    }
     let mut surface_index = 0;
-    let mut collected_vertices: Vec<Box<MdcVertex>> = Vec::new();
-    for i in 0..12 {
-        let c1 = T_EDGE_PAIRS[i][0];
-        let c2 = T_EDGE_PAIRS[i][1];
+    let mut collected_vertices: Vec<usize> = Vec::new();
+    for pair in &T_EDGE_PAIRS {
         let face_nodes = [
-            node.children[c1 as usize].as_ref(),
-            node.children[c2 as usize].as_ref(),
+            node.children[pair[0] as usize].as_deref(),
+            node.children[pair[1] as usize].as_deref(),
         ];
         cluster_face(
             &face_nodes,
-            T_EDGE_PAIRS[i][2] as usize,
+            pair[2] as usize,
             &mut surface_index,
             &mut collected_vertices,
+            vertices,
         );
     }
-    for i in 0..6 {
-        let edge_nodes = [
-            node.children[T_CELL_PROC_EDGE_MASK[i][0] as usize].as_ref(),
-            node.children[T_CELL_PROC_EDGE_MASK[i][1] as usize].as_ref(),
-            node.children[T_CELL_PROC_EDGE_MASK[i][2] as usize].as_ref(),
-            node.children[T_CELL_PROC_EDGE_MASK[i][3] as usize].as_ref(),
-        ];
+    for mask in &T_CELL_PROC_EDGE_MASK {
+        let edge_nodes = [0, 1, 2, 3].map(|j| node.children[mask[j] as usize].as_deref());
         cluster_edge(
             &edge_nodes,
-            T_CELL_PROC_EDGE_MASK[i][4] as usize,
+            mask[4] as usize,
             &mut surface_index,
             &mut collected_vertices,
+            vertices,
         );
     }
-    let mut highest_index = surface_index;
-    if highest_index == -1 {
-        highest_index = 0;
-    }
-    for child_opt in &mut node.children {
-        if let Some(child) = child_opt {
-            for v_opt in &mut child.vertices {
-                if let Some(v) = v_opt {
-                    if v.surface_index == -1 {
-                        v.surface_index = highest_index;
-                        highest_index += 1;
-                        collected_vertices.push(v.clone());
-                    }
-                }
+    let mut highest_index = surface_index.max(0);
+    for child in node.children.iter().flatten() {
+        for &v in &child.vertices {
+            if vertices[v].surface_index == -1 {
+                vertices[v].surface_index = highest_index;
+                highest_index += 1;
+                collected_vertices.push(v);
             }
         }
     }
-    if collected_vertices.is_empty() {
-        return;
-    }
-    let mut surface_vertices: HashMap<i32, Vec<Box<MdcVertex>>> = HashMap::new();
-    for v in &collected_vertices {
-        surface_vertices
-            .entry(v.surface_index)
-            .or_insert_with(Vec::new)
-            .push(v.clone());
+    node.vertices.clear();
+    let mut surfaces = vec![Vec::new(); highest_index as usize + 1];
+    for &v in &collected_vertices {
+        surfaces[vertices[v].surface_index as usize].push(v);
     }
-    let mut new_vertices: Vec<Box<MdcVertex>> = Vec::new();
-    for (_, verts_for_surface) in surface_vertices.iter() {
-        if verts_for_surface.is_empty() {
-            continue;
-        }
-        let mut qef = QefData::new(LevenQefSolver::new());
+    for surface in surfaces.iter().filter(|s| !s.is_empty()) {
+        let mut new_vertex = MdcVertex::new();
         let mut normal = Vec3::ZERO;
-        let mut count = 0;
         let mut edges = [0; 12];
         let mut euler = 0;
         let mut e = 0;
-        for v in verts_for_surface {
-            for k in 0..3 {
-                let edge_idx = T_EXTERNAL_EDGES[v.in_cell as usize][k];
-                edges[edge_idx as usize] += v.eis[edge_idx as usize];
+        for &v in surface {
+            let v = &vertices[v];
+            for edge in T_EXTERNAL_EDGES[v.in_cell as usize] {
+                edges[edge as usize] += v.eis[edge as usize];
             }
-            for k in 0..9 {
-                let edge_idx = T_INTERNAL_EDGES[v.in_cell as usize][k];
-                e += v.eis[edge_idx as usize];
+            for edge in T_INTERNAL_EDGES[v.in_cell as usize] {
+                e += v.eis[edge as usize];
             }
             euler += v.euler;
-            if let Some(qef_solver) = &v.qef {
-                qef.add(&qef_solver);
-            }
-            normal = normal + v.normal;
-            count += 1;
-        }
-        if count == 0 {
-            continue;
-        }
-        let mut face_prop2 = true;
-        for f in 0..6 {
-            if !face_prop2 {
-                break;
-            }
-            let mut intersections = 0;
-            for ei_idx in 0..4 {
-                intersections += edges[T_FACES[f][ei_idx] as usize];
-            }
-            if !(intersections == 0 || intersections == 2) {
-                face_prop2 = false;
-            }
-        }
-        let mut new_vertex = MdcVertex::new();
-        normal = normal / count as f32;
-        normal = normal.normalize();
-        new_vertex.normal = normal;
-        new_vertex.qef = Some(qef);
+            new_vertex.qef.add(&v.qef);
+            normal += v.normal;
+        }
+        // A coarse edge crossed twice hides a feature the clustered vertex cannot keep.
+        let face_prop2 = edges.iter().all(|e| *e <= 1)
+            && T_FACES.iter().all(|face| {
+                let intersections: i32 = face.iter().map(|&edge| edges[edge as usize]).sum();
+                intersections == 0 || intersections == 2
+            });
+        new_vertex.normal = (normal / surface.len() as f32).normalize();
         new_vertex.eis = edges;
         new_vertex.euler = euler - e / 4;
         new_vertex.in_cell = node.child_index;
         new_vertex.face_prop2 = face_prop2;
-        if let Some(qef) = &mut new_vertex.qef {
-            let pos_vec4 = qef.solve();
-            new_vertex.pos = Vec3::new(pos_vec4.x, pos_vec4.y, pos_vec4.z);
-            let err = qef.get_error();
-            new_vertex.collapsible = err <= error;
-            new_vertex.error = err;
+        new_vertex.solve_in_cell(node.min, node.size as f32 * voxel_size, qef);
+        let new_index = vertices.len();
+        vertices.push(new_vertex);
+        for &v in surface {
+            vertices[v].parent = Some(new_index);
         }
-        new_vertices.push(Box::new(new_vertex));
+        node.vertices.push(new_index);
+    }
+    for &v in &collected_vertices {
+        vertices[v].surface_index = -1;
     }
-    node.vertices = new_vertices.iter().map(|v| Some(v.clone())).collect();
 }
 
 pub(crate) fn cluster_face(
-    nodes: &[Option<&Box<MdcOctreeNode>>; 2],
+    nodes: &[Option<&MdcOctreeNode>; 2],
     direction: usize,
-    int_holder: &mut i32,
-    collected_vertices: &mut Vec<Box<MdcVertex>>,
+    surface_index: &mut i32,
+    collected_vertices: &mut Vec<usize>,
+    vertices: &mut [MdcVertex],
 ) {
-    if nodes[0].is_none() || nodes[1].is_none() {
+    let (Some(node0), Some(node1)) = (nodes[0], nodes[1]) else {
         return;
-    }
-    let n0 = nodes[0].unwrap();
-    let n1 = nodes[1].unwrap();
-    if n0.node_type != OctreeNodeType::NodeLeaf || n1.node_type != OctreeNodeType::NodeLeaf {
-        for i in 0..4 {
-            let mut face_nodes = [None, None];
-            for j in 0..2 {
-                if nodes[j].is_none() {
-                    continue;
-                }
-                let n = nodes[j].unwrap();
-                if n.node_type != OctreeNodeType::NodeInternal {
-                    face_nodes[j] = Some(n);
-                } else {
-                    face_nodes[j] =
-                        n.children[T_FACE_PROC_FACE_MASK[direction][i][j] as usize].as_ref();
-                }
-            }
+    };
+    if node0.node_type != OctreeNodeType::NodeLeaf || node1.node_type != OctreeNodeType::NodeLeaf {
+        for mask in &T_FACE_PROC_FACE_MASK[direction] {
+            let face_nodes = [0, 1].map(|j| nodes[j].and_then(|n| n.child_or_self(mask[j])));
             cluster_face(
                 &face_nodes,
-                T_FACE_PROC_FACE_MASK[direction][i][2] as usize,
-                int_holder,
+                mask[2] as usize,
+                surface_index,
                 collected_vertices,
+                vertices,
             );
         }
     }
     let orders = [[0, 0, 1, 1], [0, 1, 0, 1]];
-    for i in 0..4 {
-        let mut edge_nodes = [None, None, None, None];
-        for j in 0..4 {
-            let order_idx = T_FACE_PROC_EDGE_MASK[direction][i][0];
-            let node_idx = orders[order_idx as usize][j];
-            if nodes[node_idx].is_none() {
-                continue;
-            }
-            let n = nodes[node_idx].unwrap();
-            if n.node_type != OctreeNodeType::NodeInternal {
-                edge_nodes[j] = Some(n);
-            } else {
-                edge_nodes[j] =
-                    n.children[T_FACE_PROC_EDGE_MASK[direction][i][1 + j] as usize].as_ref();
-            }
-        }
+    for mask in &T_FACE_PROC_EDGE_MASK[direction] {
+        let order = orders[mask[0] as usize];
+        let edge_nodes =
+            [0, 1, 2, 3].map(|j| nodes[order[j]].and_then(|n| n.child_or_self(mask[1 + j])));
         cluster_edge(
             &edge_nodes,
-            T_FACE_PROC_EDGE_MASK[direction][i][5] as usize,
-            int_holder,
+            mask[5] as usize,
+            surface_index,
             collected_vertices,
+            vertices,
         );
     }
 }
+
 pub(crate) fn cluster_edge(
-    nodes: &[Option<&Box<MdcOctreeNode>>; 4],
+    nodes: &[Option<&MdcOctreeNode>; 4],
     direction: usize,
-    int_holder: &mut i32,
-    collected_vertices: &mut Vec<Box<MdcVertex>>,
+    surface_index: &mut i32,
+    collected_vertices: &mut Vec<usize>,
+    vertices: &mut [MdcVertex],
 ) {
-    let all_non_internal = (nodes[0].is_none()
-        || nodes[0].unwrap().node_type != OctreeNodeType::NodeInternal)
-        && (nodes[1].is_none() || nodes[1].unwrap().node_type != OctreeNodeType::NodeInternal)
-        && (nodes[2].is_none() || nodes[2].unwrap().node_type != OctreeNodeType::NodeInternal)
-        && (nodes[3].is_none() || nodes[3].unwrap().node_type != OctreeNodeType::NodeInternal);
-    if all_non_internal {
-        cluster_indexes(nodes, direction, int_holder, collected_vertices);
-    } else {
-        for i in 0..2 {
-            let mut edge_nodes = [None, None, None, None];
-            for j in 0..4 {
-                if nodes[j].is_none() {
-                    continue;
-                }
-                let n = nodes[j].unwrap();
-                if n.node_type == OctreeNodeType::NodeLeaf {
-                    edge_nodes[j] = Some(n);
-                } else {
-                    edge_nodes[j] =
-                        n.children[T_EDGE_PROC_EDGE_MASK[direction][i][j] as usize].as_ref();
-                }
-            }
-            cluster_edge(
-                &edge_nodes,
-                T_EDGE_PROC_EDGE_MASK[direction][i][4] as usize,
-                int_holder,
-                collected_vertices,
-            );
-        }
+    if nodes.iter().any(|n| n.is_none()) {
+        return;
+    }
+    if nodes
+        .iter()
+        .all(|n| n.is_some_and(|n| n.node_type == OctreeNodeType::NodeLeaf))
+    {
+        cluster_indexes(
+            nodes,
+            direction,
+            surface_index,
+            collected_vertices,
+            vertices,
+        );
+        return;
+    }
+    for mask in &T_EDGE_PROC_EDGE_MASK[direction] {
+        let edge_nodes = [0, 1, 2, 3].map(|j| nodes[j].and_then(|n| n.child_or_self(mask[j])));
+        cluster_edge(
+            &edge_nodes,
+            mask[4] as usize,
+            surface_index,
+            collected_vertices,
+            vertices,
+        );
     }
 }
 
 pub(crate) fn cluster_indexes(
-    nodes: &[Option<&Box<MdcOctreeNode>>; 4],
+    nodes: &[Option<&MdcOctreeNode>; 4],
     direction: usize,
-    int_holder: &mut i32,
-    collected_vertices: &mut Vec<Box<MdcVertex>>,
+    max_surface_index: &mut i32,
+    collected_vertices: &mut Vec<usize>,
+    vertices: &mut [MdcVertex],
 ) {
-    if nodes[0].is_none() && nodes[1].is_none() && nodes[2].is_none() && nodes[3].is_none() {
-        return;
-    }
-    let mut vertices: [Option<&Box<MdcVertex>>; 4] = [None, None, None, None];
-    let mut v_count = 0;
+    let mut edge_vertices = [None; 4];
     for i in 0..4 {
-        if nodes[i].is_none() {
+        let Some(node) = nodes[i] else {
+            continue;
+        };
+        let edge = T_PROCESS_EDGE_MASK[direction][i] as usize;
+        let m1 = (node.corners >> T_EDGE_PAIRS[edge][0]) & 1;
+        let m2 = (node.corners >> T_EDGE_PAIRS[edge][1]) & 1;
+        let (index, found) = node.edge_vertex(edge);
+        if !found && m1 == m2 {
             continue;
         }
-        let n = nodes[i].unwrap();
-        let edge = T_PROCESS_EDGE_MASK[direction][i];
-        let c1 = T_EDGE_PAIRS[edge as usize][0];
-        let c2 = T_EDGE_PAIRS[edge as usize][1];
-        let m1 = ((n.corners >> c1) & 1) as i32;
-        let m2 = ((n.corners >> c2) & 1) as i32;
-        let mut index = 0;
-        let mut skip = false;
-        for k in 0..16 {
-            let e = TRANSFORMED_EDGES_TABLE[n.corners as usize][k];
-            if e == -1 {
-                index += 1;
-                continue;
-            }
-            if e == -2 {
-                if !((m1 == 0 && m2 != 0) || (m1 != 0 && m2 == 0)) {
-                    skip = true;
-                }
-                break;
-            }
-            if e == edge as i32 {
-                break;
-            }
-        }
-        if !skip && index < n.vertices.len() {
-            if let Some(v_opt) = &n.vertices[index] {
-                let mut v = v_opt.as_ref();
-                while let Some(parent) = &v.parent {
-                    v = parent.as_ref();
-                }
-                vertices[i] = Some(v_opt);
-                v_count += 1;
-            }
+        let Some(&vertex) = node.vertices.get(index) else {
+            continue;
+        };
+        let mut root = vertex;
+        while let Some(parent) = vertices[root].parent {
+            root = parent;
         }
+        edge_vertices[i] = Some(root);
     }
-    if v_count == 0 {
+    if edge_vertices.iter().all(|v| v.is_none()) {
         return;
     }
     let mut surface_index = -1;
-    for i in 0..4 {
-        if let Some(v) = vertices[i] {
-            if v.surface_index != -1 {
-                if surface_index != -1 && surface_index != v.surface_index {
-                    assign_surface(collected_vertices, v.surface_index, surface_index);
-                } else if surface_index == -1 {
-                    surface_index = v.surface_index;
-                }
-            }
+    for v in edge_vertices.into_iter().flatten() {
+        let current = vertices[v].surface_index;
+        if current != -1 && surface_index != -1 && surface_index != current {
+            assign_surface(collected_vertices, vertices, current, surface_index);
+        } else if current != -1 && surface_index == -1 {
+            surface_index = current;
         }
     }
     if surface_index == -1 {
-        surface_index = *int_holder;
-        *int_holder += 1;
+        surface_index = *max_surface_index;
+        *max_surface_index += 1;
     }
-    for i in 0..4 {
-        if let Some(v) = vertices[i] {
-            if v.surface_index == -1 {
-                collected_vertices.push(v.clone());
-            }
+    for v in edge_vertices.into_iter().flatten() {
+        if vertices[v].surface_index == -1 {
+            collected_vertices.push(v);
         }
-    }
-    for v in collected_vertices.iter_mut() {
-        v.surface_index = surface_index;
+        vertices[v].surface_index = surface_index;
     }
 }
 
-fn assign_surface(vertices: &mut Vec<Box<MdcVertex>>, from: i32, to: i32) {
-    for v in vertices.iter_mut() {
-        if v.surface_index == from {
-            v.surface_index = to;
+fn assign_surface(collected: &[usize], vertices: &mut [MdcVertex], from: i32, to: i32) {
+    for &v in collected {
+        if vertices[v].surface_index == from {
+            vertices[v].surface_index = to;
         }
     }
 }
 
 pub(crate) struct MdcOctreeNode {
-    pub(crate) min: IVec3,
+    /// World-space corner; the node spans `size` cells from here.
+    pub(crate) min: Vec3,
     pub(crate) size: i32,
     pub(crate) node_type: OctreeNodeType,
     pub(crate) index: i32,
     pub(crate) child_index: i32,
-    pub(crate) corners: u32,
+    pub(crate) corners: u8,
     pub(crate) children: [Option<Box<MdcOctreeNode>>; 8],
-    pub(crate) vertices: Vec<Option<Box<MdcVertex>>>,
+    /// Indices into the vertex arena.
+    pub(crate) vertices: Vec<usize>,
 }
+
 impl MdcOctreeNode {
-    pub(crate) fn new(min: IVec3, size: i32, node_type: OctreeNodeType, child_index: i32) -> Self {
+    pub(crate) fn new(min: Vec3, size: i32, node_type: OctreeNodeType, child_index: i32) -> Self {
         MdcOctreeNode {
             min,
             size,
@@ -679,12 +791,52 @@ impl MdcOctreeNode {
             vertices: Vec::new(),
         }
     }
+
+    fn is_empty(&self) -> bool {
+        self.vertices.is_empty() && self.children.iter().all(|c| c.is_none())
+    }
+
+    /// Shifts the subtree's arena indices after its arena was appended at `offset`.
+    fn offset_vertices(&mut self, offset: usize) {
+        for v in &mut self.vertices {
+            *v += offset;
+        }
+        for child in self.children.iter_mut().flatten() {
+            child.offset_vertices(offset);
+        }
+    }
+
+    /// Leaves stand in for all of their would-be children during the dual traversal.
+    fn child_or_self(&self, child: i32) -> Option<&MdcOctreeNode> {
+        if self.node_type == OctreeNodeType::NodeLeaf {
+            Some(self)
+        } else {
+            self.children[child as usize].as_deref()
+        }
+    }
+
+    /// Index of the vertex owning `edge` in this node's corner configuration, and whether the
+    /// edge was found at all; if not, the index is that of the last vertex.
+    fn edge_vertex(&self, edge: usize) -> (usize, bool) {
+        let mut index = 0;
+        for &e in &TRANSFORMED_EDGES_TABLE[self.corners as usize] {
+            if e == -1 {
+                index += 1;
+            } else if e == -2 {
+                return (index, false);
+            } else if e == edge as i32 {
+                return (index, true);
+            }
+        }
+        (index, false)
+    }
 }
+
+#[derive(Clone)]
 pub(crate) struct MdcVertex {
-    pub(crate) parent: Option<Box<MdcVertex>>,
-    pub(crate) index: i32,
-    pub(crate) collapsible: bool,
-    pub(crate) qef: Option<QefData>,
+    /// Arena index of the cluster this vertex was merged into.
+    pub(crate) parent: Option<usize>,
+    pub(crate) qef: QefData,
     pub(crate) pos: Vec3,
     pub(crate) normal: Vec3,
     pub(crate) surface_index: i32,
@@ -693,218 +845,137 @@ pub(crate) struct MdcVertex {
     pub(crate) eis: [i32; 12],
     pub(crate) in_cell: i32,
     pub(crate) face_prop2: bool,
-    pub(crate) debug_flag: bool,
 }
+
 impl MdcVertex {
     pub(crate) fn new() -> Self {
         MdcVertex {
-            qef: None,
-            normal: Vec3::ZERO,
+            parent: None,
+            qef: QefData::new(),
             pos: Vec3::ZERO,
+            normal: Vec3::ZERO,
+            surface_index: -1,
             error: 0.0,
-            index: 0,
-            parent: None,
-            collapsible: false,
             euler: 0,
             eis: [0; 12],
             in_cell: 0,
             face_prop2: false,
-            surface_index: -1,
-            debug_flag: false,
-        }
-    }
-}
-impl Clone for MdcVertex {
-    fn clone(&self) -> Self {
-        MdcVertex {
-            qef: self.qef.clone(),
-            normal: self.normal,
-            pos: self.pos,
-            error: self.error,
-            index: self.index,
-            parent: self.parent.as_ref().map(|p| p.clone()),
-            collapsible: self.collapsible,
-            euler: self.euler,
-            eis: self.eis.clone(),
-            in_cell: self.in_cell,
-            face_prop2: self.face_prop2,
-            surface_index: self.surface_index,
-            debug_flag: self.debug_flag,
         }
     }
-}
-impl PartialEq for MdcVertex {
-    fn eq(&self, other: &Self) -> bool {
-        self.index == other.index && self.pos == other.pos
+
+    /// Solves the QEF, falling back to the mass point when the minimiser leaves the cell
+    /// `min..=min + extent`. The error is measured at the position actually used.
+    pub(crate) fn solve_in_cell(&mut self, min: Vec3, extent: f32, options: &QefSolveOptions) {
+        let solved = self.qef.solve(options);
+        let max = min + Vec3::splat(extent);
+        self.pos = if solved.is_finite() && solved.cmpge(min).all() && solved.cmple(max).all() {
+            solved
+        } else {
+            self.qef.average_point()
+        };
+        self.error = self.qef.get_error_for(self.pos.extend(0.0));
     }
 }
 
-pub(crate) fn process_edge(
-    nodes: &[Option<&Box<MdcOctreeNode>>; 4],
-    direction: usize,
-    indexes: &mut Vec<i32>,
-    tri_count: &mut Vec<i32>,
-    threshold: f32,
-    enforce_manifold: bool,
-) {
-    if nodes[0].is_none() || nodes[1].is_none() || nodes[2].is_none() || nodes[3].is_none() {
-        return;
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::{
+        manifold_dual_contouring::{
+            mdc::{GridBounds, MeshBuffers},
+            sampler::{CuboidSampler, Sampler, SphereSampler},
+        },
+        manifold_dual_contouring_2::mdc::mdc_mesh_generation,
+        validate::{
+            golden::{GoldenTolerance, MeshSnapshot, assert_golden, hausdorff_distance},
+            mesh::assert_mdc_is_manifold,
+        },
+    };
+
+    #[test]
+    fn test_mdc_sphere_no_flat_shading() {
+        let resolution = 32;
+        let bounding_width = 40.0;
+        let mut mesh_buffers = MeshBuffers::new();
+        let sphere = SphereSampler::new(Vec3::new(0.0, 0.0, 0.0), 20.0);
+        mdc_mesh_generation(
+            0.5,
+            &mut mesh_buffers,
+            false,
+            GridBounds::centered(bounding_width, resolution),
+            true,
+            &sphere,
+        );
+        let snapshot = MeshSnapshot::new(&mesh_buffers.positions, &mesh_buffers.indices)
+            .with_attribute("normals", &mesh_buffers.normals)
+            .with_attribute("colors", &mesh_buffers.colors);
+        assert_golden(
+            "mdc2_sphere",
+            &snapshot,
+            &GoldenTolerance::for_voxel_size(bounding_width / resolution as f32),
+        );
     }
-    if nodes[0].unwrap().node_type == OctreeNodeType::NodeLeaf
-        && nodes[1].unwrap().node_type == OctreeNodeType::NodeLeaf
-        && nodes[2].unwrap().node_type == OctreeNodeType::NodeLeaf
-        && nodes[3].unwrap().node_type == OctreeNodeType::NodeLeaf
-    {
-        process_indexes(
-            nodes,
-            direction,
-            indexes,
-            tri_count,
-            threshold,
-            enforce_manifold,
+
+    fn assert_matches_v1<S: Sampler + Send + Sync + 'static>(sampler: S) {
+        let bounds = GridBounds::centered(64.0, 64);
+        // Threshold 0 keeps every leaf vertex, so both versions triangulate the same cells.
+        let mut v1 = MeshBuffers::new();
+        crate::manifold_dual_contouring::mdc::mdc_mesh_generation(
+            0.0, &mut v1, false, bounds, true, &sampler,
         );
-    } else {
-        for i in 0..2 {
-            let mut edge_nodes = [None, None, None, None];
-            for j in 0..4 {
-                if nodes[j].is_none() {
-                    continue;
-                }
-                let n = nodes[j].unwrap();
-                if n.node_type == OctreeNodeType::NodeLeaf {
-                    edge_nodes[j] = Some(n);
-                } else {
-                    edge_nodes[j] =
-                        n.children[T_EDGE_PROC_EDGE_MASK[direction][i][j] as usize].as_ref();
-                }
-            }
-            process_edge(
-                &edge_nodes,
-                T_EDGE_PROC_EDGE_MASK[direction][i][4] as usize,
-                indexes,
-                tri_count,
-                threshold,
-                enforce_manifold,
-            );
-        }
+        let mut v2 = MeshBuffers::new();
+        mdc_mesh_generation(0.0, &mut v2, false, bounds, true, &sampler);
+        assert_eq!(v2.positions.len(), v1.positions.len());
+        let (v1_tris, v2_tris) = (v1.indices.len() / 3, v2.indices.len() / 3);
+        assert!(v2_tris.abs_diff(v1_tris) * 50 <= v1_tris);
+        let distance = hausdorff_distance(
+            &v1.positions,
+            &v1.indices,
+            &v2.positions,
+            &v2.indices,
+            bounds.cell_size,
+        );
+        assert!(distance <= 0.1 * bounds.cell_size, "{distance}");
     }
-}
-pub(crate) fn process_indexes(
-    nodes: &[Option<&Box<MdcOctreeNode>>; 4],
-    direction: usize,
-    indexes: &mut Vec<i32>,
-    tri_count: &mut Vec<i32>,
-    threshold: f32,
-    enforce_manifold: bool,
-) {
-    let mut min_size = 10000000;
-    let mut indices = [-1, -1, -1, -1];
-    let mut flip = false;
-    let mut sign_changed = false;
-    for i in 0..4 {
-        if nodes[i].is_none() {
-            continue;
-        }
-        let n = nodes[i].unwrap();
-        let edge = T_PROCESS_EDGE_MASK[direction][i];
-        let c1 = T_EDGE_PAIRS[edge as usize][0];
-        let c2 = T_EDGE_PAIRS[edge as usize][1];
-        let m1 = ((n.corners >> c1) & 1) as i32;
-        let m2 = ((n.corners >> c2) & 1) as i32;
-        if n.size < min_size {
-            min_size = n.size;
-            flip = m1 == 1;
-            sign_changed = (m1 == 0 && m2 != 0) || (m1 != 0 && m2 == 0);
-        }
-        let mut index = 0;
-        let mut skip = false;
-        for k in 0..16 {
-            let e = TRANSFORMED_EDGES_TABLE[n.corners as usize][k];
-            if e == -1 {
-                index += 1;
-                continue;
-            }
-            if e == -2 {
-                skip = true;
-                break;
-            }
-            if e == edge as i32 {
-                break;
-            }
-        }
-        if skip {
-            continue;
-        }
-        if index >= n.vertices.len() {
-            return;
-        }
-        if let Some(v_opt) = &n.vertices[index] {
-            let mut v = v_opt.as_ref();
-            let mut highest = v;
-            while let Some(parent) = &highest.parent {
-                if parent.error <= threshold
-                    && (!enforce_manifold || (parent.euler == 1 && parent.face_prop2))
-                {
-                    highest = parent.as_ref();
-                    v = parent.as_ref();
-                } else {
-                    highest = parent.as_ref();
-                }
-            }
-            indices[i] = v.index;
-        }
+
+    #[test]
+    fn test_mdc_matches_v1() {
+        assert_matches_v1(SphereSampler::new(Vec3::ZERO, 20.0));
+        assert_matches_v1(CuboidSampler::new(Vec3::ZERO, Vec3::new(10.0, 7.0, 5.0)));
     }
-    if sign_changed {
-        let mut count = 0;
-        if !flip {
-            if indices[0] != -1
-                && indices[1] != -1
-                && indices[2] != -1
-                && indices[0] != indices[1]
-                && indices[1] != indices[3]
-            {
-                indexes.push(indices[0]);
-                indexes.push(indices[1]);
-                indexes.push(indices[3]);
-                count += 1;
-            }
-            if indices[0] != -1
-                && indices[2] != -1
-                && indices[3] != -1
-                && indices[0] != indices[2]
-                && indices[2] != indices[3]
-            {
-                indexes.push(indices[0]);
-                indexes.push(indices[3]);
-                indexes.push(indices[2]);
-                count += 1;
-            }
-        } else {
-            if indices[0] != -1
-                && indices[3] != -1
-                && indices[1] != -1
-                && indices[0] != indices[1]
-                && indices[1] != indices[3]
-            {
-                indexes.push(0x10000000 | indices[0]);
-                indexes.push(0x10000000 | indices[3]);
-                indexes.push(0x10000000 | indices[1]);
-                count += 1;
-            }
-            if indices[0] != -1
-                && indices[2] != -1
-                && indices[3] != -1
-                && indices[0] != indices[2]
-                && indices[2] != indices[3]
-            {
-                indexes.push(0x10000000 | indices[0]);
-                indexes.push(0x10000000 | indices[2]);
-                indexes.push(0x10000000 | indices[3]);
-                count += 1;
-            }
-        }
-        if count > 0 {
-            tri_count.push(count);
-        }
+
+    #[test]
+    fn test_mdc_independent_of_thread_count() {
+        let generate = |threads: usize| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let mut mesh_buffers = MeshBuffers::new();
+            pool.install(|| {
+                mdc_mesh_generation(
+                    0.5,
+                    &mut mesh_buffers,
+                    false,
+                    GridBounds::centered(40.0, 32),
+                    true,
+                    &SphereSampler::new(Vec3::ZERO, 20.0),
+                )
+            });
+            mesh_buffers
+        };
+        let serial = generate(1);
+        let parallel = generate(8);
+        assert_eq!(serial.positions, parallel.positions);
+        assert_eq!(serial.normals, parallel.normals);
+        assert_eq!(serial.indices, parallel.indices);
+    }
+
+    #[test]
+    fn test_mdc_is_manifold() {
+        assert_mdc_is_manifold(|threshold, mesh_buffers, bounds, sampler| {
+            mdc_mesh_generation(threshold, mesh_buffers, false, bounds, true, sampler)
+        });
     }
 }
@@ -1,4 +1,3 @@
-pub(crate) mod solver;
 pub(crate) mod qef;
 pub mod octree;
 pub mod mdc;
@@ -1,22 +1,25 @@
 // Rust version of MdcOctreeNode (from Java Manifold Dual Contouring by John Lin20)
 // Equivalent to: manifoldDC.MdcOctreeNode
 
-use glam::Vec3;
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum OctreeNodeType {
     NodeInternal,
     NodeLeaf,
 }
 
+/// Vertex type of the old `(Vec<MeshVertex>, Vec<i32>)` output of `mdc_mesh_generation`.
+#[deprecated(
+    note = "`mdc_mesh_generation` now fills a `MeshBuffers`; read its positions, normals and colors"
+)]
 pub struct MeshVertex {
-    pub pos: Vec3,
-    pub normal: Vec3,
-    pub color: Vec3,
+    pub pos: glam::Vec3,
+    pub normal: glam::Vec3,
+    pub color: glam::Vec3,
 }
 
+#[allow(deprecated)]
 impl MeshVertex {
-    pub fn new(pos: Vec3, normal: Vec3, color: Vec3) -> Self {
+    pub fn new(pos: glam::Vec3, normal: glam::Vec3, color: glam::Vec3) -> Self {
         MeshVertex { pos, normal, color }
     }
 }
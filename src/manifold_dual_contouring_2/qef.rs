@@ -1,26 +1,21 @@
 use glam::{Vec3, Vec4};
-use std::cell::Cell;
 
-use crate::manifold_dual_contouring_2::solver::LevenQefSolver;
+use crate::qef::solver::QefSolveOptions;
 
 #[derive(Clone)]
 pub(crate) struct QefData {
     pub(crate) mat3x3_tri_ata: [f32; 6],
     pub(crate) atb: Vec4,
     pub(crate) mass_point: Vec4,
-    x: Cell<Vec4>,
-    solver: LevenQefSolver,
     btb: f32,
 }
 
 impl QefData {
-    pub(crate) fn new(solver: LevenQefSolver) -> Self {
+    pub(crate) fn new() -> Self {
         QefData {
             mat3x3_tri_ata: [0.0; 6],
             atb: Vec4::ZERO,
             mass_point: Vec4::ZERO,
-            x: Cell::new(Vec4::ZERO),
-            solver,
             btb: 0.0,
         }
     }
@@ -56,19 +51,20 @@ impl QefData {
         self.mass_point.w += 1.0;
     }
 
-    pub(crate) fn solve(&self) -> Vec4 {
-        let result = self
-            .solver
-            .solve(&self.mat3x3_tri_ata, self.atb, self.mass_point);
-        self.x.set(result);
-        result
+    pub(crate) fn solve(&self, options: &QefSolveOptions) -> Vec3 {
+        options.solve(
+            &self.mat3x3_tri_ata,
+            self.atb.truncate(),
+            self.average_point(),
+        )
     }
 
-    pub(crate) fn get_error(&self) -> f32 {
-        self.get_error_for(self.x.get())
+    /// Average of the accumulated intersection points.
+    pub(crate) fn average_point(&self) -> Vec3 {
+        self.mass_point.truncate() / self.mass_point.w.max(1.0)
     }
 
-    fn get_error_for(&self, pos: Vec4) -> f32 {
+    pub(crate) fn get_error_for(&self, pos: Vec4) -> f32 {
         let atax = self.svd_vmul_sym(&self.mat3x3_tri_ata, pos);
         let result = pos.dot(atax) - 2.0 * pos.dot(self.atb) + self.btb;
         result.max(0.0)
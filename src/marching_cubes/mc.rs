@@ -2,7 +2,11 @@ use std::collections::HashMap;
 
 use glam::Vec3;
 
-use crate::marching_cubes::tables::{EDGE_VERTICES, TRIANGLE_TABLE};
+use crate::{
+    manifold_dual_contouring::qef_solver::QEFSolver,
+    marching_cubes::tables::{EDGE_VERTICES, TRIANGLE_TABLE},
+    qef::solver::{QefSolveOptions, QefSolverKind},
+};
 
 pub struct MeshBuffers {
     pub positions: Vec<[f32; 3]>,
@@ -57,6 +61,34 @@ impl VertexCache {
             vertex_index
         }
     }
+
+    /// Adds a vertex that no other cell shares, such as a feature vertex.
+    fn push_vertex(&mut self, position: Vec3, uv: [f32; 2]) -> u32 {
+        let vertex_index = self.vertices.len() as u32;
+        self.vertices.push(position);
+        self.uvs.push(uv);
+        vertex_index
+    }
+}
+
+/// Settings of `extended_mc_mesh_generation`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SharpFeatures {
+    /// A surface patch whose edge normals open wider than this angle, in radians, gets a
+    /// feature vertex. Within a cell of an edge, normals from a baked grid lean about 30 degrees
+    /// towards the other face, so smaller angles also flag flat cells there.
+    pub feature_angle: f32,
+    /// Feature vertices are always kept inside their cell, whatever `bound_to_cell` says.
+    pub qef: QefSolveOptions,
+}
+
+impl Default for SharpFeatures {
+    fn default() -> Self {
+        Self {
+            feature_angle: 40f32.to_radians(),
+            qef: QefSolveOptions::new(QefSolverKind::Double),
+        }
+    }
 }
 
 fn voxel_data_from_index(
@@ -126,6 +158,158 @@ pub fn mc_mesh_generation(
     );
 }
 
+/// Extended Marching Cubes (Kobbelt et al. 2001): like `mc_mesh_generation`, but every surface
+/// patch of a cell whose normals disagree by more than `features.feature_angle` is replaced by a
+/// fan around a QEF-solved feature vertex, so edges and corners of the baked shape stay sharp.
+/// Feature edges are not flipped across cells, so they follow the grid where they run diagonally.
+pub fn extended_mc_mesh_generation(
+    mesh_buffers: &mut MeshBuffers,
+    densities: &[i16],
+    materials: &[u8],
+    samples_per_chunk_dim: usize,
+    half_extent: f32,
+    features: SharpFeatures,
+) {
+    let cubes_per_chunk_dim = samples_per_chunk_dim - 1;
+    let voxel_size = (half_extent * 2.0) / (samples_per_chunk_dim - 1) as f32;
+    let features = SharpFeatures {
+        qef: QefSolveOptions {
+            bound_to_cell: true,
+            ..features.qef
+        },
+        ..features
+    };
+    let normal_at = |point: Vec3| {
+        calculate_vertex_normal(
+            point,
+            densities,
+            samples_per_chunk_dim,
+            half_extent,
+            voxel_size,
+        )
+    };
+    let mut vertex_cache = VertexCache::new();
+    let mut indices = Vec::new();
+    for x in 0..cubes_per_chunk_dim {
+        for y in 0..cubes_per_chunk_dim {
+            for z in 0..cubes_per_chunk_dim {
+                let cube_vertices = get_cube_vertices(x, y, z, half_extent, voxel_size);
+                let cube_values =
+                    sample_cube_values_from_sdf(x, y, z, densities, samples_per_chunk_dim);
+                let cube_index = calculate_cube_index(&cube_values);
+                if cube_index == 0 || cube_index == 255 {
+                    continue;
+                }
+                let triangles = triangulate_cube_with_cache(
+                    cube_index,
+                    &cube_vertices,
+                    &cube_values,
+                    x,
+                    y,
+                    z,
+                    &mut vertex_cache,
+                    materials,
+                    samples_per_chunk_dim,
+                );
+                insert_feature_vertices(
+                    &triangles,
+                    (cube_vertices[0], voxel_size),
+                    &features,
+                    &normal_at,
+                    &mut vertex_cache,
+                    &mut indices,
+                );
+            }
+        }
+    }
+    build_mesh_buffers_from_cache_and_indices(
+        mesh_buffers,
+        vertex_cache,
+        indices,
+        densities,
+        samples_per_chunk_dim,
+        half_extent,
+        voxel_size,
+    );
+}
+
+/// Emits the triangles of one cell, fanning each sharp patch around a feature vertex. `cell` is
+/// the cell's min corner and edge length.
+fn insert_feature_vertices<N: Fn(Vec3) -> Vec3>(
+    triangles: &[[u32; 3]],
+    cell: (Vec3, f32),
+    features: &SharpFeatures,
+    normal_at: &N,
+    vertex_cache: &mut VertexCache,
+    indices: &mut Vec<u32>,
+) {
+    // Triangles sharing a vertex belong to the same patch.
+    let mut patch: Vec<usize> = (0..triangles.len()).collect();
+    for i in 0..triangles.len() {
+        for j in i + 1..triangles.len() {
+            if triangles[i].iter().any(|v| triangles[j].contains(v)) && patch[j] != patch[i] {
+                let (from, to) = (patch[j], patch[i]);
+                patch
+                    .iter_mut()
+                    .filter(|p| **p == from)
+                    .for_each(|p| *p = to);
+            }
+        }
+    }
+    let min_cos = features.feature_angle.cos();
+    for id in 0..triangles.len() {
+        let patch_triangles: Vec<[u32; 3]> = (0..triangles.len())
+            .filter(|&i| patch[i] == id)
+            .map(|i| triangles[i])
+            .collect();
+        if patch_triangles.is_empty() {
+            continue;
+        }
+        let mut patch_vertices: Vec<u32> = patch_triangles.iter().flatten().copied().collect();
+        patch_vertices.sort_unstable();
+        patch_vertices.dedup();
+        let hermite: Vec<(Vec3, Vec3)> = patch_vertices
+            .iter()
+            .map(|&v| {
+                let p = vertex_cache.vertices[v as usize];
+                (p, normal_at(p))
+            })
+            .filter(|(_, n)| *n != Vec3::ZERO)
+            .collect();
+        let sharp = hermite
+            .iter()
+            .enumerate()
+            .any(|(i, (_, a))| hermite[i + 1..].iter().any(|(_, b)| a.dot(*b) < min_cos));
+        if !sharp {
+            patch_triangles
+                .iter()
+                .for_each(|t| indices.extend_from_slice(t));
+            continue;
+        }
+        let mut qef = QEFSolver::new();
+        for (p, n) in &hermite {
+            qef.add(*p, *n);
+        }
+        qef.solve(&features.qef, cell.0, cell.1);
+        let uv = vertex_cache.uvs[patch_vertices[0] as usize];
+        let feature = vertex_cache.push_vertex(qef.x, uv);
+        // The patch boundary is the polygon of its edge crossings; edges shared by two of its
+        // triangles are interior.
+        for t in &patch_triangles {
+            for k in 0..3 {
+                let (a, b) = (t[k], t[(k + 1) % 3]);
+                let shared = patch_triangles
+                    .iter()
+                    .flat_map(|o| [(o[0], o[1]), (o[1], o[2]), (o[2], o[0])])
+                    .any(|edge| edge == (b, a));
+                if !shared {
+                    indices.extend_from_slice(&[a, b, feature]);
+                }
+            }
+        }
+    }
+}
+
 fn calculate_cube_index(values: &[f32; 8]) -> u8 {
     let mut cube_index = 0;
     for i in 0..8 {
@@ -283,16 +467,19 @@ fn get_or_create_edge_vertex(
         materials,
         samples_per_chunk_dim,
     );
+    let material = edge_material(material1, material2);
+    vertex_cache.get_or_create_vertex(edge_id, position, material)
+}
 
-    let material = if material1 == 2 || material2 == 2 {
+/// Material of a vertex on the edge between two voxels: grass (2) wins, then solid materials.
+pub(crate) fn edge_material(material1: u8, material2: u8) -> u8 {
+    if material1 == 2 || material2 == 2 {
         2
     } else if material1 != 0 {
         material1
     } else {
         material2
-    };
-
-    vertex_cache.get_or_create_vertex(edge_id, position, material)
+    }
 }
 
 fn get_canonical_edge_id(edge_index: usize, cube_x: usize, cube_y: usize, cube_z: usize) -> EdgeId {
@@ -415,7 +602,7 @@ fn sample_cube_values_from_sdf(
     ]
 }
 
-fn calculate_vertex_normal(
+pub(crate) fn calculate_vertex_normal(
     point: Vec3,
     densities: &[i16],
     samples_per_chunk_dim: usize,
@@ -545,6 +732,115 @@ fn build_mesh_buffers_from_cache_and_indices(
     mesh_buffers.uvs = vertex_cache.uvs;
 }
 
-fn encode_material_to_uv(material: u8) -> [f32; 2] {
+pub(crate) fn encode_material_to_uv(material: u8) -> [f32; 2] {
     [material as f32, 0.0]
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use glam::Vec3;
+
+    use crate::{
+        manifold_dual_contouring::sampler::{CuboidSampler, SphereSampler},
+        marching_cubes::mc::{
+            MeshBuffers, SharpFeatures, extended_mc_mesh_generation, mc_mesh_generation,
+        },
+        validate::golden::{GoldenTolerance, MeshSnapshot, assert_golden},
+    };
+
+    #[test]
+    fn test_mc_sphere() {
+        let samples = 33;
+        let densities = SphereSampler::new(Vec3::new(0.5, -0.25, 0.0), 11.0).bake_quantized(
+            Vec3::splat(-16.0),
+            Vec3::splat(16.0),
+            (samples, samples, samples),
+        );
+        let materials: Vec<u8> = (0..samples * samples * samples)
+            .map(|i| 1 + (i / (samples * samples) * 2 / samples) as u8)
+            .collect();
+        let mut mesh_buffers = MeshBuffers::new();
+        mc_mesh_generation(&mut mesh_buffers, &densities, &materials, samples, 16.0);
+        let snapshot = MeshSnapshot::new(&mesh_buffers.positions, &mesh_buffers.indices)
+            .with_attribute("normals", &mesh_buffers.normals)
+            .with_attribute("uvs", &mesh_buffers.uvs);
+        assert_golden(
+            "mc_sphere",
+            &snapshot,
+            &GoldenTolerance::for_voxel_size(1.0),
+        );
+    }
+
+    #[test]
+    fn test_extended_mc_keeps_cuboid_corners() {
+        let samples = 33;
+        let center = Vec3::new(0.3, -0.2, 0.45);
+        let size = Vec3::new(9.3, 7.6, 8.2);
+        let densities = CuboidSampler::new(center, size).bake_quantized(
+            Vec3::splat(-16.0),
+            Vec3::splat(16.0),
+            (samples, samples, samples),
+        );
+        let materials = vec![1; samples * samples * samples];
+        // Distance from the furthest cuboid corner to its closest mesh vertex.
+        let corner_error = |mesh_buffers: &MeshBuffers| {
+            (0..8)
+                .map(|i| {
+                    let sign = Vec3::new(
+                        if i & 1 == 0 { -1.0 } else { 1.0 },
+                        if i & 2 == 0 { -1.0 } else { 1.0 },
+                        if i & 4 == 0 { -1.0 } else { 1.0 },
+                    );
+                    let corner = center + sign * size;
+                    mesh_buffers
+                        .positions
+                        .iter()
+                        .map(|p| Vec3::from_array(*p).distance(corner))
+                        .fold(f32::MAX, f32::min)
+                })
+                .fold(0.0, f32::max)
+        };
+        let mut mc = MeshBuffers::new();
+        mc_mesh_generation(&mut mc, &densities, &materials, samples, 16.0);
+        let mut emc = MeshBuffers::new();
+        extended_mc_mesh_generation(
+            &mut emc,
+            &densities,
+            &materials,
+            samples,
+            16.0,
+            SharpFeatures::default(),
+        );
+        // Corners and edges are only as sharp as the baked grid allows: within a cell of an edge
+        // its normals lean towards the other face and its crossings towards the inside. MC's
+        // chamfer misses the corners by about a cell.
+        assert!(corner_error(&emc) < 0.6, "{}", corner_error(&emc));
+        // Feature vertices are the ones MC, which has the same edge crossings, does not have.
+        let crossings: HashSet<[u32; 3]> =
+            mc.positions.iter().map(|p| p.map(f32::to_bits)).collect();
+        let features: Vec<Vec3> = emc
+            .positions
+            .iter()
+            .filter(|p| !crossings.contains(&p.map(f32::to_bits)))
+            .map(|p| Vec3::from_array(*p))
+            .collect();
+        assert!(features.len() > 100);
+        // Distance to the closest of the twelve cuboid edges.
+        let edge_distance = |p: Vec3| {
+            let q = (p - center).abs() - size;
+            [(0, 1, 2), (1, 2, 0), (2, 0, 1)]
+                .map(|(i, j, k)| Vec3::new(q[i], q[j], q[k].max(0.0)).length())
+                .into_iter()
+                .fold(f32::MAX, f32::min)
+        };
+        for p in features {
+            assert!(
+                edge_distance(p) < 0.45,
+                "feature vertex {p} is off the edges"
+            );
+        }
+        assert_eq!(emc.indices.len() % 3, 0);
+    }
+}
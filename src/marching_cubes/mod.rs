@@ -1,3 +1,5 @@
 pub mod mc;
 mod tables;
 pub mod color_provider;
+pub mod surface_nets;
+pub mod marching_tetrahedra;
@@ -0,0 +1,172 @@
+// Naive Surface Nets: one vertex per sign-changing cell, at the average of its edge crossings,
+// and one quad across every sign-changing grid edge. Takes the same grid as `mc_mesh_generation`
+// and fills the same buffers.
+
+use glam::Vec3;
+
+use crate::marching_cubes::mc::{
+    MeshBuffers, calculate_vertex_normal, edge_material, encode_material_to_uv,
+};
+
+/// Cell corners as x, y, z offsets; bit `i` of a corner index is its offset along axis `i`.
+const CORNER_OFFSETS: [[usize; 3]; 8] = [
+    [0, 0, 0],
+    [1, 0, 0],
+    [0, 1, 0],
+    [1, 1, 0],
+    [0, 0, 1],
+    [1, 0, 1],
+    [0, 1, 1],
+    [1, 1, 1],
+];
+
+const CELL_EDGES: [(usize, usize); 12] = [
+    (0, 1),
+    (2, 3),
+    (4, 5),
+    (6, 7),
+    (0, 2),
+    (1, 3),
+    (4, 6),
+    (5, 7),
+    (0, 4),
+    (1, 5),
+    (2, 6),
+    (3, 7),
+];
+
+pub fn surface_nets_mesh_generation(
+    mesh_buffers: &mut MeshBuffers,
+    densities: &[i16],
+    materials: &[u8],
+    samples_per_chunk_dim: usize,
+    half_extent: f32,
+) {
+    let samples = samples_per_chunk_dim;
+    let cells = samples - 1;
+    let voxel_size = (half_extent * 2.0) / cells as f32;
+    let sample_index = |p: [usize; 3]| p[2] * samples * samples + p[1] * samples + p[0];
+    let cell_index = |p: [usize; 3]| p[2] * cells * cells + p[1] * cells + p[0];
+    let mut cell_vertex = vec![u32::MAX; cells * cells * cells];
+    let mut positions = Vec::new();
+    let mut uvs = Vec::new();
+    for z in 0..cells {
+        for y in 0..cells {
+            for x in 0..cells {
+                let corners = CORNER_OFFSETS.map(|d| sample_index([x + d[0], y + d[1], z + d[2]]));
+                let values = corners.map(|i| densities[i] as f32);
+                let inside = values.iter().filter(|v| **v > 0.0).count();
+                if inside == 0 || inside == 8 {
+                    continue;
+                }
+                let min =
+                    Vec3::new(x as f32, y as f32, z as f32) * voxel_size - Vec3::splat(half_extent);
+                let corner_position = |c: usize| {
+                    let d = CORNER_OFFSETS[c];
+                    min + Vec3::new(d[0] as f32, d[1] as f32, d[2] as f32) * voxel_size
+                };
+                let mut sum = Vec3::ZERO;
+                let mut count = 0;
+                let mut material = 0;
+                for (a, b) in CELL_EDGES {
+                    if (values[a] > 0.0) == (values[b] > 0.0) {
+                        continue;
+                    }
+                    let t = -values[a] / (values[b] - values[a]);
+                    sum += corner_position(a).lerp(corner_position(b), t);
+                    count += 1;
+                    material = edge_material(
+                        material,
+                        edge_material(materials[corners[a]], materials[corners[b]]),
+                    );
+                }
+                cell_vertex[cell_index([x, y, z])] = positions.len() as u32;
+                positions.push(sum / count as f32);
+                uvs.push(encode_material_to_uv(material));
+            }
+        }
+    }
+    let mut indices = Vec::new();
+    for z in 0..samples {
+        for y in 0..samples {
+            for x in 0..samples {
+                let start = [x, y, z];
+                for axis in 0..3 {
+                    let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+                    // Only edges with all four surrounding cells inside the grid get a quad.
+                    if start[axis] >= cells
+                        || start[u] == 0
+                        || start[u] >= cells
+                        || start[v] == 0
+                        || start[v] >= cells
+                    {
+                        continue;
+                    }
+                    let mut end = start;
+                    end[axis] += 1;
+                    let start_inside = densities[sample_index(start)] > 0;
+                    if start_inside == (densities[sample_index(end)] > 0) {
+                        continue;
+                    }
+                    let quad = [(1, 1), (0, 1), (0, 0), (1, 0)].map(|(du, dv)| {
+                        let mut cell = start;
+                        cell[u] -= du;
+                        cell[v] -= dv;
+                        cell_vertex[cell_index(cell)]
+                    });
+                    // Wound like `mc_mesh_generation`'s triangles.
+                    let [a, b, c, d] = if start_inside {
+                        [quad[3], quad[2], quad[1], quad[0]]
+                    } else {
+                        quad
+                    };
+                    indices.extend_from_slice(&[a, b, c, a, c, d]);
+                }
+            }
+        }
+    }
+    mesh_buffers.normals = positions
+        .iter()
+        .map(|p| {
+            calculate_vertex_normal(*p, densities, samples, half_extent, voxel_size).to_array()
+        })
+        .collect();
+    mesh_buffers.positions = positions.iter().map(|p| p.to_array()).collect();
+    mesh_buffers.indices = indices;
+    mesh_buffers.uvs = uvs;
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::{
+        manifold_dual_contouring::sampler::SphereSampler,
+        marching_cubes::{mc::MeshBuffers, surface_nets::surface_nets_mesh_generation},
+        validate::mesh::validate_mesh,
+    };
+
+    #[test]
+    fn test_surface_nets_sphere_is_closed() {
+        let samples = 33;
+        let densities = SphereSampler::new(Vec3::ZERO, 10.0).bake_quantized(
+            Vec3::splat(-16.0),
+            Vec3::splat(16.0),
+            (samples, samples, samples),
+        );
+        let materials = vec![1; samples * samples * samples];
+        let mut mesh_buffers = MeshBuffers::new();
+        surface_nets_mesh_generation(&mut mesh_buffers, &densities, &materials, samples, 16.0);
+        let report = validate_mesh(&mesh_buffers.positions, &mesh_buffers.indices, false);
+        assert!(report.is_watertight(), "{report:?}");
+        let mut volume = 0.0;
+        for t in mesh_buffers.indices.chunks(3) {
+            let [a, b, c] =
+                [t[0], t[1], t[2]].map(|i| Vec3::from_array(mesh_buffers.positions[i as usize]));
+            volume += a.dot(b.cross(c)) / 6.0;
+        }
+        let expected = 4.0 / 3.0 * std::f32::consts::PI * 1000.0;
+        assert!((volume - expected).abs() < expected * 0.02, "{volume}");
+        assert!(mesh_buffers.uvs.iter().all(|uv| *uv == [1.0, 0.0]));
+    }
+}
@@ -0,0 +1,189 @@
+// Shading baked from the SDF at every vertex: ambient occlusion from marching out along the SDF
+// gradient, where nearby geometry shows up as samples closer to the surface than the distance
+// marched, plus optionally mean curvature and a thickness estimate from marching inwards.
+// Positions have to be in the sampler's space.
+
+use glam::Vec3;
+
+use crate::manifold_dual_contouring::{
+    mdc,
+    sampler::{Sampler, get_normal},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AoOptions {
+    /// Samples marched along the normal, outwards for occlusion and inwards for thickness.
+    pub steps: u32,
+    /// Distance between samples; occlusion sees geometry up to `steps * step_size` away.
+    pub step_size: f32,
+    /// Scales how dark occluded vertices get.
+    pub strength: f32,
+    pub curvature: bool,
+    pub thickness: bool,
+}
+
+impl Default for AoOptions {
+    fn default() -> Self {
+        Self {
+            steps: 5,
+            step_size: 0.5,
+            strength: 1.0,
+            curvature: false,
+            thickness: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VertexShading {
+    /// 1 for open surroundings, down to 0 for fully occluded vertices.
+    pub occlusion: Vec<f32>,
+    /// Mean curvature, 1 / radius on a sphere: positive on convex, negative on concave surfaces.
+    /// Empty unless requested.
+    pub curvature: Vec<f32>,
+    /// 1 where the solid reaches at least `steps * step_size` inwards, less where it is thinner.
+    /// Empty unless requested.
+    pub thickness: Vec<f32>,
+}
+
+pub fn bake_vertex_shading<S: Sampler>(
+    positions: &[[f32; 3]],
+    sampler: &S,
+    options: &AoOptions,
+) -> VertexShading {
+    let mut shading = VertexShading::default();
+    for position in positions {
+        let p = Vec3::from_array(*position);
+        let normal = get_normal(p, sampler);
+        if !normal.is_finite() {
+            shading.occlusion.push(1.0);
+            shading.curvature.extend(options.curvature.then_some(0.0));
+            shading.thickness.extend(options.thickness.then_some(1.0));
+            continue;
+        }
+        // Every sample's shortfall from the distance marched, halving with each step out.
+        // Measured from the vertex's own distance, so vertices a little off the surface do not
+        // darken.
+        let surface = sampler.sample(p);
+        let mut occlusion = 0.0;
+        let mut weight = 1.0;
+        for i in 1..=options.steps {
+            let distance = i as f32 * options.step_size;
+            let reached = sampler.sample(p + normal * distance) - surface;
+            occlusion += weight * (distance - reached).max(0.0);
+            weight *= 0.5;
+        }
+        let occlusion = 1.0 - options.strength * occlusion / options.step_size;
+        shading.occlusion.push(occlusion.clamp(0.0, 1.0));
+        if options.curvature {
+            // Half the Laplacian of a distance field is its mean curvature.
+            let h = options.step_size;
+            let laplacian = [Vec3::X, Vec3::Y, Vec3::Z]
+                .iter()
+                .map(|axis| sampler.sample(p + axis * h) + sampler.sample(p - axis * h))
+                .sum::<f32>()
+                - 6.0 * surface;
+            shading.curvature.push(laplacian / (2.0 * h * h));
+        }
+        if options.thickness {
+            let depth: f32 = (1..=options.steps)
+                .map(|i| {
+                    let distance = i as f32 * options.step_size;
+                    ((surface - sampler.sample(p - normal * distance)) / distance).clamp(0.0, 1.0)
+                })
+                .sum();
+            shading.thickness.push(depth / options.steps.max(1) as f32);
+        }
+    }
+    shading
+}
+
+/// Darkens the colors of an MDC mesh by the occlusion baked from `sampler`, starting from white
+/// for meshes without colors, and returns the shading.
+pub fn bake_mdc_occlusion<S: Sampler>(
+    mesh_buffers: &mut mdc::MeshBuffers,
+    sampler: &S,
+    options: &AoOptions,
+) -> VertexShading {
+    let shading = bake_vertex_shading(&mesh_buffers.positions, sampler, options);
+    if mesh_buffers.colors.len() != mesh_buffers.positions.len() {
+        mesh_buffers.colors = vec![[1.0; 4]; mesh_buffers.positions.len()];
+    }
+    for (color, occlusion) in mesh_buffers.colors.iter_mut().zip(&shading.occlusion) {
+        for channel in &mut color[..3] {
+            *channel *= occlusion;
+        }
+    }
+    shading
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::{
+        manifold_dual_contouring::{
+            mdc::{GridBounds, MeshBuffers, mdc_mesh_generation},
+            sampler::Sampler,
+        },
+        post_process::ao::{AoOptions, bake_mdc_occlusion},
+    };
+
+    /// Two overlapping spheres of radius 6 along x, with a concave crease where they meet.
+    struct TwoSpheres;
+
+    impl Sampler for TwoSpheres {
+        fn sample(&self, p: Vec3) -> f32 {
+            let a = p.distance(Vec3::new(-4.0, 0.0, 0.0)) - 6.0;
+            let b = p.distance(Vec3::new(4.0, 0.0, 0.0)) - 6.0;
+            a.min(b)
+        }
+    }
+
+    #[test]
+    fn test_occlusion_in_crease() {
+        let mut mesh_buffers = MeshBuffers::new();
+        let bounds = GridBounds::centered(32.0, 32);
+        mdc_mesh_generation(0.0, &mut mesh_buffers, false, bounds, true, &TwoSpheres);
+        let options = AoOptions {
+            curvature: true,
+            thickness: true,
+            ..Default::default()
+        };
+        let colors = mesh_buffers.colors.clone();
+        let shading = bake_mdc_occlusion(&mut mesh_buffers, &TwoSpheres, &options);
+        let vertices = mesh_buffers.positions.len();
+        assert_eq!(shading.occlusion.len(), vertices);
+        assert_eq!(shading.curvature.len(), vertices);
+        assert_eq!(shading.thickness.len(), vertices);
+        assert_eq!(mesh_buffers.colors.len(), vertices);
+        let used: Vec<usize> = {
+            let mut used: Vec<usize> = mesh_buffers.indices.iter().map(|i| *i as usize).collect();
+            used.sort_unstable();
+            used.dedup();
+            used
+        };
+        // The crease circle lies at x = 0 with radius √20, the outer poles at x = ±10.
+        let crease = |v: usize| {
+            let p = Vec3::from_array(mesh_buffers.positions[v]);
+            p.x.abs() < 0.5 && p.length() < 4.8
+        };
+        let pole = |v: usize| Vec3::from_array(mesh_buffers.positions[v]).x.abs() > 9.5;
+        assert!(used.iter().any(|v| crease(*v)) && used.iter().any(|v| pole(*v)));
+        for &v in &used {
+            let (occlusion, thickness) = (shading.occlusion[v], shading.thickness[v]);
+            assert!((0.0..=1.0).contains(&occlusion) && (0.0..=1.0).contains(&thickness));
+            assert_eq!(mesh_buffers.colors[v][0], colors[v][0] * occlusion);
+            assert_eq!(mesh_buffers.colors[v][3], colors[v][3]);
+            if crease(v) {
+                assert!(occlusion < 0.8, "crease vertex {v}: {occlusion}");
+                assert!(shading.curvature[v] < 0.0);
+            }
+            if pole(v) {
+                assert!(occlusion > 0.95, "pole vertex {v}: {occlusion}");
+                assert!((shading.curvature[v] - 1.0 / 6.0).abs() < 0.05);
+                assert!(thickness > 0.95);
+            }
+        }
+    }
+}
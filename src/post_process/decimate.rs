@@ -0,0 +1,638 @@
+// Quadric error metric edge-collapse decimation for the meshes of any of the meshers. Boundary
+// vertices, vertices on material borders and vertices where the input is not manifold never
+// move, so chunk seams and material regions stay as generated, and the link condition keeps a
+// manifold input manifold.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    ops::Add,
+};
+
+use glam::{DVec3, Vec3};
+
+use crate::{
+    manifold_dual_contouring::mdc,
+    marching_cubes::mc,
+    qef::solver::{QefSolveOptions, QefSolverKind},
+};
+
+/// Collapses that turn a triangle by more than this (cosine of the angle) are rejected.
+const MIN_NORMAL_COS: f32 = 0.2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecimateOptions {
+    /// Collapsing stops once the mesh has no more triangles than this.
+    pub target_triangles: usize,
+    /// Collapsing stops before the first collapse with a larger error: the square root of the
+    /// summed squared distances from the new vertex to the planes of the original triangles
+    /// around it.
+    pub max_error: f32,
+}
+
+impl DecimateOptions {
+    pub fn to_triangles(target_triangles: usize) -> Self {
+        Self {
+            target_triangles,
+            max_error: f32::INFINITY,
+        }
+    }
+
+    pub fn to_error(max_error: f32) -> Self {
+        Self {
+            target_triangles: 0,
+            max_error,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DecimateStats {
+    pub triangles_before: usize,
+    pub triangles_after: usize,
+    /// Largest error of a collapse that was made.
+    pub max_error: f32,
+}
+
+/// Decimates an MDC mesh, interpolating normals and colors. Borders between materials are
+/// kept. Flat-shaded meshes share no vertices, so every edge is a boundary, and have to be
+/// welded first.
+pub fn decimate_mdc_mesh(
+    mesh_buffers: &mut mdc::MeshBuffers,
+    options: &DecimateOptions,
+) -> DecimateStats {
+    let materials: Vec<u32> = mesh_buffers.materials.iter().map(|m| *m as u32).collect();
+    let materials = (materials.len() == mesh_buffers.positions.len()).then_some(&materials[..]);
+    let decimation = decimate(
+        &mesh_buffers.positions,
+        &mesh_buffers.indices,
+        materials,
+        options,
+    );
+    decimation.apply(&mut mesh_buffers.normals, lerp_normal);
+    decimation.apply(&mut mesh_buffers.colors, |a, b, t| {
+        std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+    });
+    decimation.apply(&mut mesh_buffers.materials, |kept, _, _| kept);
+    decimation.finish(&mut mesh_buffers.positions, &mut mesh_buffers.indices)
+}
+
+/// Decimates a marching cubes, surface nets or marching tetrahedra mesh, interpolating
+/// normals. The material encoded in `uvs` is not interpolated, and borders between materials
+/// are kept.
+pub fn decimate_mc_mesh(
+    mesh_buffers: &mut mc::MeshBuffers,
+    options: &DecimateOptions,
+) -> DecimateStats {
+    let materials: Vec<u32> = mesh_buffers.uvs.iter().map(|uv| uv[0].to_bits()).collect();
+    let materials = (materials.len() == mesh_buffers.positions.len()).then_some(&materials[..]);
+    let decimation = decimate(
+        &mesh_buffers.positions,
+        &mesh_buffers.indices,
+        materials,
+        options,
+    );
+    decimation.apply(&mut mesh_buffers.normals, lerp_normal);
+    decimation.apply(&mut mesh_buffers.uvs, |kept, _, _| kept);
+    decimation.finish(&mut mesh_buffers.positions, &mut mesh_buffers.indices)
+}
+
+fn lerp_normal(a: [f32; 3], b: [f32; 3], t: f32) -> [f32; 3] {
+    let normal = Vec3::from_array(a).lerp(Vec3::from_array(b), t);
+    normal
+        .try_normalize()
+        .unwrap_or(Vec3::from_array(a))
+        .to_array()
+}
+
+/// Result of a decimation: the new geometry plus the collapses that produced it, which replay
+/// onto any per-vertex attribute.
+struct Decimation {
+    positions: Vec<[f32; 3]>,
+    indices: Vec<u32>,
+    /// `(kept, removed, weight)`: the kept vertex takes `weight` of the removed one's attributes.
+    collapses: Vec<(usize, usize, f32)>,
+    /// New index of every input vertex, `u32::MAX` for those no triangle uses anymore.
+    remap: Vec<u32>,
+    stats: DecimateStats,
+}
+
+impl Decimation {
+    fn apply<T: Copy>(&self, values: &mut Vec<T>, lerp: impl Fn(T, T, f32) -> T) {
+        if values.is_empty() {
+            return;
+        }
+        for &(kept, removed, weight) in &self.collapses {
+            values[kept] = lerp(values[kept], values[removed], weight);
+        }
+        *values = values
+            .iter()
+            .zip(&self.remap)
+            .filter(|(_, new)| **new != u32::MAX)
+            .map(|(value, _)| *value)
+            .collect();
+    }
+
+    fn finish(self, positions: &mut Vec<[f32; 3]>, indices: &mut Vec<u32>) -> DecimateStats {
+        *positions = self.positions;
+        *indices = self.indices;
+        self.stats
+    }
+}
+
+/// Sum of squared distances to a set of planes: `xᵀAᵀAx - 2xᵀAᵀb + bᵀb`.
+#[derive(Debug, Clone, Copy, Default)]
+struct Quadric {
+    /// Upper triangle of `AᵀA`, laid out like the QEF solvers expect.
+    ata: [f64; 6],
+    atb: DVec3,
+    btb: f64,
+}
+
+impl Quadric {
+    fn plane(normal: Vec3, point: Vec3) -> Self {
+        let n = normal.as_dvec3();
+        let d = n.dot(point.as_dvec3());
+        Self {
+            ata: [
+                n.x * n.x,
+                n.x * n.y,
+                n.x * n.z,
+                n.y * n.y,
+                n.y * n.z,
+                n.z * n.z,
+            ],
+            atb: n * d,
+            btb: d * d,
+        }
+    }
+
+    fn error(&self, point: Vec3) -> f64 {
+        let p = point.as_dvec3();
+        let [m00, m01, m02, m11, m12, m22] = self.ata;
+        let ap = DVec3::new(
+            m00 * p.x + m01 * p.y + m02 * p.z,
+            m01 * p.x + m11 * p.y + m12 * p.z,
+            m02 * p.x + m12 * p.y + m22 * p.z,
+        );
+        (p.dot(ap) - 2.0 * p.dot(self.atb) + self.btb).max(0.0)
+    }
+
+    /// Directions the planes leave unconstrained stay at `fallback`.
+    fn minimiser(&self, fallback: Vec3) -> Vec3 {
+        QefSolveOptions::new(QefSolverKind::Double)
+            .solve_f64(&self.ata, self.atb, fallback.as_dvec3())
+            .as_vec3()
+    }
+}
+
+impl Add for Quadric {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            ata: std::array::from_fn(|i| self.ata[i] + other.ata[i]),
+            atb: self.atb + other.atb,
+            btb: self.btb + other.btb,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Collapse {
+    cost: f64,
+    kept: usize,
+    removed: usize,
+    position: Vec3,
+    /// Share of the removed vertex in the attributes of the kept one.
+    weight: f32,
+    /// Versions of `kept` and `removed` the collapse was planned against.
+    stamps: (u32, u32),
+}
+
+// Reversed so the cheapest collapse sits on top of the max-heap.
+impl Ord for Collapse {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Collapse {}
+
+struct Decimator {
+    points: Vec<Vec3>,
+    triangles: Vec<[usize; 3]>,
+    alive: Vec<bool>,
+    vertex_triangles: Vec<Vec<usize>>,
+    quadrics: Vec<Quadric>,
+    locked: Vec<bool>,
+    removed: Vec<bool>,
+    stamps: Vec<u32>,
+}
+
+impl Decimator {
+    fn neighbors(&self, vertex: usize) -> Vec<usize> {
+        let mut neighbors: Vec<usize> = self.vertex_triangles[vertex]
+            .iter()
+            .filter(|t| self.alive[**t])
+            .flat_map(|t| self.triangles[*t])
+            .filter(|v| *v != vertex)
+            .collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    /// Plans collapsing the edge `a`-`b` into its cheapest position, or into its locked end.
+    fn plan(&self, a: usize, b: usize) -> Option<Collapse> {
+        let (kept, removed) = match (self.locked[a], self.locked[b]) {
+            (true, true) => return None,
+            (false, true) => (b, a),
+            _ => (a, b),
+        };
+        let quadric = self.quadrics[kept] + self.quadrics[removed];
+        let (from, to) = (self.points[kept], self.points[removed]);
+        let position = if self.locked[kept] {
+            from
+        } else {
+            let mid = (from + to) * 0.5;
+            [quadric.minimiser(mid), from, to, mid]
+                .into_iter()
+                .filter(|p| p.is_finite())
+                .min_by(|p, q| quadric.error(*p).total_cmp(&quadric.error(*q)))
+                .unwrap_or(mid)
+        };
+        let edge = to - from;
+        let weight = if edge.length_squared() > 0.0 {
+            ((position - from).dot(edge) / edge.length_squared()).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        Some(Collapse {
+            cost: quadric.error(position),
+            kept,
+            removed,
+            position,
+            weight,
+            stamps: (self.stamps[kept], self.stamps[removed]),
+        })
+    }
+
+    fn is_current(&self, collapse: &Collapse) -> bool {
+        !self.removed[collapse.kept]
+            && !self.removed[collapse.removed]
+            && collapse.stamps == (self.stamps[collapse.kept], self.stamps[collapse.removed])
+    }
+
+    /// Link condition, no vertex left with fewer than three neighbors, and no triangle flipped.
+    fn is_valid(&self, collapse: &Collapse) -> bool {
+        let Collapse { kept, removed, .. } = *collapse;
+        let shared: Vec<usize> = self.vertex_triangles[removed]
+            .iter()
+            .copied()
+            .filter(|t| self.alive[*t] && self.triangles[*t].contains(&kept))
+            .collect();
+        if shared.len() != 2 {
+            return false;
+        }
+        let (kept_neighbors, removed_neighbors) = (self.neighbors(kept), self.neighbors(removed));
+        if kept_neighbors.len() + removed_neighbors.len() < 7 {
+            return false;
+        }
+        let common = removed_neighbors
+            .iter()
+            .filter(|v| kept_neighbors.binary_search(v).is_ok())
+            .count();
+        if common != 2 {
+            return false;
+        }
+        [kept, removed].iter().all(|moved| {
+            self.vertex_triangles[*moved]
+                .iter()
+                .filter(|t| self.alive[**t] && !shared.contains(t))
+                .all(|t| {
+                    let corners = self.triangles[*t].map(|v| self.points[v]);
+                    let moved_corners = self.triangles[*t].map(|v| {
+                        if v == *moved {
+                            collapse.position
+                        } else {
+                            self.points[v]
+                        }
+                    });
+                    let normal = |[a, b, c]: [Vec3; 3]| (b - a).cross(c - a).normalize_or_zero();
+                    normal(corners).dot(normal(moved_corners)) >= MIN_NORMAL_COS
+                })
+        })
+    }
+
+    /// Returns the number of triangles removed.
+    fn collapse(&mut self, collapse: &Collapse) -> usize {
+        let Collapse { kept, removed, .. } = *collapse;
+        self.points[kept] = collapse.position;
+        self.quadrics[kept] = self.quadrics[kept] + self.quadrics[removed];
+        self.removed[removed] = true;
+        self.stamps[kept] += 1;
+        let mut removed_triangles = 0;
+        for t in std::mem::take(&mut self.vertex_triangles[removed]) {
+            if !self.alive[t] {
+                continue;
+            }
+            if self.triangles[t].contains(&kept) {
+                self.alive[t] = false;
+                removed_triangles += 1;
+            } else {
+                for v in &mut self.triangles[t] {
+                    if *v == removed {
+                        *v = kept;
+                    }
+                }
+                self.vertex_triangles[kept].push(t);
+            }
+        }
+        let alive = &self.alive;
+        self.vertex_triangles[kept].retain(|t| alive[*t]);
+        removed_triangles
+    }
+}
+
+/// Vertices on edges that do not have exactly two consistently wound triangles.
+pub(crate) fn boundary_vertices(triangles: &[[usize; 3]], vertex_count: usize) -> Vec<bool> {
+    let mut boundary = vec![false; vertex_count];
+    // Directed edge counts, keyed by the undirected edge, lower index first.
+    let mut edges: HashMap<(usize, usize), (u32, u32)> = HashMap::new();
+    for t in triangles {
+        for k in 0..3 {
+            let (from, to) = (t[k], t[(k + 1) % 3]);
+            let counts = edges.entry((from.min(to), from.max(to))).or_default();
+            if from < to {
+                counts.0 += 1;
+            } else {
+                counts.1 += 1;
+            }
+        }
+    }
+    for ((a, b), counts) in edges {
+        if a == b || counts != (1, 1) {
+            boundary[a] = true;
+            boundary[b] = true;
+        }
+    }
+    boundary
+}
+
+/// Vertices that must not move: on boundary or non-manifold edges, at pinches where several
+/// fans meet, and on triangles whose corners have different materials.
+fn locked_vertices(
+    triangles: &[[usize; 3]],
+    vertex_triangles: &[Vec<usize>],
+    materials: Option<&[u32]>,
+) -> Vec<bool> {
+    let mut locked = boundary_vertices(triangles, vertex_triangles.len());
+    if let Some(materials) = materials {
+        for t in triangles {
+            if materials[t[0]] != materials[t[1]] || materials[t[1]] != materials[t[2]] {
+                t.iter().for_each(|v| locked[*v] = true);
+            }
+        }
+    }
+    for (vertex, incident) in vertex_triangles.iter().enumerate() {
+        if incident.is_empty() || locked[vertex] {
+            continue;
+        }
+        // The opposite edges of a manifold vertex's triangles chain into a single cycle.
+        let link: HashMap<usize, usize> = incident
+            .iter()
+            .map(|t| {
+                let t = triangles[*t];
+                let k = t.iter().position(|v| *v == vertex).unwrap();
+                (t[(k + 1) % 3], t[(k + 2) % 3])
+            })
+            .collect();
+        let start = *link.keys().next().unwrap();
+        let (mut current, mut steps) = (start, 0);
+        while let Some(next) = link.get(&current) {
+            current = *next;
+            steps += 1;
+            if current == start || steps > incident.len() {
+                break;
+            }
+        }
+        if link.len() != incident.len() || current != start || steps != incident.len() {
+            locked[vertex] = true;
+        }
+    }
+    locked
+}
+
+fn decimate(
+    positions: &[[f32; 3]],
+    indices: &[u32],
+    materials: Option<&[u32]>,
+    options: &DecimateOptions,
+) -> Decimation {
+    let points: Vec<Vec3> = positions.iter().map(|p| Vec3::from_array(*p)).collect();
+    let triangles: Vec<[usize; 3]> = indices
+        .chunks_exact(3)
+        .map(|t| [t[0] as usize, t[1] as usize, t[2] as usize])
+        .collect();
+    let mut vertex_triangles = vec![Vec::new(); points.len()];
+    let mut quadrics = vec![Quadric::default(); points.len()];
+    for (i, t) in triangles.iter().enumerate() {
+        let [a, b, c] = t.map(|v| points[v]);
+        let plane = (b - a)
+            .cross(c - a)
+            .try_normalize()
+            .map(|normal| Quadric::plane(normal, a));
+        for v in t {
+            vertex_triangles[*v].push(i);
+            if let Some(plane) = plane {
+                quadrics[*v] = quadrics[*v] + plane;
+            }
+        }
+    }
+    let mut decimator = Decimator {
+        locked: locked_vertices(&triangles, &vertex_triangles, materials),
+        alive: vec![true; triangles.len()],
+        removed: vec![false; points.len()],
+        stamps: vec![0; points.len()],
+        points,
+        triangles,
+        vertex_triangles,
+        quadrics,
+    };
+    let mut queue = BinaryHeap::new();
+    for t in &decimator.triangles {
+        for k in 0..3 {
+            // Interior edges appear once in each direction.
+            let (a, b) = (t[k], t[(k + 1) % 3]);
+            if a < b {
+                queue.extend(decimator.plan(a, b));
+            }
+        }
+    }
+    let mut stats = DecimateStats {
+        triangles_before: decimator.triangles.len(),
+        ..Default::default()
+    };
+    let mut triangle_count = decimator.triangles.len();
+    let mut collapses = Vec::new();
+    while triangle_count > options.target_triangles {
+        let Some(collapse) = queue.pop() else {
+            break;
+        };
+        if !decimator.is_current(&collapse) {
+            continue;
+        }
+        let error = collapse.cost.sqrt() as f32;
+        if error > options.max_error {
+            break;
+        }
+        if !decimator.is_valid(&collapse) {
+            continue;
+        }
+        triangle_count -= decimator.collapse(&collapse);
+        stats.max_error = stats.max_error.max(error);
+        collapses.push((collapse.kept, collapse.removed, collapse.weight));
+        for neighbor in decimator.neighbors(collapse.kept) {
+            queue.extend(decimator.plan(collapse.kept, neighbor));
+        }
+    }
+    let mut remap = vec![u32::MAX; decimator.points.len()];
+    for (t, alive) in decimator.triangles.iter().zip(&decimator.alive) {
+        if *alive {
+            t.iter().for_each(|v| remap[*v] = 0);
+        }
+    }
+    let mut new_positions = Vec::new();
+    for (v, new) in remap.iter_mut().enumerate() {
+        if *new != u32::MAX {
+            *new = new_positions.len() as u32;
+            new_positions.push(decimator.points[v].to_array());
+        }
+    }
+    let new_indices: Vec<u32> = decimator
+        .triangles
+        .iter()
+        .zip(&decimator.alive)
+        .filter(|(_, alive)| **alive)
+        .flat_map(|(t, _)| t.map(|v| remap[v]))
+        .collect();
+    stats.triangles_after = new_indices.len() / 3;
+    Decimation {
+        positions: new_positions,
+        indices: new_indices,
+        collapses,
+        remap,
+        stats,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::{
+        manifold_dual_contouring::{
+            mdc::{GridBounds, MeshBuffers, mdc_mesh_generation},
+            sampler::SphereSampler,
+        },
+        marching_cubes::{self, mc::mc_mesh_generation},
+        post_process::decimate::{DecimateOptions, decimate_mc_mesh, decimate_mdc_mesh},
+        validate::{mesh::validate_mesh, metrics::mesh_metrics},
+    };
+
+    #[test]
+    fn test_decimate_mdc_sphere() {
+        let sphere = SphereSampler::new(Vec3::ZERO, 12.0);
+        let bounds = GridBounds::centered(32.0, 32);
+        let mut mesh_buffers = MeshBuffers::new();
+        mdc_mesh_generation(0.0, &mut mesh_buffers, false, bounds, true, &sphere);
+        let target = mesh_buffers.indices.len() / 3 / 4;
+        let stats = decimate_mdc_mesh(&mut mesh_buffers, &DecimateOptions::to_triangles(target));
+        assert!(stats.triangles_after <= target);
+        assert_eq!(stats.triangles_after * 3, mesh_buffers.indices.len());
+        assert_eq!(mesh_buffers.normals.len(), mesh_buffers.positions.len());
+        assert_eq!(mesh_buffers.colors.len(), mesh_buffers.positions.len());
+        let report = validate_mesh(&mesh_buffers.positions, &mesh_buffers.indices, true);
+        assert!(report.is_watertight(), "{report:?}");
+        assert_eq!(report.euler_characteristic, 2);
+        assert_eq!(report.self_intersections, Some(0));
+        let metrics = mesh_metrics(
+            &mesh_buffers.positions,
+            &mesh_buffers.normals,
+            &mesh_buffers.indices,
+            &sphere,
+        );
+        assert!(metrics.vertex_distance.max < 0.05 * bounds.cell_size);
+        assert!(metrics.centroid_distance.max < 0.25 * bounds.cell_size);
+    }
+
+    #[test]
+    fn test_decimate_mc_keeps_seams_and_material_borders() {
+        // The sphere pokes out of the chunk, so the mesh is open at the chunk faces.
+        let (samples, half_extent) = (33, 16.0);
+        let densities = SphereSampler::new(Vec3::new(4.0, 2.0, 0.0), 17.0).bake_quantized(
+            Vec3::splat(-half_extent),
+            Vec3::splat(half_extent),
+            (samples, samples, samples),
+        );
+        let materials: Vec<u8> = (0..samples * samples * samples)
+            .map(|i| 1 + (i / (samples * samples) * 2 / samples) as u8)
+            .collect();
+        let mut mesh_buffers = marching_cubes::mc::MeshBuffers::new();
+        mc_mesh_generation(
+            &mut mesh_buffers,
+            &densities,
+            &materials,
+            samples,
+            half_extent,
+        );
+        // Sorted positions on the chunk faces and on triangles with two materials.
+        let fixed = |mesh_buffers: &marching_cubes::mc::MeshBuffers| {
+            let mut seam = Vec::new();
+            let mut border = Vec::new();
+            for t in mesh_buffers.indices.chunks_exact(3) {
+                let material = |i: &u32| mesh_buffers.uvs[*i as usize][0];
+                let mixed = t.iter().any(|i| material(i) != material(&t[0]));
+                for i in t {
+                    let p = mesh_buffers.positions[*i as usize];
+                    if p.iter().any(|c| c.abs() >= half_extent - 1e-4) {
+                        seam.push(p.map(f32::to_bits));
+                    }
+                    if mixed {
+                        border.push(p.map(f32::to_bits));
+                    }
+                }
+            }
+            for positions in [&mut seam, &mut border] {
+                positions.sort_unstable();
+                positions.dedup();
+            }
+            (seam, border)
+        };
+        let before = fixed(&mesh_buffers);
+        let boundary_edges =
+            validate_mesh(&mesh_buffers.positions, &mesh_buffers.indices, false).boundary_edges;
+        let stats = decimate_mc_mesh(&mut mesh_buffers, &DecimateOptions::to_error(0.5));
+        assert!(stats.triangles_after < stats.triangles_before / 2);
+        assert!(stats.max_error <= 0.5);
+        assert_eq!(mesh_buffers.uvs.len(), mesh_buffers.positions.len());
+        assert!(!before.0.is_empty() && !before.1.is_empty());
+        assert_eq!(fixed(&mesh_buffers), before);
+        let report = validate_mesh(&mesh_buffers.positions, &mesh_buffers.indices, false);
+        assert!(report.is_manifold(), "{report:?}");
+        assert_eq!(report.boundary_edges, boundary_edges);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod ao;
+pub mod decimate;
+pub mod normals;
+pub mod optimize;
+pub mod paint;
+pub mod smooth;
+pub mod uv;
+pub mod weld;
@@ -0,0 +1,240 @@
+// Vertex normals recomputed from the triangles, optionally split along creases: the triangles
+// around a vertex fall into groups joined across edges where their faces meet at less than the
+// crease angle, and every group beyond the first gets its own copy of the vertex.
+
+use glam::Vec3;
+
+use crate::{manifold_dual_contouring::mdc, marching_cubes::mc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalWeighting {
+    /// Faces count by the angle of their corner at the vertex, which does not depend on how the
+    /// surface around it happens to be triangulated.
+    #[default]
+    Angle,
+    /// Faces count by their area.
+    Area,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NormalOptions {
+    pub weighting: NormalWeighting,
+    /// Faces meeting at more than this angle in degrees get separate vertices, and so hard
+    /// normals. `None` keeps every vertex smooth.
+    pub crease_angle: Option<f32>,
+}
+
+/// Recomputes the normals of an MDC mesh. Vertices split along creases copy their colors and
+/// materials.
+pub fn recompute_mdc_normals(mesh_buffers: &mut mdc::MeshBuffers, options: &NormalOptions) {
+    let (normals, splits) = compute_normals(
+        &mesh_buffers.positions,
+        &mesh_buffers.normals,
+        &mut mesh_buffers.indices,
+        options,
+    );
+    duplicate(&mut mesh_buffers.positions, &splits);
+    duplicate(&mut mesh_buffers.colors, &splits);
+    duplicate(&mut mesh_buffers.materials, &splits);
+    mesh_buffers.normals = normals;
+}
+
+/// Recomputes the normals of an MC, surface nets or marching tetrahedra mesh. Vertices split
+/// along creases copy their uvs.
+pub fn recompute_mc_normals(mesh_buffers: &mut mc::MeshBuffers, options: &NormalOptions) {
+    let (normals, splits) = compute_normals(
+        &mesh_buffers.positions,
+        &mesh_buffers.normals,
+        &mut mesh_buffers.indices,
+        options,
+    );
+    duplicate(&mut mesh_buffers.positions, &splits);
+    duplicate(&mut mesh_buffers.uvs, &splits);
+    mesh_buffers.normals = normals;
+}
+
+fn duplicate<T: Copy>(values: &mut Vec<T>, splits: &[usize]) {
+    if values.is_empty() {
+        return;
+    }
+    for source in splits {
+        values.push(values[*source]);
+    }
+}
+
+/// Returns the new normals and, for every vertex appended by a crease split, the vertex it
+/// copies. Vertices without triangles keep their old normal.
+fn compute_normals(
+    positions: &[[f32; 3]],
+    old_normals: &[[f32; 3]],
+    indices: &mut [u32],
+    options: &NormalOptions,
+) -> (Vec<[f32; 3]>, Vec<usize>) {
+    let points: Vec<Vec3> = positions.iter().map(|p| Vec3::from_array(*p)).collect();
+    let triangles: Vec<[usize; 3]> = indices
+        .chunks_exact(3)
+        .map(|t| [t[0] as usize, t[1] as usize, t[2] as usize])
+        .collect();
+    let face_normals: Vec<Vec3> = triangles
+        .iter()
+        .map(|t| {
+            let [a, b, c] = t.map(|v| points[v]);
+            (b - a).cross(c - a)
+        })
+        .collect();
+    // Weight of every corner of every triangle in its vertex's normal.
+    let corner_weights: Vec<[f32; 3]> = triangles
+        .iter()
+        .zip(&face_normals)
+        .map(|(t, normal)| match options.weighting {
+            NormalWeighting::Area => [normal.length() * 0.5; 3],
+            NormalWeighting::Angle => std::array::from_fn(|k| {
+                let corner = points[t[k]];
+                let (next, previous) = (points[t[(k + 1) % 3]], points[t[(k + 2) % 3]]);
+                let angle = (next - corner).angle_between(previous - corner);
+                if angle.is_finite() { angle } else { 0.0 }
+            }),
+        })
+        .collect();
+    let face_normals: Vec<Vec3> = face_normals.iter().map(|n| n.normalize_or_zero()).collect();
+    let mut vertex_triangles = vec![Vec::new(); points.len()];
+    for (i, t) in triangles.iter().enumerate() {
+        for (k, v) in t.iter().enumerate() {
+            vertex_triangles[*v].push((i, k));
+        }
+    }
+    let min_crease_cos = options.crease_angle.map(|angle| angle.to_radians().cos());
+    let mut normals: Vec<[f32; 3]> = (0..points.len())
+        .map(|v| old_normals.get(v).copied().unwrap_or_default())
+        .collect();
+    let mut splits = Vec::new();
+    for (vertex, incident) in vertex_triangles.iter().enumerate() {
+        if incident.is_empty() {
+            continue;
+        }
+        // Group of each incident triangle, merged across the edges they share at this vertex.
+        let mut groups: Vec<usize> = (0..incident.len()).collect();
+        let find = |groups: &[usize], mut i: usize| {
+            while groups[i] != i {
+                i = groups[i];
+            }
+            i
+        };
+        let shares_edge = |i: usize, j: usize| {
+            let (a, b) = (incident[i].0, incident[j].0);
+            triangles[a]
+                .iter()
+                .any(|v| *v != vertex && triangles[b].contains(v))
+        };
+        let degenerate = |i: usize| face_normals[incident[i].0] == Vec3::ZERO;
+        for i in (0..incident.len()).filter(|i| !degenerate(*i)) {
+            for j in (i + 1..incident.len()).filter(|j| !degenerate(*j)) {
+                let (na, nb) = (face_normals[incident[i].0], face_normals[incident[j].0]);
+                let smooth = min_crease_cos.is_none_or(|min_cos| na.dot(nb) >= min_cos);
+                if smooth && shares_edge(i, j) {
+                    let (gi, gj) = (find(&groups, i), find(&groups, j));
+                    groups[gi.max(gj)] = gi.min(gj);
+                }
+            }
+        }
+        // Degenerate faces have no normal to crease with, and join a single neighbor so they
+        // cannot bridge a crease.
+        for i in (0..incident.len()).filter(|i| degenerate(*i)) {
+            if let Some(j) = (0..incident.len()).find(|j| !degenerate(*j) && shares_edge(i, *j)) {
+                groups[i] = find(&groups, j);
+            }
+        }
+        let roots: Vec<usize> = (0..incident.len()).map(|i| find(&groups, i)).collect();
+        for (group, root) in (0..incident.len()).filter(|i| roots[*i] == *i).enumerate() {
+            let members = || (0..incident.len()).filter(|i| roots[*i] == root);
+            let normal: Vec3 = members()
+                .map(|i| {
+                    let (t, k) = incident[i];
+                    face_normals[t] * corner_weights[t][k]
+                })
+                .sum();
+            let normal = normal
+                .try_normalize()
+                .or_else(|| {
+                    members()
+                        .map(|i| face_normals[incident[i].0])
+                        .find(|n| *n != Vec3::ZERO)
+                })
+                .unwrap_or(Vec3::from_array(normals[vertex]));
+            let target = if group == 0 {
+                vertex
+            } else {
+                splits.push(vertex);
+                normals.push([0.0; 3]);
+                normals.len() - 1
+            };
+            normals[target] = normal.to_array();
+            for i in members() {
+                let (t, k) = incident[i];
+                indices[t * 3 + k] = target as u32;
+            }
+        }
+    }
+    (normals, splits)
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::{
+        manifold_dual_contouring::{
+            mdc::{GridBounds, MeshBuffers, mdc_mesh_generation},
+            sampler::{CuboidSampler, SphereSampler},
+        },
+        post_process::normals::{NormalOptions, NormalWeighting, recompute_mdc_normals},
+        validate::metrics::mesh_metrics,
+    };
+
+    #[test]
+    fn test_recompute_normals() {
+        let sphere = SphereSampler::new(Vec3::ZERO, 12.0);
+        let bounds = GridBounds::centered(32.0, 32);
+        for weighting in [NormalWeighting::Angle, NormalWeighting::Area] {
+            let mut mesh_buffers = MeshBuffers::new();
+            mdc_mesh_generation(0.0, &mut mesh_buffers, false, bounds, true, &sphere);
+            let vertices = mesh_buffers.positions.len();
+            let options = NormalOptions {
+                weighting,
+                crease_angle: Some(45.0),
+            };
+            recompute_mdc_normals(&mut mesh_buffers, &options);
+            assert_eq!(mesh_buffers.positions.len(), vertices);
+            let metrics = mesh_metrics(
+                &mesh_buffers.positions,
+                &mesh_buffers.normals,
+                &mesh_buffers.indices,
+                &sphere,
+            );
+            assert!(metrics.normal_deviation.unwrap().max < 5.0, "{metrics}");
+        }
+
+        // Every face of the cuboid gets its own corners, with normals along its axis.
+        let cuboid = CuboidSampler::new(Vec3::new(0.3, 0.2, -0.1), Vec3::new(6.5, 5.2, 4.4));
+        let mut mesh_buffers = MeshBuffers::new();
+        mdc_mesh_generation(0.0, &mut mesh_buffers, false, bounds, true, &cuboid);
+        let vertices = mesh_buffers.positions.len();
+        let options = NormalOptions {
+            crease_angle: Some(45.0),
+            ..Default::default()
+        };
+        recompute_mdc_normals(&mut mesh_buffers, &options);
+        assert!(mesh_buffers.positions.len() > vertices);
+        assert_eq!(mesh_buffers.colors.len(), mesh_buffers.positions.len());
+        for t in mesh_buffers.indices.chunks_exact(3) {
+            let [a, b, c] = [t[0], t[1], t[2]].map(|i| mesh_buffers.positions[i as usize]);
+            let face = (Vec3::from_array(b) - Vec3::from_array(a))
+                .cross(Vec3::from_array(c) - Vec3::from_array(a))
+                .normalize_or_zero();
+            for i in t {
+                let normal = Vec3::from_array(mesh_buffers.normals[*i as usize]);
+                assert!(face == Vec3::ZERO || normal.dot(face) > 0.7);
+            }
+        }
+    }
+}
@@ -0,0 +1,358 @@
+// Rule-based material painting for terrain: every vertex gets the material of the first rule
+// whose slope, height and curvature ranges it falls in, so rules go from the most specific to
+// the most general. Perlin noise jitters the slope and height a vertex is tested with, which
+// breaks up the otherwise straight borders between materials.
+
+use glam::Vec3;
+use noise::{NoiseFn, Perlin};
+
+use crate::{
+    manifold_dual_contouring::mdc,
+    marching_cubes::{color_provider::ColorProvider, mc, mc::encode_material_to_uv},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SurfacePoint {
+    pub position: Vec3,
+    pub normal: Vec3,
+    /// Mean curvature, 1 / radius on a sphere: positive on convex, negative on concave surfaces.
+    pub curvature: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaterialRule {
+    pub material: u8,
+    pub color: [f32; 4],
+    /// Angle of the normal from up in degrees: 0 on flat ground, 90 on cliffs, up to 180 under
+    /// overhangs.
+    pub slope: (f32, f32),
+    /// World height along up.
+    pub height: (f32, f32),
+    pub curvature: (f32, f32),
+}
+
+impl MaterialRule {
+    /// A rule matching everywhere, until narrowed down.
+    pub fn new(material: u8, color: [f32; 4]) -> Self {
+        Self {
+            material,
+            color,
+            slope: (f32::NEG_INFINITY, f32::INFINITY),
+            height: (f32::NEG_INFINITY, f32::INFINITY),
+            curvature: (f32::NEG_INFINITY, f32::INFINITY),
+        }
+    }
+
+    pub fn slope(mut self, min: f32, max: f32) -> Self {
+        self.slope = (min, max);
+        self
+    }
+
+    pub fn height(mut self, min: f32, max: f32) -> Self {
+        self.height = (min, max);
+        self
+    }
+
+    pub fn curvature(mut self, min: f32, max: f32) -> Self {
+        self.curvature = (min, max);
+        self
+    }
+
+    fn matches(&self, slope: f32, height: f32, curvature: f32) -> bool {
+        // Unbounded ends also take NaN, as from a missing normal.
+        let within = |value: f32, (min, max): (f32, f32)| {
+            (value >= min || min == f32::NEG_INFINITY) && (value < max || max == f32::INFINITY)
+        };
+        within(slope, self.slope)
+            && within(height, self.height)
+            && within(curvature, self.curvature)
+    }
+}
+
+/// Assigns materials by rules, and colors them as a `ColorProvider` with the colors of the
+/// rules.
+#[derive(Clone)]
+pub struct RuleColorProvider {
+    pub rules: Vec<MaterialRule>,
+    /// Material and color of vertices no rule matches, and of unknown materials.
+    pub fallback: (u8, [f32; 4]),
+    pub up: Vec3,
+    /// World position of the mesh's local origin, such as its chunk's center.
+    pub origin: Vec3,
+    noise: Option<RuleNoise>,
+}
+
+#[derive(Clone)]
+struct RuleNoise {
+    perlin: Perlin,
+    frequency: f32,
+    slope: f32,
+    height: f32,
+}
+
+impl RuleColorProvider {
+    pub fn new(fallback_material: u8, fallback_color: [f32; 4]) -> Self {
+        Self {
+            rules: Vec::new(),
+            fallback: (fallback_material, fallback_color),
+            up: Vec3::Y,
+            origin: Vec3::ZERO,
+            noise: None,
+        }
+    }
+
+    /// Appends a rule, tested after the ones before it.
+    pub fn with_rule(mut self, rule: MaterialRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Jitters slopes by up to `slope` degrees and heights by up to `height` with Perlin noise
+    /// of `frequency` per world unit.
+    pub fn with_noise(mut self, seed: u32, frequency: f32, slope: f32, height: f32) -> Self {
+        self.noise = Some(RuleNoise {
+            perlin: Perlin::new(seed),
+            frequency,
+            slope,
+            height,
+        });
+        self
+    }
+
+    /// Material of a point in the mesh's local space. Points without a valid normal only match
+    /// rules without a slope range.
+    pub fn material(&self, point: &SurfacePoint) -> u8 {
+        let world = point.position + self.origin;
+        let up = self.up.normalize_or(Vec3::Y);
+        let mut slope = point.normal.try_normalize().map_or(f32::NAN, |normal| {
+            normal.dot(up).clamp(-1.0, 1.0).acos().to_degrees()
+        });
+        let mut height = world.dot(up);
+        if let Some(noise) = &self.noise {
+            let sample = |offset: f64| {
+                let p = (world * noise.frequency).as_dvec3() + offset;
+                noise.perlin.get(p.to_array()) as f32
+            };
+            slope = (slope + sample(0.0) * noise.slope).clamp(0.0, 180.0);
+            height += sample(17.31) * noise.height;
+        }
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(slope, height, point.curvature))
+            .map_or(self.fallback.0, |rule| rule.material)
+    }
+
+    fn materials(&self, positions: &[[f32; 3]], normals: &[[f32; 3]], indices: &[u32]) -> Vec<u8> {
+        let curvature = mesh_curvature(positions, normals, indices);
+        (0..positions.len())
+            .map(|v| {
+                self.material(&SurfacePoint {
+                    position: Vec3::from_array(positions[v]),
+                    normal: normals.get(v).map_or(Vec3::ZERO, |n| Vec3::from_array(*n)),
+                    curvature: curvature[v],
+                })
+            })
+            .collect()
+    }
+}
+
+impl ColorProvider for RuleColorProvider {
+    fn get_color(&self, material: u8, _position: Vec3) -> [f32; 4] {
+        self.rules
+            .iter()
+            .find(|rule| rule.material == material)
+            .map_or(self.fallback.1, |rule| rule.color)
+    }
+
+    fn needs_material(&self) -> bool {
+        true
+    }
+}
+
+/// Paints the colors and materials of an MDC mesh by the rules, and returns the materials.
+pub fn paint_mdc_mesh(
+    mesh_buffers: &mut mdc::MeshBuffers,
+    provider: &RuleColorProvider,
+) -> Vec<u8> {
+    let materials = provider.materials(
+        &mesh_buffers.positions,
+        &mesh_buffers.normals,
+        &mesh_buffers.indices,
+    );
+    mesh_buffers.colors = materials
+        .iter()
+        .zip(&mesh_buffers.positions)
+        .map(|(material, p)| provider.get_color(*material, Vec3::from_array(*p)))
+        .collect();
+    mesh_buffers.materials = materials.clone();
+    materials
+}
+
+/// Replaces the materials in the uvs of an MC, surface nets or marching tetrahedra mesh by the
+/// rules' and returns every vertex's color, as those meshes have none.
+pub fn paint_mc_mesh(
+    mesh_buffers: &mut mc::MeshBuffers,
+    provider: &RuleColorProvider,
+) -> Vec<[f32; 4]> {
+    let materials = provider.materials(
+        &mesh_buffers.positions,
+        &mesh_buffers.normals,
+        &mesh_buffers.indices,
+    );
+    mesh_buffers.uvs = materials
+        .iter()
+        .map(|m| encode_material_to_uv(*m))
+        .collect();
+    materials
+        .iter()
+        .zip(&mesh_buffers.positions)
+        .map(|(material, p)| provider.get_color(*material, Vec3::from_array(*p)))
+        .collect()
+}
+
+/// Mean curvature at every vertex from how far its neighbors drop below its tangent plane,
+/// 2 n·(p - q) / |p - q|² averaged over the neighbors q, which is exact on a sphere. Vertices
+/// without a normal or neighbors get 0.
+pub fn mesh_curvature(positions: &[[f32; 3]], normals: &[[f32; 3]], indices: &[u32]) -> Vec<f32> {
+    let mut neighbors = vec![Vec::new(); positions.len()];
+    for t in indices.chunks_exact(3) {
+        for k in 0..3 {
+            let (a, b) = (t[k] as usize, t[(k + 1) % 3] as usize);
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+    }
+    (0..positions.len())
+        .map(|v| {
+            let list = &mut neighbors[v];
+            list.sort_unstable();
+            list.dedup();
+            let Some(normal) = normals
+                .get(v)
+                .and_then(|n| Vec3::from_array(*n).try_normalize())
+            else {
+                return 0.0;
+            };
+            let p = Vec3::from_array(positions[v]);
+            let (sum, count) = list
+                .iter()
+                .map(|q| p - Vec3::from_array(positions[*q]))
+                .filter(|offset| offset.length_squared() > f32::EPSILON)
+                .fold((0.0, 0), |(sum, count), offset| {
+                    (
+                        sum + 2.0 * normal.dot(offset) / offset.length_squared(),
+                        count + 1,
+                    )
+                });
+            if count == 0 { 0.0 } else { sum / count as f32 }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::{
+        manifold_dual_contouring::{
+            mdc::{self, GridBounds, mdc_mesh_generation},
+            sampler::{Sampler, quantize_f32_to_i16},
+        },
+        marching_cubes::{
+            color_provider::ColorProvider,
+            mc::{self, mc_mesh_generation},
+        },
+        post_process::paint::{MaterialRule, RuleColorProvider, paint_mc_mesh, paint_mdc_mesh},
+    };
+
+    const SNOW: u8 = 4;
+    const DIRT: u8 = 1;
+    const GRASS: u8 = 2;
+    const ROCK: u8 = 3;
+
+    /// Ground at y = -3 with two overlapping hills of radius 6 on it, creased where they meet.
+    struct Hills;
+
+    impl Sampler for Hills {
+        fn sample(&self, p: Vec3) -> f32 {
+            let a = p.distance(Vec3::new(-4.0, 0.0, 0.0)) - 6.0;
+            let b = p.distance(Vec3::new(4.0, 0.0, 0.0)) - 6.0;
+            a.min(b).min(p.y + 3.0)
+        }
+    }
+
+    #[test]
+    fn test_paint_terrain() {
+        let provider = RuleColorProvider::new(ROCK, [0.5, 0.5, 0.5, 1.0])
+            .with_rule(MaterialRule::new(SNOW, [1.0; 4]).height(5.0, f32::INFINITY))
+            .with_rule(
+                MaterialRule::new(DIRT, [0.3, 0.2, 0.1, 1.0]).curvature(f32::NEG_INFINITY, -0.1),
+            )
+            .with_rule(MaterialRule::new(GRASS, [0.1, 0.5, 0.1, 1.0]).slope(0.0, 30.0))
+            .with_noise(7, 0.2, 5.0, 0.0);
+        // Ground away from the hills, the hilltops, and the crease between them above ground.
+        let expected = |p: Vec3| {
+            let hills = p
+                .distance(Vec3::new(-4.0, 0.0, 0.0))
+                .min(p.distance(Vec3::new(4.0, 0.0, 0.0)));
+            if p.y > 5.2 {
+                Some(SNOW)
+            } else if p.y < -2.9 && hills > 9.0 {
+                Some(GRASS)
+            } else if p.x.abs() < 0.5 && p.length() < 4.8 && p.y > -1.0 {
+                Some(DIRT)
+            } else if p.y.abs() < 1.0 && p.x.abs() > 9.5 {
+                Some(ROCK)
+            } else {
+                None
+            }
+        };
+
+        let mut mesh_buffers = mdc::MeshBuffers::new();
+        let bounds = GridBounds::centered(32.0, 32);
+        mdc_mesh_generation(0.0, &mut mesh_buffers, false, bounds, true, &Hills);
+        let materials = paint_mdc_mesh(&mut mesh_buffers, &provider);
+        assert_eq!(materials.len(), mesh_buffers.positions.len());
+        assert_eq!(mesh_buffers.colors.len(), mesh_buffers.positions.len());
+        let mut seen = [0; 5];
+        for t in mesh_buffers.indices.iter() {
+            let v = *t as usize;
+            let p = Vec3::from_array(mesh_buffers.positions[v]);
+            if let Some(material) = expected(p) {
+                assert_eq!(materials[v], material, "vertex {v} at {p}");
+                seen[material as usize] += 1;
+            }
+            assert_eq!(
+                mesh_buffers.colors[v],
+                provider.get_color(materials[v], Vec3::ZERO)
+            );
+        }
+        assert!(seen[1..].iter().all(|count| *count > 0), "{seen:?}");
+
+        let samples = 33;
+        let densities: Vec<i16> = (0..samples * samples * samples)
+            .map(|i| {
+                let (x, y, z) = (i % samples, i / samples % samples, i / (samples * samples));
+                let p = Vec3::new(x as f32, y as f32, z as f32) - 16.0;
+                quantize_f32_to_i16(Hills.sample(p))
+            })
+            .collect();
+        let mut mesh_buffers = mc::MeshBuffers::new();
+        mc_mesh_generation(
+            &mut mesh_buffers,
+            &densities,
+            &vec![0; densities.len()],
+            samples,
+            16.0,
+        );
+        let colors = paint_mc_mesh(&mut mesh_buffers, &provider);
+        assert_eq!(colors.len(), mesh_buffers.positions.len());
+        for (v, p) in mesh_buffers.positions.iter().enumerate() {
+            let material = mesh_buffers.uvs[v][0] as u8;
+            if p[1] < -2.9 && Vec3::from_array(*p).length() > 14.0 {
+                assert_eq!(material, GRASS);
+            }
+            assert_eq!(colors[v], provider.get_color(material, Vec3::ZERO));
+        }
+    }
+}
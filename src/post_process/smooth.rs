@@ -0,0 +1,164 @@
+// Laplacian and Taubin smoothing. Every step moves each vertex towards the average of its
+// neighbors; Taubin follows it with a slightly larger step away, which cancels most of the
+// shrinking of plain Laplacian smoothing. Vertices on boundary and non-manifold edges stay put,
+// so chunk seams keep matching. Normals are left alone, recompute them with `normals`.
+
+use glam::Vec3;
+
+use crate::{
+    manifold_dual_contouring::mdc, marching_cubes::mc, post_process::decimate::boundary_vertices,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SmoothOptions {
+    pub iterations: usize,
+    /// Fraction of the way to the neighbor average every step moves.
+    pub lambda: f32,
+    /// Taubin's second step, negative and slightly larger in magnitude than `lambda`. `None`
+    /// smooths with plain Laplacian steps.
+    pub mu: Option<f32>,
+}
+
+impl SmoothOptions {
+    pub fn laplacian(iterations: usize) -> Self {
+        Self {
+            iterations,
+            lambda: 0.5,
+            mu: None,
+        }
+    }
+
+    pub fn taubin(iterations: usize) -> Self {
+        Self {
+            iterations,
+            lambda: 0.5,
+            mu: Some(-0.53),
+        }
+    }
+}
+
+pub fn smooth_mdc_mesh(mesh_buffers: &mut mdc::MeshBuffers, options: &SmoothOptions) {
+    smooth(&mut mesh_buffers.positions, &mesh_buffers.indices, options);
+}
+
+pub fn smooth_mc_mesh(mesh_buffers: &mut mc::MeshBuffers, options: &SmoothOptions) {
+    smooth(&mut mesh_buffers.positions, &mesh_buffers.indices, options);
+}
+
+fn smooth(positions: &mut [[f32; 3]], indices: &[u32], options: &SmoothOptions) {
+    let triangles: Vec<[usize; 3]> = indices
+        .chunks_exact(3)
+        .map(|t| [t[0] as usize, t[1] as usize, t[2] as usize])
+        .collect();
+    let fixed = boundary_vertices(&triangles, positions.len());
+    let mut neighbors = vec![Vec::new(); positions.len()];
+    for t in &triangles {
+        for k in 0..3 {
+            let (a, b) = (t[k], t[(k + 1) % 3]);
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+    }
+    for list in &mut neighbors {
+        list.sort_unstable();
+        list.dedup();
+    }
+    let mut points: Vec<Vec3> = positions.iter().map(|p| Vec3::from_array(*p)).collect();
+    let step = |points: &mut Vec<Vec3>, factor: f32| {
+        let moved: Vec<Vec3> = (0..points.len())
+            .map(|v| {
+                if fixed[v] || neighbors[v].is_empty() {
+                    return points[v];
+                }
+                let average = neighbors[v].iter().map(|n| points[*n]).sum::<Vec3>()
+                    / neighbors[v].len() as f32;
+                points[v] + (average - points[v]) * factor
+            })
+            .collect();
+        *points = moved;
+    };
+    for _ in 0..options.iterations {
+        step(&mut points, options.lambda);
+        if let Some(mu) = options.mu {
+            step(&mut points, mu);
+        }
+    }
+    for (position, point) in positions.iter_mut().zip(&points) {
+        *position = point.to_array();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::{
+        manifold_dual_contouring::sampler::SphereSampler,
+        marching_cubes::mc::{MeshBuffers, mc_mesh_generation},
+        post_process::smooth::{SmoothOptions, smooth_mc_mesh},
+    };
+
+    #[test]
+    fn test_smooth_keeps_boundary_and_volume() {
+        // The sphere pokes out of the chunk, so the mesh is open at the chunk faces.
+        let (samples, half_extent, radius) = (33, 16.0, 17.0);
+        let center = Vec3::new(4.0, 2.0, 0.0);
+        let densities = SphereSampler::new(center, radius).bake_quantized(
+            Vec3::splat(-half_extent),
+            Vec3::splat(half_extent),
+            (samples, samples, samples),
+        );
+        let materials = vec![1; samples * samples * samples];
+        // The mesh with its vertices off the chunk faces pushed in and out, and the others.
+        let noisy_mesh = || {
+            let mut mesh_buffers = MeshBuffers::new();
+            mc_mesh_generation(
+                &mut mesh_buffers,
+                &densities,
+                &materials,
+                samples,
+                half_extent,
+            );
+            let mut seam = Vec::new();
+            for (i, p) in mesh_buffers.positions.iter_mut().enumerate() {
+                if p.iter().any(|c| c.abs() >= half_extent - 1e-4) {
+                    seam.push(*p);
+                } else {
+                    let offset = Vec3::from_array(*p) - center;
+                    let noise = ((i * 7919) % 101) as f32 / 100.0 - 0.5;
+                    *p = (Vec3::from_array(*p) + offset.normalize() * noise * 0.4).to_array();
+                }
+            }
+            (mesh_buffers, seam)
+        };
+        // Mean distance from the sphere, and mean signed distance, over the vertices.
+        let errors = |mesh_buffers: &MeshBuffers| {
+            let distances: Vec<f32> = mesh_buffers
+                .positions
+                .iter()
+                .map(|p| Vec3::from_array(*p).distance(center) - radius)
+                .collect();
+            let count = distances.len() as f32;
+            (
+                distances.iter().map(|d| d.abs()).sum::<f32>() / count,
+                distances.iter().sum::<f32>() / count,
+            )
+        };
+        let (noisy, seam) = noisy_mesh();
+        assert!(!seam.is_empty());
+        let (noise_error, _) = errors(&noisy);
+
+        let (mut laplacian, _) = noisy_mesh();
+        smooth_mc_mesh(&mut laplacian, &SmoothOptions::laplacian(10));
+        let (mut taubin, _) = noisy_mesh();
+        smooth_mc_mesh(&mut taubin, &SmoothOptions::taubin(10));
+        for mesh_buffers in [&laplacian, &taubin] {
+            assert!(seam.iter().all(|p| mesh_buffers.positions.contains(p)));
+        }
+        assert!(errors(&taubin).0 < 0.5 * noise_error);
+        // Laplacian smoothing shrinks the sphere, Taubin smoothing barely does.
+        let (laplacian_shift, taubin_shift) = (errors(&laplacian).1, errors(&taubin).1);
+        assert!(laplacian_shift < 0.0);
+        assert!(taubin_shift.abs() < 0.5 * laplacian_shift.abs());
+    }
+}
@@ -0,0 +1,324 @@
+// Texture coordinates projected from world space onto the six sides of a box, so textures
+// continue across chunks, with triplanar blend weights and MikkTSpace tangents for normal maps.
+// Looking at any side from outside, u points right and v down, without mirroring.
+
+use bevy_mikktspace::{Geometry, generate_tangents};
+use glam::Vec3;
+
+use crate::{manifold_dual_contouring::mdc, marching_cubes::mc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UvProjection {
+    /// Every triangle projects onto the side its face normal points at most, and vertices
+    /// shared by triangles on different sides are split.
+    #[default]
+    Box,
+    /// Every vertex projects onto the side its own normal points at most, without splitting.
+    /// Triangles between two sides stretch, which triplanar shaders hide by blending.
+    Triplanar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UvOptions {
+    pub projection: UvProjection,
+    /// Texture repeats per world unit.
+    pub scale: f32,
+    /// World position of the mesh's local origin, such as its chunk's center. Chunks that pass
+    /// their own origin get matching coordinates along their seams.
+    pub origin: Vec3,
+    /// Exponent sharpening the triplanar blend weights; 0 skips them.
+    pub blend_sharpness: f32,
+}
+
+impl Default for UvOptions {
+    fn default() -> Self {
+        Self {
+            projection: UvProjection::default(),
+            scale: 1.0,
+            origin: Vec3::ZERO,
+            blend_sharpness: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeshUvs {
+    pub uvs: Vec<[f32; 2]>,
+    /// MikkTSpace tangents, the bitangent sign in `w`. Empty for meshes without normals.
+    pub tangents: Vec<[f32; 4]>,
+    /// Triplanar weights of the x, y and z projections, summing to 1, with the dominant axis
+    /// (0, 1 or 2) in `w`. Empty unless `blend_sharpness` is set.
+    pub blend_weights: Vec<[f32; 4]>,
+}
+
+/// Generates uvs for an MDC mesh. Box projection splits vertices, copying their normals,
+/// colors and materials.
+pub fn generate_mdc_uvs(mesh_buffers: &mut mdc::MeshBuffers, options: &UvOptions) -> MeshUvs {
+    let (sides, splits) = assign_sides(
+        &mesh_buffers.positions,
+        &mesh_buffers.normals,
+        &mut mesh_buffers.indices,
+        options.projection,
+    );
+    duplicate(&mut mesh_buffers.positions, &splits);
+    duplicate(&mut mesh_buffers.normals, &splits);
+    duplicate(&mut mesh_buffers.colors, &splits);
+    duplicate(&mut mesh_buffers.materials, &splits);
+    project(
+        &mesh_buffers.positions,
+        &mesh_buffers.normals,
+        &mesh_buffers.indices,
+        &sides,
+        options,
+    )
+}
+
+/// Generates uvs for an MC, surface nets or marching tetrahedra mesh, whose own `uvs` keep the
+/// material. Box projection splits vertices, copying their normals and materials.
+pub fn generate_mc_uvs(mesh_buffers: &mut mc::MeshBuffers, options: &UvOptions) -> MeshUvs {
+    let (sides, splits) = assign_sides(
+        &mesh_buffers.positions,
+        &mesh_buffers.normals,
+        &mut mesh_buffers.indices,
+        options.projection,
+    );
+    duplicate(&mut mesh_buffers.positions, &splits);
+    duplicate(&mut mesh_buffers.normals, &splits);
+    duplicate(&mut mesh_buffers.uvs, &splits);
+    project(
+        &mesh_buffers.positions,
+        &mesh_buffers.normals,
+        &mesh_buffers.indices,
+        &sides,
+        options,
+    )
+}
+
+fn duplicate<T: Copy>(values: &mut Vec<T>, splits: &[usize]) {
+    if values.is_empty() {
+        return;
+    }
+    for source in splits {
+        values.push(values[*source]);
+    }
+}
+
+/// Box side a direction points at most: axis times two, plus one for the negative side.
+fn side(direction: Vec3) -> u8 {
+    let abs = direction.abs();
+    let axis = if abs.x >= abs.y && abs.x >= abs.z {
+        0
+    } else if abs.y >= abs.z {
+        1
+    } else {
+        2
+    };
+    axis * 2 + (direction[axis as usize] < 0.0) as u8
+}
+
+/// Coordinates of `point` on `side`, facing out of the box.
+fn side_uv(point: Vec3, side: u8) -> [f32; 2] {
+    let sign = if side & 1 == 0 { 1.0 } else { -1.0 };
+    match side / 2 {
+        0 => [-sign * point.z, -point.y],
+        1 => [point.x, sign * point.z],
+        _ => [sign * point.x, -point.y],
+    }
+}
+
+/// Side of every vertex, splitting vertices whose triangles face different sides with box
+/// projection. Returns the vertex each appended vertex copies.
+fn assign_sides(
+    positions: &[[f32; 3]],
+    normals: &[[f32; 3]],
+    indices: &mut [u32],
+    projection: UvProjection,
+) -> (Vec<u8>, Vec<usize>) {
+    let point = |i: u32| Vec3::from_array(positions[i as usize]);
+    let mut sides: Vec<u8> = (0..positions.len())
+        .map(|v| normals.get(v).map_or(0, |n| side(Vec3::from_array(*n))))
+        .collect();
+    let mut splits = Vec::new();
+    if projection == UvProjection::Triplanar {
+        return (sides, splits);
+    }
+    // Vertex copy already made for every (vertex, side), the first one being the vertex itself.
+    let mut copies: Vec<Vec<(u8, u32)>> = vec![Vec::new(); positions.len()];
+    for t in indices.chunks_exact_mut(3) {
+        let [a, b, c] = [t[0], t[1], t[2]].map(point);
+        let face_side = side((b - a).cross(c - a));
+        for corner in t {
+            let vertex = *corner as usize;
+            let known = &mut copies[vertex];
+            if let Some((_, copy)) = known.iter().find(|(s, _)| *s == face_side) {
+                *corner = *copy;
+            } else if known.is_empty() {
+                known.push((face_side, *corner));
+                sides[vertex] = face_side;
+            } else {
+                let copy = (positions.len() + splits.len()) as u32;
+                known.push((face_side, copy));
+                splits.push(vertex);
+                sides.push(face_side);
+                *corner = copy;
+            }
+        }
+    }
+    (sides, splits)
+}
+
+fn project(
+    positions: &[[f32; 3]],
+    normals: &[[f32; 3]],
+    indices: &[u32],
+    sides: &[u8],
+    options: &UvOptions,
+) -> MeshUvs {
+    let world = |v: usize| (Vec3::from_array(positions[v]) + options.origin) * options.scale;
+    let uvs: Vec<[f32; 2]> = (0..positions.len())
+        .map(|v| side_uv(world(v), sides[v]))
+        .collect();
+    let blend_weights = if options.blend_sharpness > 0.0 && !normals.is_empty() {
+        normals
+            .iter()
+            .zip(sides)
+            .map(|(normal, side)| {
+                let weights = Vec3::from_array(*normal)
+                    .abs()
+                    .powf(options.blend_sharpness);
+                let weights = weights / weights.element_sum().max(f32::MIN_POSITIVE);
+                [weights.x, weights.y, weights.z, (side / 2) as f32]
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let mut geometry = TangentGeometry {
+        positions,
+        normals,
+        uvs: &uvs,
+        indices,
+        tangents: vec![[1.0, 0.0, 0.0, 1.0]; positions.len()],
+    };
+    let tangents = if !normals.is_empty() && generate_tangents(&mut geometry) {
+        geometry.tangents
+    } else {
+        Vec::new()
+    };
+    MeshUvs {
+        uvs,
+        tangents,
+        blend_weights,
+    }
+}
+
+struct TangentGeometry<'a> {
+    positions: &'a [[f32; 3]],
+    normals: &'a [[f32; 3]],
+    uvs: &'a [[f32; 2]],
+    indices: &'a [u32],
+    tangents: Vec<[f32; 4]>,
+}
+
+impl TangentGeometry<'_> {
+    fn vertex(&self, face: usize, vert: usize) -> usize {
+        self.indices[face * 3 + vert] as usize
+    }
+}
+
+impl Geometry for TangentGeometry<'_> {
+    fn num_faces(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    fn num_vertices_of_face(&self, _face: usize) -> usize {
+        3
+    }
+
+    fn position(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.positions[self.vertex(face, vert)]
+    }
+
+    fn normal(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.normals[self.vertex(face, vert)]
+    }
+
+    fn tex_coord(&self, face: usize, vert: usize) -> [f32; 2] {
+        self.uvs[self.vertex(face, vert)]
+    }
+
+    fn set_tangent_encoded(&mut self, tangent: [f32; 4], face: usize, vert: usize) {
+        let vertex = self.vertex(face, vert);
+        self.tangents[vertex] = tangent;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::{
+        manifold_dual_contouring::{
+            mdc::{GridBounds, MeshBuffers, mdc_mesh_generation},
+            sampler::CuboidSampler,
+        },
+        post_process::uv::{UvOptions, UvProjection, generate_mdc_uvs, side, side_uv},
+    };
+
+    #[test]
+    fn test_box_uvs_on_cuboid() {
+        let cuboid = CuboidSampler::new(Vec3::new(0.3, 0.2, -0.1), Vec3::new(6.5, 5.2, 4.4));
+        let bounds = GridBounds::centered(24.0, 16);
+        let mut mesh_buffers = MeshBuffers::new();
+        mdc_mesh_generation(0.0, &mut mesh_buffers, false, bounds, true, &cuboid);
+        let vertices = mesh_buffers.positions.len();
+        let options = UvOptions {
+            scale: 0.5,
+            origin: Vec3::new(32.0, 0.0, -32.0),
+            blend_sharpness: 4.0,
+            ..Default::default()
+        };
+        let uvs = generate_mdc_uvs(&mut mesh_buffers, &options);
+        let vertices_after = mesh_buffers.positions.len();
+        assert!(vertices_after > vertices);
+        assert_eq!(mesh_buffers.normals.len(), vertices_after);
+        assert_eq!(mesh_buffers.colors.len(), vertices_after);
+        assert_eq!(uvs.uvs.len(), vertices_after);
+        assert_eq!(uvs.tangents.len(), vertices_after);
+        assert_eq!(uvs.blend_weights.len(), vertices_after);
+
+        for t in mesh_buffers.indices.chunks_exact(3) {
+            let [a, b, c] =
+                [t[0], t[1], t[2]].map(|i| Vec3::from_array(mesh_buffers.positions[i as usize]));
+            let normal = (b - a).cross(c - a);
+            // Away from the rounded edges, every triangle lies on one side of the box.
+            if normal.normalize_or_zero().abs().max_element() < 0.99 {
+                continue;
+            }
+            let side = side(normal);
+            for i in t {
+                let i = *i as usize;
+                let world = (Vec3::from_array(mesh_buffers.positions[i]) + options.origin) * 0.5;
+                assert_eq!(uvs.uvs[i], side_uv(world, side));
+                let tangent = uvs.tangents[i];
+                let normal = Vec3::from_array(mesh_buffers.normals[i]);
+                assert!((Vec3::from_slice(&tangent).length() - 1.0).abs() < 1e-3);
+                assert!(Vec3::from_slice(&tangent).dot(normal).abs() < 0.1);
+                assert_eq!(tangent[3].abs(), 1.0);
+                let weights = uvs.blend_weights[i];
+                assert!((weights[0] + weights[1] + weights[2] - 1.0).abs() < 1e-4);
+            }
+        }
+
+        let mut triplanar = MeshBuffers::new();
+        mdc_mesh_generation(0.0, &mut triplanar, false, bounds, true, &cuboid);
+        let options = UvOptions {
+            projection: UvProjection::Triplanar,
+            ..Default::default()
+        };
+        let uvs = generate_mdc_uvs(&mut triplanar, &options);
+        assert_eq!(triplanar.positions.len(), vertices);
+        assert_eq!(uvs.uvs.len(), vertices);
+        assert!(uvs.blend_weights.is_empty());
+    }
+}
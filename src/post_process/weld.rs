@@ -0,0 +1,206 @@
+// Vertex welding: merges vertices that agree on position, normal and the other attributes within
+// a tolerance, such as the unshared corners of flat-shaded MDC output, and drops the triangles
+// that collapse in the process. The first vertex of every group keeps its attributes.
+
+use std::collections::HashMap;
+
+use glam::{IVec3, Vec3};
+
+use crate::{manifold_dual_contouring::mdc, marching_cubes::mc};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeldTolerance {
+    /// Largest distance between welded positions.
+    pub position: f32,
+    /// Largest angle in degrees between welded normals; 180 welds by position alone.
+    pub normal_angle: f32,
+    /// Largest difference in any color or uv component.
+    pub attribute: f32,
+}
+
+impl Default for WeldTolerance {
+    fn default() -> Self {
+        Self {
+            position: 1e-5,
+            normal_angle: 1.0,
+            attribute: 1e-3,
+        }
+    }
+}
+
+/// Welds an MDC mesh by position, normal, color and material. Returns the number of vertices
+/// removed.
+pub fn weld_mdc_mesh(mesh_buffers: &mut mdc::MeshBuffers, tolerance: &WeldTolerance) -> usize {
+    let (colors, materials) = (&mesh_buffers.colors, &mesh_buffers.materials);
+    let remap = weld_remap(
+        &mesh_buffers.positions,
+        &mesh_buffers.normals,
+        tolerance,
+        |a, b| {
+            (colors.is_empty()
+                || (0..4).all(|i| within(colors[a][i], colors[b][i], tolerance.attribute)))
+                && (materials.is_empty() || materials[a] == materials[b])
+        },
+    );
+    let before = mesh_buffers.positions.len();
+    gather(&mut mesh_buffers.positions, &remap);
+    gather(&mut mesh_buffers.normals, &remap);
+    gather(&mut mesh_buffers.colors, &remap);
+    gather(&mut mesh_buffers.materials, &remap);
+    mesh_buffers.indices = remap_indices(&mesh_buffers.indices, &remap);
+    before - mesh_buffers.positions.len()
+}
+
+/// Welds an MC, surface nets or marching tetrahedra mesh by position, normal and uv, so
+/// vertices of different materials stay apart. Returns the number of vertices removed.
+pub fn weld_mc_mesh(mesh_buffers: &mut mc::MeshBuffers, tolerance: &WeldTolerance) -> usize {
+    let uvs = &mesh_buffers.uvs;
+    let remap = weld_remap(
+        &mesh_buffers.positions,
+        &mesh_buffers.normals,
+        tolerance,
+        |a, b| uvs.is_empty() || (0..2).all(|i| within(uvs[a][i], uvs[b][i], tolerance.attribute)),
+    );
+    let before = mesh_buffers.positions.len();
+    gather(&mut mesh_buffers.positions, &remap);
+    gather(&mut mesh_buffers.normals, &remap);
+    gather(&mut mesh_buffers.uvs, &remap);
+    mesh_buffers.indices = remap_indices(&mesh_buffers.indices, &remap);
+    before - mesh_buffers.positions.len()
+}
+
+/// NaN, which flat shading gives the corners of degenerate triangles, matches anything.
+fn within(a: f32, b: f32, tolerance: f32) -> bool {
+    (a - b).abs() <= tolerance || (a - b).is_nan()
+}
+
+/// Index of every vertex's welded vertex. Welded vertices are numbered in the order their
+/// first member appears, so `remap[v] == v` only for vertices before the first weld.
+fn weld_remap(
+    positions: &[[f32; 3]],
+    normals: &[[f32; 3]],
+    tolerance: &WeldTolerance,
+    attributes_match: impl Fn(usize, usize) -> bool,
+) -> Vec<u32> {
+    let cell_size = tolerance.position.max(1e-6);
+    let min_normal_cos = tolerance.normal_angle.to_radians().cos();
+    let cell = |p: Vec3| (p / cell_size).floor().as_ivec3();
+    // Missing or degenerate normals match anything, like NaN attributes.
+    let aligned = |a: usize, b: usize| {
+        let normal = |v: usize| Vec3::from_array(normals[v]).normalize_or_zero();
+        normals.is_empty()
+            || tolerance.normal_angle >= 180.0
+            || normal(a) == Vec3::ZERO
+            || normal(b) == Vec3::ZERO
+            || normal(a).dot(normal(b)) >= min_normal_cos
+    };
+    // Vertices that start a welded vertex, by grid cell, with their new index.
+    let mut grid: HashMap<IVec3, Vec<(usize, u32)>> = HashMap::new();
+    let mut remap = Vec::with_capacity(positions.len());
+    let mut welded = 0;
+    for (v, position) in positions.iter().enumerate() {
+        let p = Vec3::from_array(*position);
+        let home = cell(p);
+        let mut found = None;
+        'search: for dz in -1..=1 {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let Some(candidates) = grid.get(&(home + IVec3::new(dx, dy, dz))) else {
+                        continue;
+                    };
+                    for &(other, index) in candidates {
+                        let close =
+                            p.distance(Vec3::from_array(positions[other])) <= tolerance.position;
+                        if close && aligned(v, other) && attributes_match(v, other) {
+                            found = Some(index);
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+        let index = found.unwrap_or_else(|| {
+            grid.entry(home).or_default().push((v, welded));
+            welded += 1;
+            welded - 1
+        });
+        remap.push(index);
+    }
+    remap
+}
+
+/// Keeps the first value mapped to every new index.
+fn gather<T: Copy>(values: &mut Vec<T>, remap: &[u32]) {
+    if values.is_empty() {
+        return;
+    }
+    let mut gathered = Vec::new();
+    for (value, new) in values.iter().zip(remap) {
+        if *new as usize == gathered.len() {
+            gathered.push(*value);
+        }
+    }
+    *values = gathered;
+}
+
+/// Remapped triangles, without those that lost a corner to a weld.
+fn remap_indices(indices: &[u32], remap: &[u32]) -> Vec<u32> {
+    indices
+        .chunks_exact(3)
+        .map(|t| [t[0], t[1], t[2]].map(|i| remap[i as usize]))
+        .filter(|[a, b, c]| a != b && b != c && c != a)
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::{
+        manifold_dual_contouring::{
+            mdc::{GridBounds, MeshBuffers, mdc_mesh_generation},
+            sampler::CuboidSampler,
+        },
+        post_process::weld::{WeldTolerance, weld_mdc_mesh},
+        validate::mesh::validate_mesh,
+    };
+
+    #[test]
+    fn test_weld_flat_shaded_mdc() {
+        let cuboid = CuboidSampler::new(Vec3::new(0.3, 0.2, -0.1), Vec3::new(6.5, 5.2, 4.4));
+        let bounds = GridBounds::centered(24.0, 16);
+        let flat_mesh = || {
+            let mut mesh_buffers = MeshBuffers::new();
+            mdc_mesh_generation(0.0, &mut mesh_buffers, true, bounds, true, &cuboid);
+            mesh_buffers
+        };
+        let mut mesh_buffers = flat_mesh();
+        let (vertices, triangles) = (mesh_buffers.positions.len(), mesh_buffers.indices.len() / 3);
+        assert_eq!(vertices, triangles * 3);
+
+        // Quads with the same face normal share their corners, the others stay apart.
+        let removed = weld_mdc_mesh(&mut mesh_buffers, &WeldTolerance::default());
+        assert!(removed > vertices / 2);
+        assert_eq!(mesh_buffers.positions.len(), vertices - removed);
+        assert_eq!(mesh_buffers.normals.len(), mesh_buffers.positions.len());
+        assert_eq!(mesh_buffers.colors.len(), mesh_buffers.positions.len());
+        let report = validate_mesh(&mesh_buffers.positions, &mesh_buffers.indices, false);
+        assert_eq!(report.triangles, triangles);
+        assert!(report.boundary_edges > 0);
+
+        let mut by_position = flat_mesh();
+        weld_mdc_mesh(
+            &mut by_position,
+            &WeldTolerance {
+                normal_angle: 180.0,
+                attribute: f32::INFINITY,
+                ..Default::default()
+            },
+        );
+        let report = validate_mesh(&by_position.positions, &by_position.indices, false);
+        assert!(report.is_watertight(), "{report:?}");
+        assert_eq!(report.euler_characteristic, 2);
+        assert!(by_position.positions.len() < mesh_buffers.positions.len());
+    }
+}
@@ -0,0 +1,91 @@
+use glam::{DVec3, Vec3};
+
+use crate::qef::solver::{QefSolveOptions, QefSolver};
+
+/// Cyclic Jacobi eigendecomposition of `AᵀA` in f64. Slower than the f32 backends, but keeps
+/// its accuracy when clusters accumulate many nearly parallel normals.
+#[derive(Debug, Clone, Copy)]
+pub struct DoubleQefSolver {
+    svd_tolerance: f64,
+    svd_sweeps: i32,
+    pseudo_inverse_threshold: f64,
+}
+
+impl DoubleQefSolver {
+    pub fn new(options: &QefSolveOptions) -> Self {
+        Self {
+            svd_tolerance: options.svd_tolerance as f64,
+            svd_sweeps: options.svd_sweeps,
+            pseudo_inverse_threshold: options.pseudo_inverse_threshold as f64,
+        }
+    }
+
+    /// Eigenvalues and eigenvectors (as columns of the second value) of the symmetric `a`.
+    fn eigen(&self, mut a: [[f64; 3]; 3]) -> ([f64; 3], [[f64; 3]; 3]) {
+        let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        let norm = a.iter().flatten().map(|x| x * x).sum::<f64>().sqrt();
+        for _ in 0..self.svd_sweeps {
+            let off = (a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2]).sqrt();
+            if off <= self.svd_tolerance * norm {
+                break;
+            }
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                // a = Jᵀ a J and v = v J for the rotation J in the (p, q) plane.
+                for row in &mut a {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+                a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+                for row in &mut v {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+            }
+        }
+        ([a[0][0], a[1][1], a[2][2]], v)
+    }
+}
+
+impl QefSolver for DoubleQefSolver {
+    fn solve(&self, ata: &[f32; 6], atb: Vec3, mass_point: Vec3) -> Vec3 {
+        self.solve_f64(
+            &ata.map(|x| x as f64),
+            atb.as_dvec3(),
+            mass_point.as_dvec3(),
+        )
+        .as_vec3()
+    }
+
+    fn solve_f64(&self, ata: &[f64; 6], atb: DVec3, mass_point: DVec3) -> DVec3 {
+        let [m00, m01, m02, m11, m12, m22] = *ata;
+        let a = [[m00, m01, m02], [m01, m11, m12], [m02, m12, m22]];
+        let a_mp = DVec3::new(
+            m00 * mass_point.x + m01 * mass_point.y + m02 * mass_point.z,
+            m01 * mass_point.x + m11 * mass_point.y + m12 * mass_point.z,
+            m02 * mass_point.x + m12 * mass_point.y + m22 * mass_point.z,
+        );
+        let b = atb - a_mp;
+        let (sigma, v) = self.eigen(a);
+        let tol = self.pseudo_inverse_threshold * sigma.iter().fold(0.0f64, |m, s| m.max(s.abs()));
+        let mut x = DVec3::ZERO;
+        for (i, s) in sigma.iter().enumerate() {
+            if s.abs() < tol || *s == 0.0 {
+                continue;
+            }
+            let column = DVec3::new(v[0][i], v[1][i], v[2][i]);
+            x += column * (column.dot(b) / s);
+        }
+        mass_point + x
+    }
+}
@@ -0,0 +1,45 @@
+use glam::Vec3;
+
+use crate::{
+    manifold_dual_contouring::{smat3::SMat3, svd::solve_symmetric},
+    qef::solver::{QefSolveOptions, QefSolver},
+};
+
+/// The `svd.rs` solver. Falls back to the mass point if the solve produces NaN.
+#[derive(Debug, Clone, Copy)]
+pub struct JacobiQefSolver {
+    svd_tolerance: f32,
+    svd_sweeps: i32,
+    pseudo_inverse_threshold: f32,
+}
+
+impl JacobiQefSolver {
+    pub fn new(options: &QefSolveOptions) -> Self {
+        Self {
+            svd_tolerance: options.svd_tolerance,
+            svd_sweeps: options.svd_sweeps,
+            pseudo_inverse_threshold: options.pseudo_inverse_threshold,
+        }
+    }
+}
+
+impl QefSolver for JacobiQefSolver {
+    fn solve(&self, ata: &[f32; 6], atb: Vec3, mass_point: Vec3) -> Vec3 {
+        let ata = SMat3::from_upper(ata);
+        let atb = atb - ata.vmul(mass_point);
+        let mut x = Vec3::ZERO;
+        let result = solve_symmetric(
+            &ata,
+            &atb,
+            &mut x,
+            self.svd_tolerance,
+            self.svd_sweeps,
+            self.pseudo_inverse_threshold,
+        );
+        if result.is_nan() {
+            mass_point
+        } else {
+            x + mass_point
+        }
+    }
+}
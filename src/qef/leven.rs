@@ -1,5 +1,9 @@
+// Leven-style symmetric SVD (qef.glsl from GPU dual contouring): fixed sweeps, no early exit.
+
 use glam::{Vec2, Vec3, Vec4};
 
+use crate::qef::solver::{QefSolveOptions, QefSolver};
+
 #[derive(Clone)]
 pub struct LevenQefSolver {
     svd_num_sweeps: i32,
@@ -7,10 +11,10 @@ pub struct LevenQefSolver {
 }
 
 impl LevenQefSolver {
-    pub fn new() -> Self {
+    pub fn new(options: &QefSolveOptions) -> Self {
         LevenQefSolver {
-            svd_num_sweeps: 10,
-            psuedo_inverse_threshold: 0.1,
+            svd_num_sweeps: options.svd_sweeps,
+            psuedo_inverse_threshold: options.pseudo_inverse_threshold,
         }
     }
 
@@ -144,17 +148,16 @@ impl LevenQefSolver {
     }
 
     fn svd_invdet(&self, x: f32, tol: f32) -> f32 {
-        if x.abs() < tol || (1.0 / x).abs() < tol {
-            0.0
-        } else {
-            1.0 / x
-        }
+        if x.abs() < tol { 0.0 } else { 1.0 / x }
     }
 
     fn svd_pseudoinverse(&self, sigma: &Vec4, mat3x3_v: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
-        let d0 = self.svd_invdet(sigma.x, self.psuedo_inverse_threshold);
-        let d1 = self.svd_invdet(sigma.y, self.psuedo_inverse_threshold);
-        let d2 = self.svd_invdet(sigma.z, self.psuedo_inverse_threshold);
+        // Relative to the largest singular value, so clusters with many points keep their
+        // well-constrained directions instead of collapsing to the mass point.
+        let tol = self.psuedo_inverse_threshold * sigma.truncate().abs().max_element();
+        let d0 = self.svd_invdet(sigma.x, tol);
+        let d1 = self.svd_invdet(sigma.y, tol);
+        let d2 = self.svd_invdet(sigma.z, tol);
         let v0 = [mat3x3_v[0][0], mat3x3_v[1][0], mat3x3_v[2][0]];
         let v1 = [mat3x3_v[0][1], mat3x3_v[1][1], mat3x3_v[2][1]];
         let v2 = [mat3x3_v[0][2], mat3x3_v[1][2], mat3x3_v[2][2]];
@@ -192,14 +195,12 @@ impl LevenQefSolver {
         result.z = mat3x3_tri_a[2] * v.x + mat3x3_tri_a[4] * v.y + mat3x3_tri_a[5] * v.z;
         result
     }
+}
 
-    pub fn solve(&self, mat3x3_tri_ata: &[f32; 6], atb: Vec4, masspoint: Vec4) -> Vec4 {
-        let mut masspoint = masspoint;
-        masspoint = masspoint / masspoint.w.max(1.0);
-        let mut a_mp = self.svd_vmul_sym(mat3x3_tri_ata, masspoint);
-        a_mp = atb - a_mp;
-        let solved_pos = self.svd_solve_ata_atb(mat3x3_tri_ata, a_mp);
-        let solved_pos = solved_pos + masspoint;
-        solved_pos
+impl QefSolver for LevenQefSolver {
+    fn solve(&self, ata: &[f32; 6], atb: Vec3, mass_point: Vec3) -> Vec3 {
+        let mass_point = mass_point.extend(1.0);
+        let a_mp = atb.extend(0.0) - self.svd_vmul_sym(ata, mass_point);
+        (self.svd_solve_ata_atb(ata, a_mp) + mass_point).truncate()
     }
 }
@@ -0,0 +1,4 @@
+pub mod double;
+pub mod jacobi;
+pub mod leven;
+pub mod solver;
@@ -0,0 +1,204 @@
+use glam::{DVec3, Vec3};
+
+use crate::qef::{double::DoubleQefSolver, jacobi::JacobiQefSolver, leven::LevenQefSolver};
+
+/// Minimises a quadratic error function built from hermite data.
+pub trait QefSolver {
+    /// `ata` is the upper triangle of `AᵀA` (m00, m01, m02, m11, m12, m22) and `mass_point` the
+    /// average intersection point, which directions the normals leave unconstrained fall back to.
+    fn solve(&self, ata: &[f32; 6], atb: Vec3, mass_point: Vec3) -> Vec3;
+
+    /// `solve` for data accumulated in f64. Backends that work in f32 round it first.
+    fn solve_f64(&self, ata: &[f64; 6], atb: DVec3, mass_point: DVec3) -> DVec3 {
+        self.solve(&ata.map(|m| m as f32), atb.as_vec3(), mass_point.as_vec3())
+            .as_dvec3()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QefSolverKind {
+    /// Symmetric Jacobi SVD with early exit, the `manifold_dual_contouring` default.
+    #[default]
+    Jacobi,
+    /// Fixed-sweep SVD, the `manifold_dual_contouring_2` default.
+    Leven,
+    /// Cyclic Jacobi eigendecomposition in f64, for badly conditioned data.
+    Double,
+}
+
+/// Which backend solves the QEFs of a generation, and how.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QefSolveOptions {
+    pub solver: QefSolverKind,
+    /// Sweeps stop once the off-diagonal norm drops below this fraction of the matrix norm.
+    /// Ignored by `Leven`.
+    pub svd_tolerance: f32,
+    pub svd_sweeps: i32,
+    /// Singular values below this are treated as zero. Absolute for `Jacobi`, relative to the
+    /// largest singular value for `Leven` and `Double`.
+    pub pseudo_inverse_threshold: f32,
+    /// Keep every vertex inside the cell it represents instead of trusting the minimiser.
+    /// Honoured by `manifold_dual_contouring`; `manifold_dual_contouring_2` always does this.
+    pub bound_to_cell: bool,
+}
+
+impl QefSolveOptions {
+    /// The settings each backend is tuned for.
+    pub fn new(solver: QefSolverKind) -> Self {
+        let (svd_tolerance, svd_sweeps, pseudo_inverse_threshold) = match solver {
+            QefSolverKind::Jacobi => (1e-6, 4, 1e-6),
+            QefSolverKind::Leven => (1e-6, 10, 0.1),
+            QefSolverKind::Double => (1e-6, 8, 0.1),
+        };
+        Self {
+            solver,
+            svd_tolerance,
+            svd_sweeps,
+            pseudo_inverse_threshold,
+            bound_to_cell: false,
+        }
+    }
+
+    pub fn solve(&self, ata: &[f32; 6], atb: Vec3, mass_point: Vec3) -> Vec3 {
+        match self.solver {
+            QefSolverKind::Jacobi => JacobiQefSolver::new(self).solve(ata, atb, mass_point),
+            QefSolverKind::Leven => LevenQefSolver::new(self).solve(ata, atb, mass_point),
+            QefSolverKind::Double => DoubleQefSolver::new(self).solve(ata, atb, mass_point),
+        }
+    }
+
+    pub fn solve_f64(&self, ata: &[f64; 6], atb: DVec3, mass_point: DVec3) -> DVec3 {
+        match self.solver {
+            QefSolverKind::Jacobi => JacobiQefSolver::new(self).solve_f64(ata, atb, mass_point),
+            QefSolverKind::Leven => LevenQefSolver::new(self).solve_f64(ata, atb, mass_point),
+            QefSolverKind::Double => DoubleQefSolver::new(self).solve_f64(ata, atb, mass_point),
+        }
+    }
+}
+
+impl Default for QefSolveOptions {
+    fn default() -> Self {
+        Self::new(QefSolverKind::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::{DVec3, EulerRot, Quat, Vec3};
+
+    use crate::qef::solver::{QefSolveOptions, QefSolverKind};
+
+    /// Hermite samples around a randomly rotated corner of `planes` orthogonal planes.
+    struct Problem {
+        corner: Vec3,
+        planes: Vec<(Vec3, Vec3)>,
+    }
+
+    impl Problem {
+        fn qef(&self) -> ([f32; 6], Vec3, Vec3) {
+            let mut ata = [0.0; 6];
+            let mut atb = Vec3::ZERO;
+            let mut mass_point = Vec3::ZERO;
+            for (p, n) in &self.planes {
+                ata[0] += n.x * n.x;
+                ata[1] += n.x * n.y;
+                ata[2] += n.x * n.z;
+                ata[3] += n.y * n.y;
+                ata[4] += n.y * n.z;
+                ata[5] += n.z * n.z;
+                atb += *n * n.dot(*p);
+                mass_point += *p;
+            }
+            (ata, atb, mass_point / self.planes.len() as f32)
+        }
+
+        fn residual(&self, x: Vec3) -> f32 {
+            self.planes.iter().map(|(p, n)| n.dot(x - *p).powi(2)).sum()
+        }
+    }
+
+    fn corpus(count: usize, planes: usize) -> Vec<Problem> {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut random = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 40) as f32 / (1u64 << 24) as f32
+        };
+        (0..count)
+            .map(|_| {
+                let rotation = Quat::from_euler(
+                    EulerRot::XYZ,
+                    random() * 6.3,
+                    random() * 6.3,
+                    random() * 6.3,
+                );
+                let corner = Vec3::new(random(), random(), random()) * 8.0;
+                let axes = [Vec3::X, Vec3::Y, Vec3::Z].map(|axis| rotation * axis);
+                let planes = (0..planes)
+                    .flat_map(|i| {
+                        let (u, v) = (axes[(i + 1) % 3], axes[(i + 2) % 3]);
+                        (0..3)
+                            .map(|_| (corner + u * random() + v * random(), axes[i]))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect();
+                Problem { corner, planes }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_solvers_on_corner_corpus() {
+        let kinds = [
+            QefSolverKind::Jacobi,
+            QefSolverKind::Leven,
+            QefSolverKind::Double,
+        ];
+        for planes in 1..=3 {
+            for problem in corpus(200, planes) {
+                let (ata, atb, mass_point) = problem.qef();
+                let mass_residual = problem.residual(mass_point);
+                for kind in kinds {
+                    let x = QefSolveOptions::new(kind).solve(&ata, atb, mass_point);
+                    assert!(x.is_finite(), "{kind:?}");
+                    assert!(
+                        problem.residual(x) <= mass_residual + 1e-3,
+                        "{kind:?} does worse than the mass point"
+                    );
+                    if planes == 3 {
+                        assert!(
+                            x.distance(problem.corner) < 1e-3,
+                            "{kind:?} misses the corner by {}",
+                            x.distance(problem.corner)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_double_solver_keeps_f64_precision() {
+        let offset = DVec3::new(40_000.0, -5_000.0, 30_000.0);
+        for problem in corpus(50, 3) {
+            let mut ata = [0.0f64; 6];
+            let mut atb = DVec3::ZERO;
+            for (p, n) in &problem.planes {
+                let (p, n) = (p.as_dvec3() + offset, n.as_dvec3());
+                ata[0] += n.x * n.x;
+                ata[1] += n.x * n.y;
+                ata[2] += n.x * n.z;
+                ata[3] += n.y * n.y;
+                ata[4] += n.y * n.z;
+                ata[5] += n.z * n.z;
+                atb += n * n.dot(p);
+            }
+            let x = QefSolveOptions::new(QefSolverKind::Double).solve_f64(&ata, atb, offset);
+            let error = x.distance(problem.corner.as_dvec3() + offset);
+            // The corpus planes are only f32-accurate. Rounded to f32 this far out, the sums would
+            // be off by millimetres.
+            assert!(error < 1e-5, "misses the corner by {error}");
+        }
+    }
+}
@@ -0,0 +1,232 @@
+// Versioned binary container: magic, version, payload length, payload, CRC-32 of the payload.
+// All values are little-endian.
+
+use std::io::{self, Read, Write};
+
+use glam::{IVec3, Vec3};
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            crc = if crc & 1 != 0 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            k += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc = CRC_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+pub(crate) fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+pub(crate) fn write_container<W: Write>(
+    writer: &mut W,
+    magic: &[u8; 4],
+    version: u32,
+    payload: &[u8],
+) -> io::Result<()> {
+    writer.write_all(magic)?;
+    writer.write_all(&version.to_le_bytes())?;
+    writer.write_all(&(payload.len() as u64).to_le_bytes())?;
+    writer.write_all(payload)?;
+    writer.write_all(&crc32(payload).to_le_bytes())
+}
+
+/// Reads a container written with `magic`, returning its version and verified payload.
+pub(crate) fn read_container<R: Read>(
+    reader: &mut R,
+    magic: &[u8; 4],
+    max_version: u32,
+) -> io::Result<(u32, Vec<u8>)> {
+    let mut header = [0u8; 16];
+    reader.read_exact(&mut header)?;
+    if &header[0..4] != magic {
+        return Err(invalid_data("bad magic"));
+    }
+    let version = u32::from_le_bytes(header[4..8].try_into().unwrap());
+    if version == 0 || version > max_version {
+        return Err(invalid_data("unsupported version"));
+    }
+    let length = u64::from_le_bytes(header[8..16].try_into().unwrap());
+    let mut payload = Vec::new();
+    reader.take(length).read_to_end(&mut payload)?;
+    if payload.len() as u64 != length {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    let mut checksum = [0u8; 4];
+    reader.read_exact(&mut checksum)?;
+    if u32::from_le_bytes(checksum) != crc32(&payload) {
+        return Err(invalid_data("checksum mismatch"));
+    }
+    Ok((version, payload))
+}
+
+#[derive(Default)]
+pub(crate) struct ByteWriter {
+    pub(crate) bytes: Vec<u8>,
+}
+
+impl ByteWriter {
+    #[inline]
+    pub(crate) fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    #[inline]
+    pub(crate) fn u16(&mut self, value: u16) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    #[inline]
+    pub(crate) fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    #[inline]
+    pub(crate) fn i32(&mut self, value: i32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    #[inline]
+    pub(crate) fn f32(&mut self, value: f32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub(crate) fn vec3(&mut self, value: Vec3) {
+        self.f32(value.x);
+        self.f32(value.y);
+        self.f32(value.z);
+    }
+
+    pub(crate) fn ivec3(&mut self, value: IVec3) {
+        self.i32(value.x);
+        self.i32(value.y);
+        self.i32(value.z);
+    }
+
+    pub(crate) fn i16_slice(&mut self, values: &[i16]) {
+        for value in values {
+            self.bytes.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+}
+
+pub(crate) struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub(crate) fn take(&mut self, count: usize) -> io::Result<&'a [u8]> {
+        if self.bytes.len() < count {
+            return Err(invalid_data("truncated payload"));
+        }
+        let (head, tail) = self.bytes.split_at(count);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    #[inline]
+    pub(crate) fn u8(&mut self) -> io::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    #[inline]
+    pub(crate) fn u16(&mut self) -> io::Result<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    #[inline]
+    pub(crate) fn u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    #[inline]
+    pub(crate) fn i32(&mut self) -> io::Result<i32> {
+        Ok(i32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    #[inline]
+    pub(crate) fn f32(&mut self) -> io::Result<f32> {
+        Ok(f32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn vec3(&mut self) -> io::Result<Vec3> {
+        Ok(Vec3::new(self.f32()?, self.f32()?, self.f32()?))
+    }
+
+    pub(crate) fn ivec3(&mut self) -> io::Result<IVec3> {
+        Ok(IVec3::new(self.i32()?, self.i32()?, self.i32()?))
+    }
+
+    pub(crate) fn i16_vec(&mut self, count: usize) -> io::Result<Vec<i16>> {
+        let length = count
+            .checked_mul(2)
+            .ok_or_else(|| invalid_data("truncated payload"))?;
+        let bytes = self.take(length)?;
+        Ok(bytes
+            .chunks_exact(2)
+            .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+            .collect())
+    }
+}
+
+/// Checks that `read` refuses a bad checksum, a truncated file, a wrong magic and a newer
+/// version of the valid container `bytes`.
+#[cfg(test)]
+pub(crate) fn assert_rejects_corruption<T>(bytes: &[u8], read: impl Fn(&[u8]) -> io::Result<T>) {
+    let error_kind = |bytes: &[u8]| read(bytes).err().map(|e| e.kind());
+    assert!(read(bytes).is_ok());
+
+    let mut bad_crc = bytes.to_vec();
+    bad_crc[20] ^= 0x10;
+    assert_eq!(error_kind(&bad_crc), Some(io::ErrorKind::InvalidData));
+
+    for length in [0, 10, 16, bytes.len() / 2, bytes.len() - 1] {
+        assert_eq!(
+            error_kind(&bytes[..length]),
+            Some(io::ErrorKind::UnexpectedEof),
+            "truncated to {length} bytes"
+        );
+    }
+
+    let mut bad_magic = bytes.to_vec();
+    bad_magic[0] = b'X';
+    assert_eq!(error_kind(&bad_magic), Some(io::ErrorKind::InvalidData));
+
+    let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+    for version in [0, version + 1] {
+        let mut bad_version = bytes.to_vec();
+        bad_version[4..8].copy_from_slice(&version.to_le_bytes());
+        assert_eq!(
+            error_kind(&bad_version),
+            Some(io::ErrorKind::InvalidData),
+            "version {version}"
+        );
+    }
+}
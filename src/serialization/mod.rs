@@ -0,0 +1,3 @@
+pub(crate) mod container;
+pub mod octree;
+pub mod volume;
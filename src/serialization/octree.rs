@@ -0,0 +1,302 @@
+// MDC chunk files: grid bounds and mesh settings, then the octree in pre-order. Internal nodes
+// store a child mask; leaves store their corner signs and the accumulated QEF of each vertex.
+// Clusters are rebuilt on load, which needs no sampling.
+
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+    sync::Arc,
+};
+
+use parking_lot::Mutex;
+
+use crate::{
+    manifold_dual_contouring::{
+        mdc::{GridBounds, MdcChunk, MeshBuffers, calculate_indexes},
+        octree::{NodeType, OctreeNode, Vertex},
+        tables::T_CORNER_DELTAS,
+    },
+    qef::solver::{QefSolveOptions, QefSolverKind},
+    serialization::container::{
+        ByteReader, ByteWriter, invalid_data, read_container, write_container,
+    },
+};
+
+const MAGIC: &[u8; 4] = b"IMDC";
+// Version 2 added the QEF solver options; version 1 files are solved with the defaults.
+const VERSION: u32 = 2;
+/// Largest grid resolution a chunk file may declare, which also bounds the octree depth.
+pub const MAX_RESOLUTION: i32 = 1 << 12;
+
+impl MdcChunk {
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut out = ByteWriter::default();
+        out.vec3(self.bounds.min);
+        out.f32(self.bounds.cell_size);
+        out.ivec3(self.bounds.resolution);
+        out.f32(self.threshold);
+        out.u8(self.enforce_manifold as u8);
+        write_qef_options(&mut out, &self.qef);
+        write_node(&mut out, &self.tree);
+        write_container(writer, MAGIC, VERSION, &out.bytes)
+    }
+
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let (version, payload) = read_container(reader, MAGIC, VERSION)?;
+        let mut input = ByteReader::new(&payload);
+        let bounds = GridBounds::new(input.vec3()?, input.f32()?, input.ivec3()?);
+        if bounds.cell_size.is_nan()
+            || bounds.cell_size <= 0.0
+            || bounds.resolution.min_element() < 1
+        {
+            return Err(invalid_data("bad grid bounds"));
+        }
+        if bounds.resolution.max_element() > MAX_RESOLUTION {
+            return Err(invalid_data("grid resolution too large"));
+        }
+        let threshold = input.f32()?;
+        let enforce_manifold = input.u8()? != 0;
+        let qef = if version >= 2 {
+            read_qef_options(&mut input)?
+        } else {
+            QefSolveOptions::default()
+        };
+        let size = bounds.octree_size();
+        let mut tree = Box::new(OctreeNode::with_params(
+            bounds.min,
+            size,
+            bounds.cell_size,
+            NodeType::Internal,
+        ));
+        read_node(&mut input, &mut tree, size.trailing_zeros())?;
+        if !input.is_empty() {
+            return Err(invalid_data("trailing bytes"));
+        }
+        tree.cluster_cell_base(&qef);
+        let mut mesh_buffers = MeshBuffers::new();
+        tree.generate_vertex_buffer(&mut mesh_buffers, &qef);
+        calculate_indexes(&tree, threshold, enforce_manifold, &mut mesh_buffers, false);
+        Ok(MdcChunk {
+            bounds,
+            mesh_buffers,
+            tree,
+            threshold,
+            enforce_manifold,
+            qef,
+        })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_from(&mut BufReader::new(File::open(path)?))
+    }
+}
+
+fn write_qef_options(out: &mut ByteWriter, qef: &QefSolveOptions) {
+    out.u8(match qef.solver {
+        QefSolverKind::Jacobi => 0,
+        QefSolverKind::Leven => 1,
+        QefSolverKind::Double => 2,
+    });
+    out.f32(qef.svd_tolerance);
+    out.i32(qef.svd_sweeps);
+    out.f32(qef.pseudo_inverse_threshold);
+    out.u8(qef.bound_to_cell as u8);
+}
+
+fn read_qef_options(input: &mut ByteReader) -> io::Result<QefSolveOptions> {
+    let solver = match input.u8()? {
+        0 => QefSolverKind::Jacobi,
+        1 => QefSolverKind::Leven,
+        2 => QefSolverKind::Double,
+        _ => return Err(invalid_data("unknown QEF solver")),
+    };
+    Ok(QefSolveOptions {
+        solver,
+        svd_tolerance: input.f32()?,
+        svd_sweeps: input.i32()?,
+        pseudo_inverse_threshold: input.f32()?,
+        bound_to_cell: input.u8()? != 0,
+    })
+}
+
+fn write_node(out: &mut ByteWriter, node: &OctreeNode) {
+    if node.size == 1 {
+        out.u8(node.corners);
+        out.u8(node.vertices.len() as u8);
+        for v_arc in &node.vertices {
+            let v = v_arc.lock();
+            let qef = &v.qef;
+            for value in [
+                qef.ata.m00,
+                qef.ata.m01,
+                qef.ata.m02,
+                qef.ata.m11,
+                qef.ata.m12,
+                qef.ata.m22,
+            ] {
+                out.f32(value);
+            }
+            out.vec3(qef.atb);
+            out.f32(qef.btb);
+            out.i32(qef.num_points);
+            out.vec3(qef.x);
+            out.vec3(qef.mass_point);
+            out.vec3(v.normal);
+            let eis = v.eis.unwrap_or([0; 12]);
+            let mask = (0..12).fold(0u16, |mask, e| mask | ((eis[e] != 0) as u16) << e);
+            out.u16(mask);
+        }
+        return;
+    }
+    let mask = (0..8).fold(0u8, |mask, i| {
+        mask | (node.children[i].is_some() as u8) << i
+    });
+    out.u8(mask);
+    for child in node.children.iter().flatten() {
+        write_node(out, child);
+    }
+}
+
+/// Reads `node` and its subtree, which may be at most `depth` levels deep.
+fn read_node(input: &mut ByteReader, node: &mut OctreeNode, depth: u32) -> io::Result<()> {
+    if node.size == 1 {
+        node.node_type = NodeType::Leaf;
+        node.corners = input.u8()?;
+        let count = input.u8()?;
+        if count > 4 {
+            return Err(invalid_data("too many leaf vertices"));
+        }
+        for _ in 0..count {
+            let mut vertex = Vertex::new();
+            let qef = &mut vertex.qef;
+            qef.ata.m00 = input.f32()?;
+            qef.ata.m01 = input.f32()?;
+            qef.ata.m02 = input.f32()?;
+            qef.ata.m11 = input.f32()?;
+            qef.ata.m12 = input.f32()?;
+            qef.ata.m22 = input.f32()?;
+            qef.atb = input.vec3()?;
+            qef.btb = input.f32()?;
+            qef.num_points = input.i32()?;
+            qef.x = input.vec3()?;
+            qef.mass_point = input.vec3()?;
+            vertex.normal = input.vec3()?;
+            let mask = input.u16()?;
+            let mut ei = [0; 12];
+            for (e, value) in ei.iter_mut().enumerate() {
+                *value = ((mask >> e) & 1) as i32;
+            }
+            // Mirrors `construct_leaf`; the stored solution keeps the error identical.
+            vertex.euler = 1;
+            vertex.eis = Some(ei);
+            vertex.in_cell = node.child_index;
+            vertex.face_prop2 = true;
+            vertex.error = vertex.qef.get_error();
+            node.vertices.push(Arc::new(Mutex::new(vertex)));
+        }
+        return Ok(());
+    }
+    if depth == 0 {
+        return Err(invalid_data("octree too deep"));
+    }
+    let mask = input.u8()?;
+    let child_size = node.size / 2;
+    for (i, delta) in T_CORNER_DELTAS.iter().enumerate() {
+        if mask & (1 << i) == 0 {
+            continue;
+        }
+        let mut child = Box::new(OctreeNode::with_params(
+            node.position + *delta * (child_size as f32 * node.voxel_size),
+            child_size,
+            node.voxel_size,
+            NodeType::Internal,
+        ));
+        child.child_index = i as i32;
+        read_node(input, &mut child, depth - 1)?;
+        node.children[i] = Some(child);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use glam::{IVec3, Vec3};
+
+    use crate::{
+        manifold_dual_contouring::{
+            mdc::{GridBounds, MdcChunk, mdc_chunk_generation_with_options},
+            sampler::SphereSampler,
+        },
+        qef::solver::{QefSolveOptions, QefSolverKind},
+        serialization::{
+            container::{ByteWriter, assert_rejects_corruption, write_container},
+            octree::{MAGIC, MAX_RESOLUTION, VERSION},
+        },
+    };
+
+    fn chunk_bytes(chunk: &MdcChunk) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        chunk.write_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_chunk_round_trip() {
+        let qef = QefSolveOptions {
+            bound_to_cell: true,
+            ..QefSolveOptions::new(QefSolverKind::Leven)
+        };
+        let chunk = mdc_chunk_generation_with_options(
+            0.5,
+            GridBounds::centered(40.0, 24),
+            true,
+            qef,
+            &SphereSampler::new(Vec3::new(1.0, -2.0, 0.5), 14.0),
+        );
+        let loaded = MdcChunk::read_from(&mut chunk_bytes(&chunk).as_slice()).unwrap();
+        assert_eq!(loaded.bounds, chunk.bounds);
+        assert_eq!(loaded.threshold, chunk.threshold);
+        assert_eq!(loaded.enforce_manifold, chunk.enforce_manifold);
+        assert_eq!(loaded.qef, qef);
+        assert_eq!(loaded.mesh_buffers.positions, chunk.mesh_buffers.positions);
+        assert_eq!(loaded.mesh_buffers.normals, chunk.mesh_buffers.normals);
+        assert_eq!(loaded.mesh_buffers.indices, chunk.mesh_buffers.indices);
+    }
+
+    #[test]
+    fn test_chunk_rejects_corruption() {
+        let chunk = mdc_chunk_generation_with_options(
+            0.5,
+            GridBounds::centered(20.0, 8),
+            false,
+            QefSolveOptions::default(),
+            &SphereSampler::new(Vec3::ZERO, 6.0),
+        );
+        assert_rejects_corruption(&chunk_bytes(&chunk), |mut bytes| {
+            MdcChunk::read_from(&mut bytes)
+        });
+    }
+
+    #[test]
+    fn test_chunk_rejects_huge_resolution() {
+        for resolution in [MAX_RESOLUTION + 1, i32::MAX] {
+            let mut out = ByteWriter::default();
+            out.vec3(Vec3::ZERO);
+            out.f32(1.0);
+            out.ivec3(IVec3::new(1, resolution, 1));
+            let mut bytes = Vec::new();
+            write_container(&mut bytes, MAGIC, VERSION, &out.bytes).unwrap();
+            let error = MdcChunk::read_from(&mut bytes.as_slice()).err().unwrap();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+}
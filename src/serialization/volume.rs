@@ -0,0 +1,196 @@
+// Density files: voxel volumes brick by brick, and standalone baked grids as fed to
+// `mc_mesh_generation`.
+
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use crate::{
+    serialization::container::{
+        ByteReader, ByteWriter, invalid_data, read_container, write_container,
+    },
+    voxel_volume::volume::{BRICK_SAMPLES, BRICK_SIZE, Brick, VoxelVolume},
+};
+
+const VOLUME_MAGIC: &[u8; 4] = b"IMVV";
+const VOLUME_VERSION: u32 = 1;
+const GRID_MAGIC: &[u8; 4] = b"IMDG";
+const GRID_VERSION: u32 = 1;
+
+impl VoxelVolume {
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut out = ByteWriter::default();
+        out.f32(self.voxel_size());
+        out.u32(BRICK_SIZE as u32);
+        out.u32(self.brick_count() as u32);
+        for (coord, brick) in self.bricks() {
+            out.ivec3(*coord);
+            out.i16_slice(&brick.densities);
+            out.bytes.extend_from_slice(&brick.materials);
+        }
+        write_container(writer, VOLUME_MAGIC, VOLUME_VERSION, &out.bytes)
+    }
+
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let (_, payload) = read_container(reader, VOLUME_MAGIC, VOLUME_VERSION)?;
+        let mut input = ByteReader::new(&payload);
+        let voxel_size = input.f32()?;
+        if voxel_size.is_nan() || voxel_size <= 0.0 {
+            return Err(invalid_data("bad voxel size"));
+        }
+        if input.u32()? != BRICK_SIZE as u32 {
+            return Err(invalid_data("brick size mismatch"));
+        }
+        let count = input.u32()?;
+        let mut volume = VoxelVolume::new(voxel_size);
+        for _ in 0..count {
+            let coord = input.ivec3()?;
+            let mut brick = Brick::empty();
+            brick
+                .densities
+                .copy_from_slice(&input.i16_vec(BRICK_SAMPLES)?);
+            brick.materials.copy_from_slice(input.take(BRICK_SAMPLES)?);
+            volume.insert_brick(coord, brick);
+        }
+        if !input.is_empty() {
+            return Err(invalid_data("trailing bytes"));
+        }
+        Ok(volume)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_from(&mut BufReader::new(File::open(path)?))
+    }
+}
+
+/// A cubic grid of quantized densities and materials, x fastest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DensityGrid {
+    pub samples_per_dim: usize,
+    pub densities: Vec<i16>,
+    pub materials: Vec<u8>,
+}
+
+impl DensityGrid {
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let samples = self.samples_per_dim.pow(3);
+        if self.densities.len() != samples || self.materials.len() != samples {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "grid length does not match samples_per_dim",
+            ));
+        }
+        let mut out = ByteWriter::default();
+        out.u32(self.samples_per_dim as u32);
+        out.i16_slice(&self.densities);
+        out.bytes.extend_from_slice(&self.materials);
+        write_container(writer, GRID_MAGIC, GRID_VERSION, &out.bytes)
+    }
+
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let (_, payload) = read_container(reader, GRID_MAGIC, GRID_VERSION)?;
+        let mut input = ByteReader::new(&payload);
+        let samples_per_dim = input.u32()? as usize;
+        let samples = samples_per_dim
+            .checked_pow(3)
+            .ok_or_else(|| invalid_data("grid too large"))?;
+        let densities = input.i16_vec(samples)?;
+        let materials = input.take(samples)?.to_vec();
+        if !input.is_empty() {
+            return Err(invalid_data("trailing bytes"));
+        }
+        Ok(Self {
+            samples_per_dim,
+            densities,
+            materials,
+        })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_from(&mut BufReader::new(File::open(path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::{
+        serialization::{container::assert_rejects_corruption, volume::DensityGrid},
+        voxel_volume::{
+            brush::{Brush, BrushOp, BrushShape},
+            volume::VoxelVolume,
+        },
+    };
+
+    fn volume_bytes(volume: &VoxelVolume) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        volume.write_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_volume_round_trip() {
+        let mut volume = VoxelVolume::new(0.5);
+        volume.apply_brush(&Brush::new(
+            BrushShape::Sphere {
+                center: Vec3::new(3.0, 4.0, -2.0),
+                radius: 5.0,
+            },
+            BrushOp::Add,
+            3,
+        ));
+        volume.apply_brush(&Brush::new(
+            BrushShape::Box {
+                center: Vec3::new(5.0, 4.0, -2.0),
+                half_extents: Vec3::splat(2.0),
+            },
+            BrushOp::SmoothSubtract { blend: 1.0 },
+            0,
+        ));
+        let bytes = volume_bytes(&volume);
+        let loaded = VoxelVolume::read_from(&mut bytes.as_slice()).unwrap();
+        assert_eq!(loaded.voxel_size(), volume.voxel_size());
+        assert_eq!(loaded.brick_count(), volume.brick_count());
+        for (coord, brick) in volume.bricks() {
+            let other = loaded.brick(*coord).unwrap();
+            assert_eq!(other.densities, brick.densities);
+            assert_eq!(other.materials, brick.materials);
+        }
+        assert_rejects_corruption(&bytes, |mut bytes| VoxelVolume::read_from(&mut bytes));
+    }
+
+    #[test]
+    fn test_density_grid_round_trip() {
+        let samples_per_dim = 5;
+        let grid = DensityGrid {
+            samples_per_dim,
+            densities: (0..125).map(|i| i * 37 - 2000).collect(),
+            materials: (0..125).map(|i| (i % 7) as u8).collect(),
+        };
+        let mut bytes = Vec::new();
+        grid.write_to(&mut bytes).unwrap();
+        assert_eq!(DensityGrid::read_from(&mut bytes.as_slice()).unwrap(), grid);
+        assert_rejects_corruption(&bytes, |mut bytes| DensityGrid::read_from(&mut bytes));
+
+        let short = DensityGrid {
+            densities: vec![0; 3],
+            ..grid
+        };
+        assert!(short.write_to(&mut Vec::new()).is_err());
+    }
+}
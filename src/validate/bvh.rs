@@ -0,0 +1,105 @@
+// Bounding volume hierarchy over triangle AABBs, split at the median of the longest axis.
+
+use glam::Vec3;
+
+/// Leaves hold at most this many triangles.
+const LEAF_SIZE: usize = 4;
+
+struct BvhNode {
+    min: Vec3,
+    max: Vec3,
+    /// Leaves: range into `TriangleBvh::order`. Internal nodes have no triangles, their first
+    /// child right after them and their second child at `start`.
+    start: usize,
+    count: usize,
+}
+
+pub(crate) struct TriangleBvh {
+    nodes: Vec<BvhNode>,
+    order: Vec<usize>,
+    bounds: Vec<(Vec3, Vec3)>,
+}
+
+impl TriangleBvh {
+    pub(crate) fn new(triangles: &[[Vec3; 3]]) -> Self {
+        let bounds = triangles
+            .iter()
+            .map(|t| (t[0].min(t[1]).min(t[2]), t[0].max(t[1]).max(t[2])))
+            .collect();
+        let mut bvh = Self {
+            nodes: Vec::new(),
+            order: (0..triangles.len()).collect(),
+            bounds,
+        };
+        if !triangles.is_empty() {
+            bvh.build(0, triangles.len());
+        }
+        bvh
+    }
+
+    fn build(&mut self, start: usize, end: usize) -> usize {
+        let (min, max) = self.order[start..end].iter().fold(
+            (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+            |(min, max), &i| (min.min(self.bounds[i].0), max.max(self.bounds[i].1)),
+        );
+        let node = self.nodes.len();
+        self.nodes.push(BvhNode {
+            min,
+            max,
+            start,
+            count: end - start,
+        });
+        if end - start <= LEAF_SIZE {
+            return node;
+        }
+        let extent = max - min;
+        let axis = if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        };
+        let bounds = &self.bounds;
+        let centre = |i: &usize| (bounds[*i].0 + bounds[*i].1)[axis];
+        let mid = (start + end) / 2;
+        self.order[start..end]
+            .select_nth_unstable_by(mid - start, |a, b| centre(a).total_cmp(&centre(b)));
+        self.nodes[node].count = 0;
+        self.build(start, mid);
+        self.nodes[node].start = self.build(mid, end);
+        node
+    }
+
+    /// Calls `visit` with every triangle whose bounds overlap those of `triangle`.
+    pub(crate) fn query<F: FnMut(usize)>(&self, triangle: &[Vec3; 3], visit: F) {
+        let min = triangle[0].min(triangle[1]).min(triangle[2]);
+        let max = triangle[0].max(triangle[1]).max(triangle[2]);
+        self.query_box(min, max, visit);
+    }
+
+    /// Calls `visit` with every triangle whose bounds overlap the box from `min` to `max`.
+    pub(crate) fn query_box<F: FnMut(usize)>(&self, min: Vec3, max: Vec3, mut visit: F) {
+        if self.nodes.is_empty() {
+            return;
+        }
+        let mut stack = vec![0];
+        while let Some(n) = stack.pop() {
+            let node = &self.nodes[n];
+            if node.max.cmplt(min).any() || node.min.cmpgt(max).any() {
+                continue;
+            }
+            if node.count == 0 {
+                stack.push(n + 1);
+                stack.push(node.start);
+                continue;
+            }
+            for &i in &self.order[node.start..node.start + node.count] {
+                let (t_min, t_max) = self.bounds[i];
+                if !(t_max.cmplt(min).any() || t_min.cmpgt(max).any()) {
+                    visit(i);
+                }
+            }
+        }
+    }
+}
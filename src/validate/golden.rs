@@ -0,0 +1,398 @@
+// Tolerance-based mesh comparison for regression tests. A golden mesh keeps its positions and
+// triangles, but only summary statistics of the other attributes, so harmless float changes in
+// a mesher pass while real geometric or shading changes do not.
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use glam::Vec3;
+
+use crate::validate::bvh::TriangleBvh;
+
+/// Per-component mean and standard deviation of a vertex attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeStats {
+    pub count: usize,
+    pub mean: Vec<f32>,
+    pub std_dev: Vec<f32>,
+}
+
+impl AttributeStats {
+    pub fn new<const N: usize>(values: &[[f32; N]]) -> Self {
+        let count = values.len().max(1) as f64;
+        let mut mean = [0.0f64; N];
+        for value in values {
+            for (m, v) in mean.iter_mut().zip(value) {
+                *m += *v as f64 / count;
+            }
+        }
+        let mut variance = [0.0f64; N];
+        for value in values {
+            for ((s, m), v) in variance.iter_mut().zip(&mean).zip(value) {
+                *s += (*v as f64 - m).powi(2) / count;
+            }
+        }
+        Self {
+            count: values.len(),
+            mean: mean.iter().map(|m| *m as f32).collect(),
+            std_dev: variance.iter().map(|s| s.sqrt() as f32).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeshSnapshot {
+    pub positions: Vec<[f32; 3]>,
+    pub indices: Vec<u32>,
+    pub attributes: Vec<(String, AttributeStats)>,
+}
+
+impl MeshSnapshot {
+    pub fn new(positions: &[[f32; 3]], indices: &[u32]) -> Self {
+        Self {
+            positions: positions.to_vec(),
+            indices: indices.to_vec(),
+            attributes: Vec::new(),
+        }
+    }
+
+    pub fn with_attribute<const N: usize>(mut self, name: &str, values: &[[f32; N]]) -> Self {
+        self.attributes
+            .push((name.to_string(), AttributeStats::new(values)));
+        self
+    }
+
+    /// Plain text: one `attribute`, `v` or `t` record per line.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for (name, stats) in &self.attributes {
+            let join = |values: &[f32]| {
+                values
+                    .iter()
+                    .map(|v| format!("{v:?}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            writeln!(
+                writer,
+                "attribute {name} {} {} | {}",
+                stats.count,
+                join(&stats.mean),
+                join(&stats.std_dev)
+            )?;
+        }
+        for p in &self.positions {
+            writeln!(writer, "v {:?} {:?} {:?}", p[0], p[1], p[2])?;
+        }
+        for t in self.indices.chunks_exact(3) {
+            writeln!(writer, "t {} {} {}", t[0], t[1], t[2])?;
+        }
+        Ok(())
+    }
+
+    pub fn read_from<R: BufRead>(reader: R) -> io::Result<Self> {
+        let invalid = |line: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid golden mesh line: {line}"),
+            )
+        };
+        let mut snapshot = Self::default();
+        for line in reader.lines() {
+            let line = line?;
+            let mut words = line.split_whitespace();
+            match words.next() {
+                Some("attribute") => {
+                    let name = words.next().ok_or_else(|| invalid(&line))?.to_string();
+                    let count = words
+                        .next()
+                        .and_then(|c| c.parse().ok())
+                        .ok_or_else(|| invalid(&line))?;
+                    let rest: Vec<&str> = words.collect();
+                    let split = rest
+                        .iter()
+                        .position(|w| *w == "|")
+                        .ok_or_else(|| invalid(&line))?;
+                    let parse = |words: &[&str]| {
+                        words
+                            .iter()
+                            .map(|w| w.parse::<f32>())
+                            .collect::<Result<Vec<_>, _>>()
+                            .map_err(|_| invalid(&line))
+                    };
+                    let stats = AttributeStats {
+                        count,
+                        mean: parse(&rest[..split])?,
+                        std_dev: parse(&rest[split + 1..])?,
+                    };
+                    snapshot.attributes.push((name, stats));
+                }
+                Some("v") => {
+                    let values: Vec<f32> = words
+                        .map(|w| w.parse())
+                        .collect::<Result<_, _>>()
+                        .map_err(|_| invalid(&line))?;
+                    let position = values.try_into().map_err(|_| invalid(&line))?;
+                    snapshot.positions.push(position);
+                }
+                Some("t") => {
+                    let values: Vec<u32> = words
+                        .map(|w| w.parse())
+                        .collect::<Result<_, _>>()
+                        .map_err(|_| invalid(&line))?;
+                    if values.len() != 3 {
+                        return Err(invalid(&line));
+                    }
+                    snapshot.indices.extend(values);
+                }
+                None => {}
+                Some(_) => return Err(invalid(&line)),
+            }
+        }
+        Ok(snapshot)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoldenTolerance {
+    /// Largest allowed Hausdorff distance between the two surfaces.
+    pub hausdorff: f32,
+    /// Largest allowed difference in triangle count, relative to the golden mesh.
+    pub triangle_band: f32,
+    /// Largest allowed difference of any attribute's per-component mean or standard deviation.
+    pub attribute: f32,
+}
+
+impl GoldenTolerance {
+    pub fn for_voxel_size(voxel_size: f32) -> Self {
+        Self {
+            hausdorff: voxel_size * 0.1,
+            triangle_band: 0.02,
+            attribute: 0.02,
+        }
+    }
+}
+
+/// Compares `actual` against `expected`, describing every tolerance it exceeds.
+pub fn compare_meshes(
+    actual: &MeshSnapshot,
+    expected: &MeshSnapshot,
+    tolerance: &GoldenTolerance,
+) -> Result<(), String> {
+    let mut failures = Vec::new();
+    let (triangles, expected_triangles) = (actual.indices.len() / 3, expected.indices.len() / 3);
+    if triangles.abs_diff(expected_triangles) as f32
+        > expected_triangles as f32 * tolerance.triangle_band
+    {
+        failures.push(format!(
+            "{triangles} triangles, expected {expected_triangles}"
+        ));
+    }
+    let distance = hausdorff_distance(
+        &actual.positions,
+        &actual.indices,
+        &expected.positions,
+        &expected.indices,
+        tolerance.hausdorff * 4.0,
+    );
+    if distance > tolerance.hausdorff {
+        failures.push(format!(
+            "Hausdorff distance {distance} exceeds {}",
+            tolerance.hausdorff
+        ));
+    }
+    for (name, expected_stats) in &expected.attributes {
+        let Some((_, stats)) = actual.attributes.iter().find(|(n, _)| n == name) else {
+            failures.push(format!("missing attribute {name}"));
+            continue;
+        };
+        let differs = |a: &[f32], b: &[f32]| {
+            a.len() != b.len()
+                || a.iter()
+                    .zip(b)
+                    .any(|(a, b)| (a - b).abs() > tolerance.attribute)
+        };
+        if differs(&stats.mean, &expected_stats.mean)
+            || differs(&stats.std_dev, &expected_stats.std_dev)
+        {
+            failures.push(format!(
+                "attribute {name}: {stats:?}, expected {expected_stats:?}"
+            ));
+        }
+    }
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("; "))
+    }
+}
+
+/// Symmetric Hausdorff distance between two triangle meshes, measured from the vertices and
+/// triangle centroids of each to the surface of the other. Distances beyond `limit` are
+/// reported as `limit`, which keeps the nearest-triangle searches local.
+pub fn hausdorff_distance(
+    positions_a: &[[f32; 3]],
+    indices_a: &[u32],
+    positions_b: &[[f32; 3]],
+    indices_b: &[u32],
+    limit: f32,
+) -> f32 {
+    directed_hausdorff(positions_a, indices_a, positions_b, indices_b, limit).max(
+        directed_hausdorff(positions_b, indices_b, positions_a, indices_a, limit),
+    )
+}
+
+fn directed_hausdorff(
+    from_positions: &[[f32; 3]],
+    from_indices: &[u32],
+    to_positions: &[[f32; 3]],
+    to_indices: &[u32],
+    limit: f32,
+) -> f32 {
+    let corners = |positions: &[[f32; 3]], indices: &[u32]| -> Vec<[Vec3; 3]> {
+        indices
+            .chunks_exact(3)
+            .map(|t| [t[0], t[1], t[2]].map(|i| Vec3::from_array(positions[i as usize])))
+            .collect()
+    };
+    let (from, to) = (
+        corners(from_positions, from_indices),
+        corners(to_positions, to_indices),
+    );
+    let bvh = TriangleBvh::new(&to);
+    from.iter()
+        .flat_map(|t| [t[0], t[1], t[2], (t[0] + t[1] + t[2]) / 3.0])
+        .map(|p| {
+            let mut nearest = limit;
+            bvh.query_box(p - Vec3::splat(limit), p + Vec3::splat(limit), |i| {
+                let [a, b, c] = to[i];
+                nearest = nearest.min(p.distance(closest_point_on_triangle(p, a, b, c)));
+            });
+            nearest
+        })
+        .fold(0.0, f32::max)
+}
+
+/// Closest point to `p` on the triangle `abc`, from Ericson's "Real-Time Collision Detection".
+pub(crate) fn closest_point_on_triangle(p: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+    let (ab, ac, ap) = (b - a, c - a, p - a);
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+    let bp = p - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+    let cp = p - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+    let sum = va + vb + vc;
+    if sum == 0.0 {
+        // Degenerate triangle; its corners are as close as it gets.
+        return [a, b, c]
+            .into_iter()
+            .min_by(|x, y| x.distance_squared(p).total_cmp(&y.distance_squared(p)))
+            .unwrap();
+    }
+    a + ab * (vb / sum) + ac * (vc / sum)
+}
+
+/// Set to regenerate every golden mesh from the current meshers instead of comparing.
+#[cfg(test)]
+pub(crate) const UPDATE_GOLDEN_VAR: &str = "ISOMESH_UPDATE_GOLDEN";
+
+/// Compares `actual` with `tests/golden/<name>.mesh`, or rewrites that file when
+/// `ISOMESH_UPDATE_GOLDEN` is set: `ISOMESH_UPDATE_GOLDEN=1 cargo test`.
+#[cfg(test)]
+pub(crate) fn assert_golden(name: &str, actual: &MeshSnapshot, tolerance: &GoldenTolerance) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.mesh"));
+    if std::env::var_os(UPDATE_GOLDEN_VAR).is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        actual.save(&path).unwrap();
+        return;
+    }
+    let expected = MeshSnapshot::load(&path).unwrap_or_else(|e| {
+        panic!(
+            "cannot read {}: {e}; create it with `{UPDATE_GOLDEN_VAR}=1 cargo test`",
+            path.display()
+        )
+    });
+    if let Err(failures) = compare_meshes(actual, &expected, tolerance) {
+        panic!(
+            "{name} no longer matches its golden mesh: {failures}. If the change is intended, \
+             regenerate it with `{UPDATE_GOLDEN_VAR}=1 cargo test`"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validate::golden::{
+        GoldenTolerance, MeshSnapshot, compare_meshes, hausdorff_distance,
+    };
+
+    /// Unit square in the z = 0 plane, split into two triangles.
+    fn square(z: f32) -> MeshSnapshot {
+        let positions = [[0.0, 0.0, z], [1.0, 0.0, z], [1.0, 1.0, z], [0.0, 1.0, z]];
+        MeshSnapshot::new(&positions, &[0, 1, 2, 0, 2, 3])
+            .with_attribute("normals", &[[0.0, 0.0, 1.0f32]; 4])
+    }
+
+    #[test]
+    fn test_golden_round_trip() {
+        let snapshot = square(0.25);
+        let mut bytes = Vec::new();
+        snapshot.write_to(&mut bytes).unwrap();
+        assert_eq!(MeshSnapshot::read_from(&bytes[..]).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn test_golden_tolerances() {
+        let tolerance = GoldenTolerance::for_voxel_size(1.0);
+        assert!(compare_meshes(&square(0.05), &square(0.0), &tolerance).is_ok());
+        assert!(compare_meshes(&square(0.2), &square(0.0), &tolerance).is_err());
+
+        let (a, b) = (square(0.0), square(0.3));
+        let distance = hausdorff_distance(&a.positions, &a.indices, &b.positions, &b.indices, 1.0);
+        assert!((distance - 0.3).abs() < 1e-6);
+
+        let mut flipped = square(0.0);
+        flipped.attributes.clear();
+        let flipped = flipped.with_attribute("normals", &[[0.0, 0.0, -1.0f32]; 4]);
+        assert!(compare_meshes(&flipped, &square(0.0), &tolerance).is_err());
+
+        let mut fewer = square(0.0);
+        fewer.indices.truncate(3);
+        assert!(compare_meshes(&fewer, &square(0.0), &tolerance).is_err());
+    }
+}
@@ -0,0 +1,317 @@
+// Topology and geometry checks for indexed triangle meshes from any of the meshers. Vertices are
+// identified by index, so flat-shaded meshes, which share none, report every edge as boundary.
+
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+#[cfg(test)]
+use std::sync::Arc;
+
+#[cfg(test)]
+use crate::manifold_dual_contouring::{
+    mdc::{GridBounds, MeshBuffers},
+    sampler::{CuboidSampler, FunBlobSampler, Sampler, SphereSampler},
+};
+use crate::validate::bvh::TriangleBvh;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MeshReport {
+    pub vertices: usize,
+    pub edges: usize,
+    pub triangles: usize,
+    /// Edges used by a single triangle.
+    pub boundary_edges: usize,
+    /// Edges used by more than two triangles.
+    pub non_manifold_edges: usize,
+    /// Vertices whose triangles do not form a single fan.
+    pub non_manifold_vertices: usize,
+    /// Triangles with a repeated index or (almost) no area.
+    pub degenerate_triangles: usize,
+    /// Edges whose two triangles traverse them in the same direction.
+    pub inconsistent_winding_edges: usize,
+    /// `V - E + F` over the vertices used by triangles; 2 per closed sphere-like component.
+    pub euler_characteristic: i64,
+    pub connected_components: usize,
+    /// Pairs of triangles without a common vertex that intersect; `None` unless requested.
+    pub self_intersections: Option<usize>,
+}
+
+impl MeshReport {
+    /// Every edge has at most two triangles, every vertex a single fan, and windings agree.
+    pub fn is_manifold(&self) -> bool {
+        self.non_manifold_edges == 0
+            && self.non_manifold_vertices == 0
+            && self.inconsistent_winding_edges == 0
+    }
+
+    /// A manifold without boundary.
+    pub fn is_watertight(&self) -> bool {
+        self.is_manifold() && self.boundary_edges == 0
+    }
+}
+
+/// Checks the triangles `indices` over `positions`. The self-intersection test builds a BVH
+/// and is by far the most expensive part.
+pub fn validate_mesh(
+    positions: &[[f32; 3]],
+    indices: &[u32],
+    check_self_intersections: bool,
+) -> MeshReport {
+    let triangles: Vec<[u32; 3]> = indices
+        .chunks_exact(3)
+        .map(|t| [t[0], t[1], t[2]])
+        .collect();
+    let mut report = MeshReport {
+        triangles: triangles.len(),
+        ..Default::default()
+    };
+    // Directed edge counts, keyed by the undirected edge, lower index first.
+    let mut edges: HashMap<(u32, u32), (u32, u32)> = HashMap::new();
+    // Opposite edges of each vertex's triangles; they chain into its fan.
+    let mut links: HashMap<u32, Vec<(u32, u32)>> = HashMap::new();
+    for t in &triangles {
+        let [a, b, c] = t.map(|i| Vec3::from_array(positions[i as usize]));
+        let longest = (b - a)
+            .length_squared()
+            .max((c - b).length_squared())
+            .max((a - c).length_squared());
+        if t[0] == t[1]
+            || t[1] == t[2]
+            || t[2] == t[0]
+            || (b - a).cross(c - a).length() <= 1e-6 * longest
+        {
+            report.degenerate_triangles += 1;
+        }
+        for k in 0..3 {
+            let (from, to) = (t[k], t[(k + 1) % 3]);
+            let counts = edges.entry((from.min(to), from.max(to))).or_default();
+            if from < to {
+                counts.0 += 1;
+            } else {
+                counts.1 += 1;
+            }
+            links.entry(t[(k + 2) % 3]).or_default().push((from, to));
+        }
+    }
+    report.edges = edges.len();
+    for (forward, backward) in edges.values() {
+        match forward + backward {
+            1 => report.boundary_edges += 1,
+            2 if *forward != 1 => report.inconsistent_winding_edges += 1,
+            2 => {}
+            _ => report.non_manifold_edges += 1,
+        }
+    }
+    report.vertices = links.len();
+    for link in links.values() {
+        let mut components = UnionFind::default();
+        for (a, b) in link {
+            components.union(*a, *b);
+        }
+        if components.count() > 1 {
+            report.non_manifold_vertices += 1;
+        }
+    }
+    let mut components = UnionFind::default();
+    for t in &triangles {
+        components.union(t[0], t[1]);
+        components.union(t[1], t[2]);
+    }
+    report.connected_components = components.count();
+    report.euler_characteristic =
+        report.vertices as i64 - report.edges as i64 + report.triangles as i64;
+    if check_self_intersections {
+        let corners: Vec<[Vec3; 3]> = triangles
+            .iter()
+            .map(|t| t.map(|i| Vec3::from_array(positions[i as usize])))
+            .collect();
+        let bvh = TriangleBvh::new(&corners);
+        let mut intersections = 0;
+        for (i, t) in triangles.iter().enumerate() {
+            bvh.query(&corners[i], |j| {
+                if j > i
+                    && !triangles[j].iter().any(|v| t.contains(v))
+                    && triangles_intersect(&corners[i], &corners[j])
+                {
+                    intersections += 1;
+                }
+            });
+        }
+        report.self_intersections = Some(intersections);
+    }
+    report
+}
+
+/// Disjoint sets over vertex indices, with union by size and path compression so chains stay
+/// short on meshes with millions of vertices.
+#[derive(Default)]
+struct UnionFind {
+    parent: HashMap<u32, u32>,
+    size: HashMap<u32, u32>,
+}
+
+impl UnionFind {
+    fn find(&mut self, x: u32) -> u32 {
+        let mut root = x;
+        loop {
+            let parent = *self.parent.entry(root).or_insert(root);
+            if parent == root {
+                break;
+            }
+            root = parent;
+        }
+        let mut node = x;
+        while node != root {
+            node = self.parent.insert(node, root).unwrap();
+        }
+        root
+    }
+
+    fn union(&mut self, a: u32, b: u32) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        let size_a = *self.size.get(&a).unwrap_or(&1);
+        let size_b = *self.size.get(&b).unwrap_or(&1);
+        let (small, large) = if size_a < size_b { (a, b) } else { (b, a) };
+        self.parent.insert(small, large);
+        self.size.insert(large, size_a + size_b);
+    }
+
+    fn count(&mut self) -> usize {
+        let keys: Vec<u32> = self.parent.keys().copied().collect();
+        keys.iter().filter(|&&k| self.find(k) == k).count()
+    }
+}
+
+/// Separating axis test; triangles that only touch do not count as intersecting.
+pub(crate) fn triangles_intersect(a: &[Vec3; 3], b: &[Vec3; 3]) -> bool {
+    let edges = |t: &[Vec3; 3]| [t[1] - t[0], t[2] - t[1], t[0] - t[2]];
+    let (edges_a, edges_b) = (edges(a), edges(b));
+    let normal_a = edges_a[0].cross(edges_a[1]);
+    let normal_b = edges_b[0].cross(edges_b[1]);
+    let mut axes = vec![normal_a, normal_b];
+    for ea in &edges_a {
+        for eb in &edges_b {
+            axes.push(ea.cross(*eb));
+        }
+    }
+    // In-plane axes separate coplanar triangles, where all the cross products above vanish.
+    axes.extend(edges_a.iter().map(|e| normal_a.cross(*e)));
+    axes.extend(edges_b.iter().map(|e| normal_b.cross(*e)));
+    let scale = a
+        .iter()
+        .chain(b)
+        .map(|p| p.abs().max_element())
+        .fold(1.0, f32::max);
+    axes.iter().all(|axis| {
+        let length = axis.length();
+        if length <= 1e-12 {
+            return true;
+        }
+        let axis = *axis / length;
+        let project = |t: &[Vec3; 3]| {
+            let d = t.map(|p| p.dot(axis));
+            (d[0].min(d[1]).min(d[2]), d[0].max(d[1]).max(d[2]))
+        };
+        let ((min_a, max_a), (min_b, max_b)) = (project(a), project(b));
+        let epsilon = 1e-5 * scale;
+        max_a > min_b + epsilon && max_b > min_a + epsilon
+    })
+}
+
+/// Meshes a sphere, a cuboid and a blob with `generate` at clustering thresholds from none to
+/// coarse and asserts every mesh is closed, without degenerate triangles or self-intersections.
+#[cfg(test)]
+pub(crate) fn assert_mdc_is_manifold(
+    generate: impl Fn(f32, &mut MeshBuffers, GridBounds, &Arc<dyn Sampler + Send + Sync>),
+) {
+    let shapes: [(Arc<dyn Sampler + Send + Sync>, GridBounds); 3] = [
+        (
+            Arc::new(SphereSampler::new(Vec3::ZERO, 20.0)),
+            GridBounds::centered(48.0, 32),
+        ),
+        (
+            Arc::new(CuboidSampler::new(Vec3::ZERO, Vec3::splat(15.0))),
+            GridBounds::centered(48.0, 32),
+        ),
+        // Large enough for the whole blob, so none of it is cut open by the grid.
+        (
+            Arc::new(FunBlobSampler::new(Vec3::ZERO, 20.0)),
+            GridBounds::centered(96.0, 48),
+        ),
+    ];
+    for (sampler, bounds) in &shapes {
+        for threshold in [0.0, 0.5, 5.0] {
+            let mut mesh_buffers = MeshBuffers::new();
+            generate(threshold, &mut mesh_buffers, *bounds, sampler);
+            let report = validate_mesh(&mesh_buffers.positions, &mesh_buffers.indices, true);
+            assert!(report.is_watertight(), "threshold {threshold}: {report:?}");
+            assert_eq!(report.degenerate_triangles, 0, "threshold {threshold}");
+            assert_eq!(report.self_intersections, Some(0), "threshold {threshold}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validate::mesh::validate_mesh;
+
+    const TETRAHEDRON: [[f32; 3]; 4] = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0],
+    ];
+
+    #[test]
+    fn test_validate_tetrahedron() {
+        let closed = [0, 2, 1, 0, 1, 3, 0, 3, 2, 1, 2, 3];
+        let report = validate_mesh(&TETRAHEDRON, &closed, true);
+        assert!(report.is_watertight());
+        assert_eq!(report.euler_characteristic, 2);
+        assert_eq!(report.connected_components, 1);
+        assert_eq!(report.self_intersections, Some(0));
+
+        let open = validate_mesh(&TETRAHEDRON, &closed[..9], false);
+        assert!(open.is_manifold() && !open.is_watertight());
+        assert_eq!(open.boundary_edges, 3);
+
+        let mut flipped = closed;
+        flipped.swap(10, 11);
+        assert_eq!(
+            validate_mesh(&TETRAHEDRON, &flipped, false).inconsistent_winding_edges,
+            3
+        );
+    }
+
+    #[test]
+    fn test_validate_self_intersection() {
+        let positions = [
+            [0.0, 0.0, 0.0],
+            [2.0, 0.0, 0.0],
+            [0.0, 2.0, 0.0],
+            [0.5, 0.5, -1.0],
+            [0.5, 0.5, 1.0],
+            [1.5, 1.5, 0.0],
+        ];
+        let report = validate_mesh(&positions, &[0, 1, 2, 3, 4, 5], true);
+        assert_eq!(report.self_intersections, Some(1));
+        assert_eq!(report.boundary_edges, 6);
+    }
+
+    #[test]
+    fn test_validate_counts_components_of_long_strips() {
+        let n = 100_000u32;
+        let positions: Vec<[f32; 3]> = (0..n + 5)
+            .map(|i| [(i / 2) as f32, (i % 2) as f32, 0.0])
+            .collect();
+        let mut indices: Vec<u32> = (0..n).flat_map(|i| [i, i + 1, i + 2]).collect();
+        indices.extend([n + 2, n + 3, n + 4]);
+        let report = validate_mesh(&positions, &indices, false);
+        assert_eq!(report.connected_components, 2);
+        assert_eq!(report.vertices, n as usize + 5);
+    }
+}
@@ -0,0 +1,228 @@
+// Approximation error and triangle quality of a mesh, measured against the sampler it was built
+// from. Meant for tuning resolutions and simplification thresholds, and for tracking quality in
+// the benches next to speed.
+
+use std::fmt;
+
+use glam::Vec3;
+
+use crate::manifold_dual_contouring::sampler::{Sampler, get_normal};
+
+/// Upper bin edges of `MeshMetrics::aspect_ratio`; the last bin is open-ended.
+pub const ASPECT_RATIO_BINS: [f32; 6] = [1.5, 2.0, 3.0, 5.0, 10.0, f32::INFINITY];
+/// Upper bin edges of `MeshMetrics::min_angle`, in degrees.
+pub const MIN_ANGLE_BINS: [f32; 6] = [10.0, 20.0, 30.0, 40.0, 50.0, 60.0];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ErrorStats {
+    pub max: f32,
+    pub mean: f32,
+}
+
+impl ErrorStats {
+    fn new(values: impl Iterator<Item = f32>) -> Self {
+        let (mut max, mut sum, mut count) = (0.0f32, 0.0f64, 0usize);
+        for value in values {
+            max = max.max(value);
+            sum += value as f64;
+            count += 1;
+        }
+        Self {
+            max,
+            mean: if count == 0 {
+                0.0
+            } else {
+                (sum / count as f64) as f32
+            },
+        }
+    }
+}
+
+/// Triangle counts per bin; bin `i` holds values up to `bins[i]` that are above `bins[i - 1]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    pub bins: Vec<f32>,
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    fn new(bins: &[f32]) -> Self {
+        Self {
+            bins: bins.to_vec(),
+            counts: vec![0; bins.len()],
+        }
+    }
+
+    fn add(&mut self, value: f32) {
+        let bin = self
+            .bins
+            .iter()
+            .position(|upper| value <= *upper)
+            .unwrap_or(self.bins.len() - 1);
+        self.counts[bin] += 1;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshMetrics {
+    pub vertices: usize,
+    pub triangles: usize,
+    /// |SDF| at the vertices used by triangles.
+    pub vertex_distance: ErrorStats,
+    /// |SDF| at the triangle centroids, where flat triangles stray furthest from curved surfaces.
+    pub centroid_distance: ErrorStats,
+    /// Angle in degrees between each vertex normal and the SDF gradient; `None` without normals.
+    pub normal_deviation: Option<ErrorStats>,
+    /// Angle in degrees between each triangle's winding normal and the SDF gradient at its
+    /// centroid. Flipped triangles show up as deviations near 180.
+    pub face_normal_deviation: ErrorStats,
+    /// Longest edge over 2√3 times the inradius: 1 for an equilateral triangle, while
+    /// degenerate triangles land in the last bin.
+    pub aspect_ratio: Histogram,
+    /// Smallest interior angle in degrees.
+    pub min_angle: Histogram,
+}
+
+/// Measures the triangles `indices` over `positions` against `sampler`. `normals` may be empty.
+pub fn mesh_metrics<S: Sampler>(
+    positions: &[[f32; 3]],
+    normals: &[[f32; 3]],
+    indices: &[u32],
+    sampler: &S,
+) -> MeshMetrics {
+    let angle = |a: Vec3, b: Vec3| a.angle_between(b).to_degrees();
+    // Degrees between `normal` and the gradient at `p`, unless either vanishes.
+    let deviation = |normal: Vec3, p: Vec3| {
+        let gradient = get_normal(p, sampler);
+        (normal != Vec3::ZERO && gradient.is_finite()).then(|| angle(normal, gradient))
+    };
+    let points: Vec<Vec3> = positions.iter().map(|p| Vec3::from_array(*p)).collect();
+    let triangles: Vec<[Vec3; 3]> = indices
+        .chunks_exact(3)
+        .map(|t| [t[0], t[1], t[2]].map(|i| points[i as usize]))
+        .collect();
+    let centroids: Vec<Vec3> = triangles
+        .iter()
+        .map(|t| (t[0] + t[1] + t[2]) / 3.0)
+        .collect();
+    // Meshers can leave vertices no triangle uses, such as those of collapsed clusters.
+    let mut used = vec![false; points.len()];
+    for i in indices {
+        used[*i as usize] = true;
+    }
+    let used_vertices = || (0..points.len()).filter(|i| used[*i]);
+    let normal_deviation = (!normals.is_empty()).then(|| {
+        ErrorStats::new(
+            used_vertices().filter_map(|i| deviation(Vec3::from_array(normals[i]), points[i])),
+        )
+    });
+    let face_normal_deviation = ErrorStats::new(
+        triangles
+            .iter()
+            .zip(&centroids)
+            .filter_map(|(t, c)| deviation((t[1] - t[0]).cross(t[2] - t[0]), *c)),
+    );
+    let mut aspect_ratio = Histogram::new(&ASPECT_RATIO_BINS);
+    let mut min_angle = Histogram::new(&MIN_ANGLE_BINS);
+    for [a, b, c] in &triangles {
+        let edges = [b - a, c - b, a - c];
+        let lengths = edges.map(|e| e.length());
+        let area = edges[0].cross(-edges[2]).length() * 0.5;
+        let perimeter: f32 = lengths.iter().sum();
+        let longest = lengths.iter().fold(0.0f32, |m, l| m.max(*l));
+        // The inradius is 2 * area / perimeter.
+        aspect_ratio.add(longest * perimeter / (4.0 * 3f32.sqrt() * area));
+        let angles = [
+            angle(edges[0], -edges[2]),
+            angle(edges[1], -edges[0]),
+            angle(edges[2], -edges[1]),
+        ];
+        min_angle.add(angles.iter().fold(180.0f32, |m, a| m.min(*a)));
+    }
+    MeshMetrics {
+        vertices: positions.len(),
+        triangles: triangles.len(),
+        vertex_distance: ErrorStats::new(used_vertices().map(|i| sampler.sample(points[i]).abs())),
+        centroid_distance: ErrorStats::new(centroids.iter().map(|c| sampler.sample(*c).abs())),
+        normal_deviation,
+        face_normal_deviation,
+        aspect_ratio,
+        min_angle,
+    }
+}
+
+impl fmt::Display for MeshMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} vertices, {} triangles",
+            self.vertices, self.triangles
+        )?;
+        let stats = |s: &ErrorStats| format!("max {:.4}, mean {:.4}", s.max, s.mean);
+        writeln!(f, "|sdf| at vertices: {}", stats(&self.vertex_distance))?;
+        writeln!(f, "|sdf| at centroids: {}", stats(&self.centroid_distance))?;
+        if let Some(deviation) = &self.normal_deviation {
+            writeln!(f, "vertex normal deviation (deg): {}", stats(deviation))?;
+        }
+        writeln!(
+            f,
+            "face normal deviation (deg): {}",
+            stats(&self.face_normal_deviation)
+        )?;
+        for (name, histogram) in [
+            ("aspect ratio", &self.aspect_ratio),
+            ("min angle (deg)", &self.min_angle),
+        ] {
+            let bins: Vec<String> = histogram
+                .bins
+                .iter()
+                .zip(&histogram.counts)
+                .map(|(upper, count)| format!("<={upper}: {count}"))
+                .collect();
+            writeln!(f, "{name}: {}", bins.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::{
+        manifold_dual_contouring::{
+            mdc::{GridBounds, MeshBuffers, mdc_mesh_generation},
+            sampler::SphereSampler,
+        },
+        validate::metrics::mesh_metrics,
+    };
+
+    #[test]
+    fn test_sphere_metrics() {
+        let sphere = SphereSampler::new(Vec3::ZERO, 12.0);
+        let bounds = GridBounds::centered(32.0, 32);
+        let mut mesh_buffers = MeshBuffers::new();
+        mdc_mesh_generation(0.0, &mut mesh_buffers, false, bounds, true, &sphere);
+        let metrics = mesh_metrics(
+            &mesh_buffers.positions,
+            &mesh_buffers.normals,
+            &mesh_buffers.indices,
+            &sphere,
+        );
+        assert_eq!(metrics.triangles * 3, mesh_buffers.indices.len());
+        assert_eq!(
+            metrics.aspect_ratio.counts.iter().sum::<usize>(),
+            metrics.triangles
+        );
+        assert_eq!(
+            metrics.min_angle.counts.iter().sum::<usize>(),
+            metrics.triangles
+        );
+        assert!(metrics.vertex_distance.max < 0.1 * bounds.cell_size);
+        // Flat triangles cut under the curved surface, but by far less than a cell.
+        assert!(metrics.centroid_distance.mean > metrics.vertex_distance.mean);
+        assert!(metrics.centroid_distance.max < 0.1 * bounds.cell_size);
+        assert!(metrics.normal_deviation.unwrap().max < 1.0);
+        assert!(metrics.face_normal_deviation.max < 15.0);
+    }
+}
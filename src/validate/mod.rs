@@ -0,0 +1,6 @@
+pub(crate) mod bvh;
+pub mod golden;
+pub mod mesh;
+pub mod metrics;
+#[cfg(test)]
+mod properties;
@@ -0,0 +1,300 @@
+// Randomised property tests: whichever mesher builds it, the mesh of a random CSG shape has to be
+// closed, consistently wound and inside its grid, and for marching tetrahedra also manifold.
+// Every case is reproducible from its seed; set `ISOMESH_PROPERTY_SEED` to rerun a single one.
+
+use glam::Vec3;
+
+use crate::{
+    manifold_dual_contouring::{
+        mdc::{GridBounds, MeshBuffers},
+        sampler::{Sampler, bake_sampler_quantized},
+    },
+    marching_cubes::{self, marching_tetrahedra::mt_mesh_generation},
+    validate::{
+        golden::{GoldenTolerance, MeshSnapshot, assert_golden},
+        mesh::validate_mesh,
+    },
+};
+
+const CASES: u64 = 24;
+const SEED_VAR: &str = "ISOMESH_PROPERTY_SEED";
+
+/// SplitMix64, enough randomness for picking shapes without a dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32 * (max - min)
+    }
+
+    fn point(&mut self, extent: f32) -> Vec3 {
+        Vec3::new(
+            self.range(-extent, extent),
+            self.range(-extent, extent),
+            self.range(-extent, extent),
+        )
+    }
+}
+
+#[derive(Debug)]
+enum Shape {
+    Sphere {
+        center: Vec3,
+        radius: f32,
+    },
+    Cuboid {
+        center: Vec3,
+        half_size: Vec3,
+    },
+    Torus {
+        center: Vec3,
+        major: f32,
+        minor: f32,
+    },
+    Union(Box<Shape>, Box<Shape>),
+    SmoothUnion(Box<Shape>, Box<Shape>, f32),
+    Intersection(Box<Shape>, Box<Shape>),
+    Difference(Box<Shape>, Box<Shape>),
+}
+
+impl Shape {
+    fn random(rng: &mut Rng, depth: u32) -> Self {
+        let choice = rng.next_u64() % if depth == 0 { 3 } else { 7 };
+        let child = |rng: &mut Rng| Box::new(Self::random(rng, depth - 1));
+        match choice {
+            0 => Self::Sphere {
+                center: rng.point(8.0),
+                radius: rng.range(3.0, 10.0),
+            },
+            1 => Self::Cuboid {
+                center: rng.point(8.0),
+                half_size: Vec3::new(
+                    rng.range(2.0, 8.0),
+                    rng.range(2.0, 8.0),
+                    rng.range(2.0, 8.0),
+                ),
+            },
+            2 => Self::Torus {
+                center: rng.point(6.0),
+                major: rng.range(5.0, 10.0),
+                minor: rng.range(1.5, 4.0),
+            },
+            3 => Self::Union(child(rng), child(rng)),
+            4 => Self::SmoothUnion(child(rng), child(rng), rng.range(1.0, 4.0)),
+            5 => Self::Intersection(child(rng), child(rng)),
+            _ => Self::Difference(child(rng), child(rng)),
+        }
+    }
+
+    fn distance(&self, p: Vec3) -> f32 {
+        match self {
+            Self::Sphere { center, radius } => p.distance(*center) - radius,
+            Self::Cuboid { center, half_size } => {
+                let q = (p - *center).abs() - *half_size;
+                q.max(Vec3::ZERO).length() + q.max_element().min(0.0)
+            }
+            Self::Torus {
+                center,
+                major,
+                minor,
+            } => {
+                let q = p - *center;
+                Vec3::new(q.x.hypot(q.z) - major, q.y, 0.0).length() - minor
+            }
+            Self::Union(a, b) => a.distance(p).min(b.distance(p)),
+            Self::SmoothUnion(a, b, k) => {
+                let (a, b) = (a.distance(p), b.distance(p));
+                let h = (0.5 + 0.5 * (b - a) / k).clamp(0.0, 1.0);
+                b + (a - b) * h - k * h * (1.0 - h)
+            }
+            Self::Intersection(a, b) => a.distance(p).max(b.distance(p)),
+            Self::Difference(a, b) => a.distance(p).max(-b.distance(p)),
+        }
+    }
+}
+
+/// A random shape clipped to a ball, so its surface always closes inside the grid.
+#[derive(Debug)]
+struct CsgSampler {
+    shape: Shape,
+    radius: f32,
+}
+
+impl Sampler for CsgSampler {
+    fn sample(&self, point: Vec3) -> f32 {
+        self.shape.distance(point).max(point.length() - self.radius)
+    }
+}
+
+fn csg_case(seed: u64) -> CsgSampler {
+    let mut rng = Rng(seed);
+    CsgSampler {
+        shape: Shape::random(&mut rng, 3),
+        radius: 18.0,
+    }
+}
+
+/// Runs `check` on the sampler of every seed and of `extra_seeds`, or only on
+/// `ISOMESH_PROPERTY_SEED` when set.
+fn for_each_case(extra_seeds: &[u64], check: impl Fn(u64, &CsgSampler)) {
+    let seeds: Vec<u64> = match std::env::var(SEED_VAR) {
+        Ok(seed) => vec![seed.parse().expect("seed must be an integer")],
+        Err(_) => (0..CASES).chain(extra_seeds.iter().copied()).collect(),
+    };
+    for seed in seeds {
+        check(seed, &csg_case(seed));
+    }
+}
+
+/// Checks the mesh is closed, consistently wound and inside the `(min, max)` box, and that it is
+/// manifold exactly when `manifold` is set.
+fn assert_closed_in_bounds(
+    mesher: &str,
+    seed: u64,
+    sampler: &CsgSampler,
+    positions: &[[f32; 3]],
+    indices: &[u32],
+    (min, max): (Vec3, Vec3),
+    manifold: bool,
+) {
+    let context = format!("{mesher}, seed {seed} ({SEED_VAR}={seed}): {sampler:?}");
+    let report = validate_mesh(positions, indices, false);
+    assert_eq!(report.boundary_edges, 0, "{context}\n{report:?}");
+    assert_eq!(
+        report.inconsistent_winding_edges, 0,
+        "{context}\n{report:?}"
+    );
+    if manifold {
+        assert!(report.is_watertight(), "{context}\n{report:?}");
+    } else {
+        assert!(
+            !report.is_watertight(),
+            "{context}\nmanifold now, drop it from MDC_NON_MANIFOLD"
+        );
+    }
+    let epsilon = Vec3::splat(1e-3);
+    assert!(
+        positions
+            .iter()
+            .map(|p| Vec3::from_array(*p))
+            .all(|p| p.cmpge(min - epsilon).all() && p.cmple(max + epsilon).all()),
+        "{context}\nvertex outside the grid"
+    );
+}
+
+/// MDC cases that are closed but not yet manifold, as (version, seed, threshold). Seed 40 has
+/// an ambiguous face joined by both of its cells, at every threshold. In the others two sheets
+/// of the surface meet at a single vertex. See "Known issues" in the README.
+const MDC_NON_MANIFOLD: [(u8, u64, f32); 9] = [
+    (1, 40, 0.0),
+    (1, 40, 0.5),
+    (1, 40, 5.0),
+    (2, 40, 0.0),
+    (2, 40, 0.5),
+    (2, 40, 5.0),
+    (1, 97, 0.0),
+    (2, 97, 0.0),
+    (2, 130, 0.5),
+];
+
+#[test]
+fn test_random_csg_mdc() {
+    let bounds = GridBounds::centered(48.0, 32);
+    let manifold = |version: u8, seed: u64, threshold: f32| {
+        !MDC_NON_MANIFOLD.contains(&(version, seed, threshold))
+    };
+    for_each_case(&[40, 97, 130], |seed, sampler| {
+        for threshold in [0.0, 0.5, 5.0] {
+            let mut v1 = MeshBuffers::new();
+            crate::manifold_dual_contouring::mdc::mdc_mesh_generation(
+                threshold, &mut v1, false, bounds, true, sampler,
+            );
+            assert_closed_in_bounds(
+                &format!("mdc, threshold {threshold}"),
+                seed,
+                sampler,
+                &v1.positions,
+                &v1.indices,
+                (bounds.min, bounds.max()),
+                manifold(1, seed, threshold),
+            );
+            let mut v2 = MeshBuffers::new();
+            crate::manifold_dual_contouring_2::mdc::mdc_mesh_generation(
+                threshold, &mut v2, false, bounds, true, sampler,
+            );
+            assert_closed_in_bounds(
+                &format!("mdc v2, threshold {threshold}"),
+                seed,
+                sampler,
+                &v2.positions,
+                &v2.indices,
+                (bounds.min, bounds.max()),
+                manifold(2, seed, threshold),
+            );
+        }
+    });
+}
+
+// A few coarse cases pinned as golden meshes, so changes to the MDC tables or clustering show
+// up as a diff of the meshes they produce.
+#[test]
+fn test_csg_mdc_golden() {
+    let bounds = GridBounds::centered(48.0, 16);
+    for seed in [5, 16, 62] {
+        let mut mesh_buffers = MeshBuffers::new();
+        crate::manifold_dual_contouring::mdc::mdc_mesh_generation(
+            0.5,
+            &mut mesh_buffers,
+            false,
+            bounds,
+            true,
+            &csg_case(seed),
+        );
+        let report = validate_mesh(&mesh_buffers.positions, &mesh_buffers.indices, false);
+        assert!(report.is_watertight(), "seed {seed}: {report:?}");
+        assert_golden(
+            &format!("mdc_csg_{seed}"),
+            &MeshSnapshot::new(&mesh_buffers.positions, &mesh_buffers.indices),
+            &GoldenTolerance::for_voxel_size(bounds.cell_size),
+        );
+    }
+}
+
+#[test]
+fn test_random_csg_mt() {
+    let (samples, half_extent) = (33, 24.0);
+    for_each_case(&[], |seed, sampler| {
+        let densities = bake_sampler_quantized(
+            sampler,
+            Vec3::splat(-half_extent),
+            Vec3::splat(half_extent),
+            (samples, samples, samples),
+        );
+        let materials = vec![1; samples * samples * samples];
+        let mut mesh_buffers = marching_cubes::mc::MeshBuffers::new();
+        mt_mesh_generation(
+            &mut mesh_buffers,
+            &densities,
+            &materials,
+            samples,
+            half_extent,
+        );
+        assert_closed_in_bounds(
+            "mt",
+            seed,
+            sampler,
+            &mesh_buffers.positions,
+            &mesh_buffers.indices,
+            (Vec3::splat(-half_extent), Vec3::splat(half_extent)),
+            true,
+        );
+    });
+}
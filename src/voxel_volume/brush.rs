@@ -0,0 +1,100 @@
+use glam::Vec3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BrushShape {
+    Sphere { center: Vec3, radius: f32 },
+    Box { center: Vec3, half_extents: Vec3 },
+}
+
+impl BrushShape {
+    /// Signed distance to the shape, negative inside.
+    pub fn distance(&self, point: Vec3) -> f32 {
+        match *self {
+            BrushShape::Sphere { center, radius } => (point - center).length() - radius,
+            BrushShape::Box {
+                center,
+                half_extents,
+            } => {
+                let q = (point - center).abs() - half_extents;
+                q.max(Vec3::ZERO).length() + q.max_element().min(0.0)
+            }
+        }
+    }
+
+    pub fn aabb(&self) -> (Vec3, Vec3) {
+        match *self {
+            BrushShape::Sphere { center, radius } => {
+                (center - Vec3::splat(radius), center + Vec3::splat(radius))
+            }
+            BrushShape::Box {
+                center,
+                half_extents,
+            } => (center - half_extents, center + half_extents),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BrushOp {
+    /// Union: `min(d, shape)`.
+    Add,
+    /// Subtraction: `max(d, -shape)`.
+    Subtract,
+    /// Union with the seam rounded over `blend` world units.
+    SmoothAdd { blend: f32 },
+    /// Subtraction with the seam rounded over `blend` world units.
+    SmoothSubtract { blend: f32 },
+}
+
+impl BrushOp {
+    /// Distance outside the shape over which the operation can still change a value.
+    #[inline]
+    pub fn reach(&self) -> f32 {
+        match *self {
+            BrushOp::Add | BrushOp::Subtract => 0.0,
+            BrushOp::SmoothAdd { blend } | BrushOp::SmoothSubtract { blend } => blend,
+        }
+    }
+
+    #[inline]
+    pub fn adds(&self) -> bool {
+        matches!(self, BrushOp::Add | BrushOp::SmoothAdd { .. })
+    }
+
+    pub fn apply(&self, distance: f32, shape: f32) -> f32 {
+        match *self {
+            BrushOp::Add => distance.min(shape),
+            BrushOp::Subtract => distance.max(-shape),
+            BrushOp::SmoothAdd { blend } => smooth_min(distance, shape, blend),
+            BrushOp::SmoothSubtract { blend } => -smooth_min(-distance, shape, blend),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Brush {
+    pub shape: BrushShape,
+    pub op: BrushOp,
+    /// Material written where an adding brush becomes the closest surface.
+    pub material: u8,
+}
+
+impl Brush {
+    pub fn new(shape: BrushShape, op: BrushOp, material: u8) -> Self {
+        Self {
+            shape,
+            op,
+            material,
+        }
+    }
+}
+
+/// Polynomial smooth minimum.
+#[inline]
+pub fn smooth_min(a: f32, b: f32, k: f32) -> f32 {
+    if k <= 0.0 {
+        return a.min(b);
+    }
+    let h = (0.5 + 0.5 * (b - a) / k).clamp(0.0, 1.0);
+    b + (a - b) * h - k * h * (1.0 - h)
+}
@@ -0,0 +1,2 @@
+pub mod brush;
+pub mod volume;
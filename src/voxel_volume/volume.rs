@@ -0,0 +1,398 @@
+// Sparse editable density storage: fixed-size bricks of quantized distances and materials,
+// allocated only where a brush has touched.
+
+use std::collections::{HashMap, HashSet};
+
+use glam::{IVec3, Vec3};
+
+use crate::{
+    manifold_dual_contouring::sampler::{Sampler, dequantize_i16_to_f32, quantize_f32_to_i16},
+    marching_cubes::mc::{MeshBuffers, mc_mesh_generation},
+    voxel_volume::brush::Brush,
+};
+
+/// Samples per brick edge. A brick meshes `BRICK_SIZE` cells, borrowing the first samples of its
+/// positive-side neighbours for the last row.
+pub const BRICK_SIZE: usize = 16;
+pub(crate) const BRICK_SAMPLES: usize = BRICK_SIZE * BRICK_SIZE * BRICK_SIZE;
+
+/// Value of samples outside every brick: as far outside the surface as quantization allows.
+pub const EMPTY_DENSITY: i16 = i16::MAX;
+
+/// Brushes write exact distances this many voxels past their surface; further out only the
+/// sign is guaranteed, which is all meshing needs there.
+const BRUSH_BAND_VOXELS: f32 = 2.0;
+
+pub struct Brick {
+    /// Quantized signed distances, x fastest.
+    pub densities: Box<[i16]>,
+    pub materials: Box<[u8]>,
+}
+
+impl Brick {
+    pub(crate) fn empty() -> Self {
+        Self {
+            densities: vec![EMPTY_DENSITY; BRICK_SAMPLES].into_boxed_slice(),
+            materials: vec![0; BRICK_SAMPLES].into_boxed_slice(),
+        }
+    }
+}
+
+#[inline]
+fn local_index(local: IVec3) -> usize {
+    local.z as usize * BRICK_SIZE * BRICK_SIZE + local.y as usize * BRICK_SIZE + local.x as usize
+}
+
+/// Grid point `g` sits at `g * voxel_size`; brick `b` owns points `b * BRICK_SIZE` up to
+/// (excluding) `(b + 1) * BRICK_SIZE` on each axis.
+pub struct VoxelVolume {
+    voxel_size: f32,
+    bricks: HashMap<IVec3, Brick>,
+}
+
+impl VoxelVolume {
+    pub fn new(voxel_size: f32) -> Self {
+        Self {
+            voxel_size,
+            bricks: HashMap::new(),
+        }
+    }
+
+    #[inline]
+    pub fn voxel_size(&self) -> f32 {
+        self.voxel_size
+    }
+
+    #[inline]
+    pub fn brick_count(&self) -> usize {
+        self.bricks.len()
+    }
+
+    pub fn bricks(&self) -> impl Iterator<Item = (&IVec3, &Brick)> {
+        self.bricks.iter()
+    }
+
+    #[inline]
+    pub fn brick(&self, coord: IVec3) -> Option<&Brick> {
+        self.bricks.get(&coord)
+    }
+
+    pub(crate) fn insert_brick(&mut self, coord: IVec3, brick: Brick) {
+        self.bricks.insert(coord, brick);
+    }
+
+    #[inline]
+    pub fn brick_coord(point: IVec3) -> IVec3 {
+        point.div_euclid(IVec3::splat(BRICK_SIZE as i32))
+    }
+
+    /// World-space corner of `coord`; its mesh spans `BRICK_SIZE` voxels from here.
+    #[inline]
+    pub fn brick_min(&self, coord: IVec3) -> Vec3 {
+        (coord * BRICK_SIZE as i32).as_vec3() * self.voxel_size
+    }
+
+    fn sample_index(&self, point: IVec3) -> Option<(&Brick, usize)> {
+        let coord = Self::brick_coord(point);
+        let local = point - coord * BRICK_SIZE as i32;
+        self.bricks
+            .get(&coord)
+            .map(|brick| (brick, local_index(local)))
+    }
+
+    #[inline]
+    pub fn density(&self, point: IVec3) -> i16 {
+        self.sample_index(point)
+            .map_or(EMPTY_DENSITY, |(brick, i)| brick.densities[i])
+    }
+
+    #[inline]
+    pub fn material(&self, point: IVec3) -> u8 {
+        self.sample_index(point)
+            .map_or(0, |(brick, i)| brick.materials[i])
+    }
+
+    /// Applies `brush` and returns the bricks whose meshes changed: every brick with a modified
+    /// sample, plus the negative-side neighbours that borrow samples on its lower faces.
+    pub fn apply_brush(&mut self, brush: &Brush) -> HashSet<IVec3> {
+        let band = BRUSH_BAND_VOXELS * self.voxel_size + brush.op.reach();
+        let (min, max) = brush.shape.aabb();
+        let min = ((min - Vec3::splat(band)) / self.voxel_size)
+            .floor()
+            .as_ivec3();
+        let max = ((max + Vec3::splat(band)) / self.voxel_size)
+            .ceil()
+            .as_ivec3();
+        let brick_min = Self::brick_coord(min);
+        let brick_max = Self::brick_coord(max);
+        let mut dirty = HashSet::new();
+        for x in brick_min.x..=brick_max.x {
+            for y in brick_min.y..=brick_max.y {
+                for z in brick_min.z..=brick_max.z {
+                    let coord = IVec3::new(x, y, z);
+                    let origin = coord * BRICK_SIZE as i32;
+                    let local_min = (min - origin).max(IVec3::ZERO);
+                    let local_max = (max - origin).min(IVec3::splat(BRICK_SIZE as i32 - 1));
+                    let Some(lower_faces) =
+                        self.apply_brush_to_brick(brush, coord, local_min, local_max)
+                    else {
+                        continue;
+                    };
+                    for i in 0..8 {
+                        let offset = IVec3::new((i >> 2) & 1, (i >> 1) & 1, i & 1);
+                        let borrows = (offset.x == 0 || lower_faces & 1 != 0)
+                            && (offset.y == 0 || lower_faces & 2 != 0)
+                            && (offset.z == 0 || lower_faces & 4 != 0);
+                        if borrows {
+                            dirty.insert(coord - offset);
+                        }
+                    }
+                }
+            }
+        }
+        dirty
+    }
+
+    /// Returns `None` if nothing changed, otherwise a mask of the lower faces (x, y, z in bits
+    /// 0, 1, 2) that had changes on them.
+    fn apply_brush_to_brick(
+        &mut self,
+        brush: &Brush,
+        coord: IVec3,
+        local_min: IVec3,
+        local_max: IVec3,
+    ) -> Option<u8> {
+        let origin = coord * BRICK_SIZE as i32;
+        let voxel_size = self.voxel_size;
+        let mut brick = self.bricks.remove(&coord);
+        let mut lower_faces = None;
+        for z in local_min.z..=local_max.z {
+            for y in local_min.y..=local_max.y {
+                for x in local_min.x..=local_max.x {
+                    let local = IVec3::new(x, y, z);
+                    let i = local_index(local);
+                    let old = brick.as_ref().map_or(EMPTY_DENSITY, |b| b.densities[i]);
+                    let point = (origin + local).as_vec3() * voxel_size;
+                    let shape = brush.shape.distance(point);
+                    let distance = brush.op.apply(dequantize_i16_to_f32(old), shape);
+                    let new = quantize_f32_to_i16(distance);
+                    if new == old {
+                        continue;
+                    }
+                    let brick = brick.get_or_insert_with(Brick::empty);
+                    brick.densities[i] = new;
+                    if brush.op.adds() && shape < dequantize_i16_to_f32(old) {
+                        brick.materials[i] = brush.material;
+                    }
+                    let faces = (x == 0) as u8 | ((y == 0) as u8) << 1 | ((z == 0) as u8) << 2;
+                    lower_faces = Some(lower_faces.unwrap_or(0) | faces);
+                }
+            }
+        }
+        if let Some(brick) = brick {
+            self.bricks.insert(coord, brick);
+        }
+        lower_faces
+    }
+
+    /// Samples of brick `coord` and its borrowed neighbour rows, `BRICK_SIZE + 1` per axis, laid
+    /// out for `mc_mesh_generation`.
+    pub fn bake_brick(&self, coord: IVec3) -> (Vec<i16>, Vec<u8>) {
+        let samples = BRICK_SIZE + 1;
+        let origin = coord * BRICK_SIZE as i32;
+        let mut densities = Vec::with_capacity(samples * samples * samples);
+        let mut materials = Vec::with_capacity(samples * samples * samples);
+        for z in 0..samples as i32 {
+            for y in 0..samples as i32 {
+                for x in 0..samples as i32 {
+                    let point = origin + IVec3::new(x, y, z);
+                    densities.push(self.density(point));
+                    materials.push(self.material(point));
+                }
+            }
+        }
+        (densities, materials)
+    }
+
+    /// Meshes brick `coord` with marching cubes into world space.
+    pub fn mesh_brick(&self, coord: IVec3, mesh_buffers: &mut MeshBuffers) {
+        let (densities, materials) = self.bake_brick(coord);
+        let half_extent = BRICK_SIZE as f32 * self.voxel_size * 0.5;
+        mc_mesh_generation(
+            mesh_buffers,
+            &densities,
+            &materials,
+            BRICK_SIZE + 1,
+            half_extent,
+        );
+        let center = self.brick_min(coord) + Vec3::splat(half_extent);
+        for position in &mut mesh_buffers.positions {
+            *position = (Vec3::from_array(*position) + center).to_array();
+        }
+    }
+}
+
+impl Sampler for VoxelVolume {
+    /// Trilinear interpolation of the stored distances.
+    fn sample(&self, point: Vec3) -> f32 {
+        let grid = point / self.voxel_size;
+        let base = grid.floor();
+        let t = grid - base;
+        let base = base.as_ivec3();
+        let d = |x: i32, y: i32, z: i32| {
+            dequantize_i16_to_f32(self.density(base + IVec3::new(x, y, z)))
+        };
+        let c00 = d(0, 0, 0) + (d(1, 0, 0) - d(0, 0, 0)) * t.x;
+        let c10 = d(0, 1, 0) + (d(1, 1, 0) - d(0, 1, 0)) * t.x;
+        let c01 = d(0, 0, 1) + (d(1, 0, 1) - d(0, 0, 1)) * t.x;
+        let c11 = d(0, 1, 1) + (d(1, 1, 1) - d(0, 1, 1)) * t.x;
+        let c0 = c00 + (c10 - c00) * t.y;
+        let c1 = c01 + (c11 - c01) * t.y;
+        c0 + (c1 - c0) * t.z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use glam::{IVec3, Vec3};
+
+    use crate::{
+        manifold_dual_contouring::sampler::{Sampler, dequantize_i16_to_f32, quantize_f32_to_i16},
+        marching_cubes::mc::MeshBuffers,
+        post_process::weld::{WeldTolerance, weld_mc_mesh},
+        validate::mesh::validate_mesh,
+        voxel_volume::{
+            brush::{Brush, BrushOp, BrushShape},
+            volume::{BRICK_SIZE, Brick, EMPTY_DENSITY, VoxelVolume},
+        },
+    };
+
+    fn sphere(center: Vec3, radius: f32, op: BrushOp) -> Brush {
+        Brush::new(BrushShape::Sphere { center, radius }, op, 3)
+    }
+
+    fn distance(volume: &VoxelVolume, point: IVec3) -> f32 {
+        dequantize_i16_to_f32(volume.density(point))
+    }
+
+    #[test]
+    fn test_brushes_combine_with_stored_distances() {
+        let mut volume = VoxelVolume::new(1.0);
+        let center = Vec3::splat(8.0);
+        volume.apply_brush(&sphere(center, 4.0, BrushOp::Add));
+        let at = |x, y, z| IVec3::new(x, y, z);
+        assert!((distance(&volume, at(8, 8, 8)) + 4.0).abs() < 1e-3);
+        assert!((distance(&volume, at(8, 8, 13)) - 1.0).abs() < 1e-3);
+        assert_eq!(volume.material(at(8, 8, 8)), 3);
+        assert_eq!(volume.material(at(8, 8, 15)), 0);
+        // Past the brush's band only the sign is kept.
+        assert_eq!(volume.density(at(8, 8, 15)), EMPTY_DENSITY);
+
+        volume.apply_brush(&sphere(Vec3::new(8.0, 8.0, 12.0), 2.0, BrushOp::Subtract));
+        assert!((distance(&volume, at(8, 8, 8)) + 2.0).abs() < 1e-3);
+        assert!((distance(&volume, at(8, 8, 12)) - 2.0).abs() < 1e-3);
+        assert!((distance(&volume, at(8, 8, 13)) - 1.0).abs() < 1e-3);
+
+        // Half way into the blend the union is rounded below both distances.
+        let blend = BrushOp::SmoothAdd { blend: 2.0 };
+        volume.apply_brush(&sphere(Vec3::new(8.0, 8.0, 16.0), 2.0, blend));
+        assert!((distance(&volume, at(8, 8, 13)) - 0.5).abs() < 1e-3);
+        assert!((distance(&volume, at(8, 8, 16)) + 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_brush_dirties_bricks_borrowing_its_samples() {
+        let brick = |x, y, z| IVec3::new(x, y, z);
+        let edge = BRICK_SIZE as f32;
+
+        // Touching the lower x face of brick (1, 0, 0) also changes the mesh of (0, 0, 0).
+        let mut volume = VoxelVolume::new(1.0);
+        let dirty = volume.apply_brush(&sphere(Vec3::new(edge + 4.0, 8.0, 8.0), 2.0, BrushOp::Add));
+        assert_eq!(dirty, HashSet::from([brick(1, 0, 0), brick(0, 0, 0)]));
+        assert_eq!(volume.brick_count(), 1);
+
+        // In the corner of a brick, every neighbour sharing that corner borrows from it.
+        let mut volume = VoxelVolume::new(1.0);
+        let dirty = volume.apply_brush(&sphere(Vec3::splat(edge + 3.0), 1.0, BrushOp::Add));
+        let expected: HashSet<IVec3> = (0..8)
+            .map(|i| brick((i >> 2) & 1, (i >> 1) & 1, i & 1))
+            .collect();
+        assert_eq!(dirty, expected);
+        assert_eq!(volume.brick_count(), 1);
+
+        // Removing material that isn't there changes nothing.
+        let mut volume = VoxelVolume::new(1.0);
+        let dirty = volume.apply_brush(&sphere(Vec3::splat(8.0), 3.0, BrushOp::Subtract));
+        assert!(dirty.is_empty());
+        assert_eq!(volume.brick_count(), 0);
+    }
+
+    #[test]
+    fn test_adjacent_brick_meshes_close() {
+        let mut volume = VoxelVolume::new(0.5);
+        let edge = BRICK_SIZE as f32 * 0.5;
+        volume.apply_brush(&sphere(Vec3::new(edge, 4.0, 4.0), 2.5, BrushOp::Add));
+        let (densities, _) = volume.bake_brick(IVec3::ZERO);
+        assert_eq!(densities.len(), (BRICK_SIZE + 1).pow(3));
+
+        let mut mesh_buffers = MeshBuffers::new();
+        for coord in [IVec3::ZERO, IVec3::X] {
+            let mut brick = MeshBuffers::new();
+            volume.mesh_brick(coord, &mut brick);
+            let report = validate_mesh(&brick.positions, &brick.indices, false);
+            assert!(report.boundary_edges > 0);
+            let offset = mesh_buffers.positions.len() as u32;
+            mesh_buffers
+                .indices
+                .extend(brick.indices.iter().map(|i| i + offset));
+            mesh_buffers.positions.append(&mut brick.positions);
+            mesh_buffers.normals.append(&mut brick.normals);
+            mesh_buffers.uvs.append(&mut brick.uvs);
+        }
+        weld_mc_mesh(
+            &mut mesh_buffers,
+            &WeldTolerance {
+                position: 1e-4,
+                normal_angle: 180.0,
+                attribute: f32::INFINITY,
+            },
+        );
+        let report = validate_mesh(&mesh_buffers.positions, &mesh_buffers.indices, false);
+        assert!(report.is_watertight(), "{report:?}");
+        assert_eq!(report.euler_characteristic, 2);
+    }
+
+    #[test]
+    fn test_sample_interpolates_trilinearly() {
+        // Trilinear interpolation reproduces a multilinear field exactly.
+        let field = |p: Vec3| 0.3 * p.x - 0.2 * p.y + 0.1 * p.z + 0.01 * p.x * p.y * p.z - 1.0;
+        let voxel_size = 0.5;
+        let mut volume = VoxelVolume::new(voxel_size);
+        let mut brick = Brick::empty();
+        for (i, density) in brick.densities.iter_mut().enumerate() {
+            let local = IVec3::new(
+                (i % BRICK_SIZE) as i32,
+                (i / BRICK_SIZE % BRICK_SIZE) as i32,
+                (i / (BRICK_SIZE * BRICK_SIZE)) as i32,
+            );
+            *density = quantize_f32_to_i16(field(local.as_vec3() * voxel_size));
+        }
+        volume.insert_brick(IVec3::ZERO, brick);
+
+        for point in [
+            Vec3::ZERO,
+            Vec3::new(1.0, 2.5, 3.0),
+            Vec3::new(0.3, 0.1, 0.45),
+            Vec3::new(2.2, 5.7, 1.9),
+            Vec3::new(7.4, 7.1, 6.8),
+        ] {
+            let sample = volume.sample(point);
+            assert!((sample - field(point)).abs() < 1e-3, "{point}: {sample}");
+        }
+        // Outside every brick the volume is empty.
+        let outside = volume.sample(Vec3::splat(-3.0));
+        assert_eq!(outside, dequantize_i16_to_f32(EMPTY_DENSITY));
+    }
+}